@@ -1,38 +1,29 @@
 use eyre::OptionExt;
-use md5::{Digest, Md5, digest::Output};
-use rayon::prelude::*;
 
-use aoc_common::U32_MAX;
+use aoc_common::md5search::{Digest, Prefix};
 use aoc_meta::Problem;
 
 /// <https://adventofcode.com/2015/day/4>
 pub const THE_IDEAL_STOCKING_STUFFER: Problem = Problem::solved(
     &|input| {
         find(input, |hash| hash[0] == 0 && hash[1] == 0 && hash[2] < 0x10)
-            .ok_or_eyre(format!("No hashes in u{} start with 5 zeros", usize::BITS))
+            .ok_or_eyre("no hashes start with 5 zeros")
     },
     &|input| {
         find(input, |hash| hash[0] == 0 && hash[1] == 0 && hash[2] == 0)
-            .ok_or_eyre(format!("no hashes in u{} start with 6 zeros", usize::BITS))
+            .ok_or_eyre("no hashes start with 6 zeros")
     },
 );
 
 #[inline]
-fn find<F: Sync + Fn(Output<Md5>) -> bool>(input: &str, f: F) -> Option<usize> {
-    let mut base = Md5::new();
-    base.update(input);
-
-    (0..U32_MAX)
-        .into_par_iter()
-        .by_exponential_blocks()
-        .find_first(|&n| {
-            let mut buf = itoa::Buffer::new();
-            let slice = buf.format(n);
+fn find<F: Sync + Fn(&Digest) -> bool>(input: &str, f: F) -> Option<u64> {
+    Prefix::new(input).matches(f).next().map(|(index, _)| index)
+}
 
-            let mut hasher: Md5 = Md5::clone(&base);
-            Digest::update(&mut hasher, slice);
-            let result = Digest::finalize(hasher);
+#[test]
+fn example() {
+    let five_zeros = |hash: &Digest| hash[0] == 0 && hash[1] == 0 && hash[2] < 0x10;
 
-            (f)(result)
-        })
+    assert_eq!(find("abcdef", five_zeros), Some(609_043));
+    assert_eq!(find("pqrstuv", five_zeros), Some(1_048_970));
 }
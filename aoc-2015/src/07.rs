@@ -1,253 +1,460 @@
-use std::collections::HashMap;
-use std::str::FromStr;
-use std::sync::OnceLock;
-
-use eyre::{OptionExt, Report, Result, eyre};
-use fnv::FnvBuildHasher;
-use rayon::prelude::*;
-use tinystr::TinyAsciiStr;
+use std::collections::hash_map::Entry;
+use std::fmt::{self, Display, Formatter};
+use std::mem;
+
+use eyre::{Result, bail, eyre};
+use fnv::FnvHashMap;
+use itertools::Itertools;
 use winnow::{
-    ascii::{alpha1, digit1},
-    combinator::{alt, separated_pair},
+    ascii::{alpha1, digit1, space0},
+    combinator::{alt, cut_err, delimited, separated_pair},
     error::StrContext,
     prelude::*,
 };
 
+use aoc_common::parse;
 use aoc_meta::Problem;
 
-type WireName = TinyAsciiStr<4>;
-const A: WireName = unsafe { WireName::from_utf8_unchecked(*b"a\0\0\0") };
-const B: WireName = unsafe { WireName::from_utf8_unchecked(*b"b\0\0\0") };
-
 /// <https://adventofcode.com/2015/day/7>
 pub const SOME_ASSEMBLY_REQUIRED: Problem = Problem::solved(
+    &|input| WireKit::new(parse::lines(input, connection))?.measure("a"),
     &|input| {
-        input
-            .par_lines()
-            .map(|line| line.trim().parse())
-            .collect::<Result<WireKit>>()?
-            .measure(A)
-            .ok_or_eyre("failed to measure wire A")
-    },
-    &|input| {
-        let mut kit = input
-            .par_lines()
-            .map(|line| line.trim().parse())
-            .collect::<Result<WireKit>>()?;
+        let mut kit = WireKit::new(parse::lines(input, connection))?;
 
-        let a = kit.measure(A).ok_or_eyre("failed to measure wire A")?;
-        kit.reset();
-        kit.set(B, a)?;
+        let a = kit.measure("a")?;
+        kit.override_wire("b", a)?;
 
-        kit.measure(A).ok_or_eyre("failed to measure wire A")
+        kit.measure("a")
     },
 );
 
+/// Wires are numbered in the order their names first appear in the instructions.
+type WireId = usize;
+
+/// A circuit of wires, each driven by exactly one gate, with the gates sorted so that every gate comes after the
+/// gates driving its inputs.
 #[derive(Debug)]
-struct WireKit(HashMap<WireName, MeasuredInput, FnvBuildHasher>);
+struct WireKit<'s> {
+    names: Vec<&'s str>,
+    ids: FnvHashMap<&'s str, WireId>,
+    /// The gate driving each wire.
+    drivers: Vec<Input<WireId>>,
+    /// Every wire, in an order where each wire comes after the wires it depends on.
+    order: Vec<WireId>,
+}
 
-impl WireKit {
-    fn measure(&self, wire: WireName) -> Option<u16> {
-        self.0.get(&wire)?.measure(self)
+impl<'s> WireKit<'s> {
+    /// Assemble a kit from its instructions, checking that every wire is driven by exactly one gate and that the
+    /// circuit has no loops.
+    fn new<I: IntoIterator<Item = Result<Connection<'s>>>>(connections: I) -> Result<Self> {
+        let mut names = Vec::new();
+        let mut ids = FnvHashMap::default();
+        let mut intern = |name: &'s str| match ids.entry(name) {
+            Entry::Occupied(occ) => *occ.get(),
+            Entry::Vacant(vac) => {
+                names.push(name);
+                *vac.insert(names.len() - 1)
+            }
+        };
+
+        let mut drivers = Vec::new();
+        for connection in connections {
+            let Connection {
+                input,
+                output: name,
+            } = connection?;
+
+            let output = intern(name);
+            let input = input.map(&mut intern);
+            if drivers.len() <= output {
+                drivers.resize_with(output + 1, || None);
+            }
+
+            if drivers[output].replace(input).is_some() {
+                bail!("wire {name} is driven by more than one gate");
+            }
+        }
+
+        drivers.resize_with(names.len(), || None);
+
+        let undriven = drivers
+            .iter()
+            .positions(Option::is_none)
+            .map(|id| names[id])
+            .collect_vec();
+        if !undriven.is_empty() {
+            bail!("nothing drives wire(s) {}", undriven.join(", "));
+        }
+
+        let drivers: Vec<Input<WireId>> = drivers.into_iter().flatten().collect();
+        let order = topological_order(&drivers).map_err(|looped| {
+            eyre!(
+                "the circuit has a loop through wire(s) {}",
+                looped.into_iter().map(|id| names[id]).join(", ")
+            )
+        })?;
+
+        Ok(Self {
+            names,
+            ids,
+            drivers,
+            order,
+        })
     }
 
-    fn reset(&mut self) {
-        self.0.par_iter_mut().for_each(|(_, input)| input.reset());
+    fn id(&self, wire: &str) -> Result<WireId> {
+        self.ids
+            .get(wire)
+            .copied()
+            .ok_or_else(|| eyre!("there's no wire named {wire}"))
     }
 
-    fn set(&mut self, wire: WireName, voltage: u16) -> Result<MeasuredInput> {
-        self.0
-            .insert(
-                wire,
-                MeasuredInput::new(Input::Constant(Source::Constant(voltage))),
-            )
-            .ok_or_else(|| eyre!("wire {wire} didn't exist in kit"))
+    /// Measure the voltage on `wire`.
+    fn measure(&self, wire: &str) -> Result<u16> {
+        let id = self.id(wire)?;
+        let mut voltages = vec![0; self.drivers.len()];
+
+        for &wire in &self.order {
+            let voltage = self.drivers[wire].eval(|source| voltages[source]);
+            voltages[wire] = voltage;
+        }
+
+        Ok(voltages[id])
     }
-}
 
-impl FromParallelIterator<Connection> for WireKit {
-    fn from_par_iter<I>(par_iter: I) -> Self
-    where
-        I: IntoParallelIterator<Item = Connection>,
-    {
-        let inner = par_iter
-            .into_par_iter()
-            .map(|Connection { input, output }| (output, MeasuredInput::new(input)))
-            .collect();
-        Self(inner)
+    /// Disconnect `wire` from the gate driving it, and connect it to a constant `voltage` instead.
+    fn override_wire(&mut self, wire: &str, voltage: u16) -> Result<()> {
+        let id = self.id(wire)?;
+
+        // Dropping the wire's inputs only removes constraints on the order, so it's still valid.
+        self.drivers[id] = Input::Constant(Source::Constant(voltage));
+        Ok(())
     }
 }
 
-#[derive(Debug)]
-struct MeasuredInput {
-    input: Input,
-    measured: OnceLock<Option<u16>>,
-}
-
-impl MeasuredInput {
-    fn measure(&self, kit: &WireKit) -> Option<u16> {
-        self.measured
-            .get_or_init(|| match self.input {
-                Input::Constant(Source::Constant(n)) => Some(n),
-                Input::Constant(Source::Wire(w)) => kit.measure(w),
-                Input::Not(Source::Constant(n)) => Some(!n),
-                Input::Not(Source::Wire(w)) => kit.measure(w).map(|n| !n),
-                Input::And(Source::Constant(n), Source::Constant(m)) => Some(n & m),
-                Input::And(Source::Constant(n), Source::Wire(w))
-                | Input::And(Source::Wire(w), Source::Constant(n)) => {
-                    kit.measure(w).map(move |m| m & n)
-                }
-                Input::And(Source::Wire(w1), Source::Wire(w2)) => std::thread::scope(|s| {
-                    let m_handle = s.spawn(move || kit.measure(w1));
-                    let n_handle = s.spawn(move || kit.measure(w2));
-
-                    let m = m_handle.join().expect("thread not to panic")?;
-                    let n = n_handle.join().expect("thread not to panic")?;
-
-                    Some(m & n)
-                }),
-                Input::Or(Source::Constant(n), Source::Constant(m)) => Some(n | m),
-                Input::Or(Source::Constant(n), Source::Wire(w))
-                | Input::Or(Source::Wire(w), Source::Constant(n)) => {
-                    kit.measure(w).map(move |m| m | n)
-                }
-                Input::Or(Source::Wire(w1), Source::Wire(w2)) => std::thread::scope(|s| {
-                    let m_handle = s.spawn(move || kit.measure(w1));
-                    let n_handle = s.spawn(move || kit.measure(w2));
-
-                    let m = m_handle.join().expect("thread not to panic")?;
-                    let n = n_handle.join().expect("thread not to panic")?;
-
-                    Some(m | n)
-                }),
-                Input::LShift(Source::Constant(n), shift) => Some(n << shift),
-                Input::LShift(Source::Wire(w), shift) => kit.measure(w).map(move |n| n << shift),
-                Input::RShift(Source::Constant(n), shift) => Some(n >> shift),
-                Input::RShift(Source::Wire(w), shift) => kit.measure(w).map(move |n| n >> shift),
-            })
-            .as_ref()
-            .copied()
+/// Sort the wires driven by `drivers` so that each comes after the wires it depends on, using Kahn's algorithm. If
+/// there's a loop, returns the wires on it (or on any of them, if there are several).
+fn topological_order(drivers: &[Input<WireId>]) -> Result<Vec<WireId>, Vec<WireId>> {
+    let mut dependents = vec![Vec::new(); drivers.len()];
+    let mut pending = vec![0; drivers.len()];
+
+    for (wire, input) in drivers.iter().enumerate() {
+        for source in input.wires() {
+            dependents[source].push(wire);
+            pending[wire] += 1;
+        }
     }
 
-    fn reset(&mut self) {
-        self.measured.take();
+    let mut order: Vec<WireId> = pending.iter().positions(|&n| n == 0).collect();
+    let mut next = 0;
+
+    while let Some(&wire) = order.get(next) {
+        next += 1;
+
+        for &dependent in &dependents[wire] {
+            pending[dependent] -= 1;
+            if pending[dependent] == 0 {
+                order.push(dependent);
+            }
+        }
+    }
+
+    if order.len() == drivers.len() {
+        Ok(order)
+    } else {
+        // The wires left over are either on a loop or downstream of one, and only the former are to blame.
+        Err(pending
+            .iter()
+            .positions(|&n| n > 0)
+            .filter(|&wire| on_loop(&dependents, wire))
+            .collect())
     }
 }
 
-impl MeasuredInput {
-    fn new(input: Input) -> Self {
-        Self {
-            input,
-            measured: OnceLock::new(),
+/// Whether `wire` feeds back into itself through the wires that depend on it.
+fn on_loop(dependents: &[Vec<WireId>], wire: WireId) -> bool {
+    let mut seen = vec![false; dependents.len()];
+    let mut stack = dependents[wire].clone();
+
+    while let Some(next) = stack.pop() {
+        if next == wire {
+            return true;
+        }
+
+        if !mem::replace(&mut seen[next], true) {
+            stack.extend(&dependents[next]);
         }
     }
+
+    false
 }
 
-#[derive(Debug)]
-struct Connection {
-    input: Input,
-    output: WireName,
+/// Renders the circuit in Graphviz DOT format, with an edge from each wire to the wires it drives.
+impl Display for WireKit<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(f, "digraph circuit {{")?;
+
+        for &wire in &self.order {
+            let name = self.names[wire];
+            let input = self.drivers[wire].map_ref(|&source| self.names[source]);
+            writeln!(f, "    \"{name}\" [label=\"{input} -> {name}\"];")?;
+
+            for source in self.drivers[wire].wires() {
+                writeln!(f, "    \"{}\" -> \"{name}\";", self.names[source])?;
+            }
+        }
+
+        write!(f, "}}")
+    }
 }
 
-impl FromStr for Connection {
-    type Err = Report;
+#[derive(Debug)]
+struct Connection<'s> {
+    input: Input<&'s str>,
+    output: &'s str,
+}
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
+fn connection<'s>(input: &mut &'s str) -> ModalResult<Connection<'s>> {
+    delimited(
+        space0,
         separated_pair(
-            input,
+            self::input,
             " -> ",
-            alpha1.parse_to().context(StrContext::Label("output")),
-        )
-        .context(StrContext::Label("line"))
-        .map(|(input, output)| Connection { input, output })
-        .parse(s)
-        .map_err(|e| eyre!("Error parsing instruction from {e}"))
+            alpha1.context(StrContext::Label("output")),
+        ),
+        space0,
+    )
+    .context(StrContext::Label("connection"))
+    .map(|(input, output)| Connection { input, output })
+    .parse_next(input)
+}
+
+#[derive(Debug, Clone)]
+enum Input<W> {
+    Constant(Source<W>),
+    Not(Source<W>),
+    And(Source<W>, Source<W>),
+    Or(Source<W>, Source<W>),
+    LShift(Source<W>, u8),
+    RShift(Source<W>, u8),
+}
+
+impl<W> Input<W> {
+    fn map<V, F: FnMut(W) -> V>(self, mut f: F) -> Input<V> {
+        match self {
+            Input::Constant(a) => Input::Constant(a.map(&mut f)),
+            Input::Not(a) => Input::Not(a.map(&mut f)),
+            Input::And(a, b) => Input::And(a.map(&mut f), b.map(&mut f)),
+            Input::Or(a, b) => Input::Or(a.map(&mut f), b.map(&mut f)),
+            Input::LShift(a, shift) => Input::LShift(a.map(&mut f), shift),
+            Input::RShift(a, shift) => Input::RShift(a.map(&mut f), shift),
+        }
+    }
+
+    fn map_ref<V, F: FnMut(&W) -> V>(&self, mut f: F) -> Input<V> {
+        let source = |source: &Source<W>, f: &mut F| match source {
+            Source::Wire(w) => Source::Wire(f(w)),
+            Source::Constant(n) => Source::Constant(*n),
+        };
+
+        match self {
+            Input::Constant(a) => Input::Constant(source(a, &mut f)),
+            Input::Not(a) => Input::Not(source(a, &mut f)),
+            Input::And(a, b) => Input::And(source(a, &mut f), source(b, &mut f)),
+            Input::Or(a, b) => Input::Or(source(a, &mut f), source(b, &mut f)),
+            Input::LShift(a, shift) => Input::LShift(source(a, &mut f), *shift),
+            Input::RShift(a, shift) => Input::RShift(source(a, &mut f), *shift),
+        }
+    }
+
+    /// The sources of this input, in order.
+    fn sources(&self) -> impl Iterator<Item = &Source<W>> {
+        let (a, b) = match self {
+            Input::Constant(a) | Input::Not(a) | Input::LShift(a, _) | Input::RShift(a, _) => {
+                (a, None)
+            }
+            Input::And(a, b) | Input::Or(a, b) => (a, Some(b)),
+        };
+
+        std::iter::once(a).chain(b)
     }
 }
 
-#[derive(Debug)]
-enum Input {
-    Constant(Source),
-    Not(Source),
-    And(Source, Source),
-    Or(Source, Source),
-    LShift(Source, u8),
-    RShift(Source, u8),
+impl<W: Copy> Input<W> {
+    /// The wires this input reads from, in order.
+    fn wires(&self) -> impl Iterator<Item = W> + '_ {
+        self.sources().filter_map(|source| match source {
+            Source::Wire(w) => Some(*w),
+            Source::Constant(_) => None,
+        })
+    }
+
+    /// Compute the output of this input, given the voltage on each wire.
+    fn eval<F: Fn(W) -> u16>(&self, voltage: F) -> u16 {
+        let read = |source: &Source<W>| match *source {
+            Source::Wire(w) => voltage(w),
+            Source::Constant(n) => n,
+        };
+
+        match self {
+            Input::Constant(a) => read(a),
+            Input::Not(a) => !read(a),
+            Input::And(a, b) => read(a) & read(b),
+            Input::Or(a, b) => read(a) | read(b),
+            Input::LShift(a, shift) => read(a) << shift,
+            Input::RShift(a, shift) => read(a) >> shift,
+        }
+    }
+}
+
+impl<W: Display> Display for Input<W> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Input::Constant(a) => write!(f, "{a}"),
+            Input::Not(a) => write!(f, "NOT {a}"),
+            Input::And(a, b) => write!(f, "{a} AND {b}"),
+            Input::Or(a, b) => write!(f, "{a} OR {b}"),
+            Input::LShift(a, shift) => write!(f, "{a} LSHIFT {shift}"),
+            Input::RShift(a, shift) => write!(f, "{a} RSHIFT {shift}"),
+        }
+    }
 }
 
-fn input(input: &mut &str) -> ModalResult<Input> {
+fn input<'s>(input: &mut &'s str) -> ModalResult<Input<&'s str>> {
     alt((not, lshift, rshift, and, or, constant))
         .context(StrContext::Label("input"))
         .parse_next(input)
 }
 
-fn constant(input: &mut &str) -> ModalResult<Input> {
+fn constant<'s>(input: &mut &'s str) -> ModalResult<Input<&'s str>> {
     source
         .map(Input::Constant)
         .context(StrContext::Label("constant wire statement"))
         .parse_next(input)
 }
 
-fn not(input: &mut &str) -> ModalResult<Input> {
+fn not<'s>(input: &mut &'s str) -> ModalResult<Input<&'s str>> {
     ("NOT ", source)
         .map(|(_, name)| Input::Not(name))
         .context(StrContext::Label("NOT statement"))
         .parse_next(input)
 }
 
-fn lshift(input: &mut &str) -> ModalResult<Input> {
-    separated_pair(
-        source,
-        " LSHIFT ",
-        digit1.parse_to().context(StrContext::Label("shift amount")),
-    )
-    .map(|(name, shift)| Input::LShift(name, shift))
-    .context(StrContext::Label("LSHIFT statement"))
-    .parse_next(input)
+fn lshift<'s>(input: &mut &'s str) -> ModalResult<Input<&'s str>> {
+    separated_pair(source, " LSHIFT ", shift_amount)
+        .map(|(name, shift)| Input::LShift(name, shift))
+        .context(StrContext::Label("LSHIFT statement"))
+        .parse_next(input)
 }
 
-fn rshift(input: &mut &str) -> ModalResult<Input> {
-    separated_pair(
-        source,
-        " RSHIFT ",
-        digit1.parse_to().context(StrContext::Label("shift amount")),
-    )
-    .map(|(name, shift)| Input::RShift(name, shift))
-    .context(StrContext::Label("RSHIFT statement"))
-    .parse_next(input)
+fn rshift<'s>(input: &mut &'s str) -> ModalResult<Input<&'s str>> {
+    separated_pair(source, " RSHIFT ", shift_amount)
+        .map(|(name, shift)| Input::RShift(name, shift))
+        .context(StrContext::Label("RSHIFT statement"))
+        .parse_next(input)
+}
+
+/// Shifting a 16-bit signal by 16 or more would lose every bit, and isn't something the instructions ask for.
+fn shift_amount(input: &mut &str) -> ModalResult<u8> {
+    cut_err(digit1.parse_to().verify(|&shift| shift < 16))
+        .context(StrContext::Label("shift amount"))
+        .parse_next(input)
 }
 
-fn and(input: &mut &str) -> ModalResult<Input> {
+fn and<'s>(input: &mut &'s str) -> ModalResult<Input<&'s str>> {
     separated_pair(source, " AND ", source)
         .map(|(a, b)| Input::And(a, b))
         .context(StrContext::Label("AND statement"))
         .parse_next(input)
 }
 
-fn or(input: &mut &str) -> ModalResult<Input> {
+fn or<'s>(input: &mut &'s str) -> ModalResult<Input<&'s str>> {
     separated_pair(source, " OR ", source)
         .map(|(a, b)| Input::Or(a, b))
         .context(StrContext::Label("OR statement"))
         .parse_next(input)
 }
 
-#[derive(Debug)]
-enum Source {
-    Wire(WireName),
+#[derive(Debug, Clone, Copy)]
+enum Source<W> {
+    Wire(W),
     Constant(u16),
 }
 
-fn source(input: &mut &str) -> ModalResult<Source> {
+impl<W> Source<W> {
+    fn map<V, F: FnMut(W) -> V>(self, mut f: F) -> Source<V> {
+        match self {
+            Source::Wire(w) => Source::Wire(f(w)),
+            Source::Constant(n) => Source::Constant(n),
+        }
+    }
+}
+
+impl<W: Display> Display for Source<W> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Source::Wire(w) => write!(f, "{w}"),
+            Source::Constant(n) => write!(f, "{n}"),
+        }
+    }
+}
+
+fn source<'s>(input: &mut &'s str) -> ModalResult<Source<&'s str>> {
     alt((
         alpha1
-            .parse_to()
             .map(Source::Wire)
-            .context(StrContext::Label("constant voltage")),
+            .context(StrContext::Label("wire name")),
         digit1
             .parse_to()
             .map(Source::Constant)
-            .context(StrContext::Label("wire name")),
+            .context(StrContext::Label("constant voltage")),
     ))
     .context(StrContext::Label("input source"))
     .parse_next(input)
 }
+
+#[test]
+fn example() {
+    let input = "123 -> x
+456 -> y
+x AND y -> d
+x OR y -> e
+x LSHIFT 2 -> f
+y RSHIFT 2 -> g
+NOT x -> h
+NOT y -> i";
+
+    let mut kit = WireKit::new(parse::lines(input, connection)).unwrap();
+    let measured = ["d", "e", "f", "g", "h", "i", "x", "y"].map(|wire| kit.measure(wire).unwrap());
+    assert_eq!(measured, [72, 507, 492, 114, 65412, 65079, 123, 456]);
+
+    kit.override_wire("x", 0).unwrap();
+    assert_eq!(kit.measure("d").unwrap(), 0);
+    assert_eq!(kit.measure("h").unwrap(), u16::MAX);
+
+    assert!(kit.to_string().contains("\"x\" -> \"d\";"));
+}
+
+#[test]
+fn broken_circuits() {
+    let err = |input| {
+        WireKit::new(parse::lines(input, connection))
+            .unwrap_err()
+            .to_string()
+    };
+
+    assert_eq!(err("x AND y -> z\n1 -> x"), "nothing drives wire(s) y");
+    assert_eq!(
+        err("1 -> a\na AND c -> b\nb -> c\nc -> d"),
+        "the circuit has a loop through wire(s) b, c"
+    );
+    assert_eq!(
+        err("a -> a\na -> b\nb OR d -> c\nc -> d"),
+        "the circuit has a loop through wire(s) a, c, d"
+    );
+    assert_eq!(
+        err("1 -> a\n2 -> a"),
+        "wire a is driven by more than one gate"
+    );
+    assert!(err("1 -> a\na LSHIFT 16 -> b").starts_with("line 2, column 10: invalid shift amount"));
+}
@@ -1,20 +1,19 @@
-use eyre::{Report, Result, eyre};
-use itertools::Itertools;
+use eyre::{OptionExt, Report, Result, eyre};
 use petgraph::{Graph, Undirected, graph::NodeIndex};
-use rayon::prelude::*;
 
 use winnow::ascii::{alpha1, digit1};
 use winnow::combinator::seq;
 use winnow::error::{ContextError, StrContext};
 use winnow::prelude::*;
 
+use aoc_common::tsp::Tsp;
 use aoc_common::{TryFromStr, TryParse};
 use aoc_meta::Problem;
 
 /// <https://adventofcode.com/2015/day/9>
 pub const ALL_IN_A_SINGLE_NIGHT: Problem = Problem::solved(
-    &|input| input.try_parse().map(Locations::shortest_distance),
-    &|input| input.try_parse().map(Locations::longest_distance),
+    &|input| input.try_parse().and_then(Locations::shortest_distance),
+    &|input| input.try_parse().and_then(Locations::longest_distance),
 );
 
 /// A list of locations Santa has to visit and how far apart they are from each other.
@@ -40,40 +39,19 @@ impl<'s> Locations<'s> {
     }
 
     /// Find the shortest distance through this list of stops.
-    fn shortest_distance(self) -> usize {
-        self.distances().reduce(|| usize::MAX, std::cmp::min)
+    fn shortest_distance(self) -> Result<usize> {
+        Tsp::from_graph(&self.0)
+            .shortest_path(None)
+            .map(|route| route.weight)
+            .ok_or_eyre("there's no route through every location")
     }
 
     /// Find the longest distance through this list of stops
-    fn longest_distance(self) -> usize {
-        self.distances().reduce(|| usize::MIN, std::cmp::max)
-    }
-
-    /// Find all possible distances through this list of stops
-    fn distances(&self) -> impl ParallelIterator<Item = usize> + use<'_> {
-        let graph = &self.0;
-
-        graph
-            .node_indices()
-            .permutations(graph.node_count())
-            .par_bridge()
-            .map(|nodes| {
-                nodes
-                    .windows(2)
-                    .map(|slice| {
-                        let a = slice[0];
-                        let b = slice[1];
-                        let distance = {
-                            let distance_maybe = graph.find_edge(a, b);
-                            debug_assert!(distance_maybe.is_some());
-                            // Safety: the graph is strongly connected
-                            unsafe { distance_maybe.unwrap_unchecked() }
-                        };
-
-                        graph[distance]
-                    })
-                    .sum()
-            })
+    fn longest_distance(self) -> Result<usize> {
+        Tsp::from_graph(&self.0)
+            .longest_path(None)
+            .map(|route| route.weight)
+            .ok_or_eyre("there's no route through every location")
     }
 }
 
@@ -120,3 +98,16 @@ impl<'s> TryFromStr<'s> for Leg<'s> {
         .parse_next(&mut input.trim())
     }
 }
+
+#[test]
+fn example() {
+    let input = "London to Dublin = 464
+London to Belfast = 518
+Dublin to Belfast = 141";
+
+    let locations: Locations = input.try_parse().unwrap();
+    assert_eq!(locations.shortest_distance().unwrap(), 605);
+
+    let locations: Locations = input.try_parse().unwrap();
+    assert_eq!(locations.longest_distance().unwrap(), 982);
+}
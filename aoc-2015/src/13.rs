@@ -1,13 +1,12 @@
 use eyre::{OptionExt, Report, Result, eyre};
-use itertools::Itertools;
 use petgraph::graph::NodeIndex;
 use petgraph::{Directed, Graph};
-use rayon::prelude::*;
 use winnow::ascii::{alpha1, digit1};
 use winnow::combinator::{alt, eof, preceded, separated_pair, seq};
 use winnow::error::{ContextError, ErrMode};
 use winnow::prelude::*;
 
+use aoc_common::tsp::Tsp;
 use aoc_common::{TryFromStr, TryParse};
 use aoc_meta::Problem;
 
@@ -26,28 +25,13 @@ impl Table<'_> {
         self.max_happiness()
     }
 
+    /// Sitting next to someone changes both people's happiness, so the best arrangement is the heaviest cycle
+    /// where each pair's weight is the sum of how they feel about each other.
     fn max_happiness(self) -> Result<isize> {
-        let graph = &self.0;
-        let k = graph.node_count();
-        graph
-            .node_indices()
-            .permutations(k)
-            .par_bridge()
-            .map(|arrangement| {
-                (0..k)
-                    .map(|i| {
-                        let a = arrangement[(i + k - 1) % k];
-                        let b = arrangement[i];
-                        let c = arrangement[(i + 1) % k];
-
-                        let left = graph.find_edge(b, a).unwrap();
-                        let right = graph.find_edge(b, c).unwrap();
-
-                        graph[left] + graph[right]
-                    })
-                    .sum()
-            })
-            .max()
+        Tsp::from_graph(&self.0)
+            .symmetrize()
+            .longest_cycle()
+            .map(|arrangement| arrangement.weight)
             .ok_or_eyre("nobody sat at the table :(")
     }
 
@@ -1,129 +1,44 @@
 use eyre::OptionExt;
-use rayon::prelude::*;
 
-use aoc_common::U32_MAX;
+use aoc_common::math;
 use aoc_meta::Problem;
 
 /// <https://adventofcode.com/2015/day/20>
 pub const INFINITE_ELVES_AND_INFINITE_HOUSES: Problem = Problem::solved(
     &|input| {
-        let n = input.trim().parse::<usize>()?;
-        (0..U32_MAX)
-            .into_par_iter()
-            .map(|address| House { address })
-            .by_exponential_blocks()
-            .find_first(|house| {
-                house
-                    .presents_with_infinite_visitors()
-                    .is_some_and(|p| p >= n)
-            })
-            .ok_or_eyre("no houses got enough presents")
-            .map(|house| house.address)
+        let n = input.trim().parse::<u64>()?;
+        // Every house gets at least `10 * (address + 1)` presents, so there's no need to look past `n / 10`.
+        let sums = math::divisor_sums(usize::try_from(n / 10)?);
+        first_house_with(&sums, 10, n).ok_or_eyre("no houses got enough presents")
     },
     &|input| {
-        let n = input.trim().parse::<usize>()?;
-        (0..U32_MAX)
-            .into_par_iter()
-            .map(|address| House { address })
-            .by_exponential_blocks()
-            .find_first(|house| {
-                house
-                    .presents_with_finite_visitors()
-                    .is_some_and(|p| p >= n)
-            })
-            .ok_or_eyre("no houses got enough presents")
-            .map(|house| house.address)
+        let n = input.trim().parse::<u64>()?;
+        // Each elf only visits the first 50 multiples of their number.
+        let sums = math::divisor_sums_bounded(usize::try_from(n / 11 + 1)?, 50);
+        first_house_with(&sums, 11, n).ok_or_eyre("no houses got enough presents")
     },
 );
 
-// the number of presents a house gets is (10 * factor for each factor of that houses address)
-
-#[derive(Debug)]
-struct House {
-    address: usize,
-}
-
-impl House {
-    fn presents_with_infinite_visitors(&self) -> Option<usize> {
-        // use `checked_mul` because if we're getting out of `usize` range, we're already too high.
-        self.address
-            .factors()
-            .map(|n| n.checked_mul(10))
-            .try_fold(1usize, |a, opt| opt.and_then(move |b| a.checked_add(b)))
-    }
-
-    fn presents_with_finite_visitors(&self) -> Option<usize> {
-        self.address
-            .factors()
-            .filter(|&fac| self.address / fac < 50)
-            .map(|n| n.checked_mul(11))
-            .try_fold(1usize, |a, opt| opt.and_then(move |b| a.checked_add(b)))
-    }
-}
-
-trait Factorable {
-    fn factors(&self) -> Factors;
-}
-
-impl Factorable for usize {
-    fn factors(&self) -> Factors {
-        Factors::of(*self)
-    }
-}
-
-struct Factors {
-    of: usize,
-    limit: usize,
-    current: usize,
-    extra: Option<usize>,
+/// The lowest-numbered house that gets at least `n` presents, where each house gets `per_elf` times the sum of the
+/// elves that visit it.
+fn first_house_with(divisor_sums: &[u64], per_elf: u64, n: u64) -> Option<usize> {
+    divisor_sums
+        .iter()
+        .skip(1)
+        .position(|&sum| sum * per_elf >= n)
+        .map(|idx| idx + 1)
 }
 
-impl Factors {
-    fn of(n: usize) -> Self {
-        // limit is sqrt of `n`, since sqrt(`n`) * sqrt(`n`) == of
-        #[allow(
-            clippy::cast_sign_loss,
-            clippy::cast_precision_loss,
-            clippy::cast_possible_truncation,
-            reason = "clippy has a lot of complaints about this line, but they're mostly overblown:
-                - cast_sign_loss: we're guaranteed to have a positive number
-                - cast_precision_loss: the 51 bits of the mantissa in `n` can fit in the 64 bits of `usize`
-                - cast_possible_truncation: the square root of `usize` always fits in a `usize`"
-        )]
-        let limit = (n as f64).sqrt().trunc() as usize;
-
-        Self {
-            of: n,
-            limit,
-            current: 0,
-            extra: None,
-        }
-    }
-}
-
-impl Iterator for Factors {
-    type Item = usize;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        if let Some(other_factor) = self.extra.take() {
-            return Some(other_factor);
-        }
-
-        while self.current < self.limit {
-            self.current += 1;
-
-            let a = self.of / self.current;
-            let b = self.of / a;
-
-            if a * b == self.of {
-                if a != b {
-                    self.extra = Some(b);
-                }
-
-                return Some(a);
-            }
-        }
-
-        None
-    }
+#[test]
+fn example() {
+    let sums = math::divisor_sums(9);
+    assert_eq!(first_house_with(&sums, 10, 70), Some(4));
+    assert_eq!(first_house_with(&sums, 10, 120), Some(6));
+    assert_eq!(first_house_with(&sums, 10, 150), Some(8));
+    assert_eq!(first_house_with(&sums, 10, 151), None);
+
+    // Elf 1 delivers to house 50, its 50th, but not to house 51.
+    let sums = math::divisor_sums_bounded(51, 50);
+    assert_eq!(sums[50], 1 + 2 + 5 + 10 + 25 + 50);
+    assert_eq!(sums[51], 3 + 17 + 51);
 }
@@ -1,107 +1,138 @@
-use std::collections::{HashSet, VecDeque};
-use std::hash::Hash;
+use std::fmt::{self, Display, Formatter};
 use std::num::NonZeroU8;
 
-use either::Either;
 use eyre::{OptionExt, Result};
-use fnv::FnvBuildHasher;
-use rayon::prelude::*;
 
+use aoc_common::search::{Path, SearchSpace, dijkstra};
+use aoc_common::trace;
 use aoc_meta::Problem;
 
 use self::boss::Boss;
 use self::player::Player;
-use self::spell::{Action, Effects, Instant, Kind, Spell};
+use self::spell::{Action, Effects, Instant, Kind, SPELLS, Spell};
 
 /// <https://adventofcode.com/2015/day/22>
 pub const WIZARD_SIMULATOR_20XX: Problem = Problem::solved(
-    &|input| {
-        let boss: Boss = input.parse()?;
-        let player = Player::new();
-
-        let game = Game::new(player, boss, false);
-        game.least_mana()
-    },
-    &|input| {
-        let boss: Boss = input.parse()?;
-        let player = Player::new();
-
-        let game = Game::new(player, boss, true);
-        game.least_mana()
-    },
+    &|input| Game::new(false).least_mana(input.parse()?),
+    &|input| Game::new(true).least_mana(input.parse()?),
 );
 
+/// The player starts every fight with 50 hit points and 500 mana.
+const PLAYER: Player = Player::new(50, 500);
+
+/// The fight as a search space, where each step is a round of the player's turn and then the boss's, costing the
+/// mana spent on the spell cast.
 struct Game {
-    queue: VecDeque<GameState>,
-    history: HashSet<GameState, FnvBuildHasher>,
+    hard_mode: bool,
 }
 
 impl Game {
-    fn new(player: Player, boss: Boss, hard_mode: bool) -> Self {
-        let mut queue = VecDeque::default();
-        queue.push_back(GameState::new(player, boss, hard_mode));
+    fn new(hard_mode: bool) -> Self {
+        Self { hard_mode }
+    }
 
-        Game {
-            queue,
-            history: HashSet::default(),
+    /// The least mana the player can spend and still win. With tracing enabled, the winning fight is recorded turn
+    /// by turn.
+    fn least_mana(&self, boss: Boss) -> Result<usize> {
+        let victory = self
+            .cheapest_victory(PLAYER, boss)
+            .ok_or_eyre("couldn't beat boss")?;
+
+        if trace::enabled() {
+            for (turn, record) in (1..).zip(self.replay(&victory.states)) {
+                trace::record(turn, &record.event, &record.registers());
+            }
         }
+
+        Ok(victory.cost)
+    }
+
+    /// The fight in which the player spends the least mana to win. The search stops as soon as it's found, since
+    /// every fight left to look at costs at least as much.
+    fn cheapest_victory(&self, player: Player, boss: Boss) -> Option<Path<GameState, usize>> {
+        dijkstra(self, GameState::new(player, boss)).next_goal()
     }
 
-    fn least_mana(mut self) -> Result<usize> {
-        while let Some(state) = self.queue.pop_front() {
-            let Some(next_states) = state.next_turn() else {
-                continue;
-            };
-
-            for next in next_states {
-                if !self.history.contains(&next) {
-                    self.history.insert(next);
-                    self.queue.push_back(next);
-                }
+    /// Every turn of the fight through `states`, working out which spell took each state to the next.
+    fn replay(&self, states: &[GameState]) -> Vec<Turn> {
+        let spells = states.windows(2).map(|pair| {
+            choices()
+                .find(|&spell| {
+                    pair[0].round(spell, self.hard_mode, &mut |_, _| {}) == Some(pair[1])
+                })
+                .expect("consecutive states are a round apart")
+        });
+
+        match states.first() {
+            Some(&start) => self.play(start, spells),
+            None => Vec::new(),
+        }
+    }
+
+    /// Every turn of the fight from `start` where the player casts `spells` in turn, up until the fight ends or a
+    /// spell can't be cast.
+    fn play(&self, start: GameState, spells: impl IntoIterator<Item = Option<Spell>>) -> Vec<Turn> {
+        let mut turns = Vec::new();
+        let mut log = |event, state: &GameState| {
+            turns.push(Turn {
+                event,
+                player: state.player,
+                boss: state.boss,
+                effects: state.effects,
+            });
+        };
+
+        let mut state = start;
+        for spell in spells {
+            match state.round(spell, self.hard_mode, &mut log) {
+                Some(next) => state = next,
+                None => break,
             }
         }
 
-        self.history
-            .par_iter()
-            .filter(|state| state.is_victory())
-            .map(|state| state.total_mana_spent)
-            .min()
-            .ok_or_eyre("couldn't beat boss")
+        turns
+    }
+}
+
+impl SearchSpace for Game {
+    type State = GameState;
+    type Cost = usize;
+
+    fn successors(&self, state: &GameState) -> impl IntoIterator<Item = (GameState, usize)> {
+        choices().filter_map(|spell| {
+            let next = state.round(spell, self.hard_mode, &mut |_, _| {})?;
+            Some((next, spell.map_or(0, |spell| spell.cost.into())))
+        })
+    }
+
+    fn is_goal(&self, state: &GameState) -> bool {
+        state.boss.is_dead()
     }
 }
 
+/// What the player can do on their turn: cast any of the spells, or nothing if the boss is already dead.
+fn choices() -> impl Iterator<Item = Option<Spell>> {
+    std::iter::once(None).chain(SPELLS.map(Some))
+}
+
+/// The state of the fight at the start of one of the player's turns.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 struct GameState {
     player: Player,
     boss: Boss,
     effects: Effects,
-    total_mana_spent: usize,
-    is_player_turn: bool,
-    hard_mode: bool,
 }
 
 impl GameState {
-    fn new(player: Player, boss: Boss, hard_mode: bool) -> Self {
+    fn new(player: Player, boss: Boss) -> Self {
         Self {
             player,
             boss,
             effects: Effects::default(),
-            total_mana_spent: 0,
-            is_player_turn: true,
-            hard_mode,
         }
     }
 
-    #[inline]
-    fn is_victory(&self) -> bool {
-        self.boss.is_dead() && !self.player.is_dead()
-    }
-
-    #[inline]
-    fn is_over(&self) -> bool {
-        self.player.is_dead() || self.boss.is_dead()
-    }
-
+    /// Apply the effects in play at the start of a turn, returning the player's armor for the turn.
     #[inline]
     fn process_effects(&mut self) -> u8 {
         let mut armor = 0;
@@ -116,52 +147,54 @@ impl GameState {
         armor
     }
 
-    fn next_turn(&self) -> Option<impl Iterator<Item = GameState>> {
-        if self.is_over() {
-            return None;
+    /// Play out the player's turn, casting `spell`, and then the boss's, calling `log` after each turn. Returns
+    /// the state at the start of the player's next turn, or the end of the fight if the boss dies.
+    ///
+    /// Returns `None` if the player dies or can't cast `spell`: it's too expensive, already in effect, or the boss
+    /// died before it could be cast (when `spell` has to be `None` instead).
+    fn round(
+        mut self,
+        spell: Option<Spell>,
+        hard_mode: bool,
+        log: &mut impl FnMut(Event, &Self),
+    ) -> Option<Self> {
+        if hard_mode {
+            self.player.defend(NonZeroU8::MIN);
+            if self.player.is_dead() {
+                return None;
+            }
         }
 
-        let is_player_turn = self.is_player_turn;
-
-        let mut this = *self;
-        this.is_player_turn = !is_player_turn;
-        if this.hard_mode && is_player_turn {
-            this.player.defend(unsafe { NonZeroU8::new_unchecked(1) });
+        self.process_effects();
+        if self.boss.is_dead() {
+            log(Event::Finished, &self);
+            return spell.is_none().then_some(self);
         }
 
-        if this.is_over() {
-            return Some(Either::Right(std::iter::once(this)));
+        let spell = spell?;
+        self = self.try_cast(spell)?;
+        log(Event::Cast(spell), &self);
+        if self.boss.is_dead() {
+            return Some(self);
         }
 
-        let armor = this.process_effects();
-        if this.is_over() {
-            return Some(Either::Right(std::iter::once(this)));
+        let armor = self.process_effects();
+        if self.boss.is_dead() {
+            log(Event::Finished, &self);
+            return Some(self);
         }
 
-        Some(if is_player_turn {
-            let base = this;
-            let mut next_states = [None; 5];
-
-            for (i, spell) in base
-                .player
-                .options()
-                .into_iter()
-                .enumerate()
-                .filter_map(|(i, opt)| opt.map(move |spell| (i, spell)))
-            {
-                next_states[i] = base.try_cast(spell);
-            }
-
-            Either::Left(next_states.into_iter().flatten())
-        } else {
-            this.player.defend(this.boss.attack(armor));
-            Either::Right(std::iter::once(this))
-        })
+        let damage = self.boss.attack(armor);
+        self.player.defend(damage);
+        log(Event::Attack(damage.get()), &self);
+        (!self.player.is_dead()).then_some(self)
     }
 
     fn try_cast(mut self, spell: Spell) -> Option<GameState> {
+        if !self.player.can_afford(spell.cost) {
+            return None;
+        }
         self.player.cast(spell.cost);
-        self.total_mana_spent = self.total_mana_spent.saturating_add(spell.cost.into());
 
         match spell.kind {
             Kind::Instant(Instant::Damage) => self.boss.defend(spell.value()),
@@ -179,6 +212,49 @@ impl GameState {
     }
 }
 
+/// Something that happened on one turn of the fight.
+#[derive(Debug, Clone, Copy)]
+enum Event {
+    Cast(Spell),
+    /// The boss attacked, dealing this much damage.
+    Attack(u8),
+    /// The effects at the start of the turn killed the boss.
+    Finished,
+}
+
+impl Display for Event {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Event::Cast(spell) => write!(f, "player casts {spell}"),
+            Event::Attack(damage) => write!(f, "boss attacks for {damage}"),
+            Event::Finished => f.write_str("effects kill the boss"),
+        }
+    }
+}
+
+/// A turn of the fight, along with how things stood at the end of it.
+#[derive(Debug, Clone, Copy)]
+struct Turn {
+    event: Event,
+    player: Player,
+    boss: Boss,
+    effects: Effects,
+}
+
+impl Turn {
+    /// The player's hit points and mana, the boss's hit points, and the turns left on each effect.
+    fn registers(&self) -> [(&'static str, i64); 6] {
+        [
+            ("hp", self.player.hp().into()),
+            ("mana", self.player.mana().into()),
+            ("boss", self.boss.hp().into()),
+            ("shield", self.effects.remaining(Action::Shield).into()),
+            ("poison", self.effects.remaining(Action::Poison).into()),
+            ("recharge", self.effects.remaining(Action::Recharge).into()),
+        ]
+    }
+}
+
 mod boss {
     use std::num::NonZeroU8;
     use std::str::FromStr;
@@ -212,6 +288,11 @@ mod boss {
         pub(super) fn is_dead(self) -> bool {
             self.hp == 0
         }
+
+        #[inline]
+        pub(super) fn hp(self) -> u8 {
+            self.hp
+        }
     }
 
     impl FromStr for Boss {
@@ -234,8 +315,6 @@ mod boss {
 mod player {
     use std::num::NonZeroU8;
 
-    use super::spell::{SPELLS, Spell};
-
     #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
     pub(super) struct Player {
         hp: u8,
@@ -245,14 +324,24 @@ mod player {
     impl Player {
         /// Construct a new [`Player`] with the given HP and mana
         #[inline]
-        pub(super) const fn new() -> Self {
-            Self { hp: 50, mana: 500 }
+        pub(super) const fn new(hp: u8, mana: u16) -> Self {
+            Self { hp, mana }
         }
 
         /// Check if the player is dead
         #[inline]
         pub(super) fn is_dead(self) -> bool {
-            self.mana < Spell::MAGIC_MISSILE.cost.into() || self.hp == 0
+            self.hp == 0
+        }
+
+        #[inline]
+        pub(super) fn hp(self) -> u8 {
+            self.hp
+        }
+
+        #[inline]
+        pub(super) fn mana(self) -> u16 {
+            self.mana
         }
 
         /// Defend from an enemey attack, taking damage equal to the given value
@@ -261,10 +350,10 @@ mod player {
             self.hp = self.hp.saturating_sub(damage.get());
         }
 
-        /// Return a list of all the spells this player can cast with their current mana.
+        /// Check if the player has enough mana to cast a spell costing `mana`
         #[inline]
-        pub(super) fn options(self) -> [Option<Spell>; 5] {
-            std::array::from_fn(|i| (u16::from(SPELLS[i].cost) <= self.mana).then_some(SPELLS[i]))
+        pub(super) fn can_afford(self, mana: u8) -> bool {
+            u16::from(mana) <= self.mana
         }
 
         /// Regain a given amount of mana
@@ -427,6 +516,12 @@ mod spell {
             self.0[action].is_some()
         }
 
+        /// The number of turns left on the given action, or 0 if it isn't in effect.
+        #[inline]
+        pub(super) fn remaining(self, action: Action) -> u8 {
+            self.0[action].map_or(0, NonZeroU8::get)
+        }
+
         /// Cast the given spell action for the given number of turns.
         #[inline]
         pub(super) fn cast(&mut self, action: Action, turns: u8) {
@@ -448,162 +543,76 @@ mod spell {
     }
 }
 
-// #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-// struct State {
-//     hp: i32,
-//     mp: i32,
-//     bhp: i32,
-//     totalmp: i32,
-//     shield: i32,
-//     poison: i32,
-//     recharge: i32,
-//     bdmg: i32,
-// }
-
-// impl State {
-//     fn new(bhp: i32, bdmg: i32) -> Self {
-//         State {
-//             hp: 50,
-//             mp: 500,
-//             bhp,
-//             totalmp: 0,
-//             shield: 0,
-//             poison: 0,
-//             recharge: 0,
-//             bdmg,
-//         }
-//     }
-
-//     fn expand(&self) -> Vec<(State, &'static str)> {
-//         let mut res = Vec::new();
-//         let mut state = self.clone();
-
-//         // Apply effects at the start of the turn
-//         if state.recharge > 0 {
-//             state.mp += 101;
-//             state.recharge -= 1;
-//         }
-//         if state.shield > 0 {
-//             state.shield -= 1;
-//         }
-//         if state.poison > 0 {
-//             state.bhp -= 3;
-//             state.poison -= 1;
-//         }
-//         if state.bhp <= 0 {
-//             state.bhp = 0;
-//             return vec![(state, "")];
-//         }
-
-//         // Spells: missile
-//         if state.mp >= 53 {
-//             let mut new_state = state.clone();
-//             new_state.mp -= 53;
-//             new_state.bhp -= 4;
-//             new_state.totalmp += 53;
-//             res.push((new_state, "missile"));
-//         }
-
-//         // Spells: drain
-//         if state.mp >= 73 {
-//             let mut new_state = state.clone();
-//             new_state.mp -= 73;
-//             new_state.hp += 2;
-//             new_state.bhp -= 2;
-//             new_state.totalmp += 73;
-//             res.push((new_state, "drain"));
-//         }
-
-//         // Conditional Spells
-//         // Shield
-//         if state.shield == 0 && state.mp >= 113 {
-//             let mut new_state = state.clone();
-//             new_state.mp -= 113;
-//             new_state.totalmp += 113;
-//             new_state.shield = 6;
-//             res.push((new_state, "shield"));
-//         }
-
-//         // Poison
-//         if state.poison == 0 && state.mp >= 173 {
-//             let mut new_state = state.clone();
-//             new_state.mp -= 173;
-//             new_state.totalmp += 173;
-//             new_state.poison = 6;
-//             res.push((new_state, "poison"));
-//         }
-
-//         // Recharge
-//         if state.recharge == 0 && state.mp >= 229 {
-//             let mut new_state = state.clone();
-//             new_state.mp -= 229;
-//             new_state.totalmp += 229;
-//             new_state.recharge = 5;
-//             res.push((new_state, "recharge"));
-//         }
-
-//         // Filtering
-//         res.retain(|(state, _)| state.mp >= 0);
-
-//         for (new_state, spell) in &mut res {
-//             if new_state.poison > 0 {
-//                 new_state.bhp -= 3;
-//                 new_state.poison -= 1;
-//             }
-//             if new_state.shield > 0 {
-//                 new_state.shield -= 1;
-//             }
-//             if new_state.recharge > 0 {
-//                 new_state.mp += 101;
-//                 new_state.recharge -= 1;
-//             }
-//             new_state.hp -= new_state.bdmg - if new_state.shield > 0 { 7 } else { 0 };
-//         }
-
-//         res.retain(|(state, _)| state.hp > 0);
-//         res
-//     }
-// }
-
-// fn main() {
-//     let inp = "13\n8";
-
-//     let bhp: i32 = inp.lines().next().unwrap().parse().unwrap();
-//     let bdmg: i32 = inp.lines().nth(1).unwrap().parse().unwrap();
-
-//     let initial_state = State::new(bhp, bdmg);
-
-//     let mut queue: VecDeque<State> = VecDeque::new();
-//     let mut visited: HashSet<State> = HashSet::new();
-//     let mut comefrom: HashMap<State, (State, &'static str)> = HashMap::new();
-
-//     queue.push_back(initial_state.clone());
-//     visited.insert(initial_state.clone());
-
-//     while let Some(current) = queue.pop_front() {
-//         if current.bhp <= 0 {
-//             let mut path = Vec::new();
-//             let mut step = current.clone();
-
-//             while let Some((prev_state, spell)) = comefrom.get(&step) {
-//                 path.push(spell);
-//                 step = prev_state.clone();
-//             }
-
-//             path.reverse();
-//             println!("{:?}", path);
-//             println!("Total Mana: {}", current.totalmp);
-//             return;
-//         }
-
-//         for (next_state, spell) in current.expand() {
-//             if !visited.contains(&next_state) {
-//                 queue.push_back(next_state.clone());
-//                 visited.insert(next_state.clone());
-//                 comefrom.insert(next_state.clone(), (current.clone(), spell));
-//             }
-//         }
-//     }
-
-//     println!("impossible");
-// }
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let player = Player::new(10, 250);
+    let boss = |input: &str| input.parse::<Boss>().unwrap();
+    let game = Game::new(false);
+
+    let victory = game
+        .cheapest_victory(player, boss("Hit Points: 13\nDamage: 8"))
+        .unwrap();
+    assert_eq!(victory.cost, 173 + 53);
+    let turns = game.replay(&victory.states);
+    let events: Vec<_> = turns.iter().map(|turn| turn.event.to_string()).collect();
+    assert_eq!(
+        events,
+        [
+            "player casts Poison",
+            "boss attacks for 8",
+            "player casts Magic Missile",
+            "effects kill the boss"
+        ]
+    );
+    assert_eq!(
+        turns[2].registers(),
+        [
+            ("hp", 2),
+            ("mana", 24),
+            ("boss", 3),
+            ("shield", 0),
+            ("poison", 4),
+            ("recharge", 0)
+        ]
+    );
+
+    // The longer fight from the puzzle, which isn't necessarily the cheapest.
+    let start = GameState::new(player, boss("Hit Points: 14\nDamage: 8"));
+    let spells = [
+        Spell::RECHARGE,
+        Spell::SHIELD,
+        Spell::DRAIN,
+        Spell::POISON,
+        Spell::MAGIC_MISSILE,
+    ];
+    let turns = game.play(start, spells.map(Some));
+    let last = turns.last().unwrap();
+    assert_eq!(turns.len(), 10);
+    assert!(matches!(last.event, Event::Finished));
+    assert_eq!(
+        last.registers(),
+        [
+            ("hp", 1),
+            ("mana", 114),
+            ("boss", 0),
+            ("shield", 0),
+            ("poison", 3),
+            ("recharge", 0)
+        ]
+    );
+    assert!(
+        game.cheapest_victory(player, boss("Hit Points: 14\nDamage: 8"))
+            .unwrap()
+            .cost
+            <= 641
+    );
+
+    // Losing a hit point at the start of each turn, the player doesn't survive long enough to win.
+    assert!(
+        Game::new(true)
+            .cheapest_victory(player, boss("Hit Points: 13\nDamage: 8"))
+            .is_none()
+    );
+}
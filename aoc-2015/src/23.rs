@@ -1,8 +1,10 @@
+use std::fmt::{self, Display, Formatter};
 use std::ops::{Index, IndexMut};
 use std::str::FromStr;
 
 use eyre::{OptionExt, Report, bail, eyre};
 
+use aoc_common::trace;
 use aoc_meta::Problem;
 
 /// <https://adventofcode.com/2015/day/23>
@@ -67,6 +69,12 @@ impl Computer {
             .copied()
         {
             let offset = self.execute(instruction);
+
+            if trace::enabled() {
+                let registers = [("a", self.a as i64), ("b", self.b as i64)];
+                trace::record(cursor as usize, &instruction, &registers);
+            }
+
             cursor += offset;
         }
     }
@@ -111,6 +119,15 @@ impl FromStr for Register {
     }
 }
 
+impl Display for Register {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Register::A => f.write_str("a"),
+            Register::B => f.write_str("b"),
+        }
+    }
+}
+
 struct Program {
     instructions: Vec<Instruction>,
 }
@@ -147,6 +164,19 @@ enum Instruction {
     JumpIfOne { register: Register, offset: i8 },
 }
 
+impl Display for Instruction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Instruction::Half(register) => write!(f, "hlf {register}"),
+            Instruction::Triple(register) => write!(f, "tpl {register}"),
+            Instruction::Increment(register) => write!(f, "inc {register}"),
+            Instruction::Jump { offset } => write!(f, "jmp {offset:+}"),
+            Instruction::JumpIfEven { register, offset } => write!(f, "jie {register}, {offset:+}"),
+            Instruction::JumpIfOne { register, offset } => write!(f, "jio {register}, {offset:+}"),
+        }
+    }
+}
+
 impl FromStr for Instruction {
     type Err = Report;
 
@@ -4,6 +4,7 @@ use std::str::FromStr;
 
 use eyre::{Report, Result, eyre};
 
+use aoc_common::math;
 use aoc_meta::Problem;
 
 /// <https://adventofcode.com/2015/day/25>
@@ -65,34 +66,17 @@ impl Coordinate {
         (x + y - 2) * (x + y - 1) / 2 + x
     }
 
+    /// The code at this coordinate. Each code is the previous one multiplied by 252533 (modulo 33554393), so the
+    /// `n`th code is the first one multiplied by 252533 to the power of `n - 1`.
     fn value(&self) -> usize {
-        Values::new()
-            .nth(self.to_index() - 1)
-            .expect("`Values` to produce infinite elements")
-    }
-}
-
-struct Values {
-    current: usize,
-}
-
-impl Values {
-    #[inline]
-    const fn new() -> Self {
-        Values {
-            current: 20_151_125,
-        }
-    }
-}
+        const FIRST: u64 = 20_151_125;
+        const FACTOR: u64 = 252_533;
+        const MODULUS: u64 = 33_554_393;
 
-impl Iterator for Values {
-    type Item = usize;
+        let exp = (self.to_index() - 1) as u64;
+        let value = FIRST * math::mod_pow(FACTOR, exp, MODULUS) % MODULUS;
 
-    #[inline]
-    fn next(&mut self) -> Option<Self::Item> {
-        let current = self.current;
-        self.current = (current * 252_533) % 33_554_393;
-        Some(current)
+        value as usize
     }
 }
 
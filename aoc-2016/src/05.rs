@@ -4,12 +4,9 @@ use std::mem::transmute;
 use std::num::NonZeroU8;
 use std::ops::Deref;
 
-use eyre::{OptionExt, Result};
-use md5::{Digest, Md5, digest::Output};
-use rayon::prelude::*;
-
-use aoc_common::U32_MAX;
+use aoc_common::md5search::{Digest, Prefix};
 use aoc_meta::Problem;
+use eyre::{OptionExt, Result};
 
 pub const HOW_ABOUT_A_NICE_GAME_OF_CHESS: Problem =
     Problem::solved(&Password::for_door_1, &Password::for_door_2);
@@ -45,8 +42,8 @@ impl Password {
 
     fn for_door_1(door_id: &str) -> Result<Self> {
         let f = Password::generator(
-            // Return `true` if the hash starts with five zeroes
-            |hash, _| hash[0] == 0 && hash[1] == 0 && hash[2] < 0x10,
+            // Every hash starting with five zeroes is a digit.
+            |_, _| true,
             // Update the password digit-by-digit.
             |hash, digits| {
                 if let Some(slot) = digits.iter_mut().find(|opt| opt.is_none()) {
@@ -61,13 +58,10 @@ impl Password {
     fn for_door_2(door_id: &str) -> Result<Self> {
         let f = Password::generator(
             // Return `true` if
-            // - the hash starts with five zeroes
             // - the sixth digit is between 0-7
             // - the sixth digit indexes to an empty slot
             |hash, digits| {
-                hash[0] == 0
-                    && hash[1] == 0
-                    && hash[2] <= 0x7
+                hash[2] <= 0x7
                     // Safety: we just checked that `hash[2]` is <= 7
                     && unsafe { digits.get_unchecked(hash[2] as usize) }.is_none()
             },
@@ -82,37 +76,26 @@ impl Password {
         f(door_id)
     }
 
-    /// Construct a password generator that applies `F` to find valid hashes,
-    /// and then `U` to use that hash to update the password.
+    /// Construct a password generator that applies `F` to find valid hashes among those starting with five
+    /// zeroes, and then `U` to use that hash to update the password.
     fn generator<F, U>(finder: F, updater: U) -> impl Fn(&str) -> Result<Self>
     where
-        F: Sync + Fn(&Output<Md5>, &[Option<NonZeroU8>; 8]) -> bool,
-        U: Fn(Output<Md5>, &mut [Option<NonZeroU8>; 8]),
+        F: Fn(&Digest, &[Option<NonZeroU8>; 8]) -> bool,
+        U: Fn(Digest, &mut [Option<NonZeroU8>; 8]),
     {
         move |door_id| {
             let mut digits = [None; 8];
-            let mut hash_idx_start = 0;
 
-            let mut base = Md5::new();
-            base.update(door_id);
+            let prefix = Prefix::new(door_id);
+            let mut hashes = prefix
+                .matches(|hash| hash[0] == 0 && hash[1] == 0 && hash[2] < 0x10)
+                .map(|(_, hash)| hash);
 
             while digits.iter().any(Option::is_none) {
-                let (hash, hash_idx) = (hash_idx_start..U32_MAX)
-                    .into_par_iter()
-                    .by_exponential_blocks()
-                    .find_map_first(|index| {
-                        let mut buf = itoa::Buffer::new();
-                        let slice = buf.format(index);
-
-                        let mut hasher: Md5 = Md5::clone(&base);
-                        Digest::update(&mut hasher, slice);
-                        let result = Digest::finalize(hasher);
-
-                        (finder)(&result, &digits).then_some((result, index))
-                    })
+                let hash = hashes
+                    .find(|hash| (finder)(hash, &digits))
                     .ok_or_eyre("ran out of hashes")?;
 
-                hash_idx_start = hash_idx + 1;
                 (updater)(hash, &mut digits);
             }
 
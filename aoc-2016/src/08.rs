@@ -1,11 +1,7 @@
-use std::collections::HashMap;
 use std::fmt::{self, Display, Formatter, Write};
 use std::str::FromStr;
-use std::sync::LazyLock;
 
-use eyre::{Report, Result, bail, eyre};
-use fnv::FnvBuildHasher;
-use itertools::Itertools;
+use eyre::{Report, Result, eyre};
 use rayon::prelude::*;
 use winnow::{
     ascii::dec_uint,
@@ -13,6 +9,7 @@ use winnow::{
     prelude::*,
 };
 
+use aoc_common::ocr;
 use aoc_meta::Problem;
 
 pub const TWO_FACTOR_AUTHENTICATION: Problem = Problem::solved(
@@ -139,83 +136,9 @@ impl<const W: usize, const H: usize> From<[[bool; H]; W]> for Screen<W, H> {
     }
 }
 
-impl<const W: usize> Screen<W, 6> {
+impl<const W: usize, const H: usize> Screen<W, H> {
     fn message(&self) -> Result<String> {
-        let chunks = self.0.chunks_exact(5);
-        if !chunks.remainder().is_empty() {
-            bail!("Grid width isn't a multiple of 5, cannot read message!");
-        }
-
-        let mut message = String::with_capacity(chunks.len());
-        for chunk in chunks {
-            // Safety: the chunks are created with length of exactly 5
-            let grid: [[bool; 6]; 5] = unsafe { chunk.try_into().unwrap_unchecked() };
-            let letter = Screen::from(grid).letter()?;
-            message.push(letter);
-        }
-
-        Ok(message)
-    }
-}
-
-impl Screen<5, 6> {
-    fn letter(&self) -> Result<char> {
-        static LETTERS: LazyLock<HashMap<String, char, FnvBuildHasher>> = LazyLock::new(|| {
-            macro_rules! include_letters {
-                    ($($letter:ident),+) => {
-                        $(
-                            const $letter: &str = include_str!(concat!(
-                                env!("CARGO_MANIFEST_DIR"),
-                                ::pathsep::path_separator!(),
-                                "fixtures",
-                                ::pathsep::path_separator!(),
-                                "letters",
-                                ::pathsep::path_separator!(),
-                                stringify!($letter)
-                            ));
-                        )+
-                    };
-                }
-
-            include_letters!(A, B, C, D, E, F, I, J, K, L, O, P, R, S, U, Y, Z);
-
-            let mut map = HashMap::default();
-
-            for (key, value) in [
-                (A, 'A'),
-                (B, 'B'),
-                (C, 'C'),
-                (D, 'D'),
-                (E, 'E'),
-                (F, 'F'),
-                (I, 'I'),
-                (J, 'J'),
-                (K, 'K'),
-                (L, 'L'),
-                (O, 'O'),
-                (P, 'P'),
-                (R, 'R'),
-                (S, 'S'),
-                (U, 'U'),
-                (Y, 'Y'),
-                (Z, 'Z'),
-            ] {
-                let trimmed: String =
-                    Itertools::intersperse(key.lines().map(str::trim), "\n").collect();
-                assert!(
-                    map.insert(trimmed, value).is_none(),
-                    "Overwrote previous value"
-                );
-            }
-
-            map
-        });
-
-        let this_letter = self.to_string();
-        LETTERS
-            .get(&this_letter)
-            .copied()
-            .ok_or_else(|| eyre!("Didn't recognize this letter:\n\n{this_letter}\n"))
+        ocr::read_grid((0..H).map(|y| self.0.iter().map(move |col| col[y])))
     }
 }
 
@@ -1,5 +1,4 @@
-use std::collections::hash_map::Entry;
-use std::collections::{HashMap, VecDeque};
+use std::collections::HashMap;
 use std::hash::{Hash, Hasher};
 use std::ops::{Deref, DerefMut};
 use std::str::FromStr;
@@ -19,6 +18,7 @@ use winnow::error::ContextError;
 use winnow::prelude::*;
 use winnow::stream::Accumulate;
 
+use aoc_common::search::{self, SearchSpace};
 use aoc_meta::Problem;
 
 pub const RADIOISOTOPE_THERMOELECTRIC_GENERATORS: Problem =
@@ -272,32 +272,15 @@ impl Column {
             .flat_map(|to| self.valid_swaps_to(to))
     }
 
-    fn fewest_steps_to_solve(mut self) -> Result<u8> {
-        self.canonicalize();
-        let target_state = self.target();
-
-        let mut queue = VecDeque::default();
-        let mut visited = FnvHashMap::default();
-
-        visited.insert(self, 0);
-        queue.push_back((self, 0));
-
-        while let Some((next, steps)) = queue.pop_front() {
-            if next == target_state {
-                return Ok(steps);
-            }
-
-            for mut state in next.next_valid_states() {
-                state.canonicalize();
-
-                if let Entry::Vacant(vac) = visited.entry(state) {
-                    vac.insert(steps + 1);
-                    queue.push_back((state, steps + 1));
-                }
-            }
-        }
+    fn fewest_steps_to_solve(self) -> Result<u8> {
+        let building = Building {
+            target: self.target(),
+        };
 
-        Err(eyre!("no solution"))
+        search::bfs(&building, self)
+            .next_goal()
+            .ok_or_else(|| eyre!("no solution"))
+            .and_then(|path| Ok(u8::try_from(path.cost)?))
     }
 
     fn valid_swaps_to(&self, to_idx: RangedU8<0, 3>) -> impl Iterator<Item = Column> {
@@ -341,6 +324,29 @@ impl Column {
     }
 }
 
+/// The search space of all reachable [`Column`]s, where each elevator trip is a single step.
+struct Building {
+    target: Column,
+}
+
+impl SearchSpace for Building {
+    type State = Column;
+    type Cost = u8;
+
+    fn successors(&self, column: &Column) -> impl IntoIterator<Item = (Column, u8)> {
+        column.next_valid_states().map(|next| (next, 1))
+    }
+
+    fn is_goal(&self, column: &Column) -> bool {
+        *column == self.target
+    }
+
+    fn canonicalize(&self, mut column: Column) -> Column {
+        column.canonicalize();
+        column
+    }
+}
+
 impl Default for Column {
     fn default() -> Column {
         Column {
@@ -391,3 +397,13 @@ impl FromStr for Column {
         Ok(col)
     }
 }
+
+#[test]
+fn example() {
+    let input = "The first floor contains a hydrogen-compatible microchip and a lithium-compatible microchip.
+The second floor contains a hydrogen generator.
+The third floor contains a lithium generator.
+The fourth floor contains nothing relevant.";
+
+    assert_eq!(minimum_steps(input).unwrap(), 11);
+}
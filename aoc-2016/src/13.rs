@@ -1,10 +1,7 @@
-use std::collections::VecDeque;
-use std::collections::btree_map::{BTreeMap, Entry};
-use std::iter::FusedIterator;
-
 use eyre::eyre;
 
 use aoc_common::grid::{Coordinate, Grid};
+use aoc_common::search::{self, SearchSpace};
 use aoc_meta::Problem;
 
 const GRID_SIZE: usize = 128;
@@ -13,16 +10,17 @@ pub const A_MAZE_OF_TWISTY_LITTLE_CUBICLES: Problem = Problem::solved(
     &|input| {
         let fav = input.parse()?;
         let end = Coordinate { x: 31, y: 39 };
+        let cubicles = Cubicles::<GRID_SIZE>::new(fav, end);
 
-        bfs(fav)
-            .find_map(|(coord, steps)| (coord == end).then_some(steps))
+        search::bfs(&cubicles, Cubicles::<GRID_SIZE>::START)
+            .next_goal()
+            .map(|path| path.cost)
             .ok_or_else(|| eyre!("no way to {end:?} on a {GRID_SIZE}x{GRID_SIZE} grid"))
     },
     &|input| {
         input.parse().map(|fav| {
-            bfs(fav)
-                .take_while(|&(_, this_steps)| this_steps <= 50)
-                .count()
+            let cubicles = Cubicles::<GRID_SIZE>::new(fav, Cubicles::<GRID_SIZE>::START);
+            search::reachable_within(&cubicles, Cubicles::<GRID_SIZE>::START, 50).count()
         })
     },
 );
@@ -40,52 +38,53 @@ impl Location {
 }
 
 #[derive(Debug)]
-struct CubicleBfs<const N: usize> {
+struct Cubicles<const N: usize> {
     grid: Grid<N, Location>,
-    queue: VecDeque<(Coordinate, usize)>,
-    visited: BTreeMap<Coordinate, usize>,
+    end: Coordinate,
 }
 
-impl<const N: usize> Iterator for CubicleBfs<N> {
-    type Item = (Coordinate, usize);
+impl<const N: usize> Cubicles<N> {
+    const START: Coordinate = Coordinate { x: 1, y: 1 };
 
-    fn next(&mut self) -> Option<Self::Item> {
-        self.queue.pop_front().inspect(|&(coord, steps)| {
-            for neighbor in coord.cardinal_neighbors() {
-                if self.grid.get(neighbor).is_some_and(Location::is_space)
-                    && let Entry::Vacant(vac) = self.visited.entry(neighbor)
-                {
-                    vac.insert(steps + 1);
-                    self.queue.push_back((neighbor, steps + 1));
-                }
+    fn new(favorite_number: usize, end: Coordinate) -> Self {
+        let grid = Grid::from_fn(|Coordinate { x, y }| {
+            let sum = x * x + 3 * x + 2 * x * y + y + y * y + favorite_number;
+            if sum.count_ones().is_multiple_of(2) {
+                Location::Space
+            } else {
+                Location::Wall
             }
-        })
+        });
+
+        Self { grid, end }
     }
 }
 
-impl<const N: usize> FusedIterator for CubicleBfs<N> {}
-
-fn bfs(favorite_number: usize) -> CubicleBfs<GRID_SIZE> {
-    let grid = Grid::from_fn(|Coordinate { x, y }| {
-        let sum = x * x + 3 * x + 2 * x * y + y + y * y + favorite_number;
-        if sum.count_ones().is_multiple_of(2) {
-            Location::Space
-        } else {
-            Location::Wall
-        }
-    });
-
-    let start = Coordinate { x: 1, y: 1 };
+impl<const N: usize> SearchSpace for Cubicles<N> {
+    type State = Coordinate;
+    type Cost = usize;
 
-    let mut queue = VecDeque::default();
-    let mut visited = BTreeMap::default();
-
-    queue.push_back((start, 0));
-    visited.insert(start, 0);
+    fn successors(&self, coord: &Coordinate) -> impl IntoIterator<Item = (Coordinate, usize)> {
+        coord
+            .cardinal_neighbors()
+            .filter(|&neighbor| self.grid.get(neighbor).is_some_and(Location::is_space))
+            .map(|neighbor| (neighbor, 1))
+    }
 
-    CubicleBfs {
-        grid,
-        queue,
-        visited,
+    fn is_goal(&self, coord: &Coordinate) -> bool {
+        *coord == self.end
     }
 }
+
+#[test]
+fn example() {
+    let cubicles = Cubicles::<10>::new(10, Coordinate { x: 7, y: 4 });
+
+    assert_eq!(
+        search::bfs(&cubicles, Cubicles::<10>::START)
+            .next_goal()
+            .unwrap()
+            .cost,
+        11
+    );
+}
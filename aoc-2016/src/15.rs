@@ -1,10 +1,12 @@
 use std::str::FromStr;
 
-use eyre::{OptionExt, Report, eyre};
+use eyre::{OptionExt, Report};
+use winnow::combinator::{delimited, preceded};
+use winnow::prelude::*;
 
+use aoc_common::math;
+use aoc_common::parse::{self, unsigned};
 use aoc_meta::Problem;
-use rayon::prelude::*;
-use regex::Regex;
 
 pub const TIMING_IS_EVERYTHING: Problem = Problem::solved(
     &|input| {
@@ -24,16 +26,17 @@ struct Sculpture {
 }
 
 impl Sculpture {
+    /// Disc `i` (0-indexed) is reached `i + 1` ticks after pressing the button, so pressing at time `t` works when
+    /// `t ≡ -(start + i + 1) (mod positions)` for every disc.
     fn time_to_press(&self) -> Option<usize> {
-        (0..usize::MAX)
-            .into_par_iter()
-            .by_exponential_blocks()
-            .find_first(|&n| {
-                self.discs
-                    .iter()
-                    .enumerate()
-                    .all(|(i, d)| d.after(n + i + 1) == 0)
-            })
+        let congruences = self.discs.iter().enumerate().map(|(i, d)| {
+            let positions = d.num_positions as u64;
+            let offset = (d.current_position + i + 1) as u64 % positions;
+            ((positions - offset) % positions, positions)
+        });
+
+        let (time, _) = math::crt(congruences)?;
+        usize::try_from(time).ok()
     }
 }
 
@@ -41,28 +44,7 @@ impl FromStr for Sculpture {
     type Err = Report;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let re = Regex::new(r"Disc #\d+ has (\d+) positions; at time=0, it is at position (\d+).")?;
-
-        s.lines()
-            .enumerate()
-            .map(|(i, line)| {
-                let caps = re
-                    .captures(line)
-                    .ok_or_else(|| eyre!("line {} didn't match regex", i + 1))?;
-                let num_positions = caps
-                    .get(1)
-                    .ok_or_else(|| eyre!("no match for # of positions in line {}", i + 1))?
-                    .as_str()
-                    .parse()?;
-                let start = caps
-                    .get(2)
-                    .ok_or_else(|| eyre!("no match for start location in line {}", i + 1))?
-                    .as_str()
-                    .parse()?;
-
-                Ok(Disc::new(start, num_positions))
-            })
-            .collect()
+        parse::lines(s, disc).collect()
     }
 }
 
@@ -87,10 +69,16 @@ impl Disc {
             num_positions,
         }
     }
+}
 
-    fn after(&self, ticks: usize) -> usize {
-        (self.current_position + ticks) % self.num_positions
-    }
+fn disc(input: &mut &str) -> ModalResult<Disc> {
+    (
+        preceded("Disc #", unsigned::<usize>),
+        delimited(" has ", unsigned, " positions;"),
+        delimited(" at time=0, it is at position ", unsigned, '.'),
+    )
+        .map(|(_, num_positions, start)| Disc::new(start, num_positions))
+        .parse_next(input)
 }
 
 #[test]
@@ -1,79 +1,86 @@
-use std::{collections::VecDeque, iter::FusedIterator, ops};
+use std::ops;
 
 use eyre::{OptionExt, Result};
-use md5::{Digest, Md5};
 
 use aoc_common::grid::Coordinate;
+use aoc_common::md5search::Prefix;
+use aoc_common::search::{self, SearchSpace};
 use aoc_meta::Problem;
 
 pub const TWO_STEPS_FORWARD: Problem = Problem::solved(&shortest_path, &longest_path_length);
 
 fn shortest_path(passcode: &str) -> Result<String> {
-    bfs(passcode).next().ok_or_eyre("no path to exit!")
+    let vault = Vault::new(passcode);
+    search::tree_bfs(&vault, Vault::START)
+        .find_map(|((coord, steps), _)| (coord == Vault::TARGET).then_some(steps))
+        .ok_or_eyre("no path to exit!")
 }
 
 fn longest_path_length(passcode: &str) -> Result<usize> {
-    bfs(passcode)
-        .map(|s| s.len())
+    let vault = Vault::new(passcode);
+    search::tree_bfs(&vault, Vault::START)
+        .filter(|((coord, _), _)| *coord == Vault::TARGET)
+        .map(|(_, depth)| depth)
         .max()
         .ok_or_eyre("no path to exit")
 }
 
-fn bfs(passcode: &str) -> Bfs {
-    Bfs::new(passcode)
+/// The vault's rooms, where each state is the current room and the path taken to get there. Since the doors
+/// depend on the path, no state is ever visited twice, so there's no need to remember which have been.
+struct Vault {
+    passcode: Prefix,
 }
 
-struct Bfs {
-    hasher: Md5,
-    queue: VecDeque<(Coordinate, String)>,
-}
-
-impl Bfs {
+impl Vault {
+    const START: (Coordinate, String) = (Coordinate { x: 0, y: 0 }, String::new());
     const TARGET: Coordinate = Coordinate { x: 3, y: 3 };
 
     fn new(passcode: &str) -> Self {
-        let hasher = Md5::new_with_prefix(passcode);
-        let mut queue = VecDeque::new();
-        queue.push_back((Coordinate { x: 0, y: 0 }, String::new()));
-
-        Self { hasher, queue }
+        Self {
+            passcode: Prefix::new(passcode),
+        }
     }
 }
 
-impl Iterator for Bfs {
-    type Item = String;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        while let Some((coord, steps)) = self.queue.pop_front() {
-            if coord == Self::TARGET {
-                return Some(steps);
-            }
-
-            let hash = self.hasher.clone().chain_update(&steps).finalize();
-            let up_open = hash[0] >> 4 > 0xA;
-            let down_open = hash[0] & 0xF > 0xA;
-            let left_open = hash[1] >> 4 > 0xA;
-            let right_open = hash[1] & 0xF > 0xA;
-
-            let next = [
-                (up_open && coord.y > 0).then_some(Move::Up),
-                (down_open && coord.y < 3).then_some(Move::Down),
-                (left_open && coord.x > 0).then_some(Move::Left),
-                (right_open && coord.x < 3).then_some(Move::Right),
-            ]
-            .into_iter()
-            .flatten()
-            .map(|mov| (coord + mov, steps.clone() + mov));
-
-            self.queue.extend(next);
-        }
+impl SearchSpace for Vault {
+    type State = (Coordinate, String);
+    type Cost = usize;
+
+    fn successors(
+        &self,
+        (coord, steps): &(Coordinate, String),
+    ) -> impl IntoIterator<Item = ((Coordinate, String), usize)> {
+        let coord = *coord;
+
+        // The vault door opens as soon as we reach it, so there's no going any further.
+        let (up_open, down_open, left_open, right_open) = if coord == Self::TARGET {
+            (false, false, false, false)
+        } else {
+            let hash = self.passcode.digest(steps);
+            (
+                hash[0] >> 4 > 0xA,
+                hash[0] & 0xF > 0xA,
+                hash[1] >> 4 > 0xA,
+                hash[1] & 0xF > 0xA,
+            )
+        };
+
+        [
+            (up_open && coord.y > 0).then_some(Move::Up),
+            (down_open && coord.y < 3).then_some(Move::Down),
+            (left_open && coord.x > 0).then_some(Move::Left),
+            (right_open && coord.x < 3).then_some(Move::Right),
+        ]
+        .into_iter()
+        .flatten()
+        .map(move |mov| ((coord + mov, steps.clone() + mov), 1))
+    }
 
-        None
+    fn is_goal(&self, (coord, _): &(Coordinate, String)) -> bool {
+        *coord == Self::TARGET
     }
 }
 
-impl FusedIterator for Bfs {}
-
 #[derive(Debug, Clone, Copy)]
 enum Move {
     Up,
@@ -116,3 +123,14 @@ impl ops::Add<Move> for String {
         self
     }
 }
+
+#[test]
+fn example() {
+    assert_eq!(shortest_path("ihgpwlah").unwrap(), "DDRRRD");
+    assert_eq!(longest_path_length("ihgpwlah").unwrap(), 370);
+    assert_eq!(
+        shortest_path("ulqzkmiv").unwrap(),
+        "DRURDRUDDLLDLUURRDULRLDUUDDDRR"
+    );
+    assert_eq!(longest_path_length("ulqzkmiv").unwrap(), 830);
+}
@@ -1,23 +1,24 @@
-use std::str::FromStr;
-
-use aoc_meta::Problem;
 use eyre::{OptionExt, Result, bail};
 use itertools::Itertools;
 use rayon::prelude::*;
+use winnow::{
+    ascii::space1,
+    combinator::{delimited, preceded, terminated},
+    prelude::*,
+};
 
-pub const GRID_COMPUTING: Problem = Problem::partially_solved(&viable_pairs);
-
-fn viable_pairs(input: &str) -> Result<usize> {
-    let (_, after) = input
-        .split_once("Use%\n")
-        .ok_or_eyre("couldn't find \"Use%\\n\" in input")?;
+use aoc_common::grid::Coordinate;
+use aoc_common::parse::{self, unsigned};
+use aoc_common::search::{self, SearchSpace};
+use aoc_meta::Problem;
 
-    let nodes = after
-        .par_lines()
-        .map(PositionedNode::from_str)
-        .collect::<Result<Vec<_>>>()?;
+pub const GRID_COMPUTING: Problem = Problem::solved(
+    &|input| parse_nodes(input).map(|nodes| viable_pairs(&nodes)),
+    &|input| Cluster::new(&parse_nodes(input)?)?.fewest_moves(),
+);
 
-    let n = nodes
+fn viable_pairs(nodes: &[PositionedNode]) -> usize {
+    nodes
         .par_iter()
         .copied()
         .filter(|a| a.used > 0)
@@ -27,54 +28,158 @@ fn viable_pairs(input: &str) -> Result<usize> {
                 .copied()
                 .filter(move |&b| !(a.x == b.x && a.y == b.y) && a.used <= b.available)
         })
-        .count();
-
-    Ok(n)
+        .count()
 }
 
-#[derive(Debug, Clone, Copy)]
-struct PositionedNode {
-    x: u8,
-    y: u8,
-    used: u8,
-    available: u8,
+/// The cluster as a sliding puzzle: the only way to move data is into the empty node, and some nodes hold more data
+/// than it could ever take, so they act as walls.
+#[derive(Debug)]
+struct Cluster {
+    width: usize,
+    height: usize,
+    walls: Vec<bool>,
+    empty: Coordinate,
 }
 
-impl FromStr for PositionedNode {
-    type Err = eyre::Report;
-
-    fn from_str(line: &str) -> Result<PositionedNode> {
-        const LINE_PREFIX: &str = "/dev/grid/node-";
+impl Cluster {
+    fn new(nodes: &[PositionedNode]) -> Result<Self> {
+        let width = nodes.iter().map(|node| node.x + 1).max().unwrap_or(0);
+        let height = nodes.iter().map(|node| node.y + 1).max().unwrap_or(0);
+        if nodes.len() != width * height {
+            bail!("{} nodes don't make a {width}x{height} grid", nodes.len());
+        }
 
-        let Some((before, after)) = line.split_at_checked(LINE_PREFIX.len()) else {
-            bail!("line \"{line}\" was too short or missing prefix \"{LINE_PREFIX}\"")
+        let Ok(empty) = nodes.iter().filter(|node| node.used == 0).exactly_one() else {
+            bail!("expected exactly one empty node");
         };
 
-        if before != LINE_PREFIX {
-            bail!("unexpected characters at start of line \"{line}\" (expected \"{LINE_PREFIX}\"")
+        let mut walls = vec![true; width * height];
+        for node in nodes {
+            walls[node.y * width + node.x] = node.used > empty.size;
         }
 
-        let Some([coords, _size, used, available, _use_percent]) =
-            after.split_ascii_whitespace().collect_array()
-        else {
-            bail!("failed to split line \"{line}\" into components")
-        };
+        Ok(Self {
+            width,
+            height,
+            walls,
+            empty: Coordinate {
+                x: empty.x,
+                y: empty.y,
+            },
+        })
+    }
 
-        let Some((x, y)) = coords.split_once("-") else {
-            bail!("failed to split {coords} into x and y");
+    /// The fewest moves to bring the data in the top-right node to the top-left one.
+    fn fewest_moves(&self) -> Result<usize> {
+        let start = Puzzle {
+            empty: self.empty,
+            goal: Coordinate {
+                x: self.width - 1,
+                y: 0,
+            },
         };
 
-        Ok(PositionedNode {
-            x: x["x".len()..].parse()?,
-            y: y["y".len()..].parse()?,
-            used: used[..used.len() - "T".len()]
-                .parse::<u16>()?
-                .try_into()
-                .unwrap_or(u8::MAX),
-            available: available[..available.len() - "T".len()]
-                .parse::<u16>()?
-                .try_into()
-                .unwrap_or(u8::MAX),
-        })
+        search::bfs(self, start)
+            .next_goal()
+            .map(|path| path.cost)
+            .ok_or_eyre("the goal data can't be moved to the top-left node")
+    }
+
+    fn is_open(&self, Coordinate { x, y }: Coordinate) -> bool {
+        x < self.width && y < self.height && !self.walls[y * self.width + x]
     }
 }
+
+/// Where the empty node and the goal data are. Nothing else about the cluster changes as data moves around.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Puzzle {
+    empty: Coordinate,
+    goal: Coordinate,
+}
+
+impl SearchSpace for Cluster {
+    type State = Puzzle;
+    type Cost = usize;
+
+    fn successors(&self, puzzle: &Puzzle) -> impl IntoIterator<Item = (Puzzle, usize)> {
+        let Puzzle { empty, goal } = *puzzle;
+
+        empty
+            .cardinal_neighbors()
+            .filter(|&neighbor| self.is_open(neighbor))
+            .map(move |neighbor| {
+                // Moving the neighbor's data into the empty node leaves the neighbor empty.
+                let goal = if neighbor == goal { empty } else { goal };
+                let puzzle = Puzzle {
+                    empty: neighbor,
+                    goal,
+                };
+
+                (puzzle, 1)
+            })
+    }
+
+    fn is_goal(&self, puzzle: &Puzzle) -> bool {
+        puzzle.goal == Coordinate { x: 0, y: 0 }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct PositionedNode {
+    x: usize,
+    y: usize,
+    size: u16,
+    used: u16,
+    available: u16,
+}
+
+/// Parse the nodes listed by `df`, after its first two lines (the prompt and the column headings).
+fn parse_nodes(input: &str) -> Result<Vec<PositionedNode>> {
+    // Skipping the parsed lines rather than the text keeps the line numbers in errors right.
+    parse::lines(input, node).skip(2).collect()
+}
+
+fn node(input: &mut &str) -> ModalResult<PositionedNode> {
+    let terabytes = || preceded(space1, terminated(unsigned, 'T'));
+
+    let (x, y, size, used, available, _) = (
+        preceded("/dev/grid/node-x", unsigned),
+        preceded("-y", unsigned),
+        terabytes(),
+        terabytes(),
+        terabytes(),
+        delimited(space1, unsigned::<u8>, '%'),
+    )
+        .parse_next(input)?;
+
+    Ok(PositionedNode {
+        x,
+        y,
+        size,
+        used,
+        available,
+    })
+}
+
+#[test]
+fn example() {
+    let input = "root@ebhq-gridcenter# df -h
+Filesystem            Size  Used  Avail  Use%
+/dev/grid/node-x0-y0   10T    8T     2T   80%
+/dev/grid/node-x0-y1   11T    6T     5T   54%
+/dev/grid/node-x0-y2   32T   28T     4T   87%
+/dev/grid/node-x1-y0    9T    7T     2T   77%
+/dev/grid/node-x1-y1    8T    0T     8T    0%
+/dev/grid/node-x1-y2   11T    7T     4T   63%
+/dev/grid/node-x2-y0   10T    6T     4T   60%
+/dev/grid/node-x2-y1    9T    8T     1T   88%
+/dev/grid/node-x2-y2    9T    6T     3T   66%
+";
+
+    let nodes = parse_nodes(input).unwrap();
+    assert_eq!(viable_pairs(&nodes), 7);
+    assert_eq!(Cluster::new(&nodes).unwrap().fewest_moves().unwrap(), 7);
+
+    let garbled = input.replace("/dev/grid/node-x2-y1", "/dev/grid/node-x2");
+    assert!(parse_nodes(&garbled).is_err());
+}
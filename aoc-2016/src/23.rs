@@ -0,0 +1,16 @@
+use eyre::Report;
+
+use aoc_meta::Problem;
+
+use crate::assembunny::{Computer, Register};
+
+pub const SAFE_CRACKING: Problem =
+    Problem::solved(&|input| crack(input, 7), &|input| crack(input, 12));
+
+fn crack(input: &str, eggs: isize) -> Result<isize, Report> {
+    let mut computer = Computer::parse(input)?.with(Register::A, eggs);
+
+    computer.execute();
+
+    Ok(computer[Register::A])
+}
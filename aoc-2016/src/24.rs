@@ -0,0 +1,141 @@
+use eyre::{OptionExt, Result, bail};
+use itertools::Itertools;
+
+use aoc_common::grid::Coordinate;
+use aoc_common::parse;
+use aoc_common::search::{self, SearchSpace};
+use aoc_common::tsp::Tsp;
+use aoc_meta::Problem;
+
+pub const AIR_DUCT_SPELUNKING: Problem =
+    Problem::solved(&|input| fewest_steps(input, false), &|input| {
+        fewest_steps(input, true)
+    });
+
+fn fewest_steps(input: &str, return_home: bool) -> Result<usize> {
+    let ducts = Ducts::new(parse::complete(input.trim_end(), parse::grid(Tile::new))?)?;
+    let tsp = ducts.distances();
+
+    if return_home {
+        tsp.shortest_cycle()
+    } else {
+        tsp.shortest_path(Some(0))
+    }
+    .map(|tour| tour.weight)
+    .ok_or_eyre("some of the numbered locations can't be reached")
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    Wall,
+    Open,
+    Location(u8),
+}
+
+impl Tile {
+    fn new(c: char) -> Option<Self> {
+        match c {
+            '#' => Some(Tile::Wall),
+            '.' => Some(Tile::Open),
+            _ => c.to_digit(10).map(|digit| Tile::Location(digit as u8)),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Ducts {
+    tiles: Vec<Vec<Tile>>,
+    /// Where each numbered location is.
+    locations: Vec<Coordinate>,
+}
+
+impl Ducts {
+    fn new(tiles: Vec<Vec<Tile>>) -> Result<Self> {
+        let locations = tiles
+            .iter()
+            .enumerate()
+            .flat_map(|(y, row)| {
+                row.iter()
+                    .enumerate()
+                    .filter_map(move |(x, &tile)| match tile {
+                        Tile::Location(n) => Some((n, Coordinate { x, y })),
+                        _ => None,
+                    })
+            })
+            .sorted_unstable()
+            .collect_vec();
+
+        if locations.is_empty() {
+            bail!("there's no location 0 to start from");
+        }
+
+        // Locations are indexed by their numbers, so they can't have any gaps.
+        if let Some((idx, &(n, _))) = locations
+            .iter()
+            .enumerate()
+            .find(|&(idx, &(n, _))| usize::from(n) != idx)
+        {
+            bail!("expected location {idx}, found location {n}");
+        }
+
+        Ok(Self {
+            tiles,
+            locations: locations.into_iter().map(|(_, coord)| coord).collect(),
+        })
+    }
+
+    /// The distance between every pair of numbered locations, found with a breadth-first search from each of them.
+    fn distances(&self) -> Tsp<usize> {
+        let rows = self
+            .locations
+            .iter()
+            .map(|&start| {
+                let mut distances = vec![None; self.locations.len()];
+
+                for (coord, steps) in search::bfs(self, start) {
+                    if let Tile::Location(n) = self.tiles[coord.y][coord.x] {
+                        distances[usize::from(n)] = Some(steps);
+                    }
+                }
+
+                distances
+            })
+            .collect_vec();
+
+        Tsp::from_fn(self.locations.len(), |a, b| rows[a][b])
+    }
+}
+
+impl SearchSpace for Ducts {
+    type State = Coordinate;
+    type Cost = usize;
+
+    fn successors(&self, coord: &Coordinate) -> impl IntoIterator<Item = (Coordinate, usize)> {
+        coord
+            .cardinal_neighbors()
+            .filter(|neighbor| {
+                self.tiles
+                    .get(neighbor.y)
+                    .and_then(|row| row.get(neighbor.x))
+                    .is_some_and(|&tile| tile != Tile::Wall)
+            })
+            .map(|neighbor| (neighbor, 1))
+    }
+
+    fn is_goal(&self, _: &Coordinate) -> bool {
+        false
+    }
+}
+
+#[test]
+fn example() {
+    let input = "###########
+#0.1.....2#
+#.#######.#
+#4.......3#
+###########
+";
+
+    assert_eq!(fewest_steps(input, false).unwrap(), 14);
+    assert_eq!(fewest_steps(input, true).unwrap(), 20);
+}
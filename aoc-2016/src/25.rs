@@ -0,0 +1,42 @@
+use eyre::{OptionExt, Result};
+use fnv::FnvHashSet;
+
+use aoc_meta::Problem;
+
+use crate::assembunny::{Computer, Register};
+
+pub const CLOCK_SIGNAL: Problem = Problem::partially_solved(&lowest_clock_seed);
+
+fn lowest_clock_seed(input: &str) -> Result<isize> {
+    const MAX_SEED: isize = 1 << 16;
+
+    let computer = Computer::parse(input)?;
+
+    (0..MAX_SEED)
+        .find(|&a| is_clock_signal(computer.clone().with(Register::A, a)))
+        .ok_or_eyre(format!(
+            "no initial value of a below {MAX_SEED} makes a clock signal"
+        ))
+}
+
+/// Whether `computer` outputs `0, 1, 0, 1, ...` forever, which it does if it gets back to a state it was in
+/// after an earlier output without getting the pattern wrong.
+fn is_clock_signal(mut computer: Computer) -> bool {
+    /// Programs that are still going after this many outputs without repeating themselves are assumed not to.
+    const MAX_OUTPUTS: usize = 10_000;
+
+    let mut seen = FnvHashSet::default();
+
+    for expected in [0, 1].into_iter().cycle().take(MAX_OUTPUTS) {
+        if computer.next_output() != Some(expected) {
+            return false;
+        }
+
+        // The state after a `1` includes what has to come next, so it only needs recording there.
+        if expected == 1 && !seen.insert(computer.clone()) {
+            return true;
+        }
+    }
+
+    false
+}
@@ -0,0 +1,402 @@
+//! The assembunny computer shared by days 12, 23 and 25.
+
+use std::fmt::{self, Display, Formatter};
+use std::ops;
+
+use either::Either;
+use eyre::Report;
+use winnow::{
+    ascii::alpha1,
+    combinator::{alt, dispatch, empty, fail, separated_pair, terminated},
+    prelude::*,
+    token::any,
+};
+
+use aoc_common::parse::{self, signed};
+use aoc_common::trace;
+
+/// A register or an immediate value.
+pub type Operand = Either<Register, isize>;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Computer {
+    instruction_pointer: usize,
+    registers: [isize; 4],
+    /// `tgl` rewrites the program as it runs, so each computer has its own copy.
+    program: Vec<Instruction>,
+}
+
+impl ops::Index<Register> for Computer {
+    type Output = isize;
+
+    #[inline]
+    fn index(&self, index: Register) -> &Self::Output {
+        &self.registers[index as usize]
+    }
+}
+
+impl ops::IndexMut<Register> for Computer {
+    #[inline]
+    fn index_mut(&mut self, index: Register) -> &mut Self::Output {
+        &mut self.registers[index as usize]
+    }
+}
+
+/// What happened when the computer took a step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Step {
+    Continue,
+    Output(isize),
+    Halt,
+}
+
+impl Computer {
+    pub fn parse(input: &str) -> Result<Self, Report> {
+        Ok(Self {
+            program: parse::lines(input, instruction).collect::<Result<_, _>>()?,
+            ..Default::default()
+        })
+    }
+
+    /// Set `register` to `value` before the program starts.
+    #[inline]
+    pub fn with(mut self, register: Register, value: isize) -> Self {
+        self[register] = value;
+        self
+    }
+
+    /// Run the program until it halts, ignoring anything it outputs.
+    pub fn execute(&mut self) {
+        while self.step() != Step::Halt {}
+    }
+
+    /// Run the program until it outputs a value, or return `None` if it halts first.
+    pub fn next_output(&mut self) -> Option<isize> {
+        loop {
+            match self.step() {
+                Step::Continue => {}
+                Step::Output(value) => return Some(value),
+                Step::Halt => return None,
+            }
+        }
+    }
+
+    /// Execute the instruction at the instruction pointer, or a whole loop starting there if it's one that
+    /// [`Computer::fused_loop`] recognizes. Fused loops show up in traces as a single step.
+    pub fn step(&mut self) -> Step {
+        let ip = self.instruction_pointer;
+        let Some(&instruction) = self.program.get(ip) else {
+            return Step::Halt;
+        };
+
+        let fused = self.fused_loop();
+        let step = if fused {
+            Step::Continue
+        } else {
+            self.execute_one(instruction)
+        };
+
+        if trace::enabled() {
+            let registers = [
+                ("a", self[Register::A] as i64),
+                ("b", self[Register::B] as i64),
+                ("c", self[Register::C] as i64),
+                ("d", self[Register::D] as i64),
+            ];
+
+            if fused {
+                trace::record(ip, &format_args!("{instruction} (fused loop)"), &registers);
+            } else {
+                trace::record(ip, &instruction, &registers);
+            }
+        }
+
+        step
+    }
+
+    fn execute_one(&mut self, instruction: Instruction) -> Step {
+        let mut output = None;
+
+        match instruction {
+            Instruction::Copy {
+                source,
+                target: Either::Left(target),
+            } => self[target] = self.value(source),
+            Instruction::Increment {
+                target: Either::Left(target),
+            } => self[target] += 1,
+            Instruction::Decrement {
+                target: Either::Left(target),
+            } => self[target] -= 1,
+            Instruction::JumpIfNotZero {
+                condition,
+                distance,
+            } if self.value(condition) != 0 => {
+                let distance = self.value(distance);
+
+                // Jumping to before the start of the program halts it, just like jumping past the end.
+                self.instruction_pointer = self
+                    .instruction_pointer
+                    .checked_add_signed(distance)
+                    .unwrap_or(usize::MAX);
+
+                return Step::Continue;
+            }
+            Instruction::Toggle { distance } => {
+                let target = self
+                    .instruction_pointer
+                    .checked_add_signed(self.value(distance));
+
+                if let Some(target) = target.and_then(|target| self.program.get_mut(target)) {
+                    *target = target.toggled();
+                }
+            }
+            Instruction::Out { value } => output = Some(self.value(value)),
+            // Anything else is an instruction that `tgl` made invalid, like `inc 1`, which is skipped.
+            _ => {}
+        }
+
+        self.instruction_pointer += 1;
+
+        output.map_or(Step::Continue, Step::Output)
+    }
+
+    #[inline]
+    fn value(&self, operand: Operand) -> isize {
+        operand.either(|register| self[register], |value| value)
+    }
+
+    /// Recognize a loop that adds or multiplies starting at the instruction pointer and run all of it at once,
+    /// returning whether there was one.
+    ///
+    /// This looks at the program as it is now, so loops that `tgl` breaks or creates are handled correctly. Loops are
+    /// only fused when their counters are positive, since otherwise they'd count down past zero and wrap around.
+    fn fused_loop(&mut self) -> bool {
+        let rest = &self.program[self.instruction_pointer..];
+
+        // cpy x inner; (inc target; dec inner; jnz inner -2); dec outer; jnz outer -5
+        if let [
+            Instruction::Copy {
+                source,
+                target: Either::Left(inner),
+            },
+            a,
+            b,
+            c,
+            Instruction::Decrement {
+                target: Either::Left(outer),
+            },
+            Instruction::JumpIfNotZero {
+                condition: Either::Left(outer_condition),
+                distance: Either::Right(-5),
+            },
+            ..,
+        ] = *rest
+            && let Some((target, counter)) = add_loop([a, b, c])
+            && counter == inner
+            && outer_condition == outer
+            && inner != outer
+            && ![inner, outer].contains(&target)
+            && source
+                .left()
+                .is_none_or(|source| ![inner, outer, target].contains(&source))
+            && self.value(source) > 0
+            && self[outer] > 0
+        {
+            self[target] += self.value(source) * self[outer];
+            self[inner] = 0;
+            self[outer] = 0;
+            self.instruction_pointer += 6;
+
+            return true;
+        }
+
+        // inc target; dec counter; jnz counter -2
+        if let [a, b, c, ..] = *rest
+            && let Some((target, counter)) = add_loop([a, b, c])
+            && self[counter] > 0
+        {
+            self[target] += self[counter];
+            self[counter] = 0;
+            self.instruction_pointer += 3;
+
+            return true;
+        }
+
+        false
+    }
+}
+
+/// If `instructions` is a loop that adds one register to another, like `inc a; dec b; jnz b -2`, return the
+/// register that's added to and the register that's counted down.
+fn add_loop(instructions: [Instruction; 3]) -> Option<(Register, Register)> {
+    let [first, second, jump] = instructions;
+
+    let (target, counter) = match (first, second) {
+        (
+            Instruction::Increment {
+                target: Either::Left(target),
+            },
+            Instruction::Decrement {
+                target: Either::Left(counter),
+            },
+        )
+        | (
+            Instruction::Decrement {
+                target: Either::Left(counter),
+            },
+            Instruction::Increment {
+                target: Either::Left(target),
+            },
+        ) => (target, counter),
+        _ => return None,
+    };
+
+    let Instruction::JumpIfNotZero {
+        condition: Either::Left(condition),
+        distance: Either::Right(-2),
+    } = jump
+    else {
+        return None;
+    };
+
+    (condition == counter && target != counter).then_some((target, counter))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Register {
+    A,
+    B,
+    C,
+    D,
+}
+
+impl Display for Register {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Register::A => f.write_str("a"),
+            Register::B => f.write_str("b"),
+            Register::C => f.write_str("c"),
+            Register::D => f.write_str("d"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Instruction {
+    Copy {
+        source: Operand,
+        target: Operand,
+    },
+    Increment {
+        target: Operand,
+    },
+    Decrement {
+        target: Operand,
+    },
+    JumpIfNotZero {
+        condition: Operand,
+        distance: Operand,
+    },
+    Toggle {
+        distance: Operand,
+    },
+    Out {
+        value: Operand,
+    },
+}
+
+impl Instruction {
+    /// The instruction that `tgl` turns this one into.
+    fn toggled(self) -> Self {
+        match self {
+            Instruction::Increment { target } => Instruction::Decrement { target },
+            Instruction::Decrement { target: operand }
+            | Instruction::Toggle { distance: operand }
+            | Instruction::Out { value: operand } => Instruction::Increment { target: operand },
+            Instruction::JumpIfNotZero {
+                condition,
+                distance,
+            } => Instruction::Copy {
+                source: condition,
+                target: distance,
+            },
+            Instruction::Copy { source, target } => Instruction::JumpIfNotZero {
+                condition: source,
+                distance: target,
+            },
+        }
+    }
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Instruction::Copy { source, target } => write!(f, "cpy {source} {target}"),
+            Instruction::Increment { target } => write!(f, "inc {target}"),
+            Instruction::Decrement { target } => write!(f, "dec {target}"),
+            Instruction::JumpIfNotZero {
+                condition,
+                distance,
+            } => write!(f, "jnz {condition} {distance}"),
+            Instruction::Toggle { distance } => write!(f, "tgl {distance}"),
+            Instruction::Out { value } => write!(f, "out {value}"),
+        }
+    }
+}
+
+fn register(input: &mut &str) -> ModalResult<Register> {
+    dispatch! {any;
+      'a' => empty.value(Register::A),
+      'b' => empty.value(Register::B),
+      'c' => empty.value(Register::C),
+      'd' => empty.value(Register::D),
+      _ => fail
+    }
+    .parse_next(input)
+}
+
+fn operand(input: &mut &str) -> ModalResult<Operand> {
+    alt((register.map(Either::Left), signed.map(Either::Right))).parse_next(input)
+}
+
+fn instruction(input: &mut &str) -> ModalResult<Instruction> {
+    dispatch!{terminated(alpha1, ' ');
+        "cpy" => separated_pair(operand, ' ', register).map(|(source, target)| Instruction::Copy { source, target: Either::Left(target) }),
+        "inc" => register.map(|target| Instruction::Increment { target: Either::Left(target) }),
+        "dec" => register.map(|target| Instruction::Decrement { target: Either::Left(target) }),
+        "jnz" => separated_pair(operand, ' ', operand).map(|(condition, distance)| Instruction::JumpIfNotZero { condition, distance }),
+        "tgl" => operand.map(|distance| Instruction::Toggle { distance }),
+        "out" => operand.map(|value| Instruction::Out { value }),
+        _ => fail,
+    }.parse_next(input)
+}
+
+#[test]
+fn examples() {
+    let mut computer = Computer::parse("cpy 41 a\ninc a\ninc a\ndec a\njnz a 2\ndec a").unwrap();
+    computer.execute();
+    assert_eq!(computer[Register::A], 42);
+
+    let mut computer =
+        Computer::parse("cpy 2 a\ntgl a\ntgl a\ntgl a\ncpy 1 a\ndec a\ndec a").unwrap();
+    computer.execute();
+    assert_eq!(computer[Register::A], 3);
+}
+
+#[test]
+fn fused_loops() {
+    // a = b * d + c, then output a
+    let program = "cpy 6 b\ncpy 7 d\ncpy 0 a\ncpy b c\ninc a\ndec c\njnz c -2\ndec d\njnz d -5\ncpy 3 c\ndec c\ninc a\njnz c -2\nout a";
+    let mut computer = Computer::parse(program).unwrap();
+    assert_eq!(computer.next_output(), Some(45));
+    assert_eq!(computer.next_output(), None);
+    assert_eq!(computer.registers, [45, 6, 0, 0]);
+
+    // Toggling the outer jump turns it into an invalid `cpy`, so the loop only runs once.
+    let program = "cpy 6 a\ncpy 4 d\ntgl a\ncpy 2 c\ninc b\ndec c\njnz c -2\ndec d\njnz d -5";
+    let mut computer = Computer::parse(program).unwrap();
+    computer.execute();
+    assert_eq!(computer[Register::B], 2);
+    assert_eq!(computer[Register::D], 3);
+}
@@ -1,5 +1,7 @@
 use aoc_meta::PROBLEMS;
 
+mod assembunny;
+
 PROBLEMS! {
     01 => NO_TIME_FOR_A_TAXICAB,
     02 => BATHROOM_SECURITY,
@@ -23,4 +25,7 @@ PROBLEMS! {
     20 => FIREWALL_RULES,
     21 => SCRAMBLED_LETTERS_AND_HASH,
     22 => GRID_COMPUTING,
+    23 => SAFE_CRACKING,
+    24 => AIR_DUCT_SPELUNKING,
+    25 => CLOCK_SIGNAL,
 }
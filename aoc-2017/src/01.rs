@@ -0,0 +1,44 @@
+use eyre::{Result, bail};
+
+use aoc_meta::Problem;
+
+pub const INVERSE_CAPTCHA: Problem = Problem::solved(&|input| captcha(input, 1), &|input| {
+    captcha(input, input.len() / 2)
+});
+
+/// The sum of every digit that matches the one `offset` places further along the (circular) list.
+fn captcha(input: &str, offset: usize) -> Result<u32> {
+    let digits = input
+        .bytes()
+        .map(|b| match b {
+            b'0'..=b'9' => Ok(u32::from(b - b'0')),
+            _ => bail!("'{}' isn't a digit", char::from(b)),
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(digits
+        .iter()
+        .zip(digits.iter().cycle().skip(offset))
+        .filter(|(a, b)| a == b)
+        .map(|(a, _)| a)
+        .sum())
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    for (input, expected) in [("1122", 3), ("1111", 4), ("1234", 0), ("91212129", 9)] {
+        assert_eq!(captcha(input, 1).unwrap(), expected);
+    }
+
+    for (input, expected) in [
+        ("1212", 6),
+        ("1221", 0),
+        ("123425", 4),
+        ("123123", 12),
+        ("12131415", 4),
+    ] {
+        assert_eq!(captcha(input, input.len() / 2).unwrap(), expected);
+    }
+}
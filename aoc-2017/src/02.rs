@@ -0,0 +1,49 @@
+use eyre::{OptionExt, Result};
+use itertools::Itertools;
+
+use aoc_meta::Problem;
+
+pub const CORRUPTION_CHECKSUM: Problem =
+    Problem::solved(&|input| checksum(input, difference), &|input| {
+        checksum(input, evenly_divisible)
+    });
+
+/// The sum of `row_checksum` over every row of the spreadsheet.
+fn checksum(input: &str, row_checksum: impl Fn(&[u32]) -> Option<u32>) -> Result<u32> {
+    input.lines().try_fold(0, |sum, line| {
+        let row = line
+            .split_whitespace()
+            .map(str::parse)
+            .collect::<Result<Vec<u32>, _>>()?;
+
+        Ok(sum + row_checksum(&row).ok_or_eyre("a row has no checksum")?)
+    })
+}
+
+/// The difference between the largest and smallest numbers in `row`.
+fn difference(row: &[u32]) -> Option<u32> {
+    row.iter()
+        .minmax()
+        .into_option()
+        .map(|(min, max)| max - min)
+}
+
+/// The quotient of the only two numbers in `row` where one evenly divides the other.
+fn evenly_divisible(row: &[u32]) -> Option<u32> {
+    row.iter()
+        .tuple_combinations()
+        .map(|(&a, &b)| (a.max(b), a.min(b)))
+        .find(|&(big, small)| small != 0 && big.is_multiple_of(small))
+        .map(|(big, small)| big / small)
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    assert_eq!(checksum("5 1 9 5\n7 5 3\n2 4 6 8", difference).unwrap(), 18);
+    assert_eq!(
+        checksum("5 9 2 8\n9 4 7 3\n3 8 6 5", evenly_divisible).unwrap(),
+        9
+    );
+}
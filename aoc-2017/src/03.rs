@@ -0,0 +1,70 @@
+use std::iter;
+
+use eyre::{OptionExt, Result};
+use fnv::FnvHashMap;
+
+use aoc_meta::Problem;
+
+pub const SPIRAL_MEMORY: Problem =
+    Problem::solved(&|input| steps_to_access_port(input.parse()?), &|input| {
+        first_stress_test_value_above(input.parse()?)
+    });
+
+/// The positions of the spiral's squares in order, starting from square 1 at the origin and heading right.
+fn spiral() -> impl Iterator<Item = (i64, i64)> {
+    const DIRECTIONS: [(i64, i64); 4] = [(1, 0), (0, 1), (-1, 0), (0, -1)];
+
+    // The sides get one square longer after every second turn: right 1, up 1, left 2, down 2, right 3, ...
+    let moves = (0..).flat_map(|turn: usize| iter::repeat_n(DIRECTIONS[turn % 4], turn / 2 + 1));
+
+    iter::once((0, 0)).chain(moves.scan((0, 0), |(x, y), (dx, dy)| {
+        (*x, *y) = (*x + dx, *y + dy);
+        Some((*x, *y))
+    }))
+}
+
+/// The Manhattan distance from `square` to square 1 in the middle of the spiral.
+fn steps_to_access_port(square: usize) -> Result<u64> {
+    let (x, y) = spiral()
+        .nth(
+            square
+                .checked_sub(1)
+                .ok_or_eyre("the spiral starts at square 1")?,
+        )
+        .ok_or_eyre("the spiral is infinite")?;
+
+    Ok(x.unsigned_abs() + y.unsigned_abs())
+}
+
+/// The first value written in the stress test that's larger than `target`. Each square gets the sum of all its
+/// neighbors that have already been written.
+fn first_stress_test_value_above(target: u64) -> Result<u64> {
+    let mut written = FnvHashMap::from_iter([((0, 0), 1)]);
+
+    spiral()
+        .skip(1)
+        .map(|(x, y)| {
+            let value = (-1..=1)
+                .flat_map(|dy| (-1..=1).map(move |dx| (x + dx, y + dy)))
+                .filter_map(|neighbor| written.get(&neighbor))
+                .sum();
+
+            written.insert((x, y), value);
+            value
+        })
+        .find(|&value| value > target)
+        .ok_or_eyre("the spiral is infinite")
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    for (square, steps) in [(1, 0), (12, 3), (23, 2), (1024, 31)] {
+        assert_eq!(steps_to_access_port(square).unwrap(), steps);
+    }
+
+    assert_eq!(first_stress_test_value_above(1).unwrap(), 2);
+    assert_eq!(first_stress_test_value_above(147).unwrap(), 304);
+    assert_eq!(first_stress_test_value_above(800).unwrap(), 806);
+}
@@ -0,0 +1,48 @@
+use fnv::FnvHashSet;
+
+use aoc_meta::Problem;
+
+pub const HIGH_ENTROPY_PASSPHRASES: Problem = Problem::solved(
+    &|input| count_valid(input, |word| word.to_string()),
+    &|input| count_valid(input, sorted_letters),
+);
+
+/// How many passphrases have no two words with the same `key`.
+fn count_valid(input: &str, key: impl Fn(&str) -> String) -> usize {
+    input
+        .lines()
+        .filter(|passphrase| {
+            let mut seen = FnvHashSet::default();
+            passphrase
+                .split_whitespace()
+                .all(|word| seen.insert(key(word)))
+        })
+        .count()
+}
+
+/// Anagrams of each other all have the same letters once they're sorted.
+fn sorted_letters(word: &str) -> String {
+    let mut letters: Vec<char> = word.chars().collect();
+    letters.sort_unstable();
+    letters.into_iter().collect()
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    assert_eq!(
+        count_valid(
+            "aa bb cc dd ee\naa bb cc dd aa\naa bb cc dd aaa",
+            str::to_string
+        ),
+        2
+    );
+    assert_eq!(
+        count_valid(
+            "abcde fghij\nabcde xyz ecdab\na ab abc abd abf abj\niiii oiii ooii oooi oooo\noiii ioii iioi iiio",
+            sorted_letters
+        ),
+        3
+    );
+}
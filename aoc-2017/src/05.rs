@@ -0,0 +1,50 @@
+use eyre::Result;
+
+use aoc_meta::Problem;
+
+pub const A_MAZE_OF_TWISTY_TRAMPOLINES_ALL_ALIKE: Problem = Problem::solved(
+    &|input| steps_to_exit(input, |offset| offset + 1),
+    &|input| {
+        steps_to_exit(
+            input,
+            |offset| if offset >= 3 { offset - 1 } else { offset + 1 },
+        )
+    },
+);
+
+/// How many jumps it takes to leave the list, changing each offset with `update` after jumping from it.
+fn steps_to_exit(input: &str, update: impl Fn(i32) -> i32) -> Result<usize> {
+    let mut offsets = input
+        .lines()
+        .map(str::parse)
+        .collect::<Result<Vec<i32>, _>>()?;
+    let (mut position, mut steps) = (0, 0);
+
+    while let Some(offset) = usize::try_from(position)
+        .ok()
+        .and_then(|p| offsets.get_mut(p))
+    {
+        position += *offset as isize;
+        *offset = update(*offset);
+        steps += 1;
+    }
+
+    Ok(steps)
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let input = "0\n3\n0\n1\n-3";
+    assert_eq!(steps_to_exit(input, |offset| offset + 1).unwrap(), 5);
+    assert_eq!(
+        steps_to_exit(input, |offset| if offset >= 3 {
+            offset - 1
+        } else {
+            offset + 1
+        })
+        .unwrap(),
+        10
+    );
+}
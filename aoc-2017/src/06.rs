@@ -0,0 +1,58 @@
+use eyre::Result;
+
+use aoc_common::cycle::{self, Cycle};
+use aoc_meta::Problem;
+
+pub const MEMORY_REALLOCATION: Problem = Problem::solved(
+    &|input| reallocation_cycle(input).map(|cycle| cycle.start + cycle.period),
+    &|input| reallocation_cycle(input).map(|cycle| cycle.period),
+);
+
+/// Where the sequence of redistributions starts repeating itself.
+fn reallocation_cycle(input: &str) -> Result<Cycle> {
+    let banks = input
+        .split_whitespace()
+        .map(str::parse)
+        .collect::<Result<Vec<u32>, _>>()?;
+
+    Ok(cycle::brent(banks, |banks| redistribute(banks.clone())))
+}
+
+/// Empty the bank with the most blocks (the first one, on a tie), handing its blocks out one at a time to each of
+/// the banks after it in turn.
+fn redistribute(mut banks: Vec<u32>) -> Vec<u32> {
+    let Some((fullest, _)) = banks
+        .iter()
+        .enumerate()
+        .rev()
+        .max_by_key(|&(_, blocks)| blocks)
+    else {
+        return banks;
+    };
+
+    let blocks = std::mem::take(&mut banks[fullest]) as usize;
+    let len = banks.len();
+
+    for (i, bank) in banks.iter_mut().enumerate() {
+        // The banks just after the fullest one get one more block than the rest once it wraps around.
+        let distance = (i + len - fullest - 1) % len;
+        *bank += (blocks / len + usize::from(distance < blocks % len)) as u32;
+    }
+
+    banks
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    assert_eq!(redistribute(vec![0, 2, 7, 0]), [2, 4, 1, 2]);
+    assert_eq!(redistribute(vec![2, 4, 1, 2]), [3, 1, 2, 3]);
+    assert_eq!(
+        reallocation_cycle("0 2 7 0").unwrap(),
+        Cycle {
+            start: 1,
+            period: 4
+        }
+    );
+}
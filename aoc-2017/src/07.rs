@@ -0,0 +1,154 @@
+use std::str::FromStr;
+
+use eyre::{OptionExt, Report, Result, bail, ensure, eyre};
+use fnv::{FnvHashMap, FnvHashSet};
+use itertools::Itertools;
+use winnow::ascii::alpha1;
+use winnow::combinator::{delimited, opt, preceded, separated};
+use winnow::prelude::*;
+
+use aoc_common::parse::{self, unsigned};
+use aoc_meta::Problem;
+
+pub const RECURSIVE_CIRCUS: Problem = Problem::solved(
+    &|input| {
+        input
+            .parse()
+            .and_then(|tower: Tower| tower.bottom().map(str::to_string))
+    },
+    &|input| {
+        input
+            .parse()
+            .and_then(|tower: Tower| tower.corrected_weight())
+    },
+);
+
+#[derive(Debug)]
+struct Program {
+    weight: u32,
+    above: Vec<String>,
+}
+
+#[derive(Debug)]
+struct Tower(FnvHashMap<String, Program>);
+
+impl Tower {
+    /// The only program that isn't standing on another one.
+    fn bottom(&self) -> Result<&str> {
+        let above: FnvHashSet<&str> = self
+            .0
+            .values()
+            .flat_map(|p| &p.above)
+            .map(String::as_str)
+            .collect();
+
+        self.0
+            .keys()
+            .map(String::as_str)
+            .find(|name| !above.contains(name))
+            .ok_or_eyre("every program is standing on another one")
+    }
+
+    /// The weight the one program with the wrong weight would need to balance the whole tower.
+    fn corrected_weight(&self) -> Result<u32> {
+        match self.balance(self.bottom()?)? {
+            Ok(_) => bail!("the tower is already balanced"),
+            Err(corrected) => Ok(corrected),
+        }
+    }
+
+    /// The total weight of the tower standing on `name`, or the corrected weight of the program that unbalances it.
+    /// Sub-towers are checked first, so the program found is the one highest up, which is the one to blame.
+    fn balance(&self, name: &str) -> Result<Result<u32, u32>> {
+        let program = self
+            .0
+            .get(name)
+            .ok_or_else(|| eyre!("there's no program called {name}"))?;
+        let mut totals = Vec::with_capacity(program.above.len());
+
+        for above in &program.above {
+            match self.balance(above)? {
+                Ok(total) => totals.push((total, above)),
+                corrected => return Ok(corrected),
+            }
+        }
+
+        if !totals.iter().map(|(total, _)| total).all_equal() {
+            ensure!(
+                totals.len() > 2,
+                "can't tell which of the programs on {name} is unbalanced"
+            );
+
+            // Only one sub-tower is off, so the right total is whichever one turns up more than once.
+            let count = |weight| totals.iter().filter(|(total, _)| *total == weight).count();
+            let expected = if count(totals[0].0) > 1 {
+                totals[0].0
+            } else {
+                totals[1].0
+            };
+            let &(odd_total, odd_name) = totals
+                .iter()
+                .find(|(total, _)| *total != expected)
+                .ok_or_eyre("every sub-tower has the same weight")?;
+
+            return (self.0[odd_name].weight + expected)
+                .checked_sub(odd_total)
+                .map(Err)
+                .ok_or_eyre("no weight would balance the tower");
+        }
+
+        Ok(Ok(
+            program.weight + totals.iter().map(|(total, _)| total).sum::<u32>()
+        ))
+    }
+}
+
+fn program(input: &mut &str) -> ModalResult<(String, Program)> {
+    let name = alpha1.map(str::to_string).parse_next(input)?;
+    let weight = delimited(" (", unsigned, ')').parse_next(input)?;
+    let above: Option<Vec<String>> = opt(preceded(
+        " -> ",
+        separated(1.., alpha1.map(str::to_string), ", "),
+    ))
+    .parse_next(input)?;
+
+    Ok((
+        name,
+        Program {
+            weight,
+            above: above.unwrap_or_default(),
+        },
+    ))
+}
+
+impl FromStr for Tower {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        parse::lines(s, program).collect::<Result<_>>().map(Self)
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let tower: Tower = "pbga (66)
+xhth (57)
+ebii (61)
+havc (66)
+ktlj (57)
+fwft (72) -> ktlj, cntj, xhth
+qoyq (66)
+padx (45) -> pbga, havc, qoyq
+tknk (41) -> ugml, padx, fwft
+jptl (61)
+ugml (68) -> gyxo, ebii, jptl
+gyxo (61)
+cntj (57)"
+        .parse()
+        .unwrap();
+
+    assert_eq!(tower.bottom().unwrap(), "tknk");
+    assert_eq!(tower.corrected_weight().unwrap(), 60);
+}
@@ -0,0 +1,64 @@
+use eyre::{Result, bail};
+use fnv::FnvHashMap;
+
+use aoc_meta::Problem;
+
+pub const I_HEARD_YOU_LIKE_REGISTERS: Problem = Problem::solved(
+    &|input| run(input).map(|(registers, _)| registers.into_values().max().unwrap_or(0)),
+    &|input| run(input).map(|(_, highest)| highest),
+);
+
+/// Run every instruction, returning the registers at the end and the highest value any of them ever held.
+fn run(input: &str) -> Result<(FnvHashMap<&str, i64>, i64)> {
+    let mut registers = FnvHashMap::default();
+    let mut highest = 0;
+
+    for line in input.lines() {
+        let [target, operation, amount, "if", source, comparison, value] =
+            line.split(' ').collect::<Vec<_>>()[..]
+        else {
+            bail!("malformed instruction \"{line}\"");
+        };
+
+        let (amount, value): (i64, i64) = (amount.parse()?, value.parse()?);
+        let source = registers.get(source).copied().unwrap_or(0);
+
+        let condition = match comparison {
+            "<" => source < value,
+            "<=" => source <= value,
+            ">" => source > value,
+            ">=" => source >= value,
+            "==" => source == value,
+            "!=" => source != value,
+            _ => bail!("unknown comparison \"{comparison}\""),
+        };
+
+        let amount = match operation {
+            "inc" => amount,
+            "dec" => -amount,
+            _ => bail!("unknown operation \"{operation}\""),
+        };
+
+        if condition {
+            let register = registers.entry(target).or_default();
+            *register += amount;
+            highest = highest.max(*register);
+        }
+    }
+
+    Ok((registers, highest))
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let (registers, highest) = run("b inc 5 if a > 1
+a inc 1 if b < 5
+c dec -10 if a >= 1
+c inc -20 if c == 10")
+    .unwrap();
+
+    assert_eq!(registers.into_values().max(), Some(1));
+    assert_eq!(highest, 10);
+}
@@ -0,0 +1,81 @@
+use eyre::{Result, bail};
+
+use aoc_meta::Problem;
+
+pub const STREAM_PROCESSING: Problem = Problem::solved(
+    &|input| process(input).map(|stream| stream.score),
+    &|input| process(input).map(|stream| stream.garbage),
+);
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct Stream {
+    /// The sum of every group's depth.
+    score: usize,
+    /// How many characters of garbage there are, not counting cancelled ones or the brackets around it.
+    garbage: usize,
+}
+
+fn process(input: &str) -> Result<Stream> {
+    let mut stream = Stream::default();
+    let mut bytes = input.bytes();
+    let (mut depth, mut in_garbage) = (0, false);
+
+    while let Some(b) = bytes.next() {
+        match (in_garbage, b) {
+            (_, b'!') => {
+                bytes.next();
+            }
+            (true, b'>') => in_garbage = false,
+            (true, _) => stream.garbage += 1,
+            (false, b'<') => in_garbage = true,
+            (false, b'{') => {
+                depth += 1;
+                stream.score += depth;
+            }
+            (false, b'}') => {
+                if depth == 0 {
+                    bail!("a group was closed without being opened");
+                }
+
+                depth -= 1;
+            }
+            (false, _) => {}
+        }
+    }
+
+    if depth != 0 || in_garbage {
+        bail!("the stream ended partway through a group");
+    }
+
+    Ok(stream)
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    for (input, score) in [
+        ("{}", 1),
+        ("{{{}}}", 6),
+        ("{{},{}}", 5),
+        ("{{{},{},{{}}}}", 16),
+        ("{<a>,<a>,<a>,<a>}", 1),
+        ("{{<ab>},{<ab>},{<ab>},{<ab>}}", 9),
+        ("{{<!!>},{<!!>},{<!!>},{<!!>}}", 9),
+        ("{{<a!>},{<a!>},{<a!>},{<ab>}}", 3),
+    ] {
+        assert_eq!(process(input).unwrap().score, score);
+    }
+
+    for (input, garbage) in [
+        ("<>", 0),
+        ("<random characters>", 17),
+        ("<<<<>", 3),
+        ("<{!>}>", 2),
+        ("<!!>", 0),
+        ("<!!!>>", 0),
+        ("<{o\"i!a,<{i<a>", 10),
+    ] {
+        assert_eq!(process(input).unwrap().garbage, garbage);
+    }
+}
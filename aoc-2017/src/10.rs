@@ -0,0 +1,31 @@
+use eyre::{Report, Result, ensure};
+
+use crate::knot_hash;
+use aoc_meta::Problem;
+
+pub const KNOT_HASH: Problem = Problem::solved(&|input| first_two_product(input, 256), &|input| {
+    Ok::<_, Report>(knot_hash::hex(input.as_bytes()))
+});
+
+/// The product of the first two marks after one round of knots with the comma-separated `lengths`.
+fn first_two_product(lengths: &str, size: usize) -> Result<u32> {
+    let lengths = lengths
+        .split(',')
+        .map(|length| length.trim().parse())
+        .collect::<Result<Vec<usize>, _>>()?;
+
+    ensure!(
+        lengths.iter().all(|&length| length <= size),
+        "lengths can't be longer than the list"
+    );
+
+    let marks = knot_hash::knot(&lengths, size, 1);
+    Ok(u32::from(marks[0]) * u32::from(marks[1]))
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    assert_eq!(first_two_product("3,4,1,5", 5).unwrap(), 12);
+}
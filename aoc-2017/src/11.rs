@@ -0,0 +1,91 @@
+use std::ops::Add;
+use std::str::FromStr;
+
+use eyre::{Report, Result, bail};
+
+use aoc_meta::Problem;
+
+pub const HEX_ED: Problem = Problem::solved(&|input| walk(input).map(|(end, _)| end), &|input| {
+    walk(input).map(|(_, furthest)| furthest)
+});
+
+/// A hex in a grid of flat-topped hexes, in cube coordinates: every step changes two of the three coordinates by
+/// one in opposite directions, so they always sum to zero.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct Hex {
+    q: i32,
+    r: i32,
+    s: i32,
+}
+
+impl Hex {
+    /// The fewest steps from the origin to this hex.
+    const fn distance(self) -> u32 {
+        let (q, r, s) = (
+            self.q.unsigned_abs(),
+            self.r.unsigned_abs(),
+            self.s.unsigned_abs(),
+        );
+        (q + r + s) / 2
+    }
+}
+
+impl Add for Hex {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            q: self.q + other.q,
+            r: self.r + other.r,
+            s: self.s + other.s,
+        }
+    }
+}
+
+impl FromStr for Hex {
+    type Err = Report;
+
+    /// The step in one of the six directions.
+    fn from_str(s: &str) -> Result<Self> {
+        let (q, r, s) = match s {
+            "n" => (0, -1, 1),
+            "ne" => (1, -1, 0),
+            "se" => (1, 0, -1),
+            "s" => (0, 1, -1),
+            "sw" => (-1, 1, 0),
+            "nw" => (-1, 0, 1),
+            _ => bail!("unknown direction \"{s}\""),
+        };
+
+        Ok(Self { q, r, s })
+    }
+}
+
+/// Follow the child process's steps, returning how far away it ends up and the furthest it ever got.
+fn walk(input: &str) -> Result<(u32, u32)> {
+    let mut position = Hex::default();
+    let mut furthest = 0;
+
+    for step in input.split(',') {
+        position = position + step.parse()?;
+        furthest = furthest.max(position.distance());
+    }
+
+    Ok((position.distance(), furthest))
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    for (input, distance) in [
+        ("ne,ne,ne", 3),
+        ("ne,ne,sw,sw", 0),
+        ("ne,ne,s,s", 2),
+        ("se,sw,se,sw,sw", 3),
+    ] {
+        assert_eq!(walk(input).unwrap().0, distance);
+    }
+
+    assert_eq!(walk("ne,ne,sw,sw").unwrap().1, 2);
+}
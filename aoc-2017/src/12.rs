@@ -0,0 +1,85 @@
+use std::str::FromStr;
+
+use disjoint_sets::UnionFind;
+use eyre::{Report, Result};
+use fnv::FnvHashSet;
+use winnow::combinator::separated_pair;
+
+use aoc_common::parse::{self, comma_separated, unsigned};
+use aoc_meta::Problem;
+
+pub const DIGITAL_PLUMBER: Problem = Problem::solved(
+    &|input| input.parse().map(|village: Village| village.group_size(0)),
+    &|input| input.parse().map(|village: Village| village.group_count()),
+);
+
+#[derive(Debug)]
+struct Village {
+    /// The groups of programs that can talk to each other, whether directly or through others.
+    groups: UnionFind<usize>,
+}
+
+impl Village {
+    fn group_size(&self, program: usize) -> usize {
+        let group = self.groups.find(program);
+        (0..self.groups.len())
+            .filter(|&p| self.groups.find(p) == group)
+            .count()
+    }
+
+    fn group_count(&self) -> usize {
+        (0..self.groups.len())
+            .map(|p| self.groups.find(p))
+            .collect::<FnvHashSet<_>>()
+            .len()
+    }
+}
+
+impl FromStr for Village {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let pipes = parse::lines(
+            s,
+            separated_pair(
+                unsigned::<usize>,
+                " <-> ",
+                comma_separated(unsigned::<usize>),
+            ),
+        )
+        .collect::<Result<Vec<(usize, Vec<usize>)>>>()?;
+
+        let len = pipes
+            .iter()
+            .flat_map(|(program, others)| others.iter().chain([program]))
+            .max()
+            .map_or(0, |&max| max + 1);
+        let mut groups = UnionFind::new(len);
+
+        for (program, others) in pipes {
+            for other in others {
+                groups.union(program, other);
+            }
+        }
+
+        Ok(Self { groups })
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let village: Village = "0 <-> 2
+1 <-> 1
+2 <-> 0, 3, 4
+3 <-> 2, 4
+4 <-> 2, 3, 6
+5 <-> 6
+6 <-> 4, 5"
+        .parse()
+        .unwrap();
+
+    assert_eq!(village.group_size(0), 6);
+    assert_eq!(village.group_count(), 2);
+}
@@ -0,0 +1,75 @@
+use std::str::FromStr;
+
+use eyre::{OptionExt, Report, Result};
+use winnow::combinator::separated_pair;
+
+use aoc_common::parse::{self, unsigned};
+use aoc_meta::Problem;
+
+pub const PACKET_SCANNERS: Problem = Problem::solved(
+    &|input| input.parse().map(|firewall: Firewall| firewall.severity()),
+    &|input| {
+        let firewall: Firewall = input.parse()?;
+        firewall
+            .safe_delay()
+            .ok_or_eyre("the packet can never get through")
+    },
+);
+
+#[derive(Debug, Clone, Copy)]
+struct Layer {
+    depth: u64,
+    range: u64,
+}
+
+impl Layer {
+    /// Whether the scanner is at the top of the layer when a packet sent after `delay` picoseconds gets there. It
+    /// takes `2 * (range - 1)` picoseconds to go down and come back up.
+    #[inline]
+    fn catches(&self, delay: u64) -> bool {
+        (delay + self.depth).is_multiple_of(2 * self.range.saturating_sub(1).max(1))
+    }
+}
+
+/// The layers of the firewall, with the quickest scanners first, since they catch packets most often.
+#[derive(Debug)]
+struct Firewall(Vec<Layer>);
+
+impl Firewall {
+    /// The sum of depth times range of every layer that catches a packet sent straight away.
+    fn severity(&self) -> u64 {
+        self.0
+            .iter()
+            .filter(|layer| layer.catches(0))
+            .map(|layer| layer.depth * layer.range)
+            .sum()
+    }
+
+    /// The shortest delay that gets a packet through without being caught.
+    fn safe_delay(&self) -> Option<u64> {
+        (0..u64::MAX).find(|&delay| self.0.iter().all(|layer| !layer.catches(delay)))
+    }
+}
+
+impl FromStr for Firewall {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut layers = parse::lines(s, separated_pair(unsigned, ": ", unsigned))
+            .map(|layer| layer.map(|(depth, range)| Layer { depth, range }))
+            .collect::<Result<Vec<_>>>()?;
+
+        layers.sort_unstable_by_key(|layer| layer.range);
+        Ok(Self(layers))
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let firewall: Firewall = "0: 3\n1: 2\n4: 4\n6: 4".parse().unwrap();
+
+    assert_eq!(firewall.severity(), 24);
+    assert_eq!(firewall.safe_delay(), Some(10));
+}
@@ -0,0 +1,69 @@
+use disjoint_sets::UnionFind;
+use fnv::FnvHashSet;
+use rayon::prelude::*;
+
+use crate::knot_hash;
+use aoc_meta::Problem;
+
+pub const DISK_DEFRAGMENTATION: Problem = Problem::solved(
+    &|key| {
+        used_squares(key)
+            .iter()
+            .map(|row| row.count_ones() as usize)
+            .sum::<usize>()
+    },
+    &|key| regions(&used_squares(key)),
+);
+
+const SIZE: usize = 128;
+
+/// Each row of the disk as a bitmask, most significant bit on the left: the knot hash of `key-row`.
+fn used_squares(key: &str) -> Vec<u128> {
+    (0..SIZE)
+        .into_par_iter()
+        .map(|row| u128::from_be_bytes(knot_hash::hash(format!("{key}-{row}").as_bytes())))
+        .collect()
+}
+
+/// How many groups of used squares there are that are only adjacent to each other.
+fn regions(rows: &[u128]) -> usize {
+    let is_used = |x: usize, y: usize| rows[y] & (1 << (SIZE - 1 - x)) != 0;
+    let mut regions = UnionFind::new(SIZE * SIZE);
+
+    for y in 0..rows.len() {
+        for x in 0..SIZE {
+            if !is_used(x, y) {
+                continue;
+            }
+
+            if x + 1 < SIZE && is_used(x + 1, y) {
+                regions.union(y * SIZE + x, y * SIZE + x + 1);
+            }
+
+            if y + 1 < rows.len() && is_used(x, y + 1) {
+                regions.union(y * SIZE + x, (y + 1) * SIZE + x);
+            }
+        }
+    }
+
+    (0..rows.len())
+        .flat_map(|y| (0..SIZE).map(move |x| (x, y)))
+        .filter(|&(x, y)| is_used(x, y))
+        .map(|(x, y)| regions.find(y * SIZE + x))
+        .collect::<FnvHashSet<_>>()
+        .len()
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let rows = used_squares("flqrgnkx");
+
+    // ##.#.#..
+    // .#.#.#.#
+    assert_eq!(rows[0] >> 120, 0b11010100);
+    assert_eq!(rows[1] >> 120, 0b01010101);
+    assert_eq!(rows.iter().map(|row| row.count_ones()).sum::<u32>(), 8108);
+    assert_eq!(regions(&rows), 1242);
+}
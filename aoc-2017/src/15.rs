@@ -0,0 +1,68 @@
+use std::iter;
+
+use eyre::{Result, bail};
+
+use aoc_meta::Problem;
+
+pub const DUELING_GENERATORS: Problem = Problem::solved(
+    &|input| {
+        parse_starts(input).map(|(a, b)| judge(generator(a, A, 1), generator(b, B, 1), 40_000_000))
+    },
+    &|input| {
+        parse_starts(input).map(|(a, b)| judge(generator(a, A, 4), generator(b, B, 8), 5_000_000))
+    },
+);
+
+const A: u64 = 16807;
+const B: u64 = 48271;
+const MODULUS: u64 = 2_147_483_647;
+
+fn parse_starts(input: &str) -> Result<(u64, u64)> {
+    let starts = input
+        .lines()
+        .map(|line| match line.rsplit_once(' ') {
+            Some((_, start)) => Ok(start.parse()?),
+            None => bail!("malformed generator \"{line}\""),
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    match starts[..] {
+        [a, b] => Ok((a, b)),
+        _ => bail!("expected two generators, found {}", starts.len()),
+    }
+}
+
+/// The values a generator produces, keeping only the ones that are multiples of `multiple`.
+fn generator(start: u64, factor: u64, multiple: u64) -> impl Iterator<Item = u64> {
+    iter::successors(Some(start), move |&value| Some(value * factor % MODULUS))
+        .skip(1)
+        .filter(move |value| value.is_multiple_of(multiple))
+}
+
+/// How many of the first `pairs` values from `a` and `b` have the same lowest 16 bits.
+fn judge(a: impl Iterator<Item = u64>, b: impl Iterator<Item = u64>, pairs: usize) -> usize {
+    a.zip(b)
+        .take(pairs)
+        .filter(|(a, b)| a & 0xffff == b & 0xffff)
+        .count()
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let (a, b) = parse_starts("Generator A starts with 65\nGenerator B starts with 8921").unwrap();
+
+    assert_eq!(
+        generator(a, A, 1).take(5).collect::<Vec<_>>(),
+        [1092455, 1181022009, 245556042, 1744312007, 1352636452]
+    );
+    assert_eq!(
+        judge(generator(a, A, 1), generator(b, B, 1), 40_000_000),
+        588
+    );
+    assert_eq!(
+        judge(generator(a, A, 4), generator(b, B, 8), 5_000_000),
+        309
+    );
+}
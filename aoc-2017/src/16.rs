@@ -0,0 +1,88 @@
+use std::str::FromStr;
+
+use eyre::{OptionExt, Report, Result, bail};
+
+use aoc_common::cycle;
+use aoc_meta::Problem;
+
+pub const PERMUTATION_PROMENADE: Problem =
+    Problem::solved(&|input| dance(input, 16, 1), &|input| {
+        dance(input, 16, 1_000_000_000)
+    });
+
+#[derive(Debug, Clone, Copy)]
+enum Move {
+    Spin(usize),
+    Exchange(usize, usize),
+    Partner(u8, u8),
+}
+
+impl FromStr for Move {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (kind, rest) = s.split_at_checked(1).ok_or_eyre("empty dance move")?;
+
+        Ok(match (kind, rest.split_once('/')) {
+            ("s", None) => Move::Spin(rest.parse()?),
+            ("x", Some((a, b))) => Move::Exchange(a.parse()?, b.parse()?),
+            ("p", Some((a, b))) => match (a.as_bytes(), b.as_bytes()) {
+                (&[a], &[b]) => Move::Partner(a, b),
+                _ => bail!("programs have one-letter names, not \"{a}\" and \"{b}\""),
+            },
+            _ => bail!("unknown dance move \"{s}\""),
+        })
+    }
+}
+
+/// Where the `programs` programs (named from `a`) stand after dancing the whole dance `times` times. The dance
+/// always leads back to an earlier line-up eventually, so there's no need to dance all of them.
+fn dance(input: &str, programs: u8, times: usize) -> Result<String> {
+    let moves = input
+        .split(',')
+        .map(str::parse)
+        .collect::<Result<Vec<Move>>>()?;
+
+    let len = usize::from(programs);
+    let fits = |m: &Move| match *m {
+        Move::Spin(n) => n <= len,
+        Move::Exchange(a, b) => a.max(b) < len,
+        Move::Partner(..) => true,
+    };
+
+    if !moves.iter().all(fits) {
+        bail!("there are only {programs} programs");
+    }
+
+    let start: Vec<u8> = (b'a'..b'a' + programs).collect();
+    let end = cycle::brent_nth(start, |line| dance_once(&moves, line.clone()), times);
+
+    Ok(String::from_utf8_lossy(&end).into_owned())
+}
+
+fn dance_once(moves: &[Move], mut line: Vec<u8>) -> Vec<u8> {
+    for &m in moves {
+        match m {
+            Move::Spin(n) => line.rotate_right(n),
+            Move::Exchange(a, b) => line.swap(a, b),
+            Move::Partner(a, b) => {
+                if let (Some(a), Some(b)) = (
+                    line.iter().position(|&p| p == a),
+                    line.iter().position(|&p| p == b),
+                ) {
+                    line.swap(a, b);
+                }
+            }
+        }
+    }
+
+    line
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    assert_eq!(dance("s1,x3/4,pe/b", 5, 1).unwrap(), "baedc");
+    assert_eq!(dance("s1,x3/4,pe/b", 5, 2).unwrap(), "ceadb");
+}
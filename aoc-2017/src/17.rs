@@ -0,0 +1,61 @@
+use eyre::{OptionExt, Report, Result};
+
+use aoc_meta::Problem;
+
+pub const SPINLOCK: Problem =
+    Problem::solved(&|input| value_after_last(input.parse()?, 2017), &|input| {
+        Ok::<_, Report>(value_after_zero(input.parse()?, 50_000_000))
+    });
+
+/// The value just after `insertions` once that many values have been inserted, stepping forward `step` places
+/// before each one.
+fn value_after_last(step: usize, insertions: usize) -> Result<usize> {
+    let mut buffer = vec![0];
+    let mut position = 0;
+
+    for value in 1..=insertions {
+        position = (position + step) % buffer.len() + 1;
+        buffer.insert(position, value);
+    }
+
+    buffer
+        .get((position + 1) % buffer.len())
+        .copied()
+        .ok_or_eyre("the buffer is empty")
+}
+
+/// The value just after 0 once `insertions` values have been inserted. Nothing is ever inserted before 0, so it
+/// stays at the front of the buffer and only insertions right after it matter.
+fn value_after_zero(step: usize, insertions: usize) -> usize {
+    let mut position = 0;
+    let mut after_zero = 0;
+
+    for value in 1..=insertions {
+        position = (position + step) % value + 1;
+
+        if position == 1 {
+            after_zero = value;
+        }
+    }
+
+    after_zero
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    assert_eq!(value_after_last(3, 2017).unwrap(), 638);
+
+    for insertions in [1, 2, 9, 100] {
+        let mut buffer = vec![0];
+        let mut position = 0;
+
+        for value in 1..=insertions {
+            position = (position + 3) % buffer.len() + 1;
+            buffer.insert(position, value);
+        }
+
+        assert_eq!(value_after_zero(3, insertions), buffer[1]);
+    }
+}
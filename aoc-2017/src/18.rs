@@ -0,0 +1,100 @@
+use std::collections::VecDeque;
+
+use eyre::{OptionExt, Result, bail};
+
+use crate::duet::{Io, Port, Program, Register, Step};
+use aoc_meta::Problem;
+
+pub const DUET: Problem = Problem::solved(
+    &|input| recovered_frequency(Program::parse(input)?),
+    &|input| Program::parse(input).map(duet),
+);
+
+/// `snd` plays a sound, and `rcv` recovers the last one played, as long as its register isn't zero.
+#[derive(Debug, Default)]
+struct Sound {
+    played: Option<i64>,
+    recovered: Option<i64>,
+}
+
+impl Io for Sound {
+    fn send(&mut self, value: i64) {
+        self.played = Some(value);
+    }
+
+    fn receive(&mut self, current: i64) -> Option<i64> {
+        if current == 0 {
+            return Some(current);
+        }
+
+        // That's all there is to find out, so stop the program there.
+        self.recovered = self.played;
+        None
+    }
+}
+
+fn recovered_frequency(mut program: Program) -> Result<i64> {
+    let mut sound = Sound::default();
+
+    match program.run(&mut sound) {
+        Step::Blocked => sound.recovered.ok_or_eyre("no sound had been played yet"),
+        _ => bail!("the program halted without recovering a frequency"),
+    }
+}
+
+/// How many values program 1 sends before both programs are waiting on each other, or have halted.
+fn duet(program: Program) -> usize {
+    let p = Register::named('p');
+    let mut programs = [program.clone().with(p, 0), program.with(p, 1)];
+    let mut queues = [VecDeque::new(), VecDeque::new()];
+    let mut sent = [0, 0];
+
+    loop {
+        let before = sent;
+
+        for id in 0..2 {
+            let [first, second] = &mut queues;
+            let (inbox, outbox) = if id == 0 {
+                (first, second)
+            } else {
+                (second, first)
+            };
+            let mut port = Port {
+                inbox,
+                outbox,
+                sent: 0,
+            };
+
+            programs[id].run(&mut port);
+            sent[id] += port.sent;
+        }
+
+        // Only sending fills a queue, so if neither program sent anything, they're both stuck for good.
+        if sent == before {
+            return sent[1];
+        }
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let program = Program::parse(
+        "set a 1
+add a 2
+mul a a
+mod a 5
+snd a
+set a 0
+rcv a
+jgz a -1
+set a 1
+jgz a -2",
+    )
+    .unwrap();
+    assert_eq!(recovered_frequency(program).unwrap(), 4);
+
+    let program = Program::parse("snd 1\nsnd 2\nsnd p\nrcv a\nrcv b\nrcv c\nrcv d").unwrap();
+    assert_eq!(duet(program), 3);
+}
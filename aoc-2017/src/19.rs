@@ -0,0 +1,64 @@
+use eyre::{OptionExt, Result};
+
+use aoc_meta::Problem;
+
+pub const A_SERIES_OF_TUBES: Problem = Problem::solved(
+    &|input| follow(input).map(|(letters, _)| letters),
+    &|input| follow(input).map(|(_, steps)| steps),
+);
+
+/// Follow the path from the top of the diagram to its end, returning the letters passed along the way and the
+/// number of steps it took.
+fn follow(diagram: &str) -> Result<(String, usize)> {
+    let rows: Vec<&[u8]> = diagram.lines().map(str::as_bytes).collect();
+    let at = |x: i64, y: i64| {
+        usize::try_from(y)
+            .ok()
+            .zip(usize::try_from(x).ok())
+            .and_then(|(y, x)| rows.get(y)?.get(x))
+            .copied()
+            .unwrap_or(b' ')
+    };
+
+    let start = rows
+        .first()
+        .and_then(|row| row.iter().position(|&b| b == b'|'))
+        .ok_or_eyre("the path doesn't start at the top of the diagram")?;
+
+    let (mut x, mut y) = (start as i64, 0);
+    let (mut dx, mut dy) = (0, 1);
+    let mut letters = String::new();
+    let mut steps = 0;
+
+    loop {
+        match at(x, y) {
+            b' ' => return Ok((letters, steps)),
+            b'+' => {
+                // Turn onto whichever side the path carries on along.
+                (dx, dy) = [(dy, dx), (-dy, -dx)]
+                    .into_iter()
+                    .find(|&(dx, dy)| at(x + dx, y + dy) != b' ')
+                    .ok_or_eyre("the path turns into a dead end")?;
+            }
+            letter if letter.is_ascii_alphabetic() => letters.push(char::from(letter)),
+            _ => {}
+        }
+
+        (x, y) = (x + dx, y + dy);
+        steps += 1;
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let diagram = "     |          
+     |  +--+    
+     A  |  C    
+ F---|----E|--+ 
+     |  |  |  D 
+     +B-+  +--+ ";
+
+    assert_eq!(follow(diagram).unwrap(), ("ABCDEF".to_string(), 38));
+}
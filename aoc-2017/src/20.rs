@@ -0,0 +1,111 @@
+use eyre::{OptionExt, Report, Result};
+use fnv::FnvHashMap;
+use winnow::ascii::space0;
+use winnow::combinator::{delimited, preceded};
+use winnow::prelude::*;
+
+use aoc_common::parse::{self, signed};
+use aoc_meta::Problem;
+
+pub const PARTICLE_SWARM: Problem = Problem::solved(
+    &|input| {
+        parse_particles(input)?
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, particle)| particle.distance_at(LONG_TERM))
+            .map(|(i, _)| i)
+            .ok_or_eyre("there are no particles")
+    },
+    &|input| Ok::<_, Report>(survivors(parse_particles(input)?)),
+);
+
+/// Far enough in the future that acceleration is all that matters.
+const LONG_TERM: i64 = 1_000_000;
+
+/// How long to keep looking for collisions. Particles that are going to collide do so early on, before they've
+/// had time to speed off in their own directions.
+const COLLISION_WINDOW: i64 = 1_000;
+
+type Vector = [i64; 3];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Particle {
+    position: Vector,
+    velocity: Vector,
+    acceleration: Vector,
+}
+
+impl Particle {
+    /// Where the particle is after `t` ticks. Velocity goes up by the acceleration before each move, so the
+    /// particle has moved `v·t + a·t(t + 1)/2`.
+    fn position_at(&self, t: i64) -> Vector {
+        std::array::from_fn(|i| {
+            self.position[i] + self.velocity[i] * t + self.acceleration[i] * t * (t + 1) / 2
+        })
+    }
+
+    fn distance_at(&self, t: i64) -> u64 {
+        self.position_at(t).iter().map(|c| c.unsigned_abs()).sum()
+    }
+}
+
+/// How many particles are left once every collision has happened.
+fn survivors(mut particles: Vec<Particle>) -> usize {
+    for t in 0..COLLISION_WINDOW {
+        let mut at: FnvHashMap<Vector, usize> = FnvHashMap::default();
+        for particle in &particles {
+            *at.entry(particle.position_at(t)).or_default() += 1;
+        }
+
+        particles.retain(|particle| at[&particle.position_at(t)] == 1);
+    }
+
+    particles.len()
+}
+
+fn vector(input: &mut &str) -> ModalResult<Vector> {
+    let coordinate = || preceded(space0, signed);
+
+    delimited(
+        '<',
+        (coordinate(), ',', coordinate(), ',', coordinate()),
+        '>',
+    )
+    .map(|(x, _, y, _, z)| [x, y, z])
+    .parse_next(input)
+}
+
+fn particle(input: &mut &str) -> ModalResult<Particle> {
+    ("p=", vector, ", v=", vector, ", a=", vector)
+        .map(|(_, position, _, velocity, _, acceleration)| Particle {
+            position,
+            velocity,
+            acceleration,
+        })
+        .parse_next(input)
+}
+
+fn parse_particles(input: &str) -> Result<Vec<Particle>> {
+    parse::lines(input, particle).collect()
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let particles =
+        parse_particles("p=< 3,0,0>, v=< 2,0,0>, a=<-1,0,0>\np=< 4,0,0>, v=< 0,0,0>, a=<-2,0,0>")
+            .unwrap();
+    assert_eq!(particles[0].position_at(3), [3, 0, 0]);
+    assert_eq!(particles[1].position_at(3), [-8, 0, 0]);
+    assert!(particles[0].distance_at(LONG_TERM) < particles[1].distance_at(LONG_TERM));
+
+    let particles = parse_particles(
+        "p=<-6,0,0>, v=< 3,0,0>, a=< 0,0,0>
+p=<-4,0,0>, v=< 2,0,0>, a=< 0,0,0>
+p=<-2,0,0>, v=< 1,0,0>, a=< 0,0,0>
+p=< 3,0,0>, v=<-1,0,0>, a=< 0,0,0>",
+    )
+    .unwrap();
+    assert_eq!(survivors(particles), 1);
+}
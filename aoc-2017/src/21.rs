@@ -0,0 +1,142 @@
+use std::str::FromStr;
+
+use eyre::{OptionExt, Report, Result, bail};
+use fnv::FnvHashMap;
+
+use aoc_meta::Problem;
+
+pub const FRACTAL_ART: Problem = Problem::solved(
+    &|input| {
+        input
+            .parse()
+            .and_then(|book: Rulebook| book.pixels_on_after(5))
+    },
+    &|input| {
+        input
+            .parse()
+            .and_then(|book: Rulebook| book.pixels_on_after(18))
+    },
+);
+
+const START: &str = ".#./..#/###";
+
+/// A square of pixels, row by row.
+type Image = Vec<Vec<bool>>;
+
+fn parse_pattern(pattern: &str) -> Result<Image> {
+    let image: Image = pattern
+        .split('/')
+        .map(|row| {
+            row.bytes()
+                .map(|b| match b {
+                    b'#' => Ok(true),
+                    b'.' => Ok(false),
+                    _ => bail!("unknown pixel '{}'", char::from(b)),
+                })
+                .collect()
+        })
+        .collect::<Result<_>>()?;
+
+    if image.iter().any(|row| row.len() != image.len()) {
+        bail!("\"{pattern}\" isn't square");
+    }
+
+    Ok(image)
+}
+
+fn rotate(image: &Image) -> Image {
+    let size = image.len();
+    (0..size)
+        .map(|y| (0..size).map(|x| image[size - 1 - x][y]).collect())
+        .collect()
+}
+
+fn flip(image: &Image) -> Image {
+    image
+        .iter()
+        .map(|row| row.iter().rev().copied().collect())
+        .collect()
+}
+
+/// The enhancement rules, keyed by every rotation and reflection of the pattern each one matches.
+#[derive(Debug)]
+struct Rulebook(FnvHashMap<Image, Image>);
+
+impl Rulebook {
+    /// Break the image up into 2x2 squares if its size is even, or 3x3 squares otherwise, and enhance each one.
+    fn enhance(&self, image: &Image) -> Result<Image> {
+        let size = image.len();
+        let block = if size.is_multiple_of(2) { 2 } else { 3 };
+        let blocks = size / block;
+        let mut enhanced = vec![vec![false; blocks * (block + 1)]; blocks * (block + 1)];
+
+        for by in 0..blocks {
+            for bx in 0..blocks {
+                let square: Image = (0..block)
+                    .map(|y| image[by * block + y][bx * block..][..block].to_vec())
+                    .collect();
+                let output = self
+                    .0
+                    .get(&square)
+                    .ok_or_eyre("no rule matches part of the image")?;
+
+                for (y, row) in output.iter().enumerate() {
+                    let start = bx * (block + 1);
+                    enhanced[by * (block + 1) + y][start..start + row.len()].copy_from_slice(row);
+                }
+            }
+        }
+
+        Ok(enhanced)
+    }
+
+    fn pixels_on_after(&self, iterations: usize) -> Result<usize> {
+        let mut image = parse_pattern(START)?;
+
+        for _ in 0..iterations {
+            image = self.enhance(&image)?;
+        }
+
+        Ok(image.iter().flatten().filter(|&&on| on).count())
+    }
+}
+
+impl FromStr for Rulebook {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut rules = FnvHashMap::default();
+
+        for line in s.lines() {
+            let (pattern, output) = line.split_once(" => ").ok_or_eyre("malformed rule")?;
+            let (mut pattern, output) = (parse_pattern(pattern)?, parse_pattern(output)?);
+
+            if output.len() != pattern.len() + 1 {
+                bail!(
+                    "a {0}x{0} pattern should become a {1}x{1} one",
+                    pattern.len(),
+                    pattern.len() + 1
+                );
+            }
+
+            for _ in 0..4 {
+                rules.insert(flip(&pattern), output.clone());
+                pattern = rotate(&pattern);
+                rules.insert(pattern.clone(), output.clone());
+            }
+        }
+
+        Ok(Self(rules))
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let book: Rulebook = "../.# => ##./#../...\n.#./..#/### => #..#/..../..../#..#"
+        .parse()
+        .unwrap();
+
+    assert_eq!(book.pixels_on_after(2).unwrap(), 12);
+}
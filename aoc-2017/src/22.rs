@@ -0,0 +1,105 @@
+use std::str::FromStr;
+
+use eyre::{Report, Result, bail};
+use fnv::FnvHashMap;
+
+use aoc_meta::Problem;
+
+pub const SPORIFICA_VIRUS: Problem = Problem::solved(
+    &|input| {
+        input
+            .parse()
+            .map(|grid: Grid| grid.infections(10_000, false))
+    },
+    &|input| {
+        input
+            .parse()
+            .map(|grid: Grid| grid.infections(10_000_000, true))
+    },
+);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Node {
+    Clean,
+    Weakened,
+    Infected,
+    Flagged,
+}
+
+/// The nodes of the computing cluster that aren't clean, with the middle of the map at the origin.
+#[derive(Debug, Clone)]
+struct Grid(FnvHashMap<(i32, i32), Node>);
+
+impl Grid {
+    /// How many bursts of activity cause an infection, out of the first `bursts`. The evolved virus weakens clean
+    /// nodes and flags infected ones before going any further.
+    fn infections(mut self, bursts: usize, evolved: bool) -> usize {
+        let (mut x, mut y) = (0, 0);
+        let (mut dx, mut dy) = (0, -1);
+        let mut infections = 0;
+
+        for _ in 0..bursts {
+            let node = self.0.entry((x, y)).or_insert(Node::Clean);
+
+            (dx, dy) = match node {
+                Node::Clean => (dy, -dx),
+                Node::Weakened => (dx, dy),
+                Node::Infected => (-dy, dx),
+                Node::Flagged => (-dx, -dy),
+            };
+
+            *node = match (*node, evolved) {
+                (Node::Clean, true) => Node::Weakened,
+                (Node::Clean, false) | (Node::Weakened, _) => Node::Infected,
+                (Node::Infected, true) => Node::Flagged,
+                (Node::Infected, false) | (Node::Flagged, _) => Node::Clean,
+            };
+
+            if *node == Node::Infected {
+                infections += 1;
+            }
+
+            (x, y) = (x + dx, y + dy);
+        }
+
+        infections
+    }
+}
+
+impl FromStr for Grid {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let rows: Vec<&str> = s.lines().collect();
+        let mut nodes = FnvHashMap::default();
+
+        for (y, row) in rows.iter().enumerate() {
+            for (x, b) in row.bytes().enumerate() {
+                let position = (
+                    x as i32 - (row.len() / 2) as i32,
+                    y as i32 - (rows.len() / 2) as i32,
+                );
+
+                match b {
+                    b'#' => nodes.insert(position, Node::Infected),
+                    b'.' => None,
+                    _ => bail!("unknown node '{}'", char::from(b)),
+                };
+            }
+        }
+
+        Ok(Self(nodes))
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let grid: Grid = "..#\n#..\n...".parse().unwrap();
+
+    assert_eq!(grid.clone().infections(7, false), 5);
+    assert_eq!(grid.clone().infections(70, false), 41);
+    assert_eq!(grid.clone().infections(10_000, false), 5587);
+    assert_eq!(grid.clone().infections(100, true), 26);
+}
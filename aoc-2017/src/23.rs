@@ -0,0 +1,91 @@
+use either::Either;
+use eyre::{OptionExt, Result, bail};
+
+use crate::duet::{Instruction, Program, Register, Step};
+use aoc_meta::Problem;
+
+pub const COPROCESSOR_CONFLAGRATION: Problem = Problem::solved(
+    &|input| Program::parse(input).map(count_multiplications),
+    &|input| final_h(Program::parse(input)?),
+);
+
+fn count_multiplications(mut program: Program) -> usize {
+    let mut count = 0;
+
+    loop {
+        match program.step(&mut ()) {
+            Step::Executed(Instruction::Multiply(..)) => count += 1,
+            Step::Executed(_) => {}
+            Step::Blocked | Step::Halted => return count,
+        }
+    }
+}
+
+/// The value left in `h` when the program runs with `a` set to 1.
+///
+/// Run for real, that takes far too long: the program counts how many of `b, b + step, ..., c` aren't prime, by
+/// trying every pair of factors. So let it set up `b` and `c`, and do the counting here instead.
+fn final_h(program: Program) -> Result<usize> {
+    let [b, c, f] = ['b', 'c', 'f'].map(Register::named);
+
+    // The counting starts by assuming `b` is prime with `set f 1`, and moves on to the next `b` with a `sub b` at
+    // the bottom of the loop.
+    let counting = program
+        .instructions()
+        .iter()
+        .position(|&i| i == Instruction::Set(f, Either::Right(1)))
+        .ok_or_eyre("the program never checks whether anything is prime")?;
+    let step = program
+        .instructions()
+        .iter()
+        .rev()
+        .find_map(|&i| match i {
+            Instruction::Subtract(register, Either::Right(step)) if register == b && step < 0 => {
+                Some(-step)
+            }
+            _ => None,
+        })
+        .ok_or_eyre("the program never moves on to the next candidate")?;
+
+    let mut program = program.with(Register::named('a'), 1);
+    while program.instruction_pointer() != counting {
+        if let Step::Blocked | Step::Halted = program.step(&mut ()) {
+            bail!("the program stopped before it started counting");
+        }
+    }
+
+    Ok(count_composites(program[b], program[c], step))
+}
+
+fn count_composites(start: i64, end: i64, step: i64) -> usize {
+    let is_composite = |n: i64| n > 3 && (2..).take_while(|d| d * d <= n).any(|d| n % d == 0);
+
+    (start..=end)
+        .step_by(step as usize)
+        .filter(|&n| is_composite(n))
+        .count()
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let program = Program::parse("set a 3\nmul b 2\nsub a 1\njnz a -2").unwrap();
+    assert_eq!(count_multiplications(program), 3);
+
+    // 100, 117, 134, 168, 185 and 202 aren't prime, but 151 is.
+    assert_eq!(count_composites(100, 202, 17), 6);
+
+    let program = Program::parse(
+        "set b 100
+set c b
+jnz a 2
+jnz 1 3
+sub c -102
+set f 1
+jnz 1 2
+sub b -17",
+    )
+    .unwrap();
+    assert_eq!(final_h(program).unwrap(), 6);
+}
@@ -0,0 +1,81 @@
+use std::str::FromStr;
+
+use eyre::{Report, Result, ensure};
+use winnow::combinator::separated_pair;
+
+use aoc_common::parse::{self, unsigned};
+use aoc_meta::Problem;
+
+pub const ELECTROMAGNETIC_MOAT: Problem = Problem::solved(
+    &|input| {
+        input
+            .parse()
+            .map(|c: Components| c.best_bridge(|_, strength| strength))
+    },
+    &|input| {
+        input
+            .parse()
+            .map(|c: Components| c.best_bridge(|length, strength| (length, strength)).1)
+    },
+);
+
+/// The components, as the number of pins on each of their two ports.
+#[derive(Debug)]
+struct Components(Vec<(u32, u32)>);
+
+impl Components {
+    /// The best bridge starting from a port with zero pins, as ranked by `rank` given its length and strength.
+    fn best_bridge<R: Ord>(&self, rank: impl Fn(usize, u32) -> R + Copy) -> R {
+        self.extend(0, 0, 0, 0, rank)
+    }
+
+    /// The best bridge that can be built by adding to one ending in a port with `pins` pins, that's already made of
+    /// the components in `used`.
+    fn extend<R: Ord>(
+        &self,
+        pins: u32,
+        used: u64,
+        length: usize,
+        strength: u32,
+        rank: impl Fn(usize, u32) -> R + Copy,
+    ) -> R {
+        self.0
+            .iter()
+            .enumerate()
+            .filter(|&(i, &(a, b))| used & (1 << i) == 0 && (a == pins || b == pins))
+            .map(|(i, &(a, b))| {
+                let other = if a == pins { b } else { a };
+                self.extend(other, used | 1 << i, length + 1, strength + a + b, rank)
+            })
+            .max()
+            .unwrap_or_else(|| rank(length, strength))
+    }
+}
+
+impl FromStr for Components {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let components =
+            parse::lines(s, separated_pair(unsigned, '/', unsigned)).collect::<Result<Vec<_>>>()?;
+        ensure!(
+            components.len() <= 64,
+            "there are too many components to keep track of"
+        );
+
+        Ok(Self(components))
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let components: Components = "0/2\n2/2\n2/3\n3/4\n3/5\n0/1\n10/1\n9/10".parse().unwrap();
+
+    assert_eq!(components.best_bridge(|_, strength| strength), 31);
+    assert_eq!(
+        components.best_bridge(|length, strength| (length, strength)),
+        (4, 19)
+    );
+}
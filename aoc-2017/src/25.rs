@@ -0,0 +1,161 @@
+use std::collections::VecDeque;
+use std::str::FromStr;
+
+use eyre::{OptionExt, Report, Result, bail, ensure};
+
+use aoc_meta::Problem;
+
+pub const THE_HALTING_PROBLEM: Problem = Problem::partially_solved(&|input| {
+    input
+        .parse()
+        .map(|blueprint: Blueprint| blueprint.checksum())
+});
+
+#[derive(Debug, Clone, Copy)]
+struct Action {
+    write: bool,
+    right: bool,
+    next: usize,
+}
+
+/// A Turing machine, with an action for each state depending on whether the current slot is 0 or 1.
+#[derive(Debug)]
+struct Blueprint {
+    start: usize,
+    steps: usize,
+    states: Vec<[Action; 2]>,
+}
+
+impl Blueprint {
+    /// How many 1s are on the tape after running the machine for the number of steps the blueprint says.
+    fn checksum(&self) -> usize {
+        let mut tape = VecDeque::from([false]);
+        let (mut cursor, mut state) = (0, self.start);
+
+        for _ in 0..self.steps {
+            let action = self.states[state][usize::from(tape[cursor])];
+            tape[cursor] = action.write;
+            state = action.next;
+
+            if action.right {
+                cursor += 1;
+                if cursor == tape.len() {
+                    tape.push_back(false);
+                }
+            } else if cursor == 0 {
+                tape.push_front(false);
+            } else {
+                cursor -= 1;
+            }
+        }
+
+        tape.into_iter().filter(|&slot| slot).count()
+    }
+}
+
+/// The last word of `line`, without its punctuation.
+fn last_word(line: Option<&str>) -> Result<&str> {
+    line.and_then(|line| line.split_whitespace().last())
+        .map(|word| word.trim_end_matches(['.', ':']))
+        .ok_or_eyre("the blueprint ended early")
+}
+
+fn state(name: &str) -> Result<usize> {
+    match name.as_bytes() {
+        &[letter @ b'A'..=b'Z'] => Ok(usize::from(letter - b'A')),
+        _ => bail!("unknown state \"{name}\""),
+    }
+}
+
+impl FromStr for Blueprint {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut lines = s.lines().map(str::trim).filter(|line| !line.is_empty());
+
+        let start = state(last_word(lines.next())?)?;
+        let steps = lines
+            .next()
+            .and_then(|line| line.split_whitespace().nth_back(1))
+            .ok_or_eyre("the blueprint doesn't say when to stop")?
+            .parse()?;
+
+        let mut states = Vec::new();
+        while let Some(line) = lines.next() {
+            ensure!(
+                state(last_word(Some(line))?)? == states.len(),
+                "states should be in order"
+            );
+
+            let mut actions = [Action {
+                write: false,
+                right: false,
+                next: 0,
+            }; 2];
+
+            for (value, action) in actions.iter_mut().enumerate() {
+                ensure!(
+                    last_word(lines.next())? == value.to_string(),
+                    "expected the action for a {value}"
+                );
+
+                action.write = last_word(lines.next())? == "1";
+                action.right = match last_word(lines.next())? {
+                    "right" => true,
+                    "left" => false,
+                    direction => bail!("unknown direction \"{direction}\""),
+                };
+                action.next = state(last_word(lines.next())?)?;
+            }
+
+            states.push(actions);
+        }
+
+        ensure!(
+            start < states.len()
+                && states
+                    .iter()
+                    .flatten()
+                    .all(|action| action.next < states.len()),
+            "the blueprint refers to a state it doesn't describe"
+        );
+
+        Ok(Self {
+            start,
+            steps,
+            states,
+        })
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let blueprint: Blueprint = "Begin in state A.
+Perform a diagnostic checksum after 6 steps.
+
+In state A:
+  If the current value is 0:
+    - Write the value 1.
+    - Move one slot to the right.
+    - Continue with state B.
+  If the current value is 1:
+    - Write the value 0.
+    - Move one slot to the left.
+    - Continue with state B.
+
+In state B:
+  If the current value is 0:
+    - Write the value 1.
+    - Move one slot to the left.
+    - Continue with state A.
+  If the current value is 1:
+    - Write the value 1.
+    - Move one slot to the right.
+    - Continue with state A."
+        .parse()
+        .unwrap();
+
+    assert_eq!(blueprint.checksum(), 3);
+}
@@ -0,0 +1,256 @@
+//! The assembly language from day 18's duet, which the coprocessor on day 23 runs a dialect of.
+//!
+//! `snd` and `rcv` mean different things depending on who's listening, so a [`Program`] hands them off to an
+//! [`Io`]: one that plays and recovers sounds, or a pair of message queues between two programs running side by
+//! side.
+
+use std::collections::VecDeque;
+use std::ops;
+
+use either::Either;
+use eyre::Report;
+use winnow::{
+    ascii::alpha1,
+    combinator::{alt, dispatch, fail, separated_pair, terminated},
+    prelude::*,
+    token::any,
+};
+
+use aoc_common::parse::{self, signed};
+
+/// A register or an immediate value.
+pub type Operand = Either<Register, i64>;
+
+/// One of the 26 registers, named `a` to `z`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Register(u8);
+
+impl Register {
+    pub const fn named(name: char) -> Self {
+        assert!(name.is_ascii_lowercase());
+        Self(name as u8 - b'a')
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Instruction {
+    Send(Operand),
+    Set(Register, Operand),
+    Add(Register, Operand),
+    Subtract(Register, Operand),
+    Multiply(Register, Operand),
+    Modulo(Register, Operand),
+    Receive(Register),
+    JumpIfGreaterThanZero(Operand, Operand),
+    JumpIfNotZero(Operand, Operand),
+}
+
+/// Whatever is on the other end of `snd` and `rcv`.
+pub trait Io {
+    fn send(&mut self, value: i64);
+
+    /// The value to put in the register being received into, given what it holds now, or `None` to wait until
+    /// there's something to receive. A program that's waiting tries the `rcv` again the next time it runs.
+    fn receive(&mut self, current: i64) -> Option<i64>;
+}
+
+/// A coprocessor with nothing attached to it, which waits forever if asked to receive anything.
+impl Io for () {
+    fn send(&mut self, _: i64) {}
+
+    fn receive(&mut self, _: i64) -> Option<i64> {
+        None
+    }
+}
+
+/// One end of a pair of message queues between two programs.
+#[derive(Debug)]
+pub struct Port<'a> {
+    pub inbox: &'a mut VecDeque<i64>,
+    pub outbox: &'a mut VecDeque<i64>,
+    /// How many values have been sent through this port.
+    pub sent: usize,
+}
+
+impl Io for Port<'_> {
+    fn send(&mut self, value: i64) {
+        self.outbox.push_back(value);
+        self.sent += 1;
+    }
+
+    fn receive(&mut self, _: i64) -> Option<i64> {
+        self.inbox.pop_front()
+    }
+}
+
+/// What happened when the program took a step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Step {
+    Executed(Instruction),
+    /// The program is waiting to receive a value.
+    Blocked,
+    /// The program jumped outside itself.
+    Halted,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Program {
+    instruction_pointer: usize,
+    registers: [i64; 26],
+    instructions: Vec<Instruction>,
+}
+
+impl ops::Index<Register> for Program {
+    type Output = i64;
+
+    #[inline]
+    fn index(&self, index: Register) -> &Self::Output {
+        &self.registers[usize::from(index.0)]
+    }
+}
+
+impl ops::IndexMut<Register> for Program {
+    #[inline]
+    fn index_mut(&mut self, index: Register) -> &mut Self::Output {
+        &mut self.registers[usize::from(index.0)]
+    }
+}
+
+impl Program {
+    pub fn parse(input: &str) -> Result<Self, Report> {
+        Ok(Self {
+            instruction_pointer: 0,
+            registers: [0; 26],
+            instructions: parse::lines(input, instruction).collect::<Result<_, _>>()?,
+        })
+    }
+
+    /// Set `register` to `value` before the program starts.
+    #[inline]
+    pub fn with(mut self, register: Register, value: i64) -> Self {
+        self[register] = value;
+        self
+    }
+
+    #[inline]
+    pub fn instruction_pointer(&self) -> usize {
+        self.instruction_pointer
+    }
+
+    #[inline]
+    pub fn instructions(&self) -> &[Instruction] {
+        &self.instructions
+    }
+
+    /// Run the program until it halts or has to wait to receive something.
+    pub fn run(&mut self, io: &mut impl Io) -> Step {
+        loop {
+            match self.step(io) {
+                Step::Executed(_) => {}
+                stopped => return stopped,
+            }
+        }
+    }
+
+    /// Execute the instruction at the instruction pointer.
+    pub fn step(&mut self, io: &mut impl Io) -> Step {
+        let Some(&instruction) = self.instructions.get(self.instruction_pointer) else {
+            return Step::Halted;
+        };
+
+        let mut jump = 1;
+
+        match instruction {
+            Instruction::Send(value) => io.send(self.value(value)),
+            Instruction::Set(target, value) => self[target] = self.value(value),
+            Instruction::Add(target, value) => self[target] += self.value(value),
+            Instruction::Subtract(target, value) => self[target] -= self.value(value),
+            Instruction::Multiply(target, value) => self[target] *= self.value(value),
+            Instruction::Modulo(target, value) => {
+                let value = self.value(value);
+                if value != 0 {
+                    self[target] %= value;
+                }
+            }
+            Instruction::Receive(target) => match io.receive(self[target]) {
+                Some(value) => self[target] = value,
+                None => return Step::Blocked,
+            },
+            Instruction::JumpIfGreaterThanZero(condition, distance)
+                if self.value(condition) > 0 =>
+            {
+                jump = self.value(distance);
+            }
+            Instruction::JumpIfNotZero(condition, distance) if self.value(condition) != 0 => {
+                jump = self.value(distance);
+            }
+            Instruction::JumpIfGreaterThanZero(..) | Instruction::JumpIfNotZero(..) => {}
+        }
+
+        // Jumping to before the start of the program halts it, just like jumping past the end.
+        self.instruction_pointer = isize::try_from(jump)
+            .ok()
+            .and_then(|jump| self.instruction_pointer.checked_add_signed(jump))
+            .unwrap_or(usize::MAX);
+
+        Step::Executed(instruction)
+    }
+
+    #[inline]
+    fn value(&self, operand: Operand) -> i64 {
+        operand.either(|register| self[register], |value| value)
+    }
+}
+
+fn register(input: &mut &str) -> ModalResult<Register> {
+    any.verify(char::is_ascii_lowercase)
+        .map(Register::named)
+        .parse_next(input)
+}
+
+fn operand(input: &mut &str) -> ModalResult<Operand> {
+    alt((register.map(Either::Left), signed.map(Either::Right))).parse_next(input)
+}
+
+fn instruction(input: &mut &str) -> ModalResult<Instruction> {
+    let arithmetic = || separated_pair(register, ' ', operand);
+    let jump = || separated_pair(operand, ' ', operand);
+
+    dispatch! {terminated(alpha1, ' ');
+        "snd" => operand.map(Instruction::Send),
+        "set" => arithmetic().map(|(target, value)| Instruction::Set(target, value)),
+        "add" => arithmetic().map(|(target, value)| Instruction::Add(target, value)),
+        "sub" => arithmetic().map(|(target, value)| Instruction::Subtract(target, value)),
+        "mul" => arithmetic().map(|(target, value)| Instruction::Multiply(target, value)),
+        "mod" => arithmetic().map(|(target, value)| Instruction::Modulo(target, value)),
+        "rcv" => register.map(Instruction::Receive),
+        "jgz" => jump().map(|(condition, distance)| Instruction::JumpIfGreaterThanZero(condition, distance)),
+        "jnz" => jump().map(|(condition, distance)| Instruction::JumpIfNotZero(condition, distance)),
+        _ => fail,
+    }
+    .parse_next(input)
+}
+
+#[test]
+fn examples() {
+    let mut program =
+        Program::parse("set a 1\nadd a 2\nmul a a\nmod a 5\nsub a -3\njgz a -10").unwrap();
+    assert_eq!(program.run(&mut ()), Step::Halted);
+    assert_eq!(program[Register::named('a')], 7);
+
+    // Waiting to receive doesn't skip the `rcv`.
+    let (mut inbox, mut outbox) = (VecDeque::new(), VecDeque::new());
+    let mut program = Program::parse("rcv b\nsnd b\nrcv b").unwrap();
+    let mut port = Port {
+        inbox: &mut inbox,
+        outbox: &mut outbox,
+        sent: 0,
+    };
+
+    assert_eq!(program.run(&mut port), Step::Blocked);
+    port.inbox.push_back(5);
+    assert_eq!(program.run(&mut port), Step::Blocked);
+    assert_eq!(program.instruction_pointer(), 2);
+    assert_eq!(port.sent, 1);
+    assert_eq!(outbox, [5]);
+}
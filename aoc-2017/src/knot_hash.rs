@@ -0,0 +1,65 @@
+//! The knot hash from day 10, which day 14 uses to build its disk grid.
+
+use std::fmt::Write;
+
+/// The lengths appended to every input before the full hash is computed.
+const SUFFIX: [u8; 5] = [17, 31, 73, 47, 23];
+
+/// Tie `rounds` rounds of knots in a circular list of the marks `0..size`, reversing a run of each of `lengths` in
+/// turn. The current position and skip size carry over from one round to the next.
+pub fn knot(lengths: &[usize], size: usize, rounds: usize) -> Vec<u8> {
+    let mut marks: Vec<u8> = (0..size).map(|mark| mark as u8).collect();
+    let (mut position, mut skip) = (0, 0);
+
+    for _ in 0..rounds {
+        for &length in lengths {
+            // Rotating the current position to the front turns the circular reversal into an ordinary one.
+            marks.rotate_left(position);
+            marks[..length].reverse();
+            marks.rotate_right(position);
+
+            position = (position + length + skip) % size;
+            skip += 1;
+        }
+    }
+
+    marks
+}
+
+/// The dense hash of `input`: 64 rounds of knots using its bytes as lengths, with each block of 16 marks XORed
+/// together.
+pub fn hash(input: &[u8]) -> [u8; 16] {
+    let lengths: Vec<usize> = input
+        .iter()
+        .chain(&SUFFIX)
+        .map(|&b| usize::from(b))
+        .collect();
+    let sparse = knot(&lengths, 256, 64);
+
+    let mut dense = [0; 16];
+    for (byte, block) in dense.iter_mut().zip(sparse.chunks(16)) {
+        *byte = block.iter().fold(0, |acc, mark| acc ^ mark);
+    }
+
+    dense
+}
+
+/// The knot hash of `input` as 32 hexadecimal digits.
+pub fn hex(input: &[u8]) -> String {
+    hash(input)
+        .iter()
+        .fold(String::with_capacity(32), |mut hex, byte| {
+            let _ = write!(hex, "{byte:02x}");
+            hex
+        })
+}
+
+#[test]
+fn examples() {
+    assert_eq!(knot(&[3, 4, 1, 5], 5, 1), [3, 4, 2, 1, 0]);
+
+    assert_eq!(hex(b""), "a2582a3a0e66e6e86e3812dcb672a272");
+    assert_eq!(hex(b"AoC 2017"), "33efeb34ea91902bb2f59c9920caa6cd");
+    assert_eq!(hex(b"1,2,3"), "3efbe78a8d82f29979031a4aa0b16a9d");
+    assert_eq!(hex(b"1,2,4"), "63960835bcdc130f0b66d7ff4f6a5a8e");
+}
@@ -1,3 +1,32 @@
 use aoc_meta::PROBLEMS;
 
-PROBLEMS! {}
+mod duet;
+mod knot_hash;
+
+PROBLEMS! {
+    01 => INVERSE_CAPTCHA,
+    02 => CORRUPTION_CHECKSUM,
+    03 => SPIRAL_MEMORY,
+    04 => HIGH_ENTROPY_PASSPHRASES,
+    05 => A_MAZE_OF_TWISTY_TRAMPOLINES_ALL_ALIKE,
+    06 => MEMORY_REALLOCATION,
+    07 => RECURSIVE_CIRCUS,
+    08 => I_HEARD_YOU_LIKE_REGISTERS,
+    09 => STREAM_PROCESSING,
+    10 => KNOT_HASH,
+    11 => HEX_ED,
+    12 => DIGITAL_PLUMBER,
+    13 => PACKET_SCANNERS,
+    14 => DISK_DEFRAGMENTATION,
+    15 => DUELING_GENERATORS,
+    16 => PERMUTATION_PROMENADE,
+    17 => SPINLOCK,
+    18 => DUET,
+    19 => A_SERIES_OF_TUBES,
+    20 => PARTICLE_SWARM,
+    21 => FRACTAL_ART,
+    22 => SPORIFICA_VIRUS,
+    23 => COPROCESSOR_CONFLAGRATION,
+    24 => ELECTROMAGNETIC_MOAT,
+    25 => THE_HALTING_PROBLEM,
+}
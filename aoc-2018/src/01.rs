@@ -0,0 +1,76 @@
+use eyre::{Result, bail};
+use fnv::FnvHashSet;
+use itertools::Itertools;
+
+use aoc_meta::Problem;
+
+pub const CHRONAL_CALIBRATION: Problem = Problem::solved(
+    &|input| parse_changes(input).map(|changes| changes.iter().sum::<i64>()),
+    &|input| parse_changes(input).and_then(|changes| first_repeat(&changes)),
+);
+
+fn parse_changes(input: &str) -> Result<Vec<i64>> {
+    input
+        .split([',', '\n'])
+        .map(|change| Ok(change.trim().parse()?))
+        .collect()
+}
+
+/// The first frequency reached twice, going through the list of changes over and over.
+fn first_repeat(changes: &[i64]) -> Result<i64> {
+    let total: i64 = changes.iter().sum();
+
+    // Each pass shifts every frequency by the total, so once the shift is bigger than the spread of frequencies
+    // within a pass, nothing new can line up with anything seen before.
+    let (min, max) = changes
+        .iter()
+        .scan(0, |frequency, change| {
+            *frequency += change;
+            Some(*frequency)
+        })
+        .chain([0])
+        .minmax()
+        .into_option()
+        .unwrap_or_default();
+    let passes = if total == 0 {
+        1
+    } else {
+        ((max - min) / total.abs()) as usize + 2
+    };
+
+    let mut seen = FnvHashSet::from_iter([0]);
+    let mut frequency = 0;
+
+    for change in changes.iter().cycle().take(changes.len() * passes) {
+        frequency += change;
+
+        if !seen.insert(frequency) {
+            return Ok(frequency);
+        }
+    }
+
+    bail!("no frequency is ever reached twice")
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    assert_eq!(
+        parse_changes("+1, -2, +3, +1").unwrap().iter().sum::<i64>(),
+        3
+    );
+
+    for (changes, repeat) in [
+        ("+1, -2, +3, +1", 2),
+        ("+1, -1", 0),
+        ("+3, +3, +4, -2, -4", 10),
+        ("-6, +3, +8, +5, -6", 5),
+        ("+7, +7, -2, -7, -4", 14),
+    ] {
+        assert_eq!(
+            first_repeat(&parse_changes(changes).unwrap()).unwrap(),
+            repeat
+        );
+    }
+}
@@ -0,0 +1,52 @@
+use eyre::{OptionExt, Report, Result};
+use itertools::Itertools;
+
+use aoc_meta::Problem;
+
+pub const INVENTORY_MANAGEMENT_SYSTEM: Problem =
+    Problem::solved(&|input| Ok::<_, Report>(checksum(input)), &|input| {
+        common_letters(input)
+    });
+
+/// The number of IDs with some letter exactly twice, times the number with some letter exactly three times.
+fn checksum(input: &str) -> usize {
+    let (twos, threes) = input.lines().fold((0, 0), |(twos, threes), id| {
+        let counts = id.bytes().counts();
+        let has = |n| usize::from(counts.values().any(|&count| count == n));
+        (twos + has(2), threes + has(3))
+    });
+
+    twos * threes
+}
+
+/// The letters the two IDs that differ in exactly one position have in common.
+fn common_letters(input: &str) -> Result<String> {
+    input
+        .lines()
+        .tuple_combinations()
+        .find(|(a, b)| {
+            a.len() == b.len() && a.bytes().zip(b.bytes()).filter(|(x, y)| x != y).count() == 1
+        })
+        .map(|(a, b)| {
+            a.chars()
+                .zip(b.chars())
+                .filter(|(x, y)| x == y)
+                .map(|(x, _)| x)
+                .collect()
+        })
+        .ok_or_eyre("no two IDs differ by exactly one letter")
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    assert_eq!(
+        checksum("abcdef\nbababc\nabbcde\nabcccd\naabcdd\nabcdee\nababab"),
+        12
+    );
+    assert_eq!(
+        common_letters("abcde\nfghij\nklmno\npqrst\nfguij\naxcye\nwvxyz").unwrap(),
+        "fgij"
+    );
+}
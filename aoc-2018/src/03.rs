@@ -0,0 +1,105 @@
+use std::str::FromStr;
+
+use eyre::{OptionExt, Report, Result};
+use winnow::combinator::{preceded, seq};
+use winnow::prelude::*;
+
+use aoc_common::parse::{self, unsigned};
+use aoc_meta::Problem;
+
+pub const NO_MATTER_HOW_YOU_SLICE_IT: Problem = Problem::solved(
+    &|input| input.parse().map(|fabric: Fabric| fabric.overlapping()),
+    &|input| {
+        input
+            .parse()
+            .and_then(|fabric: Fabric| fabric.intact_claim())
+    },
+);
+
+#[derive(Debug, Clone, Copy)]
+struct Claim {
+    id: usize,
+    left: usize,
+    top: usize,
+    width: usize,
+    height: usize,
+}
+
+impl Claim {
+    fn squares(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        (self.top..self.top + self.height)
+            .flat_map(|y| (self.left..self.left + self.width).map(move |x| (x, y)))
+    }
+}
+
+/// The claims, along with how many of them claim each square inch of fabric.
+#[derive(Debug)]
+struct Fabric {
+    claims: Vec<Claim>,
+    width: usize,
+    counts: Vec<u16>,
+}
+
+impl Fabric {
+    fn count(&self, (x, y): (usize, usize)) -> u16 {
+        self.counts[y * self.width + x]
+    }
+
+    /// How many square inches are in two or more claims.
+    fn overlapping(&self) -> usize {
+        self.counts.iter().filter(|&&count| count > 1).count()
+    }
+
+    /// The ID of the only claim that doesn't overlap any other.
+    fn intact_claim(&self) -> Result<usize> {
+        self.claims
+            .iter()
+            .find(|claim| claim.squares().all(|square| self.count(square) == 1))
+            .map(|claim| claim.id)
+            .ok_or_eyre("every claim overlaps another")
+    }
+}
+
+fn claim(input: &mut &str) -> ModalResult<Claim> {
+    seq! {Claim {
+        id: preceded('#', unsigned),
+        left: preceded(" @ ", unsigned),
+        top: preceded(',', unsigned),
+        width: preceded(": ", unsigned),
+        height: preceded('x', unsigned),
+    }}
+    .parse_next(input)
+}
+
+impl FromStr for Fabric {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let claims = parse::lines(s, claim).collect::<Result<Vec<_>>>()?;
+        let width = claims.iter().map(|c| c.left + c.width).max().unwrap_or(0);
+        let height = claims.iter().map(|c| c.top + c.height).max().unwrap_or(0);
+        let mut counts = vec![0; width * height];
+
+        for (x, y) in claims.iter().flat_map(Claim::squares) {
+            counts[y * width + x] += 1;
+        }
+
+        Ok(Self {
+            claims,
+            width,
+            counts,
+        })
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let fabric: Fabric = "#1 @ 1,3: 4x4\n#2 @ 3,1: 4x4\n#3 @ 5,5: 2x2"
+        .parse()
+        .unwrap();
+
+    assert_eq!(fabric.overlapping(), 4);
+    assert_eq!(fabric.intact_claim().unwrap(), 3);
+}
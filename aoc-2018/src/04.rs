@@ -0,0 +1,133 @@
+use std::cmp::Reverse;
+use std::str::FromStr;
+
+use eyre::{OptionExt, Report, Result, bail, eyre};
+use fnv::FnvHashMap;
+
+use aoc_meta::Problem;
+
+pub const REPOSE_RECORD: Problem = Problem::solved(
+    &|input| {
+        input
+            .parse()
+            .and_then(|record: Record| record.strategy_one())
+    },
+    &|input| {
+        input
+            .parse()
+            .and_then(|record: Record| record.strategy_two())
+    },
+);
+
+/// How many times each guard was asleep during each minute of the midnight hour.
+#[derive(Debug)]
+struct Record(FnvHashMap<usize, [u32; 60]>);
+
+impl Record {
+    /// The ID of the guard who slept the most, times the minute they were asleep for most often.
+    fn strategy_one(&self) -> Result<usize> {
+        self.0
+            .iter()
+            .max_by_key(|(_, minutes)| minutes.iter().sum::<u32>())
+            .map(|(&guard, minutes)| guard * sleepiest_minute(minutes).0)
+            .ok_or_eyre("there are no guards")
+    }
+
+    /// The ID of the guard who was asleep during the same minute the most times, times that minute.
+    fn strategy_two(&self) -> Result<usize> {
+        self.0
+            .iter()
+            .map(|(&guard, minutes)| (guard, sleepiest_minute(minutes)))
+            .max_by_key(|&(_, (_, times))| times)
+            .map(|(guard, (minute, _))| guard * minute)
+            .ok_or_eyre("there are no guards")
+    }
+}
+
+/// The minute a guard was asleep for most often, and how many times that was.
+fn sleepiest_minute(minutes: &[u32; 60]) -> (usize, u32) {
+    minutes
+        .iter()
+        .copied()
+        .enumerate()
+        .max_by_key(|&(minute, times)| (times, Reverse(minute)))
+        .unwrap_or_default()
+}
+
+impl FromStr for Record {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        // Timestamps are written so that sorting them as text puts them in order.
+        let mut lines: Vec<&str> = s.lines().collect();
+        lines.sort_unstable();
+
+        let mut guards: FnvHashMap<usize, [u32; 60]> = FnvHashMap::default();
+        let (mut guard, mut fell_asleep) = (None, None);
+
+        for line in lines {
+            let (timestamp, event) = line
+                .strip_prefix('[')
+                .and_then(|line| line.split_once("] "))
+                .ok_or_eyre("records start with a timestamp")?;
+            let minute: usize = timestamp
+                .rsplit_once(':')
+                .ok_or_eyre("malformed timestamp")?
+                .1
+                .parse()?;
+
+            match event {
+                "falls asleep" => fell_asleep = Some(minute),
+                "wakes up" => {
+                    let (Some(guard), Some(start)) = (guard, fell_asleep.take()) else {
+                        bail!("someone woke up without falling asleep");
+                    };
+
+                    let minutes = guards.entry(guard).or_insert([0; 60]);
+                    for asleep in &mut minutes[start.min(60)..minute.min(60)] {
+                        *asleep += 1;
+                    }
+                }
+                _ => {
+                    let id = event
+                        .strip_prefix("Guard #")
+                        .and_then(|event| event.strip_suffix(" begins shift"))
+                        .ok_or_else(|| eyre!("unknown event \"{event}\""))?;
+
+                    guard = Some(id.parse()?);
+                    fell_asleep = None;
+                }
+            }
+        }
+
+        Ok(Self(guards))
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let input = "[1518-11-01 00:00] Guard #10 begins shift
+[1518-11-01 00:05] falls asleep
+[1518-11-01 00:25] wakes up
+[1518-11-01 00:30] falls asleep
+[1518-11-01 00:55] wakes up
+[1518-11-01 23:58] Guard #99 begins shift
+[1518-11-02 00:40] falls asleep
+[1518-11-02 00:50] wakes up
+[1518-11-03 00:05] Guard #10 begins shift
+[1518-11-03 00:24] falls asleep
+[1518-11-03 00:29] wakes up
+[1518-11-04 00:02] Guard #99 begins shift
+[1518-11-04 00:36] falls asleep
+[1518-11-04 00:46] wakes up
+[1518-11-05 00:03] Guard #99 begins shift
+[1518-11-05 00:45] falls asleep
+[1518-11-05 00:55] wakes up";
+
+    let record: Record = input.parse().unwrap();
+
+    assert_eq!(record.strategy_one().unwrap(), 240);
+    assert_eq!(record.strategy_two().unwrap(), 4455);
+}
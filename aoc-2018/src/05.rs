@@ -0,0 +1,53 @@
+use eyre::{OptionExt, Report, Result};
+use rayon::prelude::*;
+
+use aoc_meta::Problem;
+
+pub const ALCHEMICAL_REDUCTION: Problem = Problem::solved(
+    &|input| Ok::<_, Report>(react(input.bytes()).len()),
+    &|input| shortest_without_one_unit(input),
+);
+
+/// The polymer left once every pair of adjacent units of the same type and opposite polarity has reacted.
+fn react(polymer: impl Iterator<Item = u8>) -> Vec<u8> {
+    let mut reacted: Vec<u8> = Vec::new();
+
+    for unit in polymer {
+        // Opposite polarities differ only in the ASCII case bit.
+        if reacted.last() == Some(&(unit ^ 0x20)) {
+            reacted.pop();
+        } else {
+            reacted.push(unit);
+        }
+    }
+
+    reacted
+}
+
+/// The length of the shortest polymer that can be made by removing every unit of one type, then reacting it.
+fn shortest_without_one_unit(polymer: &str) -> Result<usize> {
+    // Whatever reacts in the whole polymer reacts with a unit type removed too, so start from the reacted one.
+    let reacted = react(polymer.bytes());
+
+    (b'a'..=b'z')
+        .into_par_iter()
+        .map(|removed| {
+            react(
+                reacted
+                    .iter()
+                    .copied()
+                    .filter(|unit| unit.to_ascii_lowercase() != removed),
+            )
+            .len()
+        })
+        .min()
+        .ok_or_eyre("there are no unit types")
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    assert_eq!(react("dabAcCaCBAcCcaDA".bytes()), b"dabCBAcaDA");
+    assert_eq!(shortest_without_one_unit("dabAcCaCBAcCcaDA").unwrap(), 4);
+}
@@ -0,0 +1,120 @@
+use std::str::FromStr;
+
+use eyre::{OptionExt, Report, Result};
+use itertools::Itertools;
+use winnow::combinator::separated_pair;
+
+use aoc_common::parse::{self, signed};
+use aoc_meta::Problem;
+
+pub const CHRONAL_COORDINATES: Problem = Problem::solved(
+    &|input| input.parse().and_then(|map: Map| map.largest_finite_area()),
+    &|input| input.parse().map(|map: Map| map.safe_region(10_000)),
+);
+
+#[derive(Debug)]
+struct Map {
+    coordinates: Vec<(i32, i32)>,
+    /// The corners of the box around every coordinate, top left and bottom right.
+    bounds: ((i32, i32), (i32, i32)),
+}
+
+impl Map {
+    fn locations(&self) -> impl Iterator<Item = (i32, i32)> + use<> {
+        let ((left, top), (right, bottom)) = self.bounds;
+        (top..=bottom).flat_map(move |y| (left..=right).map(move |x| (x, y)))
+    }
+
+    fn distances(&self, (x, y): (i32, i32)) -> impl Iterator<Item = u32> + '_ {
+        self.coordinates
+            .iter()
+            .map(move |&(cx, cy)| x.abs_diff(cx) + y.abs_diff(cy))
+    }
+
+    /// The size of the largest area of locations closest to a single coordinate that doesn't go on forever.
+    /// Areas that reach the edge of the bounding box carry on past it, so those are infinite.
+    fn largest_finite_area(&self) -> Result<usize> {
+        let ((left, top), (right, bottom)) = self.bounds;
+        let mut areas = vec![Some(0); self.coordinates.len()];
+
+        for (x, y) in self.locations() {
+            let distances: Vec<u32> = self.distances((x, y)).collect();
+            let Some(&nearest) = distances.iter().min() else {
+                continue;
+            };
+
+            let mut closest = distances.iter().positions(|&d| d == nearest);
+            if let (Some(owner), None) = (closest.next(), closest.next()) {
+                if x == left || x == right || y == top || y == bottom {
+                    areas[owner] = None;
+                } else if let Some(area) = &mut areas[owner] {
+                    *area += 1;
+                }
+            }
+        }
+
+        areas
+            .into_iter()
+            .flatten()
+            .max()
+            .ok_or_eyre("every area is infinite")
+    }
+
+    /// How many locations have a total distance to every coordinate of less than `limit`.
+    fn safe_region(&self, limit: u32) -> usize {
+        // A location beyond the bounding box is further away from every coordinate than the nearest location on
+        // the edge, so extend the box far enough that nothing outside it could be safe.
+        let margin = (limit as usize / self.coordinates.len().max(1)) as i32 + 1;
+        let ((left, top), (right, bottom)) = self.bounds;
+
+        let extended = Map {
+            coordinates: Vec::new(),
+            bounds: (
+                (left - margin, top - margin),
+                (right + margin, bottom + margin),
+            ),
+        };
+
+        extended
+            .locations()
+            .filter(|&location| self.distances(location).sum::<u32>() < limit)
+            .count()
+    }
+}
+
+impl FromStr for Map {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let coordinates =
+            parse::lines(s, separated_pair(signed, ", ", signed)).collect::<Result<Vec<_>>>()?;
+
+        let (left, right) = coordinates
+            .iter()
+            .map(|&(x, _)| x)
+            .minmax()
+            .into_option()
+            .unwrap_or_default();
+        let (top, bottom) = coordinates
+            .iter()
+            .map(|&(_, y)| y)
+            .minmax()
+            .into_option()
+            .unwrap_or_default();
+
+        Ok(Self {
+            coordinates,
+            bounds: ((left, top), (right, bottom)),
+        })
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let map: Map = "1, 1\n1, 6\n8, 3\n3, 4\n5, 5\n8, 9".parse().unwrap();
+
+    assert_eq!(map.largest_finite_area().unwrap(), 17);
+    assert_eq!(map.safe_region(32), 16);
+}
@@ -0,0 +1,131 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::str::FromStr;
+
+use eyre::{Report, Result, bail, ensure};
+use winnow::prelude::*;
+use winnow::token::one_of;
+
+use aoc_common::parse;
+use aoc_meta::Problem;
+
+pub const THE_SUM_OF_ITS_PARTS: Problem = Problem::solved(
+    &|input| {
+        input
+            .parse()
+            .and_then(|steps: Instructions| steps.assemble(1, 0).map(|(order, _)| order))
+    },
+    &|input| {
+        input
+            .parse()
+            .and_then(|steps: Instructions| steps.assemble(5, 60).map(|(_, time)| time))
+    },
+);
+
+/// Which steps (numbered from A = 0) each step has to wait for, as bitmasks.
+#[derive(Debug)]
+struct Instructions {
+    prerequisites: [u32; 26],
+    steps: u32,
+}
+
+impl Instructions {
+    /// Work through the steps with `workers` workers, each step taking `base` seconds plus its position in the
+    /// alphabet, and always starting the first available step alphabetically. Returns the order the steps were
+    /// finished in and how long it took to finish all of them.
+    fn assemble(&self, workers: usize, base: u32) -> Result<(String, u32)> {
+        let (mut started, mut done) = (0u32, 0u32);
+        let mut order = String::new();
+        let mut in_progress = BinaryHeap::new();
+        let mut time = 0;
+
+        while done != self.steps {
+            // Hand out every available step to the idle workers, alphabetically.
+            while in_progress.len() < workers {
+                let Some(step) = (0..26).find(|&step| {
+                    self.steps & !started & 1 << step != 0 && self.prerequisites[step] & !done == 0
+                }) else {
+                    break;
+                };
+
+                started |= 1 << step;
+                in_progress.push(Reverse((time + base + step as u32 + 1, step)));
+            }
+
+            let Some(Reverse((finished, step))) = in_progress.pop() else {
+                bail!("some steps can never be started");
+            };
+
+            time = finished;
+            done |= 1 << step;
+            order.push(char::from(b'A' + step as u8));
+
+            // Steps that finish at the same time might all be needed for the next one.
+            while let Some(&Reverse((finished, step))) = in_progress.peek()
+                && finished == time
+            {
+                in_progress.pop();
+                done |= 1 << step;
+                order.push(char::from(b'A' + step as u8));
+            }
+        }
+
+        Ok((order, time))
+    }
+}
+
+fn dependency(input: &mut &str) -> ModalResult<(u8, u8)> {
+    let step = || one_of('A'..='Z').map(|c: char| c as u8 - b'A');
+
+    (
+        "Step ",
+        step(),
+        " must be finished before step ",
+        step(),
+        " can begin.",
+    )
+        .map(|(_, before, _, after, _)| (before, after))
+        .parse_next(input)
+}
+
+impl FromStr for Instructions {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut instructions = Self {
+            prerequisites: [0; 26],
+            steps: 0,
+        };
+
+        for line in s.lines() {
+            let (before, after) = parse::complete(line, dependency)?;
+            ensure!(before != after, "a step can't depend on itself");
+
+            instructions.prerequisites[usize::from(after)] |= 1 << before;
+            instructions.steps |= 1 << before | 1 << after;
+        }
+
+        Ok(instructions)
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let instructions: Instructions = "Step C must be finished before step A can begin.
+Step C must be finished before step F can begin.
+Step A must be finished before step B can begin.
+Step A must be finished before step D can begin.
+Step B must be finished before step E can begin.
+Step D must be finished before step E can begin.
+Step F must be finished before step E can begin."
+        .parse()
+        .unwrap();
+
+    assert_eq!(instructions.assemble(1, 0).unwrap().0, "CABDFE");
+    assert_eq!(
+        instructions.assemble(2, 0).unwrap(),
+        ("CABFDE".to_string(), 15)
+    );
+}
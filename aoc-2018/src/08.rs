@@ -0,0 +1,70 @@
+use eyre::{OptionExt, Result, bail};
+
+use aoc_meta::Problem;
+
+pub const MEMORY_MANEUVER: Problem = Problem::solved(
+    &|input| parse_tree(input).map(|root| root.metadata_sum()),
+    &|input| parse_tree(input).map(|root| root.value()),
+);
+
+#[derive(Debug)]
+struct Node {
+    children: Vec<Node>,
+    metadata: Vec<usize>,
+}
+
+impl Node {
+    fn parse(numbers: &mut impl Iterator<Item = Result<usize>>) -> Result<Self> {
+        let mut next = || numbers.next().ok_or_eyre("the tree ended early")?;
+        let (children, metadata) = (next()?, next()?);
+
+        Ok(Self {
+            children: (0..children)
+                .map(|_| Self::parse(numbers))
+                .collect::<Result<_>>()?,
+            metadata: (0..metadata)
+                .map(|_| numbers.next().ok_or_eyre("the tree ended early")?)
+                .collect::<Result<_>>()?,
+        })
+    }
+
+    fn metadata_sum(&self) -> usize {
+        self.metadata.iter().sum::<usize>()
+            + self.children.iter().map(Node::metadata_sum).sum::<usize>()
+    }
+
+    /// The sum of the node's metadata if it has no children, or else the sum of the values of the children its
+    /// metadata points to (starting from 1).
+    fn value(&self) -> usize {
+        if self.children.is_empty() {
+            return self.metadata.iter().sum();
+        }
+
+        self.metadata
+            .iter()
+            .filter_map(|&i| self.children.get(i.checked_sub(1)?))
+            .map(Node::value)
+            .sum()
+    }
+}
+
+fn parse_tree(input: &str) -> Result<Node> {
+    let mut numbers = input.split_whitespace().map(|n| Ok(n.parse()?));
+    let root = Node::parse(&mut numbers)?;
+
+    if numbers.next().is_some() {
+        bail!("there's more after the end of the tree");
+    }
+
+    Ok(root)
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let root = parse_tree("2 3 0 3 10 11 12 1 1 0 1 99 2 1 1 2").unwrap();
+
+    assert_eq!(root.metadata_sum(), 138);
+    assert_eq!(root.value(), 66);
+}
@@ -0,0 +1,80 @@
+use eyre::{Result, bail};
+
+use aoc_meta::Problem;
+
+pub const MARBLE_MANIA: Problem = Problem::solved(
+    &|input| parse_game(input).map(|(players, last)| high_score(players, last)),
+    &|input| parse_game(input).map(|(players, last)| high_score(players, last * 100)),
+);
+
+fn parse_game(input: &str) -> Result<(usize, usize)> {
+    let words: Vec<&str> = input.split_whitespace().collect();
+
+    match words[..] {
+        [
+            players,
+            "players;",
+            "last",
+            "marble",
+            "is",
+            "worth",
+            last,
+            "points",
+        ] => Ok((players.parse()?, last.parse()?)),
+        _ => bail!("malformed game description"),
+    }
+}
+
+/// The winning score once the marble worth `last` points has been played.
+fn high_score(players: usize, last: usize) -> usize {
+    // The circle as a doubly linked list, indexed by marble.
+    let mut next = vec![0; last + 1];
+    let mut prev = vec![0; last + 1];
+    let players = players.max(1);
+    let mut scores = vec![0; players];
+    let mut current = 0;
+
+    for marble in 1..=last {
+        if marble.is_multiple_of(23) {
+            let removed = (0..7).fold(current, |m, _| prev[m]);
+            let (before, after) = (prev[removed], next[removed]);
+            next[before] = after;
+            prev[after] = before;
+
+            scores[marble % players] += marble + removed;
+            current = after;
+        } else {
+            let before = next[current];
+            let after = next[before];
+            next[before] = marble;
+            prev[marble] = before;
+            next[marble] = after;
+            prev[after] = marble;
+
+            current = marble;
+        }
+    }
+
+    scores.into_iter().max().unwrap_or(0)
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    assert_eq!(
+        parse_game("10 players; last marble is worth 1618 points").unwrap(),
+        (10, 1618)
+    );
+
+    for (players, last, score) in [
+        (9, 25, 32),
+        (10, 1618, 8317),
+        (13, 7999, 146373),
+        (17, 1104, 2764),
+        (21, 6111, 54718),
+        (30, 5807, 37305),
+    ] {
+        assert_eq!(high_score(players, last), score);
+    }
+}
@@ -0,0 +1,191 @@
+use eyre::{Result, ensure};
+use winnow::ascii::space0;
+use winnow::combinator::{delimited, preceded, separated_pair};
+use winnow::prelude::*;
+
+use aoc_common::ocr;
+use aoc_common::parse::{self, signed};
+use aoc_meta::Problem;
+
+pub const THE_STARS_ALIGN: Problem = Problem::solved(
+    &|input| {
+        let (points, _) = align(parse_points(input)?)?;
+        ocr::read_grid(draw(&points))
+    },
+    &|input| align(parse_points(input)?).map(|(_, seconds)| seconds),
+);
+
+type Point = ((i64, i64), (i64, i64));
+
+fn height(points: &[Point]) -> i64 {
+    let (top, bottom) = points
+        .iter()
+        .fold((i64::MAX, i64::MIN), |(top, bottom), &((_, y), _)| {
+            (top.min(y), bottom.max(y))
+        });
+    bottom - top
+}
+
+/// Move the points until they're as close together vertically as they'll ever be, which is when they spell out
+/// the message. Returns where they are then, and how many seconds that took.
+fn align(mut points: Vec<Point>) -> Result<(Vec<Point>, usize)> {
+    ensure!(!points.is_empty(), "there are no points of light");
+    let mut seconds = 0;
+
+    loop {
+        let next: Vec<Point> = points
+            .iter()
+            .map(|&((x, y), (dx, dy))| ((x + dx, y + dy), (dx, dy)))
+            .collect();
+
+        if height(&next) >= height(&points) {
+            return Ok((points, seconds));
+        }
+
+        points = next;
+        seconds += 1;
+    }
+}
+
+/// The points as rows of pixels, from the top left corner of the box around them.
+fn draw(points: &[Point]) -> Vec<Vec<bool>> {
+    let (left, top) = points
+        .iter()
+        .fold((i64::MAX, i64::MAX), |(left, top), &((x, y), _)| {
+            (left.min(x), top.min(y))
+        });
+    let (right, bottom) = points
+        .iter()
+        .fold((i64::MIN, i64::MIN), |(r, b), &((x, y), _)| {
+            (r.max(x), b.max(y))
+        });
+
+    let mut pixels = vec![vec![false; (right - left + 1) as usize]; (bottom - top + 1) as usize];
+    for &((x, y), _) in points {
+        pixels[(y - top) as usize][(x - left) as usize] = true;
+    }
+
+    pixels
+}
+
+fn point(input: &mut &str) -> ModalResult<Point> {
+    let pair = || {
+        delimited(
+            ('<', space0),
+            separated_pair(signed, (',', space0), signed),
+            '>',
+        )
+    };
+
+    separated_pair(
+        preceded("position=", pair()),
+        ' ',
+        preceded("velocity=", pair()),
+    )
+    .parse_next(input)
+}
+
+fn parse_points(input: &str) -> Result<Vec<Point>> {
+    parse::lines(input, point).collect()
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let (points, seconds) = align(
+        parse_points(
+            "position=< 9,  1> velocity=< 0,  2>
+position=< 7,  0> velocity=<-1,  0>
+position=< 3, -2> velocity=<-1,  1>
+position=< 6, 10> velocity=<-2, -1>
+position=< 2, -4> velocity=< 2,  2>
+position=<-6, 10> velocity=< 2, -2>
+position=< 1,  8> velocity=< 1, -1>
+position=< 1,  7> velocity=< 1,  0>
+position=<-3, 11> velocity=< 1, -2>
+position=< 7,  6> velocity=<-1, -1>
+position=<-2,  3> velocity=< 1,  0>
+position=<-4,  3> velocity=< 2,  0>
+position=<10, -3> velocity=<-1,  1>
+position=< 5, 11> velocity=< 1, -2>
+position=< 4,  7> velocity=< 0, -1>
+position=< 8, -2> velocity=< 0,  1>
+position=<15,  0> velocity=<-2,  0>
+position=< 1,  6> velocity=< 1,  0>
+position=< 8,  9> velocity=< 0, -1>
+position=< 3,  3> velocity=<-1,  1>
+position=< 0,  5> velocity=< 0, -1>
+position=<-2,  2> velocity=< 2,  0>
+position=< 5, -2> velocity=< 1,  2>
+position=< 1,  4> velocity=< 2,  1>
+position=<-2,  7> velocity=< 2, -2>
+position=< 3,  6> velocity=<-1, -1>
+position=< 5,  0> velocity=< 1,  0>
+position=<-6,  0> velocity=< 2,  0>
+position=< 5,  9> velocity=< 1, -2>
+position=<14,  7> velocity=<-2,  0>
+position=<-3,  6> velocity=< 2, -1>",
+        )
+        .unwrap(),
+    )
+    .unwrap();
+
+    // The example's "HI" is only 8 pixels high, smaller than any font the real messages use.
+    let pixels: Vec<String> = draw(&points)
+        .into_iter()
+        .map(|row| {
+            row.into_iter()
+                .map(|on| if on { '#' } else { '.' })
+                .collect()
+        })
+        .collect();
+
+    assert_eq!(seconds, 3);
+    assert_eq!(
+        pixels,
+        [
+            "#...#..###",
+            "#...#...#.",
+            "#...#...#.",
+            "#####...#.",
+            "#...#...#.",
+            "#...#...#.",
+            "#...#...#.",
+            "#...#..###",
+        ]
+    );
+
+    // Scatter the points of a message in the large font instead, and make sure they come back together.
+    let message = [
+        "#....#..#####.",
+        "#....#..#....#",
+        "#....#..#....#",
+        "#....#..#....#",
+        "######..#####.",
+        "#....#..#....#",
+        "#....#..#....#",
+        "#....#..#....#",
+        "#....#..#....#",
+        "#....#..#####.",
+    ];
+    let points: Vec<Point> = message
+        .iter()
+        .zip(0..)
+        .flat_map(|(row, y)| {
+            row.bytes()
+                .zip(0..)
+                .filter(|&(b, _)| b == b'#')
+                .map(move |(_, x)| (x, y))
+        })
+        .zip(0..)
+        .map(|((x, y), i)| {
+            let velocity = (i % 7 - 3, i * 5 % 9 - 4);
+            ((x - velocity.0 * 50, y - velocity.1 * 50), velocity)
+        })
+        .collect();
+
+    let (aligned, seconds) = align(points).unwrap();
+    assert_eq!(seconds, 50);
+    assert_eq!(ocr::read_grid(draw(&aligned)).unwrap(), "HB");
+}
@@ -0,0 +1,91 @@
+use rayon::prelude::*;
+
+use aoc_meta::Problem;
+
+pub const CHRONAL_CHARGE: Problem = Problem::solved(
+    &|input| {
+        input.trim().parse().map(|serial| {
+            let (x, y, _) = Grid::new(serial).best_square(3);
+            format!("{x},{y}")
+        })
+    },
+    &|input| {
+        input.trim().parse().map(|serial| {
+            let (x, y, size) = Grid::new(serial).best_square_of_any_size();
+            format!("{x},{y},{size}")
+        })
+    },
+);
+
+const SIZE: usize = 300;
+
+/// A summed-area table of the fuel cells' power levels, so any square's total power is four lookups away.
+struct Grid {
+    sums: Vec<[i32; SIZE + 1]>,
+}
+
+impl Grid {
+    fn new(serial: i32) -> Self {
+        let mut sums = vec![[0; SIZE + 1]; SIZE + 1];
+
+        for y in 1..=SIZE {
+            for x in 1..=SIZE {
+                sums[y][x] =
+                    power_level(x as i32, y as i32, serial) + sums[y - 1][x] + sums[y][x - 1]
+                        - sums[y - 1][x - 1];
+            }
+        }
+
+        Self { sums }
+    }
+
+    /// The total power of the `size`×`size` square with its top left cell at (`x`, `y`).
+    fn square(&self, x: usize, y: usize, size: usize) -> i32 {
+        let (right, bottom) = (x + size - 1, y + size - 1);
+        self.sums[bottom][right] - self.sums[y - 1][right] - self.sums[bottom][x - 1]
+            + self.sums[y - 1][x - 1]
+    }
+
+    /// The top left corner of the `size`×`size` square with the most power, and that power.
+    fn best_square(&self, size: usize) -> (usize, usize, i32) {
+        (1..=SIZE + 1 - size)
+            .flat_map(|y| (1..=SIZE + 1 - size).map(move |x| (x, y)))
+            .map(|(x, y)| (x, y, self.square(x, y, size)))
+            .max_by_key(|&(_, _, power)| power)
+            .unwrap_or_default()
+    }
+
+    /// The top left corner and size of the square with the most power.
+    fn best_square_of_any_size(&self) -> (usize, usize, usize) {
+        let (size, (x, y, _)) = (1..=SIZE)
+            .into_par_iter()
+            .map(|size| (size, self.best_square(size)))
+            .max_by_key(|&(_, (_, _, power))| power)
+            .unwrap_or_default();
+
+        (x, y, size)
+    }
+}
+
+fn power_level(x: i32, y: i32, serial: i32) -> i32 {
+    let rack = x + 10;
+    (rack * y + serial) * rack / 100 % 10 - 5
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    assert_eq!(power_level(3, 5, 8), 4);
+    assert_eq!(power_level(122, 79, 57), -5);
+    assert_eq!(power_level(217, 196, 39), 0);
+    assert_eq!(power_level(101, 153, 71), 4);
+
+    let grid = Grid::new(18);
+    assert_eq!(grid.best_square(3), (33, 45, 29));
+    assert_eq!(grid.best_square_of_any_size(), (90, 269, 16));
+
+    let grid = Grid::new(42);
+    assert_eq!(grid.best_square(3), (21, 61, 30));
+    assert_eq!(grid.best_square_of_any_size(), (232, 251, 12));
+}
@@ -0,0 +1,155 @@
+use eyre::{OptionExt, Result, bail};
+
+use aoc_meta::Problem;
+
+pub const SUBTERRANEAN_SUSTAINABILITY: Problem = Problem::solved(
+    &|input| input.parse::<Tunnel>().map(|tunnel| tunnel.sum_after(20)),
+    &|input| {
+        input
+            .parse::<Tunnel>()
+            .map(|tunnel| tunnel.sum_after(50_000_000_000))
+    },
+);
+
+#[derive(Debug, Clone)]
+struct Tunnel {
+    /// The pots from the leftmost plant to the rightmost one.
+    pots: Vec<bool>,
+    /// The number of the first pot in `pots`.
+    offset: i64,
+    /// Whether a pot has a plant next generation, indexed by the five pots around it as bits, leftmost first.
+    rules: [bool; 32],
+}
+
+impl Tunnel {
+    fn step(&mut self) {
+        let mut pots = Vec::with_capacity(self.pots.len() + 4);
+        let mut window = 0;
+
+        // Every pot that could grow a plant is within two of an existing one.
+        for i in 0..self.pots.len() + 4 {
+            window = (window << 1 | usize::from(self.pots.get(i).copied().unwrap_or(false))) & 31;
+            pots.push(self.rules[window]);
+        }
+
+        self.offset -= 2;
+        self.pots = pots;
+        self.trim();
+    }
+
+    fn trim(&mut self) {
+        let Some(first) = self.pots.iter().position(|&pot| pot) else {
+            self.pots.clear();
+            return;
+        };
+        let last = self.pots.iter().rposition(|&pot| pot).unwrap_or(first);
+
+        self.pots.truncate(last + 1);
+        self.pots.drain(..first);
+        self.offset += first as i64;
+    }
+
+    fn sum(&self) -> i64 {
+        (self.offset..)
+            .zip(&self.pots)
+            .filter(|&(_, &pot)| pot)
+            .map(|(number, _)| number)
+            .sum()
+    }
+
+    /// The sum of the numbers of the pots with plants after `generations`. The plants settle into a pattern that
+    /// just slides along the row every generation, so once that's happened the rest can be skipped.
+    fn sum_after(mut self, generations: u64) -> i64 {
+        for generation in 0..generations {
+            let before = self.clone();
+            self.step();
+
+            if self.pots == before.pots {
+                let remaining = (generations - generation - 1) as i64;
+                let plants = self.pots.iter().filter(|&&pot| pot).count() as i64;
+                return self.sum() + remaining * (self.offset - before.offset) * plants;
+            }
+        }
+
+        self.sum()
+    }
+}
+
+impl std::str::FromStr for Tunnel {
+    type Err = eyre::Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let pot = |c| match c {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            _ => bail!("unexpected pot {c:?}"),
+        };
+
+        let mut lines = s.lines();
+        let pots = lines
+            .next()
+            .and_then(|line| line.strip_prefix("initial state: "))
+            .ok_or_eyre("missing initial state")?
+            .chars()
+            .map(pot)
+            .collect::<Result<_>>()?;
+
+        let mut rules = [false; 32];
+        for line in lines.filter(|line| !line.is_empty()) {
+            let (pattern, result) = line.split_once(" => ").ok_or_eyre("malformed rule")?;
+            let index = pattern.chars().try_fold(0, |index, c| {
+                pot(c).map(|plant| index << 1 | usize::from(plant))
+            })?;
+            rules[index & 31] = result
+                .chars()
+                .next()
+                .map(pot)
+                .ok_or_eyre("malformed rule")??;
+        }
+
+        if rules[0] {
+            bail!("plants would grow in infinitely many empty pots");
+        }
+
+        let mut tunnel = Self {
+            pots,
+            offset: 0,
+            rules,
+        };
+        tunnel.trim();
+        Ok(tunnel)
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let tunnel: Tunnel = "initial state: #..#.#..##......###...###
+
+...## => #
+..#.. => #
+.#... => #
+.#.#. => #
+.#.## => #
+.##.. => #
+.#### => #
+#.#.# => #
+#.### => #
+##.#. => #
+##.## => #
+###.. => #
+###.# => #
+####. => #"
+        .parse()
+        .unwrap();
+
+    assert_eq!(tunnel.clone().sum_after(20), 325);
+
+    // Skipping ahead has to give the same answer as simulating every generation.
+    let mut simulated = tunnel.clone();
+    for _ in 0..200 {
+        simulated.step();
+    }
+    assert_eq!(tunnel.sum_after(200), simulated.sum());
+}
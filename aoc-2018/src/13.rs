@@ -0,0 +1,173 @@
+use eyre::{Result, bail, eyre};
+
+use aoc_meta::Problem;
+
+pub const MINE_CART_MADNESS: Problem = Problem::solved(
+    &|input| {
+        let mut mine: Mine = input.parse()?;
+        loop {
+            if let Some((x, y)) = mine.tick()?.first() {
+                break Ok::<_, eyre::Report>(format!("{x},{y}"));
+            }
+        }
+    },
+    &|input| {
+        let mut mine: Mine = input.parse()?;
+        let (x, y) = mine.last_cart()?;
+        Ok::<_, eyre::Report>(format!("{x},{y}"))
+    },
+);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Cart {
+    position: (usize, usize),
+    direction: (isize, isize),
+    /// How many intersections the cart has been through, which decides which way it turns at the next one.
+    intersections: usize,
+}
+
+#[derive(Debug, Clone)]
+struct Mine {
+    tracks: Vec<Vec<u8>>,
+    carts: Vec<Cart>,
+}
+
+impl Mine {
+    /// Move every cart one step, in order from the top left. Carts that crash are taken off the tracks, and the
+    /// places they crashed are returned.
+    fn tick(&mut self) -> Result<Vec<(usize, usize)>> {
+        self.carts
+            .sort_unstable_by_key(|cart| (cart.position.1, cart.position.0));
+        let mut crashed = vec![false; self.carts.len()];
+        let mut crashes = Vec::new();
+
+        for i in 0..self.carts.len() {
+            if crashed[i] {
+                continue;
+            }
+
+            let cart = &mut self.carts[i];
+            let (x, y) = cart.position;
+            let position = (
+                x.checked_add_signed(cart.direction.0)
+                    .ok_or_else(|| eyre!("a cart left the mine at {x},{y}"))?,
+                y.checked_add_signed(cart.direction.1)
+                    .ok_or_else(|| eyre!("a cart left the mine at {x},{y}"))?,
+            );
+
+            let (dx, dy) = cart.direction;
+            cart.direction = match self
+                .tracks
+                .get(position.1)
+                .and_then(|row| row.get(position.0))
+            {
+                Some(b'-' | b'|') => (dx, dy),
+                Some(b'/') => (-dy, -dx),
+                Some(b'\\') => (dy, dx),
+                Some(b'+') => {
+                    cart.intersections += 1;
+                    match cart.intersections % 3 {
+                        1 => (dy, -dx),
+                        2 => (dx, dy),
+                        _ => (-dy, dx),
+                    }
+                }
+                _ => bail!("a cart ran off the tracks at {},{}", position.0, position.1),
+            };
+            cart.position = position;
+
+            if let Some(other) = (0..self.carts.len())
+                .find(|&j| j != i && !crashed[j] && self.carts[j].position == position)
+            {
+                crashed[i] = true;
+                crashed[other] = true;
+                crashes.push(position);
+            }
+        }
+
+        let mut crashed = crashed.into_iter();
+        self.carts.retain(|_| !crashed.next().unwrap_or(false));
+
+        Ok(crashes)
+    }
+
+    /// Where the last cart left is once all the others have crashed.
+    fn last_cart(&mut self) -> Result<(usize, usize)> {
+        if self.carts.len().is_multiple_of(2) {
+            bail!("there's an even number of carts, so they could all crash");
+        }
+
+        while self.carts.len() > 1 {
+            self.tick()?;
+        }
+
+        Ok(self.carts[0].position)
+    }
+}
+
+impl std::str::FromStr for Mine {
+    type Err = eyre::Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut tracks: Vec<Vec<u8>> = s.lines().map(|line| line.as_bytes().to_vec()).collect();
+        let mut carts = Vec::new();
+
+        for (y, row) in tracks.iter_mut().enumerate() {
+            for (x, tile) in row.iter_mut().enumerate() {
+                let (direction, track) = match tile {
+                    b'^' => ((0, -1), b'|'),
+                    b'v' => ((0, 1), b'|'),
+                    b'<' => ((-1, 0), b'-'),
+                    b'>' => ((1, 0), b'-'),
+                    _ => continue,
+                };
+
+                *tile = track;
+                carts.push(Cart {
+                    position: (x, y),
+                    direction,
+                    intersections: 0,
+                });
+            }
+        }
+
+        if carts.is_empty() {
+            bail!("there are no carts");
+        }
+
+        Ok(Self { tracks, carts })
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let mut mine: Mine = r"/->-\
+|   |  /----\
+| /-+--+-\  |
+| | |  | v  |
+\-+-/  \-+--/
+  \------/"
+        .parse()
+        .unwrap();
+
+    let first_crash = loop {
+        if let Some(&crash) = mine.tick().unwrap().first() {
+            break crash;
+        }
+    };
+    assert_eq!(first_crash, (7, 3));
+
+    let mut mine: Mine = r"/>-<\
+|   |
+| /<+-\
+| | | v
+\>+</ |
+  |   ^
+  \<->/"
+        .parse()
+        .unwrap();
+
+    assert_eq!(mine.last_cart().unwrap(), (6, 4));
+}
@@ -0,0 +1,94 @@
+use eyre::{Result, ensure};
+
+use aoc_meta::Problem;
+
+pub const CHOCOLATE_CHARTS: Problem =
+    Problem::solved(&|input| input.trim().parse().map(scores_after), &|input| {
+        recipes_before(input.trim())
+    });
+
+/// The scoreboard the elves write new recipes onto, and the two recipes they're currently on.
+struct Scoreboard {
+    scores: Vec<u8>,
+    elves: (usize, usize),
+}
+
+impl Scoreboard {
+    fn new() -> Self {
+        Self {
+            scores: vec![3, 7],
+            elves: (0, 1),
+        }
+    }
+
+    /// Combine the elves' current recipes, returning how many new recipes that added.
+    fn step(&mut self) -> usize {
+        let (a, b) = (self.scores[self.elves.0], self.scores[self.elves.1]);
+        let sum = a + b;
+
+        let added = if sum >= 10 {
+            self.scores.extend([sum / 10, sum % 10]);
+            2
+        } else {
+            self.scores.push(sum);
+            1
+        };
+
+        self.elves.0 = (self.elves.0 + 1 + usize::from(a)) % self.scores.len();
+        self.elves.1 = (self.elves.1 + 1 + usize::from(b)) % self.scores.len();
+        added
+    }
+}
+
+/// The scores of the ten recipes after the first `recipes`.
+fn scores_after(recipes: usize) -> String {
+    let mut scoreboard = Scoreboard::new();
+    while scoreboard.scores.len() < recipes + 10 {
+        scoreboard.step();
+    }
+
+    scoreboard.scores[recipes..recipes + 10]
+        .iter()
+        .map(|score| char::from(b'0' + score))
+        .collect()
+}
+
+/// How many recipes there are on the scoreboard before `digits` first appears.
+fn recipes_before(digits: &str) -> Result<usize> {
+    let pattern: Vec<u8> = digits
+        .bytes()
+        .map(|digit| digit.wrapping_sub(b'0'))
+        .collect();
+    ensure!(
+        !pattern.is_empty() && pattern.iter().all(|&digit| digit < 10),
+        "expected a sequence of digits"
+    );
+
+    let mut scoreboard = Scoreboard::new();
+    loop {
+        let added = scoreboard.step();
+        let len = scoreboard.scores.len();
+
+        // One step can add two recipes, so the pattern could end just before the last one.
+        for end in len + 1 - added..=len {
+            if end >= pattern.len() && scoreboard.scores[end - pattern.len()..end] == pattern[..] {
+                return Ok(end - pattern.len());
+            }
+        }
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    assert_eq!(scores_after(9), "5158916779");
+    assert_eq!(scores_after(5), "0124515891");
+    assert_eq!(scores_after(18), "9251071085");
+    assert_eq!(scores_after(2018), "5941429882");
+
+    assert_eq!(recipes_before("51589").unwrap(), 9);
+    assert_eq!(recipes_before("01245").unwrap(), 5);
+    assert_eq!(recipes_before("92510").unwrap(), 18);
+    assert_eq!(recipes_before("59414").unwrap(), 2018);
+}
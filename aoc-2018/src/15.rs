@@ -0,0 +1,274 @@
+use std::collections::VecDeque;
+
+use eyre::{Result, bail, ensure};
+
+use aoc_meta::Problem;
+
+pub const BEVERAGE_BANDITS: Problem = Problem::solved(
+    &|input| {
+        input
+            .parse::<Cave>()
+            .map(|cave| cave.fight(3, false).unwrap_or_default())
+    },
+    &|input| {
+        input
+            .parse::<Cave>()
+            .and_then(|cave| cave.elves_barely_win())
+    },
+);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Elf,
+    Goblin,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Unit {
+    kind: Kind,
+    /// Index into the cave's squares, which puts units in reading order when sorted.
+    position: usize,
+    hit_points: u32,
+}
+
+#[derive(Debug, Clone)]
+struct Cave {
+    width: usize,
+    walls: Vec<bool>,
+    units: Vec<Unit>,
+}
+
+impl Cave {
+    /// Let the battle play out with elves of the given attack power, returning its outcome: the number of full
+    /// rounds fought times the hit points left. With `elves_must_survive`, gives up as soon as any elf dies.
+    fn fight(mut self, elf_attack: u32, elves_must_survive: bool) -> Option<usize> {
+        let mut occupied: Vec<bool> = vec![false; self.walls.len()];
+        for unit in &self.units {
+            occupied[unit.position] = true;
+        }
+
+        for round in 0.. {
+            self.units.sort_unstable_by_key(|unit| unit.position);
+
+            for i in 0..self.units.len() {
+                let unit = self.units[i];
+                if unit.hit_points == 0 {
+                    continue;
+                }
+
+                let is_enemy = |other: &Unit| other.kind != unit.kind && other.hit_points > 0;
+                if !self.units.iter().any(is_enemy) {
+                    let hit_points: u32 = self.units.iter().map(|unit| unit.hit_points).sum();
+                    return Some(round * hit_points as usize);
+                }
+
+                if let Some(position) = self.step_towards_enemy(unit, &occupied) {
+                    occupied[unit.position] = false;
+                    occupied[position] = true;
+                    self.units[i].position = position;
+                }
+
+                let position = self.units[i].position;
+                let target = self
+                    .units
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, other)| {
+                        is_enemy(other) && self.neighbours(position).contains(&other.position)
+                    })
+                    .min_by_key(|(_, other)| (other.hit_points, other.position))
+                    .map(|(j, _)| j);
+
+                if let Some(j) = target {
+                    let attack = if unit.kind == Kind::Elf {
+                        elf_attack
+                    } else {
+                        3
+                    };
+                    let target = &mut self.units[j];
+                    target.hit_points = target.hit_points.saturating_sub(attack);
+
+                    if target.hit_points == 0 {
+                        occupied[target.position] = false;
+                        if target.kind == Kind::Elf && elves_must_survive {
+                            return None;
+                        }
+                    }
+                }
+            }
+
+            self.units.retain(|unit| unit.hit_points > 0);
+        }
+
+        unreachable!()
+    }
+
+    /// The outcome of the battle with the weakest elves that can still win it without losing anyone.
+    fn elves_barely_win(self) -> Result<usize> {
+        for attack in 4..=200 {
+            if let Some(outcome) = self.clone().fight(attack, true) {
+                return Ok(outcome);
+            }
+        }
+
+        bail!("the elves can't win without losses even with 200 attack power")
+    }
+
+    /// Where `unit` should move to get closer to the nearest square in range of an enemy, if anywhere.
+    fn step_towards_enemy(&self, unit: Unit, occupied: &[bool]) -> Option<usize> {
+        let in_range: Vec<usize> = self
+            .units
+            .iter()
+            .filter(|other| other.kind != unit.kind && other.hit_points > 0)
+            .flat_map(|enemy| self.neighbours(enemy.position))
+            .collect();
+
+        if in_range.contains(&unit.position) {
+            return None;
+        }
+
+        let distances = self.distances(unit.position, occupied);
+        let target = in_range
+            .into_iter()
+            .filter(|&position| !occupied[position])
+            .filter_map(|position| distances[position].map(|distance| (distance, position)))
+            .min()?
+            .1;
+
+        // Take whichever first step is on a shortest path to the target, going back from there.
+        let distances = self.distances(target, occupied);
+        self.neighbours(unit.position)
+            .into_iter()
+            .filter_map(|position| distances[position].map(|distance| (distance, position)))
+            .min()
+            .map(|(_, position)| position)
+    }
+
+    /// How far every open square is from `from`, walking around walls and units.
+    fn distances(&self, from: usize, occupied: &[bool]) -> Vec<Option<usize>> {
+        let mut distances = vec![None; self.walls.len()];
+        let mut queue = VecDeque::from([from]);
+        distances[from] = Some(0);
+
+        while let Some(position) = queue.pop_front() {
+            let distance = distances[position].unwrap_or_default();
+
+            for next in self.neighbours(position) {
+                if !occupied[next] && distances[next].is_none() {
+                    distances[next] = Some(distance + 1);
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        distances
+    }
+
+    /// The squares next to `position` in reading order, with any walls swapped for `position` itself.
+    fn neighbours(&self, position: usize) -> [usize; 4] {
+        // The cave is walled in, so no unit stands on its edge.
+        [
+            position - self.width,
+            position - 1,
+            position + 1,
+            position + self.width,
+        ]
+        .map(|next| if self.walls[next] { position } else { next })
+    }
+}
+
+impl std::str::FromStr for Cave {
+    type Err = eyre::Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let width = s.lines().next().map_or(0, str::len);
+        let mut walls = Vec::new();
+        let mut units = Vec::new();
+
+        for line in s.lines() {
+            ensure!(line.len() == width, "the cave isn't rectangular");
+
+            for tile in line.bytes() {
+                let kind = match tile {
+                    b'#' => None,
+                    b'.' => Some(None),
+                    b'E' => Some(Some(Kind::Elf)),
+                    b'G' => Some(Some(Kind::Goblin)),
+                    _ => bail!("unexpected tile {:?}", char::from(tile)),
+                };
+
+                if let Some(Some(kind)) = kind {
+                    units.push(Unit {
+                        kind,
+                        position: walls.len(),
+                        hit_points: 200,
+                    });
+                }
+                walls.push(kind.is_none());
+            }
+        }
+
+        let height = walls.len() / width.max(1);
+        let edge = |position: usize| {
+            let (x, y) = (position % width, position / width);
+            x == 0 || y == 0 || x == width - 1 || y == height - 1
+        };
+        ensure!(
+            (0..walls.len())
+                .filter(|&position| edge(position))
+                .all(|position| walls[position]),
+            "the cave isn't surrounded by walls"
+        );
+
+        Ok(Self {
+            width,
+            walls,
+            units,
+        })
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    for (cave, outcome, barely) in [
+        (
+            "#######\n#.G...#\n#...EG#\n#.#.#G#\n#..G#E#\n#.....#\n#######",
+            27730,
+            Some(4988),
+        ),
+        (
+            "#######\n#G..#E#\n#E#E.E#\n#G.##.#\n#...#E#\n#...E.#\n#######",
+            36334,
+            None,
+        ),
+        (
+            "#######\n#E..EG#\n#.#G.E#\n#E.##E#\n#G..#.#\n#..E#.#\n#######",
+            39514,
+            Some(31284),
+        ),
+        (
+            "#######\n#E.G#.#\n#.#G..#\n#G.#.G#\n#G..#.#\n#...E.#\n#######",
+            27755,
+            Some(3478),
+        ),
+        (
+            "#######\n#.E...#\n#.#..G#\n#.###.#\n#E#G#G#\n#...#G#\n#######",
+            28944,
+            Some(6474),
+        ),
+        (
+            "#########\n#G......#\n#.E.#...#\n#..##..G#\n#...##..#\n#...#...#\n#.G...G.#\n#.....G.#\n#########",
+            18740,
+            Some(1140),
+        ),
+    ] {
+        let cave: Cave = cave.parse().unwrap();
+        assert_eq!(cave.clone().fight(3, false), Some(outcome));
+
+        if let Some(barely) = barely {
+            assert_eq!(cave.elves_barely_win().unwrap(), barely);
+        }
+    }
+}
@@ -0,0 +1,113 @@
+use eyre::{OptionExt, Result, eyre};
+
+use aoc_meta::Problem;
+
+use crate::elfcode::{self, Opcode, Sample};
+
+pub const CHRONAL_CLASSIFICATION: Problem = Problem::solved(
+    &|input| {
+        let (samples, _) = parse_manual(input)?;
+        Ok::<_, eyre::Report>(
+            samples
+                .iter()
+                .filter(|sample| sample.candidates().count_ones() >= 3)
+                .count(),
+        )
+    },
+    &|input| {
+        let (samples, program) = parse_manual(input)?;
+        run(&elfcode::infer_opcodes(&samples)?, &program).map(|registers| registers[0])
+    },
+);
+
+/// Run a program of numbered instructions on the four registers of the device, given what each number stands for.
+fn run(opcodes: &[Opcode; 16], program: &[[u64; 4]]) -> Result<[u64; 4]> {
+    let mut registers = [0; 4];
+
+    for &[number, a, b, c] in program {
+        let opcode = opcodes
+            .get(number as usize)
+            .ok_or_else(|| eyre!("there's no opcode {number}"))?;
+        opcode
+            .apply(&mut registers, a, b, c)
+            .ok_or_else(|| eyre!("{opcode} {a} {b} {c} refers to a register that doesn't exist"))?;
+    }
+
+    Ok(registers)
+}
+
+/// The samples from the first section of the manual, and the test program from the second.
+fn parse_manual(input: &str) -> Result<(Vec<Sample>, Vec<[u64; 4]>)> {
+    let (samples, program) = input
+        .split_once("\n\n\n\n")
+        .ok_or_eyre("expected samples, then a program")?;
+
+    let samples = samples
+        .split("\n\n")
+        .map(str::parse)
+        .collect::<Result<_>>()?;
+    let program = program
+        .lines()
+        .map(|line| {
+            let numbers: Vec<u64> = line
+                .split_whitespace()
+                .map(str::parse)
+                .collect::<Result<_, _>>()?;
+            numbers
+                .try_into()
+                .map_err(|_| eyre!("instructions are four numbers"))
+        })
+        .collect::<Result<_>>()?;
+
+    Ok((samples, program))
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let (samples, program) = parse_manual(
+        "Before: [3, 2, 1, 1]
+9 2 1 2
+After:  [3, 2, 2, 1]
+
+
+
+9 2 1 2
+9 0 0 3",
+    )
+    .unwrap();
+
+    assert_eq!(samples.len(), 1);
+    assert_eq!(samples[0].candidates().count_ones(), 3);
+    assert_eq!(program, [[9, 2, 1, 2], [9, 0, 0, 3]]);
+
+    // Number the opcodes backwards, then see if samples of them in action are enough to number them again.
+    let numbering: [Opcode; 16] = std::array::from_fn(|number| Opcode::ALL[15 - number]);
+    let samples: Vec<Sample> = (0..16u64)
+        .flat_map(|number| (0..8u64).map(move |seed| (number, seed)))
+        .map(|(number, seed)| {
+            let before = [seed % 4, seed * 3 % 5, seed * 7 % 11 + 1, seed % 3];
+            let instruction = [number, seed % 4, (seed + 1) % 4, (seed + 2) % 4];
+
+            let mut after = before;
+            numbering[number as usize].apply(
+                &mut after,
+                instruction[1],
+                instruction[2],
+                instruction[3],
+            );
+            Sample {
+                before,
+                instruction,
+                after,
+            }
+        })
+        .collect();
+
+    assert_eq!(elfcode::infer_opcodes(&samples).unwrap(), numbering);
+
+    // 6 is seti, 15 is addr and 3 is gtrr, so this adds 2 to register 0 twice, then checks it's more than 2.
+    let program = [[6, 2, 0, 1], [15, 0, 1, 0], [15, 0, 1, 0], [3, 0, 1, 2]];
+    assert_eq!(run(&numbering, &program).unwrap(), [4, 2, 1, 0]);
+}
@@ -0,0 +1,140 @@
+use eyre::{Result, ensure};
+use winnow::combinator::{alt, opt, preceded};
+use winnow::prelude::*;
+
+use aoc_common::parse::{self, unsigned};
+use aoc_meta::Problem;
+
+pub const RESERVOIR_RESEARCH: Problem = Problem::solved(
+    &|input| {
+        Ground::parse(input).map(|ground| ground.flooded().count(&[Tile::Flowing, Tile::Settled]))
+    },
+    &|input| Ground::parse(input).map(|ground| ground.flooded().count(&[Tile::Settled])),
+);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    Sand,
+    Clay,
+    Flowing,
+    Settled,
+}
+
+#[derive(Debug, Clone)]
+struct Ground {
+    /// Rows from y = 0 down to the lowest clay, with one column of sand either side of the clay.
+    tiles: Vec<Vec<Tile>>,
+    /// The x coordinate of the first column.
+    left: usize,
+    /// The y coordinate of the highest clay.
+    top: usize,
+}
+
+impl Ground {
+    /// Let the water from the spring flow until it can't reach anywhere new.
+    fn flooded(mut self) -> Self {
+        let spring = 500 - self.left;
+        self.flow(spring, 0, 0);
+        self
+    }
+
+    /// Water reaching (`x`, `y`) falls if it can and spreads if it can't, in the direction `dx` or both ways if
+    /// that's zero, settling if it ends up with clay on both sides. Returns the column the water spread to.
+    fn flow(&mut self, x: usize, y: usize, dx: isize) -> usize {
+        match self.tiles[y][x] {
+            Tile::Clay => return x,
+            Tile::Sand => self.tiles[y][x] = Tile::Flowing,
+            _ => {}
+        }
+
+        if y + 1 == self.tiles.len() {
+            return x;
+        }
+
+        if self.tiles[y + 1][x] == Tile::Sand {
+            self.flow(x, y + 1, 0);
+        }
+
+        if matches!(self.tiles[y + 1][x], Tile::Clay | Tile::Settled) {
+            if dx != 0 {
+                return self.flow(x.saturating_add_signed(dx), y, dx);
+            }
+
+            let left = self.flow(x - 1, y, -1);
+            let right = self.flow(x + 1, y, 1);
+
+            if self.tiles[y][left] == Tile::Clay && self.tiles[y][right] == Tile::Clay {
+                self.tiles[y][left + 1..right].fill(Tile::Settled);
+            }
+        }
+
+        x
+    }
+
+    /// How many tiles of the given kinds are level with or below the highest clay.
+    fn count(&self, kinds: &[Tile]) -> usize {
+        self.tiles[self.top..]
+            .iter()
+            .flatten()
+            .filter(|tile| kinds.contains(tile))
+            .count()
+    }
+
+    fn parse(input: &str) -> Result<Self> {
+        let veins: Vec<_> = parse::lines(input, vein).collect::<Result<_>>()?;
+        ensure!(!veins.is_empty(), "there's no clay");
+
+        let (mut left, mut right, mut top, mut bottom) = (500, 500, usize::MAX, 0);
+        for &(xs, ys) in &veins {
+            (left, right) = (left.min(xs.0), right.max(xs.1));
+            (top, bottom) = (top.min(ys.0), bottom.max(ys.1));
+        }
+        ensure!(left > 0, "the clay is too close to the edge of the scan");
+
+        left -= 1;
+        let mut tiles = vec![vec![Tile::Sand; right + 2 - left]; bottom + 1];
+        for ((x0, x1), (y0, y1)) in veins {
+            for row in &mut tiles[y0..=y1] {
+                row[x0 - left..=x1 - left].fill(Tile::Clay);
+            }
+        }
+
+        Ok(Self { tiles, left, top })
+    }
+}
+
+type Range = (usize, usize);
+
+/// A vein of clay as the ranges of x and y coordinates it covers, from a line like `x=495, y=2..7`.
+fn vein(input: &mut &str) -> ModalResult<(Range, Range)> {
+    let range = || {
+        (unsigned, opt(preceded("..", unsigned))).map(|(start, end)| (start, end.unwrap_or(start)))
+    };
+
+    alt((
+        (preceded("x=", range()), preceded(", y=", range())),
+        (preceded("y=", range()), preceded(", x=", range())).map(|(ys, xs)| (xs, ys)),
+    ))
+    .parse_next(input)
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let ground = Ground::parse(
+        "x=495, y=2..7
+y=7, x=495..501
+x=501, y=3..7
+x=498, y=2..4
+x=506, y=1..2
+x=498, y=10..13
+x=504, y=10..13
+y=13, x=498..504",
+    )
+    .unwrap()
+    .flooded();
+
+    assert_eq!(ground.count(&[Tile::Flowing, Tile::Settled]), 57);
+    assert_eq!(ground.count(&[Tile::Settled]), 29);
+}
@@ -0,0 +1,126 @@
+use eyre::{Result, bail, ensure};
+
+use aoc_common::cycle;
+use aoc_meta::Problem;
+
+pub const SETTLERS_OF_THE_NORTH_POLE: Problem = Problem::solved(
+    &|input| {
+        input
+            .parse::<Area>()
+            .map(|area| area.after(10).resource_value())
+    },
+    &|input| {
+        input
+            .parse::<Area>()
+            .map(|area| area.after(1_000_000_000).resource_value())
+    },
+);
+
+const OPEN: u8 = b'.';
+const TREES: u8 = b'|';
+const LUMBERYARD: u8 = b'#';
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Area {
+    acres: Vec<Vec<u8>>,
+}
+
+impl Area {
+    fn step(&self) -> Self {
+        let acres = (0..self.acres.len())
+            .map(|y| {
+                (0..self.acres[y].len())
+                    .map(|x| {
+                        let count =
+                            |kind| self.neighbours(x, y).filter(|&acre| acre == kind).count();
+
+                        match self.acres[y][x] {
+                            OPEN if count(TREES) >= 3 => TREES,
+                            TREES if count(LUMBERYARD) >= 3 => LUMBERYARD,
+                            LUMBERYARD if count(LUMBERYARD) == 0 || count(TREES) == 0 => OPEN,
+                            acre => acre,
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Self { acres }
+    }
+
+    fn neighbours(&self, x: usize, y: usize) -> impl Iterator<Item = u8> + '_ {
+        let rows = &self.acres[y.saturating_sub(1)..(y + 2).min(self.acres.len())];
+
+        rows.iter().enumerate().flat_map(move |(dy, row)| {
+            let y0 = y.saturating_sub(1) + dy;
+            (x.saturating_sub(1)..(x + 2).min(row.len()))
+                .filter(move |&x0| (x0, y0) != (x, y))
+                .map(move |x0| row[x0])
+        })
+    }
+
+    /// The area after `minutes`. The landscape settles into a cycle long before a billion minutes are up.
+    fn after(self, minutes: usize) -> Self {
+        cycle::brent_nth(self, Self::step, minutes)
+    }
+
+    fn resource_value(&self) -> usize {
+        let count = |kind| {
+            self.acres
+                .iter()
+                .flatten()
+                .filter(|&&acre| acre == kind)
+                .count()
+        };
+        count(TREES) * count(LUMBERYARD)
+    }
+}
+
+impl std::str::FromStr for Area {
+    type Err = eyre::Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let acres: Vec<Vec<u8>> = s.lines().map(|line| line.as_bytes().to_vec()).collect();
+
+        ensure!(
+            acres.iter().all(|row| row.len() == acres[0].len()),
+            "the area isn't rectangular"
+        );
+        if let Some(&acre) = acres
+            .iter()
+            .flatten()
+            .find(|acre| ![OPEN, TREES, LUMBERYARD].contains(acre))
+        {
+            bail!("unexpected acre {:?}", char::from(acre));
+        }
+
+        Ok(Self { acres })
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let area: Area = ".#.#...|#.
+.....#|##|
+.|..|...#.
+..|#.....#
+#.#|||#|#|
+...#.||...
+.|....|...
+||...#|.#|
+|.||||..|.
+...#.|..|."
+        .parse()
+        .unwrap();
+
+    assert_eq!(area.clone().after(10).resource_value(), 1147);
+
+    // Skipping around the cycle ends up in the same place as stepping through every minute.
+    let mut stepped = area.clone();
+    for _ in 0..1000 {
+        stepped = stepped.step();
+    }
+    assert_eq!(area.after(1000), stepped);
+}
@@ -0,0 +1,76 @@
+use eyre::{Result, bail};
+
+use aoc_meta::Problem;
+
+use crate::elfcode::Program;
+
+pub const GO_WITH_THE_FLOW: Problem = Problem::solved(
+    &|input| {
+        input
+            .parse::<Program>()?
+            .run([0; 6])
+            .map(|registers| registers[0])
+    },
+    &|input| sum_of_divisors(&input.parse()?, 1),
+);
+
+/// What the program leaves in register 0 when it starts with `register_0` there, assuming it's the usual one that
+/// works out some number then adds up its divisors in a quadratic loop starting at instruction 1. Instead of waiting
+/// for the loop, run the program until it gets there and do the sum directly.
+fn sum_of_divisors(program: &Program, register_0: u64) -> Result<u64> {
+    let mut registers = [register_0, 0, 0, 0, 0, 0];
+
+    loop {
+        if !program.step(&mut registers)? {
+            bail!("the program halted before it started adding up divisors");
+        }
+
+        if registers[program.ip_register] == 1 {
+            break;
+        }
+    }
+
+    let n = registers.iter().copied().max().unwrap_or_default();
+    Ok((1..=n.isqrt())
+        .filter(|d| n.is_multiple_of(*d))
+        .map(|d| if d * d == n { d } else { d + n / d })
+        .sum())
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    // Sum the divisors of 10, or of 30 when register 0 starts at 1.
+    let program: Program = "#ip 4
+addi 4 16 4
+seti 1 0 1
+seti 1 0 2
+mulr 1 2 3
+eqrr 3 5 3
+addr 3 4 4
+addi 4 1 4
+addr 1 0 0
+addi 2 1 2
+gtrr 2 5 3
+addr 4 3 4
+seti 2 0 4
+addi 1 1 1
+gtrr 1 5 3
+addr 3 4 4
+seti 1 0 4
+mulr 4 4 4
+seti 10 0 5
+addr 4 0 4
+seti 0 0 4
+seti 30 0 5
+seti 0 0 0
+seti 0 0 4"
+        .parse()
+        .unwrap();
+
+    assert_eq!(program.run([0; 6]).unwrap()[0], 18);
+    assert_eq!(program.run([1, 0, 0, 0, 0, 0]).unwrap()[0], 72);
+    assert_eq!(sum_of_divisors(&program, 0).unwrap(), 18);
+    assert_eq!(sum_of_divisors(&program, 1).unwrap(), 72);
+}
@@ -0,0 +1,125 @@
+use eyre::{OptionExt, Result, bail, ensure};
+use fnv::{FnvHashMap, FnvHashSet};
+
+use aoc_common::search::{self, SearchSpace};
+use aoc_meta::Problem;
+
+pub const A_REGULAR_MAP: Problem = Problem::solved(
+    &|input| {
+        Facility::parse(input)?
+            .distances()
+            .max()
+            .ok_or_eyre("there are no rooms")
+    },
+    &|input| {
+        Facility::parse(input)
+            .map(|facility| facility.distances().filter(|&doors| doors >= 1000).count())
+    },
+);
+
+type Room = (i32, i32);
+
+/// The rooms of the facility, and the rooms each one has doors to.
+#[derive(Debug, Default)]
+struct Facility {
+    doors: FnvHashMap<Room, Vec<Room>>,
+}
+
+impl Facility {
+    /// Follow every route the regex describes from the starting room, recording each door that's passed through.
+    fn parse(input: &str) -> Result<Self> {
+        let routes = input
+            .trim()
+            .strip_prefix('^')
+            .and_then(|routes| routes.strip_suffix('$'))
+            .ok_or_eyre("the regex should be wrapped in ^ and $")?;
+
+        let mut facility = Self::default();
+        // Branches can end up in different rooms, so keep track of everywhere the route might have got to so far.
+        let mut current: FnvHashSet<Room> = FnvHashSet::from_iter([(0, 0)]);
+        // For each group of branches: where it started, and where the branches finished so far have ended up.
+        let mut groups: Vec<(FnvHashSet<Room>, FnvHashSet<Room>)> = Vec::new();
+
+        for c in routes.chars() {
+            match c {
+                'N' | 'E' | 'S' | 'W' => {
+                    let (dx, dy) = match c {
+                        'N' => (0, -1),
+                        'E' => (1, 0),
+                        'S' => (0, 1),
+                        _ => (-1, 0),
+                    };
+
+                    current = current
+                        .into_iter()
+                        .map(|(x, y)| {
+                            let next = (x + dx, y + dy);
+                            facility.doors.entry((x, y)).or_default().push(next);
+                            facility.doors.entry(next).or_default().push((x, y));
+                            next
+                        })
+                        .collect();
+                }
+                '(' => groups.push((current.clone(), FnvHashSet::default())),
+                '|' => {
+                    let (start, ends) =
+                        groups.last_mut().ok_or_eyre("| outside of any branches")?;
+                    ends.extend(current);
+                    current = start.clone();
+                }
+                ')' => {
+                    let (_, ends) = groups.pop().ok_or_eyre("unmatched )")?;
+                    current.extend(ends);
+                }
+                _ => bail!("unexpected {c:?} in the regex"),
+            }
+        }
+
+        ensure!(groups.is_empty(), "unmatched (");
+        Ok(facility)
+    }
+
+    /// The fewest doors needed to pass through to reach each room.
+    fn distances(&self) -> impl Iterator<Item = usize> + '_ {
+        search::bfs(self, (0, 0)).map(|(_, doors)| doors)
+    }
+}
+
+impl SearchSpace for Facility {
+    type State = Room;
+    type Cost = usize;
+
+    fn successors(&self, room: &Room) -> impl IntoIterator<Item = (Room, usize)> {
+        self.doors
+            .get(room)
+            .into_iter()
+            .flatten()
+            .map(|&next| (next, 1))
+    }
+
+    fn is_goal(&self, _: &Room) -> bool {
+        false
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    for (regex, furthest) in [
+        ("^WNE$", 3),
+        ("^ENWWW(NEEE|SSE(EE|N))$", 10),
+        ("^ENNWSWW(NEWS|)SSSEEN(WNSE|)EE(SWEN|)NNN$", 18),
+        ("^ESSWWN(E|NNENN(EESS(WNSE|)SSS|WWWSSSSE(SW|NNNE)))$", 23),
+        (
+            "^WSSEESWWWNW(S|NENNEEEENN(ESSSSW(NWSW|SSEN)|WSWWN(E|WWS(E|SS))))$",
+            31,
+        ),
+    ] {
+        assert_eq!(
+            Facility::parse(regex).unwrap().distances().max(),
+            Some(furthest),
+            "{regex}"
+        );
+    }
+}
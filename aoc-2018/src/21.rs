@@ -0,0 +1,120 @@
+use eyre::{OptionExt, Result};
+use fnv::FnvHashSet;
+
+use aoc_meta::Problem;
+
+use crate::elfcode::{Instruction, Opcode, Program, Registers};
+
+pub const CHRONAL_CONVERSION: Problem = Problem::solved(
+    &|input| {
+        Comparisons::new(input.parse()?)?
+            .next()
+            .ok_or_eyre("register 0 is never checked")?
+    },
+    &|input| {
+        let mut seen = FnvHashSet::default();
+        let mut last = None;
+
+        for value in Comparisons::new(input.parse()?)? {
+            let value = value?;
+            if !seen.insert(value) {
+                break;
+            }
+            last = Some(value);
+        }
+
+        last.ok_or_eyre("register 0 is never checked")
+    },
+);
+
+/// The values the program compares register 0 against to decide whether to halt, in the order it checks them. Register
+/// 0 is never written to, so each of them is a value it could start with to make the program halt at that point.
+struct Comparisons {
+    program: Program,
+    registers: Registers,
+    /// Where the program checks whether to halt, and the register it compares against register 0.
+    check: (usize, usize),
+}
+
+impl Comparisons {
+    fn new(program: Program) -> Result<Self> {
+        let check = program
+            .instructions
+            .iter()
+            .enumerate()
+            .find_map(|(ip, instruction)| match *instruction {
+                Instruction {
+                    opcode: Opcode::Eqrr,
+                    a: 0,
+                    b: register,
+                    ..
+                }
+                | Instruction {
+                    opcode: Opcode::Eqrr,
+                    a: register,
+                    b: 0,
+                    ..
+                } => Some((ip, register as usize)),
+                _ => None,
+            })
+            .ok_or_eyre("the program never looks at register 0")?;
+
+        Ok(Self {
+            program,
+            registers: [0; 6],
+            check,
+        })
+    }
+}
+
+impl Iterator for Comparisons {
+    type Item = Result<u64>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.program.step(&mut self.registers) {
+                Ok(true) => {}
+                Ok(false) => return None,
+                Err(error) => return Some(Err(error)),
+            }
+
+            if let Some((ip, _)) = self.program.next_instruction(&self.registers)
+                && ip == self.check.0
+            {
+                // Step past the check, since it'll fail anyway with register 0 left at zero.
+                let value = self.registers[self.check.1];
+                return Some(self.program.step(&mut self.registers).map(|_| value));
+            }
+        }
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    // Go through the numbers from 100 to 115 in a scrambled order forever, halting if one matches register 0.
+    let program: Program = "#ip 5
+seti 7 0 1
+muli 1 5 1
+addi 1 3 1
+bani 1 15 1
+addi 1 100 3
+eqrr 3 0 2
+addr 2 5 5
+seti 0 0 5"
+        .parse()
+        .unwrap();
+
+    let values: Vec<u64> = Comparisons::new(program)
+        .unwrap()
+        .take(17)
+        .map(Result::unwrap)
+        .collect();
+    assert_eq!(
+        values,
+        [
+            106, 101, 108, 111, 110, 105, 112, 115, 114, 109, 100, 103, 102, 113, 104, 107, 106
+        ]
+    );
+}
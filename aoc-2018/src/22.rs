@@ -0,0 +1,133 @@
+use eyre::{OptionExt, Result};
+use winnow::combinator::{preceded, separated_pair};
+
+use aoc_common::parse::{self, unsigned};
+use aoc_common::search::{self, SearchSpace};
+use aoc_meta::Problem;
+
+pub const MODE_MAZE: Problem = Problem::solved(
+    &|input| Cave::parse(input).map(|cave| cave.risk_level()),
+    &|input| {
+        Cave::parse(input)?
+            .fastest_rescue()
+            .ok_or_eyre("the target can't be reached")
+    },
+);
+
+/// Region types are 0 for rocky, 1 for wet and 2 for narrow. Tools are numbered so that each region type's number
+/// is the one tool that can't be used there: 0 for neither, 1 for the torch and 2 for climbing gear.
+const TORCH: u8 = 1;
+
+#[derive(Debug)]
+struct Cave {
+    target: (usize, usize),
+    width: usize,
+    /// Region types, row by row, in a rectangle that extends well past the target.
+    regions: Vec<u8>,
+}
+
+impl Cave {
+    fn new(depth: usize, target: (usize, usize)) -> Self {
+        // Straying further from the target than it is from the mouth of the cave never pays off in practice.
+        let margin = target.0.max(target.1);
+        let (width, height) = (target.0 + margin + 1, target.1 + margin + 1);
+
+        let mut erosion = vec![0; width * height];
+        for y in 0..height {
+            for x in 0..width {
+                let geologic_index = match (x, y) {
+                    (0, 0) => 0,
+                    _ if (x, y) == target => 0,
+                    (x, 0) => x * 16807,
+                    (0, y) => y * 48271,
+                    _ => erosion[y * width + x - 1] * erosion[(y - 1) * width + x],
+                };
+
+                erosion[y * width + x] = (geologic_index + depth) % 20183;
+            }
+        }
+
+        let regions = erosion.into_iter().map(|level| (level % 3) as u8).collect();
+        Self {
+            target,
+            width,
+            regions,
+        }
+    }
+
+    /// The sum of the region types in the rectangle between the mouth of the cave and the target.
+    fn risk_level(&self) -> usize {
+        (0..=self.target.1)
+            .flat_map(|y| (0..=self.target.0).map(move |x| (x, y)))
+            .map(|(x, y)| usize::from(self.regions[y * self.width + x]))
+            .sum()
+    }
+
+    /// The fewest minutes it takes to reach the target and be holding a torch.
+    fn fastest_rescue(&self) -> Option<usize> {
+        search::astar(self, ((0, 0), TORCH)).map(|path| path.cost)
+    }
+
+    fn parse(input: &str) -> Result<Self> {
+        let (depth, target) = parse::complete(
+            input,
+            separated_pair(
+                preceded("depth: ", unsigned),
+                '\n',
+                preceded("target: ", separated_pair(unsigned, ',', unsigned)),
+            ),
+        )?;
+
+        Ok(Self::new(depth, target))
+    }
+}
+
+impl SearchSpace for Cave {
+    type State = ((usize, usize), u8);
+    type Cost = usize;
+
+    fn successors(
+        &self,
+        &((x, y), tool): &Self::State,
+    ) -> impl IntoIterator<Item = (Self::State, usize)> {
+        let height = self.regions.len() / self.width;
+        let region = self.regions[y * self.width + x];
+
+        // Switch to the other tool that works here, or move somewhere the current tool works.
+        let switch = (3 - region - tool, 7);
+        let moves = [
+            (x.wrapping_sub(1), y),
+            (x + 1, y),
+            (x, y.wrapping_sub(1)),
+            (x, y + 1),
+        ]
+        .into_iter()
+        .filter(move |&(x, y)| {
+            x < self.width && y < height && self.regions[y * self.width + x] != tool
+        })
+        .map(move |position| ((position, tool), 1));
+
+        std::iter::once((((x, y), switch.0), switch.1)).chain(moves)
+    }
+
+    fn is_goal(&self, &(position, tool): &Self::State) -> bool {
+        position == self.target && tool == TORCH
+    }
+
+    fn heuristic(&self, &((x, y), tool): &Self::State) -> usize {
+        let switch = if tool == TORCH { 0 } else { 7 };
+        x.abs_diff(self.target.0) + y.abs_diff(self.target.1) + switch
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let cave = Cave::parse("depth: 510\ntarget: 10,10").unwrap();
+    assert_eq!(cave.regions[..2], [0, 1]);
+    assert_eq!(cave.regions[cave.width..cave.width + 2], [0, 2]);
+
+    assert_eq!(cave.risk_level(), 114);
+    assert_eq!(cave.fastest_rescue(), Some(45));
+}
@@ -0,0 +1,137 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use eyre::{OptionExt, Result};
+use winnow::combinator::{delimited, preceded, separated_pair};
+use winnow::prelude::*;
+
+use aoc_common::parse::{self, signed, unsigned};
+use aoc_meta::Problem;
+
+pub const EXPERIMENTAL_EMERGENCY_TELEPORTATION: Problem = Problem::solved(
+    &|input| in_range_of_strongest(&parse_nanobots(input)?),
+    &|input| best_position(&parse_nanobots(input)?).ok_or_eyre("there are no nanobots"),
+);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Nanobot {
+    position: [i64; 3],
+    radius: u64,
+}
+
+impl Nanobot {
+    /// How far the nanobot is from the nearest point in the cube with `size` long sides and its lowest corner at
+    /// `corner`.
+    fn distance_to_cube(&self, corner: [i64; 3], size: i64) -> u64 {
+        (0..3)
+            .map(|axis| {
+                let (low, high) = (corner[axis], corner[axis] + size - 1);
+                let p = self.position[axis];
+                (low - p).max(p - high).max(0) as u64
+            })
+            .sum()
+    }
+}
+
+fn in_range_of_strongest(nanobots: &[Nanobot]) -> Result<usize> {
+    let strongest = nanobots
+        .iter()
+        .max_by_key(|bot| bot.radius)
+        .ok_or_eyre("there are no nanobots")?;
+    Ok(nanobots
+        .iter()
+        .filter(|bot| strongest.distance_to_cube(bot.position, 1) <= strongest.radius)
+        .count())
+}
+
+/// How far the closest position in range of the most nanobots is from the origin. Splits space into ever smaller
+/// cubes, always looking inside the one that could have the most nanobots in range first, so the first single
+/// position it gets down to is the best one.
+fn best_position(nanobots: &[Nanobot]) -> Option<u64> {
+    let extent = nanobots
+        .iter()
+        .flat_map(|bot| bot.position.map(|p| p.unsigned_abs() + bot.radius))
+        .max()?;
+    let size = (2 * extent + 1).next_power_of_two() as i64;
+
+    let cube = |corner: [i64; 3], size: i64| {
+        let in_range = nanobots
+            .iter()
+            .filter(|bot| bot.distance_to_cube(corner, size) <= bot.radius)
+            .count();
+        let origin = Nanobot {
+            position: [0; 3],
+            radius: 0,
+        }
+        .distance_to_cube(corner, size);
+        (in_range, Reverse(origin), Reverse(size), corner)
+    };
+
+    let mut queue = BinaryHeap::from([cube([-size / 2; 3], size)]);
+
+    while let Some((_, Reverse(origin), Reverse(size), corner)) = queue.pop() {
+        if size == 1 {
+            return Some(origin);
+        }
+
+        let half = size / 2;
+        for octant in 0..8 {
+            let corner =
+                [0, 1, 2].map(|axis| corner[axis] + if octant >> axis & 1 == 1 { half } else { 0 });
+            queue.push(cube(corner, half));
+        }
+    }
+
+    None
+}
+
+fn nanobot(input: &mut &str) -> ModalResult<Nanobot> {
+    separated_pair(
+        delimited(
+            "pos=<",
+            (signed, preceded(',', signed), preceded(',', signed)),
+            '>',
+        ),
+        ", r=",
+        unsigned,
+    )
+    .map(|((x, y, z), radius)| Nanobot {
+        position: [x, y, z],
+        radius,
+    })
+    .parse_next(input)
+}
+
+fn parse_nanobots(input: &str) -> Result<Vec<Nanobot>> {
+    parse::lines(input, nanobot).collect()
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let nanobots = parse_nanobots(
+        "pos=<0,0,0>, r=4
+pos=<1,0,0>, r=1
+pos=<4,0,0>, r=3
+pos=<0,2,0>, r=1
+pos=<0,5,0>, r=3
+pos=<0,0,3>, r=1
+pos=<1,1,1>, r=1
+pos=<1,1,2>, r=1
+pos=<1,3,1>, r=1",
+    )
+    .unwrap();
+    assert_eq!(in_range_of_strongest(&nanobots).unwrap(), 7);
+
+    let nanobots = parse_nanobots(
+        "pos=<10,12,12>, r=2
+pos=<12,14,12>, r=2
+pos=<16,12,12>, r=4
+pos=<14,14,14>, r=6
+pos=<50,50,50>, r=200
+pos=<10,10,10>, r=5",
+    )
+    .unwrap();
+    assert_eq!(best_position(&nanobots), Some(36));
+}
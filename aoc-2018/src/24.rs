@@ -0,0 +1,225 @@
+use std::cmp::Reverse;
+
+use eyre::{OptionExt, Result, bail, eyre};
+
+use aoc_meta::Problem;
+
+pub const IMMUNE_SYSTEM_SIMULATOR: Problem = Problem::solved(
+    &|input| {
+        fight(parse_armies(input)?, 0)
+            .map(|(_, units)| units)
+            .ok_or_eyre("the fight never ends")
+    },
+    &|input| {
+        let groups = parse_armies(input)?;
+        (1..100_000)
+            .find_map(|boost| match fight(groups.clone(), boost) {
+                Some((Army::ImmuneSystem, units)) => Some(units),
+                _ => None,
+            })
+            .ok_or_eyre("no boost lets the immune system win")
+    },
+);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Army {
+    ImmuneSystem,
+    Infection,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Group {
+    army: Army,
+    units: u32,
+    hit_points: u32,
+    weaknesses: Vec<String>,
+    immunities: Vec<String>,
+    damage: u32,
+    attack_type: String,
+    initiative: u32,
+}
+
+impl Group {
+    fn effective_power(&self) -> u32 {
+        self.units * self.damage
+    }
+
+    /// How much damage this group would deal to `target`.
+    fn damage_to(&self, target: &Group) -> u32 {
+        if target.immunities.contains(&self.attack_type) {
+            0
+        } else if target.weaknesses.contains(&self.attack_type) {
+            2 * self.effective_power()
+        } else {
+            self.effective_power()
+        }
+    }
+}
+
+/// Fight until only one army is left, with the immune system's attacks boosted by `boost`. Returns the winner and
+/// how many units it has left, or `None` if neither army can kill any more units.
+fn fight(mut groups: Vec<Group>, boost: u32) -> Option<(Army, u32)> {
+    for group in groups
+        .iter_mut()
+        .filter(|group| group.army == Army::ImmuneSystem)
+    {
+        group.damage += boost;
+    }
+
+    loop {
+        let alive = |army| {
+            groups
+                .iter()
+                .any(|group| group.army == army && group.units > 0)
+        };
+        match (alive(Army::ImmuneSystem), alive(Army::Infection)) {
+            (true, true) => {}
+            (immune_system, _) => {
+                let winner = if immune_system {
+                    Army::ImmuneSystem
+                } else {
+                    Army::Infection
+                };
+                return Some((winner, groups.iter().map(|group| group.units).sum()));
+            }
+        }
+
+        groups.retain(|group| group.units > 0);
+
+        // Target selection, strongest groups first.
+        let mut order: Vec<usize> = (0..groups.len()).collect();
+        order.sort_by_key(|&i| Reverse((groups[i].effective_power(), groups[i].initiative)));
+
+        let mut targets: Vec<Option<usize>> = vec![None; groups.len()];
+        let mut targeted = vec![false; groups.len()];
+        for attacker in order {
+            let group = &groups[attacker];
+            let target = (0..groups.len())
+                .filter(|&i| {
+                    groups[i].army != group.army && !targeted[i] && group.damage_to(&groups[i]) > 0
+                })
+                .max_by_key(|&i| {
+                    (
+                        group.damage_to(&groups[i]),
+                        groups[i].effective_power(),
+                        groups[i].initiative,
+                    )
+                });
+
+            if let Some(target) = target {
+                targeted[target] = true;
+                targets[attacker] = Some(target);
+            }
+        }
+
+        // Attacks, by initiative.
+        let mut order: Vec<usize> = (0..groups.len()).collect();
+        order.sort_by_key(|&i| Reverse(groups[i].initiative));
+
+        let mut killed = 0;
+        for attacker in order {
+            if let Some(target) = targets[attacker]
+                && groups[attacker].units > 0
+            {
+                let damage = groups[attacker].damage_to(&groups[target]);
+                let target = &mut groups[target];
+                let deaths = (damage / target.hit_points).min(target.units);
+
+                target.units -= deaths;
+                killed += deaths;
+            }
+        }
+
+        if killed == 0 {
+            return None;
+        }
+    }
+}
+
+/// Parse a group from a line like `18 units each with 729 hit points (weak to fire; immune to cold, slashing) with
+/// an attack that does 8 radiation damage at initiative 10`.
+fn parse_group(army: Army, line: &str) -> Result<Group> {
+    let malformed = || eyre!("malformed group: {line}");
+
+    let (units, rest) = line.split_once(" units each with ").ok_or_else(malformed)?;
+    let (hit_points, rest) = rest.split_once(" hit points ").ok_or_else(malformed)?;
+    let (modifiers, rest) = match rest.strip_prefix('(') {
+        Some(rest) => rest.split_once(") ").ok_or_else(malformed)?,
+        None => ("", rest),
+    };
+    let rest = rest
+        .strip_prefix("with an attack that does ")
+        .ok_or_else(malformed)?;
+    let (attack, initiative) = rest
+        .split_once(" damage at initiative ")
+        .ok_or_else(malformed)?;
+    let (damage, attack_type) = attack.split_once(' ').ok_or_else(malformed)?;
+
+    let (mut weaknesses, mut immunities) = (Vec::new(), Vec::new());
+    for modifier in modifiers
+        .split("; ")
+        .filter(|modifier| !modifier.is_empty())
+    {
+        let (list, types) = if let Some(types) = modifier.strip_prefix("weak to ") {
+            (&mut weaknesses, types)
+        } else if let Some(types) = modifier.strip_prefix("immune to ") {
+            (&mut immunities, types)
+        } else {
+            bail!("unexpected modifier {modifier:?}");
+        };
+
+        list.extend(types.split(", ").map(String::from));
+    }
+
+    Ok(Group {
+        army,
+        units: units.parse()?,
+        hit_points: hit_points.parse()?,
+        weaknesses,
+        immunities,
+        damage: damage.parse()?,
+        attack_type: attack_type.to_string(),
+        initiative: initiative.parse()?,
+    })
+}
+
+fn parse_armies(input: &str) -> Result<Vec<Group>> {
+    let mut groups = Vec::new();
+
+    for section in input.split("\n\n") {
+        let mut lines = section.lines();
+        let army = match lines.next() {
+            Some("Immune System:") => Army::ImmuneSystem,
+            Some("Infection:") => Army::Infection,
+            header => bail!("unexpected army {header:?}"),
+        };
+
+        for line in lines {
+            groups.push(parse_group(army, line)?);
+        }
+    }
+
+    Ok(groups)
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let groups = parse_armies(
+        "Immune System:
+17 units each with 5390 hit points (weak to radiation, bludgeoning) with an attack that does 4507 fire damage at initiative 2
+989 units each with 1274 hit points (immune to fire; weak to bludgeoning, slashing) with an attack that does 25 slashing damage at initiative 3
+
+Infection:
+801 units each with 4706 hit points (weak to radiation) with an attack that does 116 bludgeoning damage at initiative 1
+4485 units each with 2961 hit points (immune to radiation; weak to fire, cold) with an attack that does 12 slashing damage at initiative 4",
+    )
+    .unwrap();
+
+    assert_eq!(groups[1].immunities, ["fire"]);
+    assert_eq!(groups[1].weaknesses, ["bludgeoning", "slashing"]);
+
+    assert_eq!(fight(groups.clone(), 0), Some((Army::Infection, 5216)));
+    assert_eq!(fight(groups, 1570), Some((Army::ImmuneSystem, 51)));
+}
@@ -0,0 +1,74 @@
+use disjoint_sets::UnionFind;
+use eyre::Result;
+use fnv::FnvHashSet;
+use winnow::combinator::separated;
+use winnow::prelude::*;
+
+use aoc_common::parse::{self, signed};
+use aoc_meta::Problem;
+
+pub const FOUR_DIMENSIONAL_ADVENTURE: Problem =
+    Problem::partially_solved(&|input| parse_points(input).map(|points| constellations(&points)));
+
+type Point = [i32; 4];
+
+/// How many constellations the points form, where points within 3 of each other are in the same one.
+fn constellations(points: &[Point]) -> usize {
+    let mut constellations = UnionFind::new(points.len());
+
+    for (i, a) in points.iter().enumerate() {
+        for (j, b) in points.iter().enumerate().skip(i + 1) {
+            if a.iter().zip(b).map(|(a, b)| a.abs_diff(*b)).sum::<u32>() <= 3 {
+                constellations.union(i, j);
+            }
+        }
+    }
+
+    (0..points.len())
+        .map(|i| constellations.find(i))
+        .collect::<FnvHashSet<_>>()
+        .len()
+}
+
+fn point(input: &mut &str) -> ModalResult<Point> {
+    separated(4, signed::<i32>, ',')
+        .map(|coordinates: Vec<i32>| {
+            [
+                coordinates[0],
+                coordinates[1],
+                coordinates[2],
+                coordinates[3],
+            ]
+        })
+        .parse_next(input)
+}
+
+fn parse_points(input: &str) -> Result<Vec<Point>> {
+    parse::lines(input, point).collect()
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    for (points, expected) in [
+        (
+            "0,0,0,0\n3,0,0,0\n0,3,0,0\n0,0,3,0\n0,0,0,3\n0,0,0,6\n9,0,0,0\n12,0,0,0",
+            2,
+        ),
+        (
+            "-1,2,2,0\n0,0,2,-2\n0,0,0,-2\n-1,2,0,0\n-2,-2,-2,2\n3,0,2,-1\n-1,3,2,2\n-1,0,-1,0\n0,2,1,-2\n3,0,0,0",
+            4,
+        ),
+        (
+            "1,-1,0,1\n2,0,-1,0\n3,2,-1,0\n0,0,3,1\n0,0,-1,-1\n2,3,-2,0\n-2,2,0,0\n2,-2,0,-1\n1,-1,0,-1\n3,2,0,2",
+            3,
+        ),
+        (
+            "1,-1,-1,-2\n-2,-2,0,1\n0,2,1,3\n-2,3,-2,1\n0,2,3,-2\n-1,-1,1,-2\n0,-2,-1,0\n-2,2,3,-1\n1,2,2,0\n-1,-2,0,-2",
+            8,
+        ),
+    ] {
+        assert_eq!(constellations(&parse_points(points).unwrap()), expected);
+    }
+}
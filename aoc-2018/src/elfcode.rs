@@ -0,0 +1,393 @@
+//! The wrist device's register machine and the programs the elves write for it. Day 16 works out which number
+//! stands for which opcode, and days 19 and 21 run programs that bind the instruction pointer to a register.
+
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+use eyre::{OptionExt, Report, Result, bail, eyre};
+use winnow::ascii::{alpha1, space1};
+use winnow::combinator::{delimited, preceded, separated, seq};
+use winnow::prelude::*;
+
+use aoc_common::parse::{self, unsigned};
+
+/// The registers of a device running a program with a bound instruction pointer.
+pub type Registers = [u64; 6];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Opcode {
+    Addr,
+    Addi,
+    Mulr,
+    Muli,
+    Banr,
+    Bani,
+    Borr,
+    Bori,
+    Setr,
+    Seti,
+    Gtir,
+    Gtri,
+    Gtrr,
+    Eqir,
+    Eqri,
+    Eqrr,
+}
+
+impl Opcode {
+    pub const ALL: [Self; 16] = [
+        Opcode::Addr,
+        Opcode::Addi,
+        Opcode::Mulr,
+        Opcode::Muli,
+        Opcode::Banr,
+        Opcode::Bani,
+        Opcode::Borr,
+        Opcode::Bori,
+        Opcode::Setr,
+        Opcode::Seti,
+        Opcode::Gtir,
+        Opcode::Gtri,
+        Opcode::Gtrr,
+        Opcode::Eqir,
+        Opcode::Eqri,
+        Opcode::Eqrr,
+    ];
+
+    /// Execute the opcode on `registers` with operands `a`, `b` and `c`, or return `None` if it refers to a
+    /// register that doesn't exist.
+    pub fn apply(self, registers: &mut [u64], a: u64, b: u64, c: u64) -> Option<()> {
+        let register = |r: u64| registers.get(usize::try_from(r).ok()?).copied();
+
+        let value = match self {
+            Opcode::Addr => register(a)? + register(b)?,
+            Opcode::Addi => register(a)? + b,
+            Opcode::Mulr => register(a)? * register(b)?,
+            Opcode::Muli => register(a)? * b,
+            Opcode::Banr => register(a)? & register(b)?,
+            Opcode::Bani => register(a)? & b,
+            Opcode::Borr => register(a)? | register(b)?,
+            Opcode::Bori => register(a)? | b,
+            Opcode::Setr => register(a)?,
+            Opcode::Seti => a,
+            Opcode::Gtir => u64::from(a > register(b)?),
+            Opcode::Gtri => u64::from(register(a)? > b),
+            Opcode::Gtrr => u64::from(register(a)? > register(b)?),
+            Opcode::Eqir => u64::from(a == register(b)?),
+            Opcode::Eqri => u64::from(register(a)? == b),
+            Opcode::Eqrr => u64::from(register(a)? == register(b)?),
+        };
+
+        *registers.get_mut(usize::try_from(c).ok()?)? = value;
+        Some(())
+    }
+
+    const fn name(self) -> &'static str {
+        match self {
+            Opcode::Addr => "addr",
+            Opcode::Addi => "addi",
+            Opcode::Mulr => "mulr",
+            Opcode::Muli => "muli",
+            Opcode::Banr => "banr",
+            Opcode::Bani => "bani",
+            Opcode::Borr => "borr",
+            Opcode::Bori => "bori",
+            Opcode::Setr => "setr",
+            Opcode::Seti => "seti",
+            Opcode::Gtir => "gtir",
+            Opcode::Gtri => "gtri",
+            Opcode::Gtrr => "gtrr",
+            Opcode::Eqir => "eqir",
+            Opcode::Eqri => "eqri",
+            Opcode::Eqrr => "eqrr",
+        }
+    }
+}
+
+impl Display for Opcode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Opcode {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Opcode::ALL
+            .into_iter()
+            .find(|opcode| opcode.name() == s)
+            .ok_or_else(|| eyre!("unknown opcode \"{s}\""))
+    }
+}
+
+/// What an instruction did to the registers, with its opcode still given as a number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Sample {
+    pub before: [u64; 4],
+    pub instruction: [u64; 4],
+    pub after: [u64; 4],
+}
+
+impl Sample {
+    /// The opcodes that would have had the same effect, as a bitmask of their positions in [`Opcode::ALL`].
+    pub fn candidates(&self) -> u16 {
+        let [_, a, b, c] = self.instruction;
+
+        Opcode::ALL
+            .iter()
+            .enumerate()
+            .filter(|&(_, opcode)| {
+                let mut registers = self.before;
+                opcode.apply(&mut registers, a, b, c).is_some() && registers == self.after
+            })
+            .fold(0, |mask, (i, _)| mask | 1 << i)
+    }
+}
+
+impl FromStr for Sample {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let registers = || {
+            delimited('[', separated(4, unsigned::<u64>, ", "), ']')
+                .map(|registers: Vec<u64>| [registers[0], registers[1], registers[2], registers[3]])
+        };
+
+        parse::complete(
+            s,
+            seq! {Sample {
+                _: ("Before:", space1),
+                before: registers(),
+                _: '\n',
+                instruction: separated(4, unsigned::<u64>, ' ')
+                    .map(|numbers: Vec<u64>| [numbers[0], numbers[1], numbers[2], numbers[3]]),
+                _: ("\nAfter:", space1),
+                after: registers(),
+            }},
+        )
+    }
+}
+
+/// Work out which opcode each number stands for from what the `samples` did. Numbers whose opcode has been pinned
+/// down rule it out for every other number, until each one is left with a single opcode.
+pub fn infer_opcodes(samples: &[Sample]) -> Result<[Opcode; 16]> {
+    let mut candidates = [u16::MAX; 16];
+
+    for sample in samples {
+        let number = usize::try_from(sample.instruction[0])
+            .ok()
+            .filter(|&number| number < 16)
+            .ok_or_eyre("opcodes are numbered 0 to 15")?;
+        candidates[number] &= sample.candidates();
+    }
+
+    let mut known = 0u16;
+    while known != u16::MAX {
+        let Some(single) = candidates
+            .iter()
+            .find(|&&mask| mask.count_ones() == 1 && mask & known == 0)
+            .copied()
+        else {
+            bail!("the samples don't pin down every opcode");
+        };
+
+        known |= single;
+        for mask in candidates.iter_mut().filter(|mask| **mask != single) {
+            *mask &= !single;
+        }
+    }
+
+    Ok(candidates.map(|mask| Opcode::ALL[mask.trailing_zeros() as usize]))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Instruction {
+    pub opcode: Opcode,
+    pub a: u64,
+    pub b: u64,
+    pub c: u64,
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} {} {}", self.opcode, self.a, self.b, self.c)
+    }
+}
+
+/// A program whose instruction pointer is bound to a register, so instructions can read and jump around by
+/// writing to it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Program {
+    pub ip_register: usize,
+    pub instructions: Vec<Instruction>,
+}
+
+impl Program {
+    /// The instruction the program is about to execute, or `None` if it's halted.
+    #[inline]
+    pub fn next_instruction(&self, registers: &Registers) -> Option<(usize, Instruction)> {
+        let ip = usize::try_from(registers[self.ip_register]).ok()?;
+        self.instructions
+            .get(ip)
+            .map(|&instruction| (ip, instruction))
+    }
+
+    /// Execute the next instruction, or a whole loop starting there if it's one that [`Program::fused_division`]
+    /// recognizes. Returns whether the program is still running.
+    pub fn step(&self, registers: &mut Registers) -> Result<bool> {
+        let Some((ip, Instruction { opcode, a, b, c })) = self.next_instruction(registers) else {
+            return Ok(false);
+        };
+
+        if self.fused_division(registers, ip) {
+            return Ok(true);
+        }
+
+        opcode
+            .apply(registers, a, b, c)
+            .ok_or_else(|| eyre!("instruction {ip} refers to a register that doesn't exist"))?;
+        registers[self.ip_register] += 1;
+
+        Ok(true)
+    }
+
+    /// Run the program until it halts.
+    pub fn run(&self, mut registers: Registers) -> Result<Registers> {
+        while self.step(&mut registers)? {}
+        Ok(registers)
+    }
+
+    /// Recognize the loop that divides one register by a constant the slow way, counting up until the next
+    /// multiple is too big, and do the division in one go instead. Returns whether it was there to run.
+    ///
+    /// ```text
+    /// seti 0 _ x            x = 0
+    /// addi x 1 t            t = (x + 1) * k
+    /// muli t k t
+    /// gtrr t n t            if t > n, jump out of the loop
+    /// addr t ip ip
+    /// addi ip 1 ip
+    /// seti END _ ip
+    /// addi x 1 x            x += 1, and go round again
+    /// seti START _ ip
+    /// ```
+    fn fused_division(&self, registers: &mut Registers, start: usize) -> bool {
+        let ip = self.ip_register as u64;
+        let op = |i: usize| {
+            self.instructions
+                .get(start + i)
+                .map(|i| (i.opcode, i.a, i.b, i.c))
+        };
+
+        if let Some((Opcode::Seti, 0, _, x)) = op(0)
+            && let Some((Opcode::Addi, x1, 1, t)) = op(1)
+            && let Some((Opcode::Muli, t1, k, t2)) = op(2)
+            && let Some((Opcode::Gtrr, t3, n, t4)) = op(3)
+            && let Some((Opcode::Addr, t5, ip1, ip2)) = op(4)
+            && let Some((Opcode::Addi, ip3, 1, ip4)) = op(5)
+            && let Some((Opcode::Seti, end, _, ip5)) = op(6)
+            && let Some((Opcode::Addi, x2, 1, x3)) = op(7)
+            && let Some((Opcode::Seti, loop_start, _, ip6)) = op(8)
+            && [x1, x2, x3].iter().all(|&r| r == x)
+            && [t1, t2, t3, t4, t5].iter().all(|&r| r == t)
+            && [ip1, ip2, ip3, ip4, ip5, ip6].iter().all(|&r| r == ip)
+            && end == start as u64 + 8
+            && loop_start == start as u64
+            && k > 0
+            && x != t
+            && ![x, t].contains(&ip)
+            && ![x, t, ip].contains(&n)
+            && n < 6
+            && x < 6
+            && t < 6
+        {
+            registers[x as usize] = registers[n as usize] / k;
+            registers[t as usize] = 1;
+            registers[self.ip_register] = start as u64 + 9;
+
+            return true;
+        }
+
+        false
+    }
+}
+
+fn instruction(input: &mut &str) -> ModalResult<Instruction> {
+    seq! {Instruction {
+        opcode: alpha1.verify_map(|name: &str| name.parse().ok()),
+        a: preceded(' ', unsigned),
+        b: preceded(' ', unsigned),
+        c: preceded(' ', unsigned),
+    }}
+    .parse_next(input)
+}
+
+impl FromStr for Program {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (declaration, instructions) = s.split_once('\n').ok_or_eyre("the program is empty")?;
+        let ip_register = parse::complete(declaration, preceded("#ip ", unsigned))?;
+
+        if ip_register >= 6 {
+            bail!("there are only 6 registers to bind the instruction pointer to");
+        }
+
+        Ok(Self {
+            ip_register,
+            instructions: parse::lines(instructions, instruction).collect::<Result<_>>()?,
+        })
+    }
+}
+
+#[test]
+fn examples() {
+    let sample: Sample = "Before: [3, 2, 1, 1]\n9 2 1 2\nAfter:  [3, 2, 2, 1]"
+        .parse()
+        .unwrap();
+    assert_eq!(sample.candidates().count_ones(), 3);
+
+    let program: Program = "#ip 0
+seti 5 0 1
+seti 6 0 2
+addi 0 1 0
+addr 1 2 3
+setr 1 0 0
+seti 8 0 4
+seti 9 0 5"
+        .parse()
+        .unwrap();
+    assert_eq!(program.run([0; 6]).unwrap(), [7, 5, 6, 0, 0, 9]);
+}
+
+#[test]
+fn fused_division() {
+    // r5 = r4 / 256, then r0 = r5
+    let program: Program = "#ip 1
+seti 0 2 5
+addi 5 1 3
+muli 3 256 3
+gtrr 3 4 3
+addr 3 1 1
+addi 1 1 1
+seti 8 3 1
+addi 5 1 5
+seti 0 1 1
+setr 5 6 0"
+        .parse()
+        .unwrap();
+
+    for dividend in [0, 255, 256, 1000, 65535, 65536] {
+        let registers = program.run([0, 0, 0, 0, dividend, 0]).unwrap();
+        assert_eq!(registers[0], dividend / 256);
+    }
+
+    // Running the loop the slow way gets the same answer.
+    let mut registers = [0, 0, 0, 0, 1000, 0];
+    while let Some((_, Instruction { opcode, a, b, c })) = program.next_instruction(&registers) {
+        opcode.apply(&mut registers, a, b, c).unwrap();
+        registers[program.ip_register] += 1;
+    }
+    assert_eq!(registers[0], 3);
+}
@@ -1,3 +1,31 @@
 use aoc_meta::PROBLEMS;
 
-PROBLEMS! {}
+mod elfcode;
+
+PROBLEMS! {
+    01 => CHRONAL_CALIBRATION,
+    02 => INVENTORY_MANAGEMENT_SYSTEM,
+    03 => NO_MATTER_HOW_YOU_SLICE_IT,
+    04 => REPOSE_RECORD,
+    05 => ALCHEMICAL_REDUCTION,
+    06 => CHRONAL_COORDINATES,
+    07 => THE_SUM_OF_ITS_PARTS,
+    08 => MEMORY_MANEUVER,
+    09 => MARBLE_MANIA,
+    10 => THE_STARS_ALIGN,
+    11 => CHRONAL_CHARGE,
+    12 => SUBTERRANEAN_SUSTAINABILITY,
+    13 => MINE_CART_MADNESS,
+    14 => CHOCOLATE_CHARTS,
+    15 => BEVERAGE_BANDITS,
+    16 => CHRONAL_CLASSIFICATION,
+    17 => RESERVOIR_RESEARCH,
+    18 => SETTLERS_OF_THE_NORTH_POLE,
+    19 => GO_WITH_THE_FLOW,
+    20 => A_REGULAR_MAP,
+    21 => CHRONAL_CONVERSION,
+    22 => MODE_MAZE,
+    23 => EXPERIMENTAL_EMERGENCY_TELEPORTATION,
+    24 => IMMUNE_SYSTEM_SIMULATOR,
+    25 => FOUR_DIMENSIONAL_ADVENTURE,
+}
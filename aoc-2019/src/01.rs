@@ -0,0 +1,35 @@
+use eyre::Result;
+
+use aoc_meta::Problem;
+
+pub const THE_TYRANNY_OF_THE_ROCKET_EQUATION: Problem =
+    Problem::solved(&|input| total_fuel(input, fuel), &|input| {
+        total_fuel(input, fuel_including_fuel)
+    });
+
+fn total_fuel(input: &str, fuel: impl Fn(u64) -> u64) -> Result<u64> {
+    input.lines().map(|mass| Ok(fuel(mass.parse()?))).sum()
+}
+
+#[inline]
+const fn fuel(mass: u64) -> u64 {
+    (mass / 3).saturating_sub(2)
+}
+
+/// Fuel has mass too, so it needs fuel of its own.
+fn fuel_including_fuel(mass: u64) -> u64 {
+    std::iter::successors(Some(fuel(mass)), |&mass| Some(fuel(mass)))
+        .take_while(|&fuel| fuel > 0)
+        .sum()
+}
+
+#[test]
+fn example() {
+    assert_eq!(fuel(12), 2);
+    assert_eq!(fuel(1969), 654);
+    assert_eq!(fuel(100756), 33583);
+
+    assert_eq!(fuel_including_fuel(14), 2);
+    assert_eq!(fuel_including_fuel(1969), 966);
+    assert_eq!(fuel_including_fuel(100756), 50346);
+}
@@ -0,0 +1,37 @@
+use std::sync::mpsc;
+
+use eyre::{OptionExt, Result};
+use itertools::iproduct;
+
+use crate::intcode::Machine;
+use aoc_meta::Problem;
+
+pub const PROGRAM_ALARM_1202: Problem = Problem::solved(
+    &|input| input.parse().and_then(|machine| output(machine, 12, 2)),
+    &|input| {
+        let machine: Machine = input.parse()?;
+
+        iproduct!(0..100, 0..100)
+            .find_map(|(noun, verb)| {
+                let output = output(machine.clone(), noun, verb).ok()?;
+                (output == 19690720).then_some(100 * noun + verb)
+            })
+            .ok_or_eyre("no noun and verb produce 19690720")
+    },
+);
+
+/// The value left at address 0 after running the program with `noun` and `verb` at addresses 1 and 2.
+fn output(mut machine: Machine, noun: i64, verb: i64) -> Result<i64> {
+    machine.write(1, noun);
+    machine.write(2, verb);
+
+    let (tx, rx) = mpsc::channel();
+    machine.run(&rx, &tx)?;
+    Ok(machine.read(0))
+}
+
+#[test]
+fn example() {
+    let machine: Machine = "1,9,10,3,2,3,11,0,99,30,40,50".parse().unwrap();
+    assert_eq!(output(machine, 9, 10).unwrap(), 3500);
+}
@@ -0,0 +1,94 @@
+use eyre::{OptionExt, Result, bail};
+use fnv::FnvHashMap;
+
+use aoc_meta::Problem;
+
+pub const CROSSED_WIRES: Problem = Problem::solved(
+    &|input| {
+        let (first, second) = parse_wires(input)?;
+        crossings(&first, &second)
+            .map(|((x, y), _)| x.unsigned_abs() + y.unsigned_abs())
+            .min()
+            .ok_or_eyre("the wires never cross")
+    },
+    &|input| {
+        let (first, second) = parse_wires(input)?;
+        crossings(&first, &second)
+            .map(|(_, steps)| steps)
+            .min()
+            .ok_or_eyre("the wires never cross")
+    },
+);
+
+/// How many steps along a wire it first reaches each point it passes through.
+type Wire = FnvHashMap<(i64, i64), usize>;
+
+fn parse_wires(input: &str) -> Result<(Wire, Wire)> {
+    let mut lines = input.lines();
+    let mut wire = || parse_wire(lines.next().ok_or_eyre("expected two wires")?);
+
+    Ok((wire()?, wire()?))
+}
+
+fn parse_wire(path: &str) -> Result<Wire> {
+    let mut wire = Wire::default();
+    let (mut x, mut y, mut steps) = (0, 0, 0);
+
+    for segment in path.split(',') {
+        let (dx, dy) = match segment.as_bytes().first() {
+            Some(b'U') => (0, -1),
+            Some(b'D') => (0, 1),
+            Some(b'L') => (-1, 0),
+            Some(b'R') => (1, 0),
+            _ => bail!("unknown direction in \"{segment}\""),
+        };
+
+        let length: usize = segment[1..].parse()?;
+        for _ in 0..length {
+            (x, y, steps) = (x + dx, y + dy, steps + 1);
+            wire.entry((x, y)).or_insert(steps);
+        }
+    }
+
+    Ok(wire)
+}
+
+/// Every point where the wires cross, with the combined steps along both wires to get there.
+fn crossings<'w>(
+    first: &'w Wire,
+    second: &'w Wire,
+) -> impl Iterator<Item = ((i64, i64), usize)> + 'w {
+    first
+        .iter()
+        .filter_map(|(point, steps)| Some((*point, steps + second.get(point)?)))
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let examples = [
+        ("R8,U5,L5,D3\nU7,R6,D4,L4", 6, 30),
+        (
+            "R75,D30,R83,U83,L12,D49,R71,U7,L72\nU62,R66,U55,R34,D71,R55,D58,R83",
+            159,
+            610,
+        ),
+        (
+            "R98,U47,R26,D63,R33,U87,L62,D20,R33,U53,R51\nU98,R91,D20,R16,D67,R40,U7,R15,U6,R7",
+            135,
+            410,
+        ),
+    ];
+
+    for (input, distance, steps) in examples {
+        let (first, second) = parse_wires(input).unwrap();
+        let closest = crossings(&first, &second)
+            .map(|((x, y), _)| x.abs() + y.abs())
+            .min();
+        let fewest = crossings(&first, &second).map(|(_, steps)| steps).min();
+
+        assert_eq!(closest, Some(distance));
+        assert_eq!(fewest, Some(steps));
+    }
+}
@@ -0,0 +1,41 @@
+use eyre::{Result, bail};
+
+use aoc_meta::Problem;
+
+pub const SECURE_CONTAINER: Problem =
+    Problem::solved(&|input| count_passwords(input, |run| run >= 2), &|input| {
+        count_passwords(input, |run| run == 2)
+    });
+
+/// The number of passwords in the range whose digits never decrease, and that have a run of repeated digits whose
+/// length satisfies `valid_run`.
+fn count_passwords(input: &str, valid_run: impl Fn(usize) -> bool) -> Result<usize> {
+    let Some((low, high)) = input.trim().split_once('-') else {
+        bail!("unable to split \"{input}\" into a range")
+    };
+
+    let (low, high): (u32, u32) = (low.parse()?, high.parse()?);
+    Ok((low..=high).filter(|&n| is_password(n, &valid_run)).count())
+}
+
+fn is_password(n: u32, valid_run: impl Fn(usize) -> bool) -> bool {
+    let digits = n.to_string().into_bytes();
+
+    digits.is_sorted()
+        && digits
+            .chunk_by(|a, b| a == b)
+            .any(|run| valid_run(run.len()))
+}
+
+#[test]
+fn example() {
+    let at_least_two = |run| run >= 2;
+    assert!(is_password(111111, at_least_two));
+    assert!(!is_password(223450, at_least_two));
+    assert!(!is_password(123789, at_least_two));
+
+    let exactly_two = |run| run == 2;
+    assert!(is_password(112233, exactly_two));
+    assert!(!is_password(123444, exactly_two));
+    assert!(is_password(111122, exactly_two));
+}
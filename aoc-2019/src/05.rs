@@ -0,0 +1,37 @@
+use eyre::{OptionExt, Result, bail};
+
+use crate::intcode::{Machine, Node};
+use aoc_meta::Problem;
+
+pub const SUNNY_WITH_A_CHANCE_OF_ASTEROIDS: Problem =
+    Problem::solved(&|input| diagnostic_code(input.parse()?, 1), &|input| {
+        diagnostic_code(input.parse()?, 5)
+    });
+
+/// Run the diagnostic program for the system with ID `system`. Every test before the diagnostic code outputs how far
+/// off it was, so they should all be zero.
+fn diagnostic_code(machine: Machine, system: i64) -> Result<i64> {
+    let outputs = Node::new(machine).run_with([system])?;
+    let (&code, tests) = outputs
+        .split_last()
+        .ok_or_eyre("the program has no output")?;
+
+    if let Some(failed) = tests.iter().position(|&test| test != 0) {
+        bail!("diagnostic test {failed} failed by {}", tests[failed]);
+    }
+
+    Ok(code)
+}
+
+#[test]
+fn example() {
+    // Outputs 999 if the input is below 8, 1000 if it's equal to 8, or 1001 if it's greater than 8.
+    let program = "3,21,1008,21,8,20,1005,20,22,107,8,21,20,1006,20,31,\
+        1106,0,36,98,0,0,1002,21,125,20,4,20,1105,1,46,104,\
+        999,1105,1,46,1101,1000,1,20,4,20,1105,1,46,98,99";
+
+    let machine: Machine = program.parse().unwrap();
+    assert_eq!(diagnostic_code(machine.clone(), 7).unwrap(), 999);
+    assert_eq!(diagnostic_code(machine.clone(), 8).unwrap(), 1000);
+    assert_eq!(diagnostic_code(machine, 9).unwrap(), 1001);
+}
@@ -0,0 +1,115 @@
+use std::str::FromStr;
+
+use eyre::{Report, Result, bail, eyre};
+use fnv::FnvHashMap;
+
+use aoc_meta::Problem;
+
+pub const UNIVERSAL_ORBIT_MAP: Problem = Problem::solved(
+    &|input| input.parse().map(|map: OrbitMap| map.count_orbits()),
+    &|input| {
+        input
+            .parse()
+            .and_then(|map: OrbitMap| map.orbital_transfers("YOU", "SAN"))
+    },
+);
+
+#[derive(Debug)]
+struct OrbitMap {
+    /// What each object directly orbits.
+    parents: FnvHashMap<String, String>,
+}
+
+impl OrbitMap {
+    /// Every object that `object` orbits, directly or indirectly, from closest to furthest.
+    fn ancestors<'m>(&'m self, object: &'m str) -> impl Iterator<Item = &'m str> {
+        std::iter::successors(self.parents.get(object), |parent| {
+            self.parents.get(parent.as_str())
+        })
+        .map(String::as_str)
+    }
+
+    /// The total number of direct and indirect orbits.
+    fn count_orbits(&self) -> usize {
+        let mut depths: FnvHashMap<&str, usize> = FnvHashMap::default();
+
+        self.parents
+            .keys()
+            .map(|object| self.depth(object, &mut depths))
+            .sum()
+    }
+
+    fn depth<'m>(&'m self, object: &'m str, depths: &mut FnvHashMap<&'m str, usize>) -> usize {
+        if let Some(&depth) = depths.get(object) {
+            return depth;
+        }
+
+        let depth = self
+            .parents
+            .get(object)
+            .map_or(0, |parent| self.depth(parent, depths) + 1);
+        depths.insert(object, depth);
+        depth
+    }
+
+    /// The fewest moves from the object `from` orbits to the object `to` orbits.
+    fn orbital_transfers(&self, from: &str, to: &str) -> Result<usize> {
+        let from_ancestors: FnvHashMap<&str, usize> = self
+            .ancestors(from)
+            .enumerate()
+            .map(|(distance, object)| (object, distance))
+            .collect();
+
+        self.ancestors(to)
+            .enumerate()
+            .find_map(|(distance, object)| Some(distance + from_ancestors.get(object)?))
+            .ok_or_else(|| eyre!("{from} and {to} aren't orbiting anything in common"))
+    }
+}
+
+impl FromStr for OrbitMap {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut parents = FnvHashMap::default();
+
+        for line in s.lines() {
+            let Some((parent, object)) = line.split_once(')') else {
+                bail!("unable to split \"{line}\" into two objects")
+            };
+
+            if parents
+                .insert(object.to_owned(), parent.to_owned())
+                .is_some()
+            {
+                bail!("{object} orbits more than one object");
+            }
+        }
+
+        Ok(Self { parents })
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let map: OrbitMap = "COM)B
+B)C
+C)D
+D)E
+E)F
+B)G
+G)H
+D)I
+E)J
+J)K
+K)L
+K)YOU
+I)SAN"
+        .parse()
+        .unwrap();
+
+    assert_eq!(map.count_orbits(), 54);
+    assert_eq!(map.orbital_transfers("YOU", "SAN").unwrap(), 4);
+}
@@ -0,0 +1,121 @@
+use std::sync::mpsc;
+use std::thread;
+
+use eyre::{OptionExt, Result, eyre};
+use itertools::Itertools;
+
+use crate::intcode::{Machine, Node};
+use aoc_meta::Problem;
+
+pub const AMPLIFICATION_CIRCUIT: Problem = Problem::solved(
+    &|input| highest_signal(&input.parse()?, 0..5, chain),
+    &|input| highest_signal(&input.parse()?, 5..10, feedback_loop),
+);
+
+fn highest_signal(
+    machine: &Machine,
+    phases: std::ops::Range<i64>,
+    run: fn(&Machine, &[i64]) -> Result<i64>,
+) -> Result<i64> {
+    phases
+        .permutations(5)
+        .map(|phases| run(machine, &phases))
+        .process_results(|signals| signals.max())?
+        .ok_or_eyre("no phase settings to try")
+}
+
+/// Pass the signal through each amplifier in turn.
+fn chain(machine: &Machine, phases: &[i64]) -> Result<i64> {
+    phases.iter().try_fold(0, |signal, &phase| {
+        let outputs = Node::new(machine.clone()).run_with([phase, signal])?;
+        outputs
+            .first()
+            .copied()
+            .ok_or_eyre("an amplifier didn't output anything")
+    })
+}
+
+/// Wire each amplifier's output into the next one's input, with the last one feeding back into the first, and run
+/// them all at once until they halt. The answer is the last signal sent back to the first amplifier.
+fn feedback_loop(machine: &Machine, phases: &[i64]) -> Result<i64> {
+    let (senders, receivers): (Vec<_>, Vec<_>) = phases
+        .iter()
+        .map(|&phase| {
+            let (tx, rx) = mpsc::channel();
+            tx.send(phase).expect("receiver is still around");
+            (tx, rx)
+        })
+        .unzip();
+
+    let (last_tx, last_rx) = mpsc::channel();
+    let first = senders[0].clone();
+    first.send(0).expect("receiver is still around");
+
+    let outputs = senders.into_iter().skip(1).chain([last_tx]);
+
+    thread::scope(|scope| {
+        let amplifiers = receivers
+            .into_iter()
+            .zip(outputs)
+            .map(|(input, output)| {
+                let mut machine = machine.clone();
+                scope.spawn(move || machine.run_blocking(&input, &output))
+            })
+            .collect_vec();
+
+        // Forward the last amplifier's output round to the first one, keeping hold of the last signal. The first
+        // amplifier may well have halted before the final signal arrives, so it doesn't matter if it's not there.
+        let mut signal = None;
+        for value in last_rx {
+            signal = Some(value);
+            let _ = first.send(value);
+        }
+
+        // Nothing more is coming round the loop, so stop the first amplifier waiting for it.
+        drop(first);
+
+        for amplifier in amplifiers {
+            amplifier
+                .join()
+                .map_err(|_| eyre!("an amplifier panicked"))??;
+        }
+
+        signal.ok_or_eyre("the last amplifier didn't output anything")
+    })
+}
+
+#[test]
+fn example() {
+    let machine = "3,15,3,16,1002,16,10,16,1,16,15,15,4,15,99,0,0"
+        .parse()
+        .unwrap();
+    assert_eq!(highest_signal(&machine, 0..5, chain).unwrap(), 43210);
+
+    let machine = "3,23,3,24,1002,24,10,24,1002,23,-1,23,101,5,23,23,1,24,23,23,4,23,99,0,0"
+        .parse()
+        .unwrap();
+    assert_eq!(highest_signal(&machine, 0..5, chain).unwrap(), 54321);
+
+    let machine =
+        "3,26,1001,26,-4,26,3,27,1002,27,2,27,1,27,26,27,4,27,1001,28,-1,28,1005,28,6,99,0,0,5"
+            .parse()
+            .unwrap();
+    assert_eq!(
+        highest_signal(&machine, 5..10, feedback_loop).unwrap(),
+        139629729
+    );
+    assert_eq!(
+        feedback_loop(&machine, &[9, 8, 7, 6, 5]).unwrap(),
+        139629729
+    );
+
+    let machine = "3,52,1001,52,-5,52,3,53,1,52,56,54,1007,54,5,55,1005,55,26,1001,54,\
+        -5,54,1105,1,12,1,53,54,53,1008,54,0,55,1001,55,1,55,2,53,55,53,4,\
+        53,1001,56,-1,56,1005,56,6,99,0,0,0,0,10"
+        .parse()
+        .unwrap();
+    assert_eq!(
+        highest_signal(&machine, 5..10, feedback_loop).unwrap(),
+        18216
+    );
+}
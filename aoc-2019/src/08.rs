@@ -0,0 +1,71 @@
+use eyre::{OptionExt, Result, bail};
+use itertools::Itertools;
+
+use aoc_common::ocr;
+use aoc_meta::Problem;
+
+pub const SPACE_IMAGE_FORMAT: Problem = Problem::solved(
+    &|input| checksum(&parse_layers(input, WIDTH, HEIGHT)?),
+    &|input| ocr::read(&decode(&parse_layers(input, WIDTH, HEIGHT)?, WIDTH)),
+);
+
+const WIDTH: usize = 25;
+const HEIGHT: usize = 6;
+
+fn parse_layers(input: &str, width: usize, height: usize) -> Result<Vec<&[u8]>> {
+    let pixels = input.trim_end().as_bytes();
+
+    if let Some(pixel) = pixels.iter().find(|pixel| !pixel.is_ascii_digit()) {
+        bail!("unknown pixel '{}'", *pixel as char);
+    }
+
+    if !pixels.len().is_multiple_of(width * height) {
+        bail!("the image doesn't divide evenly into {width}x{height} layers");
+    }
+
+    Ok(pixels.chunks(width * height).collect())
+}
+
+/// The number of 1 digits times the number of 2 digits on the layer with the fewest 0 digits.
+fn checksum(layers: &[&[u8]]) -> Result<usize> {
+    let count = |layer: &[u8], digit| layer.iter().filter(|&&pixel| pixel == digit).count();
+
+    let layer = layers
+        .iter()
+        .min_by_key(|layer| count(layer, b'0'))
+        .ok_or_eyre("the image has no layers")?;
+
+    Ok(count(layer, b'1') * count(layer, b'2'))
+}
+
+/// Stack the layers up, with the first one on top, and draw the resulting image. Pixels are 0 for black, 1 for
+/// white, and 2 for transparent.
+fn decode(layers: &[&[u8]], width: usize) -> String {
+    let size = layers.first().map_or(0, |layer| layer.len());
+
+    let pixels = (0..size)
+        .map(|idx| {
+            let pixel = layers
+                .iter()
+                .map(|layer| layer[idx])
+                .find(|&pixel| pixel != b'2');
+            if pixel == Some(b'1') { '#' } else { '.' }
+        })
+        .collect_vec();
+
+    pixels
+        .chunks(width)
+        .map(|row| row.iter().collect::<String>())
+        .join("\n")
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let layers = parse_layers("123456789012", 3, 2).unwrap();
+    assert_eq!(checksum(&layers).unwrap(), 1);
+
+    let layers = parse_layers("0222112222120000", 2, 2).unwrap();
+    assert_eq!(decode(&layers, 2), ".#\n#.");
+}
@@ -0,0 +1,17 @@
+use eyre::{Result, bail};
+
+use crate::intcode::{Machine, Node};
+use aoc_meta::Problem;
+
+pub const SENSOR_BOOST: Problem = Problem::solved(&|input| boost(input.parse()?, 1), &|input| {
+    boost(input.parse()?, 2)
+});
+
+/// Run the BOOST program in test mode (1) or sensor boost mode (2). In test mode, any opcodes that aren't working
+/// properly are output before the keycode.
+fn boost(machine: Machine, mode: i64) -> Result<i64> {
+    match Node::new(machine).run_with([mode])?[..] {
+        [keycode] => Ok(keycode),
+        ref outputs => bail!("expected a single output, found {outputs:?}"),
+    }
+}
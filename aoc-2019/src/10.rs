@@ -0,0 +1,156 @@
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+use eyre::{OptionExt, Report, Result, bail, eyre};
+use fnv::FnvHashMap;
+use itertools::Itertools;
+
+use aoc_common::math::gcd;
+use aoc_meta::Problem;
+
+pub const MONITORING_STATION: Problem = Problem::solved(
+    &|input| {
+        let map: AsteroidMap = input.parse()?;
+        map.best_station().map(|(_, visible)| visible)
+    },
+    &|input| {
+        let map: AsteroidMap = input.parse()?;
+        let (station, _) = map.best_station()?;
+        let (x, y) = map
+            .vaporization_order(station)
+            .nth(199)
+            .ok_or_eyre("fewer than 200 asteroids get vaporized")?;
+
+        Ok::<_, Report>(x * 100 + y)
+    },
+);
+
+#[derive(Debug)]
+struct AsteroidMap {
+    asteroids: Vec<(i64, i64)>,
+}
+
+impl AsteroidMap {
+    /// The asteroids other than `station`, grouped by the direction they're in from it (as the smallest whole step
+    /// in that direction), with the closest one first in each group.
+    fn lines_of_sight(&self, station: (i64, i64)) -> FnvHashMap<(i64, i64), Vec<(i64, i64)>> {
+        let mut lines: FnvHashMap<_, Vec<_>> = FnvHashMap::default();
+
+        for &(x, y) in self
+            .asteroids
+            .iter()
+            .filter(|&&asteroid| asteroid != station)
+        {
+            let (dx, dy) = (x - station.0, y - station.1);
+            let step = gcd(dx.unsigned_abs(), dy.unsigned_abs()) as i64;
+            lines
+                .entry((dx / step, dy / step))
+                .or_default()
+                .push((x, y));
+        }
+
+        for line in lines.values_mut() {
+            line.sort_unstable_by_key(|&(x, y)| (x - station.0).abs() + (y - station.1).abs());
+        }
+
+        lines
+    }
+
+    /// The asteroid that can see the most others, and how many it can see.
+    fn best_station(&self) -> Result<((i64, i64), usize)> {
+        self.asteroids
+            .iter()
+            .map(|&station| (station, self.lines_of_sight(station).len()))
+            .max_by_key(|&(_, visible)| visible)
+            .ok_or_eyre("there are no asteroids to build a station on")
+    }
+
+    /// The order the laser at `station` vaporizes every other asteroid, turning clockwise from straight up and only
+    /// hitting the closest asteroid in each direction on each turn.
+    fn vaporization_order(&self, station: (i64, i64)) -> impl Iterator<Item = (i64, i64)> {
+        self.lines_of_sight(station)
+            .into_iter()
+            .flat_map(|(direction, line)| {
+                line.into_iter()
+                    .enumerate()
+                    .map(move |(turn, asteroid)| (turn, direction, asteroid))
+            })
+            .sorted_unstable_by(|&(turn_a, a, _), &(turn_b, b, _)| {
+                turn_a.cmp(&turn_b).then_with(|| clockwise(a, b))
+            })
+            .map(|(_, _, asteroid)| asteroid)
+    }
+}
+
+/// Compare two directions by their clockwise angle from straight up, where up is negative `y`.
+fn clockwise((ax, ay): (i64, i64), (bx, by): (i64, i64)) -> Ordering {
+    // Directions on the right half (including straight up) come before the left half (including straight down).
+    let half = |x: i64, y: i64| x < 0 || (x == 0 && y > 0);
+
+    half(ax, ay)
+        .cmp(&half(bx, by))
+        .then_with(|| (bx * ay).cmp(&(ax * by)))
+}
+
+impl FromStr for AsteroidMap {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut asteroids = Vec::new();
+
+        for (y, line) in s.lines().enumerate() {
+            for (x, ch) in line.chars().enumerate() {
+                match ch {
+                    '#' => asteroids.push((x as i64, y as i64)),
+                    '.' => {}
+                    _ => return Err(eyre!("unknown map tile '{ch}'")),
+                }
+            }
+        }
+
+        if asteroids.is_empty() {
+            bail!("there are no asteroids on the map");
+        }
+
+        Ok(Self { asteroids })
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let map: AsteroidMap = ".#..#
+.....
+#####
+....#
+...##"
+        .parse()
+        .unwrap();
+
+    assert_eq!(map.best_station().unwrap(), ((3, 4), 8));
+
+    let map: AsteroidMap = ".#....#####...#..
+##...##.#####..##
+##...#...#.#####.
+..#.....#...###..
+..#.#.....#....##"
+        .parse()
+        .unwrap();
+
+    let order = map.vaporization_order((8, 3)).take(9).collect_vec();
+    assert_eq!(
+        order,
+        [
+            (8, 1),
+            (9, 0),
+            (9, 1),
+            (10, 0),
+            (9, 2),
+            (11, 1),
+            (12, 1),
+            (11, 2),
+            (15, 1)
+        ]
+    );
+}
@@ -0,0 +1,123 @@
+use eyre::{Result, bail};
+use fnv::FnvHashMap;
+use itertools::Itertools;
+
+use crate::intcode::{Node, Status};
+use aoc_common::ocr;
+use aoc_meta::Problem;
+
+pub const SPACE_POLICE: Problem = Problem::solved(
+    &|input| {
+        let mut brain = Node::new(input.parse()?);
+        paint(&mut brain, false).map(|hull| hull.len())
+    },
+    &|input| {
+        let mut brain = Node::new(input.parse()?);
+        let hull = paint(&mut brain, true)?;
+        registration_identifier(&hull)
+    },
+);
+
+/// Whether each panel that's been painted is white.
+type Hull = FnvHashMap<(i64, i64), bool>;
+
+/// Something that tells the robot what to paint and which way to turn (`false` for left) given the color of the
+/// panel it's on, until it stops.
+trait Brain {
+    fn think(&mut self, white: bool) -> Result<Option<(bool, bool)>>;
+}
+
+impl Brain for Node {
+    fn think(&mut self, white: bool) -> Result<Option<(bool, bool)>> {
+        self.send(i64::from(white));
+        let status = self.run()?;
+
+        let outputs = self.outputs().collect_vec();
+
+        match (status, &outputs[..]) {
+            (Status::Halted, []) => Ok(None),
+            (_, &[color, turn]) => Ok(Some((color == 1, turn == 1))),
+            (_, outputs) => bail!("expected a color and a turn, found {outputs:?}"),
+        }
+    }
+}
+
+/// Paint the hull, starting on a single white panel if `start_white`.
+fn paint(brain: &mut impl Brain, start_white: bool) -> Result<Hull> {
+    let mut hull = Hull::default();
+    if start_white {
+        hull.insert((0, 0), true);
+    }
+
+    let (mut position, mut direction) = ((0, 0), (0, -1));
+
+    while let Some((white, right)) = brain.think(hull.get(&position).copied().unwrap_or(false))? {
+        hull.insert(position, white);
+
+        let (dx, dy) = direction;
+        direction = if right { (-dy, dx) } else { (dy, -dx) };
+        position = (position.0 + direction.0, position.1 + direction.1);
+    }
+
+    Ok(hull)
+}
+
+fn registration_identifier(hull: &Hull) -> Result<String> {
+    let white = hull
+        .iter()
+        .filter(|&(_, &white)| white)
+        .map(|(&panel, _)| panel)
+        .collect_vec();
+    let (min_x, max_x) = white
+        .iter()
+        .map(|&(x, _)| x)
+        .minmax()
+        .into_option()
+        .unwrap_or((0, 0));
+    let (min_y, max_y) = white
+        .iter()
+        .map(|&(_, y)| y)
+        .minmax()
+        .into_option()
+        .unwrap_or((0, 0));
+
+    ocr::read_grid(
+        (min_y..=max_y)
+            .map(|y| (min_x..=max_x).map(move |x| hull.get(&(x, y)).copied().unwrap_or(false))),
+    )
+}
+
+#[cfg(test)]
+impl Brain for std::vec::IntoIter<(bool, bool)> {
+    fn think(&mut self, _: bool) -> Result<Option<(bool, bool)>> {
+        Ok(self.next())
+    }
+}
+
+#[test]
+fn example() {
+    let mut brain = vec![
+        (true, false),
+        (false, false),
+        (true, false),
+        (true, false),
+        (false, true),
+        (true, false),
+        (true, false),
+    ]
+    .into_iter();
+
+    let hull = paint(&mut brain, false).unwrap();
+    assert_eq!(hull.len(), 6);
+    assert_eq!(hull.values().filter(|&&white| white).count(), 4);
+}
+
+#[test]
+fn intcode_brain() {
+    // Paints every panel white and turns right, four times over.
+    let program = "3,100,104,1,104,1,1001,101,1,101,1008,101,4,102,1006,102,0,99";
+    let mut brain = Node::new(program.parse().unwrap());
+
+    let hull = paint(&mut brain, false).unwrap();
+    assert_eq!(hull.len(), 4);
+}
@@ -0,0 +1,146 @@
+use std::str::FromStr;
+
+use eyre::{OptionExt, Report, Result, eyre};
+
+use aoc_common::math::lcm;
+use aoc_meta::Problem;
+
+pub const THE_N_BODY_PROBLEM: Problem = Problem::solved(
+    &|input| input.parse().map(|moons: Moons| moons.energy_after(1000)),
+    &|input| input.parse().and_then(|moons: Moons| moons.period()),
+);
+
+/// The position and velocity of every moon along one axis. The axes don't affect each other at all.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Axis {
+    positions: Vec<i64>,
+    velocities: Vec<i64>,
+}
+
+impl Axis {
+    fn step(&mut self) {
+        for (i, &a) in self.positions.iter().enumerate() {
+            let pull: i64 = self.positions.iter().map(|&b| (b - a).signum()).sum();
+            self.velocities[i] += pull;
+        }
+
+        for (position, velocity) in self.positions.iter_mut().zip(&self.velocities) {
+            *position += velocity;
+        }
+    }
+
+    /// How many steps until the moons are back where they started along this axis. Each step can be undone, so the
+    /// first state to repeat is always the initial one.
+    fn period(&self) -> u64 {
+        let mut axis = self.clone();
+        let mut steps = 0;
+
+        loop {
+            axis.step();
+            steps += 1;
+
+            if axis == *self {
+                return steps;
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Moons {
+    axes: [Axis; 3],
+}
+
+impl Moons {
+    fn energy_after(mut self, steps: usize) -> i64 {
+        for _ in 0..steps {
+            self.axes.iter_mut().for_each(Axis::step);
+        }
+
+        (0..self.axes[0].positions.len())
+            .map(|moon| {
+                let potential: i64 = self
+                    .axes
+                    .iter()
+                    .map(|axis| axis.positions[moon].abs())
+                    .sum();
+                let kinetic: i64 = self
+                    .axes
+                    .iter()
+                    .map(|axis| axis.velocities[moon].abs())
+                    .sum();
+                potential * kinetic
+            })
+            .sum()
+    }
+
+    /// The number of steps until the moons are all back where they started, which is the first time every axis is
+    /// back where it started at once.
+    fn period(&self) -> Result<u64> {
+        self.axes
+            .iter()
+            .try_fold(1, |period, axis| lcm(period, axis.period()))
+            .ok_or_eyre("the period is too long to count")
+    }
+}
+
+impl FromStr for Moons {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut axes = [(); 3].map(|_| Axis {
+            positions: Vec::new(),
+            velocities: Vec::new(),
+        });
+
+        for line in s.lines() {
+            let coordinates = line
+                .strip_prefix('<')
+                .and_then(|line| line.strip_suffix('>'))
+                .ok_or_else(|| eyre!("unable to parse \"{line}\""))?
+                .split(", ")
+                .zip(["x=", "y=", "z="])
+                .map(|(coordinate, prefix)| {
+                    let value = coordinate
+                        .strip_prefix(prefix)
+                        .ok_or_else(|| eyre!("expected \"{prefix}\" in \"{line}\""))?;
+                    Ok::<i64, Report>(value.parse()?)
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+            let Ok::<[_; 3], _>(coordinates) = coordinates.try_into() else {
+                return Err(eyre!("expected three coordinates in \"{line}\""));
+            };
+
+            for (axis, coordinate) in axes.iter_mut().zip(coordinates) {
+                axis.positions.push(coordinate);
+                axis.velocities.push(0);
+            }
+        }
+
+        Ok(Self { axes })
+    }
+}
+
+#[test]
+fn example() {
+    let moons: Moons = "<x=-1, y=0, z=2>
+<x=2, y=-10, z=-7>
+<x=4, y=-8, z=8>
+<x=3, y=5, z=-1>"
+        .parse()
+        .unwrap();
+
+    assert_eq!(moons.clone().energy_after(10), 179);
+    assert_eq!(moons.period().unwrap(), 2772);
+
+    let moons: Moons = "<x=-8, y=-10, z=0>
+<x=5, y=5, z=10>
+<x=2, y=-7, z=3>
+<x=9, y=-8, z=-3>"
+        .parse()
+        .unwrap();
+
+    assert_eq!(moons.clone().energy_after(100), 1940);
+    assert_eq!(moons.period().unwrap(), 4686774924);
+}
@@ -0,0 +1,113 @@
+use eyre::{Result, bail};
+use fnv::FnvHashMap;
+
+use crate::intcode::{Machine, Node, Status};
+use aoc_meta::Problem;
+
+pub const CARE_PACKAGE: Problem = Problem::solved(
+    &|input| {
+        let mut cabinet = Node::new(input.parse()?);
+        cabinet.run()?;
+
+        let mut screen = Screen::default();
+        screen.draw(&cabinet.outputs().collect::<Vec<_>>())?;
+        Ok::<_, eyre::Report>(screen.count(Tile::Block))
+    },
+    &|input| play(input.parse()?),
+);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    Empty,
+    Wall,
+    Block,
+    Paddle,
+    Ball,
+}
+
+#[derive(Debug, Default)]
+struct Screen {
+    tiles: FnvHashMap<(i64, i64), Tile>,
+    score: i64,
+    ball: i64,
+    paddle: i64,
+}
+
+impl Screen {
+    /// Draw the arcade cabinet's output, which comes in threes: an `x` and `y` position and the tile to draw there,
+    /// or the score if the position is `(-1, 0)`.
+    fn draw(&mut self, outputs: &[i64]) -> Result<()> {
+        let (chunks, rest) = outputs.as_chunks();
+        if !rest.is_empty() {
+            bail!("the cabinet's output doesn't come in threes");
+        }
+
+        for &[x, y, value] in chunks {
+            if (x, y) == (-1, 0) {
+                self.score = value;
+                continue;
+            }
+
+            let tile = match value {
+                0 => Tile::Empty,
+                1 => Tile::Wall,
+                2 => Tile::Block,
+                3 => Tile::Paddle,
+                4 => Tile::Ball,
+                _ => bail!("unknown tile {value}"),
+            };
+
+            match tile {
+                Tile::Paddle => self.paddle = x,
+                Tile::Ball => self.ball = x,
+                _ => {}
+            }
+
+            self.tiles.insert((x, y), tile);
+        }
+
+        Ok(())
+    }
+
+    fn count(&self, tile: Tile) -> usize {
+        self.tiles.values().filter(|&&t| t == tile).count()
+    }
+}
+
+/// Play the game for free, always moving the paddle towards the ball, and return the score once every block is
+/// broken.
+fn play(mut machine: Machine) -> Result<i64> {
+    // Two quarters
+    machine.write(0, 2);
+
+    let mut cabinet = Node::new(machine);
+    let mut screen = Screen::default();
+
+    loop {
+        let status = cabinet.run()?;
+        screen.draw(&cabinet.outputs().collect::<Vec<_>>())?;
+
+        match status {
+            Status::Halted if screen.count(Tile::Block) > 0 => {
+                bail!("the game ended with blocks left")
+            }
+            Status::Halted => return Ok(screen.score),
+            Status::NeedsInput => cabinet.send((screen.ball - screen.paddle).signum()),
+        }
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let mut screen = Screen::default();
+    screen
+        .draw(&[1, 2, 3, 6, 5, 4, 2, 2, 2, -1, 0, 12345])
+        .unwrap();
+
+    assert_eq!(screen.paddle, 1);
+    assert_eq!(screen.ball, 6);
+    assert_eq!(screen.count(Tile::Block), 1);
+    assert_eq!(screen.score, 12345);
+}
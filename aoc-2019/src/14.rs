@@ -0,0 +1,180 @@
+use std::str::FromStr;
+
+use eyre::{OptionExt, Report, Result, bail, eyre};
+use fnv::FnvHashMap;
+
+use aoc_meta::Problem;
+
+pub const SPACE_STOICHIOMETRY: Problem = Problem::solved(
+    &|input| {
+        input
+            .parse()
+            .and_then(|nanofactory: Nanofactory| nanofactory.ore_for_fuel(1))
+    },
+    &|input| {
+        input
+            .parse()
+            .and_then(|nanofactory: Nanofactory| nanofactory.fuel_from_ore(1_000_000_000_000))
+    },
+);
+
+#[derive(Debug)]
+struct Reaction {
+    quantity: u64,
+    inputs: Vec<(String, u64)>,
+}
+
+#[derive(Debug)]
+struct Nanofactory {
+    /// The reaction that produces each chemical.
+    reactions: FnvHashMap<String, Reaction>,
+    /// Every chemical, ordered so that each comes before everything it's made from.
+    order: Vec<String>,
+}
+
+impl Nanofactory {
+    /// The least ore needed to make `fuel` units of fuel.
+    fn ore_for_fuel(&self, fuel: u64) -> Result<u64> {
+        let mut needed: FnvHashMap<&str, u64> = FnvHashMap::from_iter([("FUEL", fuel)]);
+
+        // Working through chemicals so that nothing else will ever need more of each one, leftovers never go to
+        // waste in more than one reaction.
+        for chemical in &self.order {
+            let Some(&amount) = needed.get(chemical.as_str()) else {
+                continue;
+            };
+
+            let reaction = &self.reactions[chemical];
+            let times = amount.div_ceil(reaction.quantity);
+
+            for (input, quantity) in &reaction.inputs {
+                let total = needed.entry(input).or_default();
+                *total = quantity
+                    .checked_mul(times)
+                    .and_then(|more| total.checked_add(more))
+                    .ok_or_eyre("too much ore to count")?;
+            }
+        }
+
+        Ok(needed.get("ORE").copied().unwrap_or(0))
+    }
+
+    /// The most fuel that can be made from `ore` units of ore.
+    fn fuel_from_ore(&self, ore: u64) -> Result<u64> {
+        let (mut low, mut high) = (0, 1);
+
+        while self.ore_for_fuel(high)? <= ore {
+            (low, high) = (high, high * 2);
+        }
+
+        // The most fuel is at least `low` and less than `high`.
+        while high - low > 1 {
+            let mid = low + (high - low) / 2;
+            if self.ore_for_fuel(mid)? <= ore {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+
+        Ok(low)
+    }
+}
+
+impl FromStr for Nanofactory {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let chemical = |s: &str| -> Result<(String, u64)> {
+            let Some((quantity, name)) = s.trim().split_once(' ') else {
+                bail!("unable to split \"{s}\" into a quantity and chemical")
+            };
+
+            Ok((name.to_owned(), quantity.parse()?))
+        };
+
+        let mut reactions = FnvHashMap::default();
+        for line in s.lines() {
+            let Some((inputs, output)) = line.split_once(" => ") else {
+                bail!("unable to split \"{line}\" into inputs and output")
+            };
+
+            let (output, quantity) = chemical(output)?;
+            let inputs = inputs.split(", ").map(chemical).collect::<Result<_>>()?;
+
+            if reactions
+                .insert(output.clone(), Reaction { quantity, inputs })
+                .is_some()
+            {
+                bail!("more than one reaction makes {output}");
+            }
+        }
+
+        // Depth-first post-order from FUEL, reversed, puts every chemical before its ingredients.
+        let mut order = Vec::new();
+        let mut state: FnvHashMap<&str, bool> = FnvHashMap::default();
+        let mut stack = vec![("FUEL", false)];
+
+        while let Some((chemical, finished)) = stack.pop() {
+            if finished {
+                state.insert(chemical, true);
+                order.push(chemical.to_owned());
+                continue;
+            }
+
+            match state.get(chemical) {
+                Some(true) => continue,
+                Some(false) => bail!("{chemical} is made from itself"),
+                None => {}
+            }
+
+            state.insert(chemical, false);
+            stack.push((chemical, true));
+
+            let reaction = reactions
+                .get(chemical)
+                .ok_or_else(|| eyre!("nothing makes {chemical}"))?;
+
+            for (input, _) in &reaction.inputs {
+                if input != "ORE" && state.get(input.as_str()) != Some(&true) {
+                    stack.push((input, false));
+                }
+            }
+        }
+
+        order.reverse();
+        Ok(Self { reactions, order })
+    }
+}
+
+#[test]
+fn example() {
+    let nanofactory: Nanofactory = "10 ORE => 10 A
+1 ORE => 1 B
+7 A, 1 B => 1 C
+7 A, 1 C => 1 D
+7 A, 1 D => 1 E
+7 A, 1 E => 1 FUEL"
+        .parse()
+        .unwrap();
+
+    assert_eq!(nanofactory.ore_for_fuel(1).unwrap(), 31);
+
+    let nanofactory: Nanofactory = "157 ORE => 5 NZVS
+165 ORE => 6 DCFZ
+44 XJWVT, 5 KHKGT, 1 QDVJ, 29 NZVS, 9 GPVTF, 48 HKGWZ => 1 FUEL
+12 HKGWZ, 1 GPVTF, 8 PSHF => 9 QDVJ
+179 ORE => 7 PSHF
+177 ORE => 5 HKGWZ
+7 DCFZ, 7 PSHF => 2 XJWVT
+165 ORE => 2 GPVTF
+3 DCFZ, 7 NZVS, 5 HKGWZ, 10 PSHF => 8 KHKGT"
+        .parse()
+        .unwrap();
+
+    assert_eq!(nanofactory.ore_for_fuel(1).unwrap(), 13312);
+    assert_eq!(
+        nanofactory.fuel_from_ore(1_000_000_000_000).unwrap(),
+        82892753
+    );
+}
@@ -0,0 +1,192 @@
+use std::collections::VecDeque;
+
+use eyre::{OptionExt, Result, bail};
+use fnv::{FnvHashMap, FnvHashSet};
+
+use crate::intcode::Node;
+use aoc_meta::Problem;
+
+pub const OXYGEN_SYSTEM: Problem = Problem::solved(
+    &|input| {
+        let area = Area::explore(&mut Node::new(input.parse()?))?;
+        area.distances_from((0, 0))
+            .get(&area.oxygen_system)
+            .copied()
+            .ok_or_eyre("the droid can't reach the oxygen system")
+    },
+    &|input| {
+        let area = Area::explore(&mut Node::new(input.parse()?))?;
+        area.distances_from(area.oxygen_system)
+            .into_values()
+            .max()
+            .ok_or_eyre("there's nowhere for the oxygen to go")
+    },
+);
+
+const DIRECTIONS: [(i64, (i64, i64)); 4] = [(1, (0, -1)), (2, (0, 1)), (3, (-1, 0)), (4, (1, 0))];
+
+/// What happened when the droid tried to move.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Reply {
+    Wall,
+    Moved,
+    FoundOxygenSystem,
+}
+
+trait Droid {
+    /// Try to move north (1), south (2), west (3) or east (4).
+    fn try_move(&mut self, direction: i64) -> Result<Reply>;
+}
+
+impl Droid for Node {
+    fn try_move(&mut self, direction: i64) -> Result<Reply> {
+        match self.run_with([direction])?[..] {
+            [0] => Ok(Reply::Wall),
+            [1] => Ok(Reply::Moved),
+            [2] => Ok(Reply::FoundOxygenSystem),
+            ref outputs => bail!("expected a status code, found {outputs:?}"),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Area {
+    open: Vec<(i64, i64)>,
+    oxygen_system: (i64, i64),
+}
+
+impl Area {
+    /// Walk the droid down every passage, backtracking at dead ends, to map out the whole area.
+    fn explore(droid: &mut impl Droid) -> Result<Self> {
+        let mut seen: FnvHashMap<(i64, i64), bool> = FnvHashMap::from_iter([((0, 0), true)]);
+        let mut oxygen_system = None;
+        // The way back from each step of the current path.
+        let mut path: Vec<i64> = Vec::new();
+        let mut position = (0, 0);
+
+        loop {
+            let unexplored = DIRECTIONS
+                .iter()
+                .find(|(_, (dx, dy))| !seen.contains_key(&(position.0 + dx, position.1 + dy)));
+
+            let Some(&(direction, (dx, dy))) = unexplored else {
+                let Some(back) = path.pop() else {
+                    break;
+                };
+
+                if droid.try_move(back)? == Reply::Wall {
+                    bail!("the droid couldn't go back the way it came");
+                }
+
+                let (_, (dx, dy)) = DIRECTIONS[back as usize - 1];
+                position = (position.0 + dx, position.1 + dy);
+                continue;
+            };
+
+            let next = (position.0 + dx, position.1 + dy);
+            let reply = droid.try_move(direction)?;
+            seen.insert(next, reply != Reply::Wall);
+
+            if reply == Reply::Wall {
+                continue;
+            }
+
+            if reply == Reply::FoundOxygenSystem {
+                oxygen_system = Some(next);
+            }
+
+            // North and south are 1 and 2, west and east are 3 and 4.
+            path.push(if direction % 2 == 1 {
+                direction + 1
+            } else {
+                direction - 1
+            });
+            position = next;
+        }
+
+        Ok(Self {
+            open: seen
+                .into_iter()
+                .filter(|&(_, open)| open)
+                .map(|(p, _)| p)
+                .collect(),
+            oxygen_system: oxygen_system.ok_or_eyre("the droid never found the oxygen system")?,
+        })
+    }
+
+    /// The fewest moves from `start` to every open position.
+    fn distances_from(&self, start: (i64, i64)) -> FnvHashMap<(i64, i64), usize> {
+        let open: FnvHashSet<_> = self.open.iter().copied().collect();
+        let mut distances = FnvHashMap::from_iter([(start, 0)]);
+        let mut queue = VecDeque::from([start]);
+
+        while let Some((x, y)) = queue.pop_front() {
+            let distance = distances[&(x, y)];
+
+            for (_, (dx, dy)) in DIRECTIONS {
+                let next = (x + dx, y + dy);
+                if open.contains(&next) && !distances.contains_key(&next) {
+                    distances.insert(next, distance + 1);
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        distances
+    }
+}
+
+#[cfg(test)]
+struct Map {
+    tiles: Vec<Vec<u8>>,
+    position: (i64, i64),
+}
+
+#[cfg(test)]
+impl Droid for Map {
+    fn try_move(&mut self, direction: i64) -> Result<Reply> {
+        let (_, (dx, dy)) = DIRECTIONS[direction as usize - 1];
+        let (x, y) = (self.position.0 + dx, self.position.1 + dy);
+
+        match self.tiles[y as usize][x as usize] {
+            b'#' => Ok(Reply::Wall),
+            tile => {
+                self.position = (x, y);
+                Ok(if tile == b'O' {
+                    Reply::FoundOxygenSystem
+                } else {
+                    Reply::Moved
+                })
+            }
+        }
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let mut map = Map {
+        tiles: [" ##   ", "#..## ", "#.#..#", "#.O.# ", " ###  "]
+            .map(|row| row.as_bytes().to_vec())
+            .to_vec(),
+        position: (1, 1),
+    };
+
+    // Treat the blank corners as walls too.
+    for row in &mut map.tiles {
+        for tile in row.iter_mut().filter(|tile| **tile == b' ') {
+            *tile = b'#';
+        }
+    }
+
+    let area = Area::explore(&mut map).unwrap();
+    // The droid's starting position is the origin, so everything is relative to it.
+    assert_eq!(area.oxygen_system, (1, 2));
+    assert_eq!(area.open.len(), 8);
+    assert_eq!(area.distances_from((0, 0))[&area.oxygen_system], 3);
+    assert_eq!(
+        area.distances_from(area.oxygen_system).into_values().max(),
+        Some(4)
+    );
+}
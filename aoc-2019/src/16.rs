@@ -0,0 +1,100 @@
+use eyre::{Result, bail};
+use itertools::Itertools;
+
+use aoc_meta::Problem;
+
+pub const FLAWED_FREQUENCY_TRANSMISSION: Problem = Problem::solved(
+    &|input| parse_signal(input).map(|signal| first_digits_after(signal, 100)),
+    &|input| real_message(&parse_signal(input)?),
+);
+
+fn parse_signal(input: &str) -> Result<Vec<u8>> {
+    input
+        .trim_end()
+        .chars()
+        .map(|ch| match ch.to_digit(10) {
+            Some(digit) => Ok(digit as u8),
+            None => bail!("unexpected character '{ch}' in the signal"),
+        })
+        .collect()
+}
+
+/// The first eight digits of the signal after `phases` phases of FFT.
+fn first_digits_after(mut signal: Vec<u8>, phases: usize) -> String {
+    for _ in 0..phases {
+        signal = phase(&signal);
+    }
+
+    signal.iter().take(8).join("")
+}
+
+/// Run one phase of the FFT. Output element `i` repeats each element of the pattern `0, 1, 0, -1` `i + 1` times
+/// (skipping the very first), so it adds up runs of `i + 1` inputs at a time, which a running total makes quick.
+fn phase(signal: &[u8]) -> Vec<u8> {
+    let mut prefix = vec![0i64; signal.len() + 1];
+    for (i, &digit) in signal.iter().enumerate() {
+        prefix[i + 1] = prefix[i] + i64::from(digit);
+    }
+
+    let sum = |start: usize, len: usize| {
+        let (start, end) = (start.min(signal.len()), (start + len).min(signal.len()));
+        prefix[end] - prefix[start]
+    };
+
+    (1..=signal.len())
+        .map(|run| {
+            let total: i64 = (run - 1..signal.len())
+                .step_by(4 * run)
+                .map(|start| sum(start, run) - sum(start + 2 * run, run))
+                .sum();
+
+            (total.abs() % 10) as u8
+        })
+        .collect()
+}
+
+/// The eight digits at the offset given by the first seven digits, after 100 phases on the signal repeated 10,000
+/// times. The offset is in the second half of the signal, where every pattern is zeros followed by ones, so each
+/// output digit is just the sum of the inputs from there to the end.
+fn real_message(signal: &[u8]) -> Result<String> {
+    let len = signal.len() * 10_000;
+    let offset = signal
+        .iter()
+        .take(7)
+        .fold(0, |n, &digit| n * 10 + usize::from(digit));
+
+    if offset < len / 2 || offset + 8 > len {
+        bail!("the message offset {offset} isn't in the second half of the signal");
+    }
+
+    let mut tail = (offset..len)
+        .map(|i| signal[i % signal.len()])
+        .collect_vec();
+
+    for _ in 0..100 {
+        let mut sum = 0;
+        for digit in tail.iter_mut().rev() {
+            sum = (sum + *digit) % 10;
+            *digit = sum;
+        }
+    }
+
+    Ok(tail.iter().take(8).join(""))
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let mut signal = parse_signal("12345678").unwrap();
+    for expected in ["48226158", "34040438", "03415518", "01029498"] {
+        signal = phase(&signal);
+        assert_eq!(signal.iter().join(""), expected);
+    }
+
+    let signal = parse_signal("80871224585914546619083218645595").unwrap();
+    assert_eq!(first_digits_after(signal, 100), "24176176");
+
+    let signal = parse_signal("03036732577212944063491565474664").unwrap();
+    assert_eq!(real_message(&signal).unwrap(), "84462026");
+}
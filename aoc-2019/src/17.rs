@@ -0,0 +1,258 @@
+use std::str::FromStr;
+
+use eyre::{OptionExt, Report, Result, bail};
+use itertools::Itertools;
+
+use crate::intcode::{Machine, Node};
+use aoc_meta::Problem;
+
+pub const SET_AND_FORGET: Problem = Problem::solved(
+    &|input| camera_view(input.parse()?).map(|scaffolding| scaffolding.alignment_parameters()),
+    &|input| collect_dust(input.parse()?),
+);
+
+/// The longest a movement routine or function can be, not counting the newline.
+const MAX_ROUTINE_LEN: usize = 20;
+
+fn camera_view(machine: Machine) -> Result<Scaffolding> {
+    let mut ascii = Node::new(machine);
+    ascii.run()?;
+    ascii.read_ascii().0.parse()
+}
+
+/// Work out how to get the robot to visit every part of the scaffolding, then wake it up and send it on its way.
+/// It reports how much dust it collected at the end.
+fn collect_dust(machine: Machine) -> Result<i64> {
+    let scaffolding = camera_view(machine.clone())?;
+    let path = scaffolding.path();
+    let (main, functions) =
+        compress(&path).ok_or_eyre("the path doesn't fit into three movement functions")?;
+
+    let mut ascii = Node::new(machine);
+    ascii.machine.write(0, 2);
+
+    ascii.send_ascii(&main.iter().map(|&f| char::from(b'A' + f as u8)).join(","));
+    for function in &functions {
+        ascii.send_ascii(&routine(function));
+    }
+    // No continuous video feed, thanks.
+    ascii.send_ascii("n");
+
+    ascii.run()?;
+    match ascii.read_ascii() {
+        (_, Some(dust)) => Ok(dust),
+        (text, None) => bail!("the robot didn't report any dust:\n{text}"),
+    }
+}
+
+/// A turn, left or right, followed by some number of steps forward.
+type Move = (char, usize);
+
+fn routine(moves: &[Move]) -> String {
+    moves
+        .iter()
+        .map(|(turn, steps)| format!("{turn},{steps}"))
+        .join(",")
+}
+
+/// Split `path` into a main routine calling up to three functions, with every routine short enough for the robot's
+/// memory.
+fn compress(path: &[Move]) -> Option<(Vec<usize>, Vec<&[Move]>)> {
+    fn search<'p>(
+        rest: &'p [Move],
+        main: &mut Vec<usize>,
+        functions: &mut Vec<&'p [Move]>,
+    ) -> bool {
+        if rest.is_empty() {
+            return true;
+        }
+
+        // Calling a function takes one character, plus a comma if it's not the first.
+        if 2 * main.len() + 1 > MAX_ROUTINE_LEN {
+            return false;
+        }
+
+        for f in 0..functions.len() {
+            if rest.starts_with(functions[f]) {
+                main.push(f);
+                if search(&rest[functions[f].len()..], main, functions) {
+                    return true;
+                }
+                main.pop();
+            }
+        }
+
+        if functions.len() < 3 {
+            for len in 1..=rest.len() {
+                if routine(&rest[..len]).len() > MAX_ROUTINE_LEN {
+                    break;
+                }
+
+                functions.push(&rest[..len]);
+                main.push(functions.len() - 1);
+                if search(&rest[len..], main, functions) {
+                    return true;
+                }
+                main.pop();
+                functions.pop();
+            }
+        }
+
+        false
+    }
+
+    let (mut main, mut functions) = (Vec::new(), Vec::new());
+    search(path, &mut main, &mut functions).then_some((main, functions))
+}
+
+#[derive(Debug)]
+struct Scaffolding {
+    tiles: Vec<Vec<u8>>,
+    robot: (usize, usize),
+}
+
+impl Scaffolding {
+    fn is_scaffold(&self, x: isize, y: isize) -> bool {
+        let (Ok(x), Ok(y)) = (usize::try_from(x), usize::try_from(y)) else {
+            return false;
+        };
+
+        self.tiles
+            .get(y)
+            .and_then(|row| row.get(x))
+            .is_some_and(|&tile| tile != b'.')
+    }
+
+    /// The sum over every intersection of scaffolding of its `x` times its `y` coordinate.
+    fn alignment_parameters(&self) -> usize {
+        self.tiles
+            .iter()
+            .enumerate()
+            .flat_map(|(y, row)| (0..row.len()).map(move |x| (x, y)))
+            .filter(|&(x, y)| {
+                let (x, y) = (x as isize, y as isize);
+                [(0, 0), (0, -1), (1, 0), (0, 1), (-1, 0)]
+                    .iter()
+                    .all(|&(dx, dy)| self.is_scaffold(x + dx, y + dy))
+            })
+            .map(|(x, y)| x * y)
+            .sum()
+    }
+
+    /// The moves that take the robot all the way along the scaffolding, going straight over every intersection.
+    fn path(&self) -> Vec<Move> {
+        let (mut x, mut y) = (self.robot.0 as isize, self.robot.1 as isize);
+        let (mut dx, mut dy) = match self.tiles[self.robot.1][self.robot.0] {
+            b'^' => (0, -1),
+            b'v' => (0, 1),
+            b'<' => (-1, 0),
+            _ => (1, 0),
+        };
+
+        let mut path = Vec::new();
+        loop {
+            let (turn, (ndx, ndy)) = if self.is_scaffold(x + dy, y - dx) {
+                ('L', (dy, -dx))
+            } else if self.is_scaffold(x - dy, y + dx) {
+                ('R', (-dy, dx))
+            } else {
+                return path;
+            };
+
+            (dx, dy) = (ndx, ndy);
+            let mut steps = 0;
+            while self.is_scaffold(x + dx, y + dy) {
+                (x, y, steps) = (x + dx, y + dy, steps + 1);
+            }
+
+            path.push((turn, steps));
+        }
+    }
+}
+
+impl FromStr for Scaffolding {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let tiles = s
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(|line| line.as_bytes().to_vec())
+            .collect_vec();
+
+        let robots = tiles
+            .iter()
+            .enumerate()
+            .flat_map(|(y, row)| {
+                row.iter()
+                    .positions(|tile| b"^v<>".contains(tile))
+                    .map(move |x| (x, y))
+            })
+            .collect_vec();
+
+        let [robot] = robots[..] else {
+            bail!(
+                "expected one robot on the scaffolding, found {}",
+                robots.len()
+            );
+        };
+
+        Ok(Self { tiles, robot })
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let scaffolding: Scaffolding = "..#..........
+..#..........
+#######...###
+#.#...#...#.#
+#############
+..#...#...#..
+..#####...^.."
+        .parse()
+        .unwrap();
+
+    assert_eq!(scaffolding.alignment_parameters(), 76);
+
+    let scaffolding: Scaffolding = "#######...#####
+#.....#...#...#
+#.....#...#...#
+......#...#...#
+......#...###.#
+......#.....#.#
+^########...#.#
+......#.#...#.#
+......#########
+........#...#..
+....#########..
+....#...#......
+....#...#......
+....#...#......
+....#####......"
+        .parse()
+        .unwrap();
+
+    let path = scaffolding.path();
+    assert_eq!(
+        routine(&path),
+        "R,8,R,8,R,4,R,4,R,8,L,6,L,2,R,4,R,4,R,8,R,8,R,8,L,6,L,2"
+    );
+
+    let (main, functions) = compress(&path).unwrap();
+    assert!(functions.len() <= 3);
+    assert!(
+        functions
+            .iter()
+            .all(|function| routine(function).len() <= MAX_ROUTINE_LEN)
+    );
+
+    let expanded = main
+        .iter()
+        .flat_map(|&f| functions[f])
+        .copied()
+        .collect_vec();
+    assert_eq!(expanded, path);
+}
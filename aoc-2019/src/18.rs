@@ -0,0 +1,273 @@
+use std::collections::VecDeque;
+use std::str::FromStr;
+
+use eyre::{OptionExt, Report, Result, bail};
+use itertools::Itertools;
+
+use aoc_common::search::{self, SearchSpace};
+use aoc_meta::Problem;
+
+pub const MANY_WORLDS_INTERPRETATION: Problem = Problem::solved(
+    &|input| input.parse().and_then(|vault: Vault| vault.fewest_steps()),
+    &|input| {
+        input
+            .parse()
+            .and_then(|vault: Vault| vault.split_up().fewest_steps())
+    },
+);
+
+#[derive(Debug, Clone)]
+struct Vault {
+    width: usize,
+    tiles: Vec<u8>,
+}
+
+/// The way from somewhere to a key.
+#[derive(Debug, Clone, Copy)]
+struct Route {
+    key: usize,
+    steps: usize,
+    /// The doors in the way, as a mask of the keys that open them.
+    doors: u32,
+    /// Other keys picked up on the way.
+    keys: u32,
+}
+
+/// Every robot's position (as the key it's on, or one of the entrances after the keys), and the keys collected.
+type State = ([u8; 4], u32);
+
+#[derive(Debug)]
+struct Collection {
+    robots: usize,
+    /// `routes[i]` is every key reachable from key `i`, or from entrance `i - 26`.
+    routes: Vec<Vec<Route>>,
+    all_keys: u32,
+}
+
+impl Vault {
+    fn entrances(&self) -> Vec<usize> {
+        self.tiles.iter().positions(|&tile| tile == b'@').collect()
+    }
+
+    /// Wall off the middle of the vault into four sections, each with an entrance of its own.
+    fn split_up(mut self) -> Self {
+        let entrances = self.entrances();
+        if entrances.len() == 4 {
+            return self;
+        }
+
+        let w = self.width;
+        for &entrance in &entrances {
+            for (offset, tile) in [
+                (entrance - w - 1, b'@'),
+                (entrance - w, b'#'),
+                (entrance - w + 1, b'@'),
+                (entrance - 1, b'#'),
+                (entrance, b'#'),
+                (entrance + 1, b'#'),
+                (entrance + w - 1, b'@'),
+                (entrance + w, b'#'),
+                (entrance + w + 1, b'@'),
+            ] {
+                self.tiles[offset] = tile;
+            }
+        }
+
+        self
+    }
+
+    /// Breadth-first search from `start` for every key, noting the doors and keys on the way to each.
+    fn routes_from(&self, start: usize) -> Vec<Route> {
+        let mut seen = vec![false; self.tiles.len()];
+        let mut queue = VecDeque::from([(start, 0, 0, 0)]);
+        let mut routes = Vec::new();
+        seen[start] = true;
+
+        while let Some((position, steps, doors, keys)) = queue.pop_front() {
+            let (mut doors, mut keys) = (doors, keys);
+
+            match self.tiles[position] {
+                key @ b'a'..=b'z' if position != start => {
+                    let key = usize::from(key - b'a');
+                    routes.push(Route {
+                        key,
+                        steps,
+                        doors,
+                        keys,
+                    });
+                    keys |= 1 << key;
+                }
+                door @ b'A'..=b'Z' => doors |= 1 << (door - b'A'),
+                _ => {}
+            }
+
+            for next in [
+                position - self.width,
+                position + 1,
+                position + self.width,
+                position - 1,
+            ] {
+                if self.tiles[next] != b'#' && !std::mem::replace(&mut seen[next], true) {
+                    queue.push_back((next, steps + 1, doors, keys));
+                }
+            }
+        }
+
+        routes
+    }
+
+    /// The fewest steps for the robots to collect every key.
+    fn fewest_steps(&self) -> Result<usize> {
+        let entrances = self.entrances();
+        if entrances.is_empty() || entrances.len() > 4 {
+            bail!("expected one to four entrances, found {}", entrances.len());
+        }
+
+        let mut keys = [None; 26];
+        for (position, &tile) in self.tiles.iter().enumerate() {
+            if tile.is_ascii_lowercase() {
+                keys[usize::from(tile - b'a')] = Some(position);
+            }
+        }
+
+        let routes = keys
+            .iter()
+            .map(|&key| key.map_or_else(Vec::new, |position| self.routes_from(position)))
+            .chain(entrances.iter().map(|&entrance| self.routes_from(entrance)))
+            .collect();
+
+        let collection = Collection {
+            robots: entrances.len(),
+            routes,
+            all_keys: keys
+                .iter()
+                .enumerate()
+                .filter(|(_, key)| key.is_some())
+                .fold(0, |mask, (key, _)| mask | 1 << key),
+        };
+
+        let start = [26, 27, 28, 29];
+        search::dijkstra(&collection, (start, 0))
+            .find(|(state, _)| collection.is_goal(state))
+            .map(|(_, steps)| steps)
+            .ok_or_eyre("some keys can't be reached")
+    }
+}
+
+impl SearchSpace for Collection {
+    type State = State;
+    type Cost = usize;
+
+    fn successors(&self, &(robots, keys): &State) -> impl IntoIterator<Item = (State, usize)> {
+        (0..self.robots).flat_map(move |robot| {
+            self.routes[usize::from(robots[robot])]
+                .iter()
+                .filter(move |route| keys & (1 << route.key) == 0 && route.doors & !keys == 0)
+                .map(move |route| {
+                    let mut robots = robots;
+                    robots[robot] = route.key as u8;
+                    ((robots, keys | route.keys | 1 << route.key), route.steps)
+                })
+        })
+    }
+
+    fn is_goal(&self, &(_, keys): &State) -> bool {
+        keys == self.all_keys
+    }
+}
+
+impl FromStr for Vault {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let width = s.lines().next().ok_or_eyre("empty map")?.len();
+        let mut tiles = Vec::new();
+
+        for line in s.lines() {
+            if line.len() != width {
+                bail!("map isn't rectangular");
+            }
+
+            for &tile in line.as_bytes() {
+                if !matches!(tile, b'#' | b'.' | b'@' | b'a'..=b'z' | b'A'..=b'Z') {
+                    bail!("unknown map tile '{}'", tile as char);
+                }
+
+                tiles.push(tile);
+            }
+        }
+
+        // Walls all the way around mean searches can never leave the map.
+        let enclosed = tiles[..width]
+            .iter()
+            .chain(&tiles[tiles.len() - width..])
+            .all(|&t| t == b'#')
+            && tiles
+                .chunks(width)
+                .all(|row| row[0] == b'#' && row[width - 1] == b'#');
+        if !enclosed {
+            bail!("the vault isn't surrounded by walls");
+        }
+
+        Ok(Self { width, tiles })
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let steps = |map: &str| map.parse::<Vault>().unwrap().fewest_steps().unwrap();
+
+    assert_eq!(steps("#########\n#b.A.@.a#\n#########"), 8);
+    assert_eq!(
+        steps(
+            "########################
+#f.D.E.e.C.b.A.@.a.B.c.#
+######################.#
+#d.....................#
+########################"
+        ),
+        86
+    );
+    assert_eq!(
+        steps(
+            "#################
+#i.G..c...e..H.p#
+########.########
+#j.A..b...f..D.o#
+########@########
+#k.E..a...g..B.n#
+########.########
+#l.F..d...h..C.m#
+#################"
+        ),
+        136
+    );
+
+    let vault: Vault = "#######
+#a.#Cd#
+##...##
+##.@.##
+##...##
+#cB#Ab#
+#######"
+        .parse()
+        .unwrap();
+    assert_eq!(vault.split_up().fewest_steps().unwrap(), 8);
+
+    assert_eq!(
+        steps(
+            "#############
+#g#f.D#..h#l#
+#F###e#E###.#
+#dCba@#@BcIJ#
+#############
+#nK.L@#@G...#
+#M###N#H###.#
+#o#m..#i#jk.#
+#############"
+        ),
+        72
+    );
+}
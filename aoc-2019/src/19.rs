@@ -0,0 +1,71 @@
+use eyre::{Result, bail};
+use itertools::iproduct;
+
+use crate::intcode::{Machine, Node};
+use aoc_meta::Problem;
+
+pub const TRACTOR_BEAM: Problem = Problem::solved(
+    &|input| {
+        let drone = input.parse()?;
+        iproduct!(0..50, 0..50).try_fold(0, |pulled, (x, y)| {
+            Ok::<_, eyre::Report>(pulled + usize::from(deploy(&drone, x, y)?))
+        })
+    },
+    &|input| {
+        let drone = input.parse()?;
+        closest_square(|x, y| deploy(&drone, x, y), 100).map(|(x, y)| x * 10_000 + y)
+    },
+);
+
+/// Whether a drone sent to `(x, y)` is pulled by the beam. Each drone program only answers once.
+fn deploy(drone: &Machine, x: i64, y: i64) -> Result<bool> {
+    match Node::new(drone.clone()).run_with([x, y])?[..] {
+        [pulled] => Ok(pulled == 1),
+        ref outputs => bail!("expected the drone's state, found {outputs:?}"),
+    }
+}
+
+/// The top left corner of the closest `size` by `size` square that fits entirely in the beam.
+///
+/// The beam widens as it goes, with its left edge moving right, so walk down the left edge looking for a row where
+/// the top right corner of a square with its bottom left corner on that edge is in the beam too.
+fn closest_square(
+    mut pulled: impl FnMut(i64, i64) -> Result<bool>,
+    size: i64,
+) -> Result<(i64, i64)> {
+    let (mut x, mut y) = (0, size);
+
+    // The beam can miss rows entirely near the emitter, so start far enough out that it's solid.
+    loop {
+        while !pulled(x, y)? {
+            x += 1;
+
+            // The beam never gets that shallow, so it must have missed this row.
+            if x > 10 * y {
+                bail!("the beam doesn't reach row {y}");
+            }
+        }
+
+        if pulled(x + size - 1, y - size + 1)? {
+            return Ok((x, y - size + 1));
+        }
+
+        y += 1;
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    // A beam bounded by lines through the emitter, x from 0.7y to 1.2y.
+    let beam = |x: i64, y: i64| Ok(10 * x >= 7 * y && 10 * x <= 12 * y);
+
+    let brute_force = (0..200)
+        .flat_map(|y| (0..300).map(move |x| (x, y)))
+        .filter(|&(x, y)| (0..10).all(|dx| (0..10).all(|dy| beam(x + dx, y + dy).unwrap())))
+        .min_by_key(|&(x, y)| (y, x))
+        .unwrap();
+
+    assert_eq!(closest_square(beam, 10).unwrap(), brute_force);
+}
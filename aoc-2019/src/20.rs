@@ -0,0 +1,181 @@
+use std::str::FromStr;
+
+use eyre::{OptionExt, Report, Result, bail};
+use fnv::FnvHashMap;
+
+use aoc_common::search::{self, SearchSpace};
+use aoc_meta::Problem;
+
+pub const DONUT_MAZE: Problem = Problem::solved(
+    &|input| {
+        input
+            .parse()
+            .and_then(|maze: Maze| maze.fewest_steps(false))
+    },
+    &|input| input.parse().and_then(|maze: Maze| maze.fewest_steps(true)),
+);
+
+#[derive(Debug, Clone)]
+struct Maze {
+    width: usize,
+    open: Vec<bool>,
+    /// Where each portal tile leads, and whether it's on the inside edge of the donut.
+    portals: FnvHashMap<usize, (usize, bool)>,
+    start: usize,
+    end: usize,
+    /// Whether the inner portals lead down a level into a smaller copy of the maze.
+    recursive: bool,
+}
+
+impl Maze {
+    fn fewest_steps(mut self, recursive: bool) -> Result<usize> {
+        self.recursive = recursive;
+
+        search::bfs(&self, (self.start, 0))
+            .next_goal()
+            .map(|path| path.cost)
+            .ok_or_eyre("there's no way from AA to ZZ")
+    }
+}
+
+impl SearchSpace for Maze {
+    /// A position and how many levels down it is.
+    type State = (usize, usize);
+    type Cost = usize;
+
+    fn successors(
+        &self,
+        &(position, level): &(usize, usize),
+    ) -> impl IntoIterator<Item = ((usize, usize), usize)> {
+        let walk = [
+            position - self.width,
+            position + 1,
+            position + self.width,
+            position - 1,
+        ]
+        .into_iter()
+        .filter(|&next| self.open[next])
+        .map(move |next| (next, level));
+
+        let warp = self.portals.get(&position).and_then(|&(exit, inner)| {
+            if !self.recursive {
+                Some((exit, level))
+            } else if inner {
+                // Any deeper than there are portals and the way back up must have gone round in circles.
+                (level < self.portals.len()).then_some((exit, level + 1))
+            } else {
+                level.checked_sub(1).map(|level| (exit, level))
+            }
+        });
+
+        walk.chain(warp).map(|state| (state, 1))
+    }
+
+    fn is_goal(&self, &(position, level): &(usize, usize)) -> bool {
+        position == self.end && level == 0
+    }
+}
+
+impl FromStr for Maze {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        // Pad the map with a blank border and make every line the same length.
+        let width = s.lines().map(str::len).max().ok_or_eyre("empty maze")? + 2;
+        let blank = vec![b' '; width];
+        let tiles: Vec<u8> = blank
+            .iter()
+            .copied()
+            .chain(s.lines().flat_map(|line| {
+                let padding = width - 1 - line.len();
+                std::iter::once(b' ')
+                    .chain(line.bytes())
+                    .chain(std::iter::repeat_n(b' ', padding))
+            }))
+            .chain(blank.iter().copied())
+            .collect();
+        let height = tiles.len() / width;
+
+        let mut labels: FnvHashMap<[u8; 2], Vec<(usize, bool)>> = FnvHashMap::default();
+
+        for (position, _) in tiles.iter().enumerate().filter(|&(_, &tile)| tile == b'.') {
+            for (step, after) in [(1, false), (width, false), (1, true), (width, true)] {
+                // Labels read left to right or top to bottom, on whichever side of the tile they are.
+                let (first, second) = if after {
+                    (position + step, position + 2 * step)
+                } else {
+                    (position - 2 * step, position - step)
+                };
+
+                let label = [tiles[first], tiles[second]];
+                if label.iter().all(u8::is_ascii_uppercase) {
+                    let (x, y) = (position % width, position / width);
+                    let outer = x == 3 || y == 3 || x == width - 4 || y == height - 4;
+                    labels.entry(label).or_default().push((position, !outer));
+                }
+            }
+        }
+
+        let mut single = |label: &[u8; 2]| match labels.remove(label).as_deref() {
+            Some(&[(position, _)]) => Ok(position),
+            _ => bail!(
+                "expected exactly one {} tile",
+                String::from_utf8_lossy(label)
+            ),
+        };
+
+        let (start, end) = (single(b"AA")?, single(b"ZZ")?);
+
+        let mut portals = FnvHashMap::default();
+        for (label, ends) in labels {
+            let [(a, a_inner), (b, b_inner)] = ends[..] else {
+                bail!(
+                    "portal {} doesn't have two ends",
+                    String::from_utf8_lossy(&label)
+                );
+            };
+
+            portals.insert(a, (b, a_inner));
+            portals.insert(b, (a, b_inner));
+        }
+
+        Ok(Self {
+            width,
+            open: tiles.iter().map(|&tile| tile == b'.').collect(),
+            portals,
+            start,
+            end,
+            recursive: false,
+        })
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let maze: Maze = "         A           
+         A           
+  #######.#########  
+  #######.........#  
+  #######.#######.#  
+  #######.#######.#  
+  #######.#######.#  
+  #####  B    ###.#  
+BC...##  C    ###.#  
+  ##.##       ###.#  
+  ##...DE  F  ###.#  
+  #####    G  ###.#  
+  #########.#####.#  
+DE..#######...###.#  
+  #.#########.###.#  
+FG..#########.....#  
+  ###########.#####  
+             Z       
+             Z"
+    .parse()
+    .unwrap();
+
+    assert_eq!(maze.clone().fewest_steps(false).unwrap(), 23);
+    assert_eq!(maze.fewest_steps(true).unwrap(), 26);
+}
@@ -0,0 +1,111 @@
+use eyre::{Result, bail};
+
+use crate::intcode::{Machine, Node};
+use aoc_meta::Problem;
+
+pub const SPRINGDROID_ADVENTURE: Problem =
+    Problem::solved(&|input| survey_hull(input.parse()?, WALK), &|input| {
+        survey_hull(input.parse()?, RUN)
+    });
+
+/// Jump if there's a hole in any of the next three tiles, as long as there's somewhere to land four tiles ahead.
+const WALK: &str = "NOT A J
+NOT B T
+OR T J
+NOT C T
+OR T J
+AND D J
+WALK";
+
+/// The same as walking, but only if after landing it's possible to either take a step (E) or jump again (H), since
+/// jumping too early can leave the droid stuck in front of a hole it can't get over.
+const RUN: &str = "NOT A J
+NOT B T
+OR T J
+NOT C T
+OR T J
+AND D J
+NOT E T
+NOT T T
+OR H T
+AND T J
+RUN";
+
+/// Program the springdroid with `script` and send it across the hull, returning the amount of hull damage it
+/// reports.
+fn survey_hull(machine: Machine, script: &str) -> Result<i64> {
+    let mut droid = Node::new(machine);
+    droid.send_ascii(script);
+    droid.run()?;
+
+    match droid.read_ascii() {
+        (_, Some(damage)) => Ok(damage),
+        (text, None) => bail!("the springdroid didn't make it across the hull:\n{text}"),
+    }
+}
+
+/// Whether `script` makes the droid jump when its sensors see ground where `ground` is true, the same way the
+/// springdroid would.
+#[cfg(test)]
+fn jumps(script: &str, ground: &[bool]) -> bool {
+    let (mut t, mut j) = (false, false);
+
+    for line in script.lines() {
+        let mut words = line.split(' ');
+        let (Some(op), Some(x), Some(y)) = (words.next(), words.next(), words.next()) else {
+            continue;
+        };
+
+        let x = match x {
+            "T" => t,
+            "J" => j,
+            sensor => ground[usize::from(sensor.as_bytes()[0] - b'A')],
+        };
+        let y = if y == "T" { &mut t } else { &mut j };
+
+        *y = match op {
+            "AND" => x && *y,
+            "OR" => x || *y,
+            _ => !x,
+        };
+    }
+
+    j
+}
+
+#[test]
+fn example() {
+    // Whether the droid gets to the end of the hull with `script`, which sees up to `range` tiles ahead.
+    let survives = |script: &str, range: usize, hull: &str| {
+        let hull: Vec<bool> = hull.bytes().map(|tile| tile == b'#').collect();
+        let mut position = 0;
+
+        while position < hull.len() {
+            if !hull[position] {
+                return false;
+            }
+
+            let ground: Vec<bool> = (1..=range)
+                .map(|ahead| hull.get(position + ahead).copied().unwrap_or(true))
+                .collect();
+            position += if jumps(script, &ground) { 4 } else { 1 };
+        }
+
+        true
+    };
+
+    for hull in [
+        "#####.###########",
+        "#####...#########",
+        "#####..#.########",
+        "#####.#..########",
+    ] {
+        assert!(survives(WALK, 4, hull), "walking over {hull}");
+        assert!(survives(RUN, 9, hull), "running over {hull}");
+    }
+
+    // Jumping at the first hole lands right in front of another one, too far from ground to jump again.
+    let hull = "#####.#.##.#.####";
+    assert!(!survives(WALK, 4, hull));
+    assert!(survives(RUN, 9, hull));
+}
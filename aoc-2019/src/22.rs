@@ -0,0 +1,159 @@
+use std::str::FromStr;
+
+use eyre::{OptionExt, Report, Result, bail};
+
+use aoc_common::math::mod_inv;
+use aoc_meta::Problem;
+
+pub const SLAM_SHUFFLE: Problem = Problem::solved(
+    &|input| Shuffle::parse(input, 10_007).map(|shuffle| shuffle.position_of(2019)),
+    &|input| {
+        let shuffle = Shuffle::parse(input, 119_315_717_514_047)?;
+        shuffle.repeat(101_741_582_076_661).card_at(2020)
+    },
+);
+
+/// A shuffle of a deck of `deck` cards, as the function `position * a + b` from a card's position before the
+/// shuffle to its position after. Every technique is a function like that, and so is doing one after another.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Shuffle {
+    a: u64,
+    b: u64,
+    deck: u64,
+}
+
+impl Shuffle {
+    const fn identity(deck: u64) -> Self {
+        Self { a: 1, b: 0, deck }
+    }
+
+    fn parse(input: &str, deck: u64) -> Result<Self> {
+        input
+            .lines()
+            .try_fold(Self::identity(deck), |shuffle, line| {
+                let technique: Technique = line.parse()?;
+                Ok(shuffle.then(technique.shuffle(deck)))
+            })
+    }
+
+    #[inline]
+    fn mul(&self, x: u64, y: u64) -> u64 {
+        (u128::from(x) * u128::from(y) % u128::from(self.deck)) as u64
+    }
+
+    /// This shuffle followed by `next`.
+    fn then(&self, next: Self) -> Self {
+        Self {
+            a: self.mul(self.a, next.a),
+            b: (self.mul(self.b, next.a) + next.b) % self.deck,
+            deck: self.deck,
+        }
+    }
+
+    /// This shuffle done `times` times over, by repeated squaring.
+    fn repeat(&self, mut times: u64) -> Self {
+        let (mut result, mut square) = (Self::identity(self.deck), *self);
+
+        while times > 0 {
+            if times & 1 == 1 {
+                result = result.then(square);
+            }
+
+            square = square.then(square);
+            times >>= 1;
+        }
+
+        result
+    }
+
+    fn position_of(&self, card: u64) -> u64 {
+        (self.mul(card, self.a) + self.b) % self.deck
+    }
+
+    /// The card that ends up at `position`, which is wherever the inverse of the shuffle takes it.
+    fn card_at(&self, position: u64) -> Result<u64> {
+        let inverse = mod_inv(self.a, self.deck).ok_or_eyre("the shuffle can't be undone")?;
+        let shifted = (position + self.deck - self.b) % self.deck;
+        Ok(self.mul(shifted, inverse))
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Technique {
+    DealIntoNewStack,
+    Cut(i64),
+    DealWithIncrement(u64),
+}
+
+impl Technique {
+    fn shuffle(self, deck: u64) -> Shuffle {
+        let (a, b) = match self {
+            Technique::DealIntoNewStack => (deck - 1, deck - 1),
+            Technique::Cut(n) => (1, (-n).rem_euclid(deck as i64) as u64),
+            Technique::DealWithIncrement(n) => (n % deck, 0),
+        };
+
+        Shuffle { a, b, deck }
+    }
+}
+
+impl FromStr for Technique {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        if s == "deal into new stack" {
+            Ok(Technique::DealIntoNewStack)
+        } else if let Some(n) = s.strip_prefix("cut ") {
+            Ok(Technique::Cut(n.parse()?))
+        } else if let Some(n) = s.strip_prefix("deal with increment ") {
+            Ok(Technique::DealWithIncrement(n.parse()?))
+        } else {
+            bail!("unknown technique \"{s}\"")
+        }
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let deck = |techniques: &str| {
+        let shuffle = Shuffle::parse(techniques, 10).unwrap();
+        (0..10)
+            .map(|position| shuffle.card_at(position).unwrap())
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        deck("deal with increment 7\ndeal into new stack\ndeal into new stack"),
+        [0, 3, 6, 9, 2, 5, 8, 1, 4, 7]
+    );
+    assert_eq!(
+        deck("cut 6\ndeal with increment 7\ndeal into new stack"),
+        [3, 0, 7, 4, 1, 8, 5, 2, 9, 6]
+    );
+    assert_eq!(
+        deck("deal with increment 7\ndeal with increment 9\ncut -2"),
+        [6, 3, 0, 7, 4, 1, 8, 5, 2, 9]
+    );
+    assert_eq!(
+        deck(
+            "deal into new stack
+cut -2
+deal with increment 7
+cut 8
+cut -4
+deal with increment 7
+cut 3
+deal with increment 9
+deal with increment 3
+cut -1"
+        ),
+        [9, 2, 5, 8, 1, 4, 7, 0, 3, 6]
+    );
+
+    // Shuffling a prime-sized deck enough times brings it back where it started.
+    let shuffle = Shuffle::parse("cut 3\ndeal with increment 7\ndeal into new stack", 11).unwrap();
+    assert_eq!(shuffle.repeat(10).repeat(11).position_of(5), 5);
+    assert_eq!(shuffle.repeat(3), shuffle.then(shuffle).then(shuffle));
+}
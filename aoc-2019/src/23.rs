@@ -0,0 +1,135 @@
+use std::collections::VecDeque;
+
+use eyre::{Result, bail};
+
+use crate::intcode::{Machine, Node};
+use aoc_meta::Problem;
+
+pub const CATEGORY_SIX: Problem =
+    Problem::solved(&|input| first_sent_to_nat(input.parse()?), &|input| {
+        first_repeated_wake_up(input.parse()?)
+    });
+
+fn first_sent_to_nat(machine: Machine) -> Result<i64> {
+    let mut network = Network::boot(machine, 50);
+
+    loop {
+        if let Some((_, y)) = network.step()? {
+            return Ok(y);
+        }
+    }
+}
+
+/// The first Y value the NAT sends to wake up the network twice in a row.
+fn first_repeated_wake_up(machine: Machine) -> Result<i64> {
+    let mut network = Network::boot(machine, 50);
+    let (mut nat, mut last_sent) = (None, None);
+
+    loop {
+        let packet = network.step()?;
+        let idle = packet.is_none() && network.is_idle();
+        nat = packet.or(nat);
+
+        if idle {
+            let Some((x, y)) = nat else {
+                bail!("the network went idle before anything was sent to the NAT");
+            };
+
+            if last_sent == Some(y) {
+                return Ok(y);
+            }
+
+            network.queues[0].push_back((x, y));
+            last_sent = Some(y);
+        }
+    }
+}
+
+const NAT: i64 = 255;
+
+struct Network {
+    nodes: Vec<Node>,
+    queues: Vec<VecDeque<(i64, i64)>>,
+    /// Output from each node that doesn't make up a whole packet yet.
+    partial: Vec<Vec<i64>>,
+    /// Whether anything was sent or received in the last step.
+    active: bool,
+}
+
+impl Network {
+    fn boot(machine: Machine, size: usize) -> Self {
+        let nodes = (0..size as i64)
+            .map(|address| {
+                let node = Node::new(machine.clone());
+                node.send(address);
+                node
+            })
+            .collect();
+
+        Self {
+            nodes,
+            queues: vec![VecDeque::new(); size],
+            partial: vec![Vec::new(); size],
+            active: true,
+        }
+    }
+
+    /// Give every node its queued packets, or -1 if it has none, and run it until it wants more. Returns the last
+    /// packet sent to the NAT, if there was one.
+    fn step(&mut self) -> Result<Option<(i64, i64)>> {
+        let mut to_nat = None;
+        self.active = false;
+
+        for (i, node) in self.nodes.iter_mut().enumerate() {
+            if self.queues[i].is_empty() {
+                node.send(-1);
+            } else {
+                self.active = true;
+
+                for (x, y) in self.queues[i].drain(..) {
+                    node.send(x);
+                    node.send(y);
+                }
+            }
+
+            node.run()?;
+            self.partial[i].extend(node.outputs());
+
+            let whole = self.partial[i].len() / 3 * 3;
+            let packets: Vec<i64> = self.partial[i].drain(..whole).collect();
+
+            for &[destination, x, y] in packets.as_chunks().0 {
+                self.active = true;
+
+                match usize::try_from(destination) {
+                    _ if destination == NAT => to_nat = Some((x, y)),
+                    Ok(destination) if destination < self.queues.len() => {
+                        self.queues[destination].push_back((x, y));
+                    }
+                    _ => bail!("node {i} sent a packet to unknown address {destination}"),
+                }
+            }
+        }
+
+        Ok(to_nat)
+    }
+
+    fn is_idle(&self) -> bool {
+        !self.active && self.queues.iter().all(VecDeque::is_empty)
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    // Node 0 sends a packet to the NAT and then echoes everything it receives back to it. Every other node just
+    // waits for input forever.
+    let program = "3,100,1005,100,31,104,255,104,1,104,42,3,101,1008,101,-1,102,1005,102,11,3,103,\
+                   104,255,4,101,4,103,1105,1,11,3,101,1105,1,31";
+
+    let machine: Machine = program.parse().unwrap();
+
+    assert_eq!(first_sent_to_nat(machine.clone()).unwrap(), 42);
+    assert_eq!(first_repeated_wake_up(machine).unwrap(), 42);
+}
@@ -0,0 +1,173 @@
+use std::str::FromStr;
+
+use eyre::{Report, Result, bail};
+use fnv::FnvHashSet;
+
+use aoc_meta::Problem;
+
+pub const PLANET_OF_DISCORD: Problem = Problem::solved(
+    &|input| input.parse().map(|eris: Eris| eris.first_repeat()),
+    &|input| {
+        input
+            .parse()
+            .map(|eris: Eris| eris.recursive_bugs_after(200))
+    },
+);
+
+const SIZE: usize = 5;
+const MIDDLE: usize = SIZE * SIZE / 2;
+
+/// The bugs on a 5x5 grid of tiles as a bitmask, read left to right and top to bottom. That also happens to be its
+/// biodiversity rating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Eris(u32);
+
+impl Eris {
+    #[inline]
+    const fn has_bug(self, tile: usize) -> bool {
+        self.0 & (1 << tile) != 0
+    }
+
+    /// Whether a tile with `neighbors` adjacent bugs has a bug next minute.
+    #[inline]
+    const fn lives(has_bug: bool, neighbors: usize) -> bool {
+        neighbors == 1 || (!has_bug && neighbors == 2)
+    }
+
+    /// The tiles on the same level next to `tile`.
+    fn neighbors(tile: usize) -> impl Iterator<Item = usize> {
+        let (x, y) = (tile % SIZE, tile / SIZE);
+
+        [
+            (y > 0).then(|| tile - SIZE),
+            (y < SIZE - 1).then(|| tile + SIZE),
+            (x > 0).then(|| tile - 1),
+            (x < SIZE - 1).then(|| tile + 1),
+        ]
+        .into_iter()
+        .flatten()
+    }
+
+    fn step(self) -> Self {
+        Self((0..SIZE * SIZE).fold(0, |next, tile| {
+            let neighbors = Self::neighbors(tile).filter(|&n| self.has_bug(n)).count();
+            next | u32::from(Self::lives(self.has_bug(tile), neighbors)) << tile
+        }))
+    }
+
+    /// The biodiversity rating of the first layout to appear twice.
+    fn first_repeat(self) -> u32 {
+        let mut seen = FnvHashSet::default();
+        let mut eris = self;
+
+        while seen.insert(eris) {
+            eris = eris.step();
+        }
+
+        eris.0
+    }
+
+    /// The number of adjacent bugs on `levels[level]`, where the middle tile of each level holds the next one.
+    fn recursive_neighbors(levels: &[Self], level: usize, tile: usize) -> usize {
+        let (x, y) = (tile % SIZE, tile / SIZE);
+        let outer = level.checked_sub(1).map(|l| levels[l]);
+        let inner = levels.get(level + 1);
+
+        // Stepping off an edge leads to the tile next to the middle of the level outside.
+        let edges = [
+            (y == 0, MIDDLE - SIZE),
+            (y == SIZE - 1, MIDDLE + SIZE),
+            (x == 0, MIDDLE - 1),
+            (x == SIZE - 1, MIDDLE + 1),
+        ];
+        let outside = edges
+            .into_iter()
+            .filter(|&(edge, outer_tile)| edge && outer.is_some_and(|o| o.has_bug(outer_tile)))
+            .count();
+
+        let inside = Self::neighbors(tile)
+            .map(|neighbor| {
+                if neighbor != MIDDLE {
+                    usize::from(levels[level].has_bug(neighbor))
+                } else if let Some(inner) = inner {
+                    // Stepping into the middle leads to the whole edge of the level inside that faces this tile.
+                    (0..SIZE)
+                        .map(|i| match tile {
+                            t if t == MIDDLE - SIZE => i,
+                            t if t == MIDDLE + SIZE => (SIZE - 1) * SIZE + i,
+                            t if t == MIDDLE - 1 => i * SIZE,
+                            _ => i * SIZE + SIZE - 1,
+                        })
+                        .filter(|&t| inner.has_bug(t))
+                        .count()
+                } else {
+                    0
+                }
+            })
+            .sum::<usize>();
+
+        outside + inside
+    }
+
+    fn recursive_bugs_after(self, minutes: usize) -> u32 {
+        let mut levels = vec![self];
+
+        for _ in 0..minutes {
+            // Bugs can spread at most one level further out and in each minute.
+            levels.insert(0, Self(0));
+            levels.push(Self(0));
+
+            levels = (0..levels.len())
+                .map(|level| {
+                    Self(
+                        (0..SIZE * SIZE)
+                            .filter(|&tile| tile != MIDDLE)
+                            .fold(0, |next, tile| {
+                                let neighbors = Self::recursive_neighbors(&levels, level, tile);
+                                let lives = Self::lives(levels[level].has_bug(tile), neighbors);
+                                next | u32::from(lives) << tile
+                            }),
+                    )
+                })
+                .collect();
+        }
+
+        levels.iter().map(|level| level.0.count_ones()).sum()
+    }
+}
+
+impl FromStr for Eris {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let tiles: Vec<u8> = s.lines().flat_map(|line| line.trim().bytes()).collect();
+
+        if s.lines().count() != SIZE || tiles.len() != SIZE * SIZE {
+            bail!("Eris should be a {SIZE}x{SIZE} grid");
+        }
+
+        tiles
+            .iter()
+            .enumerate()
+            .try_fold(0, |bugs, (tile, &b)| match b {
+                b'#' => Ok(bugs | 1 << tile),
+                b'.' => Ok(bugs),
+                _ => bail!("unknown tile '{}'", char::from(b)),
+            })
+            .map(Self)
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let eris: Eris = "....#\n#..#.\n#..##\n..#..\n#....".parse().unwrap();
+
+    assert_eq!(
+        eris.step(),
+        "#..#.\n####.\n###.#\n##.##\n.##..".parse().unwrap()
+    );
+    assert_eq!(eris.first_repeat(), 2129920);
+    assert_eq!(eris.recursive_bugs_after(10), 99);
+}
@@ -0,0 +1,337 @@
+use eyre::{OptionExt, Result, bail};
+use fnv::FnvHashSet;
+
+use crate::intcode::Node;
+use aoc_meta::Problem;
+
+pub const CRYOSTASIS: Problem =
+    Problem::partially_solved(&|input| password(&mut Node::new(input.parse()?)));
+
+/// Items that end the game, or the droid's ability to move, as soon as they're picked up.
+const DANGEROUS: [&str; 5] = [
+    "escape pod",
+    "giant electromagnet",
+    "infinite loop",
+    "molten lava",
+    "photons",
+];
+
+const CHECKPOINT: &str = "Security Checkpoint";
+
+trait Terminal {
+    /// Type `command` and read everything the droid prints before it asks for the next one. An empty command just
+    /// reads what the droid has to say, like where it starts out.
+    fn command(&mut self, command: &str) -> Result<String>;
+}
+
+impl Terminal for Node {
+    fn command(&mut self, command: &str) -> Result<String> {
+        self.send_ascii(command);
+        self.run()?;
+        Ok(self.read_ascii().0)
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+struct Room {
+    name: String,
+    doors: Vec<String>,
+    items: Vec<String>,
+}
+
+impl Room {
+    /// The last room described in `text`, since being thrown out of one room lands the droid in another.
+    fn parse(text: &str) -> Result<Self> {
+        let mut room: Option<Room> = None;
+        let mut listing_doors = None;
+
+        for line in text.lines() {
+            if let Some(name) = line.strip_prefix("== ").and_then(|l| l.strip_suffix(" ==")) {
+                room = Some(Room {
+                    name: name.to_string(),
+                    ..Room::default()
+                });
+            } else if let Some(room) = &mut room {
+                match (line, line.strip_prefix("- "), listing_doors) {
+                    ("Doors here lead:", _, _) => listing_doors = Some(true),
+                    ("Items here:", _, _) => listing_doors = Some(false),
+                    (_, Some(entry), Some(true)) => room.doors.push(entry.to_string()),
+                    (_, Some(entry), Some(false)) => room.items.push(entry.to_string()),
+                    _ => listing_doors = None,
+                }
+            }
+        }
+
+        room.ok_or_eyre("the droid didn't describe a room")
+    }
+}
+
+fn opposite(door: &str) -> Result<&'static str> {
+    Ok(match door {
+        "north" => "south",
+        "south" => "north",
+        "east" => "west",
+        "west" => "east",
+        _ => bail!("unknown direction \"{door}\""),
+    })
+}
+
+#[derive(Debug, Default)]
+struct Exploration {
+    visited: FnvHashSet<String>,
+    items: Vec<String>,
+    /// The doors from the start to the security checkpoint, and the one onto the pressure-sensitive floor.
+    checkpoint: Option<(Vec<String>, String)>,
+}
+
+impl Exploration {
+    /// Visit every room reachable from `room` and pick up everything safe along the way, then come back.
+    fn explore(
+        &mut self,
+        terminal: &mut impl Terminal,
+        room: Room,
+        path: &mut Vec<String>,
+    ) -> Result<()> {
+        let back = path.last().map(|door| opposite(door)).transpose()?;
+        self.visited.insert(room.name.clone());
+
+        for item in room.items {
+            if !DANGEROUS.contains(&item.as_str()) {
+                terminal.command(&format!("take {item}"))?;
+                self.items.push(item);
+            }
+        }
+
+        let mut doors = room
+            .doors
+            .into_iter()
+            .filter(|door| Some(door.as_str()) != back);
+
+        if room.name == CHECKPOINT {
+            // Stepping onto the floor without the right weight just throws the droid back, so leave that for last.
+            let floor = doors
+                .next_back()
+                .ok_or_eyre("the security checkpoint is a dead end")?;
+            self.checkpoint = Some((path.clone(), floor));
+            return Ok(());
+        }
+
+        for door in doors {
+            let next = Room::parse(&terminal.command(&door)?)?;
+
+            if !self.visited.contains(&next.name) {
+                path.push(door.clone());
+                self.explore(terminal, next, path)?;
+                path.pop();
+            }
+
+            terminal.command(opposite(&door)?)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Collect every safe item, then try carrying each combination of them past the security checkpoint until the
+/// weight is right and Santa gives out the password to the airlock.
+fn password(terminal: &mut impl Terminal) -> Result<u64> {
+    let start = Room::parse(&terminal.command("")?)?;
+    let mut exploration = Exploration::default();
+    exploration.explore(terminal, start, &mut Vec::new())?;
+
+    let (path, floor) = exploration
+        .checkpoint
+        .ok_or_eyre("the droid never found the security checkpoint")?;
+    let items = exploration.items;
+
+    for door in &path {
+        terminal.command(door)?;
+    }
+
+    for item in &items {
+        terminal.command(&format!("drop {item}"))?;
+    }
+
+    // Walk through the subsets in Gray code order, so only one item changes hands between attempts.
+    let mut held = 0u32;
+    for i in 0..1u32 << items.len() {
+        let next = i ^ (i >> 1);
+
+        if let Some(changed) = (held ^ next).checked_ilog2() {
+            let verb = if next & (1 << changed) != 0 {
+                "take"
+            } else {
+                "drop"
+            };
+            terminal.command(&format!("{verb} {}", items[changed as usize]))?;
+        }
+
+        held = next;
+        let reply = terminal.command(&floor)?;
+
+        if let Some((_, rest)) = reply.split_once("typing ") {
+            let digits = rest
+                .split(|c: char| !c.is_ascii_digit())
+                .next()
+                .unwrap_or_default();
+            return Ok(digits.parse()?);
+        }
+    }
+
+    bail!("no combination of items got the droid past the security checkpoint")
+}
+
+/// Which way each door goes, and where it leads.
+#[cfg(test)]
+type Doors = Vec<(&'static str, &'static str)>;
+
+#[cfg(test)]
+struct Ship {
+    /// Each room's doors, and the items lying around in it.
+    rooms: fnv::FnvHashMap<&'static str, (Doors, Vec<String>)>,
+    room: &'static str,
+    inventory: Vec<String>,
+}
+
+#[cfg(test)]
+impl Ship {
+    fn describe(&self) -> String {
+        let (doors, items) = &self.rooms[self.room];
+        let mut text = format!(
+            "\n\n\n== {} ==\nIt's a room.\n\nDoors here lead:\n",
+            self.room
+        );
+
+        for (door, _) in doors {
+            text += &format!("- {door}\n");
+        }
+
+        if !items.is_empty() {
+            text += "\nItems here:\n";
+
+            for item in items {
+                text += &format!("- {item}\n");
+            }
+        }
+
+        text + "\nCommand?\n"
+    }
+}
+
+#[cfg(test)]
+impl Terminal for Ship {
+    fn command(&mut self, command: &str) -> Result<String> {
+        if let Some(item) = command.strip_prefix("take ") {
+            if DANGEROUS.contains(&item) {
+                bail!("the droid picked up the {item}");
+            }
+
+            let items = &mut self.rooms.get_mut(self.room).unwrap().1;
+            items.retain(|i| i != item);
+            self.inventory.push(item.to_string());
+            return Ok(format!("\nYou take the {item}.\n\nCommand?\n"));
+        }
+
+        if let Some(item) = command.strip_prefix("drop ") {
+            self.inventory.retain(|i| i != item);
+            self.rooms
+                .get_mut(self.room)
+                .unwrap()
+                .1
+                .push(item.to_string());
+            return Ok(format!("\nYou drop the {item}.\n\nCommand?\n"));
+        }
+
+        if !command.is_empty() {
+            let doors = &self.rooms[self.room].0;
+            let Some(&(_, next)) = doors.iter().find(|(door, _)| *door == command) else {
+                bail!("you can't go {command}");
+            };
+
+            if next != "Pressure-Sensitive Floor" {
+                self.room = next;
+            } else {
+                self.inventory.sort();
+
+                if self.inventory == ["candy cane", "mug"] {
+                    return Ok("\"Oh, hello! You should be able to get in by typing 2424308736 on the keypad \
+                               at the main airlock.\"\n"
+                        .to_string());
+                }
+
+                return Ok(format!(
+                    "\nA loud, robotic voice says \"Alert!\"{}",
+                    self.describe()
+                ));
+            }
+        }
+
+        Ok(self.describe())
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let text = "
+
+
+== Hull Breach ==
+You got in through a hole in the floor here. To keep your ship from also freezing, the hole has been sealed.
+
+Doors here lead:
+- north
+- east
+- south
+
+Items here:
+- mug
+
+Command?
+";
+
+    assert_eq!(
+        Room::parse(text).unwrap(),
+        Room {
+            name: "Hull Breach".to_string(),
+            doors: vec!["north".to_string(), "east".to_string(), "south".to_string()],
+            items: vec!["mug".to_string()],
+        }
+    );
+
+    let items = |items: &[&str]| items.iter().map(|item| item.to_string()).collect();
+    let mut ship = Ship {
+        rooms: fnv::FnvHashMap::from_iter([
+            (
+                "Hull Breach",
+                (vec![("north", "Kitchen"), ("east", "Hallway")], Vec::new()),
+            ),
+            (
+                "Kitchen",
+                (
+                    vec![("south", "Hull Breach")],
+                    items(&["mug", "molten lava"]),
+                ),
+            ),
+            (
+                "Hallway",
+                (
+                    vec![("west", "Hull Breach"), ("north", CHECKPOINT)],
+                    items(&["spool of cat6", "candy cane"]),
+                ),
+            ),
+            (
+                CHECKPOINT,
+                (
+                    vec![("south", "Hallway"), ("west", "Pressure-Sensitive Floor")],
+                    Vec::new(),
+                ),
+            ),
+        ]),
+        room: "Hull Breach",
+        inventory: Vec::new(),
+    };
+
+    assert_eq!(password(&mut ship).unwrap(), 2424308736);
+}
@@ -0,0 +1,301 @@
+//! The Intcode computer that most of 2019 is built around.
+//!
+//! A [`Machine`] reads its input from a [`Receiver`] and writes its output to a [`Sender`], so machines can be wired
+//! straight into each other and run on their own threads with [`Machine::run_blocking`], or driven a batch of I/O at
+//! a time with [`Machine::run`], which pauses whenever it's waiting for input that hasn't been sent yet. A [`Node`]
+//! bundles a machine with its own pair of channels for the common case of talking to a single machine.
+
+use std::str::FromStr;
+use std::sync::mpsc::{self, Receiver, Sender, TryIter};
+
+use eyre::{Report, Result, bail, eyre};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Machine {
+    memory: Vec<i64>,
+    instruction_pointer: usize,
+    relative_base: i64,
+}
+
+/// Why a machine stopped running.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Halted,
+    /// The machine is waiting to read input. Running it again will retry the read.
+    NeedsInput,
+}
+
+impl Machine {
+    pub const fn new(program: Vec<i64>) -> Self {
+        Self {
+            memory: program,
+            instruction_pointer: 0,
+            relative_base: 0,
+        }
+    }
+
+    /// The value at `address`. Memory past the end of the program starts out as zero.
+    #[inline]
+    pub fn read(&self, address: usize) -> i64 {
+        self.memory.get(address).copied().unwrap_or(0)
+    }
+
+    #[inline]
+    pub fn write(&mut self, address: usize, value: i64) {
+        if address >= self.memory.len() {
+            self.memory.resize(address + 1, 0);
+        }
+
+        self.memory[address] = value;
+    }
+
+    /// Run until the machine halts or wants input that isn't waiting in `input` yet.
+    pub fn run(&mut self, input: &Receiver<i64>, output: &Sender<i64>) -> Result<Status> {
+        self.execute(|| Ok(input.try_recv().ok()), |value| send(output, value))
+    }
+
+    /// Run until the machine halts, waiting for `input` whenever it wants some.
+    pub fn run_blocking(&mut self, input: &Receiver<i64>, output: &Sender<i64>) -> Result<()> {
+        let read = || {
+            input
+                .recv()
+                .map(Some)
+                .map_err(|_| eyre!("the input was closed while the machine was waiting for it"))
+        };
+
+        self.execute(read, |value| send(output, value)).map(|_| ())
+    }
+
+    fn execute(
+        &mut self,
+        mut read: impl FnMut() -> Result<Option<i64>>,
+        mut write: impl FnMut(i64) -> Result<()>,
+    ) -> Result<Status> {
+        loop {
+            let instruction = self.read(self.instruction_pointer);
+            let opcode = instruction % 100;
+
+            match opcode {
+                1 | 2 | 7 | 8 => {
+                    let (a, b) = (
+                        self.parameter(instruction, 1)?,
+                        self.parameter(instruction, 2)?,
+                    );
+                    let result = match opcode {
+                        1 => a + b,
+                        2 => a * b,
+                        7 => i64::from(a < b),
+                        _ => i64::from(a == b),
+                    };
+
+                    let address = self.address(instruction, 3)?;
+                    self.write(address, result);
+                    self.instruction_pointer += 4;
+                }
+                3 => {
+                    let Some(value) = read()? else {
+                        return Ok(Status::NeedsInput);
+                    };
+
+                    let address = self.address(instruction, 1)?;
+                    self.write(address, value);
+                    self.instruction_pointer += 2;
+                }
+                4 => {
+                    write(self.parameter(instruction, 1)?)?;
+                    self.instruction_pointer += 2;
+                }
+                5 | 6 => {
+                    let (condition, target) = (
+                        self.parameter(instruction, 1)?,
+                        self.parameter(instruction, 2)?,
+                    );
+
+                    if (condition != 0) == (opcode == 5) {
+                        self.instruction_pointer = usize::try_from(target)
+                            .map_err(|_| eyre!("jump to negative address {target}"))?;
+                    } else {
+                        self.instruction_pointer += 3;
+                    }
+                }
+                9 => {
+                    self.relative_base += self.parameter(instruction, 1)?;
+                    self.instruction_pointer += 2;
+                }
+                99 => return Ok(Status::Halted),
+                _ => bail!(
+                    "unknown opcode {opcode} at address {}",
+                    self.instruction_pointer
+                ),
+            }
+        }
+    }
+
+    /// The address the `n`th parameter of `instruction` refers to.
+    fn address(&self, instruction: i64, n: u32) -> Result<usize> {
+        let raw = self.read(self.instruction_pointer + n as usize);
+
+        let address = match instruction / 10i64.pow(n + 1) % 10 {
+            0 => raw,
+            2 => self.relative_base + raw,
+            mode => bail!(
+                "parameter {n} of instruction {instruction} at address {} can't be written to in mode {mode}",
+                self.instruction_pointer
+            ),
+        };
+
+        usize::try_from(address).map_err(|_| eyre!("negative address {address}"))
+    }
+
+    /// The value of the `n`th parameter of `instruction`.
+    fn parameter(&self, instruction: i64, n: u32) -> Result<i64> {
+        if instruction / 10i64.pow(n + 1) % 10 == 1 {
+            Ok(self.read(self.instruction_pointer + n as usize))
+        } else {
+            self.address(instruction, n)
+                .map(|address| self.read(address))
+        }
+    }
+}
+
+#[inline]
+fn send(output: &Sender<i64>, value: i64) -> Result<()> {
+    output
+        .send(value)
+        .map_err(|_| eyre!("nothing is listening to the machine's output"))
+}
+
+impl FromStr for Machine {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let program = s
+            .trim()
+            .split(',')
+            .map(|value| value.trim().parse())
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self::new(program))
+    }
+}
+
+/// A machine with channels of its own to send it input and collect its output.
+#[derive(Debug)]
+pub struct Node {
+    pub machine: Machine,
+    input: Sender<i64>,
+    machine_input: Receiver<i64>,
+    machine_output: Sender<i64>,
+    output: Receiver<i64>,
+}
+
+impl Node {
+    pub fn new(machine: Machine) -> Self {
+        let (input, machine_input) = mpsc::channel();
+        let (machine_output, output) = mpsc::channel();
+
+        Self {
+            machine,
+            input,
+            machine_input,
+            machine_output,
+            output,
+        }
+    }
+
+    /// Queue up `value` for the machine to read.
+    pub fn send(&self, value: i64) {
+        // The node holds on to the receiving end, so this can't fail.
+        let _ = self.input.send(value);
+    }
+
+    /// Queue up every line of `text` for the machine to read as ASCII, with a newline after each one.
+    pub fn send_ascii(&self, text: &str) {
+        for line in text.lines() {
+            for byte in line.bytes() {
+                self.send(i64::from(byte));
+            }
+
+            self.send(i64::from(b'\n'));
+        }
+    }
+
+    /// Run the machine until it halts or runs out of input.
+    pub fn run(&mut self) -> Result<Status> {
+        self.machine.run(&self.machine_input, &self.machine_output)
+    }
+
+    /// Send `inputs` to the machine and run it, returning everything it outputs before it halts or runs out of input.
+    pub fn run_with(&mut self, inputs: impl IntoIterator<Item = i64>) -> Result<Vec<i64>> {
+        for value in inputs {
+            self.send(value);
+        }
+
+        self.run()?;
+        Ok(self.outputs().collect())
+    }
+
+    /// Everything the machine has output that hasn't been collected yet.
+    pub fn outputs(&self) -> TryIter<'_, i64> {
+        self.output.try_iter()
+    }
+
+    /// Collect the machine's output as ASCII text, along with the last value that wasn't ASCII, if any. Machines
+    /// talking in ASCII usually report their final answer that way.
+    pub fn read_ascii(&self) -> (String, Option<i64>) {
+        let mut text = String::new();
+        let mut value = None;
+
+        for output in self.outputs() {
+            match u8::try_from(output) {
+                Ok(byte) if byte.is_ascii() => text.push(char::from(byte)),
+                _ => value = Some(output),
+            }
+        }
+
+        (text, value)
+    }
+}
+
+#[test]
+fn examples() {
+    let run = |program: &str, inputs: &[i64]| {
+        Node::new(program.parse().unwrap())
+            .run_with(inputs.iter().copied())
+            .unwrap()
+    };
+
+    let mut machine: Machine = "1,9,10,3,2,3,11,0,99,30,40,50".parse().unwrap();
+    let (tx, rx) = mpsc::channel();
+    assert_eq!(machine.run(&rx, &tx).unwrap(), Status::Halted);
+    assert_eq!(machine.read(0), 3500);
+
+    // Compare the input to 8 in position and immediate mode.
+    assert_eq!(run("3,9,8,9,10,9,4,9,99,-1,8", &[8]), [1]);
+    assert_eq!(run("3,3,1107,-1,8,3,4,3,99", &[9]), [0]);
+
+    // Jumps, and whether the input is 0.
+    assert_eq!(run("3,12,6,12,15,1,13,14,13,4,13,99,-1,0,1,9", &[0]), [0]);
+    assert_eq!(run("3,3,1105,-1,9,1101,0,0,12,4,12,99,1", &[5]), [1]);
+
+    let quine = "109,1,204,-1,1001,100,1,100,1008,100,16,101,1006,101,0,99";
+    let expected: Vec<i64> = quine.split(',').map(|n| n.parse().unwrap()).collect();
+    assert_eq!(run(quine, &[]), expected);
+
+    assert_eq!(run("104,1125899906842624,99", &[]), [1125899906842624]);
+    assert_eq!(
+        run("1102,34915192,34915192,7,4,7,99,0", &[]),
+        [1219070632396864]
+    );
+}
+
+#[test]
+fn pausing_for_input() {
+    // Echo each input doubled, forever.
+    let mut node = Node::new("3,11,102,2,11,11,4,11,1105,1,0".parse().unwrap());
+
+    assert_eq!(node.run().unwrap(), Status::NeedsInput);
+    assert_eq!(node.run_with([3, 4]).unwrap(), [6, 8]);
+    assert_eq!(node.run_with([-1]).unwrap(), [-2]);
+    assert_eq!(node.run().unwrap(), Status::NeedsInput);
+}
@@ -1,3 +1,31 @@
 use aoc_meta::PROBLEMS;
 
-PROBLEMS! {}
+mod intcode;
+
+PROBLEMS! {
+    01 => THE_TYRANNY_OF_THE_ROCKET_EQUATION,
+    02 => PROGRAM_ALARM_1202,
+    03 => CROSSED_WIRES,
+    04 => SECURE_CONTAINER,
+    05 => SUNNY_WITH_A_CHANCE_OF_ASTEROIDS,
+    06 => UNIVERSAL_ORBIT_MAP,
+    07 => AMPLIFICATION_CIRCUIT,
+    08 => SPACE_IMAGE_FORMAT,
+    09 => SENSOR_BOOST,
+    10 => MONITORING_STATION,
+    11 => SPACE_POLICE,
+    12 => THE_N_BODY_PROBLEM,
+    13 => CARE_PACKAGE,
+    14 => SPACE_STOICHIOMETRY,
+    15 => OXYGEN_SYSTEM,
+    16 => FLAWED_FREQUENCY_TRANSMISSION,
+    17 => SET_AND_FORGET,
+    18 => MANY_WORLDS_INTERPRETATION,
+    19 => TRACTOR_BEAM,
+    20 => DONUT_MAZE,
+    21 => SPRINGDROID_ADVENTURE,
+    22 => SLAM_SHUFFLE,
+    23 => CATEGORY_SIX,
+    24 => PLANET_OF_DISCORD,
+    25 => CRYOSTASIS,
+}
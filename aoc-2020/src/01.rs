@@ -0,0 +1,42 @@
+use eyre::{OptionExt, Result};
+use itertools::Itertools;
+
+use aoc_meta::Problem;
+
+pub const REPORT_REPAIR: Problem = Problem::solved(
+    &|input| product_of_entries_summing_to(&parse_entries(input)?, 2),
+    &|input| product_of_entries_summing_to(&parse_entries(input)?, 3),
+);
+
+const TARGET: u32 = 2020;
+
+fn parse_entries(input: &str) -> Result<Vec<u32>> {
+    Ok(input.lines().map(str::parse).collect::<Result<_, _>>()?)
+}
+
+/// The product of the `count` entries that add up to 2020.
+fn product_of_entries_summing_to(entries: &[u32], count: usize) -> Result<u64> {
+    entries
+        .iter()
+        .combinations(count)
+        .find(|combination| combination.iter().copied().sum::<u32>() == TARGET)
+        .map(|combination| {
+            combination
+                .into_iter()
+                .map(|&entry| u64::from(entry))
+                .product()
+        })
+        .ok_or_eyre("no entries add up to 2020")
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let entries = [1721, 979, 366, 299, 675, 1456];
+    assert_eq!(product_of_entries_summing_to(&entries, 2).unwrap(), 514579);
+    assert_eq!(
+        product_of_entries_summing_to(&entries, 3).unwrap(),
+        241861950
+    );
+}
@@ -0,0 +1,62 @@
+use eyre::Result;
+use winnow::combinator::{separated_pair, terminated};
+use winnow::prelude::*;
+use winnow::token::{any, rest};
+
+use aoc_common::parse::{self, unsigned};
+use aoc_meta::Problem;
+
+pub const PASSWORD_PHILOSOPHY: Problem = Problem::solved(
+    &|input| count_valid(input, Entry::valid_by_count),
+    &|input| count_valid(input, Entry::valid_by_position),
+);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Entry<'a> {
+    numbers: (usize, usize),
+    letter: char,
+    password: &'a str,
+}
+
+impl Entry<'_> {
+    /// The sled rental place's policy: the letter appears between the two numbers of times, inclusive.
+    fn valid_by_count(&self) -> bool {
+        let count = self.password.chars().filter(|&c| c == self.letter).count();
+        (self.numbers.0..=self.numbers.1).contains(&count)
+    }
+
+    /// The toboggan corporate policy: the letter is at exactly one of the two (1-based) positions.
+    fn valid_by_position(&self) -> bool {
+        let at = |position: usize| {
+            self.password.chars().nth(position.wrapping_sub(1)) == Some(self.letter)
+        };
+        at(self.numbers.0) != at(self.numbers.1)
+    }
+}
+
+fn entry<'a>(input: &mut &'a str) -> ModalResult<Entry<'a>> {
+    (
+        terminated(separated_pair(unsigned, '-', unsigned), ' '),
+        terminated(any, ": "),
+        rest,
+    )
+        .map(|(numbers, letter, password)| Entry {
+            numbers,
+            letter,
+            password,
+        })
+        .parse_next(input)
+}
+
+fn count_valid<'a>(input: &'a str, policy: fn(&Entry<'a>) -> bool) -> Result<usize> {
+    parse::lines(input, entry).try_fold(0, |count, entry| Ok(count + usize::from(policy(&entry?))))
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let input = "1-3 a: abcde\n1-3 b: cdefg\n2-9 c: ccccccccc";
+    assert_eq!(count_valid(input, Entry::valid_by_count).unwrap(), 2);
+    assert_eq!(count_valid(input, Entry::valid_by_position).unwrap(), 1);
+}
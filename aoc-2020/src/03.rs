@@ -0,0 +1,78 @@
+use eyre::{Result, ensure};
+
+use aoc_meta::Problem;
+
+pub const TOBOGGAN_TRAJECTORY: Problem = Problem::solved(
+    &|input| input.parse::<Map>().map(|map| map.trees_on_slope(3, 1)),
+    &|input| {
+        input.parse::<Map>().map(|map| {
+            [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)]
+                .into_iter()
+                .map(|(right, down)| map.trees_on_slope(right, down))
+                .product::<usize>()
+        })
+    },
+);
+
+/// The trees on the slope, one row at a time. The pattern repeats forever to the right.
+#[derive(Debug)]
+struct Map {
+    rows: Vec<Vec<bool>>,
+}
+
+impl Map {
+    fn trees_on_slope(&self, right: usize, down: usize) -> usize {
+        self.rows
+            .iter()
+            .step_by(down)
+            .enumerate()
+            .filter(|(step, row)| row[step * right % row.len()])
+            .count()
+    }
+}
+
+impl std::str::FromStr for Map {
+    type Err = eyre::Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let rows: Vec<Vec<bool>> = s
+            .lines()
+            .map(|line| line.bytes().map(|b| b == b'#').collect())
+            .collect();
+        ensure!(
+            !rows.is_empty()
+                && rows
+                    .iter()
+                    .all(|row| row.len() == rows[0].len() && !row.is_empty()),
+            "the map should be a non-empty rectangle"
+        );
+
+        Ok(Self { rows })
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let map: Map = "..##.......
+#...#...#..
+.#....#..#.
+..#.#...#.#
+.#...##..#.
+..#.##.....
+.#.#.#....#
+.#........#
+#.##...#...
+#...##....#
+.#..#...#.#"
+        .parse()
+        .unwrap();
+
+    assert_eq!(map.trees_on_slope(3, 1), 7);
+    assert_eq!(
+        [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)]
+            .map(|(right, down)| map.trees_on_slope(right, down)),
+        [2, 7, 3, 4, 2]
+    );
+}
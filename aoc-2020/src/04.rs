@@ -0,0 +1,133 @@
+use aoc_meta::Problem;
+
+pub const PASSPORT_PROCESSING: Problem = Problem::solved(
+    &|input| {
+        passports(input)
+            .filter(|passport| has_required_fields(passport))
+            .count()
+    },
+    &|input| {
+        passports(input)
+            .filter(|passport| has_valid_fields(passport))
+            .count()
+    },
+);
+
+const REQUIRED: [&str; 7] = ["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid"];
+
+type Passport<'a> = Vec<(&'a str, &'a str)>;
+
+/// The passports in the batch file, as their `key:value` fields.
+fn passports(input: &str) -> impl Iterator<Item = Passport<'_>> {
+    input.split("\n\n").map(|passport| {
+        passport
+            .split_whitespace()
+            .filter_map(|field| field.split_once(':'))
+            .collect()
+    })
+}
+
+fn has_required_fields(passport: &Passport) -> bool {
+    REQUIRED
+        .iter()
+        .all(|required| passport.iter().any(|(key, _)| key == required))
+}
+
+fn has_valid_fields(passport: &Passport) -> bool {
+    has_required_fields(passport) && passport.iter().all(|&(key, value)| valid_field(key, value))
+}
+
+fn valid_field(key: &str, value: &str) -> bool {
+    let year_between = |min, max| {
+        value.len() == 4
+            && value
+                .parse()
+                .is_ok_and(|year: u32| (min..=max).contains(&year))
+    };
+
+    match key {
+        "byr" => year_between(1920, 2002),
+        "iyr" => year_between(2010, 2020),
+        "eyr" => year_between(2020, 2030),
+        "hgt" => {
+            let height = |unit, min, max| {
+                value
+                    .strip_suffix(unit)
+                    .and_then(|height| height.parse().ok())
+                    .is_some_and(|height: u32| (min..=max).contains(&height))
+            };
+            height("cm", 150, 193) || height("in", 59, 76)
+        }
+        "hcl" => value.strip_prefix('#').is_some_and(|hex| {
+            hex.len() == 6 && hex.bytes().all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f'))
+        }),
+        "ecl" => ["amb", "blu", "brn", "gry", "grn", "hzl", "oth"].contains(&value),
+        "pid" => value.len() == 9 && value.bytes().all(|b| b.is_ascii_digit()),
+        "cid" => true,
+        _ => false,
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let input = "ecl:gry pid:860033327 eyr:2020 hcl:#fffffd
+byr:1937 iyr:2017 cid:147 hgt:183cm
+
+iyr:2013 ecl:amb cid:350 eyr:2023 pid:028048884
+hcl:#cfa07d byr:1929
+
+hcl:#ae17e1 iyr:2013
+eyr:2024
+ecl:brn pid:760753108 byr:1931
+hgt:179cm
+
+hcl:#cfa07d eyr:2025 pid:166559648
+iyr:2011 ecl:brn hgt:59in";
+    assert_eq!(
+        passports(input)
+            .filter(|passport| has_required_fields(passport))
+            .count(),
+        2
+    );
+
+    let invalid = "eyr:1972 cid:100
+hcl:#18171d ecl:amb hgt:170 pid:186cm iyr:2018 byr:1926
+
+iyr:2019
+hcl:#602927 eyr:1967 hgt:170cm
+ecl:grn pid:012533040 byr:1946
+
+hcl:dab227 iyr:2012
+ecl:brn hgt:182cm pid:021572410 eyr:2020 byr:1992 cid:277
+
+hgt:59cm ecl:zzz
+eyr:2038 hcl:74454a iyr:2023
+pid:3556412378 byr:2007";
+    assert_eq!(
+        passports(invalid)
+            .filter(|passport| has_valid_fields(passport))
+            .count(),
+        0
+    );
+
+    let valid = "pid:087499704 hgt:74in ecl:grn iyr:2012 eyr:2030 byr:1980
+hcl:#623a2f
+
+eyr:2029 ecl:blu cid:129 byr:1989
+iyr:2014 pid:896056539 hcl:#a97842 hgt:165cm
+
+hcl:#888785
+hgt:164cm byr:2001 iyr:2015 cid:88
+pid:545766238 ecl:hzl
+eyr:2022
+
+iyr:2010 hgt:158cm hcl:#b6652a ecl:blu byr:1944 eyr:2021 pid:093154719";
+    assert_eq!(
+        passports(valid)
+            .filter(|passport| has_valid_fields(passport))
+            .count(),
+        4
+    );
+}
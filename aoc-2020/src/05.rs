@@ -0,0 +1,55 @@
+use eyre::{OptionExt, Result, bail};
+use itertools::Itertools;
+
+use aoc_meta::Problem;
+
+pub const BINARY_BOARDING: Problem = Problem::solved(
+    &|input| {
+        parse_seats(input)?
+            .into_iter()
+            .max()
+            .ok_or_eyre("there are no boarding passes")
+    },
+    &|input| missing_seat(parse_seats(input)?),
+);
+
+/// The seat ID of a boarding pass. The row and column are binary numbers, with `B` and `R` as ones, and the ID is
+/// just all ten digits read as one number.
+fn seat_id(pass: &str) -> Result<u16> {
+    if pass.len() != 10 {
+        bail!("boarding passes are 10 characters long");
+    }
+
+    pass.chars().try_fold(0, |id, c| match c {
+        'F' | 'L' => Ok(id << 1),
+        'B' | 'R' => Ok(id << 1 | 1),
+        _ => bail!("unexpected {c:?} in boarding pass {pass}"),
+    })
+}
+
+fn parse_seats(input: &str) -> Result<Vec<u16>> {
+    input.lines().map(seat_id).collect()
+}
+
+/// The only empty seat with full seats either side.
+fn missing_seat(mut seats: Vec<u16>) -> Result<u16> {
+    seats.sort_unstable();
+    seats
+        .into_iter()
+        .tuple_windows()
+        .find(|&(a, b)| b == a + 2)
+        .map(|(a, _)| a + 1)
+        .ok_or_eyre("there's no gap in the seats")
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    assert_eq!(seat_id("FBFBBFFRLR").unwrap(), 357);
+    assert_eq!(seat_id("BFFFBBFRRR").unwrap(), 567);
+    assert_eq!(seat_id("FFFBBBFRRR").unwrap(), 119);
+    assert_eq!(seat_id("BBFFBBFRLL").unwrap(), 820);
+
+    assert_eq!(missing_seat(vec![10, 8, 12, 9, 11, 14]).unwrap(), 13);
+}
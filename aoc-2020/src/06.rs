@@ -0,0 +1,43 @@
+use aoc_meta::Problem;
+
+pub const CUSTOM_CUSTOMS: Problem = Problem::solved(
+    &|input| count_questions(input, |group| group.fold(0, |all, answers| all | answers)),
+    &|input| {
+        count_questions(input, |group| {
+            group.fold(u32::MAX, |all, answers| all & answers)
+        })
+    },
+);
+
+/// The sum over every group of how many questions are picked out by `combine`, given the bitmasks of the questions
+/// each person in the group answered "yes" to.
+fn count_questions(input: &str, combine: impl Fn(&mut dyn Iterator<Item = u32>) -> u32) -> usize {
+    input
+        .split("\n\n")
+        .map(|group| {
+            let mut people = group.lines().map(|person| {
+                person
+                    .bytes()
+                    .filter(u8::is_ascii_lowercase)
+                    .fold(0, |answers, question| answers | 1 << (question - b'a'))
+            });
+            combine(&mut people).count_ones() as usize
+        })
+        .sum()
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let input = "abc\n\na\nb\nc\n\nab\nac\n\na\na\na\na\n\nb";
+    assert_eq!(
+        count_questions(input, |group| group.fold(0, |all, answers| all | answers)),
+        11
+    );
+    assert_eq!(
+        count_questions(input, |group| group
+            .fold(u32::MAX, |all, answers| all & answers)),
+        6
+    );
+}
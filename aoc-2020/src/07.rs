@@ -0,0 +1,116 @@
+use eyre::{OptionExt, Result, bail, eyre};
+use fnv::FnvHashMap;
+
+use aoc_meta::Problem;
+
+pub const HANDY_HAVERSACKS: Problem = Problem::solved(
+    &|input| Rules::parse(input).map(|rules| rules.colors_containing(SHINY_GOLD)),
+    &|input| Rules::parse(input)?.bags_inside(SHINY_GOLD),
+);
+
+const SHINY_GOLD: &str = "shiny gold";
+
+/// What each color of bag has to contain, and how many of each.
+#[derive(Debug)]
+struct Rules<'a> {
+    contents: FnvHashMap<&'a str, Vec<(usize, &'a str)>>,
+}
+
+impl<'a> Rules<'a> {
+    /// How many colors of bag end up holding a `color` bag somewhere inside them.
+    fn colors_containing(&self, color: &str) -> usize {
+        let mut memo = FnvHashMap::default();
+        self.contents
+            .keys()
+            .filter(|&&outer| self.contains(outer, color, &mut memo))
+            .count()
+    }
+
+    fn contains(&self, outer: &'a str, color: &str, memo: &mut FnvHashMap<&'a str, bool>) -> bool {
+        if let Some(&contains) = memo.get(outer) {
+            return contains;
+        }
+
+        let contains = self.contents.get(outer).is_some_and(|contents| {
+            contents
+                .iter()
+                .any(|&(_, inner)| inner == color || self.contains(inner, color, memo))
+        });
+        memo.insert(outer, contains);
+        contains
+    }
+
+    /// How many bags a `color` bag has inside it, counting bags inside bags.
+    fn bags_inside(&self, color: &str) -> Result<usize> {
+        self.contents
+            .get(color)
+            .ok_or_else(|| eyre!("there's no rule for {color} bags"))?
+            .iter()
+            .map(|&(count, inner)| Ok(count * (1 + self.bags_inside(inner)?)))
+            .sum()
+    }
+
+    fn parse(input: &'a str) -> Result<Self> {
+        let mut contents = FnvHashMap::default();
+
+        for line in input.lines() {
+            let (outer, inner) = line
+                .split_once(" bags contain ")
+                .ok_or_eyre("malformed rule")?;
+            let inner = inner
+                .strip_suffix('.')
+                .ok_or_eyre("rules end with a full stop")?;
+
+            let inner = if inner == "no other bags" {
+                Vec::new()
+            } else {
+                inner
+                    .split(", ")
+                    .map(|bags| {
+                        let bags = bags.trim_end_matches(" bags").trim_end_matches(" bag");
+                        let Some((count, color)) = bags.split_once(' ') else {
+                            bail!("malformed bag count {bags:?}");
+                        };
+                        Ok((count.parse()?, color))
+                    })
+                    .collect::<Result<_>>()?
+            };
+
+            contents.insert(outer, inner);
+        }
+
+        Ok(Self { contents })
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let rules = Rules::parse(
+        "light red bags contain 1 bright white bag, 2 muted yellow bags.
+dark orange bags contain 3 bright white bags, 4 muted yellow bags.
+bright white bags contain 1 shiny gold bag.
+muted yellow bags contain 2 shiny gold bags, 9 faded blue bags.
+shiny gold bags contain 1 dark olive bag, 2 vibrant plum bags.
+dark olive bags contain 3 faded blue bags, 4 dotted black bags.
+vibrant plum bags contain 5 faded blue bags, 6 dotted black bags.
+faded blue bags contain no other bags.
+dotted black bags contain no other bags.",
+    )
+    .unwrap();
+    assert_eq!(rules.colors_containing(SHINY_GOLD), 4);
+    assert_eq!(rules.bags_inside(SHINY_GOLD).unwrap(), 32);
+
+    let rules = Rules::parse(
+        "shiny gold bags contain 2 dark red bags.
+dark red bags contain 2 dark orange bags.
+dark orange bags contain 2 dark yellow bags.
+dark yellow bags contain 2 dark green bags.
+dark green bags contain 2 dark blue bags.
+dark blue bags contain 2 dark violet bags.
+dark violet bags contain no other bags.",
+    )
+    .unwrap();
+    assert_eq!(rules.bags_inside(SHINY_GOLD).unwrap(), 126);
+}
@@ -0,0 +1,171 @@
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+use eyre::{OptionExt, Report, Result, bail, eyre};
+
+use aoc_meta::Problem;
+
+pub const HANDHELD_HALTING: Problem = Problem::solved(
+    &|input| match input.parse::<Program>()?.run()? {
+        Exit::Loop(accumulator) => Ok(accumulator),
+        Exit::Terminated(_) => bail!("the program terminated without looping"),
+    },
+    &|input| {
+        input
+            .parse::<Program>()?
+            .repair()
+            .map(|(_, accumulator)| accumulator)
+    },
+);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Instruction {
+    Acc(i64),
+    Jmp(i64),
+    Nop(i64),
+}
+
+impl Instruction {
+    /// The instruction with `jmp` and `nop` swapped, if it's either of them.
+    fn patched(self) -> Option<Self> {
+        match self {
+            Instruction::Acc(_) => None,
+            Instruction::Jmp(n) => Some(Instruction::Nop(n)),
+            Instruction::Nop(n) => Some(Instruction::Jmp(n)),
+        }
+    }
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Instruction::Acc(n) => write!(f, "acc {n:+}"),
+            Instruction::Jmp(n) => write!(f, "jmp {n:+}"),
+            Instruction::Nop(n) => write!(f, "nop {n:+}"),
+        }
+    }
+}
+
+impl FromStr for Instruction {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (operation, argument) = s
+            .split_once(' ')
+            .ok_or_eyre("instructions take an argument")?;
+        let argument = argument.parse()?;
+
+        match operation {
+            "acc" => Ok(Instruction::Acc(argument)),
+            "jmp" => Ok(Instruction::Jmp(argument)),
+            "nop" => Ok(Instruction::Nop(argument)),
+            _ => bail!("unknown operation {operation:?}"),
+        }
+    }
+}
+
+/// How a program stopped, and what was in the accumulator when it did.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Exit {
+    /// The program was about to run an instruction for the second time, so it would have looped forever.
+    Loop(i64),
+    /// The program tried to run the instruction just after its last one.
+    Terminated(i64),
+}
+
+#[derive(Debug, Clone)]
+struct Program {
+    instructions: Vec<Instruction>,
+}
+
+impl Program {
+    /// Run the program until it terminates or starts repeating itself.
+    fn run(&self) -> Result<Exit> {
+        self.run_patched(None)
+    }
+
+    /// Run the program with the instruction at `patch` (if any) patched, until it terminates or starts repeating
+    /// itself. The program has no branches other than its jumps, so running any instruction twice means a loop.
+    fn run_patched(&self, patch: Option<usize>) -> Result<Exit> {
+        let mut visited = vec![false; self.instructions.len()];
+        let mut accumulator = 0;
+        let mut pointer = 0;
+
+        loop {
+            if pointer == self.instructions.len() {
+                return Ok(Exit::Terminated(accumulator));
+            }
+
+            let Some(instruction) = self.instructions.get(pointer) else {
+                bail!("jumped to {pointer}, outside of the program");
+            };
+            if std::mem::replace(&mut visited[pointer], true) {
+                return Ok(Exit::Loop(accumulator));
+            }
+
+            let instruction = match patch {
+                Some(patch) if patch == pointer => instruction.patched().unwrap_or(*instruction),
+                _ => *instruction,
+            };
+
+            match instruction {
+                Instruction::Acc(n) => accumulator += n,
+                Instruction::Jmp(n) => {
+                    pointer = pointer.checked_add_signed(n as isize - 1).ok_or_else(|| {
+                        eyre!("{instruction} at {pointer} jumps before the program")
+                    })?;
+                }
+                Instruction::Nop(_) => {}
+            }
+            pointer += 1;
+        }
+    }
+
+    /// Find the single `jmp` or `nop` that stops the program looping when it's swapped for the other, returning
+    /// its position and the accumulator once the fixed program terminates.
+    fn repair(&self) -> Result<(usize, i64)> {
+        for (position, instruction) in self.instructions.iter().enumerate() {
+            if instruction.patched().is_none() {
+                continue;
+            }
+
+            // Patches that jump outside the program are just as broken as ones that loop.
+            if let Ok(Exit::Terminated(accumulator)) = self.run_patched(Some(position)) {
+                return Ok((position, accumulator));
+            }
+        }
+
+        bail!("no single patch stops the program looping")
+    }
+}
+
+impl FromStr for Program {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Ok(Self {
+            instructions: s.lines().map(str::parse).collect::<Result<_>>()?,
+        })
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let program: Program = "nop +0
+acc +1
+jmp +4
+acc +3
+jmp -3
+acc -99
+acc +1
+jmp -4
+acc +6"
+        .parse()
+        .unwrap();
+
+    assert_eq!(program.run().unwrap(), Exit::Loop(5));
+    assert_eq!(program.repair().unwrap(), (7, 8));
+    assert_eq!(program.instructions[7].to_string(), "jmp -4");
+}
@@ -0,0 +1,73 @@
+use std::cmp::Ordering;
+
+use eyre::{OptionExt, Result};
+
+use aoc_meta::Problem;
+
+pub const ENCODING_ERROR: Problem = Problem::solved(
+    &|input| first_invalid(&parse_numbers(input)?, PREAMBLE).ok_or_eyre("every number is valid"),
+    &|input| {
+        let numbers = parse_numbers(input)?;
+        let invalid = first_invalid(&numbers, PREAMBLE).ok_or_eyre("every number is valid")?;
+        encryption_weakness(&numbers, invalid)
+            .ok_or_eyre("no contiguous range adds up to the invalid number")
+    },
+);
+
+const PREAMBLE: usize = 25;
+
+fn parse_numbers(input: &str) -> Result<Vec<u64>> {
+    Ok(input.lines().map(str::parse).collect::<Result<_, _>>()?)
+}
+
+/// The first number that isn't the sum of two different numbers among the `preamble` before it.
+fn first_invalid(numbers: &[u64], preamble: usize) -> Option<u64> {
+    numbers.windows(preamble + 1).find_map(|window| {
+        let (&number, previous) = window.split_last()?;
+        let valid = previous
+            .iter()
+            .enumerate()
+            .any(|(i, &a)| previous[i + 1..].iter().any(|&b| a != b && a + b == number));
+
+        (!valid).then_some(number)
+    })
+}
+
+/// The sum of the smallest and largest numbers in the contiguous range of at least two numbers that adds up to
+/// `target`. Every number is positive, so a sliding window finds it.
+fn encryption_weakness(numbers: &[u64], target: u64) -> Option<u64> {
+    let (mut start, mut sum) = (0, 0);
+
+    for end in 0..numbers.len() {
+        sum += numbers[end];
+
+        loop {
+            match sum.cmp(&target) {
+                Ordering::Greater => {
+                    sum -= numbers[start];
+                    start += 1;
+                }
+                Ordering::Equal if end > start => {
+                    let range = &numbers[start..=end];
+                    return Some(range.iter().min()? + range.iter().max()?);
+                }
+                _ => break,
+            }
+        }
+    }
+
+    None
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let numbers = parse_numbers(
+        "35\n20\n15\n25\n47\n40\n62\n55\n65\n95\n102\n117\n150\n182\n127\n219\n299\n277\n309\n576",
+    )
+    .unwrap();
+
+    assert_eq!(first_invalid(&numbers, 5), Some(127));
+    assert_eq!(encryption_weakness(&numbers, 127), Some(62));
+}
@@ -0,0 +1,68 @@
+use eyre::{Result, ensure};
+
+use aoc_meta::Problem;
+
+pub const ADAPTER_ARRAY: Problem = Problem::solved(
+    &|input| {
+        let differences = joltage_differences(&parse_chain(input)?);
+        Ok::<_, eyre::Report>(differences[1] * differences[3])
+    },
+    &|input| parse_chain(input).map(|chain| arrangements(&chain)),
+);
+
+/// Every joltage from the outlet, through the adapters in order, to the device.
+fn parse_chain(input: &str) -> Result<Vec<u64>> {
+    let mut chain: Vec<u64> = input.lines().map(str::parse).collect::<Result<_, _>>()?;
+    chain.push(0);
+    chain.sort_unstable();
+    chain.push(chain[chain.len() - 1] + 3);
+
+    ensure!(
+        chain
+            .windows(2)
+            .all(|pair| (1..=3).contains(&(pair[1] - pair[0]))),
+        "the adapters can't all be chained together"
+    );
+    Ok(chain)
+}
+
+/// How many times each difference between adjacent joltages in the chain comes up.
+fn joltage_differences(chain: &[u64]) -> [usize; 4] {
+    let mut differences = [0; 4];
+    for pair in chain.windows(2) {
+        differences[(pair[1] - pair[0]) as usize] += 1;
+    }
+
+    differences
+}
+
+/// How many ways there are to connect the outlet to the device with some of the adapters.
+fn arrangements(chain: &[u64]) -> u64 {
+    let mut ways = vec![0; chain.len()];
+    ways[0] = 1;
+
+    for i in 1..chain.len() {
+        ways[i] = (i.saturating_sub(3)..i)
+            .filter(|&j| chain[i] - chain[j] <= 3)
+            .map(|j| ways[j])
+            .sum();
+    }
+
+    ways[chain.len() - 1]
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let small = parse_chain("16\n10\n15\n5\n1\n11\n7\n19\n6\n12\n4").unwrap();
+    assert_eq!(joltage_differences(&small), [0, 7, 0, 5]);
+    assert_eq!(arrangements(&small), 8);
+
+    let large = parse_chain(
+        "28\n33\n18\n42\n31\n14\n46\n20\n48\n47\n24\n23\n49\n45\n19\n38\n39\n11\n1\n32\n25\n35\n8\n17\n7\n9\n4\n2\n34\n10\n3",
+    )
+    .unwrap();
+    assert_eq!(joltage_differences(&large), [0, 22, 0, 10]);
+    assert_eq!(arrangements(&large), 19208);
+}
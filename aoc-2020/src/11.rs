@@ -0,0 +1,139 @@
+use eyre::{Result, bail, ensure};
+
+use aoc_meta::Problem;
+
+pub const SEATING_SYSTEM: Problem = Problem::solved(
+    &|input| {
+        input
+            .parse::<WaitingArea>()
+            .map(|area| area.settle(Some(1), 4))
+    },
+    &|input| {
+        input
+            .parse::<WaitingArea>()
+            .map(|area| area.settle(None, 5))
+    },
+);
+
+const DIRECTIONS: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+#[derive(Debug, Clone)]
+struct WaitingArea {
+    width: usize,
+    /// Which tiles are seats (rather than floor), row by row.
+    seats: Vec<bool>,
+}
+
+impl WaitingArea {
+    /// The seats someone in each seat pays attention to: the first seat in each direction, within `range` tiles
+    /// if there's a limit.
+    fn neighbours(&self, range: Option<usize>) -> Vec<Vec<usize>> {
+        let height = self.seats.len() / self.width;
+
+        (0..self.seats.len())
+            .map(|seat| {
+                let (x, y) = (seat % self.width, seat / self.width);
+
+                DIRECTIONS
+                    .iter()
+                    .filter_map(|&(dx, dy)| {
+                        (1..=range.unwrap_or(usize::MAX))
+                            .map_while(|distance| {
+                                let x = x
+                                    .checked_add_signed(dx * distance as isize)
+                                    .filter(|&x| x < self.width)?;
+                                let y = y
+                                    .checked_add_signed(dy * distance as isize)
+                                    .filter(|&y| y < height)?;
+                                Some(y * self.width + x)
+                            })
+                            .find(|&other| self.seats[other])
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// How many seats end up occupied once people stop moving, if they sit down when none of the seats they can
+    /// see are taken and get up when at least `tolerance` of them are.
+    fn settle(&self, range: Option<usize>, tolerance: usize) -> usize {
+        let neighbours = self.neighbours(range);
+        let mut occupied = vec![false; self.seats.len()];
+
+        loop {
+            let next: Vec<bool> = (0..self.seats.len())
+                .map(|seat| {
+                    let taken = neighbours[seat]
+                        .iter()
+                        .filter(|&&other| occupied[other])
+                        .count();
+                    self.seats[seat]
+                        && if occupied[seat] {
+                            taken < tolerance
+                        } else {
+                            taken == 0
+                        }
+                })
+                .collect();
+
+            if next == occupied {
+                return occupied.into_iter().filter(|&taken| taken).count();
+            }
+            occupied = next;
+        }
+    }
+}
+
+impl std::str::FromStr for WaitingArea {
+    type Err = eyre::Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let width = s.lines().next().map_or(0, str::len);
+        let mut seats = Vec::new();
+
+        for line in s.lines() {
+            ensure!(line.len() == width, "the waiting area isn't rectangular");
+
+            for tile in line.chars() {
+                seats.push(match tile {
+                    'L' => true,
+                    '.' => false,
+                    _ => bail!("unexpected tile {tile:?}"),
+                });
+            }
+        }
+
+        ensure!(width > 0, "the waiting area is empty");
+        Ok(Self { width, seats })
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let area: WaitingArea = "L.LL.LL.LL
+LLLLLLL.LL
+L.L.L..L..
+LLLL.LL.LL
+L.LL.LL.LL
+L.LLLLL.LL
+..L.L.....
+LLLLLLLLLL
+L.LLLLLL.L
+L.LLLLL.LL"
+        .parse()
+        .unwrap();
+
+    assert_eq!(area.settle(Some(1), 4), 37);
+    assert_eq!(area.settle(None, 5), 26);
+}
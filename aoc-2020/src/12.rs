@@ -0,0 +1,83 @@
+use eyre::{Result, bail};
+
+use aoc_meta::Problem;
+
+pub const RAIN_RISK: Problem = Problem::solved(
+    &|input| navigate(&parse_instructions(input)?, (1, 0), false),
+    &|input| navigate(&parse_instructions(input)?, (10, 1), true),
+);
+
+fn parse_instructions(input: &str) -> Result<Vec<(char, i64)>> {
+    input
+        .lines()
+        .map(|line| {
+            let Some(action) = line.chars().next() else {
+                bail!("empty instruction");
+            };
+            Ok((action, line[action.len_utf8()..].parse()?))
+        })
+        .collect()
+}
+
+/// Follow the instructions and return how far the ship ends up from where it started, by Manhattan distance. The
+/// ship moves towards `waypoint`, which is relative to the ship; it starts off as just the ship's heading, but with
+/// `move_waypoint` the compass directions move the waypoint instead of the ship.
+fn navigate(
+    instructions: &[(char, i64)],
+    mut waypoint: (i64, i64),
+    move_waypoint: bool,
+) -> Result<u64> {
+    let mut ship = (0, 0);
+
+    for &(action, value) in instructions {
+        let direction = match action {
+            'N' => Some((0, 1)),
+            'S' => Some((0, -1)),
+            'E' => Some((1, 0)),
+            'W' => Some((-1, 0)),
+            _ => None,
+        };
+
+        match (action, direction) {
+            (_, Some((dx, dy))) => {
+                let target = if move_waypoint {
+                    &mut waypoint
+                } else {
+                    &mut ship
+                };
+                target.0 += dx * value;
+                target.1 += dy * value;
+            }
+            ('L' | 'R', _) => {
+                if value % 90 != 0 {
+                    bail!("the ship can only turn in steps of 90 degrees, not {value}");
+                }
+
+                let quarter_turns = if action == 'L' {
+                    value / 90
+                } else {
+                    -value / 90
+                };
+                for _ in 0..quarter_turns.rem_euclid(4) {
+                    waypoint = (-waypoint.1, waypoint.0);
+                }
+            }
+            ('F', _) => {
+                ship.0 += waypoint.0 * value;
+                ship.1 += waypoint.1 * value;
+            }
+            _ => bail!("unknown action {action:?}"),
+        }
+    }
+
+    Ok(ship.0.unsigned_abs() + ship.1.unsigned_abs())
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let instructions = parse_instructions("F10\nN3\nF7\nR90\nF11").unwrap();
+    assert_eq!(navigate(&instructions, (1, 0), false).unwrap(), 25);
+    assert_eq!(navigate(&instructions, (10, 1), true).unwrap(), 286);
+}
@@ -0,0 +1,69 @@
+use eyre::{OptionExt, Result};
+
+use aoc_common::math;
+use aoc_meta::Problem;
+
+pub const SHUTTLE_SEARCH: Problem = Problem::solved(
+    &|input| {
+        let (earliest, buses) = parse_notes(input)?;
+        earliest_bus(earliest, &buses).ok_or_eyre("there are no buses")
+    },
+    &|input| {
+        let (_, buses) = parse_notes(input)?;
+        contest(&buses).ok_or_eyre("the buses never line up")
+    },
+);
+
+/// The earliest time you could leave, and the bus IDs in the schedule with their positions in it.
+fn parse_notes(input: &str) -> Result<(u64, Vec<(u64, u64)>)> {
+    let (earliest, schedule) = input
+        .split_once('\n')
+        .ok_or_eyre("expected two lines of notes")?;
+    let buses = schedule
+        .split(',')
+        .zip(0..)
+        .filter(|&(id, _)| id != "x")
+        .map(|(id, offset)| Ok((id.parse()?, offset)))
+        .collect::<Result<_>>()?;
+
+    Ok((earliest.parse()?, buses))
+}
+
+/// The ID of the first bus to leave after `earliest`, times how long you'd wait for it.
+fn earliest_bus(earliest: u64, buses: &[(u64, u64)]) -> Option<u64> {
+    buses
+        .iter()
+        .map(|&(id, _)| (id, (id - earliest % id) % id))
+        .min_by_key(|&(_, wait)| wait)
+        .map(|(id, wait)| id * wait)
+}
+
+/// The earliest time each bus leaves as many minutes after it as its position in the schedule.
+fn contest(buses: &[(u64, u64)]) -> Option<u64> {
+    math::crt(
+        buses
+            .iter()
+            .map(|&(id, offset)| ((id - offset % id) % id, id)),
+    )
+    .map(|(time, _)| time)
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let (earliest, buses) = parse_notes("939\n7,13,x,x,59,x,31,19").unwrap();
+    assert_eq!(earliest_bus(earliest, &buses), Some(295));
+    assert_eq!(contest(&buses), Some(1068781));
+
+    for (schedule, time) in [
+        ("17,x,13,19", 3417),
+        ("67,7,59,61", 754018),
+        ("67,x,7,59,61", 779210),
+        ("67,7,x,59,61", 1261476),
+        ("1789,37,47,1889", 1202161486),
+    ] {
+        let (_, buses) = parse_notes(&format!("0\n{schedule}")).unwrap();
+        assert_eq!(contest(&buses), Some(time));
+    }
+}
@@ -0,0 +1,120 @@
+use eyre::{OptionExt, Result, bail};
+use fnv::FnvHashMap;
+
+use aoc_meta::Problem;
+
+pub const DOCKING_DATA: Problem = Problem::solved(
+    &|input| parse_program(input).map(|program| run(&program, Decoder::Value)),
+    &|input| parse_program(input).map(|program| run(&program, Decoder::Address)),
+);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Instruction {
+    Mask { ones: u64, floating: u64 },
+    Write { address: u64, value: u64 },
+}
+
+/// Which version of the decoder chip the program is running on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Decoder {
+    /// The mask overwrites bits of the values written.
+    Value,
+    /// The mask overwrites bits of the addresses written to, and `X`s stand for both values.
+    Address,
+}
+
+/// The sum of every value left in memory once the program finishes.
+fn run(program: &[Instruction], decoder: Decoder) -> u64 {
+    let mut memory = FnvHashMap::default();
+    let (mut ones, mut floating) = (0, 0);
+
+    for &instruction in program {
+        match (instruction, decoder) {
+            (
+                Instruction::Mask {
+                    ones: o,
+                    floating: f,
+                },
+                _,
+            ) => (ones, floating) = (o, f),
+            (Instruction::Write { address, value }, Decoder::Value) => {
+                memory.insert(address, value & floating | ones);
+            }
+            (Instruction::Write { address, value }, Decoder::Address) => {
+                let base = (address | ones) & !floating;
+
+                // Walk through every subset of the floating bits.
+                let mut subset = 0u64;
+                loop {
+                    memory.insert(base | subset, value);
+                    subset = subset.wrapping_sub(floating) & floating;
+                    if subset == 0 {
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    memory.values().sum()
+}
+
+fn parse_program(input: &str) -> Result<Vec<Instruction>> {
+    input
+        .lines()
+        .map(|line| {
+            let (target, value) = line.split_once(" = ").ok_or_eyre("malformed instruction")?;
+
+            if target == "mask" {
+                if value.len() != 36 {
+                    bail!("masks are 36 bits long");
+                }
+
+                let (ones, floating) =
+                    value.bytes().try_fold((0, 0), |(ones, floating), bit| {
+                        let (one, float) = match bit {
+                            b'0' => (0, 0),
+                            b'1' => (1, 0),
+                            b'X' => (0, 1),
+                            _ => bail!("unexpected mask bit {:?}", char::from(bit)),
+                        };
+                        Ok((ones << 1 | one, floating << 1 | float))
+                    })?;
+
+                Ok(Instruction::Mask { ones, floating })
+            } else {
+                let address = target
+                    .strip_prefix("mem[")
+                    .and_then(|target| target.strip_suffix(']'))
+                    .ok_or_eyre("expected a mask or a write to memory")?;
+                Ok(Instruction::Write {
+                    address: address.parse()?,
+                    value: value.parse()?,
+                })
+            }
+        })
+        .collect()
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let program = parse_program(
+        "mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X
+mem[8] = 11
+mem[7] = 101
+mem[8] = 0",
+    )
+    .unwrap();
+    assert_eq!(run(&program, Decoder::Value), 165);
+
+    let program = parse_program(
+        "mask = 000000000000000000000000000000X1001X
+mem[42] = 100
+mask = 00000000000000000000000000000000X0XX
+mem[26] = 1",
+    )
+    .unwrap();
+    assert_eq!(run(&program, Decoder::Address), 208);
+}
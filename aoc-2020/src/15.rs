@@ -0,0 +1,58 @@
+use eyre::{Result, ensure};
+
+use aoc_meta::Problem;
+
+pub const RAMBUNCTIOUS_RECITATION: Problem = Problem::solved(
+    &|input| parse_starting_numbers(input).map(|numbers| spoken(&numbers, 2020)),
+    &|input| parse_starting_numbers(input).map(|numbers| spoken(&numbers, 30_000_000)),
+);
+
+fn parse_starting_numbers(input: &str) -> Result<Vec<u32>> {
+    let numbers: Vec<u32> = input
+        .trim()
+        .split(',')
+        .map(str::parse)
+        .collect::<Result<_, _>>()?;
+    ensure!(!numbers.is_empty(), "the game needs starting numbers");
+    Ok(numbers)
+}
+
+/// The `turn`th number spoken in the game.
+fn spoken(starting: &[u32], turn: u32) -> u32 {
+    // The turn each number was last spoken on, or 0 if it hasn't been. A number spoken on turn `t` can be at most
+    // `t - 1`, so this never needs to be bigger than the number of turns.
+    let size = (turn as usize).max(starting.iter().map(|&n| n as usize + 1).max().unwrap_or(0));
+    let mut last_spoken = vec![0u32; size];
+
+    for (number, turn) in starting.iter().zip(1..) {
+        last_spoken[*number as usize] = turn;
+    }
+
+    let mut current = starting[starting.len() - 1];
+    for turn in starting.len() as u32..turn {
+        let previous = std::mem::replace(&mut last_spoken[current as usize], turn);
+        current = if previous == 0 { 0 } else { turn - previous };
+    }
+
+    current
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    for (starting, nth) in [
+        ([0, 3, 6], 436),
+        ([1, 3, 2], 1),
+        ([2, 1, 3], 10),
+        ([1, 2, 3], 27),
+        ([2, 3, 1], 78),
+        ([3, 2, 1], 438),
+        ([3, 1, 2], 1836),
+    ] {
+        assert_eq!(spoken(&starting, 2020), nth);
+    }
+
+    assert_eq!(spoken(&[0, 3, 6], 10), 0);
+    assert_eq!(spoken(&[0, 3, 6], 9), 4);
+}
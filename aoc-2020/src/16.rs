@@ -0,0 +1,196 @@
+use std::ops::RangeInclusive;
+
+use eyre::{OptionExt, Result, bail};
+
+use aoc_meta::Problem;
+
+pub const TICKET_TRANSLATION: Problem = Problem::solved(
+    &|input| Notes::parse(input).map(|notes| notes.error_rate()),
+    &|input| {
+        let notes = Notes::parse(input)?;
+        let fields = notes.field_order()?;
+
+        Ok::<_, eyre::Report>(
+            fields
+                .iter()
+                .zip(&notes.ticket)
+                .filter(|&(&field, _)| notes.fields[field].name.starts_with("departure"))
+                .map(|(_, &value)| value)
+                .product::<u64>(),
+        )
+    },
+);
+
+#[derive(Debug)]
+struct Field<'a> {
+    name: &'a str,
+    ranges: [RangeInclusive<u64>; 2],
+}
+
+impl Field<'_> {
+    fn allows(&self, value: u64) -> bool {
+        self.ranges.iter().any(|range| range.contains(&value))
+    }
+}
+
+#[derive(Debug)]
+struct Notes<'a> {
+    fields: Vec<Field<'a>>,
+    ticket: Vec<u64>,
+    nearby: Vec<Vec<u64>>,
+}
+
+impl<'a> Notes<'a> {
+    fn valid_for_any_field(&self, value: u64) -> bool {
+        self.fields.iter().any(|field| field.allows(value))
+    }
+
+    /// The sum of the values on nearby tickets that aren't valid for any field.
+    fn error_rate(&self) -> u64 {
+        self.nearby
+            .iter()
+            .flatten()
+            .filter(|&&value| !self.valid_for_any_field(value))
+            .sum()
+    }
+
+    /// Which field is at each position on the tickets, as indices into `fields`. Each position is narrowed down to
+    /// the fields every valid nearby ticket allows there, and then positions with only one field left rule that
+    /// field out everywhere else.
+    fn field_order(&self) -> Result<Vec<usize>> {
+        let valid: Vec<&Vec<u64>> = self
+            .nearby
+            .iter()
+            .filter(|ticket| ticket.iter().all(|&value| self.valid_for_any_field(value)))
+            .collect();
+
+        let mut candidates: Vec<u64> = (0..self.ticket.len())
+            .map(|position| {
+                (0..self.fields.len())
+                    .filter(|&field| {
+                        valid
+                            .iter()
+                            .all(|ticket| self.fields[field].allows(ticket[position]))
+                    })
+                    .fold(0, |mask, field| mask | 1 << field)
+            })
+            .collect();
+
+        let mut known = 0u64;
+        while let Some(single) = candidates
+            .iter()
+            .copied()
+            .find(|&mask| mask.count_ones() == 1 && mask & known == 0)
+        {
+            known |= single;
+            for mask in candidates.iter_mut().filter(|mask| **mask != single) {
+                *mask &= !single;
+            }
+        }
+
+        if candidates.iter().any(|mask| mask.count_ones() != 1) {
+            bail!("the nearby tickets don't pin down every field");
+        }
+        Ok(candidates
+            .into_iter()
+            .map(|mask| mask.trailing_zeros() as usize)
+            .collect())
+    }
+
+    fn parse(input: &'a str) -> Result<Self> {
+        let mut sections = input.split("\n\n");
+        let (Some(fields), Some(ticket), Some(nearby)) =
+            (sections.next(), sections.next(), sections.next())
+        else {
+            bail!("expected fields, your ticket and nearby tickets");
+        };
+
+        let fields = fields
+            .lines()
+            .map(|line| {
+                let (name, ranges) = line.split_once(": ").ok_or_eyre("malformed field")?;
+                let (a, b) = ranges
+                    .split_once(" or ")
+                    .ok_or_eyre("fields have two ranges")?;
+                Ok(Field {
+                    name,
+                    ranges: [parse_range(a)?, parse_range(b)?],
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        if fields.len() > 64 {
+            bail!("there are too many fields");
+        }
+
+        let tickets = |section: &str, header| -> Result<Vec<Vec<u64>>> {
+            let tickets = section
+                .strip_prefix(header)
+                .ok_or_eyre("missing ticket header")?;
+            tickets
+                .lines()
+                .map(|line| Ok(line.split(',').map(str::parse).collect::<Result<_, _>>()?))
+                .collect()
+        };
+
+        let ticket = tickets(ticket, "your ticket:\n")?
+            .into_iter()
+            .next()
+            .ok_or_eyre("missing your ticket")?;
+        let nearby = tickets(nearby, "nearby tickets:\n")?;
+
+        Ok(Self {
+            fields,
+            ticket,
+            nearby,
+        })
+    }
+}
+
+fn parse_range(range: &str) -> Result<RangeInclusive<u64>> {
+    let (start, end) = range.split_once('-').ok_or_eyre("malformed range")?;
+    Ok(start.parse()?..=end.parse()?)
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let notes = Notes::parse(
+        "class: 1-3 or 5-7
+row: 6-11 or 33-44
+seat: 13-40 or 45-50
+
+your ticket:
+7,1,14
+
+nearby tickets:
+7,3,47
+40,4,50
+55,2,20
+38,6,12",
+    )
+    .unwrap();
+    assert_eq!(notes.error_rate(), 71);
+
+    let notes = Notes::parse(
+        "class: 0-1 or 4-19
+row: 0-5 or 8-19
+seat: 0-13 or 16-19
+
+your ticket:
+11,12,13
+
+nearby tickets:
+3,9,18
+15,1,5
+5,14,9",
+    )
+    .unwrap();
+    let names: Vec<&str> = notes
+        .field_order()
+        .unwrap()
+        .into_iter()
+        .map(|field| notes.fields[field].name)
+        .collect();
+    assert_eq!(names, ["row", "class", "seat"]);
+}
@@ -0,0 +1,100 @@
+use eyre::{Result, bail};
+use fnv::{FnvHashMap, FnvHashSet};
+
+use aoc_meta::Problem;
+
+pub const CONWAY_CUBES: Problem = Problem::solved(
+    &|input| PocketDimension::<3>::parse(input).map(|dimension| dimension.boot().active.len()),
+    &|input| PocketDimension::<4>::parse(input).map(|dimension| dimension.boot().active.len()),
+);
+
+/// An infinite grid of cubes in `D` dimensions, of which only the active ones are stored.
+#[derive(Debug, Clone)]
+struct PocketDimension<const D: usize> {
+    active: FnvHashSet<[i32; D]>,
+}
+
+impl<const D: usize> PocketDimension<D> {
+    /// The offsets from a cube to each of its `3^D - 1` neighbours.
+    fn neighbour_offsets() -> Vec<[i32; D]> {
+        (0..3usize.pow(D as u32))
+            .map(|mut n| {
+                std::array::from_fn(|_| {
+                    let offset = (n % 3) as i32 - 1;
+                    n /= 3;
+                    offset
+                })
+            })
+            .filter(|offset: &[i32; D]| offset.iter().any(|&d| d != 0))
+            .collect()
+    }
+
+    fn step(&self, offsets: &[[i32; D]]) -> Self {
+        let mut neighbours: FnvHashMap<[i32; D], u8> = FnvHashMap::default();
+
+        for cube in &self.active {
+            for offset in offsets {
+                *neighbours
+                    .entry(std::array::from_fn(|axis| cube[axis] + offset[axis]))
+                    .or_default() += 1;
+            }
+        }
+
+        let active = neighbours
+            .into_iter()
+            .filter(|(cube, count)| *count == 3 || *count == 2 && self.active.contains(cube))
+            .map(|(cube, _)| cube)
+            .collect();
+
+        Self { active }
+    }
+
+    /// The dimension after the six cycles of the boot process.
+    fn boot(self) -> Self {
+        let offsets = Self::neighbour_offsets();
+        (0..6).fold(self, |dimension, _| dimension.step(&offsets))
+    }
+
+    /// A slice of the dimension, with every coordinate past the first two at 0.
+    fn parse(input: &str) -> Result<Self> {
+        let mut active = FnvHashSet::default();
+
+        for (line, y) in input.lines().zip(0..) {
+            for (cube, x) in line.chars().zip(0..) {
+                match cube {
+                    '#' => {
+                        active.insert(std::array::from_fn(|axis| {
+                            [x, y].get(axis).copied().unwrap_or(0)
+                        }));
+                    }
+                    '.' => {}
+                    _ => bail!("unexpected cube {cube:?}"),
+                }
+            }
+        }
+
+        Ok(Self { active })
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let input = ".#.\n..#\n###";
+
+    let dimension = PocketDimension::<3>::parse(input).unwrap();
+    let offsets = PocketDimension::<3>::neighbour_offsets();
+    assert_eq!(offsets.len(), 26);
+    assert_eq!(dimension.step(&offsets).active.len(), 11);
+    assert_eq!(dimension.boot().active.len(), 112);
+
+    assert_eq!(
+        PocketDimension::<4>::parse(input)
+            .unwrap()
+            .boot()
+            .active
+            .len(),
+        848
+    );
+}
@@ -0,0 +1,119 @@
+use eyre::{Result, bail};
+
+use aoc_meta::Problem;
+
+pub const OPERATION_ORDER: Problem = Problem::solved(
+    &|input| {
+        input
+            .lines()
+            .map(|line| evaluate(line, |_| 0))
+            .sum::<Result<u64>>()
+    },
+    &|input| {
+        input
+            .lines()
+            .map(|line| evaluate(line, |operator| if operator == b'+' { 1 } else { 0 }))
+            .sum::<Result<u64>>()
+    },
+);
+
+/// Evaluate an expression of `+`, `*`, parentheses and single digits, where operators with higher `precedence`
+/// are applied first and operators with the same precedence are applied left to right.
+fn evaluate(expression: &str, precedence: fn(u8) -> u8) -> Result<u64> {
+    let tokens: Vec<u8> = expression
+        .bytes()
+        .filter(|b| !b.is_ascii_whitespace())
+        .collect();
+    let mut parser = Parser {
+        tokens: &tokens,
+        position: 0,
+        precedence,
+    };
+
+    let value = parser.expression(0)?;
+    if parser.position != tokens.len() {
+        bail!(
+            "unexpected {:?} in {expression:?}",
+            char::from(tokens[parser.position])
+        );
+    }
+
+    Ok(value)
+}
+
+/// A precedence climbing parser, evaluating as it goes.
+struct Parser<'a> {
+    tokens: &'a [u8],
+    position: usize,
+    precedence: fn(u8) -> u8,
+}
+
+impl Parser<'_> {
+    /// Evaluate as much of the expression as possible using only operators with at least `min_precedence`.
+    fn expression(&mut self, min_precedence: u8) -> Result<u64> {
+        let mut value = self.operand()?;
+
+        while let Some(&operator @ (b'+' | b'*')) = self.tokens.get(self.position) {
+            let precedence = (self.precedence)(operator);
+            if precedence < min_precedence {
+                break;
+            }
+
+            self.position += 1;
+            let rhs = self.expression(precedence + 1)?;
+            value = if operator == b'+' {
+                value + rhs
+            } else {
+                value * rhs
+            };
+        }
+
+        Ok(value)
+    }
+
+    fn operand(&mut self) -> Result<u64> {
+        let token = self.tokens.get(self.position).copied();
+        self.position += 1;
+
+        match token {
+            Some(digit @ b'0'..=b'9') => Ok(u64::from(digit - b'0')),
+            Some(b'(') => {
+                let value = self.expression(0)?;
+                if self.tokens.get(self.position) != Some(&b')') {
+                    bail!("unmatched (");
+                }
+                self.position += 1;
+                Ok(value)
+            }
+            Some(token) => bail!("expected a number or (, found {:?}", char::from(token)),
+            None => bail!("the expression ends too soon"),
+        }
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let same = |_| 0;
+    let addition_first = |operator| if operator == b'+' { 1 } else { 0 };
+
+    for (expression, left_to_right, additions_first) in [
+        ("1 + 2 * 3 + 4 * 5 + 6", 71, 231),
+        ("1 + (2 * 3) + (4 * (5 + 6))", 51, 51),
+        ("2 * 3 + (4 * 5)", 26, 46),
+        ("5 + (8 * 3 + 9 + 3 * 4 * 3)", 437, 1445),
+        ("5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))", 12240, 669060),
+        (
+            "((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2",
+            13632,
+            23340,
+        ),
+    ] {
+        assert_eq!(evaluate(expression, same).unwrap(), left_to_right);
+        assert_eq!(
+            evaluate(expression, addition_first).unwrap(),
+            additions_first
+        );
+    }
+}
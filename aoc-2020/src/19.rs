@@ -0,0 +1,181 @@
+use eyre::{OptionExt, Result, bail};
+use fnv::FnvHashMap;
+
+use aoc_meta::Problem;
+
+pub const MONSTER_MESSAGES: Problem = Problem::solved(
+    &|input| {
+        let (rules, messages) = parse_rules_and_messages(input)?;
+        Ok::<_, eyre::Report>(messages.filter(|message| rules.matches(message)).count())
+    },
+    &|input| {
+        let (mut rules, messages) = parse_rules_and_messages(input)?;
+        rules.add_loops();
+        Ok::<_, eyre::Report>(messages.filter(|message| rules.matches(message)).count())
+    },
+);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Rule {
+    Char(u8),
+    /// Any one of a list of sequences of other rules.
+    Alternatives(Vec<Vec<usize>>),
+}
+
+#[derive(Debug)]
+struct Rules {
+    rules: FnvHashMap<usize, Rule>,
+}
+
+impl Rules {
+    /// Swap rules 8 and 11 for the versions that loop: one or more 42s, and one or more 42s followed by as many
+    /// 31s.
+    fn add_loops(&mut self) {
+        self.rules
+            .insert(8, Rule::Alternatives(vec![vec![42], vec![42, 8]]));
+        self.rules
+            .insert(11, Rule::Alternatives(vec![vec![42, 31], vec![42, 11, 31]]));
+    }
+
+    /// Whether all of `message` matches rule 0.
+    fn matches(&self, message: &str) -> bool {
+        self.ends(0, message.as_bytes(), 0).contains(&message.len())
+    }
+
+    /// Every position rule `rule` could finish matching at if it starts at `start`. Following every possibility
+    /// like this copes with rules that loop back on themselves, as long as they consume something first.
+    fn ends(&self, rule: usize, message: &[u8], start: usize) -> Vec<usize> {
+        match self.rules.get(&rule) {
+            Some(&Rule::Char(c)) => match message.get(start) {
+                Some(&m) if m == c => vec![start + 1],
+                _ => Vec::new(),
+            },
+            Some(Rule::Alternatives(alternatives)) => alternatives
+                .iter()
+                .flat_map(|sequence| {
+                    sequence.iter().fold(vec![start], |positions, &rule| {
+                        positions
+                            .into_iter()
+                            .filter(|&position| position < message.len())
+                            .flat_map(|position| self.ends(rule, message, position))
+                            .collect()
+                    })
+                })
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+}
+
+fn parse_rules_and_messages(input: &str) -> Result<(Rules, impl Iterator<Item = &str>)> {
+    let (rules, messages) = input
+        .split_once("\n\n")
+        .ok_or_eyre("expected rules, then messages")?;
+
+    let rules = rules
+        .lines()
+        .map(|line| {
+            let (number, rule) = line.split_once(": ").ok_or_eyre("malformed rule")?;
+
+            let rule = if let Some(c) = rule
+                .strip_prefix('"')
+                .and_then(|rule| rule.strip_suffix('"'))
+            {
+                match c.as_bytes() {
+                    &[c] => Rule::Char(c),
+                    _ => bail!("rules match one character at a time"),
+                }
+            } else {
+                Rule::Alternatives(
+                    rule.split(" | ")
+                        .map(|sequence| sequence.split(' ').map(str::parse).collect())
+                        .collect::<Result<_, _>>()?,
+                )
+            };
+
+            Ok((number.parse()?, rule))
+        })
+        .collect::<Result<_>>()?;
+
+    Ok((Rules { rules }, messages.lines()))
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let (rules, messages) = parse_rules_and_messages(
+        r#"0: 4 1 5
+1: 2 3 | 3 2
+2: 4 4 | 5 5
+3: 4 5 | 5 4
+4: "a"
+5: "b"
+
+ababbb
+bababa
+abbbab
+aaabbb
+aaaabbb"#,
+    )
+    .unwrap();
+    assert_eq!(messages.filter(|message| rules.matches(message)).count(), 2);
+
+    let input = r#"42: 9 14 | 10 1
+9: 14 27 | 1 26
+10: 23 14 | 28 1
+1: "a"
+11: 42 31
+5: 1 14 | 15 1
+19: 14 1 | 14 14
+12: 24 14 | 19 1
+16: 15 1 | 14 14
+31: 14 17 | 1 13
+6: 14 14 | 1 14
+2: 1 24 | 14 4
+0: 8 11
+13: 14 3 | 1 12
+15: 1 | 14
+17: 14 2 | 1 7
+23: 25 1 | 22 14
+28: 16 1
+4: 1 1
+20: 14 14 | 1 15
+3: 5 14 | 16 1
+27: 1 6 | 14 18
+14: "b"
+21: 14 1 | 1 14
+25: 1 1 | 1 14
+22: 14 14
+8: 42
+26: 14 22 | 1 20
+18: 15 15
+7: 14 5 | 1 21
+24: 14 1
+
+abbbbbabbbaaaababbaabbbbabababbbabbbbbbabaaaa
+bbabbbbaabaabba
+babbbbaabbbbbabbbbbbaabaaabaaa
+aaabbbbbbaaaabaababaabababbabaaabbababababaaa
+bbbbbbbaaaabbbbaaabbabaaa
+bbbababbbbaaaaaaaabbababaaababaabab
+ababaaaaaabaaab
+ababaaaaabbbaba
+baabbaaaabbaaaababbaababb
+abbbbabbbbaaaababbbbbbaaaababb
+aaaaabbaabaaaaababaa
+aaaabbaaaabbaaa
+aaaabbaabbaaaaaaabbbabbbaaabbaabaaa
+babaaabbbaaabaababbaabababaaab
+aabbbbbaabbbaaaaaabbbbbababaaaaabbaaabba"#;
+
+    let (rules, messages) = parse_rules_and_messages(input).unwrap();
+    assert_eq!(messages.filter(|message| rules.matches(message)).count(), 3);
+
+    let (mut rules, messages) = parse_rules_and_messages(input).unwrap();
+    rules.add_loops();
+    assert_eq!(
+        messages.filter(|message| rules.matches(message)).count(),
+        12
+    );
+}
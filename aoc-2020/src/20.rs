@@ -0,0 +1,355 @@
+use eyre::{OptionExt, Result, bail, ensure};
+use fnv::FnvHashMap;
+
+use aoc_meta::Problem;
+
+pub const JURASSIC_JIGSAW: Problem = Problem::solved(
+    &|input| {
+        let jigsaw = Jigsaw::parse(input)?;
+        Ok::<_, eyre::Report>(
+            jigsaw
+                .corners()?
+                .into_iter()
+                .map(|corner| jigsaw.tiles[corner].id)
+                .product::<u64>(),
+        )
+    },
+    &|input| {
+        Jigsaw::parse(input)?
+            .assemble()
+            .map(|image| water_roughness(&image))
+    },
+);
+
+/// Rows of pixels, top to bottom, where `true` is `#`.
+type Pixels = Vec<Vec<bool>>;
+
+/// `pixels` turned a quarter turn clockwise.
+fn rotate(pixels: &Pixels) -> Pixels {
+    let size = pixels.len();
+    (0..size)
+        .map(|row| {
+            (0..size)
+                .map(|column| pixels[size - 1 - column][row])
+                .collect()
+        })
+        .collect()
+}
+
+/// Every way `pixels` could be turned and flipped over.
+fn orientations(pixels: &Pixels) -> impl Iterator<Item = Pixels> {
+    let flipped: Pixels = pixels
+        .iter()
+        .map(|row| row.iter().rev().copied().collect())
+        .collect();
+
+    [pixels.clone(), flipped].into_iter().flat_map(|pixels| {
+        std::iter::successors(Some(pixels), |pixels| Some(rotate(pixels))).take(4)
+    })
+}
+
+/// The edges of `pixels` as bits, top and bottom read left to right, and left and right read top to bottom.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Edges {
+    top: u16,
+    right: u16,
+    bottom: u16,
+    left: u16,
+}
+
+impl Edges {
+    fn of(pixels: &Pixels) -> Self {
+        let bits = |pixels: &mut dyn Iterator<Item = bool>| {
+            pixels.fold(0, |bits, pixel| bits << 1 | u16::from(pixel))
+        };
+        let last = pixels.len() - 1;
+
+        Self {
+            top: bits(&mut pixels[0].iter().copied()),
+            right: bits(&mut pixels.iter().map(|row| row[last])),
+            bottom: bits(&mut pixels[last].iter().copied()),
+            left: bits(&mut pixels.iter().map(|row| row[0])),
+        }
+    }
+
+    fn all(self) -> [u16; 4] {
+        [self.top, self.right, self.bottom, self.left]
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Tile {
+    id: u64,
+    pixels: Pixels,
+}
+
+#[derive(Debug)]
+struct Jigsaw {
+    tiles: Vec<Tile>,
+    /// How many tiles have each edge, keyed by whichever of the edge's two readings is smaller, so the same edge
+    /// is counted no matter how the tile it's on is oriented.
+    edge_counts: FnvHashMap<u16, usize>,
+    /// How wide a tile is.
+    size: usize,
+}
+
+impl Jigsaw {
+    fn canonical(&self, edge: u16) -> u16 {
+        edge.min(edge.reverse_bits() >> (16 - self.size))
+    }
+
+    /// Whether `edge` doesn't match any other tile's, so must be on the outside of the image.
+    fn is_outside(&self, edge: u16) -> bool {
+        self.edge_counts[&self.canonical(edge)] == 1
+    }
+
+    /// The indices of the four tiles that only match other tiles on two of their edges.
+    fn corners(&self) -> Result<Vec<usize>> {
+        let corners: Vec<usize> = (0..self.tiles.len())
+            .filter(|&tile| {
+                let edges = Edges::of(&self.tiles[tile].pixels).all();
+                edges
+                    .into_iter()
+                    .filter(|&edge| self.is_outside(edge))
+                    .count()
+                    == 2
+            })
+            .collect();
+
+        ensure!(
+            corners.len() == 4,
+            "found {} corner tiles instead of 4",
+            corners.len()
+        );
+        Ok(corners)
+    }
+
+    /// Fit the tiles together, starting from a corner and working row by row, then strip their borders off to get
+    /// the actual image. Edges are unique enough that there's only ever one tile that fits.
+    fn assemble(&self) -> Result<Pixels> {
+        let side = self.tiles.len().isqrt();
+        ensure!(
+            side * side == self.tiles.len(),
+            "the tiles don't make a square"
+        );
+
+        let start = self.corners()?[0];
+        let corner = orientations(&self.tiles[start].pixels)
+            .find(|pixels| {
+                let edges = Edges::of(pixels);
+                self.is_outside(edges.top) && self.is_outside(edges.left)
+            })
+            .ok_or_eyre("the corner can't be turned to fit the top left")?;
+
+        let mut used = vec![false; self.tiles.len()];
+        used[start] = true;
+        let mut placed: Vec<Pixels> = vec![corner];
+
+        for position in 1..self.tiles.len() {
+            let fits = |pixels: &Pixels| {
+                let edges = Edges::of(pixels);
+                if position % side == 0 {
+                    edges.top == Edges::of(&placed[position - side]).bottom
+                } else {
+                    edges.left == Edges::of(&placed[position - 1]).right
+                }
+            };
+
+            let Some((index, pixels)) = self
+                .tiles
+                .iter()
+                .enumerate()
+                .filter(|&(index, _)| !used[index])
+                .find_map(|(index, tile)| {
+                    orientations(&tile.pixels)
+                        .find(fits)
+                        .map(|pixels| (index, pixels))
+                })
+            else {
+                bail!(
+                    "no tile fits at row {}, column {}",
+                    position / side,
+                    position % side
+                );
+            };
+
+            used[index] = true;
+            placed.push(pixels);
+        }
+
+        let inner = self.size - 2;
+        Ok((0..side * inner)
+            .map(|row| {
+                (0..side * inner)
+                    .map(|column| {
+                        placed[row / inner * side + column / inner][row % inner + 1]
+                            [column % inner + 1]
+                    })
+                    .collect()
+            })
+            .collect())
+    }
+
+    fn parse(input: &str) -> Result<Self> {
+        let tiles = input
+            .split("\n\n")
+            .map(|tile| {
+                let (header, pixels) = tile.split_once('\n').ok_or_eyre("tiles have a header")?;
+                let id = header
+                    .strip_prefix("Tile ")
+                    .and_then(|header| header.strip_suffix(':'))
+                    .ok_or_eyre("malformed tile header")?
+                    .parse()?;
+                let pixels: Pixels = pixels
+                    .lines()
+                    .map(|row| row.bytes().map(|b| b == b'#').collect())
+                    .collect();
+
+                Ok(Tile { id, pixels })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let size = tiles.first().map_or(0, |tile| tile.pixels.len());
+        ensure!(
+            (3..=16).contains(&size),
+            "tiles should be between 3 and 16 pixels wide"
+        );
+        ensure!(
+            tiles
+                .iter()
+                .all(|tile| tile.pixels.len() == size
+                    && tile.pixels.iter().all(|row| row.len() == size)),
+            "every tile should be the same size and square"
+        );
+
+        let mut jigsaw = Self {
+            tiles,
+            edge_counts: FnvHashMap::default(),
+            size,
+        };
+        for tile in &jigsaw.tiles {
+            for edge in Edges::of(&tile.pixels).all() {
+                *jigsaw
+                    .edge_counts
+                    .entry(jigsaw.canonical(edge))
+                    .or_default() += 1;
+            }
+        }
+
+        Ok(jigsaw)
+    }
+}
+
+const SEA_MONSTER: [&str; 3] = [
+    "                  # ",
+    "#    ##    ##    ###",
+    " #  #  #  #  #  #   ",
+];
+
+/// How many `#`s in the image aren't part of a sea monster, with the image turned so the monsters can be seen.
+fn water_roughness(image: &Pixels) -> usize {
+    let monster: Vec<(usize, usize)> = SEA_MONSTER
+        .iter()
+        .enumerate()
+        .flat_map(|(y, row)| {
+            row.bytes()
+                .enumerate()
+                .filter(|&(_, b)| b == b'#')
+                .map(move |(x, _)| (x, y))
+        })
+        .collect();
+    let (width, height) = (SEA_MONSTER[0].len(), SEA_MONSTER.len());
+    let total = image.iter().flatten().filter(|&&pixel| pixel).count();
+
+    for image in orientations(image) {
+        let mut part_of_monster = vec![vec![false; image.len()]; image.len()];
+
+        for top in 0..=image.len().saturating_sub(height) {
+            for left in 0..=image.len().saturating_sub(width) {
+                if monster.iter().all(|&(x, y)| image[top + y][left + x]) {
+                    for &(x, y) in &monster {
+                        part_of_monster[top + y][left + x] = true;
+                    }
+                }
+            }
+        }
+
+        let monsters = part_of_monster
+            .iter()
+            .flatten()
+            .filter(|&&pixel| pixel)
+            .count();
+        if monsters > 0 {
+            return total - monsters;
+        }
+    }
+
+    total
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    // Draw a picture of 3×3 tiles, where neighbouring tiles share a row or column of border, and hide a sea monster
+    // in the part that's left once the borders are removed. Like the real tiles, the noise it's drawn with leaves every
+    // border different from the others.
+    let mut state: u64 = 2;
+    let mut picture: Pixels = (0..28)
+        .map(|_| {
+            (0..28)
+                .map(|_| {
+                    state = state
+                        .wrapping_mul(6364136223846793005)
+                        .wrapping_add(1442695040888963407);
+                    state >> 63 == 0
+                })
+                .collect()
+        })
+        .collect();
+
+    let inside = |n: usize| n + n / 8 + 1;
+    for (y, row) in SEA_MONSTER.iter().enumerate() {
+        for (x, b) in row.bytes().enumerate() {
+            picture[inside(y + 3)][inside(x + 2)] = b == b'#';
+        }
+    }
+
+    let image: Pixels = (0..24)
+        .map(|y| (0..24).map(|x| picture[inside(y)][inside(x)]).collect())
+        .collect();
+    let total = image.iter().flatten().filter(|&&pixel| pixel).count();
+
+    // Cut it up, then turn the tiles this way and that and shuffle them.
+    let input: Vec<String> = (0..9)
+        .map(|n| (n * 4 % 9, n))
+        .map(|(position, n)| {
+            let (top, left) = (position / 3 * 9, position % 3 * 9);
+            let tile: Pixels = picture[top..top + 10]
+                .iter()
+                .map(|row| row[left..left + 10].to_vec())
+                .collect();
+            let tile = orientations(&tile).nth(n * 3 % 8).unwrap();
+
+            let rows: Vec<String> = tile
+                .iter()
+                .map(|row| row.iter().map(|&p| if p { '#' } else { '.' }).collect())
+                .collect();
+            format!("Tile {}:\n{}", 1000 + position, rows.join("\n"))
+        })
+        .collect();
+
+    let jigsaw = Jigsaw::parse(&input.join("\n\n")).unwrap();
+    let mut corners: Vec<u64> = jigsaw
+        .corners()
+        .unwrap()
+        .into_iter()
+        .map(|corner| jigsaw.tiles[corner].id)
+        .collect();
+    corners.sort_unstable();
+    assert_eq!(corners, [1000, 1002, 1006, 1008]);
+
+    let assembled = jigsaw.assemble().unwrap();
+    assert!(orientations(&image).any(|image| image == assembled));
+    assert_eq!(water_roughness(&assembled), total - 15);
+}
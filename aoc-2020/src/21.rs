@@ -0,0 +1,112 @@
+use std::collections::BTreeMap;
+
+use eyre::{OptionExt, Result, bail};
+use fnv::FnvHashSet;
+use itertools::Itertools;
+
+use aoc_meta::Problem;
+
+pub const ALLERGEN_ASSESSMENT: Problem = Problem::solved(
+    &|input| {
+        let foods = parse_foods(input)?;
+        let dangerous: FnvHashSet<&str> = identify_allergens(&foods)?.into_values().collect();
+
+        Ok::<_, eyre::Report>(
+            foods
+                .iter()
+                .flat_map(|(ingredients, _)| ingredients)
+                .filter(|i| !dangerous.contains(*i))
+                .count(),
+        )
+    },
+    &|input| {
+        identify_allergens(&parse_foods(input)?).map(|allergens| allergens.into_values().join(","))
+    },
+);
+
+type Food<'a> = (Vec<&'a str>, Vec<&'a str>);
+
+fn parse_foods(input: &str) -> Result<Vec<Food<'_>>> {
+    input
+        .lines()
+        .map(|line| {
+            let (ingredients, allergens) = match line.split_once(" (contains ") {
+                Some((ingredients, allergens)) => (
+                    ingredients,
+                    allergens
+                        .strip_suffix(')')
+                        .ok_or_eyre("unclosed allergen list")?,
+                ),
+                None => (line, ""),
+            };
+
+            Ok((
+                ingredients.split(' ').collect(),
+                allergens.split(", ").filter(|a| !a.is_empty()).collect(),
+            ))
+        })
+        .collect()
+}
+
+/// Which ingredient contains each allergen, sorted by allergen. An allergen's ingredient has to be in every food
+/// that lists the allergen, and once an allergen is pinned down to one ingredient, no other allergen can be in it.
+fn identify_allergens<'a>(foods: &[Food<'a>]) -> Result<BTreeMap<&'a str, &'a str>> {
+    let mut candidates: BTreeMap<&str, FnvHashSet<&str>> = BTreeMap::new();
+
+    for (ingredients, allergens) in foods {
+        for &allergen in allergens {
+            let ingredients: FnvHashSet<&str> = ingredients.iter().copied().collect();
+            candidates
+                .entry(allergen)
+                .and_modify(|candidates| candidates.retain(|i| ingredients.contains(i)))
+                .or_insert(ingredients);
+        }
+    }
+
+    let mut identified = BTreeMap::new();
+    while !candidates.is_empty() {
+        let Some((&allergen, ingredient)) =
+            candidates.iter().find_map(|(allergen, ingredients)| {
+                ingredients
+                    .iter()
+                    .exactly_one()
+                    .ok()
+                    .map(|&i| (allergen, i))
+            })
+        else {
+            bail!("the allergens can't all be pinned down");
+        };
+
+        candidates.remove(allergen);
+        for ingredients in candidates.values_mut() {
+            ingredients.remove(ingredient);
+        }
+        identified.insert(allergen, ingredient);
+    }
+
+    Ok(identified)
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let foods = parse_foods(
+        "mxmxvkd kfcds sqjhc nhms (contains dairy, fish)
+trh fvjkl sbzzf mxmxvkd (contains dairy)
+sqjhc fvjkl (contains soy)
+sqjhc mxmxvkd sbzzf (contains fish)",
+    )
+    .unwrap();
+
+    let allergens = identify_allergens(&foods).unwrap();
+    let dangerous: FnvHashSet<&str> = allergens.values().copied().collect();
+    let safe = foods
+        .iter()
+        .flat_map(|(ingredients, _)| ingredients)
+        .filter(|i| !dangerous.contains(*i))
+        .count();
+
+    assert_eq!(safe, 5);
+    assert_eq!(allergens.into_values().join(","), "mxmxvkd,sqjhc,fvjkl");
+}
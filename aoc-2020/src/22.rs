@@ -0,0 +1,114 @@
+use std::collections::VecDeque;
+
+use eyre::{OptionExt, Result};
+use fnv::FnvHashSet;
+
+use aoc_meta::Problem;
+
+pub const CRAB_COMBAT: Problem = Problem::solved(
+    &|input| {
+        let (mut one, mut two) = parse_decks(input)?;
+        let winner = if combat(&mut one, &mut two) { one } else { two };
+        Ok::<_, eyre::Report>(score(&winner))
+    },
+    &|input| {
+        let (mut one, mut two) = parse_decks(input)?;
+        let winner = if recursive_combat(&mut one, &mut two) {
+            one
+        } else {
+            two
+        };
+        Ok::<_, eyre::Report>(score(&winner))
+    },
+);
+
+type Deck = VecDeque<u8>;
+
+fn parse_decks(input: &str) -> Result<(Deck, Deck)> {
+    let (one, two) = input.split_once("\n\n").ok_or_eyre("expected two decks")?;
+    let deck = |deck: &str| -> Result<Deck> {
+        let (_, cards) = deck
+            .split_once('\n')
+            .ok_or_eyre("decks start with the player")?;
+        Ok(cards.lines().map(str::parse).collect::<Result<_, _>>()?)
+    };
+
+    Ok((deck(one)?, deck(two)?))
+}
+
+fn score(deck: &Deck) -> usize {
+    deck.iter()
+        .rev()
+        .zip(1..)
+        .map(|(&card, position)| usize::from(card) * position)
+        .sum()
+}
+
+/// Play until one player has all the cards, returning whether player one won.
+fn combat(one: &mut Deck, two: &mut Deck) -> bool {
+    while let (Some(a), Some(b)) = (one.front().copied(), two.front().copied()) {
+        one.pop_front();
+        two.pop_front();
+
+        if a > b {
+            one.extend([a, b]);
+        } else {
+            two.extend([b, a]);
+        }
+    }
+
+    two.is_empty()
+}
+
+/// Play recursive combat, returning whether player one won. Rounds where both players have enough cards are
+/// decided by a sub-game with copies of their next few cards, and a game that repeats itself goes to player one.
+fn recursive_combat(one: &mut Deck, two: &mut Deck) -> bool {
+    let mut seen = FnvHashSet::default();
+
+    while let (Some(a), Some(b)) = (one.front().copied(), two.front().copied()) {
+        if !seen.insert((one.clone(), two.clone())) {
+            return true;
+        }
+
+        one.pop_front();
+        two.pop_front();
+
+        let one_wins = if one.len() >= usize::from(a) && two.len() >= usize::from(b) {
+            let mut one: Deck = one.iter().take(usize::from(a)).copied().collect();
+            let mut two: Deck = two.iter().take(usize::from(b)).copied().collect();
+
+            // Whoever holds the highest card can never lose it, but it can still end in a repeat, which player
+            // one wins either way.
+            one.iter().max() > two.iter().max() || recursive_combat(&mut one, &mut two)
+        } else {
+            a > b
+        };
+
+        if one_wins {
+            one.extend([a, b]);
+        } else {
+            two.extend([b, a]);
+        }
+    }
+
+    two.is_empty()
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let input = "Player 1:\n9\n2\n6\n3\n1\n\nPlayer 2:\n5\n8\n4\n7\n10";
+
+    let (mut one, mut two) = parse_decks(input).unwrap();
+    assert!(!combat(&mut one, &mut two));
+    assert_eq!(score(&two), 306);
+
+    let (mut one, mut two) = parse_decks(input).unwrap();
+    assert!(!recursive_combat(&mut one, &mut two));
+    assert_eq!(score(&two), 291);
+
+    // This one would go on forever without spotting the repeat.
+    let (mut one, mut two) = parse_decks("Player 1:\n43\n19\n\nPlayer 2:\n2\n29\n14").unwrap();
+    assert!(recursive_combat(&mut one, &mut two));
+}
@@ -0,0 +1,117 @@
+use eyre::{Result, bail};
+
+use aoc_meta::Problem;
+
+pub const CRAB_CUPS: Problem = Problem::solved(
+    &|input| {
+        let cups = Cups::new(&parse_labels(input)?, 9);
+        Ok::<_, eyre::Report>(cups.play(100).labels_after_one())
+    },
+    &|input| {
+        let cups = Cups::new(&parse_labels(input)?, 1_000_000).play(10_000_000);
+        let first = cups.next[1];
+        Ok::<_, eyre::Report>(u64::from(first) * u64::from(cups.next[first as usize]))
+    },
+);
+
+fn parse_labels(input: &str) -> Result<Vec<u32>> {
+    let labels: Vec<u32> = input
+        .trim()
+        .chars()
+        .filter_map(|c| c.to_digit(10))
+        .collect();
+
+    let mut sorted = labels.clone();
+    sorted.sort_unstable();
+    if sorted != (1..=9).collect::<Vec<_>>() || input.trim().len() != 9 {
+        bail!("expected the labels 1 to 9 in some order");
+    }
+
+    Ok(labels)
+}
+
+/// The circle of cups as a linked list: `next[label]` is the label of the cup clockwise of it.
+#[derive(Debug)]
+struct Cups {
+    next: Vec<u32>,
+    current: u32,
+}
+
+impl Cups {
+    /// The cups with the given labels, followed by the rest of the labels up to `count` in order.
+    fn new(labels: &[u32], count: u32) -> Self {
+        let order: Vec<u32> = labels
+            .iter()
+            .copied()
+            .chain(labels.len() as u32 + 1..=count)
+            .collect();
+
+        let mut next = vec![0; count as usize + 1];
+        for (i, &label) in order.iter().enumerate() {
+            next[label as usize] = order[(i + 1) % order.len()];
+        }
+
+        Self {
+            next,
+            current: order[0],
+        }
+    }
+
+    fn play(mut self, moves: usize) -> Self {
+        let max = self.next.len() as u32 - 1;
+
+        for _ in 0..moves {
+            let a = self.next[self.current as usize];
+            let b = self.next[a as usize];
+            let c = self.next[b as usize];
+
+            let mut destination = self.current;
+            loop {
+                destination = if destination == 1 {
+                    max
+                } else {
+                    destination - 1
+                };
+                if ![a, b, c].contains(&destination) {
+                    break;
+                }
+            }
+
+            self.next[self.current as usize] = self.next[c as usize];
+            self.next[c as usize] = self.next[destination as usize];
+            self.next[destination as usize] = a;
+            self.current = self.next[self.current as usize];
+        }
+
+        self
+    }
+
+    /// The labels on the cups clockwise of cup 1.
+    fn labels_after_one(&self) -> String {
+        std::iter::successors(Some(self.next[1]), |&cup| Some(self.next[cup as usize]))
+            .take_while(|&cup| cup != 1)
+            .map(|cup| cup.to_string())
+            .collect()
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let labels = parse_labels("389125467").unwrap();
+    assert_eq!(
+        Cups::new(&labels, 9).play(10).labels_after_one(),
+        "92658374"
+    );
+    assert_eq!(
+        Cups::new(&labels, 9).play(100).labels_after_one(),
+        "67384529"
+    );
+
+    let cups = Cups::new(&labels, 1_000_000).play(10_000_000);
+    assert_eq!(
+        (cups.next[1], cups.next[cups.next[1] as usize]),
+        (934001, 159792)
+    );
+}
@@ -0,0 +1,127 @@
+use eyre::{Result, bail, ensure};
+use fnv::FnvHashSet;
+
+use aoc_common::automaton::{Board, Edges, Neighborhood, Rule};
+use aoc_meta::Problem;
+
+pub const LOBBY_LAYOUT: Problem = Problem::solved(
+    &|input| black_tiles(input).map(|tiles| tiles.len()),
+    &|input| black_tiles(input).and_then(|tiles| exhibit(&tiles, 100)),
+);
+
+/// A hex tile in axial coordinates: east is `(1, 0)` and south east is `(0, 1)`.
+type Hex = (i32, i32);
+
+/// The neighbours of a tile: east, west, south east, north west, south west and north east.
+const NEIGHBOURS: [(isize, isize); 6] = [(1, 0), (-1, 0), (0, 1), (0, -1), (-1, 1), (1, -1)];
+
+/// The tile at the end of a line of directions from the reference tile.
+fn follow(directions: &str) -> Result<Hex> {
+    let mut tile = (0, 0);
+    let mut chars = directions.chars();
+
+    while let Some(c) = chars.next() {
+        let (dq, dr) = match (
+            c,
+            if matches!(c, 'n' | 's') {
+                chars.next()
+            } else {
+                None
+            },
+        ) {
+            ('e', None) => (1, 0),
+            ('w', None) => (-1, 0),
+            ('s', Some('e')) => (0, 1),
+            ('s', Some('w')) => (-1, 1),
+            ('n', Some('e')) => (1, -1),
+            ('n', Some('w')) => (0, -1),
+            _ => bail!("unexpected direction in {directions:?}"),
+        };
+
+        tile = (tile.0 + dq, tile.1 + dr);
+    }
+
+    Ok(tile)
+}
+
+/// The tiles left black side up after flipping the tile at the end of each line of directions.
+fn black_tiles(input: &str) -> Result<FnvHashSet<Hex>> {
+    let mut black = FnvHashSet::default();
+
+    for line in input.lines() {
+        let tile = follow(line)?;
+        if !black.remove(&tile) {
+            black.insert(tile);
+        }
+    }
+
+    Ok(black)
+}
+
+/// How many tiles are black after `days` of the exhibit: black tiles with zero or more than two black neighbours
+/// flip to white, and white tiles with exactly two black neighbours flip to black.
+fn exhibit(black: &FnvHashSet<Hex>, days: usize) -> Result<usize> {
+    // The pattern can grow by at most one tile in each direction a day, so leave room for that.
+    let extent = black
+        .iter()
+        .map(|&(q, r)| q.unsigned_abs().max(r.unsigned_abs()))
+        .max()
+        .unwrap_or(0) as usize;
+    let radius = extent + days + 1;
+    let size = 2 * radius + 1;
+    ensure!(size <= 4096, "the tiles are spread out too far");
+
+    let mut board = Board::new(size, size, Edges::Bounded);
+    for &(q, r) in black {
+        board.set(
+            (q + radius as i32) as usize,
+            (r + radius as i32) as usize,
+            true,
+        );
+    }
+
+    let neighborhood = Neighborhood::Custom(NEIGHBOURS.to_vec());
+    let rule = Rule::new([2], [1, 2]);
+    for _ in 0..days {
+        board.step(&neighborhood, &rule);
+    }
+
+    Ok(board.population())
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    assert_eq!(follow("esew").unwrap(), (0, 1));
+    assert_eq!(follow("nwwswee").unwrap(), (0, 0));
+
+    let black = black_tiles(
+        "sesenwnenenewseeswwswswwnenewsewsw
+neeenesenwnwwswnenewnwwsewnenwseswesw
+seswneswswsenwwnwse
+nwnwneseeswswnenewneswwnewseswneseene
+swweswneswnenwsewnwneneseenw
+eesenwseswswnenwswnwnwsewwnwsene
+sewnenenenesenwsewnenwwwse
+wenwwweseeeweswwwnwwe
+wsweesenenewnwwnwsenewsenwwsesesenwne
+neeswseenwwswnwswswnw
+nenwswwsewswnenenewsenwsenwnesesenew
+enewnwewneswsewnwswenweswnenwsenwsw
+sweneswneswneneenwnewenewwneswswnese
+swwesenesewenwneswnwwneseswwne
+enesenwswwswneneswsenwnewswseenwsese
+wnwnesenesenenwwnenwsewesewsesesew
+nenewswnwewswnenesenwnesewesw
+eneswnwswnwsenenwnwnwwseeswneewsenese
+neswnwewnwnwseenwseesewsenwsweewe
+wseweeenwnesenwwwswnew",
+    )
+    .unwrap();
+
+    assert_eq!(black.len(), 10);
+    assert_eq!(exhibit(&black, 1).unwrap(), 15);
+    assert_eq!(exhibit(&black, 10).unwrap(), 37);
+    assert_eq!(exhibit(&black, 100).unwrap(), 2208);
+}
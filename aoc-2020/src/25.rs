@@ -0,0 +1,43 @@
+use eyre::{OptionExt, Result};
+
+use aoc_common::math;
+use aoc_meta::Problem;
+
+pub const COMBO_BREAKER: Problem = Problem::partially_solved(&|input| {
+    let (card, door) = input
+        .split_once('\n')
+        .ok_or_eyre("expected the card's and the door's public keys")?;
+    encryption_key(card.trim().parse()?, door.trim().parse()?)
+});
+
+const MODULUS: u64 = 20201227;
+const SUBJECT: u64 = 7;
+
+/// The loop size that transforms the subject number 7 into `public_key`.
+fn loop_size(public_key: u64) -> Option<u64> {
+    let mut value = 1;
+
+    for loop_size in 0..MODULUS {
+        if value == public_key {
+            return Some(loop_size);
+        }
+        value = value * SUBJECT % MODULUS;
+    }
+
+    None
+}
+
+/// The key the card and door agree on: either public key transformed by the other device's loop size.
+fn encryption_key(card: u64, door: u64) -> Result<u64> {
+    let card_loop_size = loop_size(card).ok_or_eyre("the card's public key isn't a power of 7")?;
+    Ok(math::mod_pow(door, card_loop_size, MODULUS))
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    assert_eq!(loop_size(5764801), Some(8));
+    assert_eq!(loop_size(17807724), Some(11));
+    assert_eq!(encryption_key(5764801, 17807724).unwrap(), 14897079);
+}
@@ -1,3 +1,29 @@
 use aoc_meta::PROBLEMS;
 
-PROBLEMS! {}
+PROBLEMS! {
+    01 => REPORT_REPAIR,
+    02 => PASSWORD_PHILOSOPHY,
+    03 => TOBOGGAN_TRAJECTORY,
+    04 => PASSPORT_PROCESSING,
+    05 => BINARY_BOARDING,
+    06 => CUSTOM_CUSTOMS,
+    07 => HANDY_HAVERSACKS,
+    08 => HANDHELD_HALTING,
+    09 => ENCODING_ERROR,
+    10 => ADAPTER_ARRAY,
+    11 => SEATING_SYSTEM,
+    12 => RAIN_RISK,
+    13 => SHUTTLE_SEARCH,
+    14 => DOCKING_DATA,
+    15 => RAMBUNCTIOUS_RECITATION,
+    16 => TICKET_TRANSLATION,
+    17 => CONWAY_CUBES,
+    18 => OPERATION_ORDER,
+    19 => MONSTER_MESSAGES,
+    20 => JURASSIC_JIGSAW,
+    21 => ALLERGEN_ASSESSMENT,
+    22 => CRAB_COMBAT,
+    23 => CRAB_CUPS,
+    24 => LOBBY_LAYOUT,
+    25 => COMBO_BREAKER,
+}
@@ -0,0 +1,31 @@
+use eyre::Result;
+
+use aoc_meta::Problem;
+
+pub const SONAR_SWEEP: Problem = Problem::solved(
+    &|input| parse_depths(input).map(|depths| increases(&depths, 1)),
+    &|input| parse_depths(input).map(|depths| increases(&depths, 3)),
+);
+
+fn parse_depths(input: &str) -> Result<Vec<u32>> {
+    Ok(input.lines().map(str::parse).collect::<Result<_, _>>()?)
+}
+
+/// How many sums of `window` consecutive depths are bigger than the one before. Neighbouring windows share all but
+/// their first and last depths, so only those need comparing.
+fn increases(depths: &[u32], window: usize) -> usize {
+    depths
+        .iter()
+        .zip(depths.iter().skip(window))
+        .filter(|(a, b)| b > a)
+        .count()
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let depths = [199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+    assert_eq!(increases(&depths, 1), 7);
+    assert_eq!(increases(&depths, 3), 5);
+}
@@ -0,0 +1,48 @@
+use eyre::{OptionExt, Result, bail};
+
+use aoc_meta::Problem;
+
+pub const DIVE: Problem = Problem::solved(
+    &|input| {
+        let (position, depth) = parse_commands(input)?
+            .into_iter()
+            .fold((0, 0), |(position, depth), (dx, dy)| {
+                (position + dx, depth + dy)
+            });
+        Ok::<_, eyre::Report>(position * depth)
+    },
+    &|input| {
+        let (position, depth, _) = parse_commands(input)?
+            .into_iter()
+            .fold((0, 0, 0), |(position, depth, aim), (dx, dy)| {
+                (position + dx, depth + aim * dx, aim + dy)
+            });
+        Ok::<_, eyre::Report>(position * depth)
+    },
+);
+
+/// Each command as how far it moves forward and how far down.
+fn parse_commands(input: &str) -> Result<Vec<(i64, i64)>> {
+    input
+        .lines()
+        .map(|line| {
+            let (direction, units) = line.split_once(' ').ok_or_eyre("malformed command")?;
+            let units: i64 = units.parse()?;
+
+            Ok(match direction {
+                "forward" => (units, 0),
+                "down" => (0, units),
+                "up" => (0, -units),
+                _ => bail!("unknown direction {direction:?}"),
+            })
+        })
+        .collect()
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let commands = parse_commands("forward 5\ndown 5\nforward 8\nup 3\ndown 8\nforward 2").unwrap();
+    assert_eq!(commands, [(5, 0), (0, 5), (8, 0), (0, -3), (0, 8), (2, 0)]);
+}
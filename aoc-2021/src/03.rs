@@ -0,0 +1,86 @@
+use eyre::{OptionExt, Result, ensure};
+
+use aoc_meta::Problem;
+
+pub const BINARY_DIAGNOSTIC: Problem = Problem::solved(
+    &|input| Report::parse(input).map(|report| report.power_consumption()),
+    &|input| Report::parse(input)?.life_support_rating(),
+);
+
+#[derive(Debug)]
+struct Report {
+    numbers: Vec<u32>,
+    width: usize,
+}
+
+impl Report {
+    fn power_consumption(&self) -> u32 {
+        let gamma = (0..self.width)
+            .filter(|&bit| 2 * count_ones(&self.numbers, bit) > self.numbers.len())
+            .fold(0, |gamma, bit| gamma | 1 << bit);
+        let epsilon = !gamma & ((1 << self.width) - 1);
+
+        gamma * epsilon
+    }
+
+    fn life_support_rating(&self) -> Result<u32> {
+        Ok(self.rating(true)? * self.rating(false)?)
+    }
+
+    /// Filter the numbers bit by bit from the left, keeping those with the most common value in that position
+    /// (preferring ones), or the least common (preferring zeros) if `most_common` is false, until one is left.
+    fn rating(&self, most_common: bool) -> Result<u32> {
+        let mut numbers = self.numbers.clone();
+
+        for bit in (0..self.width).rev() {
+            if numbers.len() == 1 {
+                break;
+            }
+
+            let ones = count_ones(&numbers, bit);
+            let keep_ones = (2 * ones >= numbers.len()) == most_common;
+            numbers.retain(|number| (number >> bit & 1 == 1) == keep_ones);
+        }
+
+        numbers
+            .first()
+            .copied()
+            .ok_or_eyre("every number was filtered out")
+    }
+
+    fn parse(input: &str) -> Result<Self> {
+        let width = input.lines().next().map_or(0, str::len);
+        ensure!(
+            (1..32).contains(&width),
+            "expected binary numbers up to 31 bits long"
+        );
+
+        let numbers = input
+            .lines()
+            .map(|line| u32::from_str_radix(line, 2))
+            .collect::<Result<_, _>>()?;
+        Ok(Self { numbers, width })
+    }
+}
+
+fn count_ones(numbers: &[u32], bit: usize) -> usize {
+    numbers
+        .iter()
+        .filter(|&&number| number >> bit & 1 == 1)
+        .count()
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let report = Report::parse(
+        "00100\n11110\n10110\n10111\n10101\n01111\n00111\n11100\n10000\n11001\n00010\n01010",
+    )
+    .unwrap();
+
+    assert_eq!(report.power_consumption(), 198);
+    assert_eq!(report.rating(true).unwrap(), 23);
+    assert_eq!(report.rating(false).unwrap(), 10);
+    assert_eq!(report.life_support_rating().unwrap(), 230);
+}
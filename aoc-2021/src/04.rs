@@ -0,0 +1,115 @@
+use eyre::{OptionExt, Result, ensure};
+
+use aoc_meta::Problem;
+
+pub const GIANT_SQUID: Problem = Problem::solved(
+    &|input| {
+        Bingo::parse(input)?
+            .winning_scores()
+            .into_iter()
+            .next()
+            .ok_or_eyre("no board ever wins")
+    },
+    &|input| {
+        Bingo::parse(input)?
+            .winning_scores()
+            .into_iter()
+            .next_back()
+            .ok_or_eyre("no board ever wins")
+    },
+);
+
+#[derive(Debug)]
+struct Bingo {
+    numbers: Vec<u32>,
+    boards: Vec<[u32; 25]>,
+}
+
+impl Bingo {
+    /// The final score of each board that wins, in the order they win.
+    fn winning_scores(&self) -> Vec<u32> {
+        let mut marked = vec![0u32; self.boards.len()];
+        let mut won = vec![false; self.boards.len()];
+        let mut scores = Vec::new();
+
+        for &number in &self.numbers {
+            for (i, board) in self.boards.iter().enumerate() {
+                if won[i] {
+                    continue;
+                }
+
+                if let Some(position) = board.iter().position(|&n| n == number) {
+                    marked[i] |= 1 << position;
+                }
+
+                let row = |r: usize| 0b11111 << (5 * r);
+                let column = |c: usize| 0b00001_00001_00001_00001_00001 << c;
+                if (0..5).any(|line| {
+                    marked[i] & row(line) == row(line) || marked[i] & column(line) == column(line)
+                }) {
+                    won[i] = true;
+                    let unmarked: u32 = (0..25)
+                        .filter(|&p| marked[i] >> p & 1 == 0)
+                        .map(|p| board[p])
+                        .sum();
+                    scores.push(unmarked * number);
+                }
+            }
+        }
+
+        scores
+    }
+
+    fn parse(input: &str) -> Result<Self> {
+        let mut sections = input.split("\n\n");
+        let numbers = sections
+            .next()
+            .ok_or_eyre("missing the numbers to draw")?
+            .split(',')
+            .map(str::parse)
+            .collect::<Result<_, _>>()?;
+
+        let boards = sections
+            .map(|board| {
+                let numbers: Vec<u32> = board
+                    .split_whitespace()
+                    .map(str::parse)
+                    .collect::<Result<_, _>>()?;
+                ensure!(numbers.len() == 25, "boards are 5×5");
+                Ok(std::array::from_fn(|i| numbers[i]))
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Self { numbers, boards })
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let bingo = Bingo::parse(
+        "7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
+
+22 13 17 11  0
+ 8  2 23  4 24
+21  9 14 16  7
+ 6 10  3 18  5
+ 1 12 20 15 19
+
+ 3 15  0  2 22
+ 9 18 13 17  5
+19  8  7 25 23
+20 11 10 24  4
+14 21 16 12  6
+
+14 21 17 24  4
+10 16 15  9 19
+18  8 23 26 20
+22 11 13  6  5
+ 2  0 12  3  7",
+    )
+    .unwrap();
+
+    assert_eq!(bingo.winning_scores(), [4512, 2192, 1924]);
+}
@@ -0,0 +1,64 @@
+use eyre::Result;
+use fnv::FnvHashMap;
+use winnow::combinator::separated_pair;
+use winnow::prelude::*;
+
+use aoc_common::parse::{self, signed};
+use aoc_meta::Problem;
+
+pub const HYDROTHERMAL_VENTURE: Problem = Problem::solved(
+    &|input| parse_lines(input).map(|lines| overlaps(&lines, false)),
+    &|input| parse_lines(input).map(|lines| overlaps(&lines, true)),
+);
+
+type Point = (i32, i32);
+
+/// How many points at least two lines of vents cover, counting diagonal lines if `diagonals`.
+fn overlaps(lines: &[(Point, Point)], diagonals: bool) -> usize {
+    let mut covered: FnvHashMap<Point, u32> = FnvHashMap::default();
+
+    for &((x1, y1), (x2, y2)) in lines {
+        let (dx, dy) = ((x2 - x1).signum(), (y2 - y1).signum());
+        if dx != 0 && dy != 0 && !diagonals {
+            continue;
+        }
+
+        let length = (x2 - x1).abs().max((y2 - y1).abs());
+        for step in 0..=length {
+            *covered.entry((x1 + dx * step, y1 + dy * step)).or_default() += 1;
+        }
+    }
+
+    covered.values().filter(|&&count| count >= 2).count()
+}
+
+fn line(input: &mut &str) -> ModalResult<(Point, Point)> {
+    let point = || separated_pair(signed, ',', signed);
+    separated_pair(point(), " -> ", point()).parse_next(input)
+}
+
+fn parse_lines(input: &str) -> Result<Vec<(Point, Point)>> {
+    parse::lines(input, line).collect()
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let lines = parse_lines(
+        "0,9 -> 5,9
+8,0 -> 0,8
+9,4 -> 3,4
+2,2 -> 2,1
+7,0 -> 7,4
+6,4 -> 2,0
+0,9 -> 2,9
+3,4 -> 1,4
+0,0 -> 8,8
+5,5 -> 8,2",
+    )
+    .unwrap();
+
+    assert_eq!(overlaps(&lines, false), 5);
+    assert_eq!(overlaps(&lines, true), 12);
+}
@@ -0,0 +1,41 @@
+use eyre::{Result, ensure};
+
+use aoc_meta::Problem;
+
+pub const LANTERNFISH: Problem = Problem::solved(
+    &|input| parse_school(input).map(|school| population_after(school, 80)),
+    &|input| parse_school(input).map(|school| population_after(school, 256)),
+);
+
+/// How many fish there are with each number of days left on their timers.
+fn parse_school(input: &str) -> Result<[u64; 9]> {
+    let mut school = [0; 9];
+
+    for timer in input.trim().split(',') {
+        let timer: usize = timer.parse()?;
+        ensure!(timer < 9, "timers don't go above 8");
+        school[timer] += 1;
+    }
+
+    Ok(school)
+}
+
+fn population_after(mut school: [u64; 9], days: usize) -> u64 {
+    for _ in 0..days {
+        school.rotate_left(1);
+        // The fish that just gave birth (now at 8, as their offspring) restart their own timers at 6.
+        school[6] += school[8];
+    }
+
+    school.iter().sum()
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let school = parse_school("3,4,3,1,2").unwrap();
+    assert_eq!(population_after(school, 18), 26);
+    assert_eq!(population_after(school, 80), 5934);
+    assert_eq!(population_after(school, 256), 26984457539);
+}
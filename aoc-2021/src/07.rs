@@ -0,0 +1,61 @@
+use eyre::{OptionExt, Result};
+
+use aoc_meta::Problem;
+
+pub const THE_TREACHERY_OF_WHALES: Problem = Problem::solved(
+    &|input| cheapest_alignment(&parse_positions(input)?, |distance| distance),
+    &|input| {
+        cheapest_alignment(&parse_positions(input)?, |distance| {
+            distance * (distance + 1) / 2
+        })
+    },
+);
+
+fn parse_positions(input: &str) -> Result<Vec<u64>> {
+    Ok(input
+        .trim()
+        .split(',')
+        .map(str::parse)
+        .collect::<Result<_, _>>()?)
+}
+
+/// The least fuel it takes to line every crab up at the same position, where moving a crab `distance` steps
+/// takes `cost(distance)` fuel. The total cost is convex in the position, so a binary search finds the cheapest.
+fn cheapest_alignment(positions: &[u64], cost: fn(u64) -> u64) -> Result<u64> {
+    let total = |target: u64| {
+        positions
+            .iter()
+            .map(|&position| cost(position.abs_diff(target)))
+            .sum::<u64>()
+    };
+
+    let (mut low, mut high) = (
+        *positions.iter().min().ok_or_eyre("there are no crabs")?,
+        *positions.iter().max().ok_or_eyre("there are no crabs")?,
+    );
+    while low < high {
+        let middle = low + (high - low) / 2;
+        if total(middle) <= total(middle + 1) {
+            high = middle;
+        } else {
+            low = middle + 1;
+        }
+    }
+
+    Ok(total(low))
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let positions = parse_positions("16,1,2,0,4,2,7,1,2,14").unwrap();
+    assert_eq!(
+        cheapest_alignment(&positions, |distance| distance).unwrap(),
+        37
+    );
+    assert_eq!(
+        cheapest_alignment(&positions, |distance| distance * (distance + 1) / 2).unwrap(),
+        168
+    );
+}
@@ -0,0 +1,93 @@
+use eyre::{OptionExt, Result, eyre};
+
+use aoc_meta::Problem;
+
+pub const SEVEN_SEGMENT_SEARCH: Problem = Problem::solved(
+    &|input| {
+        let displays = parse_displays(input)?;
+        Ok::<_, eyre::Report>(
+            displays
+                .iter()
+                .flat_map(|display| &display.output)
+                .filter(|digit| matches!(digit.count_ones(), 2 | 3 | 4 | 7))
+                .count(),
+        )
+    },
+    &|input| {
+        parse_displays(input)?
+            .iter()
+            .map(Display::decode)
+            .sum::<Result<u32>>()
+    },
+);
+
+/// Each digit's score: the sum over its segments of how many of the ten digits use that segment. These happen to
+/// be unique, and they don't depend on how the wires are crossed.
+const SCORES: [u32; 10] = [42, 17, 34, 39, 30, 37, 41, 25, 49, 45];
+
+#[derive(Debug)]
+struct Display {
+    /// The ten unique signal patterns, each a bitmask of the wires `a` to `g` it lights.
+    patterns: [u8; 10],
+    output: Vec<u8>,
+}
+
+impl Display {
+    fn decode(&self) -> Result<u32> {
+        let frequency = |wire: u32| {
+            self.patterns
+                .iter()
+                .filter(|&&pattern| pattern >> wire & 1 == 1)
+                .count() as u32
+        };
+
+        self.output.iter().try_fold(0, |value, &digit| {
+            let score: u32 = (0..7)
+                .filter(|&wire| digit >> wire & 1 == 1)
+                .map(frequency)
+                .sum();
+            let digit = SCORES
+                .iter()
+                .position(|&s| s == score)
+                .ok_or_eyre("the wiring can't be worked out")?;
+            Ok(value * 10 + digit as u32)
+        })
+    }
+}
+
+fn parse_displays(input: &str) -> Result<Vec<Display>> {
+    let pattern = |wires: &str| {
+        wires
+            .bytes()
+            .fold(0, |pattern, wire| pattern | 1 << (wire - b'a'))
+    };
+
+    input
+        .lines()
+        .map(|line| {
+            let (patterns, output) = line
+                .split_once(" | ")
+                .ok_or_eyre("missing the output digits")?;
+            let patterns: Vec<u8> = patterns.split(' ').map(pattern).collect();
+
+            Ok(Display {
+                patterns: patterns
+                    .try_into()
+                    .map_err(|_| eyre!("expected ten signal patterns"))?,
+                output: output.split(' ').map(pattern).collect(),
+            })
+        })
+        .collect()
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let displays = parse_displays(
+        "acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab | cdfeb fcadb cdfeb cdbaf",
+    )
+    .unwrap();
+
+    assert_eq!(displays[0].decode().unwrap(), 5353);
+}
@@ -0,0 +1,99 @@
+use eyre::Result;
+
+use aoc_common::parse;
+use aoc_meta::Problem;
+
+pub const SMOKE_BASIN: Problem = Problem::solved(
+    &|input| {
+        let map = Heightmap::parse(input)?;
+        Ok::<_, eyre::Report>(
+            map.low_points()
+                .map(|point| map.heights[point] + 1)
+                .sum::<u32>(),
+        )
+    },
+    &|input| {
+        let mut sizes = Heightmap::parse(input)?.basin_sizes();
+        sizes.sort_unstable_by(|a, b| b.cmp(a));
+        Ok::<_, eyre::Report>(sizes.iter().take(3).product::<usize>())
+    },
+);
+
+#[derive(Debug)]
+struct Heightmap {
+    width: usize,
+    heights: Vec<u32>,
+}
+
+impl Heightmap {
+    fn neighbours(&self, point: usize) -> impl Iterator<Item = usize> + use<> {
+        let (width, len) = (self.width, self.heights.len());
+        let (x, y) = (point % width, point / width);
+
+        [
+            (x > 0).then(|| point - 1),
+            (x + 1 < width).then(|| point + 1),
+            (y > 0).then(|| point - width),
+            (point + width < len).then(|| point + width),
+        ]
+        .into_iter()
+        .flatten()
+    }
+
+    fn low_points(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.heights.len()).filter(|&point| {
+            self.neighbours(point)
+                .all(|other| self.heights[other] > self.heights[point])
+        })
+    }
+
+    /// The size of every basin: the areas bounded by height 9 locations, each of which flows down to one low point.
+    fn basin_sizes(&self) -> Vec<usize> {
+        let mut seen: Vec<bool> = self.heights.iter().map(|&height| height == 9).collect();
+
+        self.low_points()
+            .map(|low_point| {
+                let mut stack = vec![low_point];
+                seen[low_point] = true;
+                let mut size = 0;
+
+                while let Some(point) = stack.pop() {
+                    size += 1;
+                    for other in self.neighbours(point) {
+                        if !seen[other] {
+                            seen[other] = true;
+                            stack.push(other);
+                        }
+                    }
+                }
+
+                size
+            })
+            .collect()
+    }
+
+    fn parse(input: &str) -> Result<Self> {
+        let rows = parse::complete(input.trim_end(), parse::grid(|c| c.to_digit(10)))?;
+
+        Ok(Self {
+            width: rows[0].len(),
+            heights: rows.into_iter().flatten().collect(),
+        })
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let map =
+        Heightmap::parse("2199943210\n3987894921\n9856789892\n8767896789\n9899965678").unwrap();
+
+    assert_eq!(
+        map.low_points()
+            .map(|point| map.heights[point] + 1)
+            .sum::<u32>(),
+        15
+    );
+    assert_eq!(map.basin_sizes(), [3, 9, 14, 9]);
+}
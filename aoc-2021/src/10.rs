@@ -0,0 +1,104 @@
+use eyre::{Result, bail};
+
+use aoc_meta::Problem;
+
+pub const SYNTAX_SCORING: Problem = Problem::solved(
+    &|input| {
+        input.lines().map(check).try_fold(0, |total, line| {
+            Ok::<_, eyre::Report>(match line? {
+                Line::Corrupted(score) => total + score,
+                Line::Incomplete(_) => total,
+            })
+        })
+    },
+    &|input| {
+        let mut scores = Vec::new();
+        for line in input.lines() {
+            if let Line::Incomplete(score) = check(line)? {
+                scores.push(score);
+            }
+        }
+
+        scores.sort_unstable();
+        Ok::<_, eyre::Report>(scores.get(scores.len() / 2).copied().unwrap_or_default())
+    },
+);
+
+#[derive(Debug, PartialEq, Eq)]
+enum Line {
+    /// The syntax error score of the first illegal closing character.
+    Corrupted(u64),
+    /// The score of the closing characters that would complete the line.
+    Incomplete(u64),
+}
+
+fn check(line: &str) -> Result<Line> {
+    let mut expected = Vec::new();
+
+    for c in line.chars() {
+        match c {
+            '(' => expected.push(')'),
+            '[' => expected.push(']'),
+            '{' => expected.push('}'),
+            '<' => expected.push('>'),
+            ')' | ']' | '}' | '>' if expected.pop() != Some(c) => {
+                return Ok(Line::Corrupted(match c {
+                    ')' => 3,
+                    ']' => 57,
+                    '}' => 1197,
+                    _ => 25137,
+                }));
+            }
+            ')' | ']' | '}' | '>' => {}
+            _ => bail!("unexpected character {c:?}"),
+        }
+    }
+
+    let score = expected.iter().rev().fold(0, |score, c| {
+        score * 5
+            + match c {
+                ')' => 1,
+                ']' => 2,
+                '}' => 3,
+                _ => 4,
+            }
+    });
+    Ok(Line::Incomplete(score))
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let lines: Vec<Line> = [
+        "[({(<(())[]>[[{[]{<()<>>",
+        "[(()[<>])]({[<{<<[]>>(",
+        "{([(<{}[<>[]}>{[]{[(<()>",
+        "(((({<>}<{<{<>}{[]{[]{}",
+        "[[<[([]))<([[{}[[()]]]",
+        "[{[{({}]{}}([{[{{{}}([]",
+        "{<[[]]>}<{[{[{[]{()[[[]",
+        "[<(<(<(<{}))><([]([]()",
+        "<{([([[(<>()){}]>(<<{{",
+        "<{([{{}}[<[[[<>{}]]]>[]]",
+    ]
+    .into_iter()
+    .map(|line| check(line).unwrap())
+    .collect();
+
+    assert_eq!(
+        lines,
+        [
+            Line::Incomplete(288957),
+            Line::Incomplete(5566),
+            Line::Corrupted(1197),
+            Line::Incomplete(1480781),
+            Line::Corrupted(3),
+            Line::Corrupted(57),
+            Line::Incomplete(995444),
+            Line::Corrupted(3),
+            Line::Corrupted(25137),
+            Line::Incomplete(294),
+        ]
+    );
+}
@@ -0,0 +1,100 @@
+use eyre::{Result, ensure};
+
+use aoc_common::parse;
+use aoc_meta::Problem;
+
+pub const DUMBO_OCTOPUS: Problem = Problem::solved(
+    &|input| {
+        let mut cavern = Cavern::parse(input)?;
+        Ok::<_, eyre::Report>((0..100).map(|_| cavern.step()).sum::<usize>())
+    },
+    &|input| {
+        let mut cavern = Cavern::parse(input)?;
+        Ok::<_, eyre::Report>(
+            (1..)
+                .find(|_| cavern.step() == SIZE * SIZE)
+                .unwrap_or_default(),
+        )
+    },
+);
+
+const SIZE: usize = 10;
+
+#[derive(Debug)]
+struct Cavern([[u8; SIZE]; SIZE]);
+
+impl Cavern {
+    /// Advance one step, returning how many octopuses flashed.
+    fn step(&mut self) -> usize {
+        let mut flashing = Vec::new();
+
+        for y in 0..SIZE {
+            for x in 0..SIZE {
+                self.0[y][x] += 1;
+                if self.0[y][x] == 10 {
+                    flashing.push((x, y));
+                }
+            }
+        }
+
+        let mut flashes = 0;
+        while let Some((x, y)) = flashing.pop() {
+            flashes += 1;
+
+            for ny in y.saturating_sub(1)..=(y + 1).min(SIZE - 1) {
+                for nx in x.saturating_sub(1)..=(x + 1).min(SIZE - 1) {
+                    self.0[ny][nx] += 1;
+                    if self.0[ny][nx] == 10 {
+                        flashing.push((nx, ny));
+                    }
+                }
+            }
+        }
+
+        for energy in self.0.iter_mut().flatten() {
+            if *energy > 9 {
+                *energy = 0;
+            }
+        }
+
+        flashes
+    }
+
+    fn parse(input: &str) -> Result<Self> {
+        let rows = parse::complete(
+            input.trim_end(),
+            parse::grid(|c| c.to_digit(10).map(|d| d as u8)),
+        )?;
+        ensure!(
+            rows.len() == SIZE && rows[0].len() == SIZE,
+            "the cavern is {SIZE}×{SIZE}"
+        );
+
+        Ok(Self(std::array::from_fn(|y| {
+            std::array::from_fn(|x| rows[y][x])
+        })))
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let example = "5483143223
+2745854711
+5264556173
+6141336146
+6357385478
+4167524645
+2176841721
+6882881134
+4846848554
+5283751526";
+
+    let mut cavern = Cavern::parse(example).unwrap();
+    assert_eq!((0..10).map(|_| cavern.step()).sum::<usize>(), 204);
+    assert_eq!((10..100).map(|_| cavern.step()).sum::<usize>(), 1656 - 204);
+
+    let mut cavern = Cavern::parse(example).unwrap();
+    assert_eq!((1..).find(|_| cavern.step() == SIZE * SIZE), Some(195));
+}
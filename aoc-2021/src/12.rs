@@ -0,0 +1,105 @@
+use eyre::{OptionExt, Result, ensure};
+use fnv::FnvHashMap;
+
+use aoc_meta::Problem;
+
+pub const PASSAGE_PATHING: Problem = Problem::solved(
+    &|input| Caves::parse(input).map(|caves| caves.paths(false)),
+    &|input| Caves::parse(input).map(|caves| caves.paths(true)),
+);
+
+#[derive(Debug)]
+struct Caves {
+    /// The caves connected to each one, by index.
+    connections: Vec<Vec<usize>>,
+    small: Vec<bool>,
+    start: usize,
+    end: usize,
+}
+
+impl Caves {
+    /// How many paths lead from the start to the end, visiting small caves at most once, except that one of them
+    /// can be visited twice if `revisit` is allowed.
+    fn paths(&self, revisit: bool) -> usize {
+        self.paths_from(self.start, 1 << self.start, revisit)
+    }
+
+    fn paths_from(&self, cave: usize, visited: u64, revisit: bool) -> usize {
+        if cave == self.end {
+            return 1;
+        }
+
+        self.connections[cave]
+            .iter()
+            .map(|&next| {
+                if !self.small[next] {
+                    self.paths_from(next, visited, revisit)
+                } else if visited >> next & 1 == 0 {
+                    self.paths_from(next, visited | 1 << next, revisit)
+                } else if revisit && next != self.start {
+                    self.paths_from(next, visited, false)
+                } else {
+                    0
+                }
+            })
+            .sum()
+    }
+
+    fn parse(input: &str) -> Result<Self> {
+        let mut indices: FnvHashMap<&str, usize> = FnvHashMap::default();
+        let mut caves = Self {
+            connections: Vec::new(),
+            small: Vec::new(),
+            start: 0,
+            end: 0,
+        };
+
+        for line in input.lines() {
+            let (a, b) = line.split_once('-').ok_or_eyre("malformed connection")?;
+
+            let [a, b] = [a, b].map(|name| {
+                *indices.entry(name).or_insert_with(|| {
+                    caves.connections.push(Vec::new());
+                    caves
+                        .small
+                        .push(name.chars().all(|c| c.is_ascii_lowercase()));
+                    caves.small.len() - 1
+                })
+            });
+
+            caves.connections[a].push(b);
+            caves.connections[b].push(a);
+        }
+
+        ensure!(caves.small.len() <= 64, "there are too many caves");
+        ensure!(
+            caves
+                .connections
+                .iter()
+                .enumerate()
+                .all(|(a, connections)| caves.small[a]
+                    || connections.iter().all(|&b| caves.small[b])),
+            "two big caves are connected, so there are infinitely many paths"
+        );
+
+        caves.start = *indices.get("start").ok_or_eyre("missing the start cave")?;
+        caves.end = *indices.get("end").ok_or_eyre("missing the end cave")?;
+        Ok(caves)
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let caves = Caves::parse("start-A\nstart-b\nA-c\nA-b\nb-d\nA-end\nb-end").unwrap();
+    assert_eq!(caves.paths(false), 10);
+    assert_eq!(caves.paths(true), 36);
+
+    let caves = Caves::parse(
+        "dc-end\nHN-start\nstart-kj\ndc-start\ndc-HN\nLN-dc\nHN-end\nkj-sa\nkj-HN\nkj-dc",
+    )
+    .unwrap();
+    assert_eq!(caves.paths(false), 19);
+    assert_eq!(caves.paths(true), 103);
+}
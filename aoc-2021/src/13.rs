@@ -0,0 +1,110 @@
+use eyre::{OptionExt, Result, bail};
+use fnv::FnvHashSet;
+
+use aoc_common::ocr;
+use aoc_meta::Problem;
+
+pub const TRANSPARENT_ORIGAMI: Problem = Problem::solved(
+    &|input| {
+        let (dots, folds) = parse_manual(input)?;
+        let first = folds.first().ok_or_eyre("there are no folds")?;
+        Ok::<_, eyre::Report>(fold(&dots, *first).len())
+    },
+    &|input| {
+        let (dots, folds) = parse_manual(input)?;
+        ocr::read_grid(draw(
+            &folds.into_iter().fold(dots, |dots, line| fold(&dots, line)),
+        ))
+    },
+);
+
+type Dot = (u32, u32);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Fold {
+    Left(u32),
+    Up(u32),
+}
+
+fn fold(dots: &FnvHashSet<Dot>, line: Fold) -> FnvHashSet<Dot> {
+    let reflect = |coordinate: u32, line: u32| {
+        if coordinate > line {
+            2 * line - coordinate
+        } else {
+            coordinate
+        }
+    };
+
+    dots.iter()
+        .map(|&(x, y)| match line {
+            Fold::Left(line) => (reflect(x, line), y),
+            Fold::Up(line) => (x, reflect(y, line)),
+        })
+        .collect()
+}
+
+fn draw(dots: &FnvHashSet<Dot>) -> Vec<Vec<bool>> {
+    let width = dots.iter().map(|&(x, _)| x + 1).max().unwrap_or_default();
+    let height = dots.iter().map(|&(_, y)| y + 1).max().unwrap_or_default();
+
+    (0..height)
+        .map(|y| (0..width).map(|x| dots.contains(&(x, y))).collect())
+        .collect()
+}
+
+fn parse_manual(input: &str) -> Result<(FnvHashSet<Dot>, Vec<Fold>)> {
+    let (dots, folds) = input
+        .split_once("\n\n")
+        .ok_or_eyre("missing the fold instructions")?;
+
+    let dots = dots
+        .lines()
+        .map(|line| {
+            let (x, y) = line.split_once(',').ok_or_eyre("malformed dot")?;
+            Ok((x.parse()?, y.parse()?))
+        })
+        .collect::<Result<_>>()?;
+
+    let folds = folds
+        .lines()
+        .map(|line| {
+            let (axis, position) = line
+                .strip_prefix("fold along ")
+                .and_then(|fold| fold.split_once('='))
+                .ok_or_eyre("malformed fold")?;
+
+            Ok(match axis {
+                "x" => Fold::Left(position.parse()?),
+                "y" => Fold::Up(position.parse()?),
+                _ => bail!("unknown axis {axis:?}"),
+            })
+        })
+        .collect::<Result<_>>()?;
+
+    Ok((dots, folds))
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let (dots, folds) = parse_manual(
+        "6,10\n0,14\n9,10\n0,3\n10,4\n4,11\n6,0\n6,12\n4,1\n0,13\n10,12\n3,4\n3,0\n8,4\n1,10\n2,14\n8,10\n9,0
+
+fold along y=7
+fold along x=5",
+    )
+    .unwrap();
+
+    assert_eq!(folds, [Fold::Up(7), Fold::Left(5)]);
+
+    let once = fold(&dots, folds[0]);
+    assert_eq!(once.len(), 17);
+
+    let square = draw(&fold(&once, folds[1]));
+    let square: Vec<String> = square
+        .iter()
+        .map(|row| row.iter().map(|&dot| if dot { '#' } else { '.' }).collect())
+        .collect();
+    assert_eq!(square, ["#####", "#...#", "#...#", "#...#", "#####"]);
+}
@@ -0,0 +1,108 @@
+use eyre::{OptionExt, Result, ensure};
+use fnv::FnvHashMap;
+
+use aoc_meta::Problem;
+
+pub const EXTENDED_POLYMERIZATION: Problem = Problem::solved(
+    &|input| Polymer::parse(input).map(|polymer| polymer.spread_after(10)),
+    &|input| Polymer::parse(input).map(|polymer| polymer.spread_after(40)),
+);
+
+#[derive(Debug)]
+struct Polymer {
+    template: Vec<u8>,
+    rules: FnvHashMap<[u8; 2], u8>,
+}
+
+impl Polymer {
+    /// The difference between the quantities of the most and least common elements after `steps` steps of pair
+    /// insertion. Only the number of each pair of adjacent elements matters, not where they are.
+    fn spread_after(&self, steps: usize) -> u64 {
+        let mut pairs: FnvHashMap<[u8; 2], u64> = FnvHashMap::default();
+        for pair in self.template.windows(2) {
+            *pairs.entry([pair[0], pair[1]]).or_default() += 1;
+        }
+
+        for _ in 0..steps {
+            let mut next = FnvHashMap::default();
+            for (pair, count) in pairs {
+                match self.rules.get(&pair) {
+                    Some(&inserted) => {
+                        *next.entry([pair[0], inserted]).or_default() += count;
+                        *next.entry([inserted, pair[1]]).or_default() += count;
+                    }
+                    None => *next.entry(pair).or_default() += count,
+                }
+            }
+            pairs = next;
+        }
+
+        // Every element is the first of a pair, except the last one, which never changes.
+        let mut elements: FnvHashMap<u8, u64> = FnvHashMap::default();
+        for ([first, _], count) in pairs {
+            *elements.entry(first).or_default() += count;
+        }
+        if let Some(&last) = self.template.last() {
+            *elements.entry(last).or_default() += 1;
+        }
+
+        elements.values().max().unwrap_or(&0) - elements.values().min().unwrap_or(&0)
+    }
+
+    fn parse(input: &str) -> Result<Self> {
+        let (template, rules) = input
+            .split_once("\n\n")
+            .ok_or_eyre("missing the insertion rules")?;
+
+        let rules = rules
+            .lines()
+            .map(|line| {
+                let (pair, inserted) = line
+                    .split_once(" -> ")
+                    .ok_or_eyre("malformed insertion rule")?;
+                ensure!(
+                    pair.len() == 2 && inserted.len() == 1,
+                    "malformed insertion rule"
+                );
+
+                let pair = pair.as_bytes();
+                Ok(([pair[0], pair[1]], inserted.as_bytes()[0]))
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Self {
+            template: template.as_bytes().to_vec(),
+            rules,
+        })
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let polymer = Polymer::parse(
+        "NNCB
+
+CH -> B
+HH -> N
+CB -> H
+NH -> C
+HB -> C
+HC -> B
+HN -> C
+NN -> C
+BH -> H
+NC -> B
+NB -> B
+BN -> B
+BB -> N
+BC -> B
+CC -> N
+CN -> C",
+    )
+    .unwrap();
+
+    assert_eq!(polymer.spread_after(10), 1588);
+    assert_eq!(polymer.spread_after(40), 2188189693529);
+}
@@ -0,0 +1,103 @@
+use eyre::{OptionExt, Result};
+
+use aoc_common::parse;
+use aoc_common::search::{SearchSpace, astar};
+use aoc_meta::Problem;
+
+pub const CHITON: Problem = Problem::solved(
+    &|input| {
+        Cavern::parse(input, 1)?
+            .lowest_risk()
+            .ok_or_eyre("there's no way through the cavern")
+    },
+    &|input| {
+        Cavern::parse(input, 5)?
+            .lowest_risk()
+            .ok_or_eyre("there's no way through the cavern")
+    },
+);
+
+#[derive(Debug)]
+struct Cavern {
+    tile: Vec<Vec<u32>>,
+    /// How many copies of the tile the full map has along each side.
+    repeats: usize,
+}
+
+impl Cavern {
+    fn size(&self) -> (usize, usize) {
+        (
+            self.tile[0].len() * self.repeats,
+            self.tile.len() * self.repeats,
+        )
+    }
+
+    /// The risk level of a position in the full map, where each copy of the tile is one riskier than the one to
+    /// its left or above it, wrapping back around from 9 to 1.
+    fn risk(&self, (x, y): (usize, usize)) -> u32 {
+        let (width, height) = (self.tile[0].len(), self.tile.len());
+        let increase = (x / width + y / height) as u32;
+        (self.tile[y % height][x % width] + increase - 1) % 9 + 1
+    }
+
+    fn lowest_risk(&self) -> Option<u32> {
+        astar(self, (0, 0)).map(|path| path.cost)
+    }
+
+    fn parse(input: &str, repeats: usize) -> Result<Self> {
+        let tile = parse::complete(input.trim_end(), parse::grid(|c| c.to_digit(10)))?;
+        Ok(Self { tile, repeats })
+    }
+}
+
+impl SearchSpace for Cavern {
+    type State = (usize, usize);
+    type Cost = u32;
+
+    fn successors(&self, &(x, y): &Self::State) -> impl IntoIterator<Item = (Self::State, u32)> {
+        let (width, height) = self.size();
+
+        [
+            (x.wrapping_sub(1), y),
+            (x + 1, y),
+            (x, y.wrapping_sub(1)),
+            (x, y + 1),
+        ]
+        .into_iter()
+        .filter(move |&(x, y)| x < width && y < height)
+        .map(|position| (position, self.risk(position)))
+    }
+
+    fn is_goal(&self, &(x, y): &Self::State) -> bool {
+        let (width, height) = self.size();
+        (x, y) == (width - 1, height - 1)
+    }
+
+    fn heuristic(&self, &(x, y): &Self::State) -> u32 {
+        let (width, height) = self.size();
+        (width - 1 - x + height - 1 - y) as u32
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let example = "1163751742
+1381373672
+2136511328
+3694931569
+7463417111
+1319128137
+1359912421
+3125421639
+1293138521
+2311944581";
+
+    assert_eq!(Cavern::parse(example, 1).unwrap().lowest_risk(), Some(40));
+
+    let cavern = Cavern::parse(example, 5).unwrap();
+    assert_eq!(cavern.risk((49, 49)), 9);
+    assert_eq!(cavern.risk((40, 0)), 5);
+    assert_eq!(cavern.lowest_risk(), Some(315));
+}
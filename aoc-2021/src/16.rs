@@ -0,0 +1,194 @@
+use eyre::{OptionExt, Result, bail, ensure};
+
+use aoc_meta::Problem;
+
+pub const PACKET_DECODER: Problem = Problem::solved(
+    &|input| Packet::decode(input).map(|packet| packet.version_sum()),
+    &|input| Packet::decode(input)?.evaluate(),
+);
+
+#[derive(Debug, PartialEq, Eq)]
+enum Packet {
+    Literal {
+        version: u64,
+        value: u64,
+    },
+    Operator {
+        version: u64,
+        type_id: u64,
+        packets: Vec<Packet>,
+    },
+}
+
+impl Packet {
+    fn version_sum(&self) -> u64 {
+        match self {
+            Packet::Literal { version, .. } => *version,
+            Packet::Operator {
+                version, packets, ..
+            } => version + packets.iter().map(Packet::version_sum).sum::<u64>(),
+        }
+    }
+
+    fn evaluate(&self) -> Result<u64> {
+        let (type_id, packets) = match self {
+            Packet::Literal { value, .. } => return Ok(*value),
+            Packet::Operator {
+                type_id, packets, ..
+            } => (*type_id, packets),
+        };
+
+        let values = packets
+            .iter()
+            .map(Packet::evaluate)
+            .collect::<Result<Vec<_>>>()?;
+        let compare = |comparison: fn(&u64, &u64) -> bool| match values[..] {
+            [a, b] => Ok(comparison(&a, &b) as u64),
+            _ => bail!("comparisons take exactly two packets"),
+        };
+
+        match type_id {
+            0 => Ok(values.iter().sum()),
+            1 => Ok(values.iter().product()),
+            2 => values
+                .iter()
+                .min()
+                .copied()
+                .ok_or_eyre("the minimum of no packets"),
+            3 => values
+                .iter()
+                .max()
+                .copied()
+                .ok_or_eyre("the maximum of no packets"),
+            5 => compare(u64::gt),
+            6 => compare(u64::lt),
+            7 => compare(u64::eq),
+            _ => bail!("unknown packet type {type_id}"),
+        }
+    }
+
+    fn decode(hex: &str) -> Result<Self> {
+        let bits = hex
+            .trim()
+            .chars()
+            .map(|c| c.to_digit(16).ok_or_eyre("expected hexadecimal"))
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .flat_map(|digit| (0..4).rev().map(move |bit| digit >> bit & 1 == 1))
+            .collect();
+
+        Bits { bits, position: 0 }.packet()
+    }
+}
+
+/// A cursor over the bits of a transmission.
+#[derive(Debug)]
+struct Bits {
+    bits: Vec<bool>,
+    position: usize,
+}
+
+impl Bits {
+    fn read(&mut self, len: usize) -> Result<u64> {
+        let bits = self
+            .bits
+            .get(self.position..self.position + len)
+            .ok_or_eyre("the transmission ended early")?;
+        self.position += len;
+        Ok(bits.iter().fold(0, |value, &bit| value << 1 | bit as u64))
+    }
+
+    fn packet(&mut self) -> Result<Packet> {
+        let version = self.read(3)?;
+        let type_id = self.read(3)?;
+
+        if type_id == 4 {
+            let mut value = 0;
+            loop {
+                let more = self.read(1)? == 1;
+                value = value << 4 | self.read(4)?;
+                if !more {
+                    return Ok(Packet::Literal { version, value });
+                }
+            }
+        }
+
+        let mut packets = Vec::new();
+        if self.read(1)? == 0 {
+            let len = self.read(15)? as usize;
+            let end = self.position + len;
+            while self.position < end {
+                packets.push(self.packet()?);
+            }
+            ensure!(self.position == end, "sub-packets overran their length");
+        } else {
+            for _ in 0..self.read(11)? {
+                packets.push(self.packet()?);
+            }
+        }
+
+        Ok(Packet::Operator {
+            version,
+            type_id,
+            packets,
+        })
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    assert_eq!(
+        Packet::decode("D2FE28").unwrap(),
+        Packet::Literal {
+            version: 6,
+            value: 2021
+        }
+    );
+    assert_eq!(
+        Packet::decode("38006F45291200").unwrap(),
+        Packet::Operator {
+            version: 1,
+            type_id: 6,
+            packets: vec![
+                Packet::Literal {
+                    version: 6,
+                    value: 10
+                },
+                Packet::Literal {
+                    version: 2,
+                    value: 20
+                }
+            ],
+        }
+    );
+
+    let version_sums = [
+        ("8A004A801A8002F478", 16),
+        ("620080001611562C8802118E34", 12),
+        ("C0015000016115A2E0802F182340", 23),
+        ("A0016C880162017C3686B18A3D4780", 31),
+    ];
+    for (hex, sum) in version_sums {
+        assert_eq!(Packet::decode(hex).unwrap().version_sum(), sum, "{hex}");
+    }
+
+    let values = [
+        ("C200B40A82", 3),
+        ("04005AC33890", 54),
+        ("880086C3E88112", 7),
+        ("CE00C43D881120", 9),
+        ("D8005AC2A8F0", 1),
+        ("F600BC2D8F", 0),
+        ("9C005AC2F8F0", 0),
+        ("9C0141080250320F1802104A08", 1),
+    ];
+    for (hex, value) in values {
+        assert_eq!(
+            Packet::decode(hex).unwrap().evaluate().unwrap(),
+            value,
+            "{hex}"
+        );
+    }
+}
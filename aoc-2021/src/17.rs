@@ -0,0 +1,80 @@
+use std::ops::RangeInclusive;
+
+use eyre::{Result, ensure};
+use winnow::combinator::{preceded, separated_pair};
+use winnow::prelude::*;
+
+use aoc_common::parse::{self, signed};
+use aoc_meta::Problem;
+
+pub const TRICK_SHOT: Problem = Problem::solved(
+    &|input| Target::parse(input).map(|target| target.highest_point()),
+    &|input| Target::parse(input).map(|target| target.hits()),
+);
+
+#[derive(Debug)]
+struct Target {
+    x: RangeInclusive<i32>,
+    y: RangeInclusive<i32>,
+}
+
+impl Target {
+    /// The highest the probe can go and still hit the target. Launched upwards at `vy`, it comes back down through
+    /// y = 0 going at `-(vy + 1)`, which mustn't overshoot the bottom of the target.
+    fn highest_point(&self) -> i32 {
+        let vy = -self.y.start() - 1;
+        vy * (vy + 1) / 2
+    }
+
+    /// How many initial velocities land the probe in the target at some step.
+    fn hits(&self) -> usize {
+        let vys = *self.y.start()..=-self.y.start() - 1;
+
+        (0..=*self.x.end())
+            .flat_map(|vx| vys.clone().map(move |vy| (vx, vy)))
+            .filter(|&velocity| self.hit(velocity))
+            .count()
+    }
+
+    fn hit(&self, (mut vx, mut vy): (i32, i32)) -> bool {
+        let (mut x, mut y) = (0, 0);
+
+        while x <= *self.x.end() && y >= *self.y.start() {
+            if self.x.contains(&x) && self.y.contains(&y) {
+                return true;
+            }
+
+            (x, y) = (x + vx, y + vy);
+            vx -= vx.signum();
+            vy -= 1;
+        }
+
+        false
+    }
+
+    fn parse(input: &str) -> Result<Self> {
+        let range = || separated_pair(signed, "..", signed).map(|(start, end)| start..=end);
+        let (x, y) = parse::complete(
+            input.trim(),
+            preceded("target area: x=", separated_pair(range(), ", y=", range())),
+        )?;
+
+        ensure!(
+            *x.start() >= 0 && *y.end() < 0,
+            "the target is expected to be ahead and below"
+        );
+        Ok(Self { x, y })
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let target = Target::parse("target area: x=20..30, y=-10..-5").unwrap();
+    assert_eq!(target.highest_point(), 45);
+    assert!(target.hit((7, 2)));
+    assert!(target.hit((6, 9)));
+    assert!(!target.hit((17, -4)));
+    assert_eq!(target.hits(), 112);
+}
@@ -0,0 +1,59 @@
+use eyre::Result;
+use itertools::Itertools;
+
+use aoc_meta::Problem;
+
+use crate::snailfish::Number;
+
+pub const SNAILFISH: Problem = Problem::solved(
+    &|input| {
+        Ok::<_, eyre::Report>(
+            parse_homework(input)?
+                .into_iter()
+                .sum::<Number>()
+                .magnitude(),
+        )
+    },
+    &|input| parse_homework(input).map(|numbers| largest_sum(&numbers)),
+);
+
+/// The largest magnitude of the sum of any two different numbers, in either order.
+fn largest_sum(numbers: &[Number]) -> u32 {
+    numbers
+        .iter()
+        .permutations(2)
+        .map(|pair| (pair[0].clone() + pair[1].clone()).magnitude())
+        .max()
+        .unwrap_or_default()
+}
+
+fn parse_homework(input: &str) -> Result<Vec<Number>> {
+    input.lines().map(str::parse).collect()
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let numbers = parse_homework(
+        "[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]
+[[[5,[2,8]],4],[5,[[9,9],0]]]
+[6,[[[6,2],[5,6]],[[7,6],[4,7]]]]
+[[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]
+[[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]
+[[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]
+[[[[5,4],[7,7]],8],[[8,3],8]]
+[[9,3],[[9,9],[6,[4,9]]]]
+[[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]
+[[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]",
+    )
+    .unwrap();
+
+    let sum: Number = numbers.iter().cloned().sum();
+    assert_eq!(
+        sum.to_string(),
+        "[[[[6,6],[7,6]],[[7,7],[7,0]]],[[[7,7],[7,7]],[[7,8],[9,9]]]]"
+    );
+    assert_eq!(sum.magnitude(), 4140);
+    assert_eq!(largest_sum(&numbers), 3993);
+}
@@ -0,0 +1,245 @@
+use eyre::{OptionExt, Result, ensure};
+use fnv::{FnvHashMap, FnvHashSet};
+use itertools::Itertools;
+
+use aoc_meta::Problem;
+
+pub const BEACON_SCANNER: Problem = Problem::solved(
+    &|input| Map::assemble(&parse_reports(input)?).map(|map| map.beacons.len()),
+    &|input| Map::assemble(&parse_reports(input)?).map(|map| map.largest_distance()),
+);
+
+type Point = [i32; 3];
+
+/// Scanners are only known to agree on where they are once they see at least this many of the same beacons.
+const OVERLAP: usize = 12;
+
+/// One of the 24 ways a scanner can be facing: each of its axes is one of the true axes, possibly reversed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Rotation {
+    axes: [usize; 3],
+    signs: [i32; 3],
+}
+
+impl Rotation {
+    /// Every rotation, that is every signed permutation of the axes that doesn't mirror them.
+    fn all() -> impl Iterator<Item = Rotation> {
+        [0, 1, 2].into_iter().permutations(3).flat_map(|axes| {
+            let even = axes
+                .iter()
+                .tuple_combinations()
+                .filter(|(a, b)| a > b)
+                .count()
+                % 2
+                == 0;
+
+            (0..8u32).filter_map(move |bits| {
+                let signs = [0, 1, 2].map(|axis| if bits >> axis & 1 == 1 { -1 } else { 1 });
+                let unflipped = bits.count_ones() % 2 == 0;
+                (even == unflipped).then_some(Rotation {
+                    axes: [axes[0], axes[1], axes[2]],
+                    signs,
+                })
+            })
+        })
+    }
+
+    fn apply(&self, point: Point) -> Point {
+        std::array::from_fn(|axis| self.signs[axis] * point[self.axes[axis]])
+    }
+}
+
+#[derive(Debug)]
+struct Map {
+    beacons: FnvHashSet<Point>,
+    scanners: Vec<Point>,
+}
+
+impl Map {
+    /// Work out where every scanner is, relative to the first, by repeatedly finding an unplaced scanner that sees
+    /// enough of the same beacons as a placed one.
+    fn assemble(reports: &[Vec<Point>]) -> Result<Self> {
+        // Distances between beacons don't depend on where the scanner is or which way it faces, so scanners that
+        // don't share enough of them can't overlap.
+        let fingerprints: Vec<FnvHashSet<i32>> = reports
+            .iter()
+            .map(|beacons| {
+                beacons
+                    .iter()
+                    .tuple_combinations()
+                    .map(|(a, b)| (0..3).map(|axis| (a[axis] - b[axis]).pow(2)).sum())
+                    .collect()
+            })
+            .collect();
+
+        let mut placed: Vec<Option<(Vec<Point>, Point)>> = vec![None; reports.len()];
+        placed[0] = Some((reports[0].clone(), [0; 3]));
+        let mut queue = vec![0];
+
+        while let Some(known) = queue.pop() {
+            for other in 0..reports.len() {
+                if placed[other].is_some()
+                    || fingerprints[known]
+                        .intersection(&fingerprints[other])
+                        .count()
+                        < OVERLAP * (OVERLAP - 1) / 2
+                {
+                    continue;
+                }
+
+                let (beacons, _) = placed[known].as_ref().unwrap();
+                if let Some(alignment) = align(beacons, &reports[other]) {
+                    placed[other] = Some(alignment);
+                    queue.push(other);
+                }
+            }
+        }
+
+        let placed = placed
+            .into_iter()
+            .collect::<Option<Vec<_>>>()
+            .ok_or_eyre("some scanners couldn't be placed")?;
+        Ok(Self {
+            beacons: placed
+                .iter()
+                .flat_map(|(beacons, _)| beacons.iter().copied())
+                .collect(),
+            scanners: placed.into_iter().map(|(_, scanner)| scanner).collect(),
+        })
+    }
+
+    fn largest_distance(&self) -> i32 {
+        self.scanners
+            .iter()
+            .tuple_combinations()
+            .map(|(a, b)| (0..3).map(|axis| (a[axis] - b[axis]).abs()).sum())
+            .max()
+            .unwrap_or_default()
+    }
+}
+
+/// Find the rotation and offset that line up at least [`OVERLAP`] of the beacons in `report` with `known` ones,
+/// returning the beacons and the scanner's position in the same frame as `known`.
+fn align(known: &[Point], report: &[Point]) -> Option<(Vec<Point>, Point)> {
+    Rotation::all().find_map(|rotation| {
+        let rotated: Vec<Point> = report
+            .iter()
+            .map(|&beacon| rotation.apply(beacon))
+            .collect();
+
+        let mut votes: FnvHashMap<Point, usize> = FnvHashMap::default();
+        for a in known {
+            for b in &rotated {
+                let offset = std::array::from_fn(|axis| a[axis] - b[axis]);
+                let count = votes.entry(offset).or_default();
+                *count += 1;
+
+                if *count == OVERLAP {
+                    let beacons = rotated
+                        .iter()
+                        .map(|b| std::array::from_fn(|axis| b[axis] + offset[axis]))
+                        .collect();
+                    return Some((beacons, offset));
+                }
+            }
+        }
+
+        None
+    })
+}
+
+fn parse_reports(input: &str) -> Result<Vec<Vec<Point>>> {
+    let reports: Vec<Vec<Point>> = input
+        .split("\n\n")
+        .map(|report| {
+            report
+                .lines()
+                .skip(1)
+                .map(|line| {
+                    let coordinates: Vec<i32> =
+                        line.split(',').map(str::parse).collect::<Result<_, _>>()?;
+                    ensure!(coordinates.len() == 3, "beacons have three coordinates");
+                    Ok([coordinates[0], coordinates[1], coordinates[2]])
+                })
+                .collect()
+        })
+        .collect::<Result<_>>()?;
+
+    ensure!(!reports.is_empty(), "there are no scanner reports");
+    Ok(reports)
+}
+
+#[test]
+fn rotations() {
+    use pretty_assertions::assert_eq;
+
+    let images: FnvHashSet<Point> = Rotation::all()
+        .map(|rotation| rotation.apply([1, 2, 3]))
+        .collect();
+    assert_eq!(images.len(), 24);
+    assert!(images.contains(&[1, -2, -3]));
+    assert!(!images.contains(&[-1, 2, 3]));
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    // Scatter beacons across a region, then have a few scanners report the ones they can see, each facing its
+    // own way.
+    let mut state: u64 = 19;
+    let mut random = |range: i32| {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (state >> 33) as i32 % range
+    };
+
+    let beacons: Vec<Point> = (0..400)
+        .map(|_| {
+            [
+                random(3100) - 1000,
+                random(3100) - 1000,
+                random(3100) - 1000,
+            ]
+        })
+        .collect();
+    let scanners: [Point; 4] = [
+        [0, 0, 0],
+        [1100, 40, -30],
+        [1050, 1120, 60],
+        [-20, 1000, 1100],
+    ];
+    let rotations: Vec<Rotation> = Rotation::all().collect();
+
+    let mut visible = FnvHashSet::default();
+    let reports: Vec<String> = scanners
+        .iter()
+        .enumerate()
+        .map(|(i, scanner)| {
+            let facing = rotations[if i == 0 { 0 } else { 5 * i + 2 }];
+            let inverse = rotations
+                .iter()
+                .find(|r| r.apply(facing.apply([1, 2, 3])) == [1, 2, 3])
+                .unwrap();
+
+            let seen: Vec<String> = beacons
+                .iter()
+                .filter(|beacon| (0..3).all(|axis| (beacon[axis] - scanner[axis]).abs() <= 1000))
+                .map(|beacon| {
+                    visible.insert(*beacon);
+                    let [x, y, z] =
+                        inverse.apply(std::array::from_fn(|axis| beacon[axis] - scanner[axis]));
+                    format!("{x},{y},{z}")
+                })
+                .collect();
+
+            format!("--- scanner {i} ---\n{}", seen.join("\n"))
+        })
+        .collect();
+
+    let map = Map::assemble(&parse_reports(&reports.join("\n\n")).unwrap()).unwrap();
+    assert_eq!(map.scanners, scanners);
+    assert_eq!(map.beacons, visible);
+    assert_eq!(map.largest_distance(), 1120 + 960 + 1130);
+}
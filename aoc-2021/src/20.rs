@@ -0,0 +1,115 @@
+use eyre::{OptionExt, Result, ensure};
+
+use aoc_meta::Problem;
+
+pub const TRENCH_MAP: Problem = Problem::solved(
+    &|input| Image::parse(input).map(|image| image.enhance_times(2).lit()),
+    &|input| Image::parse(input).map(|image| image.enhance_times(50).lit()),
+);
+
+#[derive(Debug, Clone)]
+struct Image {
+    algorithm: Vec<bool>,
+    pixels: Vec<Vec<bool>>,
+    /// Whether the infinitely many pixels outside the tracked region are lit. An algorithm that lights a pixel
+    /// surrounded by darkness makes the whole background flicker.
+    background: bool,
+}
+
+impl Image {
+    fn enhance(&self) -> Self {
+        let (width, height) = (self.pixels[0].len() as isize, self.pixels.len() as isize);
+        let pixel = |x: isize, y: isize| {
+            if (0..width).contains(&x) && (0..height).contains(&y) {
+                self.pixels[y as usize][x as usize]
+            } else {
+                self.background
+            }
+        };
+
+        // The image can only grow by one pixel on each side per step.
+        let pixels = (-1..=height)
+            .map(|y| {
+                (-1..=width)
+                    .map(|x| {
+                        let index = (y - 1..=y + 1)
+                            .flat_map(|y| (x - 1..=x + 1).map(move |x| (x, y)))
+                            .fold(0, |index, (x, y)| index << 1 | pixel(x, y) as usize);
+                        self.algorithm[index]
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let background = self.algorithm[if self.background { 511 } else { 0 }];
+        Self {
+            algorithm: self.algorithm.clone(),
+            pixels,
+            background,
+        }
+    }
+
+    fn enhance_times(self, times: usize) -> Self {
+        (0..times).fold(self, |image, _| image.enhance())
+    }
+
+    /// How many pixels are lit, which is only finite if the background is dark.
+    fn lit(&self) -> usize {
+        assert!(!self.background, "infinitely many pixels are lit");
+        self.pixels.iter().flatten().filter(|&&lit| lit).count()
+    }
+
+    fn parse(input: &str) -> Result<Self> {
+        let (algorithm, image) = input
+            .split_once("\n\n")
+            .ok_or_eyre("missing the input image")?;
+        let pixels = |line: &str| line.chars().map(|c| c == '#').collect::<Vec<_>>();
+
+        let algorithm = pixels(algorithm.trim());
+        ensure!(
+            algorithm.len() == 512,
+            "the enhancement algorithm should be 512 pixels long"
+        );
+
+        let pixels: Vec<Vec<bool>> = image.lines().map(pixels).collect();
+        ensure!(
+            !pixels.is_empty()
+                && pixels
+                    .iter()
+                    .all(|row| !row.is_empty() && row.len() == pixels[0].len()),
+            "the image should be a non-empty rectangle"
+        );
+
+        Ok(Self {
+            algorithm,
+            pixels,
+            background: false,
+        })
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let image = |algorithm: fn(usize) -> bool| {
+        let algorithm: String = (0..512)
+            .map(|index| if algorithm(index) { '#' } else { '.' })
+            .collect();
+        Image::parse(&format!("{algorithm}\n\n#..\n.#.\n##.")).unwrap()
+    };
+
+    // Leaving every pixel as it was.
+    let identity = image(|index| index >> 4 & 1 == 1);
+    assert_eq!(identity.enhance_times(2).lit(), 4);
+
+    // Lighting every pixel next to a lit one.
+    let dilation = image(|index| index != 0);
+    assert_eq!(dilation.enhance_times(2).lit(), 41);
+
+    // Inverting every pixel, including the background.
+    let inversion = image(|index| index >> 4 & 1 == 0);
+    let once = inversion.enhance();
+    assert!(once.background);
+    assert_eq!(once.enhance().lit(), 4);
+}
@@ -0,0 +1,94 @@
+use eyre::{OptionExt, Result, ensure};
+use fnv::FnvHashMap;
+
+use aoc_meta::Problem;
+
+pub const DIRAC_DICE: Problem =
+    Problem::solved(&|input| parse_starts(input).map(practice_game), &|input| {
+        let (first, second) = quantum_wins(parse_starts(input)?);
+        Ok::<_, eyre::Report>(first.max(second))
+    });
+
+/// Play to 1000 with a deterministic die that rolls 1, 2, 3… up to 100, returning the losing score multiplied by
+/// how many times the die was rolled.
+fn practice_game(starts: [u32; 2]) -> u32 {
+    let mut positions = starts;
+    let mut scores = [0; 2];
+    let mut rolls = 0;
+
+    for player in [0, 1].into_iter().cycle() {
+        let moves: u32 = (rolls..rolls + 3).map(|roll| roll % 100 + 1).sum();
+        rolls += 3;
+
+        positions[player] = (positions[player] + moves - 1) % 10 + 1;
+        scores[player] += positions[player];
+        if scores[player] >= 1000 {
+            return scores[1 - player] * rolls;
+        }
+    }
+
+    unreachable!()
+}
+
+/// In how many universes each player wins when playing to 21 with a three-sided Dirac die, which splits the
+/// universe on every roll.
+fn quantum_wins(starts: [u32; 2]) -> (u64, u64) {
+    wins(starts, [0, 0], &mut FnvHashMap::default())
+}
+
+/// The wins from each pair of positions and scores, with the player about to move first.
+type Cache = FnvHashMap<([u32; 2], [u32; 2]), (u64, u64)>;
+
+/// How many ways the three rolls of a turn add up to 3, 4, … 9.
+const ROLLS: [(u32, u64); 7] = [(3, 1), (4, 3), (5, 6), (6, 7), (7, 6), (8, 3), (9, 1)];
+
+/// The universes in which the player about to move wins, and those in which the other player does, given both
+/// players' positions and scores with the player about to move first.
+fn wins(positions: [u32; 2], scores: [u32; 2], cache: &mut Cache) -> (u64, u64) {
+    if let Some(&known) = cache.get(&(positions, scores)) {
+        return known;
+    }
+
+    let mut total = (0, 0);
+    for (moves, universes) in ROLLS {
+        let position = (positions[0] + moves - 1) % 10 + 1;
+        let score = scores[0] + position;
+
+        if score >= 21 {
+            total.0 += universes;
+        } else {
+            let (theirs, ours) = wins([positions[1], position], [scores[1], score], cache);
+            total.0 += ours * universes;
+            total.1 += theirs * universes;
+        }
+    }
+
+    cache.insert((positions, scores), total);
+    total
+}
+
+fn parse_starts(input: &str) -> Result<[u32; 2]> {
+    let mut starts = input.lines().map(|line| {
+        let (_, start) = line
+            .split_once("starting position: ")
+            .ok_or_eyre("malformed starting position")?;
+        let start: u32 = start.trim().parse()?;
+        ensure!((1..=10).contains(&start), "the track has spaces 1 to 10");
+        Ok(start)
+    });
+
+    let first = starts.next().ok_or_eyre("missing player 1")??;
+    let second = starts.next().ok_or_eyre("missing player 2")??;
+    Ok([first, second])
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let starts =
+        parse_starts("Player 1 starting position: 4\nPlayer 2 starting position: 8").unwrap();
+    assert_eq!(starts, [4, 8]);
+    assert_eq!(practice_game(starts), 739785);
+    assert_eq!(quantum_wins(starts), (444356092776315, 341960390180808));
+}
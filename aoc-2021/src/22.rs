@@ -0,0 +1,68 @@
+use eyre::Result;
+use winnow::combinator::{alt, preceded, separated_pair, seq};
+use winnow::prelude::*;
+
+use aoc_common::parse::{self, signed};
+use aoc_meta::Problem;
+
+use crate::cuboid::{Cuboid, CuboidSet};
+
+pub const REACTOR_REBOOT: Problem = Problem::solved(
+    &|input| {
+        let initialization = Cuboid::new(-50..=50, -50..=50, -50..=50);
+        Ok::<_, eyre::Report>(reboot(&parse_steps(input)?).clamp(&initialization).volume())
+    },
+    &|input| parse_steps(input).map(|steps| reboot(&steps).volume()),
+);
+
+/// The cubes left on after running every reboot step.
+fn reboot(steps: &[(bool, Cuboid)]) -> CuboidSet {
+    let mut cubes = CuboidSet::new();
+
+    for &(on, cuboid) in steps {
+        if on {
+            cubes.insert(cuboid);
+        } else {
+            cubes.remove(cuboid);
+        }
+    }
+
+    cubes
+}
+
+fn step(input: &mut &str) -> ModalResult<(bool, Cuboid)> {
+    let range = || separated_pair(signed, "..", signed).map(|(start, end)| start..=end);
+
+    seq!(
+        alt(("on".value(true), "off".value(false))),
+        preceded(" x=", range()),
+        preceded(",y=", range()),
+        preceded(",z=", range()),
+    )
+    .map(|(on, x, y, z)| (on, Cuboid::new(x, y, z)))
+    .parse_next(input)
+}
+
+fn parse_steps(input: &str) -> Result<Vec<(bool, Cuboid)>> {
+    parse::lines(input, step).collect()
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let steps = parse_steps(
+        "on x=10..12,y=10..12,z=10..12
+on x=11..13,y=11..13,z=11..13
+off x=9..11,y=9..11,z=9..11
+on x=10..10,y=10..10,z=10..10",
+    )
+    .unwrap();
+
+    assert_eq!(steps[2], (false, Cuboid::new(9..=11, 9..=11, 9..=11)));
+
+    let volumes: Vec<i64> = (1..=steps.len())
+        .map(|n| reboot(&steps[..n]).volume())
+        .collect();
+    assert_eq!(volumes, [27, 46, 38, 39]);
+}
@@ -0,0 +1,199 @@
+use eyre::{OptionExt, Result, ensure};
+
+use aoc_common::search::{SearchSpace, astar};
+use aoc_meta::Problem;
+
+pub const AMPHIPOD: Problem = Problem::solved(
+    &|input| Burrow::<2>::organize(&parse_amphipods(input, &[])?),
+    &|input| Burrow::<4>::organize(&parse_amphipods(input, &["DCBA", "DBAC"])?),
+);
+
+const HALLWAY: usize = 11;
+
+/// The hallway spaces just outside each room, where amphipods can't stop.
+const DOORS: [usize; 4] = [2, 4, 6, 8];
+
+/// The energy an amphipod of each type uses per step.
+const ENERGY: [u32; 4] = [1, 10, 100, 1000];
+
+/// Where every amphipod is, with types 0 to 4 standing for A to D. Rooms are listed from the top down.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct State<const DEPTH: usize> {
+    hallway: [Option<u8>; HALLWAY],
+    rooms: [[Option<u8>; DEPTH]; 4],
+}
+
+impl<const DEPTH: usize> State<DEPTH> {
+    /// Whether everything in `room` is an amphipod that belongs there, so nothing needs to leave it.
+    fn settled(&self, room: usize) -> bool {
+        self.rooms[room]
+            .iter()
+            .flatten()
+            .all(|&kind| kind as usize == room)
+    }
+
+    /// Whether the hallway is clear between `from` and `to`, not counting `from` itself.
+    fn clear(&self, from: usize, to: usize) -> bool {
+        let path = if from < to {
+            from + 1..=to
+        } else {
+            to..=from - 1
+        };
+        self.hallway[path].iter().all(Option::is_none)
+    }
+}
+
+struct Burrow<const DEPTH: usize>;
+
+impl<const DEPTH: usize> Burrow<DEPTH> {
+    /// The least energy needed to sort the amphipods, given row by row from the top of the rooms.
+    fn organize(rows: &[[u8; 4]]) -> Result<u32> {
+        ensure!(rows.len() == DEPTH, "expected {DEPTH} rows of amphipods");
+
+        let start: State<DEPTH> = State {
+            hallway: [None; HALLWAY],
+            rooms: std::array::from_fn(|room| std::array::from_fn(|depth| Some(rows[depth][room]))),
+        };
+        astar(&Burrow, start)
+            .map(|path| path.cost)
+            .ok_or_eyre("the amphipods can't be organized")
+    }
+}
+
+impl<const DEPTH: usize> SearchSpace for Burrow<DEPTH> {
+    type State = State<DEPTH>;
+    type Cost = u32;
+
+    /// Amphipods move straight from their room into the hallway, or straight from the hallway into their own room
+    /// once it holds no strangers. Moving between rooms is the same as stopping in the hallway on the way.
+    fn successors(&self, state: &Self::State) -> impl IntoIterator<Item = (Self::State, u32)> {
+        let mut successors = Vec::new();
+
+        for (position, kind) in state.hallway.iter().enumerate() {
+            let Some(kind) = *kind else { continue };
+            let room = kind as usize;
+
+            if !state.settled(room) || !state.clear(position, DOORS[room]) {
+                continue;
+            }
+
+            let Some(depth) = state.rooms[room].iter().rposition(Option::is_none) else {
+                continue;
+            };
+            let mut next = state.clone();
+            next.hallway[position] = None;
+            next.rooms[room][depth] = Some(kind);
+
+            let steps = position.abs_diff(DOORS[room]) + depth + 1;
+            successors.push((next, steps as u32 * ENERGY[room]));
+        }
+
+        for (room, &door) in DOORS.iter().enumerate() {
+            if state.settled(room) {
+                continue;
+            }
+
+            let Some(depth) = state.rooms[room].iter().position(Option::is_some) else {
+                continue;
+            };
+            let kind = state.rooms[room][depth].unwrap();
+
+            for position in (0..HALLWAY).filter(|position| !DOORS.contains(position)) {
+                if state.hallway[position].is_some() || !state.clear(door, position) {
+                    continue;
+                }
+
+                let mut next = state.clone();
+                next.rooms[room][depth] = None;
+                next.hallway[position] = Some(kind);
+
+                let steps = depth + 1 + door.abs_diff(position);
+                successors.push((next, steps as u32 * ENERGY[kind as usize]));
+            }
+        }
+
+        successors
+    }
+
+    fn is_goal(&self, state: &Self::State) -> bool {
+        (0..4).all(|room| {
+            state.rooms[room]
+                .iter()
+                .all(|&kind| kind == Some(room as u8))
+        })
+    }
+
+    /// The energy it would take every amphipod to get to its room's doorway and step in, if nothing were in the
+    /// way. Amphipods in the wrong room, or blocking one that is, have to at least step out and back in again.
+    fn heuristic(&self, state: &Self::State) -> u32 {
+        let hallway = state
+            .hallway
+            .iter()
+            .enumerate()
+            .filter_map(|(position, kind)| {
+                let kind = (*kind)? as usize;
+                Some((position.abs_diff(DOORS[kind]) + 1) as u32 * ENERGY[kind])
+            });
+
+        let rooms = (0..4).flat_map(|room| {
+            let settled = state.rooms[room]
+                .iter()
+                .rposition(|&kind| kind != Some(room as u8))
+                .map_or(0, |i| i + 1);
+
+            state.rooms[room][..settled]
+                .iter()
+                .enumerate()
+                .filter_map(move |(depth, kind)| {
+                    let kind = (*kind)? as usize;
+                    let across = DOORS[room].abs_diff(DOORS[kind]).max(2);
+                    Some((depth + 1 + across + 1) as u32 * ENERGY[kind])
+                })
+        });
+
+        hallway.chain(rooms).sum()
+    }
+}
+
+/// The amphipods in each row of the rooms from the top, with the `unfolded` rows inserted after the first.
+fn parse_amphipods(input: &str, unfolded: &[&str]) -> Result<Vec<[u8; 4]>> {
+    let mut rows: Vec<String> = input
+        .lines()
+        .map(|line| {
+            line.chars()
+                .filter(char::is_ascii_uppercase)
+                .collect::<String>()
+        })
+        .filter(|row| !row.is_empty())
+        .collect();
+    rows.splice(1..1, unfolded.iter().map(|row| row.to_string()));
+
+    rows.iter()
+        .map(|row| {
+            ensure!(
+                row.len() == 4 && row.bytes().all(|b| (b'A'..=b'D').contains(&b)),
+                "malformed row of amphipods"
+            );
+            let row = row.as_bytes();
+            Ok(std::array::from_fn(|room| row[room] - b'A'))
+        })
+        .collect()
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let example = "#############
+#...........#
+###B#C#B#D###
+  #A#D#C#A#
+  #########";
+
+    let rows = parse_amphipods(example, &[]).unwrap();
+    assert_eq!(rows, [[1, 2, 1, 3], [0, 3, 2, 0]]);
+    assert_eq!(Burrow::<2>::organize(&rows).unwrap(), 12521);
+
+    let rows = parse_amphipods(example, &["DCBA", "DBAC"]).unwrap();
+    assert_eq!(Burrow::<4>::organize(&rows).unwrap(), 44169);
+}
@@ -0,0 +1,26 @@
+use eyre::{Result, ensure};
+
+use aoc_meta::Problem;
+
+use crate::alu::Program;
+
+pub const ARITHMETIC_LOGIC_UNIT: Problem = Problem::solved(
+    &|input| extreme_model_numbers(input).map(|(largest, _)| largest),
+    &|input| extreme_model_numbers(input).map(|(_, smallest)| smallest),
+);
+
+/// The largest and smallest model numbers MONAD accepts, double-checked by actually running it on them.
+fn extreme_model_numbers(input: &str) -> Result<(u64, u64)> {
+    let monad = Program::parse(input)?;
+    let (largest, smallest) = monad.extreme_model_numbers()?;
+
+    for number in [largest, smallest] {
+        let digits: Vec<u8> = number.to_string().bytes().map(|b| b - b'0').collect();
+        ensure!(
+            monad.accepts(&digits)?,
+            "MONAD doesn't actually accept {number}"
+        );
+    }
+
+    Ok((largest, smallest))
+}
@@ -0,0 +1,95 @@
+use eyre::{Result, bail, ensure};
+
+use aoc_meta::Problem;
+
+pub const SEA_CUCUMBER: Problem =
+    Problem::partially_solved(&|input| Seafloor::parse(input).map(Seafloor::settle));
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Cell {
+    Empty,
+    East,
+    South,
+}
+
+#[derive(Debug)]
+struct Seafloor {
+    width: usize,
+    cells: Vec<Cell>,
+}
+
+impl Seafloor {
+    /// The first step on which no sea cucumber moves.
+    fn settle(mut self) -> usize {
+        (1..).find(|_| !self.step()).unwrap_or_default()
+    }
+
+    /// Move the east-facing herd and then the south-facing one, wrapping around the edges, returning whether
+    /// any sea cucumber moved.
+    fn step(&mut self) -> bool {
+        let height = self.cells.len() / self.width;
+        let east = |i: usize| i / self.width * self.width + (i + 1) % self.width;
+        let south = |i: usize| (i + self.width) % (self.width * height);
+
+        let mut moved = false;
+        for (herd, ahead) in [
+            (Cell::East, &east as &dyn Fn(usize) -> usize),
+            (Cell::South, &south),
+        ] {
+            let moving: Vec<usize> = (0..self.cells.len())
+                .filter(|&i| self.cells[i] == herd && self.cells[ahead(i)] == Cell::Empty)
+                .collect();
+
+            for &i in &moving {
+                self.cells[i] = Cell::Empty;
+                self.cells[ahead(i)] = herd;
+            }
+            moved |= !moving.is_empty();
+        }
+
+        moved
+    }
+
+    fn parse(input: &str) -> Result<Self> {
+        let width = input.lines().next().map_or(0, str::len);
+        ensure!(
+            width > 0 && input.lines().all(|line| line.len() == width),
+            "the seafloor should be a rectangle"
+        );
+
+        let cells = input
+            .lines()
+            .flat_map(str::chars)
+            .map(|c| {
+                Ok(match c {
+                    '.' => Cell::Empty,
+                    '>' => Cell::East,
+                    'v' => Cell::South,
+                    _ => bail!("unexpected character {c:?}"),
+                })
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Self { width, cells })
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let seafloor = Seafloor::parse(
+        "v...>>.vv>
+.vv>>.vv..
+>>.>v>...v
+>>v>>.>.v.
+v>v.vv.v..
+>.>>..v...
+.vv..>.>v.
+v.v..>>v.v
+....v..v.>",
+    )
+    .unwrap();
+
+    assert_eq!(seafloor.settle(), 58);
+}
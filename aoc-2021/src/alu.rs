@@ -0,0 +1,301 @@
+//! The submarine's arithmetic logic unit, and an analysis of the model number validator (MONAD) it runs.
+//!
+//! MONAD is fourteen copies of the same block of instructions, one per digit, differing only in three constants.
+//! Each block treats `z` as a stack of base 26 digits: half of them push the input digit plus an offset, and the
+//! other half pop a value and only avoid pushing again when their own input matches it plus another offset. A
+//! model number is accepted when the stack ends up empty, so every pop has to match, which pins each popping
+//! digit to the digit of the block that pushed what it pops.
+
+use eyre::{OptionExt, Report, Result, bail, ensure};
+use winnow::ascii::alpha1;
+use winnow::combinator::{alt, dispatch, fail, separated_pair, terminated};
+use winnow::prelude::*;
+use winnow::token::one_of;
+
+use aoc_common::parse::{self, signed};
+
+/// One of the registers `w`, `x`, `y` and `z`, numbered from 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Register(u8);
+
+impl Register {
+    pub const X: Self = Self(1);
+    pub const Y: Self = Self(2);
+    pub const Z: Self = Self(3);
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Operand {
+    Register(Register),
+    Value(i64),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Instruction {
+    Input(Register),
+    Add(Register, Operand),
+    Multiply(Register, Operand),
+    Divide(Register, Operand),
+    Modulo(Register, Operand),
+    Equal(Register, Operand),
+}
+
+/// The instructions MONAD runs for each digit, with the constants that vary between digits as zeros.
+const BLOCK: &str = "inp w
+mul x 0
+add x z
+mod x 26
+div z 0
+add x 0
+eql x w
+eql x 0
+mul y 0
+add y 25
+mul y x
+add y 1
+mul z y
+mul y 0
+add y w
+add y 0
+mul y x
+add z y";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Program {
+    instructions: Vec<Instruction>,
+}
+
+impl Program {
+    pub fn parse(input: &str) -> Result<Self, Report> {
+        Ok(Self {
+            instructions: parse::lines(input, instruction).collect::<Result<_, _>>()?,
+        })
+    }
+
+    /// Run the program, reading `inputs` in turn, and return the final values of `w`, `x`, `y` and `z`.
+    pub fn run(&self, inputs: impl IntoIterator<Item = i64>) -> Result<[i64; 4]> {
+        let mut registers = [0; 4];
+        let mut inputs = inputs.into_iter();
+
+        for &instruction in &self.instructions {
+            let value = |operand| match operand {
+                Operand::Register(Register(r)) => registers[usize::from(r)],
+                Operand::Value(value) => value,
+            };
+
+            let (Instruction::Input(Register(target))
+            | Instruction::Add(Register(target), _)
+            | Instruction::Multiply(Register(target), _)
+            | Instruction::Divide(Register(target), _)
+            | Instruction::Modulo(Register(target), _)
+            | Instruction::Equal(Register(target), _)) = instruction;
+            let a = registers[usize::from(target)];
+
+            registers[usize::from(target)] = match instruction {
+                Instruction::Input(_) => {
+                    inputs.next().ok_or_eyre("the program ran out of input")?
+                }
+                Instruction::Add(_, b) => a + value(b),
+                Instruction::Multiply(_, b) => a * value(b),
+                Instruction::Divide(_, b) => {
+                    let b = value(b);
+                    ensure!(b != 0, "division by zero");
+                    a / b
+                }
+                Instruction::Modulo(_, b) => {
+                    let b = value(b);
+                    ensure!(a >= 0 && b > 0, "{a} mod {b} is undefined");
+                    a % b
+                }
+                Instruction::Equal(_, b) => (a == value(b)) as i64,
+            };
+        }
+
+        Ok(registers)
+    }
+
+    /// Whether MONAD accepts the model number given by `digits`, which it does when `z` ends up 0.
+    pub fn accepts(&self, digits: &[u8]) -> Result<bool> {
+        let [.., z] = self.run(digits.iter().map(|&digit| i64::from(digit)))?;
+        Ok(z == 0)
+    }
+
+    /// The largest and smallest model numbers MONAD accepts, worked out from the constants in each of its blocks.
+    pub fn extreme_model_numbers(&self) -> Result<(u64, u64)> {
+        let mut largest = [0; 14];
+        let mut smallest = [0; 14];
+        let mut stack = Vec::new();
+
+        for (digit, (pops, check, offset)) in self.blocks()?.into_iter().enumerate() {
+            if !pops {
+                // Nothing on the stack is less than 0 or more than 25, so a push can never be skipped.
+                ensure!(!(-25..=9).contains(&check), "block {digit} might not push");
+                stack.push((digit, offset));
+                continue;
+            }
+
+            let (pushed, pushed_offset) =
+                stack.pop().ok_or_eyre("MONAD pops more than it pushes")?;
+
+            // The popping digit has to be the pushing digit plus `difference`.
+            let difference = pushed_offset + check;
+            ensure!(
+                difference.abs() <= 8,
+                "digits {pushed} and {digit} can never match"
+            );
+
+            let difference = difference as i8;
+            largest[pushed] = (9 - difference.max(0)) as u8;
+            largest[digit] = (9 + difference.min(0)) as u8;
+            smallest[pushed] = (1 - difference.min(0)) as u8;
+            smallest[digit] = (1 + difference.max(0)) as u8;
+        }
+
+        ensure!(
+            stack.is_empty(),
+            "MONAD pushes more than it pops, so it accepts nothing"
+        );
+
+        let number = |digits: [u8; 14]| {
+            digits
+                .iter()
+                .fold(0, |number, &digit| number * 10 + u64::from(digit))
+        };
+        Ok((number(largest), number(smallest)))
+    }
+
+    /// The varying constants from each of MONAD's blocks: whether it pops (dividing `z` by 26 rather than 1), the
+    /// offset added to the top of the stack before comparing it with the input, and the offset added to the input
+    /// before pushing it.
+    fn blocks(&self) -> Result<Vec<(bool, i64, i64)>> {
+        let template = Program::parse(BLOCK)?.instructions;
+        ensure!(
+            self.instructions.len() == 14 * template.len(),
+            "MONAD has a block of instructions per digit"
+        );
+
+        self.instructions
+            .chunks(template.len())
+            .map(|block| {
+                for (i, (&instruction, &expected)) in block.iter().zip(&template).enumerate() {
+                    if ![4, 5, 15].contains(&i) {
+                        ensure!(
+                            instruction == expected,
+                            "unexpected instruction {instruction:?} in MONAD"
+                        );
+                    }
+                }
+
+                let pops = match block[4] {
+                    Instruction::Divide(Register::Z, Operand::Value(1)) => false,
+                    Instruction::Divide(Register::Z, Operand::Value(26)) => true,
+                    other => bail!("unexpected instruction {other:?} in MONAD"),
+                };
+                let (
+                    Instruction::Add(Register::X, Operand::Value(check)),
+                    Instruction::Add(Register::Y, Operand::Value(offset)),
+                ) = (block[5], block[15])
+                else {
+                    bail!(
+                        "unexpected instructions {:?} and {:?} in MONAD",
+                        block[5],
+                        block[15]
+                    );
+                };
+
+                Ok((pops, check, offset))
+            })
+            .collect()
+    }
+}
+
+fn register(input: &mut &str) -> ModalResult<Register> {
+    one_of(['w', 'x', 'y', 'z'])
+        .map(|name: char| Register(name as u8 - b'w'))
+        .parse_next(input)
+}
+
+fn operand(input: &mut &str) -> ModalResult<Operand> {
+    alt((register.map(Operand::Register), signed.map(Operand::Value))).parse_next(input)
+}
+
+fn instruction(input: &mut &str) -> ModalResult<Instruction> {
+    let arithmetic = || separated_pair(register, ' ', operand);
+
+    dispatch! {terminated(alpha1, ' ');
+        "inp" => register.map(Instruction::Input),
+        "add" => arithmetic().map(|(target, value)| Instruction::Add(target, value)),
+        "mul" => arithmetic().map(|(target, value)| Instruction::Multiply(target, value)),
+        "div" => arithmetic().map(|(target, value)| Instruction::Divide(target, value)),
+        "mod" => arithmetic().map(|(target, value)| Instruction::Modulo(target, value)),
+        "eql" => arithmetic().map(|(target, value)| Instruction::Equal(target, value)),
+        _ => fail,
+    }
+    .parse_next(input)
+}
+
+#[test]
+fn interpreter() {
+    use pretty_assertions::assert_eq;
+
+    let negate = Program::parse("inp x\nmul x -1").unwrap();
+    assert_eq!(negate.run([7]).unwrap(), [0, -7, 0, 0]);
+
+    let binary = Program::parse(
+        "inp w\nadd z w\nmod z 2\ndiv w 2\nadd y w\nmod y 2\ndiv w 2\nadd x w\nmod x 2\ndiv w 2\nmod w 2",
+    )
+    .unwrap();
+    assert_eq!(binary.run([13]).unwrap(), [1, 1, 0, 1]);
+
+    assert!(negate.run([]).is_err());
+    assert!(Program::parse("inp w\nmod w 3").unwrap().run([-1]).is_err());
+}
+
+#[test]
+fn analysis() {
+    use pretty_assertions::assert_eq;
+
+    // Pushes offset by `b` when `div` is 1, and pops checking against `a` when `div` is 26.
+    let blocks: [(i64, i64, i64); 14] = [
+        (1, 12, 5),
+        (1, 11, 8),
+        (26, -3, 2),
+        (1, 14, 1),
+        (26, -7, 9),
+        (26, -2, 4),
+        (1, 10, 0),
+        (1, 13, 12),
+        (26, -12, 3),
+        (1, 15, 4),
+        (26, -1, 6),
+        (26, -8, 11),
+        (1, 11, 7),
+        (26, -10, 1),
+    ];
+    let monad: Vec<String> = blocks
+        .iter()
+        .map(|(div, a, b)| {
+            let mut lines: Vec<String> = BLOCK.lines().map(str::to_string).collect();
+            lines[4] = format!("div z {div}");
+            lines[5] = format!("add x {a}");
+            lines[15] = format!("add y {b}");
+            lines.join("\n")
+        })
+        .collect();
+    let monad = Program::parse(&monad.join("\n")).unwrap();
+
+    let (largest, smallest) = monad.extreme_model_numbers().unwrap();
+    assert_eq!((largest, smallest), (64993999969196, 11671491114141));
+
+    let digits = |number: u64| {
+        number
+            .to_string()
+            .bytes()
+            .map(|b| b - b'0')
+            .collect::<Vec<_>>()
+    };
+    assert!(monad.accepts(&digits(largest)).unwrap());
+    assert!(monad.accepts(&digits(smallest)).unwrap());
+    assert!(!monad.accepts(&digits(largest + 1)).unwrap());
+    assert!(!monad.accepts(&digits(smallest - 1)).unwrap());
+}
@@ -0,0 +1,135 @@
+//! Sets of points in 3D space built up out of cuboids.
+//!
+//! Rather than splitting cuboids into disjoint pieces, a set is kept as a list of signed cuboids whose volumes
+//! add up by inclusion–exclusion: adding a cuboid first cancels out its overlap with everything already there,
+//! and removing one does only that.
+
+use std::ops::RangeInclusive;
+
+/// An axis-aligned box of integer points, with inclusive bounds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cuboid {
+    pub min: [i64; 3],
+    pub max: [i64; 3],
+}
+
+impl Cuboid {
+    pub fn new(x: RangeInclusive<i64>, y: RangeInclusive<i64>, z: RangeInclusive<i64>) -> Self {
+        Self {
+            min: [*x.start(), *y.start(), *z.start()],
+            max: [*x.end(), *y.end(), *z.end()],
+        }
+    }
+
+    /// How many points the cuboid contains.
+    pub fn volume(&self) -> i64 {
+        (0..3)
+            .map(|axis| (self.max[axis] - self.min[axis] + 1).max(0))
+            .product()
+    }
+
+    /// The points in both cuboids, if there are any.
+    pub fn intersection(&self, other: &Cuboid) -> Option<Cuboid> {
+        let min = std::array::from_fn(|axis| self.min[axis].max(other.min[axis]));
+        let max = std::array::from_fn(|axis| self.max[axis].min(other.max[axis]));
+        (0..3)
+            .all(|axis| min[axis] <= max[axis])
+            .then_some(Cuboid { min, max })
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct CuboidSet {
+    /// Cuboids that each add their volume to the set or, if negative, take it away again.
+    terms: Vec<(Cuboid, i64)>,
+}
+
+impl CuboidSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add every point in `cuboid` to the set.
+    pub fn insert(&mut self, cuboid: Cuboid) {
+        self.remove(cuboid);
+        self.terms.push((cuboid, 1));
+    }
+
+    /// Take every point in `cuboid` out of the set.
+    pub fn remove(&mut self, cuboid: Cuboid) {
+        let overlaps: Vec<(Cuboid, i64)> = self
+            .terms
+            .iter()
+            .filter_map(|(term, sign)| term.intersection(&cuboid).map(|overlap| (overlap, -sign)))
+            .collect();
+
+        self.terms.extend(overlaps);
+        self.terms.retain(|&(_, sign)| sign != 0);
+    }
+
+    /// The set's points that are also in `cuboid`.
+    pub fn clamp(&self, cuboid: &Cuboid) -> CuboidSet {
+        let terms = self
+            .terms
+            .iter()
+            .filter_map(|(term, sign)| term.intersection(cuboid).map(|overlap| (overlap, *sign)))
+            .collect();
+
+        CuboidSet { terms }
+    }
+
+    /// How many points are in the set.
+    pub fn volume(&self) -> i64 {
+        self.terms
+            .iter()
+            .map(|(cuboid, sign)| cuboid.volume() * sign)
+            .sum()
+    }
+}
+
+#[test]
+fn against_points() {
+    use fnv::FnvHashSet;
+    use pretty_assertions::assert_eq;
+
+    let mut state: u64 = 22;
+    let mut random = |range: i64| {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (state >> 33) as i64 % range
+    };
+
+    let mut set = CuboidSet::new();
+    let mut points = FnvHashSet::default();
+
+    for step in 0..60 {
+        let [x, y, z] = [(); 3].map(|_| {
+            let start = random(12) - 4;
+            start..=start + random(6)
+        });
+        let cuboid = Cuboid::new(x.clone(), y.clone(), z.clone());
+        let on = step % 3 != 2;
+
+        for point in itertools::iproduct!(x, y, z).map(|(x, y, z)| [x, y, z]) {
+            if on {
+                points.insert(point);
+            } else {
+                points.remove(&point);
+            }
+        }
+
+        if on {
+            set.insert(cuboid);
+        } else {
+            set.remove(cuboid);
+        }
+        assert_eq!(set.volume(), points.len() as i64, "after step {step}");
+    }
+
+    let region = Cuboid::new(0..=3, -2..=2, 1..=5);
+    let inside = points.iter().filter(|point| {
+        (0..3).all(|axis| (region.min[axis]..=region.max[axis]).contains(&point[axis]))
+    });
+    assert_eq!(set.clamp(&region).volume(), inside.count() as i64);
+}
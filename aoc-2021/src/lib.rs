@@ -1,3 +1,33 @@
 use aoc_meta::PROBLEMS;
 
-PROBLEMS! {}
+mod alu;
+mod cuboid;
+mod snailfish;
+
+PROBLEMS! {
+    01 => SONAR_SWEEP,
+    02 => DIVE,
+    03 => BINARY_DIAGNOSTIC,
+    04 => GIANT_SQUID,
+    05 => HYDROTHERMAL_VENTURE,
+    06 => LANTERNFISH,
+    07 => THE_TREACHERY_OF_WHALES,
+    08 => SEVEN_SEGMENT_SEARCH,
+    09 => SMOKE_BASIN,
+    10 => SYNTAX_SCORING,
+    11 => DUMBO_OCTOPUS,
+    12 => PASSAGE_PATHING,
+    13 => TRANSPARENT_ORIGAMI,
+    14 => EXTENDED_POLYMERIZATION,
+    15 => CHITON,
+    16 => PACKET_DECODER,
+    17 => TRICK_SHOT,
+    18 => SNAILFISH,
+    19 => BEACON_SCANNER,
+    20 => TRENCH_MAP,
+    21 => DIRAC_DICE,
+    22 => REACTOR_REBOOT,
+    23 => AMPHIPOD,
+    24 => ARITHMETIC_LOGIC_UNIT,
+    25 => SEA_CUCUMBER,
+}
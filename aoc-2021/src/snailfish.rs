@@ -0,0 +1,215 @@
+//! Snailfish numbers: pairs whose elements are regular numbers or other pairs, which have to be reduced after
+//! every addition.
+//!
+//! A number is stored flattened, as its regular numbers from left to right along with how many pairs deep each one
+//! is nested. Exploding and splitting only ever look at neighbouring regular numbers, which are then right next
+//! to each other, and the tree shape can always be recovered from the depths.
+
+use std::fmt::{self, Display, Formatter};
+use std::iter::Sum;
+use std::ops::Add;
+use std::str::FromStr;
+
+use eyre::{OptionExt, Report, Result, bail, ensure};
+
+/// How deeply pairs can be nested before the innermost ones explode.
+const MAX_DEPTH: u8 = 4;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Number {
+    /// Each regular number and the number of pairs it's nested inside.
+    regulars: Vec<(u32, u8)>,
+}
+
+impl Number {
+    /// Explode and split until neither applies any more, always exploding first.
+    pub fn reduce(&mut self) {
+        while self.explode() || self.split() {}
+    }
+
+    /// Explode the leftmost pair nested too deeply, if there is one. Its left value is added to the first regular
+    /// number to its left, its right value to the first to its right, and the pair itself is replaced by 0.
+    fn explode(&mut self) -> bool {
+        let Some(i) = self
+            .regulars
+            .iter()
+            .position(|&(_, depth)| depth > MAX_DEPTH)
+        else {
+            return false;
+        };
+
+        // Pairs only get too deep through adding or splitting, each of which nests by at most one level, so the
+        // exploding pair is made of two regular numbers.
+        let (left, depth) = self.regulars[i];
+        let (right, _) = self.regulars.remove(i + 1);
+
+        if let Some((value, _)) = i.checked_sub(1).map(|i| &mut self.regulars[i]) {
+            *value += left;
+        }
+        if let Some((value, _)) = self.regulars.get_mut(i + 1) {
+            *value += right;
+        }
+
+        self.regulars[i] = (0, depth - 1);
+        true
+    }
+
+    /// Split the leftmost regular number that's 10 or more, if there is one, into a pair of it halved rounded down
+    /// and halved rounded up.
+    fn split(&mut self) -> bool {
+        let Some(i) = self.regulars.iter().position(|&(value, _)| value >= 10) else {
+            return false;
+        };
+
+        let (value, depth) = self.regulars[i];
+        self.regulars[i] = (value / 2, depth + 1);
+        self.regulars.insert(i + 1, (value.div_ceil(2), depth + 1));
+        true
+    }
+
+    /// Three times the magnitude of the left element of each pair plus twice the magnitude of its right element,
+    /// where a regular number is its own magnitude.
+    pub fn magnitude(&self) -> u32 {
+        // Folding from the left, a pair is complete as soon as the top two entries of the stack are equally deep.
+        let mut stack: Vec<(u32, u8)> = Vec::new();
+
+        for &(mut value, mut depth) in &self.regulars {
+            while let Some(&(left, left_depth)) = stack.last()
+                && left_depth == depth
+                && depth > 0
+            {
+                stack.pop();
+                value = 3 * left + 2 * value;
+                depth -= 1;
+            }
+            stack.push((value, depth));
+        }
+
+        stack.first().map_or(0, |&(value, _)| value)
+    }
+
+    fn write(&self, f: &mut Formatter, i: &mut usize, depth: u8) -> fmt::Result {
+        let (value, regular_depth) = self.regulars[*i];
+        if regular_depth == depth {
+            *i += 1;
+            return write!(f, "{value}");
+        }
+
+        write!(f, "[")?;
+        self.write(f, i, depth + 1)?;
+        write!(f, ",")?;
+        self.write(f, i, depth + 1)?;
+        write!(f, "]")
+    }
+}
+
+impl Add for Number {
+    type Output = Number;
+
+    fn add(self, other: Number) -> Number {
+        let mut regulars = self.regulars;
+        regulars.extend(other.regulars);
+        for (_, depth) in &mut regulars {
+            *depth += 1;
+        }
+
+        let mut sum = Number { regulars };
+        sum.reduce();
+        sum
+    }
+}
+
+impl Sum for Number {
+    /// Add up the numbers in order. Snailfish addition isn't associative, and there's no zero, so an empty sum is
+    /// the regular number 0 and is otherwise ignored.
+    fn sum<I: Iterator<Item = Number>>(iter: I) -> Number {
+        iter.reduce(|sum, number| sum + number).unwrap_or(Number {
+            regulars: vec![(0, 0)],
+        })
+    }
+}
+
+impl FromStr for Number {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut regulars = Vec::new();
+        let mut depth: u8 = 0;
+        let mut digits = None;
+
+        for c in s.trim().chars() {
+            if let Some(digit) = c.to_digit(10) {
+                digits = Some(digits.unwrap_or(0) * 10 + digit);
+                continue;
+            }
+
+            if let Some(value) = digits.take() {
+                regulars.push((value, depth));
+            }
+
+            match c {
+                '[' => depth += 1,
+                ']' => depth = depth.checked_sub(1).ok_or_eyre("unbalanced brackets")?,
+                ',' => {}
+                _ => bail!("unexpected character {c:?} in a snailfish number"),
+            }
+        }
+
+        if let Some(value) = digits {
+            regulars.push((value, depth));
+        }
+
+        ensure!(depth == 0, "unbalanced brackets");
+        ensure!(
+            !regulars.is_empty(),
+            "a snailfish number needs at least one regular number"
+        );
+        Ok(Self { regulars })
+    }
+}
+
+impl Display for Number {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.write(f, &mut 0, 0)
+    }
+}
+
+#[test]
+fn reduction() {
+    use pretty_assertions::assert_eq;
+
+    let explode = |s: &str| {
+        let mut number: Number = s.parse().unwrap();
+        number.explode();
+        number.to_string()
+    };
+
+    assert_eq!(explode("[[[[[9,8],1],2],3],4]"), "[[[[0,9],2],3],4]");
+    assert_eq!(explode("[7,[6,[5,[4,[3,2]]]]]"), "[7,[6,[5,[7,0]]]]");
+    assert_eq!(explode("[[6,[5,[4,[3,2]]]],1]"), "[[6,[5,[7,0]]],3]");
+    assert_eq!(
+        explode("[[3,[2,[1,[7,3]]]],[6,[5,[4,[3,2]]]]]"),
+        "[[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]"
+    );
+
+    let sum = "[[[[4,3],4],4],[7,[[8,4],9]]]".parse::<Number>().unwrap() + "[1,1]".parse().unwrap();
+    assert_eq!(sum.to_string(), "[[[[0,7],4],[[7,8],[6,0]]],[8,1]]");
+
+    let sum: Number = (1..=6).map(|n| format!("[{n},{n}]").parse().unwrap()).sum();
+    assert_eq!(sum.to_string(), "[[[[5,0],[7,4]],[5,5]],[6,6]]");
+}
+
+#[test]
+fn magnitude() {
+    use pretty_assertions::assert_eq;
+
+    let magnitude = |s: &str| s.parse::<Number>().unwrap().magnitude();
+
+    assert_eq!(magnitude("[[1,2],[[3,4],5]]"), 143);
+    assert_eq!(magnitude("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]"), 1384);
+    assert_eq!(magnitude("[[9,1],[1,9]]"), 129);
+    assert_eq!(
+        magnitude("[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]"),
+        3488
+    );
+}
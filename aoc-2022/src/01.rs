@@ -0,0 +1,29 @@
+use eyre::Result;
+
+use aoc_meta::Problem;
+
+pub const CALORIE_COUNTING: Problem = Problem::solved(
+    &|input| parse_totals(input).map(|totals| totals.iter().take(1).sum::<u32>()),
+    &|input| parse_totals(input).map(|totals| totals.iter().take(3).sum::<u32>()),
+);
+
+/// The calories each elf is carrying, most first.
+fn parse_totals(input: &str) -> Result<Vec<u32>> {
+    let mut totals = input
+        .split("\n\n")
+        .map(|elf| elf.lines().map(str::parse::<u32>).sum::<Result<u32, _>>())
+        .collect::<Result<Vec<_>, _>>()?;
+
+    totals.sort_unstable_by(|a, b| b.cmp(a));
+    Ok(totals)
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let totals =
+        parse_totals("1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000")
+            .unwrap();
+    assert_eq!(totals, [24000, 11000, 10000, 6000, 4000]);
+}
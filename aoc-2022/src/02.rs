@@ -0,0 +1,51 @@
+use eyre::{Result, ensure};
+
+use aoc_meta::Problem;
+
+pub const ROCK_PAPER_SCISSORS: Problem =
+    Problem::solved(&|input| total_score(input, score), &|input| {
+        total_score(input, |theirs, column| {
+            score(theirs, (theirs + column + 2) % 3)
+        })
+    });
+
+/// The score of a round where the opponent plays `theirs` and we play `ours`, with rock, paper and scissors
+/// numbered 0 to 2 so that each beats the one before it.
+fn score(theirs: u8, ours: u8) -> u32 {
+    let outcome = (ours + 4 - theirs) % 3;
+    u32::from(ours) + 1 + 3 * u32::from(outcome)
+}
+
+/// The total score over every round of the strategy guide, where `round` scores the opponent's shape along with
+/// the second column, both numbered 0 to 2.
+fn total_score(input: &str, round: impl Fn(u8, u8) -> u32) -> Result<u32> {
+    input
+        .lines()
+        .map(|line| {
+            let line = line.as_bytes();
+            ensure!(
+                line.len() == 3
+                    && (b'A'..=b'C').contains(&line[0])
+                    && (b'X'..=b'Z').contains(&line[2]),
+                "malformed round"
+            );
+            Ok(round(line[0] - b'A', line[2] - b'X'))
+        })
+        .sum()
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let guide = "A Y\nB X\nC Z";
+    assert_eq!(total_score(guide, score).unwrap(), 15);
+    assert_eq!(
+        total_score(guide, |theirs, column| score(
+            theirs,
+            (theirs + column + 2) % 3
+        ))
+        .unwrap(),
+        12
+    );
+}
@@ -0,0 +1,72 @@
+use eyre::{Result, bail, ensure};
+use itertools::Itertools;
+
+use aoc_meta::Problem;
+
+pub const RUCKSACK_REORGANIZATION: Problem = Problem::solved(
+    &|input| {
+        input
+            .lines()
+            .map(|line| {
+                let (first, second) = line.split_at(line.len() / 2);
+                common_priority(&[first, second])
+            })
+            .sum::<Result<u32>>()
+    },
+    &|input| {
+        input
+            .lines()
+            .chunks(3)
+            .into_iter()
+            .map(|group| common_priority(&group.collect_vec()))
+            .sum::<Result<u32>>()
+    },
+);
+
+/// The priority of the one item type that's in every one of `lists`.
+fn common_priority(lists: &[&str]) -> Result<u32> {
+    let mut common = u64::MAX;
+
+    for list in lists {
+        let items = list
+            .bytes()
+            .map(priority)
+            .try_fold(0u64, |items, priority| {
+                Ok::<_, eyre::Report>(items | 1 << priority?)
+            })?;
+        common &= items;
+    }
+
+    ensure!(
+        common.count_ones() == 1,
+        "expected exactly one item type in common"
+    );
+    Ok(common.trailing_zeros())
+}
+
+fn priority(item: u8) -> Result<u32> {
+    Ok(match item {
+        b'a'..=b'z' => u32::from(item - b'a') + 1,
+        b'A'..=b'Z' => u32::from(item - b'A') + 27,
+        _ => bail!("unknown item type {:?}", item as char),
+    })
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let rucksacks = [
+        "vJrwpWtwJgWrhcsFMMfFFhFp",
+        "jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL",
+        "PmmdzqPrVvPwwTWBwg",
+        "wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn",
+        "ttgJtRGJQctTZtZT",
+        "CrZsJsPPZsGzwwsLwLmpwMDw",
+    ];
+
+    let (first, second) = rucksacks[0].split_at(12);
+    assert_eq!(common_priority(&[first, second]).unwrap(), 16);
+    assert_eq!(common_priority(&rucksacks[..3]).unwrap(), 18);
+    assert_eq!(common_priority(&rucksacks[3..]).unwrap(), 52);
+}
@@ -0,0 +1,43 @@
+use std::ops::RangeInclusive;
+
+use eyre::Result;
+use winnow::combinator::separated_pair;
+use winnow::prelude::*;
+
+use aoc_common::parse::{self, unsigned};
+use aoc_meta::Problem;
+
+pub const CAMP_CLEANUP: Problem =
+    Problem::solved(&|input| count_pairs(input, one_contains_other), &|input| {
+        count_pairs(input, overlap)
+    });
+
+type Sections = RangeInclusive<u32>;
+
+fn one_contains_other(a: &Sections, b: &Sections) -> bool {
+    a.start() <= b.start() && b.end() <= a.end() || b.start() <= a.start() && a.end() <= b.end()
+}
+
+fn overlap(a: &Sections, b: &Sections) -> bool {
+    a.start() <= b.end() && b.start() <= a.end()
+}
+
+/// How many pairs of elves have assignments for which `condition` holds.
+fn count_pairs(input: &str, condition: fn(&Sections, &Sections) -> bool) -> Result<usize> {
+    let range = || separated_pair(unsigned, '-', unsigned).map(|(start, end)| start..=end);
+    let pair = separated_pair(range(), ',', range());
+
+    parse::lines(input, pair).try_fold(0, |count, pair| {
+        let (a, b) = pair?;
+        Ok(count + condition(&a, &b) as usize)
+    })
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let assignments = "2-4,6-8\n2-3,4-5\n5-7,7-9\n2-8,3-7\n6-6,4-6\n2-6,4-8";
+    assert_eq!(count_pairs(assignments, one_contains_other).unwrap(), 2);
+    assert_eq!(count_pairs(assignments, overlap).unwrap(), 4);
+}
@@ -0,0 +1,123 @@
+use eyre::{OptionExt, Result, ensure};
+use winnow::combinator::{preceded, seq};
+use winnow::prelude::*;
+
+use aoc_common::parse::{self, unsigned};
+use aoc_meta::Problem;
+
+pub const SUPPLY_STACKS: Problem =
+    Problem::solved(&|input| Ship::parse(input)?.rearrange(false), &|input| {
+        Ship::parse(input)?.rearrange(true)
+    });
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Move {
+    count: usize,
+    from: usize,
+    to: usize,
+}
+
+#[derive(Debug)]
+struct Ship {
+    /// The crates in each stack, from the bottom up.
+    stacks: Vec<Vec<char>>,
+    moves: Vec<Move>,
+}
+
+impl Ship {
+    /// Carry out the rearrangement, moving crates one at a time or, with the CrateMover 9001, several at once, and
+    /// return the crates that end up on top of each stack.
+    fn rearrange(mut self, all_at_once: bool) -> Result<String> {
+        for Move { count, from, to } in self.moves {
+            ensure!(
+                from != to && from < self.stacks.len() && to < self.stacks.len(),
+                "no such stack"
+            );
+
+            let height = self.stacks[from]
+                .len()
+                .checked_sub(count)
+                .ok_or_eyre("not enough crates to move")?;
+            let mut moved = self.stacks[from].split_off(height);
+            if !all_at_once {
+                moved.reverse();
+            }
+            self.stacks[to].extend(moved);
+        }
+
+        Ok(self
+            .stacks
+            .iter()
+            .filter_map(|stack| stack.last())
+            .collect())
+    }
+
+    fn parse(input: &str) -> Result<Self> {
+        let (drawing, moves) = input
+            .split_once("\n\n")
+            .ok_or_eyre("missing the rearrangement procedure")?;
+
+        let mut rows = drawing.lines().rev();
+        let count = rows
+            .next()
+            .ok_or_eyre("missing the stack numbers")?
+            .split_whitespace()
+            .count();
+        let mut stacks = vec![Vec::new(); count];
+
+        for row in rows {
+            for (stack, label) in row.chars().skip(1).step_by(4).enumerate() {
+                if label.is_ascii_alphabetic() {
+                    stacks
+                        .get_mut(stack)
+                        .ok_or_eyre("a crate is outside the stacks")?
+                        .push(label);
+                }
+            }
+        }
+
+        let step = seq! {Move {
+            count: preceded("move ", unsigned),
+            from: preceded(" from ", unsigned::<usize>).map(|n| n.wrapping_sub(1)),
+            to: preceded(" to ", unsigned::<usize>).map(|n| n.wrapping_sub(1)),
+        }};
+        let moves = parse::lines(moves, step).collect::<Result<_>>()?;
+
+        Ok(Self { stacks, moves })
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let example = "    [D]    
+[N] [C]    
+[Z] [M] [P]
+ 1   2   3 
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2";
+
+    let ship = Ship::parse(example).unwrap();
+    assert_eq!(
+        ship.stacks,
+        [vec!['Z', 'N'], vec!['M', 'C', 'D'], vec!['P']]
+    );
+    assert_eq!(
+        ship.moves[1],
+        Move {
+            count: 3,
+            from: 0,
+            to: 2
+        }
+    );
+
+    assert_eq!(ship.rearrange(false).unwrap(), "CMZ");
+    assert_eq!(
+        Ship::parse(example).unwrap().rearrange(true).unwrap(),
+        "MCD"
+    );
+}
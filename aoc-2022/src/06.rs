@@ -0,0 +1,42 @@
+use eyre::{OptionExt, Result};
+
+use aoc_meta::Problem;
+
+pub const TUNING_TROUBLE: Problem =
+    Problem::solved(&|input| end_of_marker(input.trim(), 4), &|input| {
+        end_of_marker(input.trim(), 14)
+    });
+
+/// How many characters have been received by the end of the first run of `len` different ones.
+fn end_of_marker(signal: &str, len: usize) -> Result<usize> {
+    signal
+        .as_bytes()
+        .windows(len)
+        .position(|window| {
+            window
+                .iter()
+                .fold(0u32, |seen, &c| seen | 1 << (c & 31))
+                .count_ones() as usize
+                == len
+        })
+        .map(|start| start + len)
+        .ok_or_eyre("the signal has no marker")
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let examples = [
+        ("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 7, 19),
+        ("bvwbjplbgvbhsrlpgdmjqwftvncz", 5, 23),
+        ("nppdvjthqldpwncqszvftbrmjlhg", 6, 23),
+        ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 10, 29),
+        ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 11, 26),
+    ];
+
+    for (signal, packet, message) in examples {
+        assert_eq!(end_of_marker(signal, 4).unwrap(), packet, "{signal}");
+        assert_eq!(end_of_marker(signal, 14).unwrap(), message, "{signal}");
+    }
+}
@@ -0,0 +1,90 @@
+use eyre::{OptionExt, Result, bail};
+
+use aoc_meta::Problem;
+
+pub const NO_SPACE_LEFT_ON_DEVICE: Problem = Problem::solved(
+    &|input| {
+        Ok::<_, eyre::Report>(
+            directory_sizes(input)?
+                .into_iter()
+                .filter(|&size| size <= 100_000)
+                .sum::<u64>(),
+        )
+    },
+    &|input| {
+        let sizes = directory_sizes(input)?;
+        let needed = (sizes[0] + 30_000_000).saturating_sub(70_000_000);
+        sizes
+            .into_iter()
+            .filter(|&size| size >= needed)
+            .min()
+            .ok_or_eyre("no directory is big enough")
+    },
+);
+
+/// The total size of every directory the terminal output explores, starting with the root.
+fn directory_sizes(output: &str) -> Result<Vec<u64>> {
+    let mut sizes = vec![0];
+    // The index in `sizes` of each directory from the root down to the current one.
+    let mut path = vec![0];
+
+    for line in output.lines() {
+        match line.split(' ').collect::<Vec<_>>()[..] {
+            ["$", "cd", "/"] => path.truncate(1),
+            ["$", "cd", ".."] => {
+                path.pop();
+                if path.is_empty() {
+                    bail!("the root has no parent");
+                }
+            }
+            ["$", "cd", _] => {
+                path.push(sizes.len());
+                sizes.push(0);
+            }
+            ["$", "ls"] | ["dir", _] => {}
+            [size, _] => {
+                let size: u64 = size.parse()?;
+                for &directory in &path {
+                    sizes[directory] += size;
+                }
+            }
+            _ => bail!("unexpected output {line:?}"),
+        }
+    }
+
+    Ok(sizes)
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let sizes = directory_sizes(
+        "$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd a
+$ ls
+dir e
+29116 f
+2557 g
+62596 h.lst
+$ cd e
+$ ls
+584 i
+$ cd ..
+$ cd ..
+$ cd d
+$ ls
+4060174 j
+8033020 d.log
+5626152 d.ext
+7214296 k",
+    )
+    .unwrap();
+
+    assert_eq!(sizes, [48381165, 94853, 584, 24933642]);
+}
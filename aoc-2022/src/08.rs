@@ -0,0 +1,76 @@
+use eyre::Result;
+
+use aoc_common::parse;
+use aoc_meta::Problem;
+
+pub const TREETOP_TREE_HOUSE: Problem = Problem::solved(
+    &|input| Forest::parse(input).map(|forest| forest.visible()),
+    &|input| Forest::parse(input).map(|forest| forest.best_scenic_score()),
+);
+
+#[derive(Debug)]
+struct Forest(Vec<Vec<u32>>);
+
+impl Forest {
+    /// The trees in each direction from `(x, y)`, nearest first.
+    fn lines_of_sight(&self, x: usize, y: usize) -> [Vec<u32>; 4] {
+        let row = &self.0[y];
+        let column: Vec<u32> = self.0.iter().map(|row| row[x]).collect();
+
+        [
+            row[..x].iter().rev().copied().collect(),
+            row[x + 1..].to_vec(),
+            column[..y].iter().rev().copied().collect(),
+            column[y + 1..].to_vec(),
+        ]
+    }
+
+    fn trees(&self) -> impl Iterator<Item = (usize, usize, u32)> + '_ {
+        self.0.iter().enumerate().flat_map(|(y, row)| {
+            row.iter()
+                .enumerate()
+                .map(move |(x, &height)| (x, y, height))
+        })
+    }
+
+    /// How many trees can be seen from outside the forest.
+    fn visible(&self) -> usize {
+        self.trees()
+            .filter(|&(x, y, height)| {
+                self.lines_of_sight(x, y)
+                    .iter()
+                    .any(|line| line.iter().all(|&other| other < height))
+            })
+            .count()
+    }
+
+    /// The highest product of how many trees can be seen from a tree in each direction.
+    fn best_scenic_score(&self) -> usize {
+        self.trees()
+            .map(|(x, y, height)| {
+                self.lines_of_sight(x, y)
+                    .iter()
+                    .map(|line| {
+                        line.iter()
+                            .position(|&other| other >= height)
+                            .map_or(line.len(), |i| i + 1)
+                    })
+                    .product()
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn parse(input: &str) -> Result<Self> {
+        parse::complete(input.trim_end(), parse::grid(|c| c.to_digit(10))).map(Self)
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let forest = Forest::parse("30373\n25512\n65332\n33549\n35390").unwrap();
+    assert_eq!(forest.visible(), 21);
+    assert_eq!(forest.best_scenic_score(), 8);
+}
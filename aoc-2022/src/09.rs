@@ -0,0 +1,61 @@
+use eyre::{OptionExt, Result, bail};
+use fnv::FnvHashSet;
+
+use aoc_meta::Problem;
+
+pub const ROPE_BRIDGE: Problem = Problem::solved(
+    &|input| parse_motions(input).map(|motions| tail_positions::<2>(&motions)),
+    &|input| parse_motions(input).map(|motions| tail_positions::<10>(&motions)),
+);
+
+/// How many positions the tail of a rope of `KNOTS` knots visits as the head makes `motions`.
+fn tail_positions<const KNOTS: usize>(motions: &[((i32, i32), u32)]) -> usize {
+    let mut knots = [(0i32, 0i32); KNOTS];
+    let mut visited = FnvHashSet::from_iter([(0, 0)]);
+
+    for &((dx, dy), steps) in motions {
+        for _ in 0..steps {
+            knots[0] = (knots[0].0 + dx, knots[0].1 + dy);
+
+            for i in 1..KNOTS {
+                let (x, y) = (knots[i - 1].0 - knots[i].0, knots[i - 1].1 - knots[i].1);
+                if x.abs() > 1 || y.abs() > 1 {
+                    knots[i] = (knots[i].0 + x.signum(), knots[i].1 + y.signum());
+                }
+            }
+
+            visited.insert(knots[KNOTS - 1]);
+        }
+    }
+
+    visited.len()
+}
+
+fn parse_motions(input: &str) -> Result<Vec<((i32, i32), u32)>> {
+    input
+        .lines()
+        .map(|line| {
+            let (direction, steps) = line.split_once(' ').ok_or_eyre("malformed motion")?;
+            let direction = match direction {
+                "R" => (1, 0),
+                "L" => (-1, 0),
+                "U" => (0, 1),
+                "D" => (0, -1),
+                _ => bail!("unknown direction {direction:?}"),
+            };
+            Ok((direction, steps.parse()?))
+        })
+        .collect()
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let motions = parse_motions("R 4\nU 4\nL 3\nD 1\nR 4\nD 1\nL 5\nR 2").unwrap();
+    assert_eq!(tail_positions::<2>(&motions), 13);
+    assert_eq!(tail_positions::<10>(&motions), 1);
+
+    let motions = parse_motions("R 5\nU 8\nL 8\nD 3\nR 17\nD 10\nL 25\nU 20").unwrap();
+    assert_eq!(tail_positions::<10>(&motions), 36);
+}
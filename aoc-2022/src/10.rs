@@ -0,0 +1,81 @@
+use eyre::{Result, bail};
+
+use aoc_common::ocr;
+use aoc_meta::Problem;
+
+pub const CATHODE_RAY_TUBE: Problem = Problem::solved(
+    &|input| {
+        let xs = register_values(input)?;
+        Ok::<_, eyre::Report>(
+            (20..=220)
+                .step_by(40)
+                .map(|cycle| cycle as i64 * xs[cycle - 1])
+                .sum::<i64>(),
+        )
+    },
+    &|input| ocr::read_grid(draw(&register_values(input)?)),
+);
+
+const WIDTH: usize = 40;
+const HEIGHT: usize = 6;
+
+/// The value of the X register during each of the screen's cycles, carrying on from the last value if the
+/// program finishes early.
+fn register_values(program: &str) -> Result<Vec<i64>> {
+    let mut xs = Vec::with_capacity(WIDTH * HEIGHT);
+    let mut x = 1;
+
+    for line in program.lines() {
+        match line.split_once(' ') {
+            None if line == "noop" => xs.push(x),
+            Some(("addx", value)) => {
+                xs.extend([x, x]);
+                x += value.parse::<i64>()?;
+            }
+            _ => bail!("unknown instruction {line:?}"),
+        }
+    }
+
+    xs.resize(xs.len().max(WIDTH * HEIGHT), x);
+    Ok(xs)
+}
+
+/// The screen, row by row: a pixel is lit if the three-pixel-wide sprite centred on X covers it as it's drawn.
+fn draw(xs: &[i64]) -> Vec<Vec<bool>> {
+    xs[..WIDTH * HEIGHT]
+        .chunks(WIDTH)
+        .map(|row| {
+            row.iter()
+                .enumerate()
+                .map(|(pixel, x)| (pixel as i64).abs_diff(*x) <= 1)
+                .collect()
+        })
+        .collect()
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let xs = register_values("noop\naddx 3\naddx -5").unwrap();
+    assert_eq!(xs[..6], [1, 1, 1, 4, 4, -1]);
+
+    // Keep the sprite over a pixel to light it, and well away from it otherwise.
+    let message = [
+        "#..#.###..###.",
+        "#..#..#...#..#",
+        "####..#...#..#",
+        "#..#..#...###.",
+        "#..#..#...#...",
+        "#..#.###..#...",
+    ];
+    let xs: Vec<i64> = (0..WIDTH * HEIGHT)
+        .map(|cycle| {
+            let (pixel, row) = (cycle % WIDTH, cycle / WIDTH);
+            let lit = message[row].as_bytes().get(pixel) == Some(&b'#');
+            if lit { pixel as i64 } else { pixel as i64 + 3 }
+        })
+        .collect();
+
+    assert_eq!(ocr::read_grid(draw(&xs)).unwrap(), "HIP");
+}
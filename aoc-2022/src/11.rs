@@ -0,0 +1,143 @@
+use eyre::{Result, ensure};
+use winnow::ascii::line_ending;
+use winnow::combinator::{alt, delimited, preceded, separated, seq};
+use winnow::prelude::*;
+
+use aoc_common::parse::{self, unsigned};
+use aoc_meta::Problem;
+
+pub const MONKEY_IN_THE_MIDDLE: Problem = Problem::solved(
+    &|input| monkey_business(parse_monkeys(input)?, 20, true),
+    &|input| monkey_business(parse_monkeys(input)?, 10_000, false),
+);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operation {
+    Add(u64),
+    Multiply(u64),
+    Square,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Monkey {
+    items: Vec<u64>,
+    operation: Operation,
+    divisor: u64,
+    /// The monkeys to throw to when the test passes and when it fails.
+    targets: [usize; 2],
+}
+
+/// The product of how many items the two busiest monkeys inspect over `rounds` rounds, with worry levels divided
+/// by three after each inspection if there's `relief`.
+fn monkey_business(mut monkeys: Vec<Monkey>, rounds: usize, relief: bool) -> Result<u64> {
+    ensure!(
+        monkeys
+            .iter()
+            .all(|monkey| monkey.targets.iter().all(|&target| target < monkeys.len())),
+        "a monkey throws to a monkey that doesn't exist"
+    );
+
+    // Every test still gives the same answer if worry levels are reduced modulo all the divisors.
+    let modulus: u64 = monkeys.iter().map(|monkey| monkey.divisor).product();
+    let mut inspected = vec![0; monkeys.len()];
+
+    for _ in 0..rounds {
+        for i in 0..monkeys.len() {
+            let items = std::mem::take(&mut monkeys[i].items);
+            inspected[i] += items.len() as u64;
+
+            for worry in items {
+                let Monkey {
+                    operation,
+                    divisor,
+                    targets,
+                    ..
+                } = monkeys[i];
+                let mut worry = match operation {
+                    Operation::Add(n) => worry + n,
+                    Operation::Multiply(n) => worry * n,
+                    Operation::Square => worry * worry,
+                };
+                if relief {
+                    worry /= 3;
+                }
+                worry %= modulus;
+
+                let target = targets[usize::from(worry % divisor != 0)];
+                monkeys[target].items.push(worry);
+            }
+        }
+    }
+
+    inspected.sort_unstable_by(|a, b| b.cmp(a));
+    Ok(inspected.iter().take(2).product())
+}
+
+fn monkey(input: &mut &str) -> ModalResult<Monkey> {
+    seq! {Monkey {
+        _: delimited("Monkey ", unsigned::<usize>, (':', line_ending)),
+        items: delimited("  Starting items: ", separated(1.., unsigned::<u64>, ", "), line_ending),
+        operation: delimited(
+            "  Operation: new = old ",
+            alt((
+                "* old".value(Operation::Square),
+                preceded("* ", unsigned).map(Operation::Multiply),
+                preceded("+ ", unsigned).map(Operation::Add),
+            )),
+            line_ending,
+        ),
+        divisor: delimited("  Test: divisible by ", unsigned, line_ending),
+        targets: (
+            delimited("    If true: throw to monkey ", unsigned, line_ending),
+            preceded("    If false: throw to monkey ", unsigned),
+        )
+            .map(|(passed, failed): (usize, usize)| [passed, failed]),
+    }}
+    .parse_next(input)
+}
+
+fn parse_monkeys(input: &str) -> Result<Vec<Monkey>> {
+    parse::sections(input.trim_end(), monkey).collect()
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let monkeys = parse_monkeys(
+        "Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 2
+    If false: throw to monkey 3
+
+Monkey 1:
+  Starting items: 54, 65, 75, 74
+  Operation: new = old + 6
+  Test: divisible by 19
+    If true: throw to monkey 2
+    If false: throw to monkey 0
+
+Monkey 2:
+  Starting items: 79, 60, 97
+  Operation: new = old * old
+  Test: divisible by 13
+    If true: throw to monkey 1
+    If false: throw to monkey 3
+
+Monkey 3:
+  Starting items: 74
+  Operation: new = old + 3
+  Test: divisible by 17
+    If true: throw to monkey 0
+    If false: throw to monkey 1",
+    )
+    .unwrap();
+
+    assert_eq!(monkeys[2].operation, Operation::Square);
+    assert_eq!(monkeys[3].targets, [0, 1]);
+
+    assert_eq!(monkey_business(monkeys.clone(), 20, true).unwrap(), 10605);
+    assert_eq!(monkey_business(monkeys, 10_000, false).unwrap(), 2713310158);
+}
@@ -0,0 +1,117 @@
+use eyre::{OptionExt, Result, ensure};
+
+use aoc_common::search::{SearchSpace, bfs};
+use aoc_meta::Problem;
+
+pub const HILL_CLIMBING_ALGORITHM: Problem = Problem::solved(
+    &|input| {
+        Heightmap::parse(input)?
+            .fewest_steps(false)
+            .ok_or_eyre("the best signal can't be reached")
+    },
+    &|input| {
+        Heightmap::parse(input)?
+            .fewest_steps(true)
+            .ok_or_eyre("the best signal can't be reached")
+    },
+);
+
+#[derive(Debug)]
+struct Heightmap {
+    width: usize,
+    elevations: Vec<u8>,
+    start: usize,
+    end: usize,
+}
+
+impl Heightmap {
+    /// The fewest steps it takes to get to the location with the best signal, from the start or, if
+    /// `from_any_low_point`, from whichever location at the lowest elevation is closest.
+    fn fewest_steps(&self, from_any_low_point: bool) -> Option<usize> {
+        // Searching backwards from the end finds the closest of many starting points at once.
+        let descent = Descent {
+            map: self,
+            from_any_low_point,
+        };
+        bfs(&descent, self.end)
+            .find(|(location, _)| descent.is_goal(location))
+            .map(|(_, steps)| steps)
+    }
+
+    fn parse(input: &str) -> Result<Self> {
+        let width = input.lines().next().map_or(0, str::len);
+        ensure!(
+            width > 0 && input.lines().all(|line| line.len() == width),
+            "the heightmap should be a rectangle"
+        );
+
+        let mut elevations: Vec<u8> = input.lines().flat_map(str::bytes).collect();
+        let start = elevations
+            .iter()
+            .position(|&b| b == b'S')
+            .ok_or_eyre("missing the start")?;
+        let end = elevations
+            .iter()
+            .position(|&b| b == b'E')
+            .ok_or_eyre("missing the location with the best signal")?;
+        elevations[start] = b'a';
+        elevations[end] = b'z';
+
+        ensure!(
+            elevations.iter().all(u8::is_ascii_lowercase),
+            "elevations run from a to z"
+        );
+        Ok(Self {
+            width,
+            elevations,
+            start,
+            end,
+        })
+    }
+}
+
+/// Walking the heightmap backwards, so each step can go up at most one or down any distance.
+struct Descent<'a> {
+    map: &'a Heightmap,
+    from_any_low_point: bool,
+}
+
+impl SearchSpace for Descent<'_> {
+    type State = usize;
+    type Cost = usize;
+
+    fn successors(&self, &location: &usize) -> impl IntoIterator<Item = (usize, usize)> {
+        let Heightmap {
+            width, elevations, ..
+        } = self.map;
+        let (x, y) = (location % width, location / width);
+
+        [
+            (x > 0).then(|| location - 1),
+            (x + 1 < *width).then(|| location + 1),
+            (y > 0).then(|| location - width),
+            (location + width < elevations.len()).then(|| location + width),
+        ]
+        .into_iter()
+        .flatten()
+        .filter(move |&next| elevations[location] <= elevations[next] + 1)
+        .map(|next| (next, 1))
+    }
+
+    fn is_goal(&self, &location: &usize) -> bool {
+        if self.from_any_low_point {
+            self.map.elevations[location] == b'a'
+        } else {
+            location == self.map.start
+        }
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let map = Heightmap::parse("Sabqponm\nabcryxxl\naccszExk\nacctuvwj\nabdefghi").unwrap();
+    assert_eq!(map.fewest_steps(false), Some(31));
+    assert_eq!(map.fewest_steps(true), Some(29));
+}
@@ -0,0 +1,122 @@
+use std::cmp::Ordering;
+
+use eyre::Result;
+use winnow::combinator::{alt, delimited, separated, separated_pair};
+use winnow::prelude::*;
+
+use aoc_common::parse::{self, unsigned};
+use aoc_meta::Problem;
+
+pub const DISTRESS_SIGNAL: Problem = Problem::solved(
+    &|input| {
+        let pairs = parse_pairs(input)?;
+        Ok::<_, eyre::Report>(
+            pairs
+                .iter()
+                .enumerate()
+                .filter(|(_, (left, right))| left < right)
+                .map(|(i, _)| i + 1)
+                .sum::<usize>(),
+        )
+    },
+    &|input| {
+        parse_pairs(input)
+            .map(|pairs| decoder_key(pairs.into_iter().flat_map(|(left, right)| [left, right])))
+    },
+);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Packet {
+    Integer(u32),
+    List(Vec<Packet>),
+}
+
+impl Ord for Packet {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Packet::Integer(a), Packet::Integer(b)) => a.cmp(b),
+            (Packet::List(a), Packet::List(b)) => a.cmp(b),
+            (Packet::Integer(a), Packet::List(b)) => [Packet::Integer(*a)][..].cmp(b),
+            (Packet::List(a), Packet::Integer(b)) => a[..].cmp(&[Packet::Integer(*b)]),
+        }
+    }
+}
+
+impl PartialOrd for Packet {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// The product of where the divider packets `[[2]]` and `[[6]]` end up, counting from 1, once they're sorted
+/// along with every other packet. Only the packets before each divider matter, so nothing needs sorting.
+fn decoder_key(packets: impl IntoIterator<Item = Packet>) -> usize {
+    let divider = |n| Packet::List(vec![Packet::List(vec![Packet::Integer(n)])]);
+    let (two, six) = (divider(2), divider(6));
+
+    let (mut before_two, mut before_six) = (1, 2);
+    for packet in packets {
+        if packet < two {
+            before_two += 1;
+            before_six += 1;
+        } else if packet < six {
+            before_six += 1;
+        }
+    }
+
+    before_two * before_six
+}
+
+fn packet(input: &mut &str) -> ModalResult<Packet> {
+    alt((
+        unsigned.map(Packet::Integer),
+        delimited('[', separated(0.., packet, ','), ']').map(Packet::List),
+    ))
+    .parse_next(input)
+}
+
+fn parse_pairs(input: &str) -> Result<Vec<(Packet, Packet)>> {
+    parse::sections(input.trim_end(), separated_pair(packet, '\n', packet)).collect()
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let pairs = parse_pairs(
+        "[1,1,3,1,1]
+[1,1,5,1,1]
+
+[[1],[2,3,4]]
+[[1],4]
+
+[9]
+[[8,7,6]]
+
+[[4,4],4,4]
+[[4,4],4,4,4]
+
+[7,7,7,7]
+[7,7,7]
+
+[]
+[3]
+
+[[[]]]
+[[]]
+
+[1,[2,[3,[4,[5,6,7]]]],8,9]
+[1,[2,[3,[4,[5,6,0]]]],8,9]",
+    )
+    .unwrap();
+
+    let ordered: Vec<bool> = pairs.iter().map(|(left, right)| left < right).collect();
+    assert_eq!(
+        ordered,
+        [true, true, false, true, false, true, false, false]
+    );
+    assert_eq!(
+        decoder_key(pairs.into_iter().flat_map(|(left, right)| [left, right])),
+        140
+    );
+}
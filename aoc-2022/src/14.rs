@@ -0,0 +1,94 @@
+use eyre::{OptionExt, Result};
+use winnow::combinator::{separated, separated_pair};
+
+use aoc_common::parse::{self, unsigned};
+use aoc_meta::Problem;
+
+pub const REGOLITH_RESERVOIR: Problem = Problem::solved(
+    &|input| Cave::parse(input).map(|cave| cave.fill(false)),
+    &|input| Cave::parse(input).map(|cave| cave.fill(true)),
+);
+
+const SOURCE: (usize, usize) = (500, 0);
+
+#[derive(Debug, Clone)]
+struct Cave {
+    width: usize,
+    /// Whether each tile is blocked by rock or sand, row by row down to the floor.
+    blocked: Vec<bool>,
+}
+
+impl Cave {
+    /// How many units of sand come to rest before sand starts falling into the abyss or, if there's a `floor`
+    /// two below the lowest rock, before the source is blocked.
+    fn fill(mut self, floor: bool) -> usize {
+        let height = self.blocked.len() / self.width;
+        let mut rested = 0;
+        // The path the last unit of sand took, which the next one follows until it's blocked.
+        let mut path = vec![SOURCE];
+
+        while let Some(&(x, y)) = path.last() {
+            if y + 1 == height {
+                if !floor {
+                    break;
+                }
+            } else if let Some(next) = [x, x - 1, x + 1]
+                .into_iter()
+                .find(|&next| !self.blocked[(y + 1) * self.width + next])
+            {
+                path.push((next, y + 1));
+                continue;
+            }
+
+            self.blocked[y * self.width + x] = true;
+            rested += 1;
+            path.pop();
+        }
+
+        rested
+    }
+
+    fn parse(input: &str) -> Result<Self> {
+        let path = separated(
+            1..,
+            separated_pair(unsigned::<usize>, ',', unsigned::<usize>),
+            " -> ",
+        );
+        let paths: Vec<Vec<(usize, usize)>> = parse::lines(input, path).collect::<Result<_>>()?;
+
+        let lowest = paths
+            .iter()
+            .flatten()
+            .map(|&(_, y)| y)
+            .max()
+            .ok_or_eyre("there's no rock")?;
+        // Sand resting on the floor can't spread further sideways than it falls.
+        let height = lowest + 2;
+        let width = SOURCE.0 + height + 1;
+        let mut blocked = vec![false; width * height];
+
+        for path in &paths {
+            for pair in path.windows(2) {
+                let [(x1, y1), (x2, y2)] = [pair[0], pair[1]];
+                for y in y1.min(y2)..=y1.max(y2) {
+                    for x in x1.min(x2)..=x1.max(x2) {
+                        *blocked
+                            .get_mut(y * width + x)
+                            .ok_or_eyre("rock is too far to the right")? = true;
+                    }
+                }
+            }
+        }
+
+        Ok(Self { width, blocked })
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let cave = Cave::parse("498,4 -> 498,6 -> 496,6\n503,4 -> 502,4 -> 502,9 -> 494,9").unwrap();
+    assert_eq!(cave.clone().fill(false), 24);
+    assert_eq!(cave.fill(true), 93);
+}
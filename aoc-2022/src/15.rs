@@ -0,0 +1,144 @@
+use eyre::{OptionExt, Result};
+use itertools::Itertools;
+use winnow::combinator::{preceded, seq};
+use winnow::prelude::*;
+
+use aoc_common::parse::{self, signed};
+use aoc_meta::Problem;
+
+pub const BEACON_EXCLUSION_ZONE: Problem = Problem::solved(
+    &|input| parse_sensors(input).map(|sensors| ruled_out(&sensors, 2_000_000)),
+    &|input| {
+        tuning_frequency(&parse_sensors(input)?, 4_000_000)
+            .ok_or_eyre("the distress beacon can't be found")
+    },
+);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Sensor {
+    position: (i64, i64),
+    beacon: (i64, i64),
+}
+
+impl Sensor {
+    /// How far the sensor can see: no other beacon is this close to it.
+    fn range(&self) -> i64 {
+        distance(self.position, self.beacon)
+    }
+}
+
+fn distance(a: (i64, i64), b: (i64, i64)) -> i64 {
+    (a.0 - b.0).abs() + (a.1 - b.1).abs()
+}
+
+/// How many positions in row `y` can't hold a beacon.
+fn ruled_out(sensors: &[Sensor], y: i64) -> usize {
+    let ranges = sensors
+        .iter()
+        .filter_map(|sensor| {
+            let reach = sensor.range() - (sensor.position.1 - y).abs();
+            (reach >= 0).then_some((sensor.position.0 - reach, sensor.position.0 + reach))
+        })
+        .sorted_unstable();
+
+    let mut covered = 0;
+    let mut end = i64::MIN;
+    for (start, stop) in ranges {
+        if stop > end {
+            covered += stop - start.max(end + 1) + 1;
+            end = stop;
+        }
+    }
+
+    let beacons = sensors
+        .iter()
+        .map(|sensor| sensor.beacon)
+        .filter(|beacon| beacon.1 == y)
+        .unique()
+        .count();
+    covered as usize - beacons
+}
+
+/// The tuning frequency of the only position with coordinates between 0 and `limit` that no sensor can see.
+///
+/// That position must be just out of range of several sensors, so it lies on the intersection of a line just
+/// beyond the edge of one sensor's range going one way with one just beyond another's going the other way.
+fn tuning_frequency(sensors: &[Sensor], limit: i64) -> Option<i64> {
+    // Lines x + y = a and x - y = b just outside each sensor's range.
+    let rising: Vec<i64> = sensors
+        .iter()
+        .flat_map(|s| {
+            [
+                s.position.0 + s.position.1 - s.range() - 1,
+                s.position.0 + s.position.1 + s.range() + 1,
+            ]
+        })
+        .collect();
+    let falling: Vec<i64> = sensors
+        .iter()
+        .flat_map(|s| {
+            [
+                s.position.0 - s.position.1 - s.range() - 1,
+                s.position.0 - s.position.1 + s.range() + 1,
+            ]
+        })
+        .collect();
+
+    rising
+        .iter()
+        .cartesian_product(&falling)
+        .filter(|&(a, b)| (a + b) % 2 == 0)
+        .map(|(a, b)| ((a + b) / 2, (a - b) / 2))
+        .filter(|&(x, y)| (0..=limit).contains(&x) && (0..=limit).contains(&y))
+        .find(|&point| {
+            sensors
+                .iter()
+                .all(|sensor| distance(sensor.position, point) > sensor.range())
+        })
+        .map(|(x, y)| x * 4_000_000 + y)
+}
+
+fn sensor(input: &mut &str) -> ModalResult<Sensor> {
+    seq! {Sensor {
+        position: (preceded("Sensor at x=", signed), preceded(", y=", signed)),
+        beacon: (preceded(": closest beacon is at x=", signed), preceded(", y=", signed)),
+    }}
+    .parse_next(input)
+}
+
+fn parse_sensors(input: &str) -> Result<Vec<Sensor>> {
+    parse::lines(input, sensor).collect()
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let sensors = parse_sensors(
+        "Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+Sensor at x=9, y=16: closest beacon is at x=10, y=16
+Sensor at x=13, y=2: closest beacon is at x=15, y=3
+Sensor at x=12, y=14: closest beacon is at x=10, y=16
+Sensor at x=10, y=20: closest beacon is at x=10, y=16
+Sensor at x=14, y=17: closest beacon is at x=10, y=16
+Sensor at x=8, y=7: closest beacon is at x=2, y=10
+Sensor at x=2, y=0: closest beacon is at x=2, y=10
+Sensor at x=0, y=11: closest beacon is at x=2, y=10
+Sensor at x=20, y=14: closest beacon is at x=25, y=17
+Sensor at x=17, y=20: closest beacon is at x=21, y=22
+Sensor at x=16, y=7: closest beacon is at x=15, y=3
+Sensor at x=14, y=3: closest beacon is at x=15, y=3
+Sensor at x=20, y=1: closest beacon is at x=15, y=3",
+    )
+    .unwrap();
+
+    assert_eq!(
+        sensors[6],
+        Sensor {
+            position: (8, 7),
+            beacon: (2, 10)
+        }
+    );
+    assert_eq!(ruled_out(&sensors, 10), 26);
+    assert_eq!(tuning_frequency(&sensors, 20), Some(56000011));
+}
@@ -0,0 +1,236 @@
+use eyre::{OptionExt, Result, ensure};
+use fnv::FnvHashMap;
+use winnow::ascii::alpha1;
+use winnow::combinator::{alt, preceded, separated, seq};
+use winnow::prelude::*;
+
+use aoc_common::parse::{self, unsigned};
+use aoc_common::search::{SearchSpace, bfs};
+use aoc_meta::Problem;
+
+pub const PROBOSCIDEA_VOLCANIUM: Problem = Problem::solved(
+    &|input| Volcano::parse(input).map(|volcano| volcano.most_pressure(30)),
+    &|input| Volcano::parse(input).map(|volcano| volcano.most_pressure_with_elephant(26)),
+);
+
+/// The valves worth opening, and how long it takes to walk between them.
+#[derive(Debug)]
+struct Volcano {
+    flow_rates: Vec<u32>,
+    /// Minutes from each useful valve to each other one.
+    distances: Vec<Vec<u32>>,
+    /// Minutes from the starting valve to each useful one.
+    from_start: Vec<u32>,
+}
+
+/// Somewhere to be partway through opening valves: at valve `at` (or the start), with `time` minutes left, having
+/// opened the valves in `opened` for a total release of `released` by the end.
+#[derive(Debug, Clone, Copy)]
+struct Position {
+    at: Option<usize>,
+    time: u32,
+    opened: u64,
+    released: u32,
+}
+
+impl Volcano {
+    /// The valves it's worth going to next from `position`, with what would be released by the end if they were.
+    fn moves(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        (0..self.flow_rates.len())
+            .filter(move |&valve| position.opened >> valve & 1 == 0)
+            .filter_map(move |valve| {
+                let distance = position
+                    .at
+                    .map_or(self.from_start[valve], |at| self.distances[at][valve]);
+                let time = position
+                    .time
+                    .checked_sub(distance + 1)
+                    .filter(|&time| time > 0)?;
+
+                Some(Position {
+                    at: Some(valve),
+                    time,
+                    opened: position.opened | 1 << valve,
+                    released: position.released + time * self.flow_rates[valve],
+                })
+            })
+    }
+
+    /// The most pressure that could possibly be released from `position`, if every closed valve were only two
+    /// minutes away from the next, opening the best ones first.
+    fn upper_bound(&self, position: Position, by_flow: &[usize]) -> u32 {
+        let closed = by_flow
+            .iter()
+            .filter(|&&valve| position.opened >> valve & 1 == 0);
+        let times = (1..position.time).rev().step_by(2);
+        position.released
+            + closed
+                .zip(times)
+                .map(|(&valve, time)| time * self.flow_rates[valve])
+                .sum::<u32>()
+    }
+
+    /// The most pressure one person can release in `time` minutes, searching depth first and skipping any branch
+    /// that can't beat the best found so far.
+    fn most_pressure(&self, time: u32) -> u32 {
+        let mut by_flow: Vec<usize> = (0..self.flow_rates.len()).collect();
+        by_flow.sort_unstable_by_key(|&valve| std::cmp::Reverse(self.flow_rates[valve]));
+
+        let mut best = 0;
+        let mut stack = vec![Position {
+            at: None,
+            time,
+            opened: 0,
+            released: 0,
+        }];
+
+        while let Some(position) = stack.pop() {
+            best = best.max(position.released);
+            stack.extend(
+                self.moves(position)
+                    .filter(|&next| self.upper_bound(next, &by_flow) > best),
+            );
+        }
+
+        best
+    }
+
+    /// The most pressure two people working at the same time can release in `time` minutes. They open disjoint
+    /// sets of valves, so it's the best combination of the most one person can release opening each set.
+    fn most_pressure_with_elephant(&self, time: u32) -> u32 {
+        let valves = self.flow_rates.len();
+        let mut best = vec![0; 1 << valves];
+        let mut stack = vec![Position {
+            at: None,
+            time,
+            opened: 0,
+            released: 0,
+        }];
+
+        while let Some(position) = stack.pop() {
+            let best = &mut best[position.opened as usize];
+            *best = (*best).max(position.released);
+            stack.extend(self.moves(position));
+        }
+
+        // Widen each entry to the best release opening any subset of its valves.
+        for valve in 0..valves {
+            for opened in 0..best.len() {
+                if opened >> valve & 1 == 1 {
+                    best[opened] = best[opened].max(best[opened ^ 1 << valve]);
+                }
+            }
+        }
+
+        let all = best.len() - 1;
+        (0..best.len())
+            .map(|opened| best[opened] + best[all ^ opened])
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn parse(input: &str) -> Result<Self> {
+        let scan: Vec<(&str, u32, Vec<&str>)> =
+            parse::lines(input, valve).collect::<Result<_>>()?;
+        let indices: FnvHashMap<&str, usize> = scan
+            .iter()
+            .enumerate()
+            .map(|(i, &(name, ..))| (name, i))
+            .collect();
+
+        let tunnels = scan
+            .iter()
+            .map(|(_, _, tunnels)| {
+                tunnels
+                    .iter()
+                    .map(|name| {
+                        indices
+                            .get(name)
+                            .copied()
+                            .ok_or_eyre("a tunnel leads nowhere")
+                    })
+                    .collect()
+            })
+            .collect::<Result<Vec<Vec<usize>>>>()?;
+
+        let useful: Vec<usize> = (0..scan.len()).filter(|&i| scan[i].1 > 0).collect();
+        ensure!(
+            useful.len() <= 20,
+            "there are too many valves worth opening"
+        );
+
+        let start = *indices
+            .get("AA")
+            .ok_or_eyre("missing the starting valve AA")?;
+        let distances_from = |from: usize| {
+            let distances: FnvHashMap<usize, usize> = bfs(&Tunnels(&tunnels), from).collect();
+            useful
+                .iter()
+                .map(|valve| {
+                    distances
+                        .get(valve)
+                        .map(|&d| d as u32)
+                        .unwrap_or(u32::MAX / 2)
+                })
+                .collect()
+        };
+
+        Ok(Self {
+            flow_rates: useful.iter().map(|&valve| scan[valve].1).collect(),
+            distances: useful.iter().map(|&valve| distances_from(valve)).collect(),
+            from_start: distances_from(start),
+        })
+    }
+}
+
+struct Tunnels<'a>(&'a [Vec<usize>]);
+
+impl SearchSpace for Tunnels<'_> {
+    type State = usize;
+    type Cost = usize;
+
+    fn successors(&self, &valve: &usize) -> impl IntoIterator<Item = (usize, usize)> {
+        self.0[valve].iter().map(|&next| (next, 1))
+    }
+
+    fn is_goal(&self, _: &usize) -> bool {
+        false
+    }
+}
+
+fn valve<'s>(input: &mut &'s str) -> ModalResult<(&'s str, u32, Vec<&'s str>)> {
+    seq!(
+        preceded("Valve ", alpha1),
+        preceded(" has flow rate=", unsigned),
+        preceded(
+            alt(("; tunnels lead to valves ", "; tunnel leads to valve ")),
+            separated(1.., alpha1, ", ")
+        ),
+    )
+    .parse_next(input)
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let volcano = Volcano::parse(
+        "Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
+Valve BB has flow rate=13; tunnels lead to valves CC, AA
+Valve CC has flow rate=2; tunnels lead to valves DD, BB
+Valve DD has flow rate=20; tunnels lead to valves CC, AA, EE
+Valve EE has flow rate=3; tunnels lead to valves FF, DD
+Valve FF has flow rate=0; tunnels lead to valves EE, GG
+Valve GG has flow rate=0; tunnels lead to valves FF, HH
+Valve HH has flow rate=22; tunnel leads to valve GG
+Valve II has flow rate=0; tunnels lead to valves AA, JJ
+Valve JJ has flow rate=21; tunnel leads to valve II",
+    )
+    .unwrap();
+
+    assert_eq!(volcano.flow_rates, [13, 2, 20, 3, 22, 21]);
+    assert_eq!(volcano.from_start, [1, 2, 1, 2, 5, 2]);
+
+    assert_eq!(volcano.most_pressure(30), 1651);
+    assert_eq!(volcano.most_pressure_with_elephant(26), 1707);
+}
@@ -0,0 +1,129 @@
+use eyre::{OptionExt, Result, bail, ensure};
+
+use aoc_common::cycle;
+use aoc_meta::Problem;
+
+pub const PYROCLASTIC_FLOW: Problem =
+    Problem::solved(&|input| tower_height(&parse_jets(input)?, 2022), &|input| {
+        tower_height(&parse_jets(input)?, 1_000_000_000_000)
+    });
+
+/// Each rock's rows from the bottom up, as bitmasks with the leftmost column in the lowest bit.
+const ROCKS: [&[u8]; 5] = [
+    &[0b1111],
+    &[0b010, 0b111, 0b010],
+    &[0b111, 0b100, 0b100],
+    &[1, 1, 1, 1],
+    &[0b11, 0b11],
+];
+
+const WIDTH: u32 = 7;
+
+/// How many rows from the top of the tower are taken as its shape when looking for repetition. Rocks rarely fall
+/// much further than this past the top.
+const SURFACE: usize = 32;
+
+/// The tower of rocks in the chamber, as the occupied columns of each row from the floor up.
+#[derive(Debug, Default)]
+struct Chamber {
+    rows: Vec<u8>,
+    jet: usize,
+}
+
+impl Chamber {
+    fn collides(&self, rock: &[u8], shift: u32, y: usize) -> bool {
+        rock.iter().enumerate().any(|(dy, &row)| {
+            let row = row << shift;
+            row >> WIDTH != 0
+                || self
+                    .rows
+                    .get(y + dy)
+                    .is_some_and(|&occupied| occupied & row != 0)
+        })
+    }
+
+    /// Drop a rock, pushed by the jets as it falls, returning how much taller the tower got.
+    fn drop(&mut self, rock: &[u8], jets: &[i8]) -> usize {
+        let height = self.rows.len();
+        let (mut shift, mut y) = (2u32, height + 3);
+
+        loop {
+            let jet = jets[self.jet];
+            self.jet = (self.jet + 1) % jets.len();
+
+            if let Some(pushed) = shift.checked_add_signed(jet.into())
+                && !self.collides(rock, pushed, y)
+            {
+                shift = pushed;
+            }
+
+            if y == 0 || self.collides(rock, shift, y - 1) {
+                break;
+            }
+            y -= 1;
+        }
+
+        for (dy, &row) in rock.iter().enumerate() {
+            if self.rows.len() <= y + dy {
+                self.rows.push(0);
+            }
+            self.rows[y + dy] |= row << shift;
+        }
+
+        self.rows.len() - height
+    }
+
+    fn surface(&self) -> [u8; SURFACE] {
+        std::array::from_fn(|i| {
+            self.rows
+                .len()
+                .checked_sub(i + 1)
+                .map_or(0xFF, |y| self.rows[y])
+        })
+    }
+}
+
+/// How tall the tower is after `rocks` rocks have fallen. The tower soon starts growing the same way over and
+/// over, so only enough rocks to see that happen are dropped.
+fn tower_height(jets: &[i8], rocks: usize) -> Result<usize> {
+    let mut chamber = Chamber::default();
+
+    // Each rock's shape, the next jet and the top of the tower before it falls, along with how much it adds.
+    let drops = (0..).map(|rock| {
+        let key = (rock % ROCKS.len(), chamber.jet, chamber.surface());
+        (key, chamber.drop(ROCKS[rock % ROCKS.len()], jets))
+    });
+
+    let history = cycle::history_by_key(drops, rocks.min(1_000_000), |&(key, _)| key);
+    history
+        .sum_first(rocks, |&(_, growth)| growth)
+        .ok_or_eyre("the tower never settles into a pattern")
+}
+
+fn parse_jets(input: &str) -> Result<Vec<i8>> {
+    let jets = input
+        .trim()
+        .chars()
+        .map(|c| match c {
+            '<' => Ok(-1),
+            '>' => Ok(1),
+            _ => bail!("unexpected jet {c:?}"),
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    ensure!(!jets.is_empty(), "there are no jets");
+    Ok(jets)
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let jets = parse_jets(">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>").unwrap();
+    assert_eq!(tower_height(&jets, 10).unwrap(), 17);
+    assert_eq!(tower_height(&jets, 2022).unwrap(), 3068);
+    assert_eq!(
+        tower_height(&jets, 1_000_000_000_000).unwrap(),
+        1514285714288
+    );
+}
@@ -0,0 +1,97 @@
+use eyre::{Result, ensure};
+use fnv::FnvHashSet;
+
+use aoc_meta::Problem;
+
+pub const BOILING_BOULDERS: Problem = Problem::solved(
+    &|input| parse_cubes(input).map(|cubes| surface_area(&cubes)),
+    &|input| parse_cubes(input).map(|cubes| exterior_surface_area(&cubes)),
+);
+
+type Cube = [i32; 3];
+
+fn neighbours([x, y, z]: Cube) -> [Cube; 6] {
+    [
+        [x - 1, y, z],
+        [x + 1, y, z],
+        [x, y - 1, z],
+        [x, y + 1, z],
+        [x, y, z - 1],
+        [x, y, z + 1],
+    ]
+}
+
+/// How many faces of the cubes aren't touching another cube.
+fn surface_area(cubes: &FnvHashSet<Cube>) -> usize {
+    cubes
+        .iter()
+        .flat_map(|&cube| neighbours(cube))
+        .filter(|neighbour| !cubes.contains(neighbour))
+        .count()
+}
+
+/// How many faces of the cubes the steam can reach from outside, flooding in around them from a box just bigger
+/// than the droplet.
+fn exterior_surface_area(cubes: &FnvHashSet<Cube>) -> usize {
+    let low = [0, 1, 2].map(|axis| {
+        cubes
+            .iter()
+            .map(|cube| cube[axis])
+            .min()
+            .unwrap_or_default()
+            - 1
+    });
+    let high = [0, 1, 2].map(|axis| {
+        cubes
+            .iter()
+            .map(|cube| cube[axis])
+            .max()
+            .unwrap_or_default()
+            + 1
+    });
+
+    let mut steam = FnvHashSet::from_iter([low]);
+    let mut stack = vec![low];
+    let mut faces = 0;
+
+    while let Some(air) = stack.pop() {
+        for next in neighbours(air) {
+            if (0..3).any(|axis| !(low[axis]..=high[axis]).contains(&next[axis])) {
+                continue;
+            }
+
+            if cubes.contains(&next) {
+                faces += 1;
+            } else if steam.insert(next) {
+                stack.push(next);
+            }
+        }
+    }
+
+    faces
+}
+
+fn parse_cubes(input: &str) -> Result<FnvHashSet<Cube>> {
+    input
+        .lines()
+        .map(|line| {
+            let coordinates: Vec<i32> =
+                line.split(',').map(str::parse).collect::<Result<_, _>>()?;
+            ensure!(coordinates.len() == 3, "cubes have three coordinates");
+            Ok([coordinates[0], coordinates[1], coordinates[2]])
+        })
+        .collect()
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let cubes = parse_cubes(
+        "2,2,2\n1,2,2\n3,2,2\n2,1,2\n2,3,2\n2,2,1\n2,2,3\n2,2,4\n2,2,6\n1,2,5\n3,2,5\n2,1,5\n2,3,5",
+    )
+    .unwrap();
+
+    assert_eq!(surface_area(&cubes), 64);
+    assert_eq!(exterior_surface_area(&cubes), 58);
+}
@@ -0,0 +1,161 @@
+use eyre::Result;
+use winnow::combinator::{delimited, preceded, seq};
+use winnow::prelude::*;
+
+use aoc_common::parse::{self, unsigned};
+use aoc_meta::Problem;
+
+pub const NOT_ENOUGH_MINERALS: Problem = Problem::solved(
+    &|input| {
+        let blueprints = parse_blueprints(input)?;
+        Ok::<_, eyre::Report>(
+            blueprints
+                .iter()
+                .map(|blueprint| blueprint.id * blueprint.most_geodes(24))
+                .sum::<u32>(),
+        )
+    },
+    &|input| {
+        let blueprints = parse_blueprints(input)?;
+        Ok::<_, eyre::Report>(
+            blueprints
+                .iter()
+                .take(3)
+                .map(|blueprint| blueprint.most_geodes(32))
+                .product::<u32>(),
+        )
+    },
+);
+
+/// Ore, clay, obsidian and geodes, in that order.
+type Resources = [u32; 4];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Blueprint {
+    id: u32,
+    /// What each kind of robot costs to build.
+    costs: [Resources; 4],
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Factory {
+    time: u32,
+    robots: Resources,
+    resources: Resources,
+}
+
+impl Blueprint {
+    /// The most geodes that can be opened in `time` minutes.
+    ///
+    /// Rather than deciding what to do every minute, each branch picks the next robot to build and waits until it
+    /// can. There's no point building more robots of a kind than could be spent in a minute, and a branch is
+    /// dropped when even building a geode robot every remaining minute couldn't beat the best so far.
+    fn most_geodes(&self, time: u32) -> u32 {
+        let most_needed: Resources = std::array::from_fn(|kind| {
+            if kind == 3 {
+                u32::MAX
+            } else {
+                self.costs.iter().map(|cost| cost[kind]).max().unwrap_or(0)
+            }
+        });
+
+        let mut best = 0;
+        let mut stack = vec![Factory {
+            time,
+            robots: [1, 0, 0, 0],
+            resources: [0; 4],
+        }];
+
+        while let Some(factory) = stack.pop() {
+            let t = factory.time;
+            best = best.max(factory.resources[3] + factory.robots[3] * t);
+
+            if factory.resources[3] + factory.robots[3] * t + t * t.saturating_sub(1) / 2 <= best {
+                continue;
+            }
+
+            for (kind, &most_needed) in most_needed.iter().enumerate() {
+                if factory.robots[kind] >= most_needed {
+                    continue;
+                }
+
+                // The minutes until there's enough of everything to build the robot, plus one to build it.
+                let wait = (0..3).try_fold(0, |wait, resource| {
+                    let needed =
+                        self.costs[kind][resource].saturating_sub(factory.resources[resource]);
+                    match (needed, factory.robots[resource]) {
+                        (0, _) => Some(wait),
+                        (_, 0) => None,
+                        (needed, robots) => Some(wait.max(needed.div_ceil(robots))),
+                    }
+                });
+                let Some(wait) = wait.map(|wait| wait + 1).filter(|&wait| wait < t) else {
+                    continue;
+                };
+
+                let mut next = Factory {
+                    time: t - wait,
+                    robots: factory.robots,
+                    resources: std::array::from_fn(|resource| {
+                        factory.resources[resource] + factory.robots[resource] * wait
+                            - self.costs[kind][resource]
+                    }),
+                };
+                next.robots[kind] += 1;
+                stack.push(next);
+            }
+        }
+
+        best
+    }
+}
+
+fn blueprint(input: &mut &str) -> ModalResult<Blueprint> {
+    seq! {Blueprint {
+        id: delimited("Blueprint ", unsigned, ":"),
+        costs: seq!(
+            delimited(" Each ore robot costs ", unsigned, " ore."),
+            delimited(" Each clay robot costs ", unsigned, " ore."),
+            preceded(" Each obsidian robot costs ", unsigned),
+            delimited(" ore and ", unsigned, " clay."),
+            preceded(" Each geode robot costs ", unsigned),
+            delimited(" ore and ", unsigned, " obsidian."),
+        )
+        .map(|(ore, clay, obsidian_ore, obsidian_clay, geode_ore, geode_obsidian)| [
+            [ore, 0, 0, 0],
+            [clay, 0, 0, 0],
+            [obsidian_ore, obsidian_clay, 0, 0],
+            [geode_ore, 0, geode_obsidian, 0],
+        ]),
+    }}
+    .parse_next(input)
+}
+
+fn parse_blueprints(input: &str) -> Result<Vec<Blueprint>> {
+    parse::lines(input, blueprint).collect()
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let blueprints = parse_blueprints(
+        "Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 2 ore. Each obsidian robot costs 3 ore and 14 clay. Each geode robot costs 2 ore and 7 obsidian.
+Blueprint 2: Each ore robot costs 2 ore. Each clay robot costs 3 ore. Each obsidian robot costs 3 ore and 8 clay. Each geode robot costs 3 ore and 12 obsidian.",
+    )
+    .unwrap();
+
+    assert_eq!(blueprints[1].costs[3], [3, 0, 12, 0]);
+
+    let geodes: Vec<u32> = blueprints
+        .iter()
+        .map(|blueprint| blueprint.most_geodes(24))
+        .collect();
+    assert_eq!(geodes, [9, 12]);
+
+    let geodes: Vec<u32> = blueprints
+        .iter()
+        .map(|blueprint| blueprint.most_geodes(32))
+        .collect();
+    assert_eq!(geodes, [56, 62]);
+}
@@ -0,0 +1,62 @@
+use eyre::{OptionExt, Result};
+
+use aoc_meta::Problem;
+
+pub const GROVE_POSITIONING_SYSTEM: Problem = Problem::solved(
+    &|input| grove_coordinates(&parse_numbers(input)?, 1, 1),
+    &|input| grove_coordinates(&parse_numbers(input)?, 811_589_153, 10),
+);
+
+/// The sum of the numbers 1000, 2000 and 3000 after 0, once every number has been multiplied by `key` and the
+/// list has been mixed `rounds` times.
+fn grove_coordinates(numbers: &[i64], key: i64, rounds: usize) -> Result<i64> {
+    let numbers: Vec<i64> = numbers.iter().map(|&number| number * key).collect();
+    let mixed = mix(&numbers, rounds);
+
+    let zero = mixed
+        .iter()
+        .position(|&i| numbers[i] == 0)
+        .ok_or_eyre("there's no 0 in the file")?;
+    Ok([1000, 2000, 3000]
+        .iter()
+        .map(|offset| numbers[mixed[(zero + offset) % mixed.len()]])
+        .sum())
+}
+
+/// Move each number forwards or backwards around the circular list as many places as its value, in the order they
+/// originally appeared, returning the original index of each number in the mixed list.
+fn mix(numbers: &[i64], rounds: usize) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..numbers.len()).collect();
+    // With one number taken out there are one fewer places to move through.
+    let places = numbers.len() as i64 - 1;
+
+    for _ in 0..rounds {
+        for (i, &number) in numbers.iter().enumerate() {
+            let from = order.iter().position(|&j| j == i).unwrap();
+            order.remove(from);
+            let to = (from as i64 + number).rem_euclid(places.max(1)) as usize;
+            order.insert(to, i);
+        }
+    }
+
+    order
+}
+
+fn parse_numbers(input: &str) -> Result<Vec<i64>> {
+    Ok(input.lines().map(str::parse).collect::<Result<_, _>>()?)
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let numbers = [1, 2, -3, 3, -2, 0, 4];
+    let mixed: Vec<i64> = mix(&numbers, 1).iter().map(|&i| numbers[i]).collect();
+    assert_eq!(mixed, [-2, 1, 2, -3, 4, 0, 3]);
+
+    assert_eq!(grove_coordinates(&numbers, 1, 1).unwrap(), 3);
+    assert_eq!(
+        grove_coordinates(&numbers, 811_589_153, 10).unwrap(),
+        1623178306
+    );
+}
@@ -0,0 +1,160 @@
+use eyre::{OptionExt, Result, bail, ensure, eyre};
+use fnv::FnvHashMap;
+
+use aoc_meta::Problem;
+
+pub const MONKEY_MATH: Problem = Problem::solved(
+    &|input| {
+        let riddle = Riddle::parse(input)?;
+        riddle.evaluate(riddle.root)
+    },
+    &|input| Riddle::parse(input)?.solve_for_human(),
+);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Job {
+    Number(i64),
+    /// A monkey waiting on two others, by index, to combine their numbers with an operator.
+    Operation(usize, u8, usize),
+}
+
+#[derive(Debug)]
+struct Riddle {
+    jobs: Vec<Job>,
+    root: usize,
+    human: usize,
+}
+
+impl Riddle {
+    /// The number `monkey` yells.
+    fn evaluate(&self, monkey: usize) -> Result<i64> {
+        match self.jobs[monkey] {
+            Job::Number(number) => Ok(number),
+            Job::Operation(a, op, b) => {
+                let (a, b) = (self.evaluate(a)?, self.evaluate(b)?);
+                Ok(match op {
+                    b'+' => a + b,
+                    b'-' => a - b,
+                    b'*' => a * b,
+                    _ => {
+                        ensure!(b != 0 && a % b == 0, "{a} doesn't divide evenly by {b}");
+                        a / b
+                    }
+                })
+            }
+        }
+    }
+
+    /// Whether `monkey`'s number depends on what the human yells.
+    fn depends_on_human(&self, monkey: usize) -> bool {
+        monkey == self.human
+            || matches!(self.jobs[monkey], Job::Operation(a, _, b) if self.depends_on_human(a) || self.depends_on_human(b))
+    }
+
+    /// The number the human has to yell for the root monkey's two numbers to be equal.
+    ///
+    /// Only one side of each operation on the way down from the root to the human depends on the human, so the
+    /// other side can be worked out and the operation undone, until all that's left is the human.
+    fn solve_for_human(&self) -> Result<i64> {
+        let Job::Operation(a, _, b) = self.jobs[self.root] else {
+            bail!("the root monkey just yells a number")
+        };
+
+        let (mut monkey, mut target) = match (self.depends_on_human(a), self.depends_on_human(b)) {
+            (true, false) => (a, self.evaluate(b)?),
+            (false, true) => (b, self.evaluate(a)?),
+            _ => bail!("the human needs to be on exactly one side of the root monkey's equation"),
+        };
+
+        while monkey != self.human {
+            let Job::Operation(a, op, b) = self.jobs[monkey] else {
+                unreachable!()
+            };
+
+            let unknown_left = match (self.depends_on_human(a), self.depends_on_human(b)) {
+                (true, false) => true,
+                (false, true) => false,
+                _ => bail!("the human's number is used more than once"),
+            };
+            let known = self.evaluate(if unknown_left { b } else { a })?;
+
+            let divide = |a: i64, b: i64| {
+                ensure!(b != 0 && a % b == 0, "no whole number works");
+                Ok(a / b)
+            };
+            target = match (op, unknown_left) {
+                (b'+', _) => target - known,
+                (b'*', _) => divide(target, known)?,
+                (b'-', true) => target + known,
+                (b'-', false) => known - target,
+                (_, true) => target * known,
+                (_, false) => divide(known, target)?,
+            };
+            monkey = if unknown_left { a } else { b };
+        }
+
+        Ok(target)
+    }
+
+    fn parse(input: &str) -> Result<Self> {
+        let lines: Vec<(&str, &str)> = input
+            .lines()
+            .map(|line| line.split_once(": ").ok_or_eyre("malformed job"))
+            .collect::<Result<_>>()?;
+        let indices: FnvHashMap<&str, usize> = lines
+            .iter()
+            .enumerate()
+            .map(|(i, &(name, _))| (name, i))
+            .collect();
+        let index = |name: &str| {
+            indices
+                .get(name)
+                .copied()
+                .ok_or_else(|| eyre!("no monkey is called {name}"))
+        };
+
+        let jobs = lines
+            .iter()
+            .map(|&(_, job)| match job.split(' ').collect::<Vec<_>>()[..] {
+                [number] => Ok(Job::Number(number.parse()?)),
+                [a, op @ ("+" | "-" | "*" | "/"), b] => {
+                    Ok(Job::Operation(index(a)?, op.as_bytes()[0], index(b)?))
+                }
+                _ => bail!("malformed job {job:?}"),
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Self {
+            jobs,
+            root: index("root")?,
+            human: index("humn")?,
+        })
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let riddle = Riddle::parse(
+        "root: pppw + sjmn
+dbpl: 5
+cczh: sllz + lgvd
+zczc: 2
+ptdq: humn - dvpt
+dvpt: 3
+lfqf: 4
+humn: 5
+ljgn: 2
+sjmn: drzm * dbpl
+sllz: 4
+pppw: cczh / lfqf
+lgvd: ljgn * ptdq
+drzm: hmdt - zczc
+hmdt: 32",
+    )
+    .unwrap();
+
+    assert_eq!(riddle.evaluate(riddle.root).unwrap(), 152);
+    assert_eq!(riddle.solve_for_human().unwrap(), 301);
+}
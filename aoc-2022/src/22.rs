@@ -0,0 +1,353 @@
+use std::collections::VecDeque;
+
+use eyre::{OptionExt, Result, bail, ensure};
+use fnv::FnvHashMap;
+
+use aoc_meta::Problem;
+
+pub const MONKEY_MAP: Problem = Problem::solved(
+    &|input| {
+        let (board, path) = parse_notes(input)?;
+        Ok::<_, eyre::Report>(board.follow(&path, &Flat))
+    },
+    &|input| {
+        let (board, path) = parse_notes(input)?;
+        let cube = Cube::fold(&board)?;
+        Ok::<_, eyre::Report>(board.follow(&path, &cube))
+    },
+);
+
+/// Right, down, left and up, in the order facings are numbered in the password.
+const DIRECTIONS: [(isize, isize); 4] = [(1, 0), (0, 1), (-1, 0), (0, -1)];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Step {
+    Forward(usize),
+    Left,
+    Right,
+}
+
+/// Where the walker is and which way it's facing, as an index into [`DIRECTIONS`].
+type Position = (usize, usize, usize);
+
+#[derive(Debug)]
+struct Board {
+    /// The tiles row by row, padded with spaces to make a rectangle.
+    rows: Vec<Vec<u8>>,
+}
+
+/// How the edges of the board join up.
+trait Wrapping {
+    /// Where the walker ends up stepping forwards off the edge of the board from `position`.
+    fn wrap(&self, board: &Board, position: Position) -> Position;
+}
+
+impl Board {
+    fn tile(&self, x: isize, y: isize) -> u8 {
+        let tile = |x: usize, y: usize| self.rows.get(y)?.get(x).copied();
+        x.try_into()
+            .ok()
+            .zip(y.try_into().ok())
+            .and_then(|(x, y)| tile(x, y))
+            .unwrap_or(b' ')
+    }
+
+    /// Follow the path from the leftmost open tile of the top row, returning the final password.
+    fn follow(&self, path: &[Step], wrapping: &impl Wrapping) -> usize {
+        let start = self.rows[0]
+            .iter()
+            .position(|&tile| tile == b'.')
+            .unwrap_or_default();
+        let (mut x, mut y, mut facing) = (start, 0, 0);
+
+        for &step in path {
+            match step {
+                Step::Left => facing = (facing + 3) % 4,
+                Step::Right => facing = (facing + 1) % 4,
+                Step::Forward(distance) => {
+                    for _ in 0..distance {
+                        let (dx, dy) = DIRECTIONS[facing];
+                        let (nx, ny) = (x as isize + dx, y as isize + dy);
+                        let next = if self.tile(nx, ny) == b' ' {
+                            wrapping.wrap(self, (x, y, facing))
+                        } else {
+                            (nx as usize, ny as usize, facing)
+                        };
+
+                        if self.rows[next.1][next.0] == b'#' {
+                            break;
+                        }
+                        (x, y, facing) = next;
+                    }
+                }
+            }
+        }
+
+        1000 * (y + 1) + 4 * (x + 1) + facing
+    }
+}
+
+/// Coming back round to the other side of the same row or column.
+struct Flat;
+
+impl Wrapping for Flat {
+    fn wrap(&self, board: &Board, (x, y, facing): Position) -> Position {
+        let (dx, dy) = DIRECTIONS[facing];
+        let (mut x, mut y) = (x as isize, y as isize);
+
+        while board.tile(x - dx, y - dy) != b' ' {
+            (x, y) = (x - dx, y - dy);
+        }
+
+        (x as usize, y as usize, facing)
+    }
+}
+
+type Vector = [i32; 3];
+
+fn negate(v: Vector) -> Vector {
+    v.map(|c| -c)
+}
+
+/// How a face of the net sits on the cube: which way is out of the cube, and which ways its rows and columns run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Orientation {
+    normal: Vector,
+    right: Vector,
+    down: Vector,
+}
+
+impl Orientation {
+    /// Which way in space each facing on this face points.
+    fn direction(&self, facing: usize) -> Vector {
+        [self.right, self.down, negate(self.right), negate(self.down)][facing]
+    }
+
+    /// The orientation of the face across the edge in direction `facing`, folded down over that edge.
+    fn across(&self, facing: usize) -> Self {
+        let Orientation {
+            normal,
+            right,
+            down,
+        } = *self;
+
+        match facing {
+            0 => Orientation {
+                normal: right,
+                right: negate(normal),
+                down,
+            },
+            1 => Orientation {
+                normal: down,
+                right,
+                down: negate(normal),
+            },
+            2 => Orientation {
+                normal: negate(right),
+                right: normal,
+                down,
+            },
+            _ => Orientation {
+                normal: negate(down),
+                right,
+                down: normal,
+            },
+        }
+    }
+}
+
+/// The board folded up into a cube.
+#[derive(Debug)]
+struct Cube {
+    size: usize,
+    /// Each face's position in the net, in units of faces, and its orientation.
+    faces: FnvHashMap<(usize, usize), Orientation>,
+}
+
+impl Cube {
+    /// Fold the net up, working out from the first face across every edge it shares with another face in the net.
+    fn fold(board: &Board) -> Result<Self> {
+        let tiles = board
+            .rows
+            .iter()
+            .flatten()
+            .filter(|&&tile| tile != b' ')
+            .count();
+        let size = (tiles / 6).isqrt();
+        ensure!(
+            size > 0 && 6 * size * size == tiles,
+            "the board isn't the net of a cube"
+        );
+
+        let is_face =
+            |(x, y): (isize, isize)| board.tile(x * size as isize, y * size as isize) != b' ';
+        let first = (0..)
+            .find(|&x| is_face((x, 0)))
+            .ok_or_eyre("the board is empty")?;
+
+        let start = Orientation {
+            normal: [0, 0, 1],
+            right: [1, 0, 0],
+            down: [0, 1, 0],
+        };
+        let mut faces = FnvHashMap::from_iter([((first as usize, 0), start)]);
+        let mut queue = VecDeque::from([((first, 0), start)]);
+
+        while let Some(((x, y), orientation)) = queue.pop_front() {
+            for (facing, (dx, dy)) in DIRECTIONS.iter().enumerate() {
+                let next = (x + dx, y + dy);
+                if is_face(next) && !faces.contains_key(&(next.0 as usize, next.1 as usize)) {
+                    faces.insert(
+                        (next.0 as usize, next.1 as usize),
+                        orientation.across(facing),
+                    );
+                    queue.push_back((next, orientation.across(facing)));
+                }
+            }
+        }
+
+        ensure!(
+            faces.len() == 6,
+            "the net has {} faces rather than 6",
+            faces.len()
+        );
+        let mut normals: Vec<Vector> = faces.values().map(|face| face.normal).collect();
+        normals.sort_unstable();
+        normals.dedup();
+        ensure!(
+            normals.len() == 6,
+            "the net folds up with faces on top of each other"
+        );
+
+        Ok(Self { size, faces })
+    }
+}
+
+impl Wrapping for Cube {
+    fn wrap(&self, _: &Board, (x, y, facing): Position) -> Position {
+        let size = self.size;
+        let face = &self.faces[&(x / size, y / size)];
+        let (column, row) = (x % size, y % size);
+
+        let (&(face_x, face_y), next) = self
+            .faces
+            .iter()
+            .find(|(_, other)| other.normal == face.direction(facing))
+            .expect("every edge of a cube meets another face");
+
+        // Going over the edge turns the walker from heading away from this face's normal to heading into the cube.
+        let heading = negate(face.normal);
+        let next_facing = (0..4)
+            .find(|&f| next.direction(f) == heading)
+            .expect("the faces are adjacent");
+
+        // The position along the edge, as a coordinate along the axis the edge runs in, is the same on both faces.
+        let (along, offset) = if facing % 2 == 0 {
+            (face.down, row)
+        } else {
+            (face.right, column)
+        };
+        let axis = along.iter().position(|&c| c != 0).unwrap();
+        let coordinate = if along[axis] > 0 {
+            offset
+        } else {
+            size - 1 - offset
+        };
+        let local = |v: Vector| {
+            if v[axis] > 0 {
+                coordinate
+            } else {
+                size - 1 - coordinate
+            }
+        };
+
+        let entry = if next_facing < 2 { 0 } else { size - 1 };
+        let (next_column, next_row) = if next_facing % 2 == 0 {
+            (entry, local(next.down))
+        } else {
+            (local(next.right), entry)
+        };
+
+        (
+            face_x * size + next_column,
+            face_y * size + next_row,
+            next_facing,
+        )
+    }
+}
+
+fn parse_notes(input: &str) -> Result<(Board, Vec<Step>)> {
+    let (map, path) = input.split_once("\n\n").ok_or_eyre("missing the path")?;
+
+    let width = map.lines().map(str::len).max().unwrap_or_default();
+    let rows: Vec<Vec<u8>> = map
+        .lines()
+        .map(|line| {
+            let mut row = line.as_bytes().to_vec();
+            row.resize(width, b' ');
+            row
+        })
+        .collect();
+    ensure!(
+        rows.iter().flatten().all(|tile| b" .#".contains(tile)),
+        "unexpected tile on the board"
+    );
+    ensure!(
+        rows.first().is_some_and(|row| row.contains(&b'.')),
+        "there's nowhere to start"
+    );
+
+    let mut steps = Vec::new();
+    let mut distance = None;
+    for c in path.trim().chars() {
+        if let Some(digit) = c.to_digit(10) {
+            distance = Some(distance.unwrap_or(0) * 10 + digit as usize);
+            continue;
+        }
+
+        steps.extend(distance.take().map(Step::Forward));
+        steps.push(match c {
+            'L' => Step::Left,
+            'R' => Step::Right,
+            _ => bail!("unexpected turn {c:?}"),
+        });
+    }
+    steps.extend(distance.map(Step::Forward));
+
+    Ok((Board { rows }, steps))
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let (board, path) = parse_notes(
+        "        ...#
+        .#..
+        #...
+        ....
+...#.......#
+........#...
+..#....#....
+..........#.
+        ...#....
+        .....#..
+        .#......
+        ......#.
+
+10R5L5R10L4R5L5",
+    )
+    .unwrap();
+
+    assert_eq!(
+        path[..4],
+        [Step::Forward(10), Step::Right, Step::Forward(5), Step::Left]
+    );
+    assert_eq!(board.follow(&path, &Flat), 6032);
+
+    let cube = Cube::fold(&board).unwrap();
+    assert_eq!(cube.wrap(&board, (11, 5, 0),), (14, 8, 1));
+    assert_eq!(cube.wrap(&board, (10, 11, 1)), (1, 7, 3));
+    assert_eq!(cube.wrap(&board, (6, 4, 3)), (8, 2, 0));
+    assert_eq!(board.follow(&path, &cube), 5031);
+}
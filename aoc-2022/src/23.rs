@@ -0,0 +1,118 @@
+use eyre::{Result, bail};
+use fnv::{FnvHashMap, FnvHashSet};
+
+use aoc_meta::Problem;
+
+pub const UNSTABLE_DIFFUSION: Problem = Problem::solved(
+    &|input| {
+        let mut grove = Grove::parse(input)?;
+        (0..10).for_each(|_| _ = grove.round());
+        Ok::<_, eyre::Report>(grove.empty_ground())
+    },
+    &|input| {
+        let mut grove = Grove::parse(input)?;
+        Ok::<_, eyre::Report>((1..).find(|_| !grove.round()).unwrap_or_default())
+    },
+);
+
+/// North, south, west and east, each with the three positions that have to be empty to move that way.
+const DIRECTIONS: [[(i32, i32); 3]; 4] = [
+    [(0, -1), (-1, -1), (1, -1)],
+    [(0, 1), (-1, 1), (1, 1)],
+    [(-1, 0), (-1, -1), (-1, 1)],
+    [(1, 0), (1, -1), (1, 1)],
+];
+
+#[derive(Debug)]
+struct Grove {
+    elves: FnvHashSet<(i32, i32)>,
+    /// Which direction the elves consider first this round.
+    first: usize,
+}
+
+impl Grove {
+    /// Have the elves spread out, returning whether any of them moved.
+    fn round(&mut self) -> bool {
+        let occupied =
+            |(x, y): (i32, i32), (dx, dy): (i32, i32)| self.elves.contains(&(x + dx, y + dy));
+
+        // Where each elf proposes to move, or None if more than one wants to go there.
+        let mut proposals: FnvHashMap<(i32, i32), Option<(i32, i32)>> = FnvHashMap::default();
+
+        for &elf in &self.elves {
+            if DIRECTIONS
+                .iter()
+                .flatten()
+                .all(|&offset| !occupied(elf, offset))
+            {
+                continue;
+            }
+
+            let direction = (0..4)
+                .map(|i| DIRECTIONS[(self.first + i) % 4])
+                .find(|offsets| offsets.iter().all(|&offset| !occupied(elf, offset)));
+            if let Some([(dx, dy), ..]) = direction {
+                proposals
+                    .entry((elf.0 + dx, elf.1 + dy))
+                    .and_modify(|proposal| *proposal = None)
+                    .or_insert(Some(elf));
+            }
+        }
+
+        let mut moved = false;
+        for (to, from) in proposals {
+            if let Some(from) = from {
+                self.elves.remove(&from);
+                self.elves.insert(to);
+                moved = true;
+            }
+        }
+
+        self.first = (self.first + 1) % 4;
+        moved
+    }
+
+    /// How many empty tiles are in the smallest rectangle containing every elf.
+    fn empty_ground(&self) -> usize {
+        let (xs, ys): (Vec<i32>, Vec<i32>) = self.elves.iter().copied().unzip();
+        let width = xs.iter().max().unwrap_or(&0) - xs.iter().min().unwrap_or(&0) + 1;
+        let height = ys.iter().max().unwrap_or(&0) - ys.iter().min().unwrap_or(&0) + 1;
+        (width * height) as usize - self.elves.len()
+    }
+
+    fn parse(input: &str) -> Result<Self> {
+        let mut elves = FnvHashSet::default();
+
+        for (y, line) in input.lines().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                match c {
+                    '#' => _ = elves.insert((x as i32, y as i32)),
+                    '.' => {}
+                    _ => bail!("unexpected character {c:?}"),
+                }
+            }
+        }
+
+        Ok(Self { elves, first: 0 })
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let example = ".....\n..##.\n..#..\n.....\n..##.\n.....";
+
+    let mut grove = Grove::parse(example).unwrap();
+    for _ in 0..3 {
+        assert!(grove.round());
+    }
+    assert_eq!(
+        grove.elves,
+        FnvHashSet::from_iter([(2, 0), (4, 1), (0, 2), (4, 3), (2, 5)])
+    );
+    assert_eq!(grove.empty_ground(), 25);
+
+    let mut grove = Grove::parse(example).unwrap();
+    assert_eq!((1..).find(|_| !grove.round()), Some(4));
+}
@@ -0,0 +1,117 @@
+use eyre::{OptionExt, Result, ensure};
+use fnv::FnvHashSet;
+
+use aoc_meta::Problem;
+
+pub const BLIZZARD_BASIN: Problem = Problem::solved(
+    &|input| {
+        let valley = Valley::parse(input)?;
+        valley
+            .crossing(valley.start(), valley.goal(), 0)
+            .ok_or_eyre("there's no way across")
+    },
+    &|input| {
+        let valley = Valley::parse(input)?;
+        let (start, goal) = (valley.start(), valley.goal());
+        [(start, goal), (goal, start), (start, goal)]
+            .into_iter()
+            .try_fold(0, |time, (from, to)| valley.crossing(from, to, time))
+            .ok_or_eyre("there's no way across")
+    },
+);
+
+#[derive(Debug)]
+struct Valley {
+    /// The size of the inside of the valley, within its walls.
+    width: i32,
+    height: i32,
+    /// The blizzards in the valley at the start, row by row.
+    blizzards: Vec<Vec<u8>>,
+}
+
+impl Valley {
+    fn start(&self) -> (i32, i32) {
+        (0, -1)
+    }
+
+    fn goal(&self) -> (i32, i32) {
+        (self.width - 1, self.height)
+    }
+
+    /// Whether `(x, y)` is somewhere the expedition can be at `time`. Blizzards move in a straight line and wrap
+    /// around, so there's one in a position at a time if one started far enough behind it.
+    fn clear(&self, (x, y): (i32, i32), time: i32) -> bool {
+        if (x, y) == self.start() || (x, y) == self.goal() {
+            return true;
+        }
+        if !(0..self.width).contains(&x) || !(0..self.height).contains(&y) {
+            return false;
+        }
+
+        let blizzard = |x: i32, y: i32| self.blizzards[y as usize][x as usize];
+        blizzard((x - time).rem_euclid(self.width), y) != b'>'
+            && blizzard((x + time).rem_euclid(self.width), y) != b'<'
+            && blizzard(x, (y - time).rem_euclid(self.height)) != b'v'
+            && blizzard(x, (y + time).rem_euclid(self.height)) != b'^'
+    }
+
+    /// The earliest time the expedition can reach `to`, setting off from `from` at `time`.
+    fn crossing(&self, from: (i32, i32), to: (i32, i32), mut time: i32) -> Option<i32> {
+        let mut positions = FnvHashSet::from_iter([from]);
+        // The blizzards are all back where they started after this long, so waiting any longer is pointless.
+        let period = self.width * self.height;
+
+        for _ in 0..=period * 2 {
+            if positions.contains(&to) {
+                return Some(time);
+            }
+
+            time += 1;
+            positions = positions
+                .iter()
+                .flat_map(|&(x, y)| [(x, y), (x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)])
+                .filter(|&position| self.clear(position, time))
+                .collect();
+        }
+
+        None
+    }
+
+    fn parse(input: &str) -> Result<Self> {
+        let lines: Vec<&[u8]> = input.lines().map(str::as_bytes).collect();
+        ensure!(lines.len() > 2, "the valley is too small");
+
+        let blizzards: Vec<Vec<u8>> = lines[1..lines.len() - 1]
+            .iter()
+            .map(|line| line[1..line.len().saturating_sub(1)].to_vec())
+            .collect();
+        let width = blizzards[0].len();
+        ensure!(
+            width > 0 && blizzards.iter().all(|row| row.len() == width),
+            "the valley should be a rectangle"
+        );
+        ensure!(
+            lines[0].get(1) == Some(&b'.') && lines[lines.len() - 1].get(width) == Some(&b'.'),
+            "the entrance and exit should be in the top left and bottom right corners"
+        );
+
+        Ok(Self {
+            width: width as i32,
+            height: blizzards.len() as i32,
+            blizzards,
+        })
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let valley =
+        Valley::parse("#.######\n#>>.<^<#\n#.<..<<#\n#>v.><>#\n#<^v^^>#\n######.#").unwrap();
+    let (start, goal) = (valley.start(), valley.goal());
+
+    assert_eq!(valley.crossing(start, goal, 0), Some(18));
+    assert_eq!(valley.crossing(goal, start, 18), Some(41));
+    assert_eq!(valley.crossing(start, goal, 41), Some(54));
+}
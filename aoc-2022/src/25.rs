@@ -0,0 +1,62 @@
+use eyre::{Result, bail};
+
+use aoc_meta::Problem;
+
+pub const FULL_OF_HOT_AIR: Problem = Problem::partially_solved(&|input| {
+    Ok::<_, eyre::Report>(to_snafu(
+        input.lines().map(from_snafu).sum::<Result<i64>>()?,
+    ))
+});
+
+/// Read a number written in balanced base five, with digits `=`, `-`, `0`, `1` and `2` for -2 to 2.
+fn from_snafu(snafu: &str) -> Result<i64> {
+    snafu.chars().try_fold(0, |number, c| {
+        let digit = match c {
+            '=' => -2,
+            '-' => -1,
+            '0'..='2' => i64::from(c as u8 - b'0'),
+            _ => bail!("{c:?} isn't a SNAFU digit"),
+        };
+        Ok(number * 5 + digit)
+    })
+}
+
+fn to_snafu(mut number: i64) -> String {
+    let mut digits = Vec::new();
+
+    loop {
+        // Digits 3 and 4 become -2 and -1, carrying one into the next place.
+        let digit = (number + 2).rem_euclid(5) - 2;
+        digits.push(b"=-012"[(digit + 2) as usize]);
+        number = (number - digit) / 5;
+
+        if number == 0 {
+            break;
+        }
+    }
+
+    digits.reverse();
+    String::from_utf8(digits).unwrap()
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let numbers = [
+        "1=-0-2", "12111", "2=0=", "21", "2=01", "111", "20012", "112", "1=-1=", "1-12", "12",
+        "1=", "122",
+    ];
+    let decimal: Vec<i64> = numbers
+        .iter()
+        .map(|snafu| from_snafu(snafu).unwrap())
+        .collect();
+    assert_eq!(
+        decimal,
+        [1747, 906, 198, 11, 201, 31, 1257, 32, 353, 107, 7, 3, 37]
+    );
+
+    assert_eq!(decimal.iter().sum::<i64>(), 4890);
+    assert_eq!(to_snafu(4890), "2=-1=0");
+    assert_eq!(to_snafu(314159265), "1121-1110-1=0");
+}
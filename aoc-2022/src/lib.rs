@@ -1,3 +1,29 @@
 use aoc_meta::PROBLEMS;
 
-PROBLEMS! {}
+PROBLEMS! {
+    01 => CALORIE_COUNTING,
+    02 => ROCK_PAPER_SCISSORS,
+    03 => RUCKSACK_REORGANIZATION,
+    04 => CAMP_CLEANUP,
+    05 => SUPPLY_STACKS,
+    06 => TUNING_TROUBLE,
+    07 => NO_SPACE_LEFT_ON_DEVICE,
+    08 => TREETOP_TREE_HOUSE,
+    09 => ROPE_BRIDGE,
+    10 => CATHODE_RAY_TUBE,
+    11 => MONKEY_IN_THE_MIDDLE,
+    12 => HILL_CLIMBING_ALGORITHM,
+    13 => DISTRESS_SIGNAL,
+    14 => REGOLITH_RESERVOIR,
+    15 => BEACON_EXCLUSION_ZONE,
+    16 => PROBOSCIDEA_VOLCANIUM,
+    17 => PYROCLASTIC_FLOW,
+    18 => BOILING_BOULDERS,
+    19 => NOT_ENOUGH_MINERALS,
+    20 => GROVE_POSITIONING_SYSTEM,
+    21 => MONKEY_MATH,
+    22 => MONKEY_MAP,
+    23 => UNSTABLE_DIFFUSION,
+    24 => BLIZZARD_BASIN,
+    25 => FULL_OF_HOT_AIR,
+}
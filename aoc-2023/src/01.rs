@@ -0,0 +1,51 @@
+use eyre::{OptionExt, Result};
+
+use aoc_meta::Problem;
+
+pub const TREBUCHET: Problem = Problem::solved(&|input| calibration_sum(input, false), &|input| {
+    calibration_sum(input, true)
+});
+
+const SPELLED: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// The digit starting at the beginning of `text`, if any.
+fn leading_digit(text: &str, spelled: bool) -> Option<u32> {
+    let digit = text.chars().next()?.to_digit(10);
+    if digit.is_some() || !spelled {
+        return digit;
+    }
+    (1..)
+        .zip(SPELLED)
+        .find_map(|(value, word)| text.starts_with(word).then_some(value))
+}
+
+/// The calibration value of a line: its first digit followed by its last. Spelled-out digits may overlap, as in
+/// `eightwo`.
+fn calibration_value(line: &str, spelled: bool) -> Option<u32> {
+    let mut digits = (0..line.len()).filter_map(|i| leading_digit(&line[i..], spelled));
+    let first = digits.next()?;
+    Some(10 * first + digits.next_back().unwrap_or(first))
+}
+
+fn calibration_sum(input: &str, spelled: bool) -> Result<u32> {
+    input
+        .lines()
+        .map(|line| calibration_value(line, spelled).ok_or_eyre("a line has no digits"))
+        .sum()
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    assert_eq!(
+        calibration_sum("1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet", false).unwrap(),
+        142
+    );
+
+    let spelled = "two1nine\neightwothree\nabcone2threexyz\nxtwone3four\n4nineeightseven2\nzoneight234\n7pqrstsixteen";
+    assert_eq!(calibration_sum(spelled, true).unwrap(), 281);
+    assert_eq!(calibration_value("eightwo", true), Some(82));
+}
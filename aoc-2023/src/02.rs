@@ -0,0 +1,95 @@
+use eyre::Result;
+use winnow::combinator::{alt, preceded, separated, separated_pair};
+use winnow::prelude::*;
+
+use aoc_common::parse::{self, unsigned};
+use aoc_meta::Problem;
+
+pub const CUBE_CONUNDRUM: Problem = Problem::solved(
+    &|input| {
+        let games = parse_games(input)?;
+        let possible = games.iter().filter(|game| {
+            game.fewest_cubes()
+                .iter()
+                .zip(BAG)
+                .all(|(&n, held)| n <= held)
+        });
+        Ok::<_, eyre::Report>(possible.map(|game| game.id).sum::<u32>())
+    },
+    &|input| {
+        let games = parse_games(input)?;
+        Ok::<_, eyre::Report>(
+            games
+                .iter()
+                .map(|game| game.fewest_cubes().iter().product::<u32>())
+                .sum::<u32>(),
+        )
+    },
+);
+
+/// The red, green and blue cubes in the bag for the first part.
+const BAG: [u32; 3] = [12, 13, 14];
+
+#[derive(Debug)]
+struct Game {
+    id: u32,
+    /// The red, green and blue cubes revealed in each handful.
+    handfuls: Vec<[u32; 3]>,
+}
+
+impl Game {
+    /// The fewest red, green and blue cubes the bag could have held.
+    fn fewest_cubes(&self) -> [u32; 3] {
+        self.handfuls.iter().fold([0; 3], |fewest, handful| {
+            std::array::from_fn(|i| fewest[i].max(handful[i]))
+        })
+    }
+
+    fn parser(input: &mut &str) -> ModalResult<Self> {
+        let color = alt(("red".value(0), "green".value(1), "blue".value(2)));
+        let cubes = separated_pair(unsigned::<u32>, ' ', color);
+        let handful = separated(1.., cubes, ", ").map(|cubes: Vec<(u32, usize)>| {
+            let mut handful = [0; 3];
+            for (count, color) in cubes {
+                handful[color] += count;
+            }
+            handful
+        });
+
+        let id = preceded("Game ", unsigned).parse_next(input)?;
+        let handfuls = preceded(": ", separated(1.., handful, "; ")).parse_next(input)?;
+        Ok(Self { id, handfuls })
+    }
+}
+
+fn parse_games(input: &str) -> Result<Vec<Game>> {
+    parse::lines(input, Game::parser).collect()
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let games = parse_games(
+        "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green",
+    )
+    .unwrap();
+
+    assert_eq!(games[0].handfuls, [[4, 0, 3], [1, 2, 6], [0, 2, 0]]);
+    let fewest: Vec<_> = games.iter().map(Game::fewest_cubes).collect();
+    assert_eq!(
+        fewest,
+        [[4, 2, 6], [1, 3, 4], [20, 13, 6], [14, 3, 15], [6, 3, 2]]
+    );
+    assert_eq!(
+        fewest
+            .iter()
+            .map(|cubes| cubes.iter().product::<u32>())
+            .sum::<u32>(),
+        2286
+    );
+}
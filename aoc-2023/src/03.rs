@@ -0,0 +1,116 @@
+use eyre::{Result, ensure};
+use fnv::FnvHashMap;
+
+use aoc_meta::Problem;
+
+pub const GEAR_RATIOS: Problem = Problem::solved(
+    &|input| {
+        Schematic::parse(input).map(|schematic| {
+            schematic
+                .part_numbers()
+                .map(|(number, _)| number)
+                .sum::<u32>()
+        })
+    },
+    &|input| Schematic::parse(input).map(|schematic| schematic.gear_ratios().sum::<u32>()),
+);
+
+#[derive(Debug)]
+struct Schematic<'a> {
+    rows: Vec<&'a [u8]>,
+}
+
+impl<'a> Schematic<'a> {
+    /// Every number in the schematic, along with the position of each symbol adjacent to it.
+    fn numbers(&self) -> impl Iterator<Item = (u32, Vec<(usize, usize)>)> + '_ {
+        self.rows.iter().enumerate().flat_map(move |(y, row)| {
+            let mut x = 0;
+            std::iter::from_fn(move || {
+                while x < row.len() && !row[x].is_ascii_digit() {
+                    x += 1;
+                }
+                let start = x;
+                while x < row.len() && row[x].is_ascii_digit() {
+                    x += 1;
+                }
+                (start < x).then(|| {
+                    let number = row[start..x]
+                        .iter()
+                        .fold(0, |n, &d| 10 * n + u32::from(d - b'0'));
+                    (number, self.symbols_around(y, start, x))
+                })
+            })
+        })
+    }
+
+    /// The symbols surrounding the span of row `y` from `start` up to `end`.
+    fn symbols_around(&self, y: usize, start: usize, end: usize) -> Vec<(usize, usize)> {
+        let rows = y.saturating_sub(1)..(y + 2).min(self.rows.len());
+        rows.flat_map(|y| {
+            let row = self.rows[y];
+            (start.saturating_sub(1)..(end + 1).min(row.len()))
+                .filter(move |&x| row[x] != b'.' && !row[x].is_ascii_digit())
+                .map(move |x| (x, y))
+        })
+        .collect()
+    }
+
+    /// The numbers adjacent to at least one symbol.
+    fn part_numbers(&self) -> impl Iterator<Item = (u32, Vec<(usize, usize)>)> + '_ {
+        self.numbers().filter(|(_, symbols)| !symbols.is_empty())
+    }
+
+    /// The product of the two part numbers next to each gear, a `*` adjacent to exactly two of them.
+    fn gear_ratios(&self) -> impl Iterator<Item = u32> {
+        let mut stars: FnvHashMap<(usize, usize), Vec<u32>> = FnvHashMap::default();
+        for (number, symbols) in self.part_numbers() {
+            for (x, y) in symbols
+                .into_iter()
+                .filter(|&(x, y)| self.rows[y][x] == b'*')
+            {
+                stars.entry((x, y)).or_default().push(number);
+            }
+        }
+        stars
+            .into_values()
+            .filter(|numbers| numbers.len() == 2)
+            .map(|numbers| numbers[0] * numbers[1])
+    }
+
+    fn parse(input: &'a str) -> Result<Self> {
+        let rows: Vec<_> = input.lines().map(str::as_bytes).collect();
+        ensure!(
+            rows.iter().all(|row| row.len() == rows[0].len()),
+            "the schematic should be a rectangle"
+        );
+        ensure!(
+            rows.iter().all(|row| row.iter().all(u8::is_ascii_graphic)),
+            "unexpected character in the schematic"
+        );
+        Ok(Self { rows })
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let schematic = Schematic::parse(
+        "467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..",
+    )
+    .unwrap();
+
+    let parts: Vec<_> = schematic.part_numbers().map(|(number, _)| number).collect();
+    assert_eq!(parts, [467, 35, 633, 617, 592, 755, 664, 598]);
+    assert_eq!(parts.iter().sum::<u32>(), 4361);
+    assert_eq!(schematic.gear_ratios().sum::<u32>(), 467835);
+}
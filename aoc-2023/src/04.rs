@@ -0,0 +1,56 @@
+use eyre::Result;
+use winnow::ascii::space1;
+use winnow::combinator::{delimited, preceded, separated_pair};
+use winnow::prelude::*;
+
+use aoc_common::parse::{self, unsigned, whitespace_separated};
+use aoc_meta::Problem;
+
+pub const SCRATCHCARDS: Problem = Problem::solved(
+    &|input| {
+        parse_matches(input).map(|matches| matches.iter().map(|&n| (1 << n) >> 1).sum::<u32>())
+    },
+    &|input| parse_matches(input).map(|matches| total_cards(&matches)),
+);
+
+/// The total number of scratchcards won, when each card with `n` matches wins a copy of the next `n` cards.
+fn total_cards(matches: &[usize]) -> u32 {
+    let mut copies = vec![1; matches.len()];
+    for (card, &n) in matches.iter().enumerate() {
+        for later in card + 1..(card + 1 + n).min(matches.len()) {
+            copies[later] += copies[card];
+        }
+    }
+    copies.iter().sum()
+}
+
+/// How many of each card's numbers are winning numbers.
+fn parse_matches(input: &str) -> Result<Vec<usize>> {
+    let numbers = || whitespace_separated::<_, Vec<u32>, _>(unsigned::<u32>);
+    let card = preceded(
+        delimited(("Card", space1), unsigned::<u32>, ':'),
+        separated_pair(numbers(), '|', numbers()),
+    )
+    .map(|(winning, ours)| ours.iter().filter(|n| winning.contains(n)).count());
+
+    parse::lines(input, card).collect()
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let matches = parse_matches(
+        "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11",
+    )
+    .unwrap();
+
+    assert_eq!(matches, [4, 2, 2, 1, 0, 0]);
+    assert_eq!(matches.iter().map(|&n| (1 << n) >> 1).sum::<u32>(), 13);
+    assert_eq!(total_cards(&matches), 30);
+}
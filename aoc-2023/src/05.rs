@@ -0,0 +1,189 @@
+use std::ops::Range;
+
+use eyre::{OptionExt, Result};
+use winnow::ascii::{alpha1, line_ending, space1};
+use winnow::combinator::{preceded, separated, seq};
+use winnow::prelude::*;
+
+use aoc_common::parse::{self, unsigned, whitespace_separated};
+use aoc_meta::Problem;
+
+pub const IF_YOU_GIVE_A_SEED_A_FERTILIZER: Problem = Problem::solved(
+    &|input| {
+        let almanac = Almanac::parse(input)?;
+        let seeds = almanac.seeds.iter().map(|&seed| seed..seed + 1).collect();
+        almanac
+            .lowest_location(seeds)
+            .ok_or_eyre("there are no seeds")
+    },
+    &|input| {
+        let almanac = Almanac::parse(input)?;
+        let seeds = almanac
+            .seeds
+            .chunks_exact(2)
+            .map(|pair| pair[0]..pair[0] + pair[1])
+            .collect();
+        almanac
+            .lowest_location(seeds)
+            .ok_or_eyre("there are no seeds")
+    },
+);
+
+/// Part of a map, sending each number in `source` to the number just as far into the range starting at
+/// `destination`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Mapping {
+    source: Range<u64>,
+    destination: u64,
+}
+
+/// One of the almanac's maps from one category to the next. Numbers outside every mapping map to themselves.
+#[derive(Debug)]
+struct Map {
+    /// The mappings, sorted by source and not overlapping.
+    mappings: Vec<Mapping>,
+}
+
+impl Map {
+    /// Push every range of numbers through the map, splitting them wherever a mapping begins or ends.
+    fn apply(&self, ranges: Vec<Range<u64>>) -> Vec<Range<u64>> {
+        let mut mapped = Vec::with_capacity(ranges.len());
+
+        for mut range in ranges {
+            for Mapping {
+                source,
+                destination,
+            } in &self.mappings
+            {
+                if range.is_empty() {
+                    break;
+                }
+                if source.end <= range.start {
+                    continue;
+                }
+                if source.start >= range.end {
+                    break;
+                }
+
+                if range.start < source.start {
+                    mapped.push(range.start..source.start);
+                    range.start = source.start;
+                }
+                let end = range.end.min(source.end);
+                let offset = |n: u64| n - source.start + destination;
+                mapped.push(offset(range.start)..offset(end));
+                range.start = end;
+            }
+
+            if !range.is_empty() {
+                mapped.push(range);
+            }
+        }
+
+        mapped
+    }
+
+    fn parser(input: &mut &str) -> ModalResult<Self> {
+        let mapping = seq!(unsigned, _: space1, unsigned, _: space1, unsigned::<u64>).map(
+            |(destination, source, len)| Mapping {
+                source: source..source + len,
+                destination,
+            },
+        );
+
+        let (_, mut mappings): (_, Vec<_>) = (
+            seq!(alpha1, "-to-", alpha1, " map:", line_ending),
+            separated(1.., mapping, line_ending),
+        )
+            .parse_next(input)?;
+        mappings.sort_unstable_by_key(|mapping| mapping.source.start);
+        Ok(Self { mappings })
+    }
+}
+
+#[derive(Debug)]
+struct Almanac {
+    seeds: Vec<u64>,
+    /// The maps from seed to soil, soil to fertilizer, and so on through to location.
+    maps: Vec<Map>,
+}
+
+impl Almanac {
+    /// The lowest location number corresponding to any of the seeds in `seeds`.
+    fn lowest_location(&self, seeds: Vec<Range<u64>>) -> Option<u64> {
+        let locations = self
+            .maps
+            .iter()
+            .fold(seeds, |ranges, map| map.apply(ranges));
+        locations
+            .iter()
+            .filter(|range| !range.is_empty())
+            .map(|range| range.start)
+            .min()
+    }
+
+    fn parse(input: &str) -> Result<Self> {
+        let (seeds, maps) = input.split_once("\n\n").ok_or_eyre("missing the maps")?;
+        let seeds = parse::complete(
+            seeds,
+            preceded("seeds:", whitespace_separated(unsigned::<u64>)),
+        )?;
+        let maps = parse::sections(maps, Map::parser).collect::<Result<_>>()?;
+        Ok(Self { seeds, maps })
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let almanac = Almanac::parse(
+        "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4",
+    )
+    .unwrap();
+
+    assert_eq!(
+        almanac.maps[0].apply(vec![79..80, 14..15, 55..56, 13..14]),
+        [81..82, 14..15, 57..58, 13..14]
+    );
+    assert_eq!(
+        almanac.maps[0].apply(vec![40..60, 90..100]),
+        [40..50, 52..62, 92..100, 50..52]
+    );
+
+    let seeds = almanac.seeds.iter().map(|&seed| seed..seed + 1).collect();
+    assert_eq!(almanac.lowest_location(seeds), Some(35));
+    assert_eq!(almanac.lowest_location(vec![79..93, 55..68]), Some(46));
+}
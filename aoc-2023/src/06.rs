@@ -0,0 +1,90 @@
+use eyre::{Result, ensure};
+use winnow::combinator::{preceded, separated_pair};
+
+use aoc_common::parse::{self, unsigned, whitespace_separated};
+use aoc_meta::Problem;
+
+pub const WAIT_FOR_IT: Problem = Problem::solved(
+    &|input| {
+        parse_races(input).map(|races| {
+            races
+                .iter()
+                .map(|&(time, record)| ways_to_win(time, record))
+                .product::<u64>()
+        })
+    },
+    &|input| {
+        let races = parse_races(input)?;
+        let (time, record) = races.iter().fold((0, 0), |(time, record), &(t, r)| {
+            (concatenate(time, t), concatenate(record, r))
+        });
+        Ok::<_, eyre::Report>(ways_to_win(time, record))
+    },
+);
+
+/// How many ways there are to beat the `record` distance in a race lasting `time` milliseconds, where holding
+/// the button for `t` milliseconds travels `t * (time - t)` millimeters.
+fn ways_to_win(time: u64, record: u64) -> u64 {
+    let wins = |hold: u64| hold * (time - hold) > record;
+
+    // The winning holds lie strictly between the roots of `t² - time·t + record`, symmetric about `time / 2`.
+    let Some(discriminant) = (time * time).checked_sub(4 * record) else {
+        return 0;
+    };
+    let mut shortest = ((time - discriminant.isqrt()) / 2).min(time / 2);
+    while shortest > 0 && wins(shortest - 1) {
+        shortest -= 1;
+    }
+    while shortest <= time / 2 && !wins(shortest) {
+        shortest += 1;
+    }
+
+    (time + 1).saturating_sub(2 * shortest)
+}
+
+/// The number written as the digits of `a` followed by the digits of `b`, fixing the bad kerning.
+fn concatenate(a: u64, b: u64) -> u64 {
+    a * 10u64.pow(b.checked_ilog10().unwrap_or(0) + 1) + b
+}
+
+/// Each race's time along with the record distance.
+fn parse_races(input: &str) -> Result<Vec<(u64, u64)>> {
+    let numbers = || whitespace_separated::<_, Vec<u64>, _>(unsigned::<u64>);
+    let (times, records) = parse::complete(
+        input.trim_end(),
+        separated_pair(
+            preceded("Time:", numbers()),
+            '\n',
+            preceded("Distance:", numbers()),
+        ),
+    )?;
+    ensure!(
+        times.len() == records.len(),
+        "every race needs a record distance"
+    );
+    Ok(times.into_iter().zip(records).collect())
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let races = parse_races("Time:      7  15   30\nDistance:  9  40  200").unwrap();
+    assert_eq!(races, [(7, 9), (15, 40), (30, 200)]);
+    assert_eq!(
+        races
+            .iter()
+            .map(|&(time, record)| ways_to_win(time, record))
+            .collect::<Vec<_>>(),
+        [4, 8, 9]
+    );
+    assert_eq!(
+        races.iter().fold((0, 0), |(time, record), &(t, r)| (
+            concatenate(time, t),
+            concatenate(record, r)
+        )),
+        (71530, 940200)
+    );
+    assert_eq!(ways_to_win(71530, 940200), 71503);
+    assert_eq!(ways_to_win(4, 4), 0);
+}
@@ -0,0 +1,85 @@
+use eyre::Result;
+use winnow::combinator::separated_pair;
+use winnow::prelude::*;
+use winnow::token::take;
+
+use aoc_common::parse::{self, unsigned};
+use aoc_meta::Problem;
+
+pub const CAMEL_CARDS: Problem = Problem::solved(
+    &|input| parse_hands(input).map(|hands| total_winnings(hands, false)),
+    &|input| parse_hands(input).map(|hands| total_winnings(hands, true)),
+);
+
+const CARDS: &[u8] = b"23456789TJQKA";
+const JOKER: u8 = 9;
+
+/// The strength of a hand's type, from high card (0) up to five of a kind (6).
+fn hand_type(cards: &[u8; 5], jokers_wild: bool) -> u8 {
+    let mut counts = [0u8; CARDS.len()];
+    for &card in cards {
+        counts[usize::from(card)] += 1;
+    }
+    let jokers = if jokers_wild {
+        std::mem::take(&mut counts[usize::from(JOKER)])
+    } else {
+        0
+    };
+
+    counts.sort_unstable_by(|a, b| b.cmp(a));
+    // Jokers always do best pretending to be the most common other card.
+    match (counts[0] + jokers, counts[1]) {
+        (5, _) => 6,
+        (4, _) => 5,
+        (3, 2) => 4,
+        (3, _) => 3,
+        (2, 2) => 2,
+        (2, _) => 1,
+        _ => 0,
+    }
+}
+
+/// The sum of each hand's bid multiplied by its rank, the weakest hand ranking 1.
+fn total_winnings(mut hands: Vec<([u8; 5], u32)>, jokers_wild: bool) -> u32 {
+    hands.sort_by_cached_key(|(cards, _)| {
+        let values = (*cards).map(|card| {
+            if jokers_wild && card == JOKER {
+                0
+            } else {
+                card + 1
+            }
+        });
+        (hand_type(cards, jokers_wild), values)
+    });
+    (1..).zip(&hands).map(|(rank, (_, bid))| rank * bid).sum()
+}
+
+/// Each hand's cards, numbered by their position in [`CARDS`], along with its bid.
+fn parse_hands(input: &str) -> Result<Vec<([u8; 5], u32)>> {
+    let card = |label: &u8| CARDS.iter().position(|c| c == label).map(|card| card as u8);
+    let cards = take(5usize).verify_map(|labels: &str| {
+        let cards: Option<Vec<u8>> = labels.as_bytes().iter().map(card).collect();
+        cards?.try_into().ok()
+    });
+    parse::lines(input, separated_pair(cards, ' ', unsigned)).collect()
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let hands = parse_hands("32T3K 765\nT55J5 684\nKK677 28\nKTJJT 220\nQQQJA 483").unwrap();
+    let types: Vec<_> = hands
+        .iter()
+        .map(|(cards, _)| hand_type(cards, false))
+        .collect();
+    assert_eq!(types, [1, 3, 2, 2, 3]);
+    let types: Vec<_> = hands
+        .iter()
+        .map(|(cards, _)| hand_type(cards, true))
+        .collect();
+    assert_eq!(types, [1, 5, 2, 5, 5]);
+
+    assert_eq!(total_winnings(hands.clone(), false), 6440);
+    assert_eq!(total_winnings(hands, true), 5905);
+}
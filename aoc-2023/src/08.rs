@@ -0,0 +1,142 @@
+use eyre::{OptionExt, Result, bail, ensure};
+use fnv::FnvHashMap;
+use winnow::ascii::alphanumeric1;
+use winnow::combinator::{delimited, separated_pair};
+use winnow::prelude::*;
+
+use aoc_common::math::lcm;
+use aoc_common::parse;
+use aoc_meta::Problem;
+
+pub const HAUNTED_WASTELAND: Problem = Problem::solved(
+    &|input| {
+        Ok::<_, eyre::Report>(
+            Network::parse(input)?
+                .steps("AAA", 0, |node| node == "ZZZ")?
+                .0,
+        )
+    },
+    &|input| Network::parse(input)?.ghost_steps(),
+);
+
+#[derive(Debug)]
+struct Network<'a> {
+    /// Whether each instruction is to go right.
+    instructions: Vec<bool>,
+    nodes: FnvHashMap<&'a str, [&'a str; 2]>,
+}
+
+impl<'a> Network<'a> {
+    /// How many steps it takes to get from `start` to a node satisfying `is_end`, following the instructions from
+    /// the one at `offset` (repeating them as needed) and taking at least one step. Returns the end node too.
+    fn steps(
+        &self,
+        start: &'a str,
+        offset: usize,
+        is_end: impl Fn(&str) -> bool,
+    ) -> Result<(u64, &'a str)> {
+        let limit = self.nodes.len() as u64 * self.instructions.len() as u64;
+        let mut node = start;
+        for (steps, &right) in (1..=limit).zip(self.instructions.iter().cycle().skip(offset)) {
+            node = self
+                .nodes
+                .get(node)
+                .ok_or_eyre("the network leads to an unknown node")?[usize::from(right)];
+            if is_end(node) {
+                return Ok((steps, node));
+            }
+        }
+        bail!("{start} never reaches an end")
+    }
+
+    /// How many steps it takes for ghosts starting on every node ending in `A` to all be on nodes ending in `Z`
+    /// at once.
+    ///
+    /// This relies on each ghost's route looping back so that it next reaches an end after exactly as many steps
+    /// again, which is checked, so that they all line up after the least common multiple of their first arrivals.
+    fn ghost_steps(&self) -> Result<u64> {
+        let is_end = |node: &str| node.ends_with('Z');
+        let mut steps = 1;
+
+        for &start in self.nodes.keys().filter(|node| node.ends_with('A')) {
+            let (first, end) = self.steps(start, 0, is_end)?;
+            let offset = (first % self.instructions.len() as u64) as usize;
+            let (again, _) = self.steps(end, offset, is_end)?;
+            ensure!(
+                again == first,
+                "{start} doesn't loop back to an end at a steady pace"
+            );
+            steps = lcm(steps, first).ok_or_eyre("the ghosts take too long to line up")?;
+        }
+
+        Ok(steps)
+    }
+
+    fn parse(input: &'a str) -> Result<Self> {
+        let (instructions, nodes) = input.split_once("\n\n").ok_or_eyre("missing the network")?;
+        let instructions = instructions
+            .chars()
+            .map(|c| match c {
+                'L' => Ok(false),
+                'R' => Ok(true),
+                _ => bail!("instructions are L or R"),
+            })
+            .collect::<Result<_>>()?;
+
+        let node = separated_pair(
+            alphanumeric1,
+            " = ",
+            delimited('(', separated_pair(alphanumeric1, ", ", alphanumeric1), ')')
+                .map(|(l, r)| [l, r]),
+        );
+        let nodes = parse::lines(nodes, node).collect::<Result<_>>()?;
+        Ok(Self {
+            instructions,
+            nodes,
+        })
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let network = Network::parse(
+        "RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)",
+    )
+    .unwrap();
+    assert_eq!(
+        network.steps("AAA", 0, |node| node == "ZZZ").unwrap(),
+        (2, "ZZZ")
+    );
+
+    let network =
+        Network::parse("LLR\n\nAAA = (BBB, BBB)\nBBB = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)").unwrap();
+    assert_eq!(
+        network.steps("AAA", 0, |node| node == "ZZZ").unwrap(),
+        (6, "ZZZ")
+    );
+
+    let network = Network::parse(
+        "LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)",
+    )
+    .unwrap();
+    assert_eq!(network.ghost_steps().unwrap(), 6);
+}
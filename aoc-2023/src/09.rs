@@ -0,0 +1,63 @@
+use eyre::Result;
+
+use aoc_common::parse::{self, signed, whitespace_separated};
+use aoc_meta::Problem;
+
+pub const MIRAGE_MAINTENANCE: Problem = Problem::solved(
+    &|input| {
+        parse_histories(input).map(|histories| {
+            histories
+                .iter()
+                .map(|history| extrapolate(history))
+                .sum::<i64>()
+        })
+    },
+    &|input| {
+        let histories = parse_histories(input)?;
+        Ok::<_, eyre::Report>(
+            histories
+                .into_iter()
+                .map(|mut history| {
+                    history.reverse();
+                    extrapolate(&history)
+                })
+                .sum::<i64>(),
+        )
+    },
+);
+
+/// The next value in the sequence, found by repeatedly taking differences until they're all zero and then
+/// building back up.
+fn extrapolate(history: &[i64]) -> i64 {
+    let mut next = 0;
+    let mut values = history.to_vec();
+    while values.iter().any(|&value| value != 0) {
+        next += values[values.len() - 1];
+        values = values.windows(2).map(|pair| pair[1] - pair[0]).collect();
+    }
+    next
+}
+
+fn parse_histories(input: &str) -> Result<Vec<Vec<i64>>> {
+    parse::lines(input, whitespace_separated(signed::<i64>)).collect()
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let histories = parse_histories("0 3 6 9 12 15\n1 3 6 10 15 21\n10 13 16 21 30 45").unwrap();
+    assert_eq!(
+        histories
+            .iter()
+            .map(|history| extrapolate(history))
+            .collect::<Vec<_>>(),
+        [18, 28, 68]
+    );
+
+    let backwards: Vec<_> = histories
+        .iter()
+        .map(|history| extrapolate(&history.iter().rev().copied().collect::<Vec<_>>()))
+        .collect();
+    assert_eq!(backwards, [-3, 0, 5]);
+}
@@ -0,0 +1,161 @@
+use eyre::{OptionExt, Result, bail, ensure};
+
+use aoc_meta::Problem;
+
+use crate::polygon::lattice_points;
+
+pub const PIPE_MAZE: Problem = Problem::solved(
+    &|input| Maze::parse(input)?.main_loop().map(|tiles| tiles.len() / 2),
+    &|input| {
+        let tiles = Maze::parse(input)?.main_loop()?;
+        let vertices: Vec<_> = tiles.iter().map(|&(x, y)| (x as i64, y as i64)).collect();
+        Ok::<_, eyre::Report>(lattice_points(&vertices).0)
+    },
+);
+
+const NORTH: u8 = 1;
+const EAST: u8 = 2;
+const SOUTH: u8 = 4;
+const WEST: u8 = 8;
+
+/// The direction back the way `direction` came.
+const fn opposite(direction: u8) -> u8 {
+    (direction << 2 | direction >> 2) & 15
+}
+
+#[derive(Debug)]
+struct Maze {
+    /// The directions each tile's pipe connects, as a set of bits.
+    tiles: Vec<Vec<u8>>,
+    start: (usize, usize),
+}
+
+impl Maze {
+    /// The tile one step in `direction` from `(x, y)`, if it's on the map.
+    fn step(&self, (x, y): (usize, usize), direction: u8) -> Option<(usize, usize)> {
+        let (x, y) = match direction {
+            NORTH => (Some(x), y.checked_sub(1)),
+            EAST => (Some(x + 1), Some(y)),
+            SOUTH => (Some(x), Some(y + 1)),
+            _ => (x.checked_sub(1), Some(y)),
+        };
+        let (x, y) = (x?, y?);
+        (y < self.tiles.len() && x < self.tiles[y].len()).then_some((x, y))
+    }
+
+    /// The tiles of the loop through the start, in order.
+    fn main_loop(&self) -> Result<Vec<(usize, usize)>> {
+        // The start connects to whichever neighbors connect back to it.
+        let mut heading = [NORTH, EAST, SOUTH, WEST]
+            .into_iter()
+            .find(|&direction| {
+                let neighbor = self.step(self.start, direction);
+                neighbor.is_some_and(|(x, y)| self.tiles[y][x] & opposite(direction) != 0)
+            })
+            .ok_or_eyre("no pipes connect to the start")?;
+
+        let mut tiles = vec![self.start];
+        let mut position = self.start;
+        loop {
+            position = self
+                .step(position, heading)
+                .ok_or_eyre("a pipe leads off the map")?;
+            if position == self.start {
+                return Ok(tiles);
+            }
+            let (x, y) = position;
+            let exits = self.tiles[y][x] & !opposite(heading);
+            ensure!(
+                self.tiles[y][x] & opposite(heading) != 0 && exits.count_ones() == 1,
+                "the loop is broken"
+            );
+            heading = exits;
+            tiles.push(position);
+        }
+    }
+
+    fn parse(input: &str) -> Result<Self> {
+        let mut start = None;
+        let tiles = input
+            .lines()
+            .enumerate()
+            .map(|(y, line)| {
+                line.chars()
+                    .enumerate()
+                    .map(|(x, c)| {
+                        Ok(match c {
+                            '|' => NORTH | SOUTH,
+                            '-' => EAST | WEST,
+                            'L' => NORTH | EAST,
+                            'J' => NORTH | WEST,
+                            '7' => SOUTH | WEST,
+                            'F' => SOUTH | EAST,
+                            '.' => 0,
+                            'S' => {
+                                ensure!(
+                                    start.replace((x, y)).is_none(),
+                                    "there can only be one start"
+                                );
+                                0
+                            }
+                            _ => bail!("unknown tile {c:?}"),
+                        })
+                    })
+                    .collect()
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Self {
+            tiles,
+            start: start.ok_or_eyre("missing the start")?,
+        })
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let maze = Maze::parse("..F7.\n.FJ|.\nSJ.L7\n|F--J\nLJ...").unwrap();
+    assert_eq!(maze.main_loop().unwrap().len() / 2, 8);
+
+    let enclosed = |input: &str| {
+        let tiles = Maze::parse(input).unwrap().main_loop().unwrap();
+        lattice_points(
+            &tiles
+                .iter()
+                .map(|&(x, y)| (x as i64, y as i64))
+                .collect::<Vec<_>>(),
+        )
+        .0
+    };
+    assert_eq!(
+        enclosed(
+            "...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+..........."
+        ),
+        4
+    );
+    assert_eq!(
+        enclosed(
+            ".F----7F7F7F7F-7....
+.|F--7||||||||FJ....
+.||.FJ||||||||L7....
+FJL7L7LJLJ||LJ.L-7..
+L--J.L7...LJS7F-7L7.
+....F-J..F7FJ|L7L7L7
+....L7.F7||L7|.L7L7|
+.....|FJLJ|FJ|F7|.LJ
+....FJL-7.||.||||...
+....L---J.LJ.LJLJ..."
+        ),
+        8
+    );
+}
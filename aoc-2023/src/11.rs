@@ -0,0 +1,92 @@
+use eyre::{Result, ensure};
+
+use aoc_meta::Problem;
+
+pub const COSMIC_EXPANSION: Problem = Problem::solved(
+    &|input| Image::parse(input).map(|image| image.total_distance(2)),
+    &|input| Image::parse(input).map(|image| image.total_distance(1_000_000)),
+);
+
+#[derive(Debug)]
+struct Image {
+    galaxies: Vec<(u64, u64)>,
+}
+
+impl Image {
+    /// The sum of the shortest distances between every pair of galaxies, once every empty row and column has
+    /// become `expansion` rows or columns.
+    fn total_distance(&self, expansion: u64) -> u64 {
+        let xs = self.galaxies.iter().map(|&(x, _)| x).collect();
+        let ys = self.galaxies.iter().map(|&(_, y)| y).collect();
+        axis_distance(xs, expansion) + axis_distance(ys, expansion)
+    }
+
+    fn parse(input: &str) -> Result<Self> {
+        let width = input.lines().next().map_or(0, str::len);
+        ensure!(
+            input.lines().all(|line| line.len() == width),
+            "the image should be a rectangle"
+        );
+        ensure!(
+            input.bytes().all(|b| b"#.\n".contains(&b)),
+            "the image is made of # and ."
+        );
+
+        let galaxies = (0..)
+            .zip(input.lines())
+            .flat_map(|(y, line)| {
+                (0..)
+                    .zip(line.bytes())
+                    .filter(|&(_, b)| b == b'#')
+                    .map(move |(x, _)| (x, y))
+            })
+            .collect();
+        Ok(Self { galaxies })
+    }
+}
+
+/// The sum of the distances along one axis between every pair of galaxies at `coordinates`, with the gaps
+/// between them expanded.
+fn axis_distance(mut coordinates: Vec<u64>, expansion: u64) -> u64 {
+    coordinates.sort_unstable();
+
+    let mut expanded = 0;
+    let mut previous = coordinates.first().copied().unwrap_or_default();
+    let mut preceding_sum = 0;
+    let mut total = 0;
+
+    for (count, &coordinate) in (0..).zip(&coordinates) {
+        let empty = coordinate.saturating_sub(previous + 1);
+        expanded += coordinate - previous + empty * (expansion - 1);
+        previous = coordinate;
+
+        // Each galaxy is this far from every galaxy before it.
+        total += count * expanded - preceding_sum;
+        preceding_sum += expanded;
+    }
+
+    total
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let image = Image::parse(
+        "...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....",
+    )
+    .unwrap();
+
+    assert_eq!(image.total_distance(2), 374);
+    assert_eq!(image.total_distance(10), 1030);
+    assert_eq!(image.total_distance(100), 8410);
+}
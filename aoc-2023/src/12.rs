@@ -0,0 +1,122 @@
+use eyre::Result;
+use winnow::combinator::{repeat, separated_pair};
+use winnow::prelude::*;
+use winnow::token::one_of;
+
+use aoc_common::parse::{self, comma_separated, unsigned};
+use aoc_meta::Problem;
+
+pub const HOT_SPRINGS: Problem = Problem::solved(
+    &|input| {
+        parse_records(input).map(|records| records.iter().map(Record::arrangements).sum::<u64>())
+    },
+    &|input| {
+        parse_records(input).map(|records| {
+            records
+                .iter()
+                .map(|record| record.unfold().arrangements())
+                .sum::<u64>()
+        })
+    },
+);
+
+#[derive(Debug)]
+struct Record {
+    /// The condition of each spring: `.` operational, `#` damaged or `?` unknown.
+    springs: Vec<u8>,
+    /// The size of each contiguous group of damaged springs.
+    groups: Vec<usize>,
+}
+
+impl Record {
+    /// The record repeated five times, with the copies of the springs separated by unknown springs.
+    fn unfold(&self) -> Self {
+        let mut springs = self.springs.clone();
+        for _ in 1..5 {
+            springs.push(b'?');
+            springs.extend(&self.springs);
+        }
+        Self {
+            springs,
+            groups: self.groups.repeat(5),
+        }
+    }
+
+    /// How many ways the unknown springs could be filled in to match the groups.
+    fn arrangements(&self) -> u64 {
+        let springs = &self.springs;
+        let n = springs.len();
+
+        // How many springs from each one onwards could all be damaged.
+        let mut possibly_damaged = vec![0; n + 1];
+        for i in (0..n).rev() {
+            possibly_damaged[i] = if springs[i] == b'.' {
+                0
+            } else {
+                possibly_damaged[i + 1] + 1
+            };
+        }
+
+        // `ways[i]` counts the arrangements of the springs from `i` onwards matching the groups placed so far,
+        // working back from the last group. With no groups left, none of the springs can be damaged.
+        let mut ways: Vec<u64> = vec![0; n + 1];
+        ways[n] = 1;
+        for i in (0..n).rev() {
+            ways[i] = if springs[i] == b'#' { 0 } else { ways[i + 1] };
+        }
+
+        for &group in self.groups.iter().rev() {
+            let mut next = vec![0; n + 1];
+            for i in (0..n).rev() {
+                let operational = if springs[i] == b'#' { 0 } else { next[i + 1] };
+                let end = i + group;
+                let fits = possibly_damaged[i] >= group && springs.get(end) != Some(&b'#');
+                // The group has to be followed by an operational spring, unless it's at the very end.
+                let damaged = if fits { ways[(end + 1).min(n)] } else { 0 };
+                next[i] = operational + damaged;
+            }
+            ways = next;
+        }
+
+        ways[0]
+    }
+
+    fn parser(input: &mut &str) -> ModalResult<Self> {
+        separated_pair(
+            repeat(1.., one_of(['.', '#', '?'])).map(|s: String| s.into_bytes()),
+            ' ',
+            comma_separated(unsigned::<usize>),
+        )
+        .map(|(springs, groups)| Self { springs, groups })
+        .parse_next(input)
+    }
+}
+
+fn parse_records(input: &str) -> Result<Vec<Record>> {
+    parse::lines(input, Record::parser).collect()
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let records = parse_records(
+        "???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1",
+    )
+    .unwrap();
+
+    assert_eq!(
+        records.iter().map(Record::arrangements).collect::<Vec<_>>(),
+        [1, 4, 1, 1, 4, 10]
+    );
+    let unfolded: Vec<_> = records
+        .iter()
+        .map(|record| record.unfold().arrangements())
+        .collect();
+    assert_eq!(unfolded, [1, 16384, 1, 16, 2500, 506250]);
+}
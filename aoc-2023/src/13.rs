@@ -0,0 +1,105 @@
+use eyre::{OptionExt, Result};
+use winnow::prelude::*;
+
+use aoc_common::parse;
+use aoc_meta::Problem;
+
+pub const POINT_OF_INCIDENCE: Problem =
+    Problem::solved(&|input| summarize(input, 0), &|input| summarize(input, 1));
+
+#[derive(Debug)]
+struct Pattern {
+    /// Each row as a bitmask of its rocks.
+    rows: Vec<u32>,
+    /// Each column as a bitmask of its rocks.
+    columns: Vec<u32>,
+}
+
+impl Pattern {
+    /// The number of rows above a horizontal line of reflection, or columns left of a vertical one (times 100 for
+    /// rows, as the puzzle summarizes them), where the reflection is off by exactly `smudges` cells.
+    fn summary(&self, smudges: u32) -> Option<usize> {
+        reflection(&self.rows, smudges)
+            .map(|rows| 100 * rows)
+            .or_else(|| reflection(&self.columns, smudges))
+    }
+
+    fn parser(input: &mut &str) -> ModalResult<Self> {
+        let rows = parse::grid(|c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })
+        .parse_next(input)?;
+
+        let mask = |cells: Vec<bool>| {
+            cells
+                .into_iter()
+                .fold(0, |mask, rock| mask << 1 | u32::from(rock))
+        };
+        let columns = (0..rows[0].len())
+            .map(|x| mask(rows.iter().map(|row| row[x]).collect()))
+            .collect();
+        let rows = rows.into_iter().map(mask).collect();
+        Ok(Self { rows, columns })
+    }
+}
+
+/// How many lines come before the line of reflection between consecutive `lines` where the lines reflect onto
+/// each other with exactly `smudges` cells differing.
+fn reflection(lines: &[u32], smudges: u32) -> Option<usize> {
+    (1..lines.len()).find(|&before| {
+        let reflected = lines[..before].iter().rev().zip(&lines[before..]);
+        reflected.map(|(a, b)| (a ^ b).count_ones()).sum::<u32>() == smudges
+    })
+}
+
+fn summarize(input: &str, smudges: u32) -> Result<usize> {
+    parse::sections(input, Pattern::parser)
+        .map(|pattern| {
+            pattern?
+                .summary(smudges)
+                .ok_or_eyre("a pattern has no line of reflection")
+        })
+        .sum()
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let example = "#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.##..##.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#";
+
+    let patterns: Vec<_> = parse::sections(example, Pattern::parser)
+        .collect::<Result<_>>()
+        .unwrap();
+    assert_eq!(
+        patterns
+            .iter()
+            .map(|pattern| pattern.summary(0))
+            .collect::<Vec<_>>(),
+        [Some(5), Some(400)]
+    );
+    assert_eq!(
+        patterns
+            .iter()
+            .map(|pattern| pattern.summary(1))
+            .collect::<Vec<_>>(),
+        [Some(300), Some(100)]
+    );
+    assert_eq!(summarize(example, 0).unwrap(), 405);
+}
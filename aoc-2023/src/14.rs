@@ -0,0 +1,114 @@
+use eyre::{Result, ensure};
+
+use aoc_common::cycle::brent_nth;
+use aoc_meta::Problem;
+
+pub const PARABOLIC_REFLECTOR_DISH: Problem = Problem::solved(
+    &|input| {
+        Platform::parse(input).map(|mut platform| {
+            platform.tilt_north();
+            platform.load()
+        })
+    },
+    &|input| {
+        Platform::parse(input)
+            .map(|platform| brent_nth(platform, Platform::spin, 1_000_000_000).load())
+    },
+);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Platform {
+    /// The rows from north to south, with `O` for a rounded rock, `#` a cube-shaped rock and `.` empty space.
+    rows: Vec<Vec<u8>>,
+}
+
+impl Platform {
+    /// Roll every rounded rock as far north as it will go.
+    fn tilt_north(&mut self) {
+        let width = self.rows[0].len();
+        for x in 0..width {
+            let mut free = 0;
+            for y in 0..self.rows.len() {
+                match self.rows[y][x] {
+                    b'#' => free = y + 1,
+                    b'O' => {
+                        self.rows[y][x] = b'.';
+                        self.rows[free][x] = b'O';
+                        free += 1;
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    /// Turn the platform a quarter turn clockwise, so that west becomes north.
+    fn rotate(&mut self) {
+        let height = self.rows.len();
+        self.rows = (0..self.rows[0].len())
+            .map(|x| (0..height).rev().map(|y| self.rows[y][x]).collect())
+            .collect();
+    }
+
+    /// Tilt the platform north, then west, south and east.
+    fn spin(&self) -> Self {
+        let mut platform = self.clone();
+        for _ in 0..4 {
+            platform.tilt_north();
+            platform.rotate();
+        }
+        platform
+    }
+
+    /// The total load on the north support beams, each rounded rock contributing its distance from the south edge.
+    fn load(&self) -> usize {
+        (1..)
+            .zip(self.rows.iter().rev())
+            .map(|(load, row)| load * row.iter().filter(|&&b| b == b'O').count())
+            .sum()
+    }
+
+    fn parse(input: &str) -> Result<Self> {
+        let rows: Vec<Vec<u8>> = input.lines().map(|line| line.bytes().collect()).collect();
+        ensure!(
+            !rows.is_empty() && rows.iter().all(|row| row.len() == rows[0].len()),
+            "the platform should be a rectangle"
+        );
+        ensure!(
+            rows.iter().flatten().all(|b| b"O#.".contains(b)),
+            "unexpected character on the platform"
+        );
+        Ok(Self { rows })
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let platform = Platform::parse(
+        "O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....",
+    )
+    .unwrap();
+
+    let mut tilted = platform.clone();
+    tilted.tilt_north();
+    assert_eq!(tilted.load(), 136);
+
+    let spun = platform.spin();
+    assert_eq!(spun.rows[0], b".....#....");
+    assert_eq!(spun.rows[9], b"#..OO#....");
+    assert_eq!(
+        brent_nth(platform, Platform::spin, 1_000_000_000).load(),
+        64
+    );
+}
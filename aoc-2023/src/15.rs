@@ -0,0 +1,73 @@
+use eyre::{OptionExt, Result, ensure};
+
+use aoc_meta::Problem;
+
+pub const LENS_LIBRARY: Problem = Problem::solved(
+    &|input| {
+        Ok::<_, eyre::Report>(
+            input
+                .trim_end()
+                .split(',')
+                .map(|step| u32::from(hash(step)))
+                .sum::<u32>(),
+        )
+    },
+    &|input| focusing_power(input.trim_end()),
+);
+
+/// The Holiday ASCII String Helper algorithm.
+fn hash(text: &str) -> u8 {
+    text.bytes()
+        .fold(0, |hash, b| hash.wrapping_add(b).wrapping_mul(17))
+}
+
+/// Run the initialization sequence, then add up each lens's box number, slot number and focal length multiplied
+/// together.
+fn focusing_power(sequence: &str) -> Result<usize> {
+    let mut boxes: Vec<Vec<(&str, usize)>> = vec![Vec::new(); 256];
+
+    for step in sequence.split(',') {
+        if let Some(label) = step.strip_suffix('-') {
+            boxes[usize::from(hash(label))].retain(|&(lens, _)| lens != label);
+        } else {
+            let (label, focal_length) = step
+                .split_once('=')
+                .ok_or_eyre("steps either remove or insert a lens")?;
+            let focal_length = focal_length.parse()?;
+            ensure!(
+                (1..=9).contains(&focal_length),
+                "focal lengths range from 1 to 9"
+            );
+
+            let lenses = &mut boxes[usize::from(hash(label))];
+            match lenses.iter_mut().find(|(lens, _)| *lens == label) {
+                Some(lens) => lens.1 = focal_length,
+                None => lenses.push((label, focal_length)),
+            }
+        }
+    }
+
+    let powers = (1..).zip(boxes).flat_map(|(number, lenses)| {
+        (1..)
+            .zip(lenses)
+            .map(move |(slot, (_, focal_length))| number * slot * focal_length)
+    });
+    Ok(powers.sum())
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    assert_eq!(hash("HASH"), 52);
+
+    let sequence = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+    assert_eq!(
+        sequence
+            .split(',')
+            .map(|step| u32::from(hash(step)))
+            .sum::<u32>(),
+        1320
+    );
+    assert_eq!(focusing_power(sequence).unwrap(), 145);
+}
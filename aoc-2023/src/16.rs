@@ -0,0 +1,116 @@
+use eyre::{OptionExt, Result, ensure};
+
+use aoc_meta::Problem;
+
+pub const THE_FLOOR_WILL_BE_LAVA: Problem = Problem::solved(
+    &|input| Contraption::parse(input).map(|contraption| contraption.energized((0, 0), EAST)),
+    &|input| {
+        Contraption::parse(input)?
+            .most_energized()
+            .ok_or_eyre("the contraption is empty")
+    },
+);
+
+const NORTH: u8 = 0;
+const EAST: u8 = 1;
+const SOUTH: u8 = 2;
+const WEST: u8 = 3;
+
+#[derive(Debug)]
+struct Contraption {
+    width: usize,
+    tiles: Vec<u8>,
+}
+
+impl Contraption {
+    /// The directions a beam heading in `direction` leaves a tile in.
+    fn deflect(tile: u8, direction: u8) -> impl Iterator<Item = u8> {
+        let horizontal = direction == EAST || direction == WEST;
+        let (first, second) = match tile {
+            b'/' => ([EAST, NORTH, WEST, SOUTH][usize::from(direction)], None),
+            b'\\' => ([WEST, SOUTH, EAST, NORTH][usize::from(direction)], None),
+            b'|' if horizontal => (NORTH, Some(SOUTH)),
+            b'-' if !horizontal => (EAST, Some(WEST)),
+            _ => (direction, None),
+        };
+        std::iter::once(first).chain(second)
+    }
+
+    /// The tile one step in `direction` from `tile`, if it's inside the contraption.
+    fn step(&self, tile: usize, direction: u8) -> Option<usize> {
+        match direction {
+            NORTH => tile.checked_sub(self.width),
+            EAST => (tile % self.width + 1 < self.width).then_some(tile + 1),
+            SOUTH => Some(tile + self.width).filter(|&next| next < self.tiles.len()),
+            _ => (!tile.is_multiple_of(self.width)).then(|| tile - 1),
+        }
+    }
+
+    /// How many tiles end up energized by a beam entering the tile at `(x, y)` heading in `direction`.
+    fn energized(&self, (x, y): (usize, usize), direction: u8) -> usize {
+        // The directions beams have passed through each tile in, as a set of bits.
+        let mut seen = vec![0u8; self.tiles.len()];
+        let mut beams = vec![(y * self.width + x, direction)];
+
+        while let Some((tile, direction)) = beams.pop() {
+            if seen[tile] & 1 << direction != 0 {
+                continue;
+            }
+            seen[tile] |= 1 << direction;
+            beams.extend(
+                Self::deflect(self.tiles[tile], direction)
+                    .filter_map(|direction| Some((self.step(tile, direction)?, direction))),
+            );
+        }
+
+        seen.iter().filter(|&&directions| directions != 0).count()
+    }
+
+    /// The most tiles energized by a beam entering from any edge.
+    fn most_energized(&self) -> Option<usize> {
+        let (width, height) = (self.width, self.tiles.len() / self.width);
+        let rows = (0..height).flat_map(|y| [((0, y), EAST), ((width - 1, y), WEST)]);
+        let columns = (0..width).flat_map(|x| [((x, 0), SOUTH), ((x, height - 1), NORTH)]);
+        rows.chain(columns)
+            .map(|(start, direction)| self.energized(start, direction))
+            .max()
+    }
+
+    fn parse(input: &str) -> Result<Self> {
+        let width = input.lines().next().map_or(0, str::len);
+        ensure!(
+            width > 0 && input.lines().all(|line| line.len() == width),
+            "the contraption should be a rectangle"
+        );
+
+        let tiles: Vec<u8> = input.lines().flat_map(str::bytes).collect();
+        ensure!(
+            tiles.iter().all(|b| br"./\|-".contains(b)),
+            "unexpected tile in the contraption"
+        );
+        Ok(Self { width, tiles })
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let contraption = Contraption::parse(
+        r".|...\....
+|.-.\.....
+.....|-...
+........|.
+..........
+.........\
+..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|....",
+    )
+    .unwrap();
+
+    assert_eq!(contraption.energized((0, 0), EAST), 46);
+    assert_eq!(contraption.energized((3, 0), SOUTH), 51);
+    assert_eq!(contraption.most_energized(), Some(51));
+}
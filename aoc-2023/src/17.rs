@@ -0,0 +1,137 @@
+use eyre::{OptionExt, Result, ensure};
+
+use aoc_common::search::{SearchSpace, dijkstra};
+use aoc_meta::Problem;
+
+pub const CLUMSY_CRUCIBLE: Problem = Problem::solved(
+    &|input| {
+        City::parse(input)?
+            .least_heat_loss(1, 3)
+            .ok_or_eyre("the crucible can't reach the factory")
+    },
+    &|input| {
+        City::parse(input)?
+            .least_heat_loss(4, 10)
+            .ok_or_eyre("the crucible can't reach the factory")
+    },
+);
+
+#[derive(Debug)]
+struct City {
+    /// The heat lost entering each city block, row by row.
+    blocks: Vec<Vec<u32>>,
+}
+
+impl City {
+    /// The least heat lost getting a crucible from the top left block to the bottom right one, when it has to move
+    /// between `min` and `max` blocks in a straight line before turning.
+    fn least_heat_loss(&self, min: usize, max: usize) -> Option<u32> {
+        let crucible = Crucible {
+            city: self,
+            min,
+            max,
+        };
+        dijkstra(&crucible, (0, 0, Axis::Either))
+            .find(|(state, _)| crucible.is_goal(state))
+            .map(|(_, loss)| loss)
+    }
+
+    fn parse(input: &str) -> Result<Self> {
+        let blocks: Vec<Vec<u32>> = input
+            .lines()
+            .map(|line| {
+                line.chars()
+                    .map(|c| c.to_digit(10).ok_or_eyre("heat loss is a single digit"))
+                    .collect()
+            })
+            .collect::<Result<_>>()?;
+        ensure!(
+            !blocks.is_empty() && blocks.iter().all(|row| row.len() == blocks[0].len()),
+            "the city should be a rectangle"
+        );
+        Ok(Self { blocks })
+    }
+}
+
+/// The axis the crucible last moved along, so that it has to turn onto the other one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Axis {
+    Horizontal,
+    Vertical,
+    /// Before the crucible has moved at all.
+    Either,
+}
+
+struct Crucible<'a> {
+    city: &'a City,
+    min: usize,
+    max: usize,
+}
+
+impl SearchSpace for Crucible<'_> {
+    type State = (usize, usize, Axis);
+    type Cost = u32;
+
+    fn successors(
+        &self,
+        &(x, y, axis): &Self::State,
+    ) -> impl IntoIterator<Item = (Self::State, u32)> {
+        let blocks = &self.city.blocks;
+        let (width, height) = (blocks[0].len() as isize, blocks.len() as isize);
+        let directions = match axis {
+            Axis::Horizontal => &[(0, -1), (0, 1)][..],
+            Axis::Vertical => &[(-1, 0), (1, 0)],
+            Axis::Either => &[(0, -1), (0, 1), (-1, 0), (1, 0)],
+        };
+
+        directions.iter().flat_map(move |&(dx, dy)| {
+            let axis = if dy == 0 {
+                Axis::Horizontal
+            } else {
+                Axis::Vertical
+            };
+            let positions = (1..=self.max as isize)
+                .map(move |n| (x as isize + n * dx, y as isize + n * dy))
+                .take_while(move |&(x, y)| (0..width).contains(&x) && (0..height).contains(&y));
+            positions
+                .scan(0, move |loss, (x, y)| {
+                    *loss += blocks[y as usize][x as usize];
+                    Some(((x as usize, y as usize, axis), *loss))
+                })
+                .skip(self.min - 1)
+        })
+    }
+
+    fn is_goal(&self, &(x, y, _): &Self::State) -> bool {
+        let blocks = &self.city.blocks;
+        y + 1 == blocks.len() && x + 1 == blocks[0].len()
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let city = City::parse(
+        "2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533",
+    )
+    .unwrap();
+    assert_eq!(city.least_heat_loss(1, 3), Some(102));
+    assert_eq!(city.least_heat_loss(4, 10), Some(94));
+
+    let city = City::parse("111111111111\n999999999991\n999999999991\n999999999991\n999999999991")
+        .unwrap();
+    assert_eq!(city.least_heat_loss(4, 10), Some(71));
+}
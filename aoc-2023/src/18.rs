@@ -0,0 +1,105 @@
+use eyre::Result;
+use winnow::combinator::{delimited, preceded, seq};
+use winnow::prelude::*;
+use winnow::token::{one_of, take_while};
+
+use aoc_common::parse::{self, unsigned};
+use aoc_meta::Problem;
+
+use crate::polygon::lattice_points;
+
+pub const LAVADUCT_LAGOON: Problem = Problem::solved(
+    &|input| {
+        parse_plan(input)
+            .map(|plan| lagoon_size(plan.iter().map(|step| (step.direction, step.meters))))
+    },
+    &|input| parse_plan(input).map(|plan| lagoon_size(plan.iter().map(Step::decoded))),
+);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Step {
+    /// `R`, `D`, `L` or `U`.
+    direction: char,
+    meters: i64,
+    color: u32,
+}
+
+impl Step {
+    /// The real instruction hidden in the color code: five hex digits of distance, then one of direction.
+    fn decoded(&self) -> (char, i64) {
+        (
+            ['R', 'D', 'L', 'U'][(self.color & 3) as usize],
+            i64::from(self.color >> 4),
+        )
+    }
+
+    fn parser(input: &mut &str) -> ModalResult<Self> {
+        seq! {Self {
+            direction: one_of(['R', 'D', 'L', 'U']),
+            meters: preceded(' ', unsigned::<u32>).map(i64::from),
+            color: delimited(" (#", take_while(6, |c: char| c.is_ascii_hexdigit()), ')')
+                .try_map(|hex| u32::from_str_radix(hex, 16)),
+        }}
+        .parse_next(input)
+    }
+}
+
+/// How many cubic meters of lava the lagoon dug out by `steps` holds, counting its edge along with its interior.
+fn lagoon_size(steps: impl Iterator<Item = (char, i64)>) -> i64 {
+    let vertices: Vec<_> = steps
+        .scan((0, 0), |(x, y), (direction, meters)| {
+            match direction {
+                'R' => *x += meters,
+                'D' => *y += meters,
+                'L' => *x -= meters,
+                _ => *y -= meters,
+            }
+            Some((*x, *y))
+        })
+        .collect();
+
+    let (interior, boundary) = lattice_points(&vertices);
+    interior + boundary
+}
+
+fn parse_plan(input: &str) -> Result<Vec<Step>> {
+    parse::lines(input, Step::parser).collect()
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let plan = parse_plan(
+        "R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)",
+    )
+    .unwrap();
+
+    assert_eq!(
+        plan[0],
+        Step {
+            direction: 'R',
+            meters: 6,
+            color: 0x70c710
+        }
+    );
+    assert_eq!(plan[0].decoded(), ('R', 461937));
+    assert_eq!(
+        lagoon_size(plan.iter().map(|step| (step.direction, step.meters))),
+        62
+    );
+    assert_eq!(lagoon_size(plan.iter().map(Step::decoded)), 952408144115);
+}
@@ -0,0 +1,229 @@
+use std::ops::Range;
+
+use eyre::{OptionExt, Result, ensure, eyre};
+use fnv::FnvHashMap;
+use winnow::ascii::alpha1;
+use winnow::combinator::{alt, delimited, opt, separated, separated_pair, terminated};
+use winnow::prelude::*;
+
+use aoc_common::parse::{self, unsigned};
+use aoc_meta::Problem;
+
+pub const APLENTY: Problem = Problem::solved(
+    &|input| System::parse(input)?.accepted_ratings(),
+    &|input| System::parse(input)?.accepted(Hyperrectangle([const { 1..4001 }; 4])),
+);
+
+/// A box of parts, with a range of ratings in each of the `x`, `m`, `a` and `s` categories.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Hyperrectangle([Range<u64>; 4]);
+
+impl Hyperrectangle {
+    fn volume(&self) -> u64 {
+        self.0
+            .iter()
+            .map(|range| range.end.saturating_sub(range.start))
+            .product()
+    }
+
+    /// Split the box into the parts that meet `condition` and those that don't, either of which may be empty.
+    fn split(self, condition: Condition) -> (Self, Self) {
+        let Condition {
+            category,
+            greater,
+            value,
+        } = condition;
+        let Range { start, end } = self.0[category].clone();
+        // The cut falls just after `value` for `>` and just before it for `<`.
+        let cut = (value + u64::from(greater)).clamp(start, end);
+
+        let (mut low, mut high) = (self.clone(), self);
+        low.0[category] = start..cut;
+        high.0[category] = cut..end;
+        if greater { (high, low) } else { (low, high) }
+    }
+}
+
+/// Compares one category of a part's ratings with a value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Condition {
+    category: usize,
+    /// Whether the rating must be greater than `value`, rather than less.
+    greater: bool,
+    value: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Target<'a> {
+    Accept,
+    Reject,
+    Workflow(&'a str),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Rule<'a> {
+    /// The condition a part must meet for the rule to apply, or `None` if it applies to every part.
+    condition: Option<Condition>,
+    target: Target<'a>,
+}
+
+#[derive(Debug)]
+struct System<'a> {
+    workflows: FnvHashMap<&'a str, Vec<Rule<'a>>>,
+    /// The `x`, `m`, `a` and `s` ratings of each part.
+    parts: Vec<[u64; 4]>,
+}
+
+impl System<'_> {
+    /// How many of the parts in `parts` end up accepted, sending the box through the workflows from `in` and
+    /// splitting it wherever a rule applies to only some of it.
+    fn accepted(&self, parts: Hyperrectangle) -> Result<u64> {
+        let mut total = 0;
+        let mut pending = vec![(parts, "in", 0)];
+
+        while let Some((mut parts, name, depth)) = pending.pop() {
+            ensure!(
+                depth <= self.workflows.len(),
+                "the workflows send parts around in circles"
+            );
+            let rules = self
+                .workflows
+                .get(name)
+                .ok_or_else(|| eyre!("no workflow named {name}"))?;
+
+            for rule in rules {
+                let matching = match rule.condition {
+                    Some(condition) => {
+                        let (matching, rest) = parts.split(condition);
+                        parts = rest;
+                        matching
+                    }
+                    None => std::mem::replace(&mut parts, Hyperrectangle(Default::default())),
+                };
+
+                if matching.volume() > 0 {
+                    match rule.target {
+                        Target::Accept => total += matching.volume(),
+                        Target::Reject => {}
+                        Target::Workflow(next) => pending.push((matching, next, depth + 1)),
+                    }
+                }
+            }
+            ensure!(
+                parts.volume() == 0,
+                "workflow {name} doesn't decide what happens to every part"
+            );
+        }
+
+        Ok(total)
+    }
+
+    /// Whether the workflows accept a part with these ratings.
+    fn accepts(&self, part: &[u64; 4]) -> Result<bool> {
+        let parts = Hyperrectangle(part.map(|rating| rating..rating + 1));
+        Ok(self.accepted(parts)? == 1)
+    }
+
+    /// The sum of every rating of every accepted part.
+    fn accepted_ratings(&self) -> Result<u64> {
+        let mut total = 0;
+        for part in &self.parts {
+            if self.accepts(part)? {
+                total += part.iter().sum::<u64>();
+            }
+        }
+        Ok(total)
+    }
+}
+
+impl<'a> System<'a> {
+    fn parse(input: &'a str) -> Result<Self> {
+        let (workflows, parts) = input
+            .split_once("\n\n")
+            .ok_or_eyre("missing the part ratings")?;
+
+        let category = || alt(('x'.value(0), 'm'.value(1), 'a'.value(2), 's'.value(3)));
+        let target = alpha1.map(|name| match name {
+            "A" => Target::Accept,
+            "R" => Target::Reject,
+            _ => Target::Workflow(name),
+        });
+        let condition = (
+            category(),
+            alt(('<'.value(false), '>'.value(true))),
+            unsigned,
+        )
+            .map(|(category, greater, value)| Condition {
+                category,
+                greater,
+                value,
+            });
+        let rule = (opt(terminated(condition, ':')), target)
+            .map(|(condition, target)| Rule { condition, target });
+        let workflow = (alpha1, delimited('{', separated(1.., rule, ','), '}'));
+        let workflows: FnvHashMap<_, _> =
+            parse::lines(workflows, workflow).collect::<Result<_>>()?;
+
+        let rating = separated_pair(category(), '=', unsigned::<u64>);
+        let part = delimited('{', separated(4, rating, ','), '}').verify_map(
+            |ratings: Vec<(usize, u64)>| {
+                // Every category has to be rated exactly once.
+                let mut part = [None; 4];
+                for (category, value) in ratings {
+                    part[category].replace(value).is_none().then_some(())?;
+                }
+                Some(part.map(Option::unwrap))
+            },
+        );
+        let parts = parse::lines(parts, part).collect::<Result<_>>()?;
+
+        Ok(Self { workflows, parts })
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let system = System::parse(
+        "px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,a=2067,s=496}
+{x=2036,m=264,a=79,s=2244}
+{x=2461,m=1339,a=466,s=291}
+{x=2127,m=1623,a=2188,s=1013}",
+    )
+    .unwrap();
+
+    let accepted: Vec<_> = system
+        .parts
+        .iter()
+        .map(|part| system.accepts(part).unwrap())
+        .collect();
+    assert_eq!(accepted, [true, false, true, false, true]);
+    assert_eq!(system.accepted_ratings().unwrap(), 19114);
+    assert_eq!(
+        system
+            .accepted(Hyperrectangle([const { 1..4001 }; 4]))
+            .unwrap(),
+        167409079868000
+    );
+
+    let (matching, rest) = Hyperrectangle([1..11, 1..11, 1..11, 1..11]).split(Condition {
+        category: 1,
+        greater: true,
+        value: 4,
+    });
+    assert_eq!((matching.0[1].clone(), rest.0[1].clone()), (5..11, 1..5));
+}
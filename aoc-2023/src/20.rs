@@ -0,0 +1,272 @@
+use std::collections::VecDeque;
+
+use eyre::{OptionExt, Result, bail, ensure};
+use fnv::FnvHashMap;
+use winnow::ascii::alpha1;
+use winnow::combinator::{opt, separated, separated_pair};
+use winnow::prelude::*;
+use winnow::token::one_of;
+
+use aoc_common::math::lcm;
+use aoc_common::parse;
+use aoc_meta::Problem;
+
+pub const PULSE_PROPAGATION: Problem = Problem::solved(
+    &|input| Network::parse(input).map(|network| network.pulse_product(1000)),
+    &|input| Network::parse(input)?.fewest_presses("rx"),
+);
+
+/// The most button presses to watch the modules feeding the final conjunction for.
+const MAX_PRESSES: u64 = 1 << 20;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Broadcaster,
+    /// Flips between on and off with each low pulse, sending high when it turns on and low when it turns off.
+    FlipFlop,
+    /// Remembers the last pulse from each input, sending low if they were all high and high otherwise.
+    Conjunction,
+    /// Named as a destination but never defined, so it ignores every pulse.
+    Untyped,
+}
+
+#[derive(Debug)]
+struct Module {
+    kind: Kind,
+    /// Each destination module, along with this module's position among its inputs.
+    destinations: Vec<(usize, usize)>,
+    /// The modules sending pulses to this one.
+    inputs: Vec<usize>,
+}
+
+#[derive(Debug)]
+struct Network<'a> {
+    names: Vec<&'a str>,
+    modules: Vec<Module>,
+    broadcaster: usize,
+}
+
+impl<'a> Network<'a> {
+    fn index(&self, name: &str) -> Option<usize> {
+        self.names.iter().position(|&n| n == name)
+    }
+
+    /// The number of low pulses multiplied by the number of high pulses sent over `presses` presses of the button.
+    fn pulse_product(&self, presses: usize) -> u64 {
+        let mut machine = Machine::new(self);
+        let [low, high] = (0..presses).fold([0, 0], |[low, high], _| {
+            let [l, h] = machine.press(|_, _, _| {});
+            [low + l, high + h]
+        });
+        low * high
+    }
+
+    /// The fewest button presses needed to deliver a single low pulse to `target`.
+    ///
+    /// Simulating that many presses would take far too long. Instead this relies on `target` being fed by a single
+    /// conjunction, each of whose inputs sends it a high pulse on a steady cycle: after some number of presses,
+    /// and again after twice as many, which is checked. The conjunction fires low when they all line up, after the
+    /// least common multiple of their cycles.
+    fn fewest_presses(&self, target: &str) -> Result<u64> {
+        let target = self
+            .index(target)
+            .ok_or_else(|| eyre::eyre!("there's no module named {target}"))?;
+        let &[hub] = &self.modules[target].inputs[..] else {
+            bail!("the target should be fed by a single module")
+        };
+        ensure!(
+            self.modules[hub].kind == Kind::Conjunction,
+            "the target should be fed by a conjunction"
+        );
+
+        let inputs = &self.modules[hub].inputs;
+        let mut highs: Vec<Vec<u64>> = vec![Vec::new(); inputs.len()];
+        let mut machine = Machine::new(self);
+
+        for presses in 1..=MAX_PRESSES {
+            machine.press(|from, to, high| {
+                if high && to == hub {
+                    let input = inputs
+                        .iter()
+                        .position(|&input| input == from)
+                        .expect("the pulse came from an input");
+                    if highs[input].last() != Some(&presses) {
+                        highs[input].push(presses);
+                    }
+                }
+            });
+
+            if highs.iter().all(|presses| presses.len() >= 2) {
+                return highs.iter().try_fold(1, |cycles, presses| {
+                    ensure!(
+                        presses[1] == 2 * presses[0],
+                        "the conjunction's inputs don't fire on a steady cycle"
+                    );
+                    lcm(cycles, presses[0]).ok_or_eyre("the cycles take too long to line up")
+                });
+            }
+        }
+
+        bail!("the conjunction's inputs don't fire often enough to find their cycles")
+    }
+
+    fn parse(input: &'a str) -> Result<Self> {
+        let line = separated_pair(
+            (opt(one_of(['%', '&'])), alpha1),
+            " -> ",
+            separated(1.., alpha1, ", ").map(|names: Vec<&str>| names),
+        );
+        let lines: Vec<_> = parse::lines(input, line).collect::<Result<_>>()?;
+
+        let mut names: Vec<&str> = lines.iter().map(|((_, name), _)| *name).collect();
+        let mut index: FnvHashMap<&str, usize> = names
+            .iter()
+            .enumerate()
+            .map(|(i, &name)| (name, i))
+            .collect();
+        ensure!(index.len() == names.len(), "a module is defined twice");
+
+        let mut modules: Vec<Module> = lines
+            .iter()
+            .map(|&((prefix, name), _)| {
+                let kind = match (prefix, name) {
+                    (Some('%'), _) => Kind::FlipFlop,
+                    (Some(_), _) => Kind::Conjunction,
+                    (None, "broadcaster") => Kind::Broadcaster,
+                    (None, _) => bail!("{name} should be a flip-flop or a conjunction"),
+                };
+                Ok(Module {
+                    kind,
+                    destinations: Vec::new(),
+                    inputs: Vec::new(),
+                })
+            })
+            .collect::<Result<_>>()?;
+
+        for (from, (_, destinations)) in lines.iter().enumerate() {
+            for &name in destinations {
+                let to = *index.entry(name).or_insert_with(|| {
+                    names.push(name);
+                    modules.push(Module {
+                        kind: Kind::Untyped,
+                        destinations: Vec::new(),
+                        inputs: Vec::new(),
+                    });
+                    modules.len() - 1
+                });
+                let slot = modules[to].inputs.len();
+                modules[to].inputs.push(from);
+                modules[from].destinations.push((to, slot));
+            }
+        }
+
+        let broadcaster = index
+            .get("broadcaster")
+            .copied()
+            .ok_or_eyre("missing the broadcaster")?;
+        Ok(Self {
+            names,
+            modules,
+            broadcaster,
+        })
+    }
+}
+
+/// The state of every module in a network.
+struct Machine<'a> {
+    network: &'a Network<'a>,
+    /// Whether each flip-flop is on.
+    on: Vec<bool>,
+    /// The last pulse each conjunction received from each of its inputs, `true` for high.
+    memory: Vec<Vec<bool>>,
+}
+
+impl<'a> Machine<'a> {
+    fn new(network: &'a Network<'a>) -> Self {
+        let memory = network
+            .modules
+            .iter()
+            .map(|module| vec![false; module.inputs.len()])
+            .collect();
+        Self {
+            network,
+            on: vec![false; network.modules.len()],
+            memory,
+        }
+    }
+
+    /// Push the button, sending a low pulse to the broadcaster, and process pulses until they die down. Every
+    /// pulse is passed to `observe` as it's delivered, along with the modules it went from and to. Returns how many
+    /// low and high pulses were sent.
+    fn press(&mut self, mut observe: impl FnMut(usize, usize, bool)) -> [u64; 2] {
+        let modules = &self.network.modules;
+        let mut sent = [1, 0];
+        let mut pulses = VecDeque::from([(usize::MAX, self.network.broadcaster, 0, false)]);
+
+        while let Some((from, to, slot, high)) = pulses.pop_front() {
+            observe(from, to, high);
+
+            let output = match modules[to].kind {
+                Kind::Broadcaster => high,
+                Kind::FlipFlop if !high => {
+                    self.on[to] = !self.on[to];
+                    self.on[to]
+                }
+                Kind::Conjunction => {
+                    self.memory[to][slot] = high;
+                    !self.memory[to].iter().all(|&high| high)
+                }
+                _ => continue,
+            };
+
+            sent[usize::from(output)] += modules[to].destinations.len() as u64;
+            pulses.extend(
+                modules[to]
+                    .destinations
+                    .iter()
+                    .map(|&(next, slot)| (to, next, slot, output)),
+            );
+        }
+
+        sent
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let pulses = |input: &str| Network::parse(input).unwrap().pulse_product(1000);
+    assert_eq!(
+        pulses("broadcaster -> a, b, c\n%a -> b\n%b -> c\n%c -> inv\n&inv -> a"),
+        32000000
+    );
+    assert_eq!(
+        pulses("broadcaster -> a\n%a -> inv, con\n&inv -> b\n%b -> con\n&con -> output"),
+        11687500
+    );
+
+    // A chain of flip-flops counting button presses in binary, with inverters after each of them firing high
+    // whenever it turns off: every 2, 4 and 8 presses.
+    let network = Network::parse(
+        "broadcaster -> a
+%a -> b, i
+%b -> c, j
+%c -> k
+&i -> hub
+&j -> hub
+&k -> hub
+&hub -> rx",
+    )
+    .unwrap();
+    assert_eq!(network.fewest_presses("rx").unwrap(), 8);
+
+    let rx = network.index("rx").unwrap();
+    let mut machine = Machine::new(&network);
+    let mut low_to_rx = false;
+    let presses = (1..).find(|_| {
+        machine.press(|_, to, high| low_to_rx |= to == rx && !high);
+        low_to_rx
+    });
+    assert_eq!(presses, Some(8));
+}
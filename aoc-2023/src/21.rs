@@ -0,0 +1,134 @@
+use std::collections::VecDeque;
+
+use eyre::{OptionExt, Result, ensure};
+use fnv::FnvHashSet;
+
+use aoc_meta::Problem;
+
+pub const STEP_COUNTER: Problem = Problem::solved(
+    &|input| Garden::parse(input).map(|garden| garden.reachable(64)),
+    &|input| Garden::parse(input)?.extrapolate(26_501_365),
+);
+
+#[derive(Debug)]
+struct Garden {
+    size: i64,
+    /// Whether each plot (row by row) holds a rock.
+    rocks: Vec<bool>,
+    start: (i64, i64),
+}
+
+impl Garden {
+    fn is_rock(&self, (x, y): (i64, i64)) -> bool {
+        self.rocks[(y.rem_euclid(self.size) * self.size + x.rem_euclid(self.size)) as usize]
+    }
+
+    /// How many garden plots the elf could be on after exactly `steps` steps, with the map repeating infinitely in
+    /// every direction.
+    fn reachable(&self, steps: u64) -> u64 {
+        let mut seen = FnvHashSet::from_iter([self.start]);
+        let mut queue = VecDeque::from([(self.start, 0)]);
+        let mut count = 0;
+
+        // The elf can step back and forth, so any plot first reached after an even number of steps fewer is fine.
+        while let Some(((x, y), distance)) = queue.pop_front() {
+            if distance % 2 == steps % 2 {
+                count += 1;
+            }
+            if distance == steps {
+                continue;
+            }
+            for next in [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)] {
+                if !self.is_rock(next) && seen.insert(next) {
+                    queue.push_back((next, distance + 1));
+                }
+            }
+        }
+
+        count
+    }
+
+    /// How many plots could be reached after `steps` steps, far too many to search.
+    ///
+    /// Every `size` steps takes the elf across another copy of the map, and when the routes across them are
+    /// clear the reachable plots grow quadratically across those copies. The quadratic is found from the counts
+    /// after the first few crossings, checking that it also predicts the next one.
+    fn extrapolate(&self, steps: u64) -> Result<u64> {
+        let size = self.size as u64;
+        let (crossings, remainder) = (steps / size, steps % size);
+        let counts: Vec<i64> = (0..4)
+            .map(|n| self.reachable(remainder + n * size) as i64)
+            .collect();
+
+        let quadratic = |n: i64| {
+            counts[0]
+                + n * (counts[1] - counts[0])
+                + n * (n - 1) / 2 * (counts[2] - 2 * counts[1] + counts[0])
+        };
+        ensure!(
+            quadratic(3) == counts[3],
+            "the reachable plots don't grow quadratically"
+        );
+        Ok(quadratic(crossings as i64) as u64)
+    }
+
+    fn parse(input: &str) -> Result<Self> {
+        let size = input.lines().count();
+        ensure!(
+            input.lines().all(|line| line.len() == size),
+            "the garden should be square"
+        );
+        ensure!(
+            input.bytes().all(|b| b"#.S\n".contains(&b)),
+            "unexpected character in the garden"
+        );
+
+        let start = input
+            .lines()
+            .flat_map(str::bytes)
+            .position(|b| b == b'S')
+            .ok_or_eyre("missing the start")?;
+        let rocks = input
+            .lines()
+            .flat_map(str::bytes)
+            .map(|b| b == b'#')
+            .collect();
+        let size = size as i64;
+        Ok(Self {
+            size,
+            rocks,
+            start: (start as i64 % size, start as i64 / size),
+        })
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let garden = Garden::parse(
+        "...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........",
+    )
+    .unwrap();
+
+    let counts: Vec<_> = [6, 10, 50, 100]
+        .into_iter()
+        .map(|steps| garden.reachable(steps))
+        .collect();
+    assert_eq!(counts, [16, 50, 1594, 6536]);
+
+    // In an empty garden, exactly the plots within the diamond of an even distance are reachable.
+    let empty = Garden::parse(".....\n.....\n..S..\n.....\n.....").unwrap();
+    assert_eq!(empty.reachable(7), 64);
+    assert_eq!(empty.extrapolate(2 + 5 * 1000).unwrap(), 5003 * 5003);
+}
@@ -0,0 +1,152 @@
+use eyre::{Result, ensure};
+use fnv::FnvHashMap;
+use winnow::combinator::{separated_pair, seq};
+use winnow::prelude::*;
+
+use aoc_common::parse::{self, unsigned};
+use aoc_meta::Problem;
+
+pub const SAND_SLABS: Problem = Problem::solved(
+    &|input| parse_bricks(input).map(|bricks| Stack::settle(bricks).safe_to_disintegrate()),
+    &|input| {
+        parse_bricks(input).map(|bricks| Stack::settle(bricks).chain_reactions().sum::<usize>())
+    },
+);
+
+type Brick = ([u32; 3], [u32; 3]);
+
+/// The bricks once they've all fallen as far as they can.
+#[derive(Debug)]
+struct Stack {
+    /// The bricks directly underneath each one (bricks are numbered from the bottom up).
+    supporters: Vec<Vec<usize>>,
+    /// The bricks resting directly on top of each one.
+    supporting: Vec<Vec<usize>>,
+}
+
+impl Stack {
+    fn settle(mut bricks: Vec<Brick>) -> Self {
+        bricks.sort_unstable_by_key(|&(start, _)| start[2]);
+
+        // The height of the top of the stack above each column, along with the brick there.
+        let mut tops: FnvHashMap<(u32, u32), (u32, usize)> = FnvHashMap::default();
+        let mut supporters = Vec::with_capacity(bricks.len());
+
+        for (brick, (start, end)) in bricks.into_iter().enumerate() {
+            let columns: Vec<_> = (start[0]..=end[0])
+                .flat_map(|x| (start[1]..=end[1]).map(move |y| (x, y)))
+                .collect();
+            let base = columns
+                .iter()
+                .filter_map(|column| tops.get(column))
+                .map(|&(z, _)| z)
+                .max()
+                .unwrap_or(0);
+
+            let mut below: Vec<usize> = columns
+                .iter()
+                .filter_map(|column| tops.get(column))
+                .filter(|&&(z, _)| z == base)
+                .map(|&(_, b)| b)
+                .collect();
+            below.sort_unstable();
+            below.dedup();
+            supporters.push(below);
+
+            let top = base + end[2] - start[2] + 1;
+            for column in columns {
+                tops.insert(column, (top, brick));
+            }
+        }
+
+        let mut supporting = vec![Vec::new(); supporters.len()];
+        for (brick, below) in supporters.iter().enumerate() {
+            for &other in below {
+                supporting[other].push(brick);
+            }
+        }
+
+        Self {
+            supporters,
+            supporting,
+        }
+    }
+
+    /// How many bricks could be taken out without any others falling.
+    fn safe_to_disintegrate(&self) -> usize {
+        (0..self.supporting.len())
+            .filter(|&brick| {
+                self.supporting[brick]
+                    .iter()
+                    .all(|&above| self.supporters[above].len() > 1)
+            })
+            .count()
+    }
+
+    /// How many other bricks would fall if each brick were disintegrated.
+    fn chain_reactions(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.supporters.len()).map(|removed| {
+            let mut fallen = vec![false; self.supporters.len()];
+            fallen[removed] = true;
+            let mut count = 0;
+
+            // Bricks only rest on those settled before them, so one pass upwards catches every collapse.
+            for brick in removed + 1..self.supporters.len() {
+                let below = &self.supporters[brick];
+                if !below.is_empty() && below.iter().all(|&other| fallen[other]) {
+                    fallen[brick] = true;
+                    count += 1;
+                }
+            }
+            count
+        })
+    }
+}
+
+fn parse_bricks(input: &str) -> Result<Vec<Brick>> {
+    let corner = || seq!(unsigned, _: ',', unsigned, _: ',', unsigned).map(|(x, y, z)| [x, y, z]);
+    let bricks: Vec<Brick> =
+        parse::lines(input, separated_pair(corner(), '~', corner())).collect::<Result<_>>()?;
+    ensure!(
+        bricks
+            .iter()
+            .all(|(start, end)| start.iter().zip(end).all(|(s, e)| s <= e) && start[2] > 0),
+        "bricks should be listed from their lowest corner, above the ground"
+    );
+    Ok(bricks)
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let bricks = parse_bricks(
+        "1,0,1~1,2,1
+0,0,2~2,0,2
+0,2,3~2,2,3
+0,0,4~0,2,4
+2,0,5~2,2,5
+0,1,6~2,1,6
+1,1,8~1,1,9",
+    )
+    .unwrap();
+
+    let stack = Stack::settle(bricks);
+    assert_eq!(
+        stack.supporters,
+        [
+            vec![],
+            vec![0],
+            vec![0],
+            vec![1, 2],
+            vec![1, 2],
+            vec![3, 4],
+            vec![5]
+        ]
+    );
+    assert_eq!(stack.safe_to_disintegrate(), 5);
+    assert_eq!(
+        stack.chain_reactions().collect::<Vec<_>>(),
+        [6, 0, 0, 0, 0, 1, 0]
+    );
+}
@@ -0,0 +1,155 @@
+use eyre::{OptionExt, Result, ensure};
+
+use aoc_meta::Problem;
+
+pub const A_LONG_WALK: Problem = Problem::solved(
+    &|input| Trails::parse(input)?.longest_hike(true),
+    &|input| Trails::parse(input)?.longest_hike(false),
+);
+
+#[derive(Debug)]
+struct Trails {
+    width: usize,
+    tiles: Vec<u8>,
+    start: usize,
+    end: usize,
+}
+
+impl Trails {
+    /// The open tiles next to `tile`, along with the slope that leads to each of them.
+    fn neighbors(&self, tile: usize) -> impl Iterator<Item = (usize, u8)> + '_ {
+        let x = tile % self.width;
+        [
+            tile.checked_sub(self.width).map(|next| (next, b'^')),
+            (x + 1 < self.width).then(|| (tile + 1, b'>')),
+            Some(tile + self.width)
+                .filter(|&next| next < self.tiles.len())
+                .map(|next| (next, b'v')),
+            (x > 0).then(|| (tile - 1, b'<')),
+        ]
+        .into_iter()
+        .flatten()
+        .filter(|&(next, _)| self.tiles[next] != b'#')
+    }
+
+    /// The tiles where trails meet, after the start and the end.
+    fn junctions(&self) -> Vec<usize> {
+        let mut junctions = vec![self.start, self.end];
+        junctions.extend(
+            (0..self.tiles.len())
+                .filter(|&tile| self.tiles[tile] != b'#' && self.neighbors(tile).count() > 2),
+        );
+        junctions
+    }
+
+    /// The length of the trail from each of the `junctions` to each of its neighbors. If the slopes are
+    /// `slippery`, trails going up them are left out.
+    fn trails(&self, junctions: &[usize], slippery: bool) -> Vec<Vec<(usize, u32)>> {
+        let index = |tile: usize| junctions.iter().position(|&junction| junction == tile);
+
+        junctions
+            .iter()
+            .map(|&junction| {
+                self.neighbors(junction)
+                    .filter_map(|(mut tile, _)| {
+                        let mut previous = junction;
+                        let mut length = 1;
+                        loop {
+                            let slope = self.tiles[tile];
+                            if let Some(other) = index(tile) {
+                                return Some((other, length));
+                            }
+                            let (next, direction) =
+                                self.neighbors(tile).find(|&(next, _)| next != previous)?;
+                            if slippery && slope != b'.' && slope != direction {
+                                return None;
+                            }
+                            (previous, tile) = (tile, next);
+                            length += 1;
+                        }
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// The number of steps in the longest hike from the start to the end that never steps on the same tile twice.
+    fn longest_hike(&self, slippery: bool) -> Result<u32> {
+        let junctions = self.junctions();
+        ensure!(junctions.len() <= 64, "too many junctions to search");
+        let trails = self.trails(&junctions, slippery);
+        longest_path(&trails, 0, 1 << 0).ok_or_eyre("there's no way to the end")
+    }
+
+    fn parse(input: &str) -> Result<Self> {
+        let width = input.lines().next().map_or(0, str::len);
+        ensure!(
+            width > 0 && input.lines().all(|line| line.len() == width),
+            "the map should be a rectangle"
+        );
+
+        let tiles: Vec<u8> = input.lines().flat_map(str::bytes).collect();
+        ensure!(
+            tiles.iter().all(|b| b"#.^>v<".contains(b)),
+            "unexpected tile on the map"
+        );
+        let start = tiles[..width]
+            .iter()
+            .position(|&b| b == b'.')
+            .ok_or_eyre("missing the start")?;
+        let end = tiles.len() - width
+            + tiles[tiles.len() - width..]
+                .iter()
+                .position(|&b| b == b'.')
+                .ok_or_eyre("missing the end")?;
+        Ok(Self {
+            width,
+            tiles,
+            start,
+            end,
+        })
+    }
+}
+
+/// The longest path from junction `from` to the end (junction 1) without revisiting any junction in `visited`.
+fn longest_path(trails: &[Vec<(usize, u32)>], from: usize, visited: u64) -> Option<u32> {
+    if from == 1 {
+        return Some(0);
+    }
+    trails[from]
+        .iter()
+        .filter(|&&(next, _)| visited & 1 << next == 0)
+        .filter_map(|&(next, length)| {
+            Some(length + longest_path(trails, next, visited | 1 << next)?)
+        })
+        .max()
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    // A lattice of trails with a few missing, where the slopes only let hikers go right or down.
+    let trails = Trails::parse(
+        "#.#############
+#.>...>...>...#
+#v###v###v###v#
+#.###.###.###.#
+#.###.###.###.#
+#.###.>...>...#
+#v###v###v###v#
+#.###.###.###.#
+#.###.###.###.#
+#.>...###.>...#
+#v###v#######v#
+#.###.#######.#
+#.###.#######.#
+#.>...>...>...#
+#############.#",
+    )
+    .unwrap();
+
+    assert_eq!(trails.junctions().len(), 2 + 11);
+    assert_eq!(trails.longest_hike(true).unwrap(), 26);
+    assert_eq!(trails.longest_hike(false).unwrap(), 58);
+}
@@ -0,0 +1,199 @@
+use eyre::{OptionExt, Result};
+use itertools::Itertools;
+use winnow::ascii::space0;
+use winnow::combinator::{separated_pair, seq};
+use winnow::prelude::*;
+
+use aoc_common::parse::{self, signed};
+use aoc_meta::Problem;
+
+pub const NEVER_TELL_ME_THE_ODDS: Problem = Problem::solved(
+    &|input| {
+        parse_hailstones(input).map(|hailstones| {
+            future_crossings(&hailstones, 200_000_000_000_000, 400_000_000_000_000)
+        })
+    },
+    &|input| {
+        let [x, y, z] = perfect_throw(&parse_hailstones(input)?)
+            .ok_or_eyre("no throw hits every hailstone")?
+            .position;
+        Ok::<_, eyre::Report>(x + y + z)
+    },
+);
+
+/// The fastest the rock could be thrown along each axis.
+const MAX_THROW_SPEED: i64 = 1000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Hailstone {
+    position: [i64; 3],
+    velocity: [i64; 3],
+}
+
+impl Hailstone {
+    /// When the paths of `self` and `other` cross, ignoring the Z axis: the numerators of the time each hailstone
+    /// gets there, over a common positive denominator. Returns `None` for parallel paths.
+    ///
+    /// Keeping the times as fractions lets the crossing be checked without rounding errors.
+    fn crossing(&self, other: &Self) -> Option<(i128, i128, i128)> {
+        let [px, py, _] = self.position.map(i128::from);
+        let [vx, vy, _] = self.velocity.map(i128::from);
+        let [qx, qy, _] = other.position.map(i128::from);
+        let [wx, wy, _] = other.velocity.map(i128::from);
+
+        // Solve `p + t·v = q + s·w` for `t` and `s` with Cramer's rule.
+        let (dx, dy) = (qx - px, qy - py);
+        let det = wx * vy - vx * wy;
+        let t = wx * dy - wy * dx;
+        let s = vx * dy - vy * dx;
+        match det.signum() {
+            0 => None,
+            sign => Some((sign * t, sign * s, sign * det)),
+        }
+    }
+
+    /// The same hailstone seen from something moving with `velocity`, along the X and Y axes only.
+    fn relative_to(&self, [vx, vy]: [i64; 2]) -> Self {
+        let [x, y, z] = self.velocity;
+        Self {
+            velocity: [x - vx, y - vy, z],
+            ..*self
+        }
+    }
+
+    /// The time at which this hailstone reaches `(x, y)`, ignoring the Z axis, if it ever does so at a whole
+    /// number of nanoseconds.
+    fn time_at(&self, [x, y]: [i128; 2]) -> Option<i128> {
+        let [px, py, _] = self.position.map(i128::from);
+        let [vx, vy, _] = self.velocity.map(i128::from);
+        let (dx, dy) = (x - px, y - py);
+
+        let time = match (vx, vy) {
+            (0, 0) => (dx == 0 && dy == 0).then_some(0),
+            (0, _) => (dx == 0 && dy % vy == 0).then(|| dy / vy),
+            (_, _) => (dx % vx == 0).then(|| dx / vx),
+        }?;
+        (time >= 0 && py + time * vy == y).then_some(time)
+    }
+}
+
+/// How many pairs of hailstones have paths that cross (ignoring the Z axis) within the square between `min` and
+/// `max`, in the future for both of them.
+fn future_crossings(hailstones: &[Hailstone], min: i64, max: i64) -> usize {
+    let (min, max) = (i128::from(min), i128::from(max));
+
+    hailstones
+        .iter()
+        .tuple_combinations()
+        .filter(|(a, b)| {
+            let Some((t, s, det)) = a.crossing(b) else {
+                return false;
+            };
+            let [px, py, _] = a.position.map(i128::from);
+            let [vx, vy, _] = a.velocity.map(i128::from);
+            // Scaled up by `det` to avoid dividing.
+            let inside = |p: i128, v: i128| (min * det..=max * det).contains(&(p * det + t * v));
+            t >= 0 && s >= 0 && inside(px, vx) && inside(py, vy)
+        })
+        .count()
+}
+
+/// The rock thrown from a whole-numbered position with a whole-numbered velocity that hits every hailstone.
+///
+/// Seen from the rock, every hailstone flies straight at its starting position. So for each possible velocity
+/// along the X and Y axes, this finds where the first two (relative) hailstones' paths cross and checks that
+/// every other hailstone passes through that point too, at a whole number of nanoseconds. Those times then fix
+/// the Z axis.
+fn perfect_throw(hailstones: &[Hailstone]) -> Option<Hailstone> {
+    let speeds = || -MAX_THROW_SPEED..=MAX_THROW_SPEED;
+    speeds().cartesian_product(speeds()).find_map(|(vx, vy)| {
+        let relative: Vec<_> = hailstones
+            .iter()
+            .map(|hailstone| hailstone.relative_to([vx, vy]))
+            .collect();
+
+        let (first, rest) = relative.split_first()?;
+        let (t, _, det) = rest.iter().find_map(|other| first.crossing(other))?;
+        let [px, py, _] = first.position.map(i128::from);
+        let [ux, uy, _] = first.velocity.map(i128::from);
+        if t % det != 0 {
+            return None;
+        }
+        let (x, y) = (px + t / det * ux, py + t / det * uy);
+
+        let times: Vec<i128> = relative
+            .iter()
+            .map(|hailstone| hailstone.time_at([x, y]))
+            .collect::<Option<_>>()?;
+
+        // Any two hailstones hit at different times give the rock's velocity along the Z axis.
+        let z_at = |i: usize| {
+            let (p, v) = (
+                i128::from(hailstones[i].position[2]),
+                i128::from(hailstones[i].velocity[2]),
+            );
+            p + times[i] * v
+        };
+        let other = (1..times.len()).find(|&i| times[i] != times[0])?;
+        let (dz, dt) = (z_at(other) - z_at(0), times[other] - times[0]);
+        if dz % dt != 0 {
+            return None;
+        }
+        let vz = dz / dt;
+        let z = z_at(0) - times[0] * vz;
+        if (0..times.len()).any(|i| z + times[i] * vz != z_at(i)) {
+            return None;
+        }
+
+        let [x, y, z, vz] = [x, y, z, vz].map(|n| i64::try_from(n).ok());
+        Some(Hailstone {
+            position: [x?, y?, z?],
+            velocity: [vx, vy, vz?],
+        })
+    })
+}
+
+fn parse_hailstones(input: &str) -> Result<Vec<Hailstone>> {
+    let triple = || {
+        seq!(_: space0, signed, _: (',', space0), signed, _: (',', space0), signed)
+            .map(|(x, y, z)| [x, y, z])
+    };
+    let hailstone = separated_pair(triple(), " @", triple())
+        .map(|(position, velocity)| Hailstone { position, velocity });
+    parse::lines(input, hailstone).collect()
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let hailstones = parse_hailstones(
+        "19, 13, 30 @ -2,  1, -2
+18, 19, 22 @ -1, -1, -2
+20, 25, 34 @ -2, -2, -4
+12, 31, 28 @ -1, -2, -1
+20, 19, 15 @  1, -5, -3",
+    )
+    .unwrap();
+
+    assert_eq!(
+        hailstones[4],
+        Hailstone {
+            position: [20, 19, 15],
+            velocity: [1, -5, -3]
+        }
+    );
+    // The first two cross at x=14.333, y=15.333.
+    assert_eq!(hailstones[0].crossing(&hailstones[1]), Some((7, 11, 3)));
+    assert_eq!(hailstones[1].crossing(&hailstones[2]), None);
+    assert_eq!(future_crossings(&hailstones, 7, 27), 2);
+
+    let rock = perfect_throw(&hailstones).unwrap();
+    assert_eq!(
+        rock,
+        Hailstone {
+            position: [24, 13, 10],
+            velocity: [-3, 1, 2]
+        }
+    );
+}
@@ -0,0 +1,144 @@
+use std::collections::VecDeque;
+
+use eyre::{OptionExt, Result};
+use fnv::FnvHashMap;
+use winnow::ascii::alpha1;
+use winnow::combinator::{separated, separated_pair};
+use winnow::prelude::*;
+
+use aoc_common::parse;
+use aoc_meta::Problem;
+
+pub const SNOWVERLOAD: Problem = Problem::partially_solved(&|input| {
+    let wiring = Wiring::parse(input)?;
+    let group = wiring
+        .split(3)
+        .ok_or_eyre("three wires can't split the components in two")?;
+    Ok::<_, eyre::Report>(group * (wiring.neighbors.len() - group))
+});
+
+#[derive(Debug)]
+struct Wiring {
+    /// The wires connecting each component, to the other component along with the wire's index.
+    neighbors: Vec<Vec<(usize, usize)>>,
+    /// The two components each wire connects.
+    wires: Vec<(usize, usize)>,
+}
+
+impl Wiring {
+    /// The size of one of the two groups left after disconnecting exactly `cut` wires, if that's possible.
+    ///
+    /// The groups are separated by a minimum cut, so the maximum flow from a component in one group to any in the
+    /// other is exactly `cut` wires, sending one unit of flow along each wire. Once the flow from the first
+    /// component reaches a component on the other side, whatever can still be reached through the unsaturated
+    /// wires forms its group.
+    fn split(&self, cut: usize) -> Option<usize> {
+        (1..self.neighbors.len()).find_map(|sink| {
+            let mut flow = vec![0i8; self.wires.len()];
+            for _ in 0..cut {
+                self.augment(&mut flow, sink).ok()?;
+            }
+            let reachable = self.augment(&mut flow, sink).err()?;
+            (reachable < self.neighbors.len()).then_some(reachable)
+        })
+    }
+
+    /// Push one more unit of flow from the first component to `sink` along a path with spare capacity. If there is
+    /// no such path, returns how many components still have spare capacity to them instead.
+    fn augment(&self, flow: &mut [i8], sink: usize) -> Result<(), usize> {
+        // The wire each component was first reached through.
+        let mut parent: Vec<Option<usize>> = vec![None; self.neighbors.len()];
+        let mut seen = vec![false; self.neighbors.len()];
+        let mut queue = VecDeque::from([0]);
+        seen[0] = true;
+
+        // How much flow is going from `from` along wire `wire`.
+        let outflow = |flow: &[i8], from: usize, wire: usize| {
+            if self.wires[wire].0 == from {
+                flow[wire]
+            } else {
+                -flow[wire]
+            }
+        };
+
+        while let Some(component) = queue.pop_front() {
+            if component == sink {
+                let mut node = sink;
+                while let Some(wire) = parent[node] {
+                    let (a, b) = self.wires[wire];
+                    let from = if a == node { b } else { a };
+                    flow[wire] += if self.wires[wire].0 == from { 1 } else { -1 };
+                    node = from;
+                }
+                return Ok(());
+            }
+
+            for &(next, wire) in &self.neighbors[component] {
+                if !seen[next] && outflow(flow, component, wire) < 1 {
+                    seen[next] = true;
+                    parent[next] = Some(wire);
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        Err(seen.iter().filter(|&&seen| seen).count())
+    }
+
+    fn parse(input: &str) -> Result<Self> {
+        let line = separated_pair(
+            alpha1,
+            ": ",
+            separated(1.., alpha1, ' ').map(|names: Vec<&str>| names),
+        );
+        let mut index: FnvHashMap<&str, usize> = FnvHashMap::default();
+        let mut neighbors: Vec<Vec<(usize, usize)>> = Vec::new();
+        let mut wires = Vec::new();
+
+        for connections in parse::lines(input, line) {
+            let (name, others) = connections?;
+            let mut component = |name| {
+                *index.entry(name).or_insert_with(|| {
+                    neighbors.push(Vec::new());
+                    neighbors.len() - 1
+                })
+            };
+            let from = component(name);
+            let others: Vec<_> = others.into_iter().map(&mut component).collect();
+            for to in others {
+                neighbors[from].push((to, wires.len()));
+                neighbors[to].push((from, wires.len()));
+                wires.push((from, to));
+            }
+        }
+
+        Ok(Self { neighbors, wires })
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let wiring = Wiring::parse(
+        "jqt: rhn xhk nvd
+rsh: frs pzl lsr
+xhk: hfx
+cmg: qnr nvd lhk bvb
+rhn: xhk bvb hfx
+bvb: xhk hfx
+pzl: lsr hfx nvd
+qnr: nvd
+ntq: jqt hfx bvb xhk
+nvd: lhk
+lsr: lhk
+rzs: qnr cmg lsr rsh
+frs: qnr lhk lsr",
+    )
+    .unwrap();
+
+    assert_eq!(wiring.neighbors.len(), 15);
+    let group = wiring.split(3).unwrap();
+    assert_eq!(group * (15 - group), 54);
+    assert_eq!(wiring.split(2), None);
+}
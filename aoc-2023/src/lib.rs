@@ -1,3 +1,31 @@
 use aoc_meta::PROBLEMS;
 
-PROBLEMS! {}
+mod polygon;
+
+PROBLEMS! {
+    01 => TREBUCHET,
+    02 => CUBE_CONUNDRUM,
+    03 => GEAR_RATIOS,
+    04 => SCRATCHCARDS,
+    05 => IF_YOU_GIVE_A_SEED_A_FERTILIZER,
+    06 => WAIT_FOR_IT,
+    07 => CAMEL_CARDS,
+    08 => HAUNTED_WASTELAND,
+    09 => MIRAGE_MAINTENANCE,
+    10 => PIPE_MAZE,
+    11 => COSMIC_EXPANSION,
+    12 => HOT_SPRINGS,
+    13 => POINT_OF_INCIDENCE,
+    14 => PARABOLIC_REFLECTOR_DISH,
+    15 => LENS_LIBRARY,
+    16 => THE_FLOOR_WILL_BE_LAVA,
+    17 => CLUMSY_CRUCIBLE,
+    18 => LAVADUCT_LAGOON,
+    19 => APLENTY,
+    20 => PULSE_PROPAGATION,
+    21 => STEP_COUNTER,
+    22 => SAND_SLABS,
+    23 => A_LONG_WALK,
+    24 => NEVER_TELL_ME_THE_ODDS,
+    25 => SNOWVERLOAD,
+}
@@ -0,0 +1,40 @@
+//! Counting lattice points in polygons whose vertices lie on grid points.
+
+/// The number of lattice points strictly inside and on the boundary of the simple polygon with `vertices`, listed
+/// in order around its perimeter with each edge horizontal or vertical.
+///
+/// The area comes from the shoelace formula, and Pick's theorem (`A = I + B/2 - 1`) recovers the interior points
+/// from it.
+pub fn lattice_points(vertices: &[(i64, i64)]) -> (i64, i64) {
+    let edges = || vertices.iter().zip(vertices.iter().cycle().skip(1));
+    let twice_area = edges()
+        .map(|(&(x1, y1), &(x2, y2))| x1 * y2 - x2 * y1)
+        .sum::<i64>()
+        .abs();
+    let boundary = edges()
+        .map(|(&(x1, y1), &(x2, y2))| (x2 - x1).abs() + (y2 - y1).abs())
+        .sum::<i64>();
+    ((twice_area - boundary) / 2 + 1, boundary)
+}
+
+#[test]
+fn squares() {
+    use pretty_assertions::assert_eq;
+
+    assert_eq!(lattice_points(&[(0, 0), (4, 0), (4, 4), (0, 4)]), (9, 16));
+    assert_eq!(lattice_points(&[(0, 0), (0, 4), (4, 4), (4, 0)]), (9, 16));
+    // An L shape, with every point along its edges given as a vertex.
+    let l = [
+        (0, 0),
+        (1, 0),
+        (2, 0),
+        (2, 1),
+        (2, 2),
+        (1, 2),
+        (1, 3),
+        (0, 3),
+        (0, 2),
+        (0, 1),
+    ];
+    assert_eq!(lattice_points(&l), (1, 10));
+}
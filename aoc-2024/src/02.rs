@@ -3,7 +3,8 @@ use rayon::prelude::*;
 
 use aoc_meta::Problem;
 
-pub const RED_NOSED_REPORTS: Problem = Problem::partially_solved(&count_safe_levels);
+pub const RED_NOSED_REPORTS: Problem =
+    Problem::solved(&count_safe_levels, &count_dampened_safe_levels);
 
 fn count_safe_levels(input: &str) -> usize {
     input
@@ -13,6 +14,14 @@ fn count_safe_levels(input: &str) -> usize {
         .count()
 }
 
+fn count_dampened_safe_levels(input: &str) -> usize {
+    input
+        .par_lines()
+        .map(Report::parse)
+        .filter_map(|report| report.is_safe_with_dampener().then_some(()))
+        .count()
+}
+
 #[derive(Debug)]
 struct Report<I> {
     levels: I,
@@ -44,6 +53,78 @@ impl<I: Iterator<Item = u8>> Report<I> {
             has_matching_gradient && has_safe_gradient
         })
     }
+
+    /// Whether the report is safe once the Problem Dampener has removed (at most) one bad level.
+    ///
+    /// Rather than checking the report with each level removed in turn, this makes one pass over the levels, keeping
+    /// track of whether each prefix is safe with or without a removal for both directions at once.
+    #[allow(clippy::wrong_self_convention, reason = "same as `is_safe`")]
+    fn is_safe_with_dampener(self) -> bool {
+        let [increasing, decreasing] = self.fold(
+            [Dampener::new(false), Dampener::new(true)],
+            |dampeners, level| dampeners.map(|dampener| dampener.push(level)),
+        );
+
+        increasing.is_safe() || decreasing.is_safe()
+    }
+}
+
+/// The state of a one-pass check that levels change safely in one direction, with at most one level removed.
+#[derive(Debug, Clone, Copy)]
+struct Dampener {
+    decreasing: bool,
+    /// The last two levels, most recent last.
+    last: [Option<u8>; 2],
+    /// Whether the levels up to the last one are safe without removing any.
+    clean: bool,
+    /// Whether the levels up to the second-last one are safe without removing any.
+    clean_before: bool,
+    /// Whether the levels up to the last one are safe with one of them (but not the last) removed.
+    dampened: bool,
+}
+
+impl Dampener {
+    fn new(decreasing: bool) -> Self {
+        Self {
+            decreasing,
+            last: [None; 2],
+            clean: true,
+            clean_before: true,
+            dampened: false,
+        }
+    }
+
+    #[inline]
+    fn is_step_safe(&self, from: Option<u8>, to: u8) -> bool {
+        from.is_none_or(|from| {
+            let (low, high) = if self.decreasing {
+                (to, from)
+            } else {
+                (from, to)
+            };
+            (1..=3).contains(&(i16::from(high) - i16::from(low)))
+        })
+    }
+
+    fn push(self, level: u8) -> Self {
+        let [before, last] = self.last;
+
+        Self {
+            decreasing: self.decreasing,
+            last: [last, Some(level)],
+            clean: self.clean && self.is_step_safe(last, level),
+            clean_before: self.clean,
+            // Either something earlier was removed and this level follows on, or the last level is removed.
+            dampened: (self.dampened && self.is_step_safe(last, level))
+                || (last.is_some() && self.clean_before && self.is_step_safe(before, level)),
+        }
+    }
+
+    /// Whether the levels pushed so far are safe with at most one removed, where removing the last level is the
+    /// only option not already covered.
+    fn is_safe(&self) -> bool {
+        self.clean || self.dampened || self.clean_before
+    }
 }
 
 impl<I: Iterator<Item = u8>> Iterator for Report<I> {
@@ -57,3 +138,42 @@ impl<I: Iterator<Item = u8>> Iterator for Report<I> {
         self.levels.size_hint()
     }
 }
+
+#[test]
+fn example() {
+    let input = "7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1
+1 3 2 4 5
+8 6 4 4 1
+1 3 6 7 9";
+
+    assert_eq!(count_safe_levels(input), 2);
+    assert_eq!(count_dampened_safe_levels(input), 4);
+}
+
+#[test]
+fn dampener_matches_removing_each_level() {
+    let is_safe = |levels: &[u8]| {
+        Report {
+            levels: levels.iter().copied(),
+        }
+        .is_safe()
+    };
+
+    for len in 0..=6 {
+        for levels in itertools::repeat_n(1..=6, len).multi_cartesian_product() {
+            let brute_force = is_safe(&levels)
+                || (0..len).any(|skip| {
+                    let mut levels = levels.clone();
+                    levels.remove(skip);
+                    is_safe(&levels)
+                });
+
+            let report = Report {
+                levels: levels.iter().copied(),
+            };
+            assert_eq!(report.is_safe_with_dampener(), brute_force, "{levels:?}");
+        }
+    }
+}
@@ -0,0 +1,131 @@
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+use eyre::{OptionExt, Report, Result};
+use fnv::FnvHashSet;
+use itertools::Itertools;
+
+use aoc_meta::Problem;
+
+pub const PRINT_QUEUE: Problem = Problem::solved(
+    &|input| {
+        input
+            .parse()
+            .map(|queue: PrintQueue| queue.sum_correct_middles())
+    },
+    &|input| {
+        input
+            .parse()
+            .map(|queue: PrintQueue| queue.sum_fixed_middles())
+    },
+);
+
+#[derive(Debug)]
+struct PrintQueue {
+    /// Pairs `(a, b)` where page `a` has to be printed before page `b`.
+    rules: FnvHashSet<(u8, u8)>,
+    updates: Vec<Vec<u8>>,
+}
+
+impl PrintQueue {
+    fn compare(&self, a: u8, b: u8) -> Ordering {
+        if self.rules.contains(&(a, b)) {
+            Ordering::Less
+        } else if self.rules.contains(&(b, a)) {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    }
+
+    fn is_ordered(&self, update: &[u8]) -> bool {
+        update.is_sorted_by(|&a, &b| self.compare(a, b) != Ordering::Greater)
+    }
+
+    fn sum_correct_middles(&self) -> u32 {
+        self.updates
+            .iter()
+            .filter(|update| self.is_ordered(update))
+            .map(|update| u32::from(update[update.len() / 2]))
+            .sum()
+    }
+
+    fn sum_fixed_middles(&self) -> u32 {
+        self.updates
+            .iter()
+            .filter(|update| !self.is_ordered(update))
+            .map(|update| {
+                let mut update = update.clone();
+                let middle = update.len() / 2;
+
+                // Only the middle page matters, so there's no need to sort the whole update.
+                update.select_nth_unstable_by(middle, |&a, &b| self.compare(a, b));
+                u32::from(update[middle])
+            })
+            .sum()
+    }
+}
+
+impl FromStr for PrintQueue {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (rules, updates) = s
+            .split_once("\n\n")
+            .ok_or_eyre("unable to split rules from updates")?;
+
+        let rules = rules
+            .lines()
+            .map(|line| {
+                let (a, b) = line.split_once('|').ok_or_eyre("rule is missing '|'")?;
+                Ok((a.parse()?, b.parse()?))
+            })
+            .collect::<Result<_>>()?;
+
+        let updates = updates
+            .lines()
+            .map(|line| line.split(',').map(str::parse).try_collect())
+            .try_collect()?;
+
+        Ok(Self { rules, updates })
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let queue: PrintQueue = "47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47"
+        .parse()
+        .unwrap();
+
+    assert_eq!(queue.sum_correct_middles(), 143);
+    assert_eq!(queue.sum_fixed_middles(), 123);
+}
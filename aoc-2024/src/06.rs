@@ -0,0 +1,156 @@
+use std::str::FromStr;
+
+use eyre::{OptionExt, Report, Result, bail};
+use rayon::prelude::*;
+
+use aoc_meta::Problem;
+
+pub const GUARD_GALLIVANT: Problem = Problem::solved(
+    &|input| input.parse().map(|lab: Lab| lab.patrol().len()),
+    &|input| {
+        input
+            .parse()
+            .map(|lab: Lab| lab.count_looping_obstructions())
+    },
+);
+
+const UP: usize = 0;
+
+#[derive(Debug, Clone)]
+struct Lab {
+    width: usize,
+    height: usize,
+    obstructed: Vec<bool>,
+    start: usize,
+}
+
+impl Lab {
+    /// Take one step from `position` facing `direction` (0 is up, then clockwise), turning right at obstructions,
+    /// or return `None` if the guard walks off the map.
+    #[inline]
+    fn step(
+        &self,
+        position: usize,
+        direction: usize,
+        extra: Option<usize>,
+    ) -> Option<(usize, usize)> {
+        let (x, y) = (position % self.width, position / self.width);
+
+        let next = match direction {
+            0 => (y > 0).then(|| position - self.width),
+            1 => (x + 1 < self.width).then_some(position + 1),
+            2 => (y + 1 < self.height).then_some(position + self.width),
+            _ => (x > 0).then(|| position - 1),
+        }?;
+
+        if self.obstructed[next] || extra == Some(next) {
+            Some((position, (direction + 1) % 4))
+        } else {
+            Some((next, direction))
+        }
+    }
+
+    /// Every position the guard visits before leaving, in the order they're first visited.
+    fn patrol(&self) -> Vec<usize> {
+        let mut seen = vec![false; self.obstructed.len()];
+        let mut visited = vec![self.start];
+        seen[self.start] = true;
+
+        let (mut position, mut direction) = (self.start, UP);
+        while let Some(next) = self.step(position, direction, None) {
+            (position, direction) = next;
+
+            if !seen[position] {
+                seen[position] = true;
+                visited.push(position);
+            }
+        }
+
+        visited
+    }
+
+    /// Whether the guard gets stuck going round in circles with an extra obstruction at `extra`.
+    fn loops_with(&self, extra: usize) -> bool {
+        let mut seen = vec![0u8; self.obstructed.len()];
+        let (mut position, mut direction) = (self.start, UP);
+
+        while let Some(next) = self.step(position, direction, Some(extra)) {
+            // Only turns need checking, since the guard can't repeat a state without repeating a turn.
+            if next.0 == position {
+                if seen[position] & (1 << direction) != 0 {
+                    return true;
+                }
+
+                seen[position] |= 1 << direction;
+            }
+
+            (position, direction) = next;
+        }
+
+        false
+    }
+
+    /// An obstruction only changes anything if it's somewhere the guard would have walked.
+    fn count_looping_obstructions(&self) -> usize {
+        self.patrol()[1..]
+            .par_iter()
+            .filter(|&&extra| self.loops_with(extra))
+            .count()
+    }
+}
+
+impl FromStr for Lab {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let width = s.lines().next().ok_or_eyre("empty map")?.len();
+        let mut obstructed = Vec::new();
+        let mut start = None;
+
+        for line in s.lines() {
+            if line.len() != width {
+                bail!("map isn't rectangular");
+            }
+
+            for ch in line.chars() {
+                match ch {
+                    '#' => obstructed.push(true),
+                    '.' => obstructed.push(false),
+                    '^' => {
+                        start = Some(obstructed.len());
+                        obstructed.push(false);
+                    }
+                    _ => bail!("unknown map tile '{ch}'"),
+                }
+            }
+        }
+
+        Ok(Self {
+            width,
+            height: obstructed.len() / width,
+            obstructed,
+            start: start.ok_or_eyre("couldn't find the guard")?,
+        })
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let lab: Lab = "....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#..."
+        .parse()
+        .unwrap();
+
+    assert_eq!(lab.patrol().len(), 41);
+    assert_eq!(lab.count_looping_obstructions(), 6);
+}
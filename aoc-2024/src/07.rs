@@ -0,0 +1,88 @@
+use std::str::FromStr;
+
+use eyre::{OptionExt, Report, Result};
+use itertools::Itertools;
+use rayon::prelude::*;
+
+use aoc_meta::Problem;
+
+pub const BRIDGE_REPAIR: Problem =
+    Problem::solved(&|input| total_calibration(input, false), &|input| {
+        total_calibration(input, true)
+    });
+
+fn total_calibration(input: &str, concatenation: bool) -> Result<u64> {
+    let equations: Vec<Equation> = input.lines().map(str::parse).try_collect()?;
+
+    Ok(equations
+        .par_iter()
+        .filter(|equation| equation.is_possible(concatenation))
+        .map(|equation| equation.test_value)
+        .sum())
+}
+
+#[derive(Debug)]
+struct Equation {
+    test_value: u64,
+    numbers: Vec<u64>,
+}
+
+impl Equation {
+    fn is_possible(&self, concatenation: bool) -> bool {
+        solvable(self.test_value, &self.numbers, concatenation)
+    }
+}
+
+/// Operators are evaluated left to right, so work backwards from the result, undoing the last operator.
+fn solvable(target: u64, numbers: &[u64], concatenation: bool) -> bool {
+    let Some((&last, rest)) = numbers.split_last() else {
+        return false;
+    };
+
+    if rest.is_empty() {
+        return target == last;
+    }
+
+    let by_multiplying =
+        last != 0 && target.is_multiple_of(last) && solvable(target / last, rest, concatenation);
+    let by_adding = target >= last && solvable(target - last, rest, concatenation);
+    let by_concatenating = concatenation && {
+        let shift = 10u64.pow(last.checked_ilog10().unwrap_or(0) + 1);
+        target % shift == last && target > last && solvable(target / shift, rest, concatenation)
+    };
+
+    by_multiplying || by_adding || by_concatenating
+}
+
+impl FromStr for Equation {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (test_value, numbers) = s
+            .split_once(": ")
+            .ok_or_eyre("unable to split test value from numbers")?;
+
+        Ok(Self {
+            test_value: test_value.parse()?,
+            numbers: numbers.split(' ').map(str::parse).try_collect()?,
+        })
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let input = "190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20";
+
+    assert_eq!(total_calibration(input, false).unwrap(), 3749);
+    assert_eq!(total_calibration(input, true).unwrap(), 11387);
+}
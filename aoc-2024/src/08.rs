@@ -0,0 +1,108 @@
+use std::str::FromStr;
+
+use eyre::{OptionExt, Report, Result, bail};
+use fnv::{FnvHashMap, FnvHashSet};
+use itertools::Itertools;
+
+use aoc_common::math::gcd;
+use aoc_meta::Problem;
+
+pub const RESONANT_COLLINEARITY: Problem = Problem::solved(
+    &|input| input.parse().map(|city: City| city.count_antinodes(false)),
+    &|input| input.parse().map(|city: City| city.count_antinodes(true)),
+);
+
+#[derive(Debug)]
+struct City {
+    width: i32,
+    height: i32,
+    antennas: FnvHashMap<char, Vec<(i32, i32)>>,
+}
+
+impl City {
+    fn contains(&self, (x, y): (i32, i32)) -> bool {
+        (0..self.width).contains(&x) && (0..self.height).contains(&y)
+    }
+
+    /// With `harmonics`, every position in line with two antennas of the same frequency is an antinode. Otherwise,
+    /// only the ones twice as far from one antenna as the other are.
+    fn count_antinodes(&self, harmonics: bool) -> usize {
+        let mut antinodes = FnvHashSet::default();
+
+        for antennas in self.antennas.values() {
+            for (&(ax, ay), &(bx, by)) in antennas.iter().tuple_combinations() {
+                let (dx, dy) = (bx - ax, by - ay);
+
+                if harmonics {
+                    let step = gcd(dx.unsigned_abs().into(), dy.unsigned_abs().into()) as i32;
+                    let (dx, dy) = (dx / step, dy / step);
+
+                    for sign in [1, -1] {
+                        let line = (0..).map(|n| (ax + sign * n * dx, ay + sign * n * dy));
+                        antinodes.extend(line.take_while(|&pos| self.contains(pos)));
+                    }
+                } else {
+                    antinodes.extend(
+                        [(ax - dx, ay - dy), (bx + dx, by + dy)]
+                            .into_iter()
+                            .filter(|&pos| self.contains(pos)),
+                    );
+                }
+            }
+        }
+
+        antinodes.len()
+    }
+}
+
+impl FromStr for City {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let width = s.lines().next().ok_or_eyre("empty map")?.len();
+        let mut antennas: FnvHashMap<char, Vec<_>> = FnvHashMap::default();
+
+        for (y, line) in s.lines().enumerate() {
+            if line.len() != width {
+                bail!("map isn't rectangular");
+            }
+
+            for (x, ch) in line.chars().enumerate() {
+                if ch.is_ascii_alphanumeric() {
+                    antennas.entry(ch).or_default().push((x as i32, y as i32));
+                } else if ch != '.' {
+                    bail!("unknown map tile '{ch}'");
+                }
+            }
+        }
+
+        Ok(Self {
+            width: width as i32,
+            height: s.lines().count() as i32,
+            antennas,
+        })
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let city: City = "............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............"
+        .parse()
+        .unwrap();
+
+    assert_eq!(city.count_antinodes(false), 14);
+    assert_eq!(city.count_antinodes(true), 34);
+}
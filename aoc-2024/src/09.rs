@@ -0,0 +1,131 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use eyre::{Result, eyre};
+
+use aoc_meta::Problem;
+
+pub const DISK_FRAGMENTER: Problem = Problem::solved(
+    &|input| parse_disk_map(input).map(|files| compact_blocks(&files)),
+    &|input| parse_disk_map(input).map(|files| compact_files(&files)),
+);
+
+/// A file's ID is its index, and it has `len` blocks starting at block `start`.
+#[derive(Debug, Clone, Copy)]
+struct File {
+    start: u64,
+    len: u64,
+}
+
+impl File {
+    fn checksum(id: usize, start: u64, len: u64) -> u64 {
+        // id * (start + (start + 1) + ... + (start + len - 1))
+        id as u64 * (len * start + len * len.saturating_sub(1) / 2)
+    }
+}
+
+fn parse_disk_map(input: &str) -> Result<Vec<File>> {
+    let mut position = 0;
+
+    input
+        .trim_end()
+        .bytes()
+        .map(|b| match b {
+            b'0'..=b'9' => Ok(u64::from(b - b'0')),
+            _ => Err(eyre!("unexpected character '{}' in disk map", b as char)),
+        })
+        .enumerate()
+        .filter_map(|(i, len)| {
+            let len = match len {
+                Ok(len) => len,
+                Err(err) => return Some(Err(err)),
+            };
+
+            let start = position;
+            position += len;
+            i.is_multiple_of(2).then_some(Ok(File { start, len }))
+        })
+        .collect()
+}
+
+/// Move blocks one at a time from the end of the disk to the leftmost free block.
+fn compact_blocks(files: &[File]) -> u64 {
+    let mut checksum = 0;
+    let mut remaining: Vec<u64> = files.iter().map(|file| file.len).collect();
+    let mut last = files.len();
+
+    for (id, file) in files.iter().enumerate() {
+        if id >= last {
+            break;
+        }
+
+        checksum += File::checksum(id, file.start, remaining[id]);
+
+        // Fill the gap after this file with blocks from the end.
+        let mut gap_start = file.start + file.len;
+        let gap_end = files.get(id + 1).map_or(gap_start, |next| next.start);
+
+        while gap_start < gap_end && last > id + 1 {
+            let moved = remaining[last - 1].min(gap_end - gap_start);
+            checksum += File::checksum(last - 1, gap_start, moved);
+            gap_start += moved;
+            remaining[last - 1] -= moved;
+
+            if remaining[last - 1] == 0 {
+                last -= 1;
+            }
+        }
+    }
+
+    checksum
+}
+
+/// Move whole files, from the highest ID down, to the leftmost gap before them they fit in.
+fn compact_files(files: &[File]) -> u64 {
+    // Gaps of each length (up to 9), leftmost first.
+    let mut gaps: [BinaryHeap<Reverse<u64>>; 10] = Default::default();
+    for (file, next) in files.iter().zip(&files[1..]) {
+        let start = file.start + file.len;
+        let len = next.start - start;
+        gaps[len as usize].push(Reverse(start));
+    }
+
+    let mut checksum = 0;
+
+    for (id, file) in files.iter().enumerate().rev() {
+        let leftmost = (file.len as usize..gaps.len())
+            .filter_map(|len| gaps[len].peek().map(|&Reverse(start)| (start, len)))
+            .filter(|&(start, _)| start < file.start)
+            .min();
+
+        let start = match leftmost {
+            Some((start, len)) => {
+                gaps[len].pop();
+                let left = len as u64 - file.len;
+                gaps[left as usize].push(Reverse(start + file.len));
+                start
+            }
+            None => file.start,
+        };
+
+        checksum += File::checksum(id, start, file.len);
+    }
+
+    checksum
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let files = parse_disk_map("2333133121414131402").unwrap();
+    assert_eq!(compact_blocks(&files), 1928);
+    assert_eq!(compact_files(&files), 2858);
+
+    let files = parse_disk_map("12345").unwrap();
+    // 022111222
+    assert_eq!(
+        compact_blocks(&files),
+        2 + 2 * 2 + 3 + 4 + 5 + 2 * (6 + 7 + 8)
+    );
+}
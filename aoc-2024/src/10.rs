@@ -0,0 +1,125 @@
+use std::str::FromStr;
+
+use eyre::{OptionExt, Report, Result, bail, eyre};
+
+use aoc_meta::Problem;
+
+pub const HOOF_IT: Problem = Problem::solved(
+    &|input| input.parse().map(|map: TopographicMap| map.total_score()),
+    &|input| input.parse().map(|map: TopographicMap| map.total_rating()),
+);
+
+#[derive(Debug)]
+struct TopographicMap {
+    width: usize,
+    heights: Vec<u8>,
+}
+
+impl TopographicMap {
+    /// The positions one step uphill from `position`.
+    fn uphill(&self, position: usize) -> impl Iterator<Item = usize> + '_ {
+        let (x, y) = (position % self.width, position / self.width);
+        let height = self.heights.len() / self.width;
+
+        [
+            (y > 0).then(|| position - self.width),
+            (x > 0).then(|| position - 1),
+            (x + 1 < self.width).then_some(position + 1),
+            (y + 1 < height).then_some(position + self.width),
+        ]
+        .into_iter()
+        .flatten()
+        .filter(move |&next| self.heights[next] == self.heights[position] + 1)
+    }
+
+    fn trailheads(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.heights.len()).filter(|&position| self.heights[position] == 0)
+    }
+
+    /// The sum over trailheads of how many peaks can be reached from each of them.
+    fn total_score(&self) -> usize {
+        let mut seen = vec![usize::MAX; self.heights.len()];
+
+        self.trailheads()
+            .map(|trailhead| {
+                let mut stack = vec![trailhead];
+                let mut peaks = 0;
+
+                while let Some(position) = stack.pop() {
+                    if seen[position] == trailhead {
+                        continue;
+                    }
+
+                    seen[position] = trailhead;
+                    if self.heights[position] == 9 {
+                        peaks += 1;
+                    }
+
+                    stack.extend(self.uphill(position));
+                }
+
+                peaks
+            })
+            .sum()
+    }
+
+    /// The sum over trailheads of how many different hiking trails start at each of them.
+    fn total_rating(&self) -> u64 {
+        let mut trails = vec![0; self.heights.len()];
+
+        for height in (0..=9).rev() {
+            for position in (0..self.heights.len()).filter(|&p| self.heights[p] == height) {
+                trails[position] = if height == 9 {
+                    1
+                } else {
+                    self.uphill(position).map(|next| trails[next]).sum()
+                };
+            }
+        }
+
+        self.trailheads().map(|trailhead| trails[trailhead]).sum()
+    }
+}
+
+impl FromStr for TopographicMap {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let width = s.lines().next().ok_or_eyre("empty map")?.len();
+        let mut heights = Vec::new();
+
+        for line in s.lines() {
+            if line.len() != width {
+                bail!("map isn't rectangular");
+            }
+
+            for ch in line.chars() {
+                let height = ch
+                    .to_digit(10)
+                    .ok_or_else(|| eyre!("unknown height '{ch}'"))?;
+                heights.push(height as u8);
+            }
+        }
+
+        Ok(Self { width, heights })
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let map: TopographicMap = "89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732"
+        .parse()
+        .unwrap();
+
+    assert_eq!(map.total_score(), 36);
+    assert_eq!(map.total_rating(), 81);
+}
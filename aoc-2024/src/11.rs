@@ -0,0 +1,55 @@
+use eyre::Result;
+use fnv::FnvHashMap;
+
+use aoc_meta::Problem;
+
+pub const PLUTONIAN_PEBBLES: Problem =
+    Problem::solved(&|input| count_stones(input, 25), &|input| {
+        count_stones(input, 75)
+    });
+
+/// The stones don't affect each other, and there are only so many different numbers on them, so keep a count of
+/// each number instead of the whole line of stones.
+fn count_stones(input: &str, blinks: usize) -> Result<u64> {
+    let mut stones: FnvHashMap<u64, u64> = FnvHashMap::default();
+    for stone in input.split_ascii_whitespace() {
+        *stones.entry(stone.parse()?).or_default() += 1;
+    }
+
+    for _ in 0..blinks {
+        let mut next = FnvHashMap::with_capacity_and_hasher(stones.len(), Default::default());
+
+        for (stone, count) in stones {
+            for stone in blink(stone).into_iter().flatten() {
+                *next.entry(stone).or_default() += count;
+            }
+        }
+
+        stones = next;
+    }
+
+    Ok(stones.values().sum())
+}
+
+fn blink(stone: u64) -> [Option<u64>; 2] {
+    if stone == 0 {
+        return [Some(1), None];
+    }
+
+    let digits = stone.ilog10() + 1;
+    if digits.is_multiple_of(2) {
+        let half = 10u64.pow(digits / 2);
+        [Some(stone / half), Some(stone % half)]
+    } else {
+        [Some(stone * 2024), None]
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    assert_eq!(count_stones("125 17", 6).unwrap(), 22);
+    assert_eq!(count_stones("125 17", 25).unwrap(), 55312);
+    assert_eq!(blink(1000), [Some(10), Some(0)]);
+}
@@ -0,0 +1,149 @@
+use std::str::FromStr;
+
+use eyre::{OptionExt, Report, Result, bail};
+
+use aoc_meta::Problem;
+
+pub const GARDEN_GROUPS: Problem = Problem::solved(
+    &|input| {
+        input
+            .parse()
+            .map(|garden: Garden| garden.fencing_price(false))
+    },
+    &|input| {
+        input
+            .parse()
+            .map(|garden: Garden| garden.fencing_price(true))
+    },
+);
+
+#[derive(Debug)]
+struct Garden {
+    width: usize,
+    height: usize,
+    plots: Vec<u8>,
+}
+
+impl Garden {
+    /// The plant at `(x, y)`, or `None` outside the garden.
+    #[inline]
+    fn plant(&self, x: isize, y: isize) -> Option<u8> {
+        let (x, y) = (usize::try_from(x).ok()?, usize::try_from(y).ok()?);
+        (x < self.width && y < self.height).then(|| self.plots[y * self.width + x])
+    }
+
+    /// The total price of fencing every region, which is its area times either its perimeter, or with a `bulk`
+    /// discount, its number of sides.
+    fn fencing_price(&self, bulk: bool) -> usize {
+        let mut seen = vec![false; self.plots.len()];
+        let mut price = 0;
+
+        for start in 0..self.plots.len() {
+            if seen[start] {
+                continue;
+            }
+
+            let plant = self.plots[start];
+            let (mut area, mut fences) = (0, 0);
+            let mut stack = vec![start];
+            seen[start] = true;
+
+            while let Some(idx) = stack.pop() {
+                let (x, y) = ((idx % self.width) as isize, (idx / self.width) as isize);
+                let same = |dx, dy| self.plant(x + dx, y + dy) == Some(plant);
+                area += 1;
+
+                for (dx, dy) in [(0, -1), (1, 0), (0, 1), (-1, 0)] {
+                    if same(dx, dy) {
+                        let next = (y + dy) as usize * self.width + (x + dx) as usize;
+                        if !seen[next] {
+                            seen[next] = true;
+                            stack.push(next);
+                        }
+                    } else if !bulk {
+                        fences += 1;
+                    }
+
+                    // A region has as many sides as corners, and each plot's corner is either on the outside of
+                    // a bend or the inside of one.
+                    let (cx, cy) = (-dy, dx);
+                    if bulk && !same(dx, dy) && !same(cx, cy) {
+                        fences += 1;
+                    }
+                    if bulk && same(dx, dy) && same(cx, cy) && !same(dx + cx, dy + cy) {
+                        fences += 1;
+                    }
+                }
+            }
+
+            price += area * fences;
+        }
+
+        price
+    }
+}
+
+impl FromStr for Garden {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let width = s.lines().next().ok_or_eyre("empty map")?.len();
+        let mut plots = Vec::new();
+
+        for line in s.lines() {
+            if line.len() != width {
+                bail!("map isn't rectangular");
+            }
+
+            plots.extend_from_slice(line.as_bytes());
+        }
+
+        Ok(Self {
+            width,
+            height: plots.len() / width,
+            plots,
+        })
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let garden: Garden = "AAAA
+BBCD
+BBCC
+EEEC"
+        .parse()
+        .unwrap();
+
+    assert_eq!(garden.fencing_price(false), 140);
+    assert_eq!(garden.fencing_price(true), 80);
+
+    let garden: Garden = "RRRRIICCFF
+RRRRIICCCF
+VVRRRCCFFF
+VVRCCCJFFF
+VVVVCJJCFE
+VVIVCCJJEE
+VVIIICJJEE
+MIIIIIJJEE
+MIIISIJEEE
+MMMISSJEEE"
+        .parse()
+        .unwrap();
+
+    assert_eq!(garden.fencing_price(false), 1930);
+    assert_eq!(garden.fencing_price(true), 1206);
+
+    let garden: Garden = "AAAAAA
+AAABBA
+AAABBA
+ABBAAA
+ABBAAA
+AAAAAA"
+        .parse()
+        .unwrap();
+
+    assert_eq!(garden.fencing_price(true), 368);
+}
@@ -0,0 +1,116 @@
+use std::str::FromStr;
+
+use eyre::{Report, Result, eyre};
+
+use aoc_meta::Problem;
+
+pub const CLAW_CONTRACTION: Problem = Problem::solved(&|input| fewest_tokens(input, 0), &|input| {
+    fewest_tokens(input, 10_000_000_000_000)
+});
+
+fn fewest_tokens(input: &str, offset: i64) -> Result<i64> {
+    input
+        .split("\n\n")
+        .map(|machine| {
+            let machine: ClawMachine = machine.parse()?;
+            Ok(machine.tokens_to_win(offset).unwrap_or(0))
+        })
+        .sum()
+}
+
+#[derive(Debug, Clone, Copy)]
+struct ClawMachine {
+    a: (i64, i64),
+    b: (i64, i64),
+    prize: (i64, i64),
+}
+
+impl ClawMachine {
+    /// The buttons move the claw along two directions, so unless they're parallel there's exactly one way to reach
+    /// the prize, which is found with Cramer's rule. A press of A costs 3 tokens and a press of B costs 1.
+    fn tokens_to_win(&self, offset: i64) -> Option<i64> {
+        let ((ax, ay), (bx, by)) = (self.a, self.b);
+        let (px, py) = (self.prize.0 + offset, self.prize.1 + offset);
+
+        let det = ax * by - ay * bx;
+        if det == 0 {
+            return None;
+        }
+
+        let (a, b) = (px * by - py * bx, ax * py - ay * px);
+        if a % det != 0 || b % det != 0 {
+            return None;
+        }
+
+        let (a, b) = (a / det, b / det);
+        (a >= 0 && b >= 0).then_some(3 * a + b)
+    }
+}
+
+impl FromStr for ClawMachine {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut lines = s.lines();
+        let mut line = |prefix: &str, separator: &str| -> Result<(i64, i64)> {
+            let line = lines
+                .next()
+                .ok_or_else(|| eyre!("missing \"{prefix}\" line"))?;
+            let parse = |coordinate: Option<&str>, axis| -> Result<i64> {
+                let value = coordinate
+                    .and_then(|c| c.strip_prefix(axis)?.strip_prefix(separator))
+                    .ok_or_else(|| eyre!("unable to parse \"{line}\""))?;
+                Ok(value.parse()?)
+            };
+
+            let mut coordinates = line
+                .strip_prefix(prefix)
+                .ok_or_else(|| eyre!("expected \"{prefix}\", found \"{line}\""))?
+                .split(", ");
+
+            Ok((
+                parse(coordinates.next(), "X")?,
+                parse(coordinates.next(), "Y")?,
+            ))
+        };
+
+        Ok(Self {
+            a: line("Button A: ", "+")?,
+            b: line("Button B: ", "+")?,
+            prize: line("Prize: ", "=")?,
+        })
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let input = "Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+
+Button A: X+26, Y+66
+Button B: X+67, Y+21
+Prize: X=12748, Y=12176
+
+Button A: X+17, Y+86
+Button B: X+84, Y+37
+Prize: X=7870, Y=6450
+
+Button A: X+69, Y+23
+Button B: X+27, Y+71
+Prize: X=18641, Y=10279";
+
+    assert_eq!(fewest_tokens(input, 0).unwrap(), 480);
+
+    let winnable = input
+        .split("\n\n")
+        .map(|machine| {
+            let machine: ClawMachine = machine.parse().unwrap();
+            machine.tokens_to_win(10_000_000_000_000).is_some()
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(winnable, [false, true, false, true]);
+}
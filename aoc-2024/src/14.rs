@@ -0,0 +1,137 @@
+use std::str::FromStr;
+
+use eyre::{OptionExt, Report, Result, bail};
+
+use aoc_common::math::crt;
+use aoc_meta::Problem;
+
+pub const RESTROOM_REDOUBT: Problem = Problem::solved(
+    &|input| parse_robots(input).map(|robots| safety_factor(&robots, WIDTH, HEIGHT, 100)),
+    &|input| parse_robots(input).and_then(|robots| easter_egg(&robots, WIDTH, HEIGHT)),
+);
+
+const WIDTH: i64 = 101;
+const HEIGHT: i64 = 103;
+
+fn parse_robots(input: &str) -> Result<Vec<Robot>> {
+    input.lines().map(str::parse).collect()
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Robot {
+    position: (i64, i64),
+    velocity: (i64, i64),
+}
+
+impl Robot {
+    #[inline]
+    fn x_after(&self, seconds: i64, width: i64) -> i64 {
+        (self.position.0 + self.velocity.0 * seconds).rem_euclid(width)
+    }
+
+    #[inline]
+    fn y_after(&self, seconds: i64, height: i64) -> i64 {
+        (self.position.1 + self.velocity.1 * seconds).rem_euclid(height)
+    }
+}
+
+/// The product of the numbers of robots in each quadrant after `seconds`, ignoring those right in the middle.
+fn safety_factor(robots: &[Robot], width: i64, height: i64, seconds: i64) -> usize {
+    let mut quadrants = [0; 4];
+
+    for robot in robots {
+        let (x, y) = (
+            robot.x_after(seconds, width),
+            robot.y_after(seconds, height),
+        );
+        if x == width / 2 || y == height / 2 {
+            continue;
+        }
+
+        quadrants[usize::from(x > width / 2) + 2 * usize::from(y > height / 2)] += 1;
+    }
+
+    quadrants.iter().product()
+}
+
+/// The robots drawing a picture are bunched up much more tightly than usual. Their columns repeat every `width`
+/// seconds and their rows every `height` seconds, so find the time in each cycle when the spread along that axis
+/// is smallest, and combine them.
+fn easter_egg(robots: &[Robot], width: i64, height: i64) -> Result<i64> {
+    let tightest = |period: i64, coordinate: &dyn Fn(&Robot, i64) -> i64| {
+        (0..period)
+            .min_by_key(|&seconds| {
+                let values = robots.iter().map(|robot| coordinate(robot, seconds));
+                let (sum, sum_squares) = values.fold((0, 0), |(s, sq), v| (s + v, sq + v * v));
+                // The variance, scaled up by the number of robots squared.
+                robots.len() as i64 * sum_squares - sum * sum
+            })
+            .unwrap_or(0)
+    };
+
+    let x = tightest(width, &|robot, seconds| robot.x_after(seconds, width));
+    let y = tightest(height, &|robot, seconds| robot.y_after(seconds, height));
+
+    crt([(x as u64, width as u64), (y as u64, height as u64)])
+        .map(|(seconds, _)| seconds as i64)
+        .ok_or_eyre("the robots never line up into a picture")
+}
+
+impl FromStr for Robot {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let pair = |s: &str, prefix| -> Result<(i64, i64)> {
+            let Some((x, y)) = s.strip_prefix(prefix).and_then(|s| s.split_once(',')) else {
+                bail!("unable to parse \"{s}\"")
+            };
+
+            Ok((x.parse()?, y.parse()?))
+        };
+
+        let Some((position, velocity)) = s.split_once(' ') else {
+            bail!("unable to split \"{s}\" into a position and velocity")
+        };
+
+        Ok(Self {
+            position: pair(position, "p=")?,
+            velocity: pair(velocity, "v=")?,
+        })
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let robots = parse_robots(
+        "p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+p=2,0 v=2,-1
+p=0,0 v=1,3
+p=3,0 v=-2,-2
+p=7,6 v=-1,-3
+p=3,0 v=-1,-2
+p=9,3 v=2,3
+p=7,3 v=-1,2
+p=2,4 v=2,-3
+p=9,5 v=-3,-3",
+    )
+    .unwrap();
+
+    assert_eq!(safety_factor(&robots, 11, 7, 100), 12);
+
+    // A tight cluster of robots that all scatter in different directions.
+    let robots = (0..20i64)
+        .map(|i| Robot {
+            position: (
+                (50 + i % 5 - 37 * (i + 1)).rem_euclid(WIDTH),
+                (50 + i / 5 - 37 * (2 * i + 3)).rem_euclid(HEIGHT),
+            ),
+            velocity: (i + 1, 2 * i + 3),
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(easter_egg(&robots, WIDTH, HEIGHT).unwrap(), 37);
+}
@@ -0,0 +1,227 @@
+use std::str::FromStr;
+
+use eyre::{OptionExt, Report, Result, bail, eyre};
+
+use aoc_meta::Problem;
+
+pub const WAREHOUSE_WOES: Problem = Problem::solved(
+    &|input| {
+        input
+            .parse()
+            .map(|warehouse: Warehouse| warehouse.gps_sum_after_moves())
+    },
+    &|input| {
+        input
+            .parse()
+            .map(|warehouse: Warehouse| warehouse.widened().gps_sum_after_moves())
+    },
+);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    Wall,
+    Empty,
+    Box,
+    BoxLeft,
+    BoxRight,
+}
+
+#[derive(Debug, Clone)]
+struct Warehouse {
+    width: usize,
+    tiles: Vec<Tile>,
+    robot: usize,
+    /// Each move as an offset to add to a position.
+    moves: Vec<isize>,
+}
+
+impl Warehouse {
+    /// Twice as wide, with each box taking up two tiles.
+    fn widened(self) -> Self {
+        let tiles = self
+            .tiles
+            .iter()
+            .flat_map(|tile| match tile {
+                Tile::Box => [Tile::BoxLeft, Tile::BoxRight],
+                &tile => [tile; 2],
+            })
+            .collect();
+
+        let width = self.width as isize;
+        let moves = self
+            .moves
+            .iter()
+            .map(|&step| if step.abs() == width { 2 * step } else { step })
+            .collect();
+
+        Self {
+            width: 2 * self.width,
+            tiles,
+            robot: 2 * self.robot,
+            moves,
+        }
+    }
+
+    fn gps_sum_after_moves(mut self) -> usize {
+        for step in std::mem::take(&mut self.moves) {
+            let next = self.robot.wrapping_add_signed(step);
+
+            if self.can_push(next, step) {
+                self.push(next, step);
+                self.robot = next;
+            }
+        }
+
+        self.tiles
+            .iter()
+            .enumerate()
+            .filter(|&(_, &tile)| tile == Tile::Box || tile == Tile::BoxLeft)
+            .map(|(idx, _)| 100 * (idx / self.width) + idx % self.width)
+            .sum()
+    }
+
+    /// Whether whatever is at `position` can be moved by `step`, along with everything it pushes.
+    fn can_push(&self, position: usize, step: isize) -> bool {
+        let next = position.wrapping_add_signed(step);
+        let vertical = step.unsigned_abs() != 1;
+
+        match self.tiles[position] {
+            Tile::Wall => false,
+            Tile::Empty => true,
+            Tile::BoxLeft if vertical => self.can_push(next, step) && self.can_push(next + 1, step),
+            Tile::BoxRight if vertical => {
+                self.can_push(next - 1, step) && self.can_push(next, step)
+            }
+            Tile::Box | Tile::BoxLeft | Tile::BoxRight => self.can_push(next, step),
+        }
+    }
+
+    /// Move whatever is at `position` by `step`, which must already be known to be possible.
+    fn push(&mut self, position: usize, step: isize) {
+        let vertical = step.unsigned_abs() != 1;
+        let other_half = match self.tiles[position] {
+            Tile::Wall | Tile::Empty => return,
+            Tile::BoxLeft if vertical => Some(position + 1),
+            Tile::BoxRight if vertical => Some(position - 1),
+            Tile::Box | Tile::BoxLeft | Tile::BoxRight => None,
+        };
+
+        for position in std::iter::once(position).chain(other_half) {
+            let next = position.wrapping_add_signed(step);
+            self.push(next, step);
+            self.tiles[next] = self.tiles[position];
+            self.tiles[position] = Tile::Empty;
+        }
+    }
+}
+
+impl FromStr for Warehouse {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let Some((map, moves)) = s.split_once("\n\n") else {
+            bail!("unable to split input into a map and moves")
+        };
+
+        let width = map.lines().next().ok_or_eyre("empty map")?.len();
+        let mut tiles = Vec::new();
+        let mut robot = None;
+
+        for line in map.lines() {
+            if line.len() != width {
+                bail!("map isn't rectangular");
+            }
+
+            for ch in line.chars() {
+                tiles.push(match ch {
+                    '#' => Tile::Wall,
+                    '.' => Tile::Empty,
+                    'O' => Tile::Box,
+                    '@' => {
+                        robot = Some(tiles.len());
+                        Tile::Empty
+                    }
+                    _ => bail!("unknown map tile '{ch}'"),
+                });
+            }
+        }
+
+        // Walls all the way around mean the robot can never go off the map.
+        let enclosed = tiles[..width]
+            .iter()
+            .chain(&tiles[tiles.len() - width..])
+            .all(|&t| t == Tile::Wall)
+            && tiles
+                .chunks(width)
+                .all(|row| row[0] == Tile::Wall && row[width - 1] == Tile::Wall);
+        if !enclosed {
+            bail!("the warehouse isn't surrounded by walls");
+        }
+
+        let moves = moves
+            .chars()
+            .filter(|ch| !ch.is_ascii_whitespace())
+            .map(|ch| match ch {
+                '^' => Ok(-(width as isize)),
+                'v' => Ok(width as isize),
+                '<' => Ok(-1),
+                '>' => Ok(1),
+                _ => Err(eyre!("unknown move '{ch}'")),
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Self {
+            width,
+            tiles,
+            robot: robot.ok_or_eyre("there's no robot in the warehouse")?,
+            moves,
+        })
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let small: Warehouse = "########
+#..O.O.#
+##@.O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########
+
+<^^>>>vv<v>>v<<"
+        .parse()
+        .unwrap();
+
+    assert_eq!(small.gps_sum_after_moves(), 2028);
+
+    let large: Warehouse = "##########
+#..O..O.O#
+#......O.#
+#.OO..O.O#
+#..O@..O.#
+#O#..O...#
+#O..O..O.#
+#.OO.O.OO#
+#....O...#
+##########
+
+<vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
+vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
+><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
+<<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
+^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
+^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
+>^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
+<><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
+^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
+v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^"
+        .parse()
+        .unwrap();
+
+    assert_eq!(large.clone().gps_sum_after_moves(), 10092);
+    assert_eq!(large.widened().gps_sum_after_moves(), 9021);
+}
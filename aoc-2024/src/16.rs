@@ -0,0 +1,207 @@
+use std::str::FromStr;
+
+use eyre::{OptionExt, Report, Result, bail};
+use fnv::FnvHashMap;
+
+use aoc_common::search::{self, SearchSpace};
+use aoc_meta::Problem;
+
+pub const REINDEER_MAZE: Problem = Problem::solved(
+    &|input| input.parse().and_then(|maze: Maze| maze.lowest_score()),
+    &|input| input.parse().and_then(|maze: Maze| maze.count_best_seats()),
+);
+
+const EAST: u8 = 1;
+
+#[derive(Debug)]
+struct Maze {
+    width: usize,
+    walls: Vec<bool>,
+    start: usize,
+    end: usize,
+    /// Whether to search with every step reversed, to work back from the end.
+    backwards: bool,
+}
+
+/// A position in the maze and the direction the reindeer is facing (0 is north, then clockwise).
+type Reindeer = (usize, u8);
+
+impl Maze {
+    #[inline]
+    fn offset(&self, position: usize, direction: u8) -> usize {
+        match direction {
+            0 => position - self.width,
+            1 => position + 1,
+            2 => position + self.width,
+            _ => position - 1,
+        }
+    }
+
+    /// The lowest score of every state reachable from the start.
+    fn scores(&self) -> FnvHashMap<Reindeer, u64> {
+        search::dijkstra(self, (self.start, EAST)).collect()
+    }
+
+    fn lowest_score(&self) -> Result<u64> {
+        self.lowest_score_in(&self.scores())
+    }
+
+    fn lowest_score_in(&self, scores: &FnvHashMap<Reindeer, u64>) -> Result<u64> {
+        (0..4)
+            .filter_map(|direction| scores.get(&(self.end, direction)).copied())
+            .min()
+            .ok_or_eyre("there's no way through the maze")
+    }
+
+    /// The number of tiles that are part of at least one of the best paths through the maze.
+    fn count_best_seats(self) -> Result<usize> {
+        let forwards = self.scores();
+        let best = self.lowest_score_in(&forwards)?;
+        let backwards = Maze {
+            backwards: true,
+            ..self
+        };
+        let mut seats = vec![false; backwards.walls.len()];
+
+        for direction in 0..4 {
+            if forwards.get(&(backwards.end, direction)) != Some(&best) {
+                continue;
+            }
+
+            for ((position, facing), score) in
+                search::dijkstra(&backwards, (backwards.end, direction))
+            {
+                if score > best {
+                    break;
+                }
+
+                if forwards
+                    .get(&(position, facing))
+                    .is_some_and(|&s| s + score == best)
+                {
+                    seats[position] = true;
+                }
+            }
+        }
+
+        Ok(seats.into_iter().filter(|&seat| seat).count())
+    }
+}
+
+impl SearchSpace for Maze {
+    type State = Reindeer;
+    type Cost = u64;
+
+    fn successors(
+        &self,
+        &(position, direction): &Reindeer,
+    ) -> impl IntoIterator<Item = (Reindeer, u64)> {
+        let step = if self.backwards {
+            self.offset(position, (direction + 2) % 4)
+        } else {
+            self.offset(position, direction)
+        };
+
+        let forward = (!self.walls[step]).then_some(((step, direction), 1));
+        let turns = [1, 3].map(|turn| ((position, (direction + turn) % 4), 1000));
+
+        forward.into_iter().chain(turns)
+    }
+
+    fn is_goal(&self, _: &Reindeer) -> bool {
+        false
+    }
+}
+
+impl FromStr for Maze {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let width = s.lines().next().ok_or_eyre("empty maze")?.len();
+        let (mut walls, mut start, mut end) = (Vec::new(), None, None);
+
+        for line in s.lines() {
+            if line.len() != width {
+                bail!("maze isn't rectangular");
+            }
+
+            for ch in line.chars() {
+                match ch {
+                    'S' => start = Some(walls.len()),
+                    'E' => end = Some(walls.len()),
+                    '#' | '.' => {}
+                    _ => bail!("unknown maze tile '{ch}'"),
+                }
+
+                walls.push(ch == '#');
+            }
+        }
+
+        // Walls all the way around mean the reindeer can never leave the maze.
+        let enclosed = walls[..width]
+            .iter()
+            .chain(&walls[walls.len() - width..])
+            .all(|&wall| wall)
+            && walls.chunks(width).all(|row| row[0] && row[width - 1]);
+        if !enclosed {
+            bail!("the maze isn't surrounded by walls");
+        }
+
+        Ok(Self {
+            width,
+            walls,
+            start: start.ok_or_eyre("the maze has no start tile")?,
+            end: end.ok_or_eyre("the maze has no end tile")?,
+            backwards: false,
+        })
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let maze: Maze = "###############
+#.......#....E#
+#.#.###.#.###.#
+#.....#.#...#.#
+#.###.#####.#.#
+#.#.#.......#.#
+#.#.#####.###.#
+#...........#.#
+###.#.#####.#.#
+#...#.....#.#.#
+#.#.#.###.#.#.#
+#.....#...#.#.#
+#.###.#.#.#.#.#
+#S..#.....#...#
+###############"
+        .parse()
+        .unwrap();
+
+    assert_eq!(maze.lowest_score().unwrap(), 7036);
+    assert_eq!(maze.count_best_seats().unwrap(), 45);
+
+    let maze: Maze = "#################
+#...#...#...#..E#
+#.#.#.#.#.#.#.#.#
+#.#.#.#...#...#.#
+#.#.#.#.###.#.#.#
+#...#.#.#.....#.#
+#.#.#.#.#.#####.#
+#.#...#.#.#.....#
+#.#.#####.#.###.#
+#.#.#.......#...#
+#.#.###.#####.###
+#.#.#...#.....#.#
+#.#.#.#####.###.#
+#.#.#.........#.#
+#.#.#.#########.#
+#S#.............#
+#################"
+        .parse()
+        .unwrap();
+
+    assert_eq!(maze.lowest_score().unwrap(), 11048);
+    assert_eq!(maze.count_best_seats().unwrap(), 64);
+}
@@ -0,0 +1,162 @@
+use std::str::FromStr;
+
+use eyre::{OptionExt, Report, Result, bail, eyre};
+use itertools::Itertools;
+
+use aoc_meta::Problem;
+
+pub const CHRONOSPATIAL_COMPUTER: Problem = Problem::solved(
+    &|input| {
+        input
+            .parse()
+            .map(|computer: Computer| computer.run().iter().join(","))
+    },
+    &|input| {
+        input
+            .parse()
+            .and_then(|computer: Computer| computer.lowest_quine_seed())
+    },
+);
+
+/// A 3-bit computer. Its program is a list of 3-bit numbers, which alternate between opcodes and operands.
+#[derive(Debug, Clone)]
+struct Computer {
+    registers: [u64; 3],
+    program: Vec<u8>,
+}
+
+impl Computer {
+    /// Run the program until it halts, returning everything it outputs.
+    fn run(&self) -> Vec<u8> {
+        let mut computer = self.clone();
+        let mut output = Vec::new();
+        let mut ip = 0;
+
+        while let Some(&[opcode, operand]) = computer.program.get(ip..ip + 2) {
+            let [a, b, c] = &mut computer.registers;
+            let combo = match operand {
+                0..=3 => u64::from(operand),
+                4 => *a,
+                5 => *b,
+                _ => *c,
+            };
+
+            ip += 2;
+            match opcode {
+                0 => *a >>= combo.min(63),
+                1 => *b ^= u64::from(operand),
+                2 => *b = combo % 8,
+                3 if *a != 0 => ip = usize::from(operand),
+                3 => {}
+                4 => *b ^= *c,
+                5 => output.push((combo % 8) as u8),
+                6 => *b = *a >> combo.min(63),
+                _ => *c = *a >> combo.min(63),
+            }
+        }
+
+        output
+    }
+
+    /// The lowest initial value of register A that makes the program output a copy of itself.
+    ///
+    /// This relies on the program being a single loop that outputs one number and then shifts A right by 3 bits, so
+    /// that A is built up 3 bits at a time working backwards from the last output.
+    fn lowest_quine_seed(&self) -> Result<u64> {
+        let shifts = self
+            .program
+            .chunks(2)
+            .filter(|instruction| instruction == &[0, 3])
+            .count();
+        if shifts != 1 || !self.program.ends_with(&[3, 0]) {
+            bail!("the program doesn't consume register A 3 bits per loop");
+        }
+
+        self.quine_seed_from(0, self.program.len())
+            .ok_or_eyre("no value of register A makes the program output itself")
+    }
+
+    /// The lowest A that starts with the bits in `prefix` and outputs `program[remaining..]`.
+    fn quine_seed_from(&self, prefix: u64, remaining: usize) -> Option<u64> {
+        if remaining == 0 {
+            return Some(prefix);
+        }
+
+        (0..8).find_map(|bits| {
+            let a = prefix << 3 | bits;
+            let mut computer = self.clone();
+            computer.registers[0] = a;
+
+            // A starts out as zero for the very first number, which would stop the program too soon.
+            (a != 0 && computer.run() == self.program[remaining - 1..])
+                .then(|| self.quine_seed_from(a, remaining - 1))
+                .flatten()
+        })
+    }
+}
+
+impl FromStr for Computer {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let Some((registers, program)) = s.split_once("\n\n") else {
+            bail!("unable to split input into registers and a program")
+        };
+
+        let registers: Vec<u64> = registers
+            .lines()
+            .zip(["A", "B", "C"])
+            .map(|(line, name)| {
+                line.strip_prefix("Register ")
+                    .and_then(|line| line.strip_prefix(name)?.strip_prefix(": "))
+                    .ok_or_else(|| eyre!("expected register {name}, found \"{line}\""))?
+                    .parse()
+                    .map_err(Report::from)
+            })
+            .try_collect()?;
+
+        let program: Vec<u8> = program
+            .trim_end()
+            .strip_prefix("Program: ")
+            .ok_or_eyre("missing program")?
+            .split(',')
+            .map(str::parse)
+            .try_collect()?;
+
+        if let Some(n) = program.iter().find(|&&n| n > 7) {
+            bail!("{n} isn't a 3-bit number");
+        }
+
+        Ok(Self {
+            registers: registers
+                .try_into()
+                .map_err(|_| eyre!("expected 3 registers"))?,
+            program,
+        })
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let computer: Computer = "Register A: 729
+Register B: 0
+Register C: 0
+
+Program: 0,1,5,4,3,0"
+        .parse()
+        .unwrap();
+
+    assert_eq!(computer.run(), [4, 6, 3, 5, 6, 3, 5, 2, 1, 0]);
+
+    let computer: Computer = "Register A: 2024
+Register B: 0
+Register C: 0
+
+Program: 0,3,5,4,3,0"
+        .parse()
+        .unwrap();
+
+    assert_eq!(computer.lowest_quine_seed().unwrap(), 117440);
+}
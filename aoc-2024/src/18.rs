@@ -0,0 +1,137 @@
+use eyre::{OptionExt, Result, bail};
+use itertools::Itertools;
+
+use aoc_common::grid::Coordinate;
+use aoc_common::search::{self, SearchSpace};
+use aoc_meta::Problem;
+
+pub const RAM_RUN: Problem = Problem::solved(
+    &|input| {
+        let bytes = parse_bytes(input)?;
+        MemorySpace::new(SIZE, &bytes[..bytes.len().min(FIRST_KILOBYTE)])
+            .fewest_steps()
+            .ok_or_eyre("there's no way out after the first kilobyte falls")
+    },
+    &|input| {
+        first_blocking_byte(&parse_bytes(input)?, SIZE)
+            .map(|Coordinate { x, y }| format!("{x},{y}"))
+            .ok_or_eyre("the exit never gets cut off")
+    },
+);
+
+const SIZE: usize = 71;
+const FIRST_KILOBYTE: usize = 1024;
+
+fn parse_bytes(input: &str) -> Result<Vec<Coordinate>> {
+    input
+        .lines()
+        .map(|line| {
+            let Some((x, y)) = line.split_once(',') else {
+                bail!("unable to split \"{line}\" into coordinates")
+            };
+
+            Ok(Coordinate {
+                x: x.parse()?,
+                y: y.parse()?,
+            })
+        })
+        .collect()
+}
+
+/// The first byte that leaves no way to the exit once it's fallen. Blocking the exit stays blocked, so a binary search
+/// over how many bytes have fallen finds it.
+fn first_blocking_byte(bytes: &[Coordinate], size: usize) -> Option<Coordinate> {
+    let fallen = (0..=bytes.len())
+        .collect_vec()
+        .partition_point(|&n| MemorySpace::new(size, &bytes[..n]).fewest_steps().is_some());
+
+    fallen
+        .checked_sub(1)
+        .and_then(|idx| bytes.get(idx))
+        .copied()
+}
+
+#[derive(Debug)]
+struct MemorySpace {
+    size: usize,
+    corrupted: Vec<bool>,
+}
+
+impl MemorySpace {
+    fn new(size: usize, bytes: &[Coordinate]) -> Self {
+        let mut corrupted = vec![false; size * size];
+        for byte in bytes.iter().filter(|byte| byte.x < size && byte.y < size) {
+            corrupted[byte.y * size + byte.x] = true;
+        }
+
+        Self { size, corrupted }
+    }
+
+    /// The fewest steps from the top left corner to the bottom right one.
+    fn fewest_steps(&self) -> Option<usize> {
+        if self.corrupted[0] {
+            return None;
+        }
+
+        search::bfs(self, Coordinate { x: 0, y: 0 })
+            .next_goal()
+            .map(|path| path.cost)
+    }
+}
+
+impl SearchSpace for MemorySpace {
+    type State = Coordinate;
+    type Cost = usize;
+
+    fn successors(&self, coord: &Coordinate) -> impl IntoIterator<Item = (Coordinate, usize)> {
+        coord
+            .cardinal_neighbors()
+            .filter(|next| next.x < self.size && next.y < self.size)
+            .filter(|next| !self.corrupted[next.y * self.size + next.x])
+            .map(|next| (next, 1))
+    }
+
+    fn is_goal(&self, coord: &Coordinate) -> bool {
+        coord.x == self.size - 1 && coord.y == self.size - 1
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let bytes = parse_bytes(
+        "5,4
+4,2
+4,5
+3,0
+2,1
+6,3
+2,4
+1,5
+0,6
+3,3
+2,6
+5,1
+1,2
+5,5
+2,5
+6,5
+1,4
+0,4
+6,4
+1,1
+6,1
+1,0
+0,5
+1,6
+2,0",
+    )
+    .unwrap();
+
+    assert_eq!(MemorySpace::new(7, &bytes[..12]).fewest_steps(), Some(22));
+    assert_eq!(
+        first_blocking_byte(&bytes, 7),
+        Some(Coordinate { x: 6, y: 1 })
+    );
+}
@@ -0,0 +1,88 @@
+use std::str::FromStr;
+
+use eyre::{Report, Result, bail};
+
+use aoc_meta::Problem;
+
+pub const LINEN_LAYOUT: Problem = Problem::solved(
+    &|input| input.parse().map(|onsen: Onsen| onsen.count_possible()),
+    &|input| input.parse().map(|onsen: Onsen| onsen.count_arrangements()),
+);
+
+#[derive(Debug)]
+struct Onsen {
+    towels: Vec<String>,
+    designs: Vec<String>,
+}
+
+impl Onsen {
+    /// The number of designs that can be made from the towels at all.
+    fn count_possible(&self) -> usize {
+        self.designs
+            .iter()
+            .filter(|design| self.arrangements(design) > 0)
+            .count()
+    }
+
+    /// The total number of ways to make every design.
+    fn count_arrangements(&self) -> u64 {
+        self.designs
+            .iter()
+            .map(|design| self.arrangements(design))
+            .sum()
+    }
+
+    /// The number of different ways to line up towels to make `design`.
+    fn arrangements(&self, design: &str) -> u64 {
+        // `ways[i]` is the number of ways to make the design from stripe `i` onwards.
+        let mut ways = vec![0; design.len() + 1];
+        ways[design.len()] = 1;
+
+        for start in (0..design.len()).rev() {
+            ways[start] = self
+                .towels
+                .iter()
+                .filter(|towel| design[start..].starts_with(towel.as_str()))
+                .map(|towel| ways[start + towel.len()])
+                .sum();
+        }
+
+        ways[0]
+    }
+}
+
+impl FromStr for Onsen {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let Some((towels, designs)) = s.split_once("\n\n") else {
+            bail!("unable to split input into towels and designs")
+        };
+
+        Ok(Self {
+            towels: towels.split(", ").map(str::to_owned).collect(),
+            designs: designs.lines().map(str::to_owned).collect(),
+        })
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let onsen: Onsen = "r, wr, b, g, bwu, rb, gb, br
+
+brwrr
+bggr
+gbbr
+rrbgbr
+ubwu
+bwurrg
+brgr
+bbrgwb"
+        .parse()
+        .unwrap();
+
+    assert_eq!(onsen.count_possible(), 6);
+    assert_eq!(onsen.count_arrangements(), 16);
+}
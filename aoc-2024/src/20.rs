@@ -0,0 +1,118 @@
+use std::str::FromStr;
+
+use eyre::{OptionExt, Report, Result, bail};
+use rayon::prelude::*;
+
+use aoc_meta::Problem;
+
+pub const RACE_CONDITION: Problem = Problem::solved(
+    &|input| {
+        input
+            .parse()
+            .map(|track: Racetrack| track.count_cheats(2, 100))
+    },
+    &|input| {
+        input
+            .parse()
+            .map(|track: Racetrack| track.count_cheats(20, 100))
+    },
+);
+
+#[derive(Debug)]
+struct Racetrack {
+    /// Every position along the only path from the start to the end, in order.
+    path: Vec<(i32, i32)>,
+}
+
+impl Racetrack {
+    /// The number of cheats lasting up to `duration` picoseconds that save at least `threshold` picoseconds. A cheat
+    /// goes straight from one spot on the track to another one further along, through any walls in the way.
+    fn count_cheats(&self, duration: u32, threshold: usize) -> usize {
+        self.path
+            .par_iter()
+            .enumerate()
+            .map(|(i, &(x, y))| {
+                self.path
+                    .iter()
+                    .enumerate()
+                    .skip(i + threshold)
+                    .filter(|&(j, &(to_x, to_y))| {
+                        let distance = x.abs_diff(to_x) + y.abs_diff(to_y);
+                        distance <= duration && j - i >= threshold + distance as usize
+                    })
+                    .count()
+            })
+            .sum()
+    }
+}
+
+impl FromStr for Racetrack {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let grid: Vec<&[u8]> = s.lines().map(str::as_bytes).collect();
+        let find = |tile| {
+            grid.iter().enumerate().find_map(|(y, row)| {
+                let x = row.iter().position(|&t| t == tile)?;
+                Some((x as i32, y as i32))
+            })
+        };
+
+        let start = find(b'S').ok_or_eyre("the track has no start")?;
+        let end = find(b'E').ok_or_eyre("the track has no end")?;
+        let is_track = |(x, y): (i32, i32)| {
+            let tile = grid
+                .get(usize::try_from(y).ok()?)?
+                .get(usize::try_from(x).ok()?)?;
+            (*tile != b'#').then_some((x, y))
+        };
+
+        let mut path = vec![start];
+        let mut previous = None;
+
+        while let Some(&(x, y)) = path.last().filter(|&&position| position != end) {
+            let mut next = [(x, y - 1), (x + 1, y), (x, y + 1), (x - 1, y)]
+                .into_iter()
+                .filter_map(is_track)
+                .filter(|&position| Some(position) != previous);
+
+            let (Some(step), None) = (next.next(), next.next()) else {
+                bail!("the track branches or dead ends at ({x}, {y})");
+            };
+
+            previous = Some((x, y));
+            path.push(step);
+        }
+
+        Ok(Self { path })
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let track: Racetrack = "###############
+#...#...#.....#
+#.#.#.#.#.###.#
+#S#...#.#.#...#
+#######.#.#.###
+#######.#.#...#
+#######.#.###.#
+###..E#...#...#
+###.#######.###
+#...###...#...#
+#.#####.#.###.#
+#.#...#.#.#...#
+#.#.#.#.#.#.###
+#...#...#...###
+###############"
+        .parse()
+        .unwrap();
+
+    assert_eq!(track.path.len(), 85);
+    assert_eq!(track.count_cheats(2, 20), 5);
+    assert_eq!(track.count_cheats(2, 64), 1);
+    assert_eq!(track.count_cheats(20, 50), 285);
+    assert_eq!(track.count_cheats(20, 76), 3);
+}
@@ -0,0 +1,160 @@
+use std::any::TypeId;
+
+use eyre::{Result, bail};
+use fnv::FnvHashMap;
+
+use aoc_meta::Problem;
+
+pub const KEYPAD_CONUNDRUM: Problem =
+    Problem::solved(&|input| total_complexity(input, 2), &|input| {
+        total_complexity(input, 25)
+    });
+
+/// Where each key is on a keypad, with the gap nobody's allowed to point at.
+trait Keypad: 'static {
+    const GAP: (i64, i64);
+
+    fn position(key: u8) -> Option<(i64, i64)>;
+}
+
+struct Numeric;
+
+impl Keypad for Numeric {
+    const GAP: (i64, i64) = (0, 3);
+
+    fn position(key: u8) -> Option<(i64, i64)> {
+        match key {
+            b'7' => Some((0, 0)),
+            b'8' => Some((1, 0)),
+            b'9' => Some((2, 0)),
+            b'4' => Some((0, 1)),
+            b'5' => Some((1, 1)),
+            b'6' => Some((2, 1)),
+            b'1' => Some((0, 2)),
+            b'2' => Some((1, 2)),
+            b'3' => Some((2, 2)),
+            b'0' => Some((1, 3)),
+            b'A' => Some((2, 3)),
+            _ => None,
+        }
+    }
+}
+
+struct Directional;
+
+impl Keypad for Directional {
+    const GAP: (i64, i64) = (0, 0);
+
+    fn position(key: u8) -> Option<(i64, i64)> {
+        match key {
+            b'^' => Some((1, 0)),
+            b'A' => Some((2, 0)),
+            b'<' => Some((0, 1)),
+            b'v' => Some((1, 1)),
+            b'>' => Some((2, 1)),
+            _ => None,
+        }
+    }
+}
+
+/// The sum over codes of the fewest button presses on my keypad to type the code through `robots` directional
+/// keypads, times the numeric part of the code.
+fn total_complexity(input: &str, robots: usize) -> Result<u64> {
+    let mut chain = KeypadChain::default();
+
+    input
+        .lines()
+        .map(|code| {
+            let Some(number) = code.strip_suffix('A') else {
+                bail!("code \"{code}\" doesn't end with A")
+            };
+
+            let presses = chain.presses::<Numeric>(code.as_bytes(), robots + 1)?;
+            Ok(presses * number.parse::<u64>()?)
+        })
+        .sum()
+}
+
+/// Every robot's arm starts on A, and is back on A whenever the keypad it's controlled from presses A, so the cost
+/// of each key depends only on the key before it and how many keypads are in between.
+#[derive(Debug, Default)]
+struct KeypadChain {
+    /// The fewest presses to move from one key to another on some keypad and press it, through some number of
+    /// keypads.
+    cache: FnvHashMap<(TypeId, u8, u8, usize), u64>,
+}
+
+impl KeypadChain {
+    /// The fewest presses of the keypad at the top of a chain of `depth` keypads (the last being a `K`) to type
+    /// `keys` on the last one.
+    fn presses<K: Keypad>(&mut self, keys: &[u8], depth: usize) -> Result<u64> {
+        let mut from = b'A';
+        let mut total = 0;
+
+        for &to in keys {
+            total += self.step::<K>(from, to, depth)?;
+            from = to;
+        }
+
+        Ok(total)
+    }
+
+    fn step<K: Keypad>(&mut self, from: u8, to: u8, depth: usize) -> Result<u64> {
+        if depth == 0 {
+            return Ok(1);
+        }
+
+        let key = (TypeId::of::<K>(), from, to, depth);
+        if let Some(&presses) = self.cache.get(&key) {
+            return Ok(presses);
+        }
+
+        let (Some((x1, y1)), Some((x2, y2))) = (K::position(from), K::position(to)) else {
+            bail!("can't move from '{}' to '{}'", from as char, to as char);
+        };
+
+        let horizontal = if x2 > x1 { b'>' } else { b'<' };
+        let vertical = if y2 > y1 { b'v' } else { b'^' };
+        let horizontal = vec![horizontal; x1.abs_diff(x2) as usize];
+        let vertical = vec![vertical; y1.abs_diff(y2) as usize];
+
+        // Doing all of one direction and then the other is never worse than going back and forth, as long as it
+        // doesn't go over the gap.
+        let mut fewest = u64::MAX;
+        for (first, second, corner) in [
+            (&horizontal, &vertical, (x2, y1)),
+            (&vertical, &horizontal, (x1, y2)),
+        ] {
+            if corner == K::GAP {
+                continue;
+            }
+
+            let moves = [first.as_slice(), second, b"A"].concat();
+            fewest = fewest.min(self.presses::<Directional>(&moves, depth - 1)?);
+        }
+
+        self.cache.insert(key, fewest);
+
+        Ok(fewest)
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let input = "029A
+980A
+179A
+456A
+379A";
+
+    assert_eq!(
+        KeypadChain::default()
+            .presses::<Numeric>(b"029A", 3)
+            .unwrap(),
+        68
+    );
+    assert_eq!(total_complexity(input, 2).unwrap(), 126384);
+    assert_eq!(total_complexity(input, 25).unwrap(), 154115708116294);
+}
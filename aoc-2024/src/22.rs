@@ -0,0 +1,93 @@
+use eyre::{OptionExt, Result};
+use itertools::Itertools;
+use rayon::prelude::*;
+
+use aoc_meta::Problem;
+
+pub const MONKEY_MARKET: Problem = Problem::solved(
+    &|input| parse_secrets(input).map(|secrets| sum_of_secrets(&secrets, 2000)),
+    &|input| parse_secrets(input).and_then(|secrets| most_bananas(&secrets, 2000)),
+);
+
+/// Four price changes from -9 to 9 each, packed into one index.
+const SEQUENCES: usize = 19 * 19 * 19 * 19;
+
+fn parse_secrets(input: &str) -> Result<Vec<u32>> {
+    Ok(input.lines().map(str::parse).try_collect()?)
+}
+
+#[inline]
+fn next_secret(mut secret: u32) -> u32 {
+    const PRUNE: u32 = (1 << 24) - 1;
+
+    secret = (secret ^ (secret << 6)) & PRUNE;
+    secret = (secret ^ (secret >> 5)) & PRUNE;
+    (secret ^ (secret << 11)) & PRUNE
+}
+
+fn sum_of_secrets(secrets: &[u32], steps: usize) -> u64 {
+    secrets
+        .iter()
+        .map(|&secret| {
+            let last = (0..steps).fold(secret, |secret, _| next_secret(secret));
+            u64::from(last)
+        })
+        .sum()
+}
+
+/// The most bananas the monkey can get by selling to every buyer the first time their price changes by the same
+/// four amounts in a row.
+fn most_bananas(secrets: &[u32], steps: usize) -> Result<u64> {
+    let bananas = secrets
+        .par_iter()
+        .fold(
+            || vec![0u64; SEQUENCES],
+            |mut bananas, &secret| {
+                let mut seen = vec![false; SEQUENCES];
+                let mut sequence = 0;
+                let mut secret = secret;
+                let mut price = secret % 10;
+
+                for step in 0..steps {
+                    secret = next_secret(secret);
+                    let next = secret % 10;
+                    sequence = (sequence * 19 + (9 + next as usize - price as usize)) % SEQUENCES;
+                    price = next;
+
+                    if step >= 3 && !std::mem::replace(&mut seen[sequence], true) {
+                        bananas[sequence] += u64::from(price);
+                    }
+                }
+
+                bananas
+            },
+        )
+        .reduce_with(|mut a, b| {
+            a.iter_mut().zip(b).for_each(|(a, b)| *a += b);
+            a
+        })
+        .unwrap_or_default();
+
+    bananas.into_iter().max().ok_or_eyre("no buyers to sell to")
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let secrets = (0..10).scan(123, |secret, _| {
+        *secret = next_secret(*secret);
+        Some(*secret)
+    });
+
+    assert_eq!(
+        secrets.collect_vec(),
+        [
+            15887950, 16495136, 527345, 704524, 1553684, 12683156, 11100544, 12249484, 7753432,
+            5908254
+        ]
+    );
+
+    assert_eq!(sum_of_secrets(&[1, 10, 100, 2024], 2000), 37327623);
+    assert_eq!(most_bananas(&[1, 2, 3, 2024], 2000).unwrap(), 23);
+}
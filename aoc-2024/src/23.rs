@@ -0,0 +1,165 @@
+use std::collections::BTreeSet;
+use std::str::FromStr;
+
+use eyre::{Report, Result, bail};
+use fnv::{FnvHashMap, FnvHashSet};
+use itertools::Itertools;
+
+use aoc_meta::Problem;
+
+pub const LAN_PARTY: Problem = Problem::solved(
+    &|input| {
+        input
+            .parse()
+            .map(|network: Network| network.count_chief_triangles())
+    },
+    &|input| input.parse().map(|network: Network| network.password()),
+);
+
+#[derive(Debug)]
+struct Network {
+    links: FnvHashMap<String, FnvHashSet<String>>,
+}
+
+impl Network {
+    /// The number of sets of three computers all connected to each other, where at least one of them might be the
+    /// Chief Historian's (which is any computer whose name starts with `t`).
+    fn count_chief_triangles(&self) -> usize {
+        self.links
+            .iter()
+            .flat_map(|(a, neighbors)| {
+                neighbors
+                    .iter()
+                    .filter(move |b| a < b)
+                    .tuple_combinations()
+                    .filter(|(b, c)| self.links[*b].contains(*c))
+                    .map(move |(b, c)| [a, b, c])
+            })
+            .filter(|triangle| triangle.iter().any(|name| name.starts_with('t')))
+            .count()
+    }
+
+    /// The names of the computers in the largest set that are all connected to each other, sorted and joined with
+    /// commas.
+    fn password(&self) -> String {
+        let mut largest = Vec::new();
+        let all = self.links.keys().map(String::as_str).collect();
+        self.bron_kerbosch(&mut Vec::new(), all, BTreeSet::new(), &mut largest);
+
+        largest.sort_unstable();
+        largest.join(",")
+    }
+
+    /// Find the largest clique containing all of `clique`, some of `candidates` and none of `excluded`, pivoting on
+    /// whichever computer leaves the fewest candidates to try.
+    fn bron_kerbosch<'n>(
+        &'n self,
+        clique: &mut Vec<&'n str>,
+        mut candidates: BTreeSet<&'n str>,
+        mut excluded: BTreeSet<&'n str>,
+        largest: &mut Vec<&'n str>,
+    ) {
+        if candidates.is_empty() {
+            if excluded.is_empty() && clique.len() > largest.len() {
+                largest.clone_from(clique);
+            }
+
+            return;
+        }
+
+        let pivot = candidates
+            .union(&excluded)
+            .max_by_key(|&&name| self.links[name].len())
+            .copied();
+        let skip = pivot.map(|pivot| &self.links[pivot]);
+
+        for name in candidates.clone() {
+            if skip.is_some_and(|skip| skip.contains(name)) {
+                continue;
+            }
+
+            let neighbors = &self.links[name];
+            clique.push(name);
+            self.bron_kerbosch(
+                clique,
+                candidates
+                    .iter()
+                    .copied()
+                    .filter(|&n| neighbors.contains(n))
+                    .collect(),
+                excluded
+                    .iter()
+                    .copied()
+                    .filter(|&n| neighbors.contains(n))
+                    .collect(),
+                largest,
+            );
+            clique.pop();
+
+            candidates.remove(name);
+            excluded.insert(name);
+        }
+    }
+}
+
+impl FromStr for Network {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut links: FnvHashMap<String, FnvHashSet<String>> = FnvHashMap::default();
+
+        for line in s.lines() {
+            let Some((a, b)) = line.split_once('-') else {
+                bail!("unable to split \"{line}\" into two computers")
+            };
+
+            links.entry(a.to_owned()).or_default().insert(b.to_owned());
+            links.entry(b.to_owned()).or_default().insert(a.to_owned());
+        }
+
+        Ok(Self { links })
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let network: Network = "kh-tc
+qp-kh
+de-cg
+ka-co
+yn-aq
+qp-ub
+cg-tb
+vc-aq
+tb-ka
+wh-tc
+yn-cg
+kh-ub
+ta-co
+de-co
+tc-td
+tb-wq
+wh-td
+ta-ka
+td-qp
+aq-cg
+wq-ub
+ub-vc
+de-ta
+wq-aq
+wq-vc
+wh-yn
+ka-de
+kh-ta
+co-tc
+wh-qp
+tb-vc
+td-yn"
+        .parse()
+        .unwrap();
+
+    assert_eq!(network.count_chief_triangles(), 7);
+    assert_eq!(network.password(), "co,de,ka,ta");
+}
@@ -0,0 +1,296 @@
+use std::str::FromStr;
+
+use eyre::{OptionExt, Report, Result, bail, eyre};
+use fnv::{FnvHashMap, FnvHashSet};
+use itertools::Itertools;
+
+use aoc_meta::Problem;
+
+pub const CROSSED_WIRES: Problem = Problem::solved(
+    &|input| input.parse().and_then(|device: Device| device.output()),
+    &|input| {
+        input
+            .parse()
+            .and_then(|device: Device| device.swapped_wires())
+    },
+);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operation {
+    And,
+    Or,
+    Xor,
+}
+
+#[derive(Debug)]
+struct Gate {
+    inputs: [String; 2],
+    operation: Operation,
+    output: String,
+}
+
+impl Gate {
+    fn has_input(&self, wire: &str) -> bool {
+        self.inputs.iter().any(|input| input == wire)
+    }
+
+    /// Whether the gate is wired straight to the `x` and `y` inputs.
+    fn is_first_layer(&self) -> bool {
+        self.inputs
+            .iter()
+            .all(|input| input.starts_with('x') || input.starts_with('y'))
+    }
+}
+
+#[derive(Debug)]
+struct Device {
+    initial: FnvHashMap<String, bool>,
+    gates: Vec<Gate>,
+}
+
+impl Device {
+    /// The number made up of the bits on the `z` wires, once every gate has settled.
+    fn output(&self) -> Result<u64> {
+        let mut values = self.initial.clone();
+        let mut pending: Vec<&Gate> = self.gates.iter().collect();
+
+        while !pending.is_empty() {
+            let before = pending.len();
+
+            pending.retain(|gate| {
+                let [a, b] = &gate.inputs;
+                let (Some(&a), Some(&b)) = (values.get(a), values.get(b)) else {
+                    return true;
+                };
+
+                let value = match gate.operation {
+                    Operation::And => a && b,
+                    Operation::Or => a || b,
+                    Operation::Xor => a ^ b,
+                };
+
+                values.insert(gate.output.clone(), value);
+                false
+            });
+
+            if pending.len() == before {
+                bail!("some gates never get both of their inputs");
+            }
+        }
+
+        let mut bits = values
+            .iter()
+            .filter(|(wire, _)| wire.starts_with('z'))
+            .sorted_unstable()
+            .rev();
+
+        bits.try_fold(0u64, |number, (wire, &bit)| {
+            if number.leading_zeros() == 0 {
+                bail!("too many output bits to fit {wire}");
+            }
+
+            Ok(number << 1 | u64::from(bit))
+        })
+    }
+
+    /// The device is meant to be a ripple-carry adder, with four pairs of gates that have had their outputs swapped.
+    /// Every bit `i` past the first should be wired up as
+    ///
+    /// ```text
+    /// x_i XOR y_i -> s_i    s_i XOR carry_in -> z_i
+    /// x_i AND y_i -> c_i    s_i AND carry_in -> d_i    c_i OR d_i -> carry_out
+    /// ```
+    ///
+    /// with the last carry out going to the highest `z` wire. Any gate whose output breaks that pattern must have
+    /// been swapped, so this returns their output wires, sorted and joined with commas.
+    fn swapped_wires(&self) -> Result<String> {
+        let last_z = self
+            .gates
+            .iter()
+            .map(|gate| &gate.output)
+            .filter(|wire| wire.starts_with('z'))
+            .max()
+            .ok_or_eyre("the device has no output wires")?;
+
+        let feeds = |wire: &str, operation| {
+            self.gates
+                .iter()
+                .any(|gate| gate.operation == operation && gate.has_input(wire))
+        };
+
+        let first_bit = |gate: &Gate| gate.has_input("x00");
+        let mut swapped = FnvHashSet::default();
+
+        for gate in &self.gates {
+            let output = gate.output.as_str();
+            let is_z = output.starts_with('z');
+
+            let wrong = match gate.operation {
+                Operation::Xor if gate.is_first_layer() => {
+                    !first_bit(gate) && (is_z || !feeds(output, Operation::Xor))
+                }
+                Operation::Xor => !is_z,
+                Operation::And => !first_bit(gate) && !feeds(output, Operation::Or),
+                Operation::Or => {
+                    is_z != (output == last_z) || (!is_z && !feeds(output, Operation::Xor))
+                }
+            };
+
+            // The z wires can only come from XOR gates, apart from the final carry.
+            let wrong = wrong || (is_z && gate.operation != Operation::Xor && output != last_z);
+
+            if wrong {
+                swapped.insert(output);
+            }
+        }
+
+        if swapped.len() != 8 {
+            bail!(
+                "expected 4 pairs of swapped wires, found {} suspicious wires",
+                swapped.len()
+            );
+        }
+
+        Ok(swapped.into_iter().sorted_unstable().join(","))
+    }
+}
+
+impl FromStr for Device {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let Some((initial, gates)) = s.split_once("\n\n") else {
+            bail!("unable to split input into wires and gates")
+        };
+
+        let initial = initial
+            .lines()
+            .map(|line| {
+                let Some((wire, value)) = line.split_once(": ") else {
+                    bail!("unable to split \"{line}\" into a wire and value")
+                };
+
+                match value {
+                    "0" => Ok((wire.to_owned(), false)),
+                    "1" => Ok((wire.to_owned(), true)),
+                    _ => bail!("wire {wire} has value {value}, which isn't a bit"),
+                }
+            })
+            .try_collect()?;
+
+        let gates = gates
+            .lines()
+            .map(|line| {
+                let Some((a, operation, b, "->", output)) =
+                    line.split_ascii_whitespace().collect_tuple()
+                else {
+                    bail!("unable to parse gate \"{line}\"")
+                };
+
+                let operation = match operation {
+                    "AND" => Operation::And,
+                    "OR" => Operation::Or,
+                    "XOR" => Operation::Xor,
+                    _ => return Err(eyre!("unknown operation {operation}")),
+                };
+
+                Ok(Gate {
+                    inputs: [a.to_owned(), b.to_owned()],
+                    operation,
+                    output: output.to_owned(),
+                })
+            })
+            .try_collect()?;
+
+        Ok(Self { initial, gates })
+    }
+}
+
+#[cfg(test)]
+fn adder(bits: usize, swaps: &[(&str, &str)]) -> Device {
+    use std::fmt::Write;
+
+    let mut input = String::new();
+    for bit in 0..bits {
+        writeln!(input, "x{bit:02}: 1").unwrap();
+    }
+    for bit in 0..bits {
+        writeln!(input, "y{bit:02}: {}", bit % 2).unwrap();
+    }
+
+    input.push('\n');
+    let mut gate = |a: &str, operation, b: &str, output: String| {
+        let output = swaps
+            .iter()
+            .find_map(|&(p, q)| (p == output).then_some(q).or((q == output).then_some(p)))
+            .map_or(output, str::to_owned);
+        writeln!(input, "{a} {operation} {b} -> {output}").unwrap();
+    };
+
+    gate("x00", "XOR", "y00", "z00".to_owned());
+    gate("x00", "AND", "y00", "c00".to_owned());
+    for bit in 1..bits {
+        let (x, y, carry) = (
+            format!("x{bit:02}"),
+            format!("y{bit:02}"),
+            format!("c{:02}", bit - 1),
+        );
+        let carry_out = if bit + 1 == bits {
+            format!("z{bits:02}")
+        } else {
+            format!("c{bit:02}")
+        };
+
+        gate(&x, "XOR", &y, format!("s{bit:02}"));
+        gate(&format!("s{bit:02}"), "XOR", &carry, format!("z{bit:02}"));
+        gate(&x, "AND", &y, format!("a{bit:02}"));
+        gate(&format!("s{bit:02}"), "AND", &carry, format!("d{bit:02}"));
+        gate(
+            &format!("a{bit:02}"),
+            "OR",
+            &format!("d{bit:02}"),
+            carry_out,
+        );
+    }
+
+    input.trim_end().parse().unwrap()
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let device: Device = "x00: 1
+x01: 1
+x02: 1
+y00: 0
+y01: 1
+y02: 0
+
+x00 AND y00 -> z00
+x01 XOR y01 -> z01
+x02 OR y02 -> z02"
+        .parse()
+        .unwrap();
+
+    assert_eq!(device.output().unwrap(), 4);
+
+    let device = adder(45, &[]);
+    let (x, y) = ((1u64 << 45) - 1, 0x0aaa_aaaa_aaaa);
+    assert_eq!(device.output().unwrap(), x + y);
+
+    let device = adder(
+        45,
+        &[
+            ("z05", "s05"),
+            ("s12", "a12"),
+            ("z20", "c20"),
+            ("d27", "c27"),
+        ],
+    );
+
+    assert_eq!(
+        device.swapped_wires().unwrap(),
+        "a12,c20,c27,d27,s05,s12,z05,z20"
+    );
+}
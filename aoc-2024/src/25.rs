@@ -0,0 +1,110 @@
+use std::str::FromStr;
+
+use eyre::{Report, Result, bail};
+use itertools::Itertools;
+
+use aoc_meta::Problem;
+
+pub const CODE_CHRONICLE: Problem = Problem::partially_solved(&|input| {
+    input
+        .parse()
+        .map(|schematics: Schematics| schematics.count_fits())
+});
+
+#[derive(Debug)]
+struct Schematics {
+    /// The filled cells of each lock and key, one bit per cell.
+    locks: Vec<u64>,
+    keys: Vec<u64>,
+}
+
+impl Schematics {
+    /// The number of lock and key pairs that don't overlap anywhere.
+    fn count_fits(&self) -> usize {
+        self.locks
+            .iter()
+            .cartesian_product(&self.keys)
+            .filter(|&(lock, key)| lock & key == 0)
+            .count()
+    }
+}
+
+impl FromStr for Schematics {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (mut locks, mut keys) = (Vec::new(), Vec::new());
+
+        for schematic in s.split("\n\n") {
+            let cells = schematic.lines().flat_map(str::chars).collect_vec();
+            if cells.len() > 64 {
+                bail!("schematic is too big");
+            }
+
+            let bits = cells.iter().try_fold(0u64, |bits, &ch| match ch {
+                '#' => Ok(bits << 1 | 1),
+                '.' => Ok(bits << 1),
+                _ => bail!("unknown schematic cell '{ch}'"),
+            })?;
+
+            // Locks have their top row filled in, and keys have their bottom row filled in.
+            if schematic.starts_with('#') {
+                locks.push(bits);
+            } else {
+                keys.push(bits);
+            }
+        }
+
+        Ok(Self { locks, keys })
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let schematics: Schematics = "#####
+.####
+.####
+.####
+.#.#.
+.#...
+.....
+
+#####
+##.##
+.#.##
+...##
+...#.
+...#.
+.....
+
+.....
+#....
+#....
+#...#
+#.#.#
+#.###
+#####
+
+.....
+.....
+#.#..
+###..
+###.#
+###.#
+#####
+
+.....
+.....
+.....
+#....
+#.#..
+#.#.#
+#####"
+        .parse()
+        .unwrap();
+
+    assert_eq!(schematics.locks.len(), 2);
+    assert_eq!(schematics.count_fits(), 3);
+}
@@ -5,4 +5,25 @@ PROBLEMS! {
  02 => RED_NOSED_REPORTS,
  03 => MULL_IT_OVER,
  04 => CERES_SEARCH,
+ 05 => PRINT_QUEUE,
+ 06 => GUARD_GALLIVANT,
+ 07 => BRIDGE_REPAIR,
+ 08 => RESONANT_COLLINEARITY,
+ 09 => DISK_FRAGMENTER,
+ 10 => HOOF_IT,
+ 11 => PLUTONIAN_PEBBLES,
+ 12 => GARDEN_GROUPS,
+ 13 => CLAW_CONTRACTION,
+ 14 => RESTROOM_REDOUBT,
+ 15 => WAREHOUSE_WOES,
+ 16 => REINDEER_MAZE,
+ 17 => CHRONOSPATIAL_COMPUTER,
+ 18 => RAM_RUN,
+ 19 => LINEN_LAYOUT,
+ 20 => RACE_CONDITION,
+ 21 => KEYPAD_CONUNDRUM,
+ 22 => MONKEY_MARKET,
+ 23 => LAN_PARTY,
+ 24 => CROSSED_WIRES,
+ 25 => CODE_CHRONICLE,
 }
@@ -1,9 +1,10 @@
-use std::{cmp::Ordering, num::ParseIntError, ops::RangeInclusive, str::FromStr};
+use std::{num::ParseIntError, str::FromStr};
 
 use eyre::{Report, Result, bail};
+use rayon::prelude::*;
 
+use aoc_common::interval::IntervalSet;
 use aoc_meta::Problem;
-use rayon::prelude::*;
 
 pub const CAFETERIA: Problem = Problem::solved(
     &|input| input.parse().map(Ingredients::kitchen_fresh),
@@ -12,17 +13,14 @@ pub const CAFETERIA: Problem = Problem::solved(
 
 #[derive(Debug)]
 struct Ingredients {
-    fresh: Vec<RangeInclusive<u64>>,
+    fresh: IntervalSet<u64>,
     available: Vec<u64>,
 }
 
 impl Ingredients {
     /// Count the total number of fresh ingredients in the inventory system.
-    fn system_fresh(self) -> u64 {
-        self.fresh
-            .into_par_iter()
-            .map(|r| *r.end() - *r.start() + 1)
-            .sum::<u64>()
+    fn system_fresh(self) -> u128 {
+        self.fresh.len()
     }
 
     /// Count the total number of fresh ingredients in the kitchen.
@@ -35,16 +33,7 @@ impl Ingredients {
     }
 
     fn is_fresh(&self, id: u64) -> bool {
-        self.fresh
-            .binary_search_by(|range| match (*range.start() <= id, *range.end() >= id) {
-                (true, true) => Ordering::Equal,
-                (true, false) => Ordering::Less,
-                (false, true) => Ordering::Greater,
-                (false, false) => {
-                    unreachable!("range cannot be both greater than and less than id")
-                }
-            })
-            .is_ok()
+        self.fresh.contains(id)
     }
 }
 
@@ -69,7 +58,7 @@ impl FromStr for Ingredients {
 
                         Ok(start.parse()?..=end.parse()?)
                     })
-                    .collect::<Result<Vec<RangeInclusive<u64>>>>()
+                    .collect::<Result<IntervalSet<u64>>>()
             },
             || {
                 available
@@ -79,26 +68,31 @@ impl FromStr for Ingredients {
             },
         );
 
-        let mut fresh = fresh_res?;
-        fresh.par_sort_unstable_by_key(|r| *r.start());
-
-        // Collapse overlapping/adjacent ranges
-        let fresh = fresh
-            .into_iter()
-            .fold(Vec::<RangeInclusive<u64>>::new(), |mut acc, range| {
-                if let Some(last) = acc.last_mut()
-                    && *last.end() + 1 >= *range.start()
-                {
-                    *last = *last.start()..=(*last.end()).max(*range.end());
-                    return acc;
-                }
-                acc.push(range);
-                acc
-            });
-
+        let fresh = fresh_res?;
         let mut available = available_res?;
         available.sort();
 
         Ok(Self { fresh, available })
     }
 }
+
+#[test]
+fn example() {
+    let input = "3-5
+10-14
+16-20
+12-18
+
+1
+5
+8
+11
+17
+32";
+
+    let ingredients: Ingredients = input.parse().unwrap();
+    assert_eq!(ingredients.kitchen_fresh(), 3);
+
+    let ingredients: Ingredients = input.parse().unwrap();
+    assert_eq!(ingredients.system_fresh(), 14);
+}
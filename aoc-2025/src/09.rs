@@ -0,0 +1,211 @@
+use std::str::FromStr;
+
+use eyre::{Report, Result, bail};
+use itertools::Itertools;
+use rayon::prelude::*;
+
+use aoc_meta::Problem;
+
+pub const MOVIE_THEATER: Problem = Problem::solved(
+    &|input| {
+        input
+            .parse()
+            .map(|theater: Theater| theater.largest_rectangle())
+    },
+    &|input| {
+        input
+            .parse()
+            .map(|theater: Theater| theater.largest_colored_rectangle())
+    },
+);
+
+#[derive(Debug, Clone)]
+struct Theater {
+    /// The red tiles, in order around the loop they form.
+    red: Vec<Tile>,
+}
+
+impl Theater {
+    /// The largest area of a rectangle with red tiles in two opposite corners.
+    fn largest_rectangle(self) -> u64 {
+        self.red
+            .iter()
+            .tuple_combinations()
+            .map(|(a, b)| a.area_with(b))
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// The largest area of a rectangle with red tiles in two opposite corners that's made up of only red and green
+    /// tiles, which are the ones on or inside the loop of red tiles.
+    fn largest_colored_rectangle(self) -> u64 {
+        let outside = OutsideCount::new(&self.red);
+
+        self.red
+            .par_iter()
+            .enumerate()
+            .flat_map_iter(|(i, a)| self.red[i + 1..].iter().map(move |b| (a, b)))
+            .filter(|&(a, b)| outside.is_inside(a, b))
+            .map(|(a, b)| a.area_with(b))
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Tile {
+    x: u64,
+    y: u64,
+}
+
+impl Tile {
+    #[inline]
+    const fn area_with(&self, other: &Tile) -> u64 {
+        (self.x.abs_diff(other.x) + 1) * (self.y.abs_diff(other.y) + 1)
+    }
+}
+
+/// The theater floor squashed down so that each distinct coordinate of a red tile, and each gap between two of them,
+/// takes up one cell, with a running count of how many cells are outside the loop.
+#[derive(Debug)]
+struct OutsideCount {
+    xs: Vec<u64>,
+    ys: Vec<u64>,
+    /// `prefix[y * stride + x]` is the number of outside cells above and to the left of `(x, y)`, exclusive.
+    prefix: Vec<u32>,
+    stride: usize,
+}
+
+impl OutsideCount {
+    fn new(red: &[Tile]) -> Self {
+        let xs = red
+            .iter()
+            .map(|tile| tile.x)
+            .sorted_unstable()
+            .dedup()
+            .collect_vec();
+        let ys = red
+            .iter()
+            .map(|tile| tile.y)
+            .sorted_unstable()
+            .dedup()
+            .collect_vec();
+
+        // Coordinate `xs[i]` is at column `2 * i + 1`, leaving a border of gaps around everything that's definitely
+        // outside the loop.
+        let (width, height) = (2 * xs.len() + 1, 2 * ys.len() + 1);
+        let column = |x| 2 * xs.binary_search(&x).unwrap() + 1;
+        let row = |y| 2 * ys.binary_search(&y).unwrap() + 1;
+
+        let mut on_loop = vec![false; width * height];
+        for (a, b) in red.iter().circular_tuple_windows() {
+            let (x1, x2) = (column(a.x).min(column(b.x)), column(a.x).max(column(b.x)));
+            let (y1, y2) = (row(a.y).min(row(b.y)), row(a.y).max(row(b.y)));
+
+            for y in y1..=y2 {
+                on_loop[y * width + x1..=y * width + x2].fill(true);
+            }
+        }
+
+        let mut outside = vec![false; width * height];
+        let mut stack = vec![0];
+        outside[0] = true;
+
+        while let Some(idx) = stack.pop() {
+            let (x, y) = (idx % width, idx / width);
+            let neighbors = [
+                (x > 0).then(|| idx - 1),
+                (x + 1 < width).then_some(idx + 1),
+                (y > 0).then(|| idx - width),
+                (y + 1 < height).then_some(idx + width),
+            ];
+
+            for next in neighbors.into_iter().flatten() {
+                if !on_loop[next] && !outside[next] {
+                    outside[next] = true;
+                    stack.push(next);
+                }
+            }
+        }
+
+        let mut prefix = vec![0; (width + 1) * (height + 1)];
+        for y in 0..height {
+            for x in 0..width {
+                prefix[(y + 1) * (width + 1) + x + 1] = u32::from(outside[y * width + x])
+                    + prefix[y * (width + 1) + x + 1]
+                    + prefix[(y + 1) * (width + 1) + x]
+                    - prefix[y * (width + 1) + x];
+            }
+        }
+
+        Self {
+            xs,
+            ys,
+            prefix,
+            stride: width + 1,
+        }
+    }
+
+    /// Whether the rectangle with corners `a` and `b` (which must be red tiles) has no tiles outside the loop.
+    fn is_inside(&self, a: &Tile, b: &Tile) -> bool {
+        let column = |x| 2 * self.xs.binary_search(&x).unwrap() + 1;
+        let row = |y| 2 * self.ys.binary_search(&y).unwrap() + 1;
+
+        let (x1, x2) = (
+            column(a.x).min(column(b.x)),
+            column(a.x).max(column(b.x)) + 1,
+        );
+        let (y1, y2) = (row(a.y).min(row(b.y)), row(a.y).max(row(b.y)) + 1);
+
+        let at = |x, y| self.prefix[y * self.stride + x];
+        at(x2, y2) + at(x1, y1) == at(x1, y2) + at(x2, y1)
+    }
+}
+
+impl FromStr for Theater {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let red = s
+            .lines()
+            .map(|line| {
+                let Some((x, y)) = line.split_once(',') else {
+                    bail!("unable to split \"{line}\" into coordinates")
+                };
+
+                Ok(Tile {
+                    x: x.parse()?,
+                    y: y.parse()?,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        if let Some((a, b)) = red
+            .iter()
+            .circular_tuple_windows()
+            .find(|(a, b)| a.x != b.x && a.y != b.y)
+        {
+            bail!("red tiles {a:?} and {b:?} aren't in the same row or column");
+        }
+
+        Ok(Theater { red })
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let input = "7,1
+11,1
+11,7
+9,7
+9,5
+2,5
+2,3
+7,3";
+
+    let theater: Theater = input.parse().unwrap();
+    assert_eq!(theater.clone().largest_rectangle(), 50);
+    assert_eq!(theater.largest_colored_rectangle(), 24);
+}
@@ -0,0 +1,223 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use eyre::{OptionExt, Report, Result, bail, eyre};
+use itertools::Itertools;
+use rayon::prelude::*;
+
+use aoc_meta::Problem;
+
+pub const FACTORY: Problem = Problem::solved(
+    &|input| {
+        parse_machines(input)?
+            .par_iter()
+            .map(Machine::fewest_presses_for_lights)
+            .sum::<Option<u64>>()
+            .ok_or_eyre("some machine's lights can't be configured")
+    },
+    &|input| {
+        parse_machines(input)?
+            .par_iter()
+            .map(Machine::fewest_presses_for_joltage)
+            .sum::<Option<u64>>()
+            .ok_or_eyre("some machine's joltage levels can't be configured")
+    },
+);
+
+/// The most buttons a machine can have, since every set of them gets tried.
+const MAX_BUTTONS: usize = 16;
+
+fn parse_machines(input: &str) -> Result<Vec<Machine>> {
+    input.lines().map(Machine::from_str).collect()
+}
+
+#[derive(Debug, Clone)]
+struct Machine {
+    /// Bit `i` is set if light `i` should be on.
+    lights: u16,
+    /// The lights (or joltage counters) each button is wired to, as a bit mask.
+    buttons: Vec<u16>,
+    joltage: Vec<u16>,
+}
+
+impl Machine {
+    /// Pressing a button twice does nothing to the lights, so it's a matter of finding the smallest set of buttons
+    /// that toggles the right lights.
+    fn fewest_presses_for_lights(&self) -> Option<u64> {
+        (0..1u32 << self.buttons.len())
+            .filter(|&pressed| self.toggled_by(pressed) == self.lights)
+            .map(|pressed| u64::from(pressed.count_ones()))
+            .min()
+    }
+
+    #[inline]
+    fn toggled_by(&self, pressed: u32) -> u16 {
+        self.buttons
+            .iter()
+            .enumerate()
+            .filter(|&(i, _)| pressed & (1 << i) != 0)
+            .fold(0, |lights, (_, &button)| lights ^ button)
+    }
+
+    /// Any number of presses of a button is twice some number plus zero or one, so the fewest presses to reach some
+    /// joltage levels is the fewest over every set of buttons pressed once that leaves even levels `2 * rest` of
+    /// the size of that set plus twice the fewest presses to reach `rest`.
+    fn fewest_presses_for_joltage(&self) -> Option<u64> {
+        let mut by_parity: HashMap<u16, Vec<(u64, Vec<u16>)>> = HashMap::new();
+
+        for pressed in 0..1u32 << self.buttons.len() {
+            let mut levels = vec![0; self.joltage.len()];
+            for (i, &button) in self.buttons.iter().enumerate() {
+                if pressed & (1 << i) != 0 {
+                    for (counter, level) in levels.iter_mut().enumerate() {
+                        *level += (button >> counter) & 1;
+                    }
+                }
+            }
+
+            by_parity
+                .entry(parity(&levels))
+                .or_default()
+                .push((u64::from(pressed.count_ones()), levels));
+        }
+
+        fewest_presses_to(&self.joltage, &by_parity, &mut HashMap::new())
+    }
+}
+
+fn parity(levels: &[u16]) -> u16 {
+    levels
+        .iter()
+        .enumerate()
+        .fold(0, |mask, (i, level)| mask | ((level & 1) << i))
+}
+
+fn fewest_presses_to(
+    target: &[u16],
+    by_parity: &HashMap<u16, Vec<(u64, Vec<u16>)>>,
+    cache: &mut HashMap<Vec<u16>, Option<u64>>,
+) -> Option<u64> {
+    if target.iter().all(|&level| level == 0) {
+        return Some(0);
+    }
+
+    if let Some(&presses) = cache.get(target) {
+        return presses;
+    }
+
+    let presses = by_parity
+        .get(&parity(target))
+        .into_iter()
+        .flatten()
+        .filter_map(|(presses, levels)| {
+            let rest = target
+                .iter()
+                .zip(levels)
+                .map(|(&target, &level)| target.checked_sub(level).map(|rest| rest / 2))
+                .collect::<Option<Vec<_>>>()?;
+
+            Some(presses + 2 * fewest_presses_to(&rest, by_parity, cache)?)
+        })
+        .min();
+
+    cache.insert(target.to_vec(), presses);
+    presses
+}
+
+impl FromStr for Machine {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut parts = s.split_ascii_whitespace();
+
+        let lights = parts
+            .next()
+            .and_then(|lights| lights.strip_prefix('[')?.strip_suffix(']'))
+            .ok_or_eyre("missing light diagram")?;
+
+        let Some(joltage) = parts
+            .next_back()
+            .and_then(|joltage| joltage.strip_prefix('{')?.strip_suffix('}'))
+        else {
+            bail!("missing joltage requirements");
+        };
+
+        let num_lights = lights.len();
+        if num_lights > 16 {
+            bail!("too many lights ({num_lights})");
+        }
+
+        let lights = lights.chars().rev().try_fold(0, |mask, ch| match ch {
+            '#' => Ok(mask << 1 | 1),
+            '.' => Ok(mask << 1),
+            _ => Err(eyre!("unknown light '{ch}'")),
+        })?;
+
+        let buttons = parts
+            .map(|button| {
+                let Some(wiring) = button.strip_prefix('(').and_then(|b| b.strip_suffix(')'))
+                else {
+                    bail!("unable to parse button \"{button}\"")
+                };
+
+                wiring.split(',').try_fold(0, |mask, light| {
+                    let light: usize = light.parse()?;
+                    if light >= num_lights {
+                        bail!("button wired to light {light}, but there are only {num_lights}");
+                    }
+
+                    Ok(mask | 1 << light)
+                })
+            })
+            .collect::<Result<Vec<u16>>>()?;
+
+        if buttons.len() > MAX_BUTTONS {
+            bail!(
+                "too many buttons ({}), expected at most {MAX_BUTTONS}",
+                buttons.len()
+            );
+        }
+
+        let joltage: Vec<u16> = joltage.split(',').map(str::parse).try_collect()?;
+        if joltage.len() != num_lights {
+            bail!(
+                "expected {num_lights} joltage requirements, found {}",
+                joltage.len()
+            );
+        }
+
+        Ok(Machine {
+            lights,
+            buttons,
+            joltage,
+        })
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let machines = parse_machines(
+        "[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}
+[...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}
+[.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}",
+    )
+    .unwrap();
+
+    let lights = machines
+        .iter()
+        .map(|m| m.fewest_presses_for_lights().unwrap())
+        .collect_vec();
+    assert_eq!(lights, [2, 3, 2]);
+
+    let joltage = machines
+        .iter()
+        .map(|m| m.fewest_presses_for_joltage().unwrap())
+        .collect_vec();
+    assert_eq!(joltage, [10, 12, 11]);
+
+    assert!(parse_machines("[.#] (0) (2) {1,1}").is_err());
+    assert!(parse_machines("[.#] (0) (1) {1,1,1}").is_err());
+    assert!(parse_machines(&format!("[.#] {} {{1,1}}", "(0,1) ".repeat(17))).is_err());
+}
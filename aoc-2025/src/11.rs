@@ -0,0 +1,144 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use eyre::{OptionExt, Report, Result, bail};
+
+use aoc_meta::Problem;
+
+pub const REACTOR: Problem = Problem::solved(
+    &|input| {
+        input
+            .parse()
+            .and_then(|rack: Rack| rack.count_paths("you", "out"))
+    },
+    &|input| {
+        input
+            .parse()
+            .and_then(|rack: Rack| rack.count_paths_via("svr", "out", ["dac", "fft"]))
+    },
+);
+
+#[derive(Debug, Clone)]
+struct Rack {
+    outputs: HashMap<String, Vec<String>>,
+}
+
+impl Rack {
+    /// The number of different paths the data can take from `from` to `to`.
+    fn count_paths(&self, from: &str, to: &str) -> Result<u64> {
+        self.count(from, to, &mut HashMap::new())
+    }
+
+    /// The number of different paths from `from` to `to` that pass through both of `via`, in either order.
+    fn count_paths_via(&self, from: &str, to: &str, [a, b]: [&str; 2]) -> Result<u64> {
+        let through = |first, second| -> Result<u64> {
+            [(from, first), (first, second), (second, to)]
+                .into_iter()
+                .try_fold(1u64, |n, (from, to)| {
+                    n.checked_mul(self.count_paths(from, to)?)
+                        .ok_or_eyre("too many paths to count")
+                })
+        };
+
+        // The connections don't loop, so at most one of these orders has any paths.
+        Ok(through(a, b)? + through(b, a)?)
+    }
+
+    /// `paths` holds the number of paths to `to` from each device that's been counted, or `None` for devices still
+    /// being counted, which can only be reached again by going round a loop.
+    fn count<'s>(
+        &'s self,
+        from: &'s str,
+        to: &str,
+        paths: &mut HashMap<&'s str, Option<u64>>,
+    ) -> Result<u64> {
+        if from == to {
+            return Ok(1);
+        }
+
+        match paths.get(from) {
+            Some(Some(n)) => return Ok(*n),
+            Some(None) => bail!("device {from} feeds back into itself"),
+            None => {}
+        }
+
+        paths.insert(from, None);
+
+        let mut n = 0u64;
+        for next in self.outputs.get(from).into_iter().flatten() {
+            n = n
+                .checked_add(self.count(next, to, paths)?)
+                .ok_or_eyre("too many paths to count")?;
+        }
+
+        paths.insert(from, Some(n));
+        Ok(n)
+    }
+}
+
+impl FromStr for Rack {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let outputs = s
+            .lines()
+            .map(|line| {
+                let Some((device, outputs)) = line.split_once(": ") else {
+                    bail!("unable to split \"{line}\" into a device and its outputs")
+                };
+
+                Ok((
+                    device.to_owned(),
+                    outputs
+                        .split_ascii_whitespace()
+                        .map(str::to_owned)
+                        .collect(),
+                ))
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Rack { outputs })
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let rack: Rack = "aaa: you hhh
+you: bbb ccc
+bbb: ddd eee
+ccc: ddd eee fff
+ddd: ggg
+eee: out
+fff: out
+ggg: out
+hhh: ccc fff iii
+iii: out"
+        .parse()
+        .unwrap();
+
+    assert_eq!(rack.count_paths("you", "out").unwrap(), 5);
+
+    let rack: Rack = "svr: aaa bbb
+aaa: fft
+fft: ccc
+bbb: tty
+tty: ccc
+ccc: ddd eee
+ddd: hub
+hub: fff
+eee: dac
+dac: fff
+fff: ggg hhh
+ggg: out
+hhh: out"
+        .parse()
+        .unwrap();
+
+    assert_eq!(rack.count_paths("svr", "out").unwrap(), 8);
+    assert_eq!(
+        rack.count_paths_via("svr", "out", ["dac", "fft"]).unwrap(),
+        2
+    );
+}
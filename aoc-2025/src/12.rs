@@ -0,0 +1,366 @@
+use std::str::FromStr;
+
+use eyre::{OptionExt, Report, Result, bail, eyre};
+use itertools::Itertools;
+use rayon::prelude::*;
+
+use aoc_meta::Problem;
+
+pub const CHRISTMAS_TREE_FARM: Problem =
+    Problem::partially_solved(&|input| input.parse().map(Farm::count_fitting));
+
+#[derive(Debug, Clone)]
+struct Farm {
+    presents: Vec<Present>,
+    regions: Vec<Region>,
+}
+
+impl Farm {
+    /// The number of regions that all of their presents fit into.
+    fn count_fitting(self) -> usize {
+        self.regions
+            .par_iter()
+            .filter(|region| region.fits(&self.presents))
+            .count()
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Present {
+    /// Every distinct way the present can be turned or flipped, as offsets from its first cell (in reading order)
+    /// to each of its other cells.
+    orientations: Vec<Vec<(isize, isize)>>,
+    cells: usize,
+    width: usize,
+    height: usize,
+}
+
+impl Present {
+    fn new(cells: &[(isize, isize)]) -> Self {
+        // Four quarter turns, with and without flipping first.
+        let orientations = (0..8)
+            .map(|transform| {
+                let cells = cells
+                    .iter()
+                    .map(|&(x, y)| {
+                        let flipped = if transform >= 4 { (-x, y) } else { (x, y) };
+                        (0..transform % 4).fold(flipped, |(x, y), _| (-y, x))
+                    })
+                    .sorted_unstable_by_key(|&(x, y)| (y, x))
+                    .collect_vec();
+                let (first_x, first_y) = cells[0];
+
+                cells
+                    .into_iter()
+                    .skip(1)
+                    .map(|(x, y)| (x - first_x, y - first_y))
+                    .collect_vec()
+            })
+            .sorted_unstable()
+            .dedup()
+            .collect();
+
+        let extent = |axis: fn(&(isize, isize)) -> isize| {
+            let (min, max) = cells
+                .iter()
+                .map(axis)
+                .minmax()
+                .into_option()
+                .unwrap_or((0, -1));
+            (max - min + 1) as usize
+        };
+
+        Present {
+            orientations,
+            cells: cells.len(),
+            width: extent(|&(x, _)| x),
+            height: extent(|&(_, y)| y),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Region {
+    width: usize,
+    height: usize,
+    /// How many of each present have to fit under the tree.
+    quantities: Vec<usize>,
+}
+
+impl Region {
+    fn fits(&self, presents: &[Present]) -> bool {
+        let area = self.width * self.height;
+        let needed: usize = self
+            .quantities
+            .iter()
+            .zip(presents)
+            .map(|(quantity, present)| quantity * present.cells)
+            .sum();
+
+        if needed > area {
+            return false;
+        }
+
+        // If every present can have a box to itself, there's no need to fit them around each other.
+        let box_width = presents.iter().map(|p| p.width).max().unwrap_or(1);
+        let box_height = presents.iter().map(|p| p.height).max().unwrap_or(1);
+        let count: usize = self.quantities.iter().sum();
+        if (self.width / box_width) * (self.height / box_height) >= count {
+            return true;
+        }
+
+        let placements = presents
+            .iter()
+            .map(|present| self.placements(present))
+            .collect_vec();
+
+        Packing {
+            placements: &placements,
+            filled: vec![false; area],
+            remaining: self.quantities.clone(),
+        }
+        .search(0, area - needed)
+    }
+
+    /// Every way `present` can go in the region, indexed by the first cell (in reading order) it covers, as the
+    /// cells it covers.
+    fn placements(&self, present: &Present) -> Vec<Vec<Vec<usize>>> {
+        let index = |x: usize, dx: isize, y: usize, dy: isize| {
+            let (x, y) = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+            (x < self.width && y < self.height).then_some(y * self.width + x)
+        };
+
+        (0..self.width * self.height)
+            .map(|cell| {
+                let (x, y) = (cell % self.width, cell / self.width);
+
+                present
+                    .orientations
+                    .iter()
+                    .filter_map(|orientation| {
+                        let rest = orientation.iter().map(|&(dx, dy)| index(x, dx, y, dy));
+                        std::iter::once(Some(cell)).chain(rest).collect()
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+/// An exhaustive search for a way to pack presents into a region. Each step looks at the first empty cell, and
+/// either covers it with the first cell of some present or leaves it empty for good, which uses up some of the
+/// `slack` of cells that the presents don't need.
+#[derive(Debug)]
+struct Packing<'p> {
+    /// `placements[i][cell]` is every way present `i` can go with its first cell at `cell`.
+    placements: &'p [Vec<Vec<Vec<usize>>>],
+    filled: Vec<bool>,
+    remaining: Vec<usize>,
+}
+
+impl Packing<'_> {
+    fn search(&mut self, from: usize, slack: usize) -> bool {
+        if self.remaining.iter().all(|&n| n == 0) {
+            return true;
+        }
+
+        let Some(cell) = (from..self.filled.len()).find(|&cell| !self.filled[cell]) else {
+            return false;
+        };
+
+        // Cells that no present can cover any more are going to be left empty, so there had better be room for that.
+        if self.count_uncoverable(cell) > slack {
+            return false;
+        }
+
+        let placements = self.placements;
+        for (i, placements) in placements.iter().enumerate() {
+            if self.remaining[i] == 0 {
+                continue;
+            }
+
+            for placement in &placements[cell] {
+                if placement.iter().any(|&idx| self.filled[idx]) {
+                    continue;
+                }
+
+                self.place(placement, true);
+                self.remaining[i] -= 1;
+
+                let found = self.search(cell + 1, slack);
+
+                self.remaining[i] += 1;
+                self.place(placement, false);
+
+                if found {
+                    return true;
+                }
+            }
+        }
+
+        slack > 0 && {
+            self.filled[cell] = true;
+            let found = self.search(cell + 1, slack - 1);
+            self.filled[cell] = false;
+            found
+        }
+    }
+
+    /// The number of empty cells from `from` onwards that none of the remaining presents fit over.
+    fn count_uncoverable(&self, from: usize) -> usize {
+        let mut coverable = vec![false; self.filled.len()];
+
+        for (placements, _) in self
+            .placements
+            .iter()
+            .zip(&self.remaining)
+            .filter(|&(_, &remaining)| remaining > 0)
+        {
+            for placement in placements[from..].iter().flatten() {
+                if placement.iter().all(|&idx| !self.filled[idx]) {
+                    for &idx in placement {
+                        coverable[idx] = true;
+                    }
+                }
+            }
+        }
+
+        (from..self.filled.len())
+            .filter(|&idx| !self.filled[idx] && !coverable[idx])
+            .count()
+    }
+
+    #[inline]
+    fn place(&mut self, cells: &[usize], filled: bool) {
+        for &idx in cells {
+            self.filled[idx] = filled;
+        }
+    }
+}
+
+impl FromStr for Farm {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut presents = Vec::new();
+        let mut regions = Vec::new();
+
+        for section in s.split("\n\n") {
+            let mut lines = section.lines();
+            let header = lines.next().ok_or_eyre("empty section")?;
+
+            if let Some(index) = header.strip_suffix(':') {
+                if index.parse::<usize>()? != presents.len() {
+                    bail!("expected present {}, found present {index}", presents.len());
+                }
+
+                let cells = lines
+                    .enumerate()
+                    .flat_map(|(y, row)| {
+                        row.chars().enumerate().filter_map(move |(x, ch)| match ch {
+                            '#' => Some(Ok((x as isize, y as isize))),
+                            '.' => None,
+                            _ => Some(Err(eyre!("unknown present cell '{ch}'"))),
+                        })
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+
+                if cells.is_empty() {
+                    bail!("present {index} is empty");
+                }
+
+                presents.push(Present::new(&cells));
+            } else {
+                for line in section.lines() {
+                    regions.push(line.parse()?);
+                }
+            }
+        }
+
+        if let Some(region) = regions
+            .iter()
+            .find(|region: &&Region| region.quantities.len() != presents.len())
+        {
+            bail!(
+                "region lists {} quantities for {} presents",
+                region.quantities.len(),
+                presents.len()
+            );
+        }
+
+        Ok(Farm { presents, regions })
+    }
+}
+
+impl FromStr for Region {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let Some((size, quantities)) = s.split_once(": ") else {
+            bail!("unable to split \"{s}\" into a size and quantities")
+        };
+
+        let Some((width, height)) = size.split_once('x') else {
+            bail!("unable to split \"{size}\" into a width and height")
+        };
+
+        Ok(Region {
+            width: width.parse()?,
+            height: height.parse()?,
+            quantities: quantities
+                .split_ascii_whitespace()
+                .map(str::parse)
+                .try_collect()?,
+        })
+    }
+}
+
+#[test]
+fn example() {
+    use pretty_assertions::assert_eq;
+
+    let farm: Farm = "0:
+###
+##.
+##.
+
+1:
+###
+##.
+.##
+
+2:
+.##
+###
+##.
+
+3:
+##.
+###
+##.
+
+4:
+###
+#..
+###
+
+5:
+###
+.#.
+###
+
+4x4: 0 0 0 0 2 0
+12x5: 1 0 1 0 2 2
+12x5: 1 0 1 0 3 2"
+        .parse()
+        .unwrap();
+
+    let fits = farm
+        .regions
+        .iter()
+        .map(|region| region.fits(&farm.presents))
+        .collect_vec();
+
+    assert_eq!(fits, [true, true, false]);
+    assert_eq!(farm.count_fitting(), 2);
+}
@@ -9,4 +9,8 @@ PROBLEMS! {
     06 => TRASH_COMPACTOR,
     07 => LABORATORIES,
     08 => PLAYGROUND,
+    09 => MOVIE_THEATER,
+    10 => FACTORY,
+    11 => REACTOR,
+    12 => CHRISTMAS_TREE_FARM,
 }
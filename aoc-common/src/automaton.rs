@@ -0,0 +1,525 @@
+//! Cellular automata on bit-packed cells.
+//!
+//! Each row of cells is stored as a run of `u64` words, one bit per cell, so a generation is computed a whole word
+//! at a time: every neighbor offset becomes a shifted copy of a row, and neighbor counts are summed with a bit-sliced
+//! adder instead of cell by cell.
+
+use std::borrow::Cow;
+
+use eyre::{Result, bail};
+use rayon::prelude::*;
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// What lies beyond the edges of an automaton.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Edges {
+    /// Cells past the edge are always dead.
+    #[default]
+    Bounded,
+    /// The edges wrap around, so the first and last cells of each row (and column) are neighbors.
+    Toroidal,
+}
+
+/// The cells whose states are counted when deciding the next state of a cell in a [`Board`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Neighborhood {
+    /// The eight surrounding cells.
+    Moore,
+    /// The four orthogonally adjacent cells.
+    VonNeumann,
+    /// Cells at arbitrary `(dx, dy)` offsets, where `y` increases downwards. At most 31 offsets are supported.
+    Custom(Vec<(isize, isize)>),
+}
+
+impl Neighborhood {
+    fn offsets(&self) -> Cow<'_, [(isize, isize)]> {
+        match self {
+            Neighborhood::Moore => Cow::Borrowed(&[
+                (-1, -1),
+                (0, -1),
+                (1, -1),
+                (-1, 0),
+                (1, 0),
+                (-1, 1),
+                (0, 1),
+                (1, 1),
+            ]),
+            Neighborhood::VonNeumann => Cow::Borrowed(&[(0, -1), (-1, 0), (1, 0), (0, 1)]),
+            Neighborhood::Custom(offsets) => Cow::Borrowed(offsets),
+        }
+    }
+}
+
+/// A totalistic rule: whether a cell is alive in the next generation depends only on whether it's alive now and
+/// how many of its neighbors are.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rule {
+    /// Bit `n` is set if a dead cell with `n` live neighbors comes alive.
+    birth: u32,
+    /// Bit `n` is set if a live cell with `n` live neighbors stays alive.
+    survival: u32,
+}
+
+impl Rule {
+    /// Conway's Game of Life, `B3/S23`.
+    pub const LIFE: Rule = Rule {
+        birth: 1 << 3,
+        survival: (1 << 2) | (1 << 3),
+    };
+
+    /// A rule where dead cells with a neighbor count in `birth` come alive, and live cells with a neighbor count in
+    /// `survival` stay alive.
+    pub fn new<B, S>(birth: B, survival: S) -> Self
+    where
+        B: IntoIterator<Item = u32>,
+        S: IntoIterator<Item = u32>,
+    {
+        let mask =
+            |counts: &mut dyn Iterator<Item = u32>| counts.fold(0, |mask, n| mask | (1 << n));
+
+        Self {
+            birth: mask(&mut birth.into_iter()),
+            survival: mask(&mut survival.into_iter()),
+        }
+    }
+}
+
+/// Returns 64 bits of `row` starting at bit `start`, treating bits before or after `row` as zero.
+#[inline]
+fn bits_at(row: &[u64], start: isize) -> u64 {
+    let word = |idx: isize| {
+        usize::try_from(idx)
+            .ok()
+            .and_then(|idx| row.get(idx))
+            .copied()
+            .unwrap_or_default()
+    };
+
+    let idx = start.div_euclid(WORD_BITS as isize);
+    let bit = start.rem_euclid(WORD_BITS as isize) as u32;
+
+    if bit == 0 {
+        word(idx)
+    } else {
+        (word(idx) >> bit) | (word(idx + 1) << (u64::BITS - bit))
+    }
+}
+
+/// Writes `row` shifted so that each cell `x` of `out` holds cell `x + dx` of `row`.
+fn shift_row(row: &[u64], width: usize, dx: isize, edges: Edges, out: &mut [u64]) {
+    for (i, word) in out.iter_mut().enumerate() {
+        let start = (i * WORD_BITS) as isize;
+
+        *word = match edges {
+            Edges::Bounded => bits_at(row, start + dx),
+            Edges::Toroidal => {
+                let dx = dx.rem_euclid(width as isize);
+                bits_at(row, start + dx) | bits_at(row, start + dx - width as isize)
+            }
+        };
+    }
+
+    mask_last_word(out, width);
+}
+
+/// Clears the bits past `width` in the last word of `row`.
+#[inline]
+fn mask_last_word(row: &mut [u64], width: usize) {
+    let used = width % WORD_BITS;
+
+    if let Some(last) = row.last_mut()
+        && used != 0
+    {
+        *last &= (1 << used) - 1;
+    }
+}
+
+/// Adds the bits of `row` to the bit-sliced counters in `planes`, where plane `i` holds bit `i` of each count.
+#[inline]
+fn add_to_counts(planes: &mut [Vec<u64>], row: &[u64]) {
+    for (i, &bits) in row.iter().enumerate() {
+        let mut carry = bits;
+
+        for plane in planes.iter_mut() {
+            if carry == 0 {
+                break;
+            }
+
+            (plane[i], carry) = (plane[i] ^ carry, plane[i] & carry);
+        }
+    }
+}
+
+/// Returns a word where each bit is set if the corresponding count in `planes` is `n`.
+#[inline]
+fn count_is(planes: &[Vec<u64>], i: usize, n: u32) -> u64 {
+    planes
+        .iter()
+        .enumerate()
+        .fold(u64::MAX, |eq, (bit, plane)| {
+            if n & (1 << bit) == 0 {
+                eq & !plane[i]
+            } else {
+                eq & plane[i]
+            }
+        })
+}
+
+/// A one-dimensional automaton, where each cell's next state depends on itself and the cells on either side of it.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Line {
+    cells: Vec<u64>,
+    width: usize,
+    edges: Edges,
+}
+
+impl Line {
+    /// A line of `width` dead cells.
+    pub fn new(width: usize, edges: Edges) -> Self {
+        Self {
+            cells: vec![0; width.div_ceil(WORD_BITS)],
+            width,
+            edges,
+        }
+    }
+
+    /// A line whose cells are alive where `cells` is `true`.
+    pub fn from_cells<I: IntoIterator<Item = bool>>(cells: I, edges: Edges) -> Self {
+        let cells: Vec<bool> = cells.into_iter().collect();
+        let mut line = Self::new(cells.len(), edges);
+
+        for (x, alive) in cells.into_iter().enumerate() {
+            line.set(x, alive);
+        }
+
+        line
+    }
+
+    /// The number of cells in the line.
+    #[inline]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns `true` if cell `x` is alive.
+    #[inline]
+    pub fn get(&self, x: usize) -> bool {
+        x < self.width && self.cells[x / WORD_BITS] & (1 << (x % WORD_BITS)) != 0
+    }
+
+    /// Set whether cell `x` is alive.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `x` is out of bounds.
+    #[inline]
+    pub fn set(&mut self, x: usize, alive: bool) {
+        assert!(
+            x < self.width,
+            "cell {x} is outside a line of {}",
+            self.width
+        );
+
+        let bit = 1 << (x % WORD_BITS);
+        if alive {
+            self.cells[x / WORD_BITS] |= bit;
+        } else {
+            self.cells[x / WORD_BITS] &= !bit;
+        }
+    }
+
+    /// The number of live cells.
+    pub fn population(&self) -> usize {
+        self.cells
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// Advance one generation using an elementary rule, given as its Wolfram code: bit `4l + 2c + r` of `rule` is
+    /// the next state of a cell that's currently `c` with neighbors `l` and `r`. Returns `true` if any cell changed.
+    pub fn step(&mut self, rule: u8) -> bool {
+        let words = self.cells.len();
+        let mut left = vec![0; words];
+        let mut right = vec![0; words];
+        shift_row(&self.cells, self.width, -1, self.edges, &mut left);
+        shift_row(&self.cells, self.width, 1, self.edges, &mut right);
+
+        let mut changed = false;
+        for (i, word) in self.cells.iter_mut().enumerate() {
+            let (l, c, r) = (left[i], *word, right[i]);
+
+            let next =
+                (0..8)
+                    .filter(|pattern| rule & (1 << pattern) != 0)
+                    .fold(0, |next, pattern| {
+                        let pick =
+                            |bits: u64, bit: u8| if pattern & bit == 0 { !bits } else { bits };
+                        next | (pick(l, 4) & pick(c, 2) & pick(r, 1))
+                    });
+
+            changed |= next != *word;
+            *word = next;
+        }
+
+        mask_last_word(&mut self.cells, self.width);
+        changed
+    }
+}
+
+/// A two-dimensional automaton.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Board {
+    /// Rows of cells, top to bottom, each `words_per_row` long.
+    cells: Vec<u64>,
+    /// Cells that never change, laid out like `cells`.
+    fixed: Vec<u64>,
+    width: usize,
+    height: usize,
+    words_per_row: usize,
+    edges: Edges,
+}
+
+impl Board {
+    /// A board of dead cells.
+    pub fn new(width: usize, height: usize, edges: Edges) -> Self {
+        let words_per_row = width.div_ceil(WORD_BITS);
+
+        Self {
+            cells: vec![0; words_per_row * height],
+            fixed: vec![0; words_per_row * height],
+            width,
+            height,
+            words_per_row,
+            edges,
+        }
+    }
+
+    /// A board whose cells are alive where `rows` (from top to bottom) are `true`. Every row must be the same
+    /// length.
+    pub fn from_rows<R, C>(rows: R, edges: Edges) -> Result<Self>
+    where
+        R: IntoIterator<Item = C>,
+        C: IntoIterator<Item = bool>,
+    {
+        let rows: Vec<Vec<bool>> = rows
+            .into_iter()
+            .map(|row| row.into_iter().collect())
+            .collect();
+
+        let width = rows.first().map(Vec::len).unwrap_or_default();
+        if let Some(y) = rows.iter().position(|row| row.len() != width) {
+            bail!(
+                "row {y} has {} cells, but the first row has {width}",
+                rows[y].len()
+            );
+        }
+
+        let mut board = Self::new(width, rows.len(), edges);
+        for (y, row) in rows.into_iter().enumerate() {
+            for (x, alive) in row.into_iter().enumerate() {
+                board.set(x, y, alive);
+            }
+        }
+
+        Ok(board)
+    }
+
+    #[inline]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    #[inline]
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    #[inline]
+    fn position(&self, x: usize, y: usize) -> (usize, u64) {
+        assert!(
+            x < self.width && y < self.height,
+            "({x}, {y}) is outside a {}x{} board",
+            self.width,
+            self.height
+        );
+
+        (y * self.words_per_row + x / WORD_BITS, 1 << (x % WORD_BITS))
+    }
+
+    /// Returns `true` if the cell at `(x, y)` is alive.
+    #[inline]
+    pub fn get(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.height && {
+            let (idx, bit) = self.position(x, y);
+            self.cells[idx] & bit != 0
+        }
+    }
+
+    /// Set whether the cell at `(x, y)` is alive.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `(x, y)` is out of bounds.
+    #[inline]
+    pub fn set(&mut self, x: usize, y: usize, alive: bool) {
+        let (idx, bit) = self.position(x, y);
+
+        if alive {
+            self.cells[idx] |= bit;
+        } else {
+            self.cells[idx] &= !bit;
+        }
+    }
+
+    /// Set whether the cell at `(x, y)` is alive, and keep it that way in every later generation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `(x, y)` is out of bounds.
+    pub fn fix(&mut self, x: usize, y: usize, alive: bool) {
+        self.set(x, y, alive);
+
+        let (idx, bit) = self.position(x, y);
+        self.fixed[idx] |= bit;
+    }
+
+    /// The number of live cells.
+    pub fn population(&self) -> usize {
+        self.cells
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// The row `dy` rows away from row `y`, if there is one.
+    fn row(&self, y: usize, dy: isize) -> Option<&[u64]> {
+        let y = match self.edges {
+            Edges::Bounded => y.checked_add_signed(dy).filter(|&y| y < self.height)?,
+            Edges::Toroidal => (y as isize + dy).rem_euclid(self.height as isize) as usize,
+        };
+
+        Some(&self.cells[y * self.words_per_row..][..self.words_per_row])
+    }
+
+    /// Advance one generation. Returns `true` if any cell changed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `neighborhood` has more than 31 cells.
+    pub fn step(&mut self, neighborhood: &Neighborhood, rule: &Rule) -> bool {
+        let offsets = neighborhood.offsets();
+        assert!(
+            offsets.len() < u32::BITS as usize,
+            "neighborhoods of more than 31 cells aren't supported"
+        );
+
+        let num_planes = (usize::BITS - offsets.len().leading_zeros()) as usize;
+        let words_per_row = self.words_per_row.max(1);
+
+        let mut next = vec![0; self.cells.len()];
+        next.par_chunks_mut(words_per_row)
+            .enumerate()
+            .for_each_init(
+                || {
+                    (
+                        vec![vec![0; words_per_row]; num_planes],
+                        vec![0; words_per_row],
+                    )
+                },
+                |(planes, shifted), (y, out)| {
+                    planes.iter_mut().for_each(|plane| plane.fill(0));
+
+                    for &(dx, dy) in offsets.iter() {
+                        if let Some(row) = self.row(y, dy) {
+                            shift_row(row, self.width, dx, self.edges, shifted);
+                            add_to_counts(planes, shifted);
+                        }
+                    }
+
+                    let current = &self.cells[y * words_per_row..][..words_per_row];
+                    let fixed = &self.fixed[y * words_per_row..][..words_per_row];
+
+                    for (i, word) in out.iter_mut().enumerate() {
+                        let alive = current[i];
+                        let next = (0..=offsets.len() as u32).fold(0, |next, n| {
+                            let mut keep = 0;
+                            if rule.birth & (1 << n) != 0 {
+                                keep |= !alive;
+                            }
+                            if rule.survival & (1 << n) != 0 {
+                                keep |= alive;
+                            }
+
+                            if keep == 0 {
+                                next
+                            } else {
+                                next | (keep & count_is(planes, i, n))
+                            }
+                        });
+
+                        *word = (next & !fixed[i]) | (alive & fixed[i]);
+                    }
+
+                    mask_last_word(out, self.width);
+                },
+            );
+
+        let changed = next != self.cells;
+        self.cells = next;
+        changed
+    }
+}
+
+#[test]
+fn lines() {
+    // Rule 90: each cell becomes the XOR of its neighbors.
+    let mut line = Line::from_cells((0..70).map(|x| x == 64), Edges::Bounded);
+    assert!(line.step(90));
+    assert_eq!(
+        (line.get(63), line.get(64), line.get(65)),
+        (true, false, true)
+    );
+    assert_eq!(line.population(), 2);
+
+    let mut ring = Line::from_cells((0..5).map(|x| x == 0), Edges::Toroidal);
+    ring.step(90);
+    assert!(ring.get(1) && ring.get(4) && ring.population() == 2);
+
+    // Rule 204 is the identity.
+    assert!(!ring.step(204));
+}
+
+#[test]
+fn boards() {
+    let glider = [".#...", "..#..", "###..", ".....", "....."];
+    let rows = || glider.iter().map(|row| row.chars().map(|c| c == '#'));
+
+    // A glider on a torus comes back to where it started after 4 generations per cell of travel.
+    let mut torus = Board::from_rows(rows(), Edges::Toroidal).unwrap();
+    let start = torus.clone();
+    for _ in 0..20 {
+        torus.step(&Neighborhood::Moore, &Rule::LIFE);
+    }
+    assert_eq!(torus, start);
+
+    // On a bounded board it runs into the corner and becomes a block.
+    let mut bounded = Board::from_rows(rows(), Edges::Bounded).unwrap();
+    for _ in 0..12 {
+        bounded.step(&Neighborhood::Moore, &Rule::LIFE);
+    }
+    assert_eq!(bounded.population(), 4);
+    assert!(bounded.get(3, 3) && bounded.get(4, 4));
+    assert!(!bounded.step(&Neighborhood::Moore, &Rule::LIFE));
+
+    // Fixed cells ignore the rule.
+    let mut board = Board::new(3, 3, Edges::Bounded);
+    board.fix(0, 0, true);
+    board.step(&Neighborhood::VonNeumann, &Rule::new([], []));
+    assert_eq!(board.population(), 1);
+    board.step(&Neighborhood::VonNeumann, &Rule::new([1], []));
+    assert_eq!(board.population(), 3);
+
+    assert!(Board::from_rows([vec![true], vec![]], Edges::Bounded).is_err());
+}
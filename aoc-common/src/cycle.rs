@@ -0,0 +1,214 @@
+//! Finding where a sequence of states starts repeating, so that a far-off state can be found without simulating
+//! every step on the way there.
+
+use std::collections::hash_map::Entry;
+use std::hash::Hash;
+
+use fnv::FnvHashMap;
+
+/// Where a sequence of states starts repeating itself.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cycle {
+    /// The index of the first state that's part of the cycle (i.e. the length of the prefix before it).
+    pub start: usize,
+    /// The number of states in the cycle.
+    pub period: usize,
+}
+
+impl Cycle {
+    /// The index of the earliest state that's the same as state `n`.
+    #[inline]
+    pub const fn reduce(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.period
+        }
+    }
+
+    /// How many of the first `n` states are the same as state `idx`, where `idx < start + period`.
+    #[inline]
+    pub const fn occurrences(&self, n: usize, idx: usize) -> usize {
+        if n <= idx {
+            0
+        } else if idx < self.start {
+            1
+        } else {
+            (n - idx - 1) / self.period + 1
+        }
+    }
+}
+
+/// Find the cycle in the sequence `start, f(start), f(f(start)), ...` with Brent's algorithm, which only keeps two
+/// states around at a time.
+///
+/// This never returns if the sequence doesn't repeat.
+pub fn brent<T: Clone + Eq, F: FnMut(&T) -> T>(start: T, mut f: F) -> Cycle {
+    // Find the period by letting the hare run ahead of the tortoise, teleporting the tortoise to the hare whenever
+    // the distance between them reaches a power of two.
+    let mut power = 1;
+    let mut period = 1;
+    let mut tortoise = start.clone();
+    let mut hare = f(&start);
+
+    while tortoise != hare {
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+
+        hare = f(&hare);
+        period += 1;
+    }
+
+    // Then find the start by walking two states a period apart until they meet.
+    let mut tortoise = start.clone();
+    let mut hare = (0..period).fold(start, |state, _| f(&state));
+    let mut cycle_start = 0;
+
+    while tortoise != hare {
+        tortoise = f(&tortoise);
+        hare = f(&hare);
+        cycle_start += 1;
+    }
+
+    Cycle {
+        start: cycle_start,
+        period,
+    }
+}
+
+/// The `n`th state (counting `start` as the zeroth) of the sequence `start, f(start), f(f(start)), ...`, skipping
+/// over every complete trip around its cycle, which is found with [`brent`].
+///
+/// This never returns if the sequence doesn't repeat.
+pub fn brent_nth<T: Clone + Eq, F: FnMut(&T) -> T>(start: T, mut f: F, n: usize) -> T {
+    let cycle = brent(start.clone(), &mut f);
+    (0..cycle.reduce(n)).fold(start, |state, _| f(&state))
+}
+
+/// The states of a sequence up to the point where it starts repeating itself, returned by [`history`] and
+/// [`history_by_key`].
+#[derive(Debug, Clone)]
+pub struct History<T> {
+    states: Vec<T>,
+    cycle: Option<Cycle>,
+}
+
+/// Record the states of `states` until one of them repeats, or `max_len` of them have been seen.
+pub fn history<I>(states: I, max_len: usize) -> History<I::Item>
+where
+    I: IntoIterator,
+    I::Item: Clone + Eq + Hash,
+{
+    history_by_key(states, max_len, Clone::clone)
+}
+
+/// Record the states of `states` until two of them have the same fingerprint, as given by `key`, or `max_len` of
+/// them have been seen.
+pub fn history_by_key<I, K, F>(states: I, max_len: usize, mut key: F) -> History<I::Item>
+where
+    I: IntoIterator,
+    K: Eq + Hash,
+    F: FnMut(&I::Item) -> K,
+{
+    let mut seen = FnvHashMap::default();
+    let mut history = Vec::new();
+
+    for state in states.into_iter().take(max_len) {
+        match seen.entry(key(&state)) {
+            Entry::Occupied(occ) => {
+                let start = *occ.get();
+
+                return History {
+                    cycle: Some(Cycle {
+                        start,
+                        period: history.len() - start,
+                    }),
+                    states: history,
+                };
+            }
+            Entry::Vacant(vac) => {
+                vac.insert(history.len());
+                history.push(state);
+            }
+        }
+    }
+
+    History {
+        states: history,
+        cycle: None,
+    }
+}
+
+impl<T> History<T> {
+    /// The cycle in the sequence, if one was found.
+    #[inline]
+    pub fn cycle(&self) -> Option<Cycle> {
+        self.cycle
+    }
+
+    /// The `n`th state of the sequence, if it was either recorded or is part of the cycle.
+    pub fn nth(&self, n: usize) -> Option<&T> {
+        match self.cycle {
+            Some(cycle) => self.states.get(cycle.reduce(n)),
+            None => self.states.get(n),
+        }
+    }
+
+    /// The sum of `f` over the first `n` states of the sequence, if they were either recorded or are part of the
+    /// cycle.
+    pub fn sum_first<F: FnMut(&T) -> usize>(&self, n: usize, mut f: F) -> Option<usize> {
+        match self.cycle {
+            Some(cycle) => Some(
+                self.states
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, state)| f(state) * cycle.occurrences(n, idx))
+                    .sum(),
+            ),
+            None => self
+                .states
+                .get(..n)
+                .map(|states| states.iter().map(f).sum()),
+        }
+    }
+}
+
+#[test]
+fn cycles() {
+    // 0, 1, 2, 3, 4, 2, 3, 4, ...
+    let next = |&n: &usize| if n == 4 { 2 } else { n + 1 };
+    let expected = Cycle {
+        start: 2,
+        period: 3,
+    };
+
+    assert_eq!(brent(0, next), expected);
+    assert_eq!(brent_nth(0, next, 1_000_000), 4);
+
+    let states = || std::iter::successors(Some(0), |n| Some(next(n)));
+    let recorded = history(states(), usize::MAX);
+    assert_eq!(recorded.cycle(), Some(expected));
+    assert_eq!(recorded.nth(1_000_000), Some(&4));
+    assert_eq!(recorded.sum_first(7, |&n| n), Some(15));
+    assert_eq!(
+        recorded.sum_first(1000, |&n| n),
+        Some(states().take(1000).sum())
+    );
+
+    let parity = history_by_key(states(), 10, |n| n % 2);
+    assert_eq!(
+        parity.cycle(),
+        Some(Cycle {
+            start: 0,
+            period: 2
+        })
+    );
+
+    let truncated = history(0.., 5);
+    assert_eq!(truncated.cycle(), None);
+    assert_eq!(truncated.nth(4), Some(&4));
+    assert_eq!(truncated.nth(5), None);
+}
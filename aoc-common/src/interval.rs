@@ -0,0 +1,348 @@
+//! Sets of integers stored as sorted, disjoint, inclusive ranges.
+
+use std::fmt::{self, Debug, Formatter};
+use std::ops::RangeInclusive;
+
+use rayon::prelude::*;
+
+/// An integer type that can be stored in an [`IntervalSet`].
+pub trait Endpoint: Copy + Ord + Send + Sync {
+    /// The next integer after `self`, if there is one.
+    fn successor(self) -> Option<Self>;
+
+    /// The integer before `self`, if there is one.
+    fn predecessor(self) -> Option<Self>;
+
+    /// The number of integers in `start..=end`, where `start <= end`.
+    fn span(start: Self, end: Self) -> u128;
+}
+
+macro_rules! impl_endpoint_for {
+    ($($ty:ty),+) => {
+        $(
+            impl Endpoint for $ty {
+                #[inline]
+                fn successor(self) -> Option<Self> {
+                    self.checked_add(1)
+                }
+
+                #[inline]
+                fn predecessor(self) -> Option<Self> {
+                    self.checked_sub(1)
+                }
+
+                #[inline]
+                fn span(start: Self, end: Self) -> u128 {
+                    (end as i128 - start as i128) as u128 + 1
+                }
+            }
+        )+
+    };
+}
+
+impl_endpoint_for!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+/// A set of integers, stored as the smallest possible list of sorted, disjoint, non-adjacent inclusive ranges.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    ranges: Vec<RangeInclusive<T>>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self { ranges: Vec::new() }
+    }
+}
+
+impl<T: Debug> Debug for IntervalSet<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(&self.ranges).finish()
+    }
+}
+
+impl<T: Endpoint> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build a set from ranges that are already sorted by their start.
+    fn from_sorted<I: IntoIterator<Item = RangeInclusive<T>>>(sorted: I) -> Self {
+        let mut ranges: Vec<RangeInclusive<T>> = Vec::new();
+
+        for range in sorted.into_iter().filter(|r| !r.is_empty()) {
+            if let Some(last) = ranges.last_mut()
+                && last
+                    .end()
+                    .successor()
+                    .is_none_or(|after| after >= *range.start())
+            {
+                if range.end() > last.end() {
+                    *last = *last.start()..=*range.end();
+                }
+            } else {
+                ranges.push(range);
+            }
+        }
+
+        Self { ranges }
+    }
+
+    /// Returns `true` if the set contains no integers.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// The total number of integers in the set.
+    pub fn len(&self) -> u128 {
+        self.ranges
+            .iter()
+            .map(|range| T::span(*range.start(), *range.end()))
+            .sum()
+    }
+
+    /// The smallest integer in the set.
+    #[inline]
+    pub fn min(&self) -> Option<T> {
+        self.ranges.first().map(|range| *range.start())
+    }
+
+    /// The largest integer in the set.
+    #[inline]
+    pub fn max(&self) -> Option<T> {
+        self.ranges.last().map(|range| *range.end())
+    }
+
+    /// Returns `true` if `value` is in the set.
+    pub fn contains(&self, value: T) -> bool {
+        let idx = self.ranges.partition_point(|range| *range.end() < value);
+        self.ranges
+            .get(idx)
+            .is_some_and(|range| *range.start() <= value)
+    }
+
+    /// Returns `true` if every integer in `range` is in the set.
+    pub fn contains_range(&self, range: RangeInclusive<T>) -> bool {
+        if range.is_empty() {
+            return true;
+        }
+
+        let idx = self.ranges.partition_point(|r| r.end() < range.start());
+        self.ranges
+            .get(idx)
+            .is_some_and(|r| r.start() <= range.start() && r.end() >= range.end())
+    }
+
+    /// Add every integer in `range` to the set.
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        if range.is_empty() {
+            return;
+        }
+
+        // Every range that overlaps or touches `range` sits in `first..last`.
+        let first = self.ranges.partition_point(|r| {
+            r.end()
+                .successor()
+                .is_some_and(|after| after < *range.start())
+        });
+        let last = self.ranges.partition_point(|r| {
+            range
+                .end()
+                .successor()
+                .is_none_or(|after| *r.start() <= after)
+        });
+
+        let merged = if first < last {
+            let start = T::min(*self.ranges[first].start(), *range.start());
+            let end = T::max(*self.ranges[last - 1].end(), *range.end());
+            start..=end
+        } else {
+            range
+        };
+
+        self.ranges.splice(first..last, [merged]);
+    }
+
+    /// Remove every integer in `range` from the set.
+    pub fn remove(&mut self, range: RangeInclusive<T>) {
+        *self = self.difference(&IntervalSet::from(range));
+    }
+
+    /// Every integer in either `self` or `other`.
+    pub fn union(&self, other: &Self) -> Self {
+        let mut ranges = Vec::with_capacity(self.ranges.len() + other.ranges.len());
+        let (mut a, mut b) = (
+            self.ranges.iter().peekable(),
+            other.ranges.iter().peekable(),
+        );
+
+        while let Some(next) = match (a.peek(), b.peek()) {
+            (Some(x), Some(y)) if x.start() <= y.start() => a.next(),
+            (Some(_), Some(_)) => b.next(),
+            (Some(_), None) => a.next(),
+            (None, _) => b.next(),
+        } {
+            ranges.push(next.clone());
+        }
+
+        Self::from_sorted(ranges)
+    }
+
+    /// Every integer in both `self` and `other`.
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+
+        while let (Some(a), Some(b)) = (self.ranges.get(i), other.ranges.get(j)) {
+            let start = T::max(*a.start(), *b.start());
+            let end = T::min(*a.end(), *b.end());
+
+            if start <= end {
+                ranges.push(start..=end);
+            }
+
+            if a.end() < b.end() {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        Self { ranges }
+    }
+
+    /// Every integer in `self` that isn't in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let mut others = other.ranges.iter().peekable();
+
+        for range in &self.ranges {
+            let mut start = Some(*range.start());
+
+            while let Some(cut) = others.peek() {
+                let Some(from) = start.filter(|from| from <= range.end()) else {
+                    break;
+                };
+
+                if cut.end() < &from {
+                    others.next();
+                    continue;
+                }
+
+                if cut.start() > range.end() {
+                    break;
+                }
+
+                if let Some(before) = cut.start().predecessor()
+                    && from <= before
+                {
+                    ranges.push(from..=before);
+                }
+
+                start = cut.end().successor();
+                if cut.end() > range.end() {
+                    break;
+                }
+                others.next();
+            }
+
+            if let Some(from) = start.filter(|from| from <= range.end()) {
+                ranges.push(from..=*range.end());
+            }
+        }
+
+        Self { ranges }
+    }
+
+    /// The ranges of integers within `bounds` that aren't in the set, in ascending order.
+    pub fn gaps(&self, bounds: RangeInclusive<T>) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        IntervalSet::from(bounds)
+            .difference(self)
+            .ranges
+            .into_iter()
+    }
+
+    /// The ranges in the set, in ascending order.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = RangeInclusive<T>> + '_ {
+        self.ranges.iter().cloned()
+    }
+
+    /// The ranges in the set, in parallel.
+    pub fn par_iter(&self) -> impl IndexedParallelIterator<Item = RangeInclusive<T>> + '_ {
+        self.ranges.par_iter().cloned()
+    }
+}
+
+impl<T: Endpoint> From<RangeInclusive<T>> for IntervalSet<T> {
+    fn from(range: RangeInclusive<T>) -> Self {
+        Self::from_sorted([range])
+    }
+}
+
+impl<T: Endpoint> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(iter: I) -> Self {
+        let mut ranges: Vec<_> = iter.into_iter().collect();
+        ranges.sort_unstable_by_key(|range| *range.start());
+        Self::from_sorted(ranges)
+    }
+}
+
+impl<T: Endpoint> FromParallelIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_par_iter<I: IntoParallelIterator<Item = RangeInclusive<T>>>(par_iter: I) -> Self {
+        let mut ranges: Vec<_> = par_iter.into_par_iter().collect();
+        ranges.par_sort_unstable_by_key(|range| *range.start());
+        Self::from_sorted(ranges)
+    }
+}
+
+impl<T: Endpoint> Extend<RangeInclusive<T>> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item = RangeInclusive<T>>>(&mut self, iter: I) {
+        *self = self.union(&iter.into_iter().collect());
+    }
+}
+
+impl<T: Endpoint> IntoIterator for IntervalSet<T> {
+    type Item = RangeInclusive<T>;
+    type IntoIter = std::vec::IntoIter<RangeInclusive<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.ranges.into_iter()
+    }
+}
+
+#[test]
+fn set_operations() {
+    let mut set: IntervalSet<u8> = [5..=8, 0..=2, 3..=3, 12..=15].into_iter().collect();
+    assert_eq!(set.iter().collect::<Vec<_>>(), [0..=3, 5..=8, 12..=15]);
+    assert_eq!(set.len(), 12);
+
+    set.insert(9..=10);
+    assert_eq!(set.iter().collect::<Vec<_>>(), [0..=3, 5..=10, 12..=15]);
+    set.insert(4..=11);
+    assert_eq!(set.iter().collect::<Vec<_>>(), [0..=15]);
+
+    set.remove(3..=5);
+    set.remove(15..=255);
+    assert_eq!(set.iter().collect::<Vec<_>>(), [0..=2, 6..=14]);
+    assert!(set.contains(6) && set.contains(14) && !set.contains(4) && !set.contains(15));
+    assert!(set.contains_range(7..=9) && !set.contains_range(2..=6));
+
+    let other: IntervalSet<u8> = [1..=7, 10..=10, 13..=20].into_par_iter().collect();
+    assert_eq!(
+        set.intersection(&other).iter().collect::<Vec<_>>(),
+        [1..=2, 6..=7, 10..=10, 13..=14]
+    );
+    assert_eq!(
+        set.difference(&other).iter().collect::<Vec<_>>(),
+        [0..=0, 8..=9, 11..=12]
+    );
+    assert_eq!(set.union(&other).iter().collect::<Vec<_>>(), [0..=20]);
+    assert_eq!(
+        set.gaps(0..=u8::MAX).collect::<Vec<_>>(),
+        [3..=5, 15..=u8::MAX]
+    );
+
+    let full = IntervalSet::from(u8::MIN..=u8::MAX);
+    assert_eq!(full.len(), 256);
+    assert!(full.gaps(0..=u8::MAX).next().is_none());
+}
@@ -10,7 +10,17 @@ pub const U32_MAX: usize =
 mod bool_ext;
 mod from_str_ext;
 
+pub mod automaton;
+pub mod cycle;
 pub mod grid;
+pub mod interval;
+pub mod math;
+pub mod md5search;
+pub mod ocr;
+pub mod parse;
+pub mod search;
+pub mod trace;
+pub mod tsp;
 
 pub use bool_ext::BoolExt;
 pub use from_str_ext::{TryFromStr, TryParse};
@@ -0,0 +1,207 @@
+//! Number theory that comes up often enough to share.
+
+/// The greatest common divisor of `a` and `b`.
+pub const fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    a
+}
+
+/// The least common multiple of `a` and `b`, or `None` if it doesn't fit in a `u64`.
+pub const fn lcm(a: u64, b: u64) -> Option<u64> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+/// Returns `(g, x, y)` such that `a * x + b * y == g == gcd(a, b)`.
+const fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_s, mut s) = (1, 0);
+    let (mut old_t, mut t) = (0, 1);
+
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_s, s) = (s, old_s - q * s);
+        (old_t, t) = (t, old_t - q * t);
+    }
+
+    (old_r, old_s, old_t)
+}
+
+/// Computes `base.pow(exp) % modulus` without overflowing.
+pub const fn mod_pow(base: u64, mut exp: u64, modulus: u64) -> u64 {
+    if modulus == 1 {
+        return 0;
+    }
+
+    let modulus = modulus as u128;
+    let mut base = base as u128 % modulus;
+    let mut result = 1;
+
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % modulus;
+        }
+
+        base = base * base % modulus;
+        exp >>= 1;
+    }
+
+    result as u64
+}
+
+/// The multiplicative inverse of `a` modulo `modulus`, if `a` and `modulus` are coprime (and `modulus` isn't 0).
+pub const fn mod_inv(a: u64, modulus: u64) -> Option<u64> {
+    if modulus == 0 {
+        return None;
+    }
+
+    let (g, x, _) = extended_gcd(a as i128, modulus as i128);
+
+    if g != 1 {
+        return None;
+    }
+
+    Some(x.rem_euclid(modulus as i128) as u64)
+}
+
+/// Solve a system of congruences `x ≡ residue (mod modulus)` with the (generalized) Chinese remainder theorem.
+///
+/// The moduli don't need to be pairwise coprime. Returns `Some((x, m))` where every solution is congruent to `x`
+/// modulo `m` (the least common multiple of the moduli), or `None` if the congruences are inconsistent or `m`
+/// doesn't fit in a `u64`.
+pub fn crt<I: IntoIterator<Item = (u64, u64)>>(congruences: I) -> Option<(u64, u64)> {
+    congruences
+        .into_iter()
+        .try_fold((0u64, 1u64), |(x, m), (residue, modulus)| {
+            if modulus == 0 {
+                return None;
+            }
+
+            let residue = residue % modulus;
+
+            // x + m * k ≡ residue (mod modulus) has a solution for k iff gcd(m, modulus) divides the difference.
+            let (g, p, _) = extended_gcd(i128::from(m), i128::from(modulus));
+            let diff = i128::from(residue) - i128::from(x);
+            if diff % g != 0 {
+                return None;
+            }
+
+            // `p` is the inverse of `m / g` modulo `modulus / g`, so multiplying by it solves for `k`.
+            let reduced = modulus / g as u64;
+            let diff = (diff / g).rem_euclid(i128::from(reduced)) as u128;
+            let p = p.rem_euclid(i128::from(reduced)) as u128;
+            let k = diff * p % u128::from(reduced);
+
+            let lcm = u128::from(m / g as u64) * u128::from(modulus);
+            let next = (u128::from(x) + u128::from(m) * k) % lcm;
+
+            Some((u64::try_from(next).ok()?, u64::try_from(lcm).ok()?))
+        })
+}
+
+/// The sum of the divisors of every integer in `0..=limit`, so `divisor_sums(limit)[n]` is σ(n).
+pub fn divisor_sums(limit: usize) -> Vec<u64> {
+    divisor_sums_bounded(limit, usize::MAX)
+}
+
+/// The sum of the divisors `d` of every integer `n` in `0..=limit`, counting only those with `n / d <= max_quotient`.
+pub fn divisor_sums_bounded(limit: usize, max_quotient: usize) -> Vec<u64> {
+    let mut sums = vec![0; limit + 1];
+
+    for d in 1..=limit {
+        let last = d.saturating_mul(max_quotient).min(limit);
+
+        for n in (d..=last).step_by(d) {
+            sums[n] += d as u64;
+        }
+    }
+
+    sums
+}
+
+/// The (1-indexed) survivor of a circle of `n` people where, starting from the first, each person eliminates
+/// the person to their left.
+pub const fn josephus(n: usize) -> usize {
+    let prev_power_of_two = 1 << n.ilog2();
+    let remainder = n - prev_power_of_two;
+    2 * remainder + 1
+}
+
+/// The (1-indexed) survivor of a circle of `n` people where, starting from the first, each person eliminates
+/// the person directly across the circle from them (the nearer one to their left, if there are two).
+pub const fn josephus_across(n: usize) -> usize {
+    let prev_power_of_three = 3_usize.pow(n.ilog(3));
+    let remainder = n - prev_power_of_three;
+
+    if remainder == 0 {
+        prev_power_of_three
+    } else if remainder <= prev_power_of_three {
+        remainder
+    } else {
+        2 * remainder - prev_power_of_three
+    }
+}
+
+/// The (1-indexed) survivor of a circle of `n` people where every `k`th person is eliminated, using the
+/// recurrence `J(1) = 0, J(i) = (J(i - 1) + k) mod i`.
+pub fn josephus_every(n: usize, k: usize) -> usize {
+    (2..=n).fold(0, |survivor, i| (survivor + k) % i) + 1
+}
+
+#[test]
+fn modular_arithmetic() {
+    assert_eq!(gcd(12, 18), 6);
+    assert_eq!(lcm(4, 6), Some(12));
+    assert_eq!(lcm(u64::MAX, 2), None);
+
+    assert_eq!(mod_pow(4, 13, 497), 445);
+    assert_eq!(mod_pow(u64::MAX, u64::MAX, u64::MAX - 1), 1);
+    assert_eq!(mod_inv(3, 11), Some(4));
+    assert_eq!(mod_inv(6, 9), None);
+    assert_eq!(mod_inv(1, 0), None);
+
+    assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+    assert_eq!(crt([(3, 4), (1, 6)]), Some((7, 12)));
+    assert_eq!(crt([(0, 4), (1, 6)]), None);
+    assert_eq!(crt([]), Some((0, 1)));
+}
+
+#[test]
+fn sieves() {
+    assert_eq!(divisor_sums(9), [0, 1, 3, 4, 7, 6, 12, 8, 15, 13]);
+    assert_eq!(divisor_sums_bounded(9, 2), [0, 1, 3, 3, 6, 5, 9, 7, 12, 9]);
+}
+
+#[test]
+fn josephus_problems() {
+    for n in 1..100 {
+        assert_eq!(josephus(n), josephus_every(n, 2), "{n} people");
+    }
+
+    assert_eq!(josephus(5), 3);
+    assert_eq!(josephus_across(5), 2);
+    assert_eq!(josephus_every(7, 3), 4);
+}
+
+#[test]
+fn josephus_across_matches_simulation() {
+    use std::collections::VecDeque;
+
+    for n in 1..=500 {
+        // The person taking their turn is always at the front.
+        let mut circle: VecDeque<usize> = (1..=n).collect();
+        while circle.len() > 1 {
+            circle.remove(circle.len() / 2);
+            circle.rotate_left(1);
+        }
+
+        assert_eq!(josephus_across(n), circle[0], "{n} people");
+    }
+}
@@ -0,0 +1,640 @@
+//! Brute-force searches over the MD5 digests of `prefix + decimal index`, which several puzzles are built on.
+//!
+//! A [`Prefix`] hashes its prefix once and reuses that state for every candidate. Indices are formatted by bumping
+//! their decimal digits in place rather than being reformatted each time, candidates are hashed [`LANES`] at a time
+//! in SIMD lanes, and batches of candidates are spread across every core while still being handed back in index
+//! order.
+
+use std::array;
+use std::collections::VecDeque;
+use std::iter::FusedIterator;
+
+use rayon::prelude::*;
+use wide::u32x8;
+
+/// An MD5 digest.
+pub type Digest = [u8; 16];
+
+/// The number of candidates hashed at once.
+pub const LANES: usize = 8;
+
+/// The last index [`Prefix::matches`] tries. Puzzle answers fit in a `u32`, so a search that gets this far without
+/// a match isn't going to find one.
+pub const LAST_INDEX: u64 = u32::MAX as u64;
+
+/// The number of (unstretched) candidates each parallel task hashes at a time.
+const TASK_LEN: u64 = 1 << 12;
+
+const INIT: [u32; 4] = [0x6745_2301, 0xefcd_ab89, 0x98ba_dcfe, 0x1032_5476];
+
+const SHIFTS: [u32; 64] = [
+    7, 12, 17, 22, 7, 12, 17, 22, 7, 12, 17, 22, 7, 12, 17, 22, //
+    5, 9, 14, 20, 5, 9, 14, 20, 5, 9, 14, 20, 5, 9, 14, 20, //
+    4, 11, 16, 23, 4, 11, 16, 23, 4, 11, 16, 23, 4, 11, 16, 23, //
+    6, 10, 15, 21, 6, 10, 15, 21, 6, 10, 15, 21, 6, 10, 15, 21,
+];
+
+const CONSTANTS: [u32; 64] = [
+    0xd76a_a478,
+    0xe8c7_b756,
+    0x2420_70db,
+    0xc1bd_ceee,
+    0xf57c_0faf,
+    0x4787_c62a,
+    0xa830_4613,
+    0xfd46_9501,
+    0x6980_98d8,
+    0x8b44_f7af,
+    0xffff_5bb1,
+    0x895c_d7be,
+    0x6b90_1122,
+    0xfd98_7193,
+    0xa679_438e,
+    0x49b4_0821,
+    0xf61e_2562,
+    0xc040_b340,
+    0x265e_5a51,
+    0xe9b6_c7aa,
+    0xd62f_105d,
+    0x0244_1453,
+    0xd8a1_e681,
+    0xe7d3_fbc8,
+    0x21e1_cde6,
+    0xc337_07d6,
+    0xf4d5_0d87,
+    0x455a_14ed,
+    0xa9e3_e905,
+    0xfcef_a3f8,
+    0x676f_02d9,
+    0x8d2a_4c8a,
+    0xfffa_3942,
+    0x8771_f681,
+    0x6d9d_6122,
+    0xfde5_380c,
+    0xa4be_ea44,
+    0x4bde_cfa9,
+    0xf6bb_4b60,
+    0xbebf_bc70,
+    0x289b_7ec6,
+    0xeaa1_27fa,
+    0xd4ef_3085,
+    0x0488_1d05,
+    0xd9d4_d039,
+    0xe6db_99e5,
+    0x1fa2_7cf8,
+    0xc4ac_5665,
+    0xf429_2244,
+    0x432a_ff97,
+    0xab94_23a7,
+    0xfc93_a039,
+    0x655b_59c3,
+    0x8f0c_cc92,
+    0xffef_f47d,
+    0x8584_5dd1,
+    0x6fa8_7e4f,
+    0xfe2c_e6e0,
+    0xa301_4314,
+    0x4e08_11a1,
+    0xf753_7e82,
+    0xbd3a_f235,
+    0x2ad7_d2bb,
+    0xeb86_d391,
+];
+
+/// A 32-bit word, or several of them processed in lockstep.
+trait Word: Copy {
+    fn splat(value: u32) -> Self;
+    fn add(self, rhs: Self) -> Self;
+    fn mul(self, rhs: u32) -> Self;
+    fn and(self, rhs: Self) -> Self;
+    fn or(self, rhs: Self) -> Self;
+    fn xor(self, rhs: Self) -> Self;
+    fn not(self) -> Self;
+    fn shl(self, n: u32) -> Self;
+    fn shr(self, n: u32) -> Self;
+
+    #[inline(always)]
+    fn rotate_left(self, n: u32) -> Self {
+        self.shl(n).or(self.shr(32 - n))
+    }
+}
+
+impl Word for u32 {
+    #[inline(always)]
+    fn splat(value: u32) -> Self {
+        value
+    }
+
+    #[inline(always)]
+    fn add(self, rhs: Self) -> Self {
+        self.wrapping_add(rhs)
+    }
+
+    #[inline(always)]
+    fn mul(self, rhs: u32) -> Self {
+        self.wrapping_mul(rhs)
+    }
+
+    #[inline(always)]
+    fn and(self, rhs: Self) -> Self {
+        self & rhs
+    }
+
+    #[inline(always)]
+    fn or(self, rhs: Self) -> Self {
+        self | rhs
+    }
+
+    #[inline(always)]
+    fn xor(self, rhs: Self) -> Self {
+        self ^ rhs
+    }
+
+    #[inline(always)]
+    fn not(self) -> Self {
+        !self
+    }
+
+    #[inline(always)]
+    fn shl(self, n: u32) -> Self {
+        self << n
+    }
+
+    #[inline(always)]
+    fn shr(self, n: u32) -> Self {
+        self >> n
+    }
+}
+
+impl Word for u32x8 {
+    #[inline(always)]
+    fn splat(value: u32) -> Self {
+        u32x8::splat(value)
+    }
+
+    #[inline(always)]
+    fn add(self, rhs: Self) -> Self {
+        self + rhs
+    }
+
+    #[inline(always)]
+    fn mul(self, rhs: u32) -> Self {
+        self * rhs
+    }
+
+    #[inline(always)]
+    fn and(self, rhs: Self) -> Self {
+        self & rhs
+    }
+
+    #[inline(always)]
+    fn or(self, rhs: Self) -> Self {
+        self | rhs
+    }
+
+    #[inline(always)]
+    fn xor(self, rhs: Self) -> Self {
+        self ^ rhs
+    }
+
+    #[inline(always)]
+    fn not(self) -> Self {
+        !self
+    }
+
+    #[inline(always)]
+    fn shl(self, n: u32) -> Self {
+        self << n
+    }
+
+    #[inline(always)]
+    fn shr(self, n: u32) -> Self {
+        self >> n
+    }
+}
+
+/// Run the MD5 compression function over a single 64-byte block.
+#[inline(always)]
+fn compress<W: Word>(state: [W; 4], block: &[W; 16]) -> [W; 4] {
+    let [mut a, mut b, mut c, mut d] = state;
+
+    for i in 0..64 {
+        let (f, g) = match i / 16 {
+            0 => (b.and(c).or(b.not().and(d)), i),
+            1 => (d.and(b).or(d.not().and(c)), (5 * i + 1) % 16),
+            2 => (b.xor(c).xor(d), (3 * i + 5) % 16),
+            _ => (c.xor(b.or(d.not())), (7 * i) % 16),
+        };
+
+        let f = f.add(a).add(W::splat(CONSTANTS[i])).add(block[g]);
+        (a, d, c) = (d, c, b);
+        b = b.add(f.rotate_left(SHIFTS[i]));
+    }
+
+    [
+        state[0].add(a),
+        state[1].add(b),
+        state[2].add(c),
+        state[3].add(d),
+    ]
+}
+
+/// Hash the lowercase hex representation of a digest, as in key stretching.
+#[inline(always)]
+fn rehash<W: Word>(digest: [W; 4]) -> [W; 4] {
+    // Spread the nibbles of two bytes of a little-endian word into four bytes, high nibble first, then map each of
+    // them to its ASCII hex digit.
+    let hex = |w: W| {
+        let nibbles = w
+            .shr(4)
+            .and(W::splat(0xf))
+            .or(w.and(W::splat(0xf)).shl(8))
+            .or(w.shr(12).and(W::splat(0xf)).shl(16))
+            .or(w.shr(8).and(W::splat(0xf)).shl(24));
+        let letters = nibbles
+            .add(W::splat(0x0606_0606))
+            .shr(4)
+            .and(W::splat(0x0101_0101));
+
+        nibbles
+            .add(W::splat(0x3030_3030))
+            .add(letters.mul(u32::from(b'a' - b'0' - 10)))
+    };
+
+    let mut block = [W::splat(0); 16];
+    for (i, &word) in digest.iter().enumerate() {
+        block[2 * i] = hex(word);
+        block[2 * i + 1] = hex(word.shr(16));
+    }
+    block[8] = W::splat(0x80);
+    block[14] = W::splat(32 * 8);
+
+    compress(INIT.map(W::splat), &block)
+}
+
+#[inline]
+fn to_digest(state: [u32; 4]) -> Digest {
+    let mut digest = [0; 16];
+    for (chunk, word) in digest.chunks_exact_mut(4).zip(state) {
+        chunk.copy_from_slice(&word.to_le_bytes());
+    }
+
+    digest
+}
+
+#[inline]
+fn to_words(block: &[u8]) -> [u32; 16] {
+    array::from_fn(|i| u32::from_le_bytes(block[4 * i..4 * i + 4].try_into().unwrap()))
+}
+
+/// A non-negative integer stored as ASCII decimal digits, so that counting up doesn't need to reformat it.
+#[derive(Debug, Clone)]
+struct Decimal {
+    digits: [u8; 20],
+    start: usize,
+}
+
+impl Decimal {
+    fn new(mut n: u64) -> Self {
+        let mut digits = [b'0'; 20];
+        let mut start = digits.len() - 1;
+
+        loop {
+            digits[start] = b'0' + (n % 10) as u8;
+            n /= 10;
+
+            if n == 0 {
+                break;
+            }
+            start -= 1;
+        }
+
+        Self { digits, start }
+    }
+
+    #[inline]
+    fn as_bytes(&self) -> &[u8] {
+        &self.digits[self.start..]
+    }
+
+    #[inline]
+    fn increment(&mut self) {
+        for (i, digit) in self.digits.iter_mut().enumerate().rev() {
+            if *digit == b'9' {
+                *digit = b'0';
+            } else {
+                *digit += 1;
+                self.start = self.start.min(i);
+                return;
+            }
+        }
+    }
+}
+
+/// The MD5 state after hashing a prefix, which candidates are appended to.
+#[derive(Debug, Clone)]
+pub struct Prefix {
+    state: [u32; 4],
+    /// The bytes of the prefix after its last complete block.
+    tail: Vec<u8>,
+    /// The length of the prefix, in bytes.
+    len: u64,
+    stretch: usize,
+}
+
+impl Prefix {
+    pub fn new<B: AsRef<[u8]>>(prefix: B) -> Self {
+        let prefix = prefix.as_ref();
+        let mut blocks = prefix.chunks_exact(64);
+        let state = blocks
+            .by_ref()
+            .fold(INIT, |state, block| compress(state, &to_words(block)));
+
+        Self {
+            state,
+            tail: blocks.remainder().to_vec(),
+            len: prefix.len() as u64,
+            stretch: 0,
+        }
+    }
+
+    /// Rehash every digest `rounds` more times, each time hashing the lowercase hex representation of the previous
+    /// digest.
+    pub fn stretched(self, rounds: usize) -> Self {
+        Self {
+            stretch: rounds,
+            ..self
+        }
+    }
+
+    /// Pad the message `tail + suffix` into `buf`, returning the number of blocks it takes up.
+    fn pad(&self, suffix: &[u8], buf: &mut Vec<u8>) -> usize {
+        buf.clear();
+        buf.extend_from_slice(&self.tail);
+        buf.extend_from_slice(suffix);
+        buf.push(0x80);
+
+        let blocks = (buf.len() + 8).div_ceil(64);
+        buf.resize(blocks * 64 - 8, 0);
+
+        let bits = (self.len + suffix.len() as u64) * 8;
+        buf.extend_from_slice(&bits.to_le_bytes());
+
+        blocks
+    }
+
+    /// The digest of the prefix followed by `suffix`.
+    pub fn digest<B: AsRef<[u8]>>(&self, suffix: B) -> Digest {
+        let mut buf = Vec::with_capacity(128);
+        self.pad(suffix.as_ref(), &mut buf);
+
+        let state = buf
+            .chunks_exact(64)
+            .fold(self.state, |state, block| compress(state, &to_words(block)));
+
+        to_digest((0..self.stretch).fold(state, |state, _| rehash(state)))
+    }
+
+    /// The digest of the prefix followed by `index` in decimal.
+    pub fn digest_index(&self, index: u64) -> Digest {
+        self.digest(Decimal::new(index).as_bytes())
+    }
+
+    /// Hash the next [`LANES`] indices counted by `counter`, advancing it past them.
+    fn digest_lanes(&self, counter: &mut Decimal, buf: &mut Vec<u8>) -> [Digest; LANES] {
+        // A prefix tail is under 64 bytes, and an index is at most 20 digits, so padding never needs three blocks.
+        let mut blocks = [[0; 32]; LANES];
+        let mut num_blocks = [0; LANES];
+
+        for (lane_blocks, lane_len) in blocks.iter_mut().zip(&mut num_blocks) {
+            *lane_len = self.pad(counter.as_bytes(), buf);
+            for (dst, src) in lane_blocks.iter_mut().zip(buf.chunks_exact(4)) {
+                *dst = u32::from_le_bytes(src.try_into().unwrap());
+            }
+
+            counter.increment();
+        }
+
+        let mut state = self.state.map(u32x8::splat);
+        for block in 0..num_blocks.iter().copied().max().unwrap_or_default() {
+            let words: [u32x8; 16] =
+                array::from_fn(|i| u32x8::new(array::from_fn(|lane| blocks[lane][16 * block + i])));
+            let next = compress(state, &words);
+
+            // Lanes whose message has already ended keep the state they finished with.
+            let active = u32x8::new(num_blocks.map(|len| if len > block { u32::MAX } else { 0 }));
+            state = array::from_fn(|i| active.blend(next[i], state[i]));
+        }
+
+        for _ in 0..self.stretch {
+            state = rehash(state);
+        }
+
+        let lanes = state.map(u32x8::to_array);
+        array::from_fn(|lane| to_digest(lanes.map(|words| words[lane])))
+    }
+
+    /// The number of consecutive indices each parallel task should hash, taking stretching into account so that
+    /// a batch doesn't do much more work than necessary.
+    fn task_len(&self) -> u64 {
+        (TASK_LEN / (self.stretch as u64 + 1)).next_multiple_of(LANES as u64)
+    }
+
+    /// Hash `len` consecutive indices from `start` (a multiple of [`LANES`]), in order.
+    fn digest_range(&self, start: u64, len: u64) -> impl Iterator<Item = (u64, Digest)> + '_ {
+        let mut counter = Decimal::new(start);
+        let mut buf = Vec::with_capacity(128);
+
+        (start..start + len).step_by(LANES).flat_map(move |first| {
+            let digests = self.digest_lanes(&mut counter, &mut buf);
+            (first..).zip(digests)
+        })
+    }
+
+    /// Hash the next batch of indices from `start` in parallel, keeping only what `f` returns, in index order.
+    fn batch<T, F>(&self, start: u64, f: F) -> (Vec<T>, u64)
+    where
+        T: Send,
+        F: Fn(u64, Digest) -> Option<T> + Sync,
+    {
+        let task_len = self.task_len();
+        let tasks = 4 * rayon::current_num_threads() as u64;
+
+        let found = (0..tasks)
+            .into_par_iter()
+            .flat_map_iter(|task| {
+                self.digest_range(start + task * task_len, task_len)
+                    .filter_map(|(index, digest)| f(index, digest))
+            })
+            .collect();
+
+        (found, start + tasks * task_len)
+    }
+
+    /// Every index up to [`LAST_INDEX`] whose digest satisfies `predicate`, in ascending order, along with its
+    /// digest.
+    pub fn matches<F>(&self, predicate: F) -> Matches<'_, F>
+    where
+        F: Fn(&Digest) -> bool + Sync,
+    {
+        Matches {
+            prefix: self,
+            predicate,
+            next_index: 0,
+            last_index: LAST_INDEX,
+            found: VecDeque::new(),
+        }
+    }
+
+    /// A cache of `map` applied to the digest of each index, for puzzles that look ahead of the current index.
+    pub fn window<T, F>(&self, map: F) -> Window<'_, T, F>
+    where
+        T: Send,
+        F: Fn(Digest) -> T + Sync,
+    {
+        Window {
+            prefix: self,
+            map,
+            start: 0,
+            values: VecDeque::new(),
+        }
+    }
+}
+
+/// Iterator over `(index, digest)` pairs whose digests satisfy a predicate, returned by [`Prefix::matches`].
+pub struct Matches<'p, F> {
+    prefix: &'p Prefix,
+    predicate: F,
+    next_index: u64,
+    last_index: u64,
+    found: VecDeque<(u64, Digest)>,
+}
+
+impl<F: Fn(&Digest) -> bool + Sync> Iterator for Matches<'_, F> {
+    type Item = (u64, Digest);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(found) = self.found.pop_front() {
+                return Some(found);
+            }
+
+            if self.next_index > self.last_index {
+                return None;
+            }
+
+            let (predicate, last_index) = (&self.predicate, self.last_index);
+            let (found, next_index) = self.prefix.batch(self.next_index, |index, digest| {
+                (index <= last_index && predicate(&digest)).then_some((index, digest))
+            });
+
+            self.found.extend(found);
+            self.next_index = next_index;
+        }
+    }
+}
+
+impl<F: Fn(&Digest) -> bool + Sync> FusedIterator for Matches<'_, F> {}
+
+/// A sliding window over values derived from the digests of consecutive indices, returned by [`Prefix::window`].
+///
+/// Values are computed in parallel batches as the window is extended, and are kept until the window slides past
+/// them.
+pub struct Window<'p, T, F> {
+    prefix: &'p Prefix,
+    map: F,
+    start: u64,
+    values: VecDeque<T>,
+}
+
+impl<T: Send, F: Fn(Digest) -> T + Sync> Window<'_, T, F> {
+    /// The value for `index`, which must not be before the start of the window.
+    pub fn get(&mut self, index: u64) -> &T {
+        assert!(
+            index >= self.start,
+            "index {index} has already left the window, which starts at {}",
+            self.start
+        );
+
+        while index - self.start >= self.values.len() as u64 {
+            let map = &self.map;
+            let end = self.start + self.values.len() as u64;
+            let (values, _) = self.prefix.batch(end, |_, digest| Some(map(digest)));
+            self.values.extend(values);
+        }
+
+        &self.values[(index - self.start) as usize]
+    }
+
+    /// Forget every value before `index`.
+    pub fn slide_to(&mut self, index: u64) {
+        let drop = index
+            .saturating_sub(self.start)
+            .min(self.values.len() as u64);
+        self.values.drain(..drop as usize);
+        self.start = index.max(self.start);
+    }
+}
+
+#[test]
+fn known_digests() {
+    fn hex(digest: Digest) -> String {
+        digest.iter().map(|byte| format!("{byte:02x}")).collect()
+    }
+
+    assert_eq!(
+        hex(Prefix::new("").digest("")),
+        "d41d8cd98f00b204e9800998ecf8427e"
+    );
+    assert_eq!(
+        hex(Prefix::new("a").digest("bc")),
+        "900150983cd24fb0d6963f7d28e17f72"
+    );
+    assert_eq!(
+        hex(Prefix::new("The quick brown fox jumps over ").digest("the lazy dog")),
+        "9e107d9d372bb6826bd81d3542a419d6"
+    );
+    assert_eq!(
+        hex(Prefix::new("1234567890".repeat(8)).digest("")),
+        "57edf4a22be3c955ac49da2e2107b67a"
+    );
+    assert_eq!(
+        hex(Prefix::new("abc").stretched(2016).digest_index(0)),
+        "a107ff634856bb300138cac6568c0f24"
+    );
+
+    // The lanes agree with one-at-a-time hashing across a change in the number of digits and the number of blocks.
+    for prefix in [
+        Prefix::new("abc"),
+        Prefix::new("x".repeat(53)),
+        Prefix::new("abc").stretched(3),
+    ] {
+        let lanes: Vec<_> = prefix.digest_range(96, 16).collect();
+        let single: Vec<_> = (96..112).map(|i| (i, prefix.digest_index(i))).collect();
+        assert_eq!(lanes, single);
+    }
+}
+
+#[test]
+fn searches() {
+    let prefix = Prefix::new("abcdef");
+    let (index, digest) = prefix
+        .matches(|digest| digest[0] == 0 && digest[1] == 0 && digest[2] < 0x10)
+        .next()
+        .unwrap();
+    assert_eq!((index, &digest[..3]), (609_043, &[0, 0, 1][..]));
+
+    // A search with nothing to find gives up at the end of its range.
+    let mut hopeless = Matches {
+        last_index: 609_042,
+        ..prefix.matches(|digest| digest[0] == 0 && digest[1] == 0 && digest[2] < 0x10)
+    };
+    assert_eq!(hopeless.next(), None);
+    assert_eq!(hopeless.next(), None);
+
+    let prefix = Prefix::new("abc");
+    let mut window = prefix.window(|digest| digest[0]);
+    assert_eq!(*window.get(39), prefix.digest_index(39)[0]);
+    window.slide_to(20);
+    assert_eq!(*window.get(20), prefix.digest_index(20)[0]);
+}
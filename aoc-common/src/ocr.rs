@@ -0,0 +1,339 @@
+//! Reading the block-letter messages that some puzzles draw instead of printing an answer.
+//!
+//! Advent of Code uses two fonts: a small one that's 6 pixels high (usually drawn in 5-pixel-wide cells), and a
+//! large one that's 10 pixels high (usually drawn in 8-pixel-wide cells). Glyphs are matched on their tight
+//! bounding boxes, so the font is detected from the height of the message and letters can be spaced however they
+//! like, including not at all.
+
+use std::collections::HashMap;
+use std::fmt::{self, Display, Formatter};
+use std::sync::LazyLock;
+
+use eyre::{Result, bail, eyre};
+use fnv::FnvBuildHasher;
+
+/// One of the fonts used to draw messages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Font {
+    /// The 6-pixel-high font.
+    Small,
+    /// The 10-pixel-high font.
+    Large,
+}
+
+impl Font {
+    /// The height of every glyph in this font, in pixels.
+    pub const fn height(self) -> usize {
+        match self {
+            Font::Small => 6,
+            Font::Large => 10,
+        }
+    }
+
+    /// Returns the font whose glyphs are `height` pixels high, if there is one.
+    pub const fn with_height(height: usize) -> Option<Self> {
+        match height {
+            6 => Some(Font::Small),
+            10 => Some(Font::Large),
+            _ => None,
+        }
+    }
+
+    fn glyphs(self) -> &'static HashMap<Columns, char, FnvBuildHasher> {
+        static SMALL: LazyLock<HashMap<Columns, char, FnvBuildHasher>> =
+            LazyLock::new(|| load(&SMALL_GLYPHS));
+        static LARGE: LazyLock<HashMap<Columns, char, FnvBuildHasher>> =
+            LazyLock::new(|| load(&LARGE_GLYPHS));
+
+        match self {
+            Font::Small => &SMALL,
+            Font::Large => &LARGE,
+        }
+    }
+}
+
+impl Display for Font {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Font::Small => f.write_str("small"),
+            Font::Large => f.write_str("large"),
+        }
+    }
+}
+
+/// Read the message drawn in `text`, where `#` is a lit pixel and anything else (usually `.` or ` `) is unlit.
+pub fn read(text: &str) -> Result<String> {
+    read_grid(text.lines().map(|line| line.chars().map(|c| c == '#')))
+}
+
+/// Read the message drawn in a grid of pixels, given as rows from top to bottom.
+pub fn read_grid<R, C>(rows: R) -> Result<String>
+where
+    R: IntoIterator<Item = C>,
+    C: IntoIterator<Item = bool>,
+{
+    let rows: Vec<Vec<bool>> = rows
+        .into_iter()
+        .map(|row| row.into_iter().collect())
+        .collect();
+
+    let Some(top) = rows.iter().position(|row| row.contains(&true)) else {
+        bail!("there's no message to read, no pixels are lit");
+    };
+    let bottom = rows
+        .iter()
+        .rposition(|row| row.contains(&true))
+        .unwrap_or(top);
+
+    let height = bottom - top + 1;
+    let font = Font::with_height(height).ok_or_else(|| {
+        eyre!("the message is {height} pixels high, which doesn't match any known font (6 or 10 pixels)")
+    })?;
+
+    let width = rows.iter().map(Vec::len).max().unwrap_or_default();
+    let columns: Columns = (0..width)
+        .map(|x| {
+            rows[top..=bottom]
+                .iter()
+                .enumerate()
+                .filter(|(_, row)| row.get(x).copied().unwrap_or_default())
+                .fold(0, |column, (y, _)| column | (1 << y))
+        })
+        .collect();
+
+    let mut message = String::new();
+    let mut x = 0;
+
+    while x < columns.len() {
+        if columns[x] == 0 {
+            x += 1;
+            continue;
+        }
+
+        let end = columns[x..]
+            .iter()
+            .position(|&column| column == 0)
+            .map_or(columns.len(), |len| x + len);
+
+        let letters = split(font, &columns[x..end]).ok_or_else(|| {
+            eyre!(
+                "couldn't read the {font} font glyph at columns {x}..{end}:\n\n{}\n",
+                Render(&columns[x..end], height)
+            )
+        })?;
+        message.push_str(&letters);
+
+        x = end;
+    }
+
+    Ok(message)
+}
+
+/// Glyphs stored as bitmasks of their columns, left to right, with the top row in the lowest bit.
+type Columns = Vec<u16>;
+
+/// Split a run of non-blank columns into letters. Usually a run is a single glyph, but glyphs that fill their whole
+/// cell (like `Y` in the small font) can touch the next one.
+fn split(font: Font, columns: &[u16]) -> Option<String> {
+    if columns.is_empty() {
+        return Some(String::new());
+    }
+
+    if let Some(&letter) = font.glyphs().get(columns) {
+        return Some(letter.to_string());
+    }
+
+    (1..columns.len()).rev().find_map(|len| {
+        let &letter = font.glyphs().get(&columns[..len])?;
+        let rest = split(font, &columns[len..])?;
+
+        Some(format!("{letter}{rest}"))
+    })
+}
+
+fn load(glyphs: &[(char, &str)]) -> HashMap<Columns, char, FnvBuildHasher> {
+    let mut map = HashMap::default();
+
+    for &(letter, art) in glyphs {
+        let rows: Vec<&str> = art.lines().collect();
+        let width = rows.iter().map(|row| row.len()).max().unwrap_or_default();
+
+        let columns: Columns = (0..width)
+            .map(|x| {
+                rows.iter()
+                    .enumerate()
+                    .filter(|(_, row)| row.as_bytes().get(x) == Some(&b'#'))
+                    .fold(0, |column, (y, _)| column | (1 << y))
+            })
+            .skip_while(|&column| column == 0)
+            .collect();
+        let trailing = columns
+            .iter()
+            .rev()
+            .take_while(|&&column| column == 0)
+            .count();
+
+        assert!(
+            map.insert(columns[..columns.len() - trailing].to_vec(), letter)
+                .is_none(),
+            "Overwrote previous value"
+        );
+    }
+
+    map
+}
+
+struct Render<'c>(&'c [u16], usize);
+
+impl Display for Render<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let Render(columns, height) = *self;
+
+        for y in 0..height {
+            if y > 0 {
+                writeln!(f)?;
+            }
+
+            for column in columns {
+                f.write_str(if column & (1 << y) == 0 { "." } else { "#" })?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+const SMALL_GLYPHS: [(char, &str); 19] = [
+    ('A', ".##.\n#..#\n#..#\n####\n#..#\n#..#"),
+    ('B', "###.\n#..#\n###.\n#..#\n#..#\n###."),
+    ('C', ".##.\n#..#\n#...\n#...\n#..#\n.##."),
+    ('D', "###.\n#..#\n#..#\n#..#\n#..#\n###."),
+    ('E', "####\n#...\n###.\n#...\n#...\n####"),
+    ('F', "####\n#...\n###.\n#...\n#...\n#..."),
+    ('G', ".##.\n#..#\n#...\n#.##\n#..#\n.###"),
+    ('H', "#..#\n#..#\n####\n#..#\n#..#\n#..#"),
+    ('I', "###\n.#.\n.#.\n.#.\n.#.\n###"),
+    ('J', "..##\n...#\n...#\n...#\n#..#\n.##."),
+    ('K', "#..#\n#.#.\n##..\n#.#.\n#.#.\n#..#"),
+    ('L', "#...\n#...\n#...\n#...\n#...\n####"),
+    ('O', ".##.\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('P', "###.\n#..#\n#..#\n###.\n#...\n#..."),
+    ('R', "###.\n#..#\n#..#\n###.\n#.#.\n#..#"),
+    ('S', ".###\n#...\n#...\n.##.\n...#\n###."),
+    ('U', "#..#\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('Y', "#...#\n#...#\n.#.#.\n..#..\n..#..\n..#.."),
+    ('Z', "####\n...#\n..#.\n.#..\n#...\n####"),
+];
+
+const LARGE_GLYPHS: [(char, &str); 15] = [
+    (
+        'A',
+        "..##..\n.#..#.\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#",
+    ),
+    (
+        'B',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#....#\n#....#\n#....#\n#....#\n#####.",
+    ),
+    (
+        'C',
+        ".####.\n#....#\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#....#\n.####.",
+    ),
+    (
+        'E',
+        "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n######",
+    ),
+    (
+        'F',
+        "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n#.....",
+    ),
+    (
+        'G',
+        ".####.\n#....#\n#.....\n#.....\n#.....\n#..###\n#....#\n#....#\n#...##\n.###.#",
+    ),
+    (
+        'H',
+        "#....#\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#\n#....#",
+    ),
+    (
+        'J',
+        "...###\n....#.\n....#.\n....#.\n....#.\n....#.\n....#.\n#...#.\n#...#.\n.###..",
+    ),
+    (
+        'K',
+        "#....#\n#...#.\n#..#..\n#.#...\n##....\n##....\n#.#...\n#..#..\n#...#.\n#....#",
+    ),
+    (
+        'L',
+        "#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n######",
+    ),
+    (
+        'N',
+        "#....#\n##...#\n##...#\n#.#..#\n#.#..#\n#..#.#\n#..#.#\n#...##\n#...##\n#....#",
+    ),
+    (
+        'P',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#.....\n#.....\n#.....\n#.....\n#.....",
+    ),
+    (
+        'R',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#..#..\n#...#.\n#...#.\n#....#\n#....#",
+    ),
+    (
+        'X',
+        "#....#\n#....#\n.#..#.\n.#..#.\n..##..\n..##..\n.#..#.\n.#..#.\n#....#\n#....#",
+    ),
+    (
+        'Z',
+        "######\n.....#\n.....#\n....#.\n...#..\n..#...\n.#....\n#.....\n#.....\n######",
+    ),
+];
+
+#[test]
+fn round_trip() {
+    fn draw(glyphs: &[(char, &str)], pitch: usize) -> (String, String) {
+        let word = glyphs.iter().map(|&(letter, _)| letter).collect();
+        let arts: Vec<Vec<&str>> = glyphs
+            .iter()
+            .map(|(_, art)| art.lines().collect())
+            .collect();
+
+        let text = (0..arts[0].len())
+            .map(|y| {
+                arts.iter()
+                    .map(|art| format!("{:.<pitch$}", art[y]))
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        (word, text)
+    }
+
+    for (glyphs, pitch) in [(&SMALL_GLYPHS[..], 5), (&LARGE_GLYPHS[..], 8)] {
+        let (word, text) = draw(glyphs, pitch);
+        assert_eq!(read(&text).unwrap(), word);
+    }
+
+    // `Y` fills its whole cell, so it touches the letter after it.
+    let (word, text) = draw(&[SMALL_GLYPHS[17], SMALL_GLYPHS[10], SMALL_GLYPHS[17]], 5);
+    assert_eq!(read(&text).unwrap(), word);
+}
+
+#[test]
+fn unrecognized_glyph() {
+    let err = read(
+        "\
+.##...#.#
+#..#..#.#
+#..#...#.
+####..#.#
+#..#..#.#
+#..#..#.#",
+    )
+    .unwrap_err();
+
+    assert_eq!(
+        err.to_string(),
+        "couldn't read the small font glyph at columns 6..9:\n\n#.#\n#.#\n.#.\n#.#\n#.#\n#.#\n"
+    );
+}
@@ -0,0 +1,257 @@
+//! Common [`winnow`] parsers, plus drivers that turn parse failures into [`Report`]s pointing at the offending
+//! line and column of the puzzle input.
+//!
+//! ```ignore
+//! let discs: Vec<(u8, u8)> = parse::lines(input, separated_pair(unsigned, ' ', unsigned))
+//!     .collect::<Result<_>>()?;
+//! ```
+//!
+//! A failure on the 37th line of the input is reported as:
+//!
+//! ```text
+//! line 37, column 12: invalid shift amount
+//!    |
+//! 37 | x LSHIFT 1z -> y
+//!    |            ^
+//! ```
+
+use std::fmt::Write;
+
+use eyre::{Report, Result, eyre};
+use winnow::ascii::{Int, Uint, dec_int, dec_uint, line_ending, space0, space1};
+use winnow::combinator::{delimited, repeat, separated, separated_pair};
+use winnow::error::{ContextError, ErrMode, StrContext};
+use winnow::prelude::*;
+use winnow::stream::Accumulate;
+use winnow::token::none_of;
+
+/// Parse an unsigned decimal integer.
+#[inline]
+pub fn unsigned<T: Uint>(input: &mut &str) -> ModalResult<T> {
+    dec_uint.parse_next(input)
+}
+
+/// Parse a decimal integer with an optional leading `+` or `-`.
+#[inline]
+pub fn signed<T: Int>(input: &mut &str) -> ModalResult<T> {
+    dec_int.parse_next(input)
+}
+
+/// Parse one or more `item`s separated by commas, each optionally followed by spaces (e.g. `1,2, 3`).
+pub fn comma_separated<'s, O, C, P>(item: P) -> impl Parser<&'s str, C, ErrMode<ContextError>>
+where
+    P: Parser<&'s str, O, ErrMode<ContextError>>,
+    C: Accumulate<O>,
+{
+    separated(1.., item, (',', space0))
+}
+
+/// Parse one or more `item`s separated (and optionally surrounded) by spaces or tabs.
+pub fn whitespace_separated<'s, O, C, P>(item: P) -> impl Parser<&'s str, C, ErrMode<ContextError>>
+where
+    P: Parser<&'s str, O, ErrMode<ContextError>>,
+    C: Accumulate<O>,
+{
+    delimited(space0, separated(1.., item, space1), space0)
+}
+
+/// Parse a single `key: value` pair.
+pub fn key_value<'s, K, V, PK, PV>(
+    key: PK,
+    value: PV,
+) -> impl Parser<&'s str, (K, V), ErrMode<ContextError>>
+where
+    PK: Parser<&'s str, K, ErrMode<ContextError>>,
+    PV: Parser<&'s str, V, ErrMode<ContextError>>,
+{
+    separated_pair(key, (':', space0), value)
+}
+
+/// Parse a comma-separated record of `key: value` pairs (e.g. `cars: 9, akitas: 3`).
+pub fn record<'s, K, V, C, PK, PV>(
+    key: PK,
+    value: PV,
+) -> impl Parser<&'s str, C, ErrMode<ContextError>>
+where
+    PK: Parser<&'s str, K, ErrMode<ContextError>>,
+    PV: Parser<&'s str, V, ErrMode<ContextError>>,
+    C: Accumulate<(K, V)>,
+{
+    comma_separated(key_value(key, value))
+}
+
+/// Parse a rectangular grid of characters, mapping each one through `cell`. Rows are returned top to bottom.
+pub fn grid<'s, T, F>(cell: F) -> impl Parser<&'s str, Vec<Vec<T>>, ErrMode<ContextError>>
+where
+    F: FnMut(char) -> Option<T>,
+{
+    separated(
+        1..,
+        repeat(1.., none_of(['\r', '\n']).verify_map(cell)).context(StrContext::Label("grid cell")),
+        line_ending,
+    )
+    .verify(|rows: &Vec<Vec<T>>| rows.iter().all(|row| row.len() == rows[0].len()))
+    .context(StrContext::Label("rectangular grid"))
+}
+
+/// Parse the whole of `input` with `parser`, reporting the line and column of any failure.
+pub fn complete<'s, O, P>(input: &'s str, mut parser: P) -> Result<O>
+where
+    P: Parser<&'s str, O, ErrMode<ContextError>>,
+{
+    parser
+        .parse(input)
+        .map_err(|e| locate(input, 0, e.offset(), e.inner()))
+}
+
+/// Parse each line of `input` with `parser`, reporting the line and column of any failure.
+pub fn lines<'s, O, P>(input: &'s str, mut parser: P) -> impl Iterator<Item = Result<O>> + 's
+where
+    P: Parser<&'s str, O, ErrMode<ContextError>> + 's,
+{
+    input.lines().enumerate().map(move |(i, line)| {
+        parser
+            .parse(line)
+            .map_err(|e| locate(line, i, e.offset(), e.inner()))
+    })
+}
+
+/// Parse each blank-line-separated section of `input` with `parser`, reporting the line and column (relative to
+/// the start of `input`) of any failure. Sections don't include their trailing newline.
+pub fn sections<'s, O, P>(input: &'s str, mut parser: P) -> impl Iterator<Item = Result<O>> + 's
+where
+    P: Parser<&'s str, O, ErrMode<ContextError>> + 's,
+{
+    let mut first_line = 0;
+    let mut rest = Some(input);
+
+    // Split at each blank line, whether the input's lines end in `\n` or `\r\n`.
+    std::iter::from_fn(move || {
+        let text = rest?;
+        let blank = text.match_indices('\n').find_map(|(i, _)| {
+            let after = &text[i + 1..];
+            let gap = if after.starts_with('\n') {
+                1
+            } else if after.starts_with("\r\n") {
+                2
+            } else {
+                return None;
+            };
+            Some((i, i + 1 + gap))
+        });
+
+        match blank {
+            Some((end, next)) => {
+                rest = Some(&text[next..]);
+                Some(&text[..end])
+            }
+            None => {
+                rest = None;
+                Some(text)
+            }
+        }
+    })
+    .map(|section| section.trim_end_matches('\r'))
+    .map(move |section| {
+        let this_line = first_line;
+        first_line += section.lines().count() + 1;
+
+        parser
+            .parse(section)
+            .map_err(|e| locate(section, this_line, e.offset(), e.inner()))
+    })
+}
+
+/// Build a [`Report`] for an error at byte `offset` of `input`, whose first line is line `first_line` (0-indexed)
+/// of the puzzle input.
+fn locate(input: &str, first_line: usize, offset: usize, error: &ContextError) -> Report {
+    let before = &input[..offset];
+    let line_idx = before.matches('\n').count();
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    let line_end = input[offset..]
+        .find(['\r', '\n'])
+        .map_or(input.len(), |i| offset + i);
+
+    let line_number = first_line + line_idx + 1;
+    let column = input[line_start..offset].chars().count() + 1;
+    let text = &input[line_start..line_end];
+
+    let message = match error.to_string() {
+        message if message.is_empty() => "unexpected input".to_string(),
+        message => message.replace('\n', "; "),
+    };
+
+    let gutter = " ".repeat(line_number.to_string().len());
+    let mut report = format!("line {line_number}, column {column}: {message}\n");
+    // Writing to a `String` can't fail.
+    let _ = writeln!(report, "{gutter} |");
+    let _ = writeln!(report, "{line_number} | {text}");
+    let _ = write!(report, "{gutter} | {:>column$}", "^");
+
+    eyre!(report)
+}
+
+#[test]
+fn report_location() {
+    let input = "1,2,3\n4,5,6\n7,8,x";
+    let err = lines(input, comma_separated::<u8, Vec<_>, _>(unsigned))
+        .collect::<Result<Vec<Vec<u8>>>>()
+        .unwrap_err();
+
+    assert_eq!(
+        err.to_string(),
+        "line 3, column 4: unexpected input\n  |\n3 | 7,8,x\n  |    ^"
+    );
+
+    let err = sections(
+        "a: 1\n\nb: 2\n\nc: x",
+        key_value(winnow::token::any, unsigned::<u8>),
+    )
+    .collect::<Result<Vec<_>>>()
+    .unwrap_err();
+
+    assert!(err.to_string().starts_with("line 5, column 4"));
+
+    let err = sections(
+        "a: 1\r\nb: 2\r\n\r\nc: x",
+        separated(
+            1..,
+            key_value(winnow::token::any, unsigned::<u8>),
+            line_ending,
+        ),
+    )
+    .collect::<Result<Vec<Vec<_>>>>()
+    .unwrap_err();
+
+    assert!(err.to_string().starts_with("line 4, column 4"));
+    assert_eq!(sections("a\r\nb\r\n\r\nc", winnow::token::rest).count(), 2);
+}
+
+#[test]
+fn parsers() {
+    assert_eq!(
+        complete(" 1\t-2  3 ", whitespace_separated::<i8, Vec<_>, _>(signed)).unwrap(),
+        [1, -2, 3]
+    );
+    assert_eq!(
+        complete(
+            "cars: 9, akitas: 3",
+            record::<_, u8, Vec<_>, _, _>(winnow::ascii::alpha1, unsigned)
+        )
+        .unwrap(),
+        [("cars", 9), ("akitas", 3)]
+    );
+    assert_eq!(
+        complete(
+            "#.\n.#",
+            grid(|c| match c {
+                '#' => Some(true),
+                '.' => Some(false),
+                _ => None,
+            })
+        )
+        .unwrap(),
+        [[true, false], [false, true]]
+    );
+    assert!(complete("#.\n.", grid(|c| Some(c == '#'))).is_err());
+}
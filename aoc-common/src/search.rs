@@ -0,0 +1,365 @@
+//! Generic state-space search over anything implementing [`SearchSpace`].
+//!
+//! Searches keep every state they've discovered in an arena so that the route to any visited state can be
+//! reconstructed after the fact with `path_to`.
+
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, VecDeque};
+use std::hash::Hash;
+use std::iter::FusedIterator;
+use std::ops::Add;
+
+use fnv::FnvHashMap;
+
+/// A graph of states that can be searched by [`bfs`], [`tree_bfs`], [`dijkstra`], [`astar`], and
+/// [`reachable_within`].
+pub trait SearchSpace {
+    /// A node in the search graph.
+    type State: Clone + Eq + Hash;
+
+    /// The cost of moving along an edge. [`Default::default`] is used as zero.
+    type Cost: Copy + Ord + Default + Add<Output = Self::Cost>;
+
+    /// Returns every state reachable in one step from `state`, along with the cost of that step.
+    fn successors(
+        &self,
+        state: &Self::State,
+    ) -> impl IntoIterator<Item = (Self::State, Self::Cost)>;
+
+    /// Returns `true` if `state` is a goal of the search.
+    fn is_goal(&self, state: &Self::State) -> bool;
+
+    /// An estimate of the remaining cost from `state` to the nearest goal, used by [`astar`]. To guarantee
+    /// optimal results the estimate must be consistent: it can't drop by more than the cost of any step (and so
+    /// never exceeds the true cost), since [`astar`] never reopens a state once it's settled.
+    #[inline]
+    fn heuristic(&self, _state: &Self::State) -> Self::Cost {
+        Self::Cost::default()
+    }
+
+    /// Maps `state` onto a representative of its equivalence class, so that equivalent states are only
+    /// visited once.
+    #[inline]
+    fn canonicalize(&self, state: Self::State) -> Self::State {
+        state
+    }
+}
+
+/// A route through a [`SearchSpace`], from the starting state to (and including) the final state.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<T, C> {
+    pub states: Vec<T>,
+    pub cost: C,
+}
+
+/// Search `space` breadth-first from `start`, treating every edge as a single step.
+pub fn bfs<S: SearchSpace>(space: &S, start: S::State) -> Bfs<'_, S> {
+    Bfs::new(space, start, None, true)
+}
+
+/// Search `space` breadth-first from `start` without keeping track of the states already visited, for spaces
+/// that are trees (where no state can be reached twice) and so gain nothing from it. [`Bfs::path_to`] always
+/// returns `None` for these searches, but [`Bfs::next_goal`] still works.
+pub fn tree_bfs<S: SearchSpace>(space: &S, start: S::State) -> Bfs<'_, S> {
+    Bfs::new(space, start, None, false)
+}
+
+/// Search `space` breadth-first from `start`, without expanding any state more than `max_depth` steps away.
+pub fn reachable_within<S: SearchSpace>(
+    space: &S,
+    start: S::State,
+    max_depth: usize,
+) -> Bfs<'_, S> {
+    Bfs::new(space, start, Some(max_depth), true)
+}
+
+/// Search `space` from `start` in order of increasing total cost.
+pub fn dijkstra<S: SearchSpace>(space: &S, start: S::State) -> Dijkstra<'_, S> {
+    Dijkstra::new(space, start, false)
+}
+
+/// Find the cheapest path from `start` to a goal of `space`, guided by [`SearchSpace::heuristic`].
+pub fn astar<S: SearchSpace>(space: &S, start: S::State) -> Option<Path<S::State, S::Cost>> {
+    Dijkstra::new(space, start, true).next_goal()
+}
+
+#[derive(Debug, Clone)]
+struct Node<T, C> {
+    state: T,
+    parent: Option<usize>,
+    cost: C,
+}
+
+/// Reconstruct the path to the node at `idx` by following parent links.
+fn trace<T: Clone, C: Copy>(nodes: &[Node<T, C>], idx: usize) -> Path<T, C> {
+    let cost = nodes[idx].cost;
+    let mut states = Vec::new();
+    let mut next = Some(idx);
+
+    while let Some(i) = next {
+        states.push(nodes[i].state.clone());
+        next = nodes[i].parent;
+    }
+
+    states.reverse();
+    Path { states, cost }
+}
+
+/// Breadth-first iterator over `(state, depth)` pairs, returned by [`bfs`], [`tree_bfs`] and [`reachable_within`].
+///
+/// A state is expanded when it is yielded, so stopping early never does more work than necessary.
+pub struct Bfs<'a, S: SearchSpace> {
+    space: &'a S,
+    nodes: Vec<Node<S::State, usize>>,
+    index: FnvHashMap<S::State, usize>,
+    queue: VecDeque<usize>,
+    max_depth: Option<usize>,
+    /// Whether to skip states that have already been discovered, which requires indexing every state.
+    dedup: bool,
+}
+
+impl<'a, S: SearchSpace> Bfs<'a, S> {
+    fn new(space: &'a S, start: S::State, max_depth: Option<usize>, dedup: bool) -> Self {
+        let start = space.canonicalize(start);
+        let mut index = FnvHashMap::default();
+        if dedup {
+            index.insert(start.clone(), 0);
+        }
+
+        Self {
+            space,
+            nodes: vec![Node {
+                state: start,
+                parent: None,
+                cost: 0,
+            }],
+            index,
+            queue: VecDeque::from([0]),
+            max_depth,
+            dedup,
+        }
+    }
+
+    /// Returns the path to `state`, if it has been discovered.
+    pub fn path_to(&self, state: &S::State) -> Option<Path<S::State, usize>> {
+        self.index.get(state).map(|&idx| trace(&self.nodes, idx))
+    }
+
+    /// Advance the search until it reaches a goal, returning the path to it.
+    pub fn next_goal(&mut self) -> Option<Path<S::State, usize>> {
+        loop {
+            let idx = self.expand_next()?;
+            if self.space.is_goal(&self.nodes[idx].state) {
+                return Some(trace(&self.nodes, idx));
+            }
+        }
+    }
+
+    /// Expand the next state in the queue, returning its index in the arena.
+    fn expand_next(&mut self) -> Option<usize> {
+        let idx = self.queue.pop_front()?;
+        let cost = self.nodes[idx].cost;
+
+        if self.max_depth.is_none_or(|max| cost < max) {
+            let state = self.nodes[idx].state.clone();
+            for (next, _) in self.space.successors(&state) {
+                let next = self.space.canonicalize(next);
+
+                if self.dedup {
+                    match self.index.entry(next.clone()) {
+                        Entry::Occupied(_) => continue,
+                        Entry::Vacant(vac) => vac.insert(self.nodes.len()),
+                    };
+                }
+
+                self.queue.push_back(self.nodes.len());
+                self.nodes.push(Node {
+                    state: next,
+                    parent: Some(idx),
+                    cost: cost + 1,
+                });
+            }
+        }
+
+        Some(idx)
+    }
+}
+
+impl<S: SearchSpace> Iterator for Bfs<'_, S> {
+    type Item = (S::State, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let idx = self.expand_next()?;
+        let Node { state, cost, .. } = &self.nodes[idx];
+        Some((state.clone(), *cost))
+    }
+}
+
+impl<S: SearchSpace> FusedIterator for Bfs<'_, S> {}
+
+/// Best-first iterator over `(state, cost)` pairs in order of increasing cost, returned by [`dijkstra`].
+pub struct Dijkstra<'a, S: SearchSpace> {
+    space: &'a S,
+    nodes: Vec<Node<S::State, S::Cost>>,
+    settled: Vec<bool>,
+    index: FnvHashMap<S::State, usize>,
+    heap: BinaryHeap<Reverse<(S::Cost, usize)>>,
+    use_heuristic: bool,
+}
+
+impl<'a, S: SearchSpace> Dijkstra<'a, S> {
+    fn new(space: &'a S, start: S::State, use_heuristic: bool) -> Self {
+        let start = space.canonicalize(start);
+        let priority = if use_heuristic {
+            space.heuristic(&start)
+        } else {
+            S::Cost::default()
+        };
+
+        let mut index = FnvHashMap::default();
+        index.insert(start.clone(), 0);
+
+        Self {
+            space,
+            nodes: vec![Node {
+                state: start,
+                parent: None,
+                cost: S::Cost::default(),
+            }],
+            settled: vec![false],
+            index,
+            heap: BinaryHeap::from([Reverse((priority, 0))]),
+            use_heuristic,
+        }
+    }
+
+    /// Returns the cheapest known path to `state`, if it has been discovered. The path is only guaranteed to
+    /// be optimal once `state` has been yielded.
+    pub fn path_to(&self, state: &S::State) -> Option<Path<S::State, S::Cost>> {
+        self.index.get(state).map(|&idx| trace(&self.nodes, idx))
+    }
+
+    /// Advance the search until it reaches a goal, returning the path to it.
+    pub fn next_goal(&mut self) -> Option<Path<S::State, S::Cost>> {
+        let space = self.space;
+        let (goal, _) = self.find(|(state, _)| space.is_goal(state))?;
+        self.path_to(&goal)
+    }
+
+    fn priority(&self, state: &S::State, cost: S::Cost) -> S::Cost {
+        if self.use_heuristic {
+            cost + self.space.heuristic(state)
+        } else {
+            cost
+        }
+    }
+}
+
+impl<S: SearchSpace> Iterator for Dijkstra<'_, S> {
+    type Item = (S::State, S::Cost);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let idx = loop {
+            let Reverse((_, idx)) = self.heap.pop()?;
+            if !std::mem::replace(&mut self.settled[idx], true) {
+                break idx;
+            }
+        };
+
+        let Node { state, cost, .. } = self.nodes[idx].clone();
+
+        for (next, step) in self.space.successors(&state) {
+            let next = self.space.canonicalize(next);
+            let next_cost = cost + step;
+
+            let next_idx = match self.index.entry(next) {
+                Entry::Occupied(occ) => {
+                    let next_idx = *occ.get();
+                    if self.settled[next_idx] || self.nodes[next_idx].cost <= next_cost {
+                        continue;
+                    }
+
+                    self.nodes[next_idx].cost = next_cost;
+                    self.nodes[next_idx].parent = Some(idx);
+                    next_idx
+                }
+                Entry::Vacant(vac) => {
+                    let next_idx = self.nodes.len();
+                    self.nodes.push(Node {
+                        state: vac.key().clone(),
+                        parent: Some(idx),
+                        cost: next_cost,
+                    });
+                    self.settled.push(false);
+                    vac.insert(next_idx);
+                    next_idx
+                }
+            };
+
+            let priority = self.priority(&self.nodes[next_idx].state, next_cost);
+            self.heap.push(Reverse((priority, next_idx)));
+        }
+
+        Some((state, cost))
+    }
+}
+
+impl<S: SearchSpace> FusedIterator for Dijkstra<'_, S> {}
+
+#[test]
+fn weighted_graph() {
+    // 0 --1-- 1 --1-- 2 --1-- 3
+    //  \_________5___________/
+    struct Line;
+
+    impl SearchSpace for Line {
+        type State = u8;
+        type Cost = u32;
+
+        fn successors(&self, &state: &u8) -> impl IntoIterator<Item = (u8, u32)> {
+            let shortcut = (state == 0).then_some((3, 5));
+            let step = (state < 3).then_some((state + 1, 1));
+            shortcut.into_iter().chain(step)
+        }
+
+        fn is_goal(&self, &state: &u8) -> bool {
+            state == 3
+        }
+
+        fn heuristic(&self, &state: &u8) -> u32 {
+            u32::from(3 - state)
+        }
+    }
+
+    let expected = Path {
+        states: vec![0, 1, 2, 3],
+        cost: 3,
+    };
+
+    assert_eq!(dijkstra(&Line, 0).next_goal(), Some(expected.clone()));
+    assert_eq!(astar(&Line, 0), Some(expected));
+    assert_eq!(
+        bfs(&Line, 0).next_goal(),
+        Some(Path {
+            states: vec![0, 3],
+            cost: 1
+        })
+    );
+    assert_eq!(
+        reachable_within(&Line, 0, 1).collect::<Vec<_>>(),
+        [(0, 0), (3, 1), (1, 1)]
+    );
+
+    // Without deduplication, 3 is reached both directly and along the line.
+    assert_eq!(
+        tree_bfs(&Line, 0)
+            .map(|(state, _)| state)
+            .collect::<Vec<_>>(),
+        [0, 3, 1, 2, 3]
+    );
+    assert_eq!(
+        tree_bfs(&Line, 0).next_goal().map(|path| path.states),
+        Some(vec![0, 3])
+    );
+}
@@ -0,0 +1,213 @@
+//! An instruction-level trace of the puzzle VMs, for working out why one gives the wrong answer.
+//!
+//! Solutions don't get any say in how they're called, so there's one [`Tracer`] for the whole process, set up with
+//! [`install`] before solving. The interpreters call [`record`] after every instruction they execute, which does
+//! nothing unless a tracer is installed.
+
+use std::fmt::Display;
+use std::io::{self, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, PoisonError};
+
+use fnv::{FnvHashMap, FnvHashSet};
+use itertools::Itertools;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static TRACER: Mutex<Option<Tracer>> = Mutex::new(None);
+
+/// Writes a line for each instruction a VM executes, showing its instruction pointer, the instruction and the
+/// registers afterwards.
+///
+/// By default every instruction is written. With breakpoints or watched registers, only instructions at a breakpoint
+/// or that change a watched register are.
+pub struct Tracer {
+    sink: Box<dyn Write + Send>,
+    breakpoints: FnvHashSet<usize>,
+    watched: FnvHashMap<String, Option<i64>>,
+    /// How many times each instruction was executed, by its position and how it looked at the time.
+    histogram: Option<FnvHashMap<(usize, String), u64>>,
+    steps: u64,
+    error: Option<io::Error>,
+}
+
+impl Tracer {
+    pub fn new<W: Write + Send + 'static>(sink: W) -> Self {
+        Self {
+            sink: Box::new(sink),
+            breakpoints: FnvHashSet::default(),
+            watched: FnvHashMap::default(),
+            histogram: None,
+            steps: 0,
+            error: None,
+        }
+    }
+
+    /// Only write instructions at these instruction pointers (or that change a watched register).
+    pub fn with_breakpoints<I: IntoIterator<Item = usize>>(mut self, breakpoints: I) -> Self {
+        self.breakpoints.extend(breakpoints);
+        self
+    }
+
+    /// Only write instructions that change these registers (or are at a breakpoint).
+    pub fn with_watched<I: IntoIterator<Item = S>, S: Into<String>>(
+        mut self,
+        registers: I,
+    ) -> Self {
+        self.watched
+            .extend(registers.into_iter().map(|name| (name.into(), None)));
+        self
+    }
+
+    /// Count how many times each instruction is executed, and write the counts at the end of the trace, which is
+    /// handy for finding the hot loops.
+    pub fn with_histogram(mut self) -> Self {
+        self.histogram = Some(FnvHashMap::default());
+        self
+    }
+
+    fn record(&mut self, ip: usize, instruction: &dyn Display, registers: &[(&str, i64)]) {
+        self.steps += 1;
+
+        if let Some(histogram) = &mut self.histogram {
+            *histogram.entry((ip, instruction.to_string())).or_default() += 1;
+        }
+
+        let mut changed = false;
+        for &(name, value) in registers {
+            if let Some(last) = self.watched.get_mut(name) {
+                changed |= last.replace(value).is_some_and(|last| last != value);
+            }
+        }
+
+        let filtered = !self.breakpoints.is_empty() || !self.watched.is_empty();
+        if filtered && !changed && !self.breakpoints.contains(&ip) {
+            return;
+        }
+
+        let registers = registers
+            .iter()
+            .format_with(" ", |(name, value), f| f(&format_args!("{name}={value}")));
+        let result = writeln!(
+            self.sink,
+            "{:>10} {ip:>4}: {:<24} {registers}",
+            self.steps,
+            instruction.to_string()
+        );
+
+        if let Err(err) = result {
+            self.error.get_or_insert(err);
+        }
+    }
+
+    fn finish(mut self) -> io::Result<()> {
+        if let Some(err) = self.error {
+            return Err(err);
+        }
+
+        if let Some(histogram) = self.histogram {
+            writeln!(self.sink, "\n{:>10} {:>4}  instruction", "count", "ip")?;
+
+            for ((ip, instruction), count) in
+                histogram
+                    .into_iter()
+                    .sorted_unstable_by(|(a, a_count), (b, b_count)| {
+                        b_count.cmp(a_count).then(a.cmp(b))
+                    })
+            {
+                writeln!(self.sink, "{count:>10} {ip:>4}: {instruction}")?;
+            }
+        }
+
+        self.sink.flush()
+    }
+}
+
+/// Start sending every instruction the VMs execute to `tracer`, replacing any tracer that's already installed.
+pub fn install(tracer: Tracer) {
+    *TRACER.lock().unwrap_or_else(PoisonError::into_inner) = Some(tracer);
+    ENABLED.store(true, Ordering::Release);
+}
+
+/// Stop tracing, write the histogram if there is one and flush the trace, reporting the first error writing it.
+pub fn finish() -> io::Result<()> {
+    ENABLED.store(false, Ordering::Release);
+
+    match TRACER.lock().unwrap_or_else(PoisonError::into_inner).take() {
+        Some(tracer) => tracer.finish(),
+        None => Ok(()),
+    }
+}
+
+/// Whether a tracer is installed, for VMs to check before collecting their registers for [`record`].
+#[inline]
+pub fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Record that the instruction at `ip` was executed, leaving the registers as `registers`.
+#[inline]
+pub fn record<I: Display + ?Sized>(ip: usize, instruction: &I, registers: &[(&str, i64)]) {
+    if enabled()
+        && let Some(tracer) = TRACER
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .as_mut()
+    {
+        tracer.record(ip, &format_args!("{instruction}"), registers);
+    }
+}
+
+#[test]
+fn tracing() {
+    use std::sync::Arc;
+
+    #[derive(Clone, Default)]
+    struct Shared(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Shared {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    // inc a; jnz a 0 (x3); dec b
+    let run = |tracer: Tracer| {
+        install(tracer);
+        record(0, "inc a", &[("a", 1), ("b", 5)]);
+        for _ in 0..3 {
+            record(1, "jnz a 0", &[("a", 1), ("b", 5)]);
+        }
+        record(2, "dec b", &[("a", 1), ("b", 4)]);
+        finish().unwrap();
+        record(3, "ignored", &[]);
+    };
+
+    let shared = Shared::default();
+    run(Tracer::new(shared.clone()).with_histogram());
+    let output = String::from_utf8(shared.0.lock().unwrap().clone()).unwrap();
+    let lines = output.lines().collect_vec();
+    assert_eq!(lines.len(), 5 + 2 + 3);
+    assert_eq!(
+        lines[0].split_whitespace().collect_vec(),
+        ["1", "0:", "inc", "a", "a=1", "b=5"]
+    );
+    assert_eq!(
+        lines[7].split_whitespace().collect_vec(),
+        ["3", "1:", "jnz", "a", "0"]
+    );
+
+    let shared = Shared::default();
+    run(Tracer::new(shared.clone())
+        .with_breakpoints([0])
+        .with_watched(["b"]));
+    let output = String::from_utf8(shared.0.lock().unwrap().clone()).unwrap();
+    let steps = output
+        .lines()
+        .map(|line| line.split_whitespace().next().unwrap())
+        .collect_vec();
+    assert_eq!(steps, ["1", "5"]);
+}
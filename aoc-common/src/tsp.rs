@@ -0,0 +1,233 @@
+//! Exact solutions to travelling-salesman-style problems, using the Held–Karp bitmask dynamic program. That takes
+//! O(2ⁿn²) time rather than the O(n!) of trying every ordering, which makes 15 or so nodes practical.
+
+use std::ops::Add;
+
+use petgraph::visit::EdgeRef;
+use petgraph::{EdgeType, Graph, graph::IndexType};
+
+/// The best route through every node of a [`Tsp`], found by its `shortest_*` and `longest_*` methods.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tour<W> {
+    /// The nodes in the order they're visited. For cycles, the return to the first node isn't repeated at the end.
+    pub order: Vec<usize>,
+    /// The total weight of the route.
+    pub weight: W,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Objective {
+    Shortest,
+    Longest,
+}
+
+impl Objective {
+    #[inline]
+    fn prefers<W: Ord>(self, candidate: W, current: W) -> bool {
+        match self {
+            Objective::Shortest => candidate < current,
+            Objective::Longest => candidate > current,
+        }
+    }
+}
+
+/// A complete or partial weighted graph of up to 31 nodes to find Hamiltonian paths and cycles through. Node `i` is
+/// the node with index `i` in the graph it was built from.
+#[derive(Debug, Clone)]
+pub struct Tsp<W> {
+    /// The weight of the edge from `a` to `b` is at `a * len + b`.
+    weights: Vec<Option<W>>,
+    len: usize,
+}
+
+impl<W: Copy + Ord + Default + Add<Output = W>> Tsp<W> {
+    /// Build a problem with `len` nodes, where `weight(a, b)` is the weight of the edge from `a` to `b` (if there is
+    /// one).
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are more than 31 nodes.
+    pub fn from_fn<F: FnMut(usize, usize) -> Option<W>>(len: usize, mut weight: F) -> Self {
+        assert!(len < 32, "{len} nodes is too many to solve exactly");
+
+        let weights = (0..len * len)
+            .map(|i| {
+                (i / len != i % len)
+                    .then(|| weight(i / len, i % len))
+                    .flatten()
+            })
+            .collect();
+
+        Self { weights, len }
+    }
+
+    /// Build a problem from the edges of `graph`. Edges of undirected graphs can be followed both ways.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are more than 31 nodes.
+    pub fn from_graph<N, Ty: EdgeType, Ix: IndexType>(graph: &Graph<N, W, Ty, Ix>) -> Self {
+        let len = graph.node_count();
+        let mut tsp = Self::from_fn(len, |_, _| None);
+
+        for edge in graph.edge_references() {
+            let (a, b) = (edge.source().index(), edge.target().index());
+            tsp.weights[a * len + b] = Some(*edge.weight());
+
+            if !graph.is_directed() {
+                tsp.weights[b * len + a] = Some(*edge.weight());
+            }
+        }
+
+        tsp
+    }
+
+    /// Make the weight between each pair of nodes the sum of the weights in both directions, for problems where
+    /// moving between two nodes matters to both of them. Pairs missing an edge in either direction become
+    /// unconnected.
+    pub fn symmetrize(mut self) -> Self {
+        let len = self.len;
+
+        for a in 0..len {
+            for b in a + 1..len {
+                let sum = self.weight(a, b).zip(self.weight(b, a)).map(|(x, y)| x + y);
+                self.weights[a * len + b] = sum;
+                self.weights[b * len + a] = sum;
+            }
+        }
+
+        self
+    }
+
+    /// The number of nodes.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if there are no nodes.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[inline]
+    fn weight(&self, a: usize, b: usize) -> Option<W> {
+        self.weights[a * self.len + b]
+    }
+
+    /// The lightest path that visits every node once, starting from `start` if it's given.
+    pub fn shortest_path(&self, start: Option<usize>) -> Option<Tour<W>> {
+        self.solve(Objective::Shortest, start, false)
+    }
+
+    /// The heaviest path that visits every node once, starting from `start` if it's given.
+    pub fn longest_path(&self, start: Option<usize>) -> Option<Tour<W>> {
+        self.solve(Objective::Longest, start, false)
+    }
+
+    /// The lightest cycle that visits every node once, starting and ending at node 0.
+    pub fn shortest_cycle(&self) -> Option<Tour<W>> {
+        self.solve(Objective::Shortest, Some(0), true)
+    }
+
+    /// The heaviest cycle that visits every node once, starting and ending at node 0.
+    pub fn longest_cycle(&self) -> Option<Tour<W>> {
+        self.solve(Objective::Longest, Some(0), true)
+    }
+
+    fn solve(&self, objective: Objective, start: Option<usize>, cycle: bool) -> Option<Tour<W>> {
+        let n = self.len;
+        if n == 0 || start.is_some_and(|start| start >= n) {
+            return None;
+        }
+
+        // `best[mask * n + last]` is the best weight of a path that visits exactly the nodes in `mask`, ending at
+        // `last`, and `parent` is the node visited before `last` on that path.
+        let full = (1 << n) - 1;
+        let mut best: Vec<Option<W>> = vec![None; (full + 1) * n];
+        let mut parent = vec![None; (full + 1) * n];
+
+        for first in start.map_or(0..n, |start| start..start + 1) {
+            best[(1 << first) * n + first] = Some(W::default());
+        }
+
+        // Adding a node to a set always makes a bigger mask, so every path is finished before it's extended.
+        for mask in 1..=full {
+            for last in (0..n).filter(|last| mask & (1 << last) != 0) {
+                let Some(so_far) = best[mask * n + last] else {
+                    continue;
+                };
+
+                for next in (0..n).filter(|next| mask & (1 << next) == 0) {
+                    let Some(step) = self.weight(last, next) else {
+                        continue;
+                    };
+
+                    let candidate = so_far + step;
+                    let slot = (mask | (1 << next)) * n + next;
+
+                    if best[slot].is_none_or(|current| objective.prefers(candidate, current)) {
+                        best[slot] = Some(candidate);
+                        parent[slot] = Some(last);
+                    }
+                }
+            }
+        }
+
+        let (mut last, weight) = (0..n)
+            .filter_map(|last| {
+                let weight = best[full * n + last]?;
+
+                match start {
+                    Some(start) if cycle && n > 1 => {
+                        Some((last, weight + self.weight(last, start)?))
+                    }
+                    _ => Some((last, weight)),
+                }
+            })
+            .reduce(|a, b| if objective.prefers(b.1, a.1) { b } else { a })?;
+
+        let mut order = vec![last];
+        let mut mask = full;
+        while let Some(prev) = parent[mask * n + last] {
+            mask &= !(1 << last);
+            last = prev;
+            order.push(last);
+        }
+        order.reverse();
+
+        Some(Tour { order, weight })
+    }
+}
+
+#[test]
+fn tours() {
+    // 0 --1-- 1 --1-- 2 --1-- 3, plus 0 --5-- 2 and 1 --4-- 3
+    let mut graph = petgraph::graph::UnGraph::<(), u32>::new_undirected();
+    let nodes: Vec<_> = (0..4).map(|_| graph.add_node(())).collect();
+    for (a, b, w) in [(0, 1, 1), (1, 2, 1), (2, 3, 1), (0, 2, 5), (1, 3, 4)] {
+        graph.add_edge(nodes[a], nodes[b], w);
+    }
+
+    let tsp = Tsp::from_graph(&graph);
+    assert_eq!(
+        tsp.shortest_path(Some(0)),
+        Some(Tour {
+            order: vec![0, 1, 2, 3],
+            weight: 3
+        })
+    );
+    assert_eq!(tsp.shortest_path(None).unwrap().weight, 3);
+    assert_eq!(tsp.longest_path(None).unwrap().weight, 10);
+    assert_eq!(tsp.shortest_path(Some(1)).unwrap().weight, 7);
+    assert_eq!(tsp.shortest_cycle().unwrap().weight, 11);
+
+    graph.remove_edge(graph.find_edge(nodes[1], nodes[3]).unwrap());
+    assert_eq!(Tsp::from_graph(&graph).shortest_cycle(), None);
+
+    let complete = Tsp::from_fn(4, |a, b| Some(a.abs_diff(b) * 10 + usize::from(a < b)));
+    assert_eq!(complete.shortest_cycle().unwrap().weight, 61);
+    assert_eq!(complete.longest_cycle().unwrap().weight, 82);
+    assert_eq!(complete.symmetrize().shortest_cycle().unwrap().weight, 124);
+}
@@ -55,13 +55,226 @@ bench! {
         19: [1, 2],
         20: [1, 2],
         21: [1, 2],
-        22: [1],
+        22: [1, 2],
+        23: [1, 2],
+        24: [1, 2],
+        25: [1],
+    },
+    2017: {
+        1: [1, 2],
+        2: [1, 2],
+        3: [1, 2],
+        4: [1, 2],
+        5: [1, 2],
+        6: [1, 2],
+        7: [1, 2],
+        8: [1, 2],
+        9: [1, 2],
+        10: [1, 2],
+        11: [1, 2],
+        12: [1, 2],
+        13: [1, 2],
+        14: [1, 2],
+        15: [1, 2],
+        16: [1, 2],
+        17: [1, 2],
+        18: [1, 2],
+        19: [1, 2],
+        20: [1, 2],
+        21: [1, 2],
+        22: [1, 2],
+        23: [1, 2],
+        24: [1, 2],
+        25: [1],
+    },
+    2018: {
+        1: [1, 2],
+        2: [1, 2],
+        3: [1, 2],
+        4: [1, 2],
+        5: [1, 2],
+        6: [1, 2],
+        7: [1, 2],
+        8: [1, 2],
+        9: [1, 2],
+        10: [1, 2],
+        11: [1, 2],
+        12: [1, 2],
+        13: [1, 2],
+        14: [1, 2],
+        15: [1, 2],
+        16: [1, 2],
+        17: [1, 2],
+        18: [1, 2],
+        19: [1, 2],
+        20: [1, 2],
+        21: [1, 2],
+        22: [1, 2],
+        23: [1, 2],
+        24: [1, 2],
+        25: [1],
+    },
+    2019: {
+        1: [1, 2],
+        2: [1, 2],
+        3: [1, 2],
+        4: [1, 2],
+        5: [1, 2],
+        6: [1, 2],
+        7: [1, 2],
+        8: [1, 2],
+        9: [1, 2],
+        10: [1, 2],
+        11: [1, 2],
+        12: [1, 2],
+        13: [1, 2],
+        14: [1, 2],
+        15: [1, 2],
+        16: [1, 2],
+        17: [1, 2],
+        18: [1, 2],
+        19: [1, 2],
+        20: [1, 2],
+        21: [1, 2],
+        22: [1, 2],
+        23: [1, 2],
+        24: [1, 2],
+        25: [1],
+    },
+    2020: {
+        1: [1, 2],
+        2: [1, 2],
+        3: [1, 2],
+        4: [1, 2],
+        5: [1, 2],
+        6: [1, 2],
+        7: [1, 2],
+        8: [1, 2],
+        9: [1, 2],
+        10: [1, 2],
+        11: [1, 2],
+        12: [1, 2],
+        13: [1, 2],
+        14: [1, 2],
+        15: [1, 2],
+        16: [1, 2],
+        17: [1, 2],
+        18: [1, 2],
+        19: [1, 2],
+        20: [1, 2],
+        21: [1, 2],
+        22: [1, 2],
+        23: [1, 2],
+        24: [1, 2],
+        25: [1],
+    },
+    2021: {
+        1: [1, 2],
+        2: [1, 2],
+        3: [1, 2],
+        4: [1, 2],
+        5: [1, 2],
+        6: [1, 2],
+        7: [1, 2],
+        8: [1, 2],
+        9: [1, 2],
+        10: [1, 2],
+        11: [1, 2],
+        12: [1, 2],
+        13: [1, 2],
+        14: [1, 2],
+        15: [1, 2],
+        16: [1, 2],
+        17: [1, 2],
+        18: [1, 2],
+        19: [1, 2],
+        20: [1, 2],
+        21: [1, 2],
+        22: [1, 2],
+        23: [1, 2],
+        24: [1, 2],
+        25: [1],
+    },
+    2022: {
+        1: [1, 2],
+        2: [1, 2],
+        3: [1, 2],
+        4: [1, 2],
+        5: [1, 2],
+        6: [1, 2],
+        7: [1, 2],
+        8: [1, 2],
+        9: [1, 2],
+        10: [1, 2],
+        11: [1, 2],
+        12: [1, 2],
+        13: [1, 2],
+        14: [1, 2],
+        15: [1, 2],
+        16: [1, 2],
+        17: [1, 2],
+        18: [1, 2],
+        19: [1, 2],
+        20: [1, 2],
+        21: [1, 2],
+        22: [1, 2],
+        23: [1, 2],
+        24: [1, 2],
+        25: [1],
+    },
+    2023: {
+        1: [1, 2],
+        2: [1, 2],
+        3: [1, 2],
+        4: [1, 2],
+        5: [1, 2],
+        6: [1, 2],
+        7: [1, 2],
+        8: [1, 2],
+        9: [1, 2],
+        10: [1, 2],
+        11: [1, 2],
+        12: [1, 2],
+        13: [1, 2],
+        14: [1, 2],
+        15: [1, 2],
+        16: [1, 2],
+        17: [1, 2],
+        18: [1, 2],
+        19: [1, 2],
+        20: [1, 2],
+        21: [1, 2],
+        22: [1, 2],
+        23: [1, 2],
+        24: [1, 2],
+        25: [1],
     },
     2024: {
         1: [1, 2],
-        2: [1],
+        2: [1, 2],
         3: [1, 2],
         4: [1, 2],
+        5: [1, 2],
+        6: [1, 2],
+        7: [1, 2],
+        8: [1, 2],
+        9: [1, 2],
+        10: [1, 2],
+        11: [1, 2],
+        12: [1, 2],
+        13: [1, 2],
+        14: [1, 2],
+        15: [1, 2],
+        16: [1, 2],
+        17: [1, 2],
+        18: [1, 2],
+        19: [1, 2],
+        20: [1, 2],
+        21: [1, 2],
+        22: [1, 2],
+        23: [1, 2],
+        24: [1, 2],
+        25: [1],
     },
     2025: {
         1: [1, 2],
@@ -71,6 +284,10 @@ bench! {
         5: [1, 2],
         6: [1, 2],
         7: [1, 2],
-        8: [1]
+        8: [1, 2],
+        9: [1, 2],
+        10: [1, 2],
+        11: [1, 2],
+        12: [1],
     },
 }
@@ -1,8 +1,13 @@
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::PathBuf;
+
 use clap::Parser;
 use clap_stdin::FileOrStdin;
 use eyre::Result;
 
 use aoc::AOC;
+use aoc_common::trace::{self, Tracer};
 use aoc_meta::{Day, Part, Year};
 
 #[derive(Debug, Parser)]
@@ -18,6 +23,23 @@ struct Args {
 
     /// File containing puzzle input (can also be read from STDIN).
     input: FileOrStdin,
+
+    /// Write every instruction executed by the problem's VM (if it has one), or every turn of a game it simulates,
+    /// to this file.
+    #[arg(long, value_name = "FILE")]
+    trace: Option<PathBuf>,
+
+    /// Only trace instructions at this instruction pointer (can be repeated).
+    #[arg(long = "break", value_name = "IP", requires = "trace")]
+    breakpoints: Vec<usize>,
+
+    /// Only trace instructions that change this register (can be repeated).
+    #[arg(long = "watch", value_name = "REGISTER", requires = "trace")]
+    watched: Vec<String>,
+
+    /// Finish the trace with how many times each instruction was executed.
+    #[arg(long, requires = "trace")]
+    histogram: bool,
 }
 
 fn main() -> Result<()> {
@@ -26,11 +48,28 @@ fn main() -> Result<()> {
         day,
         part,
         input,
+        trace,
+        breakpoints,
+        watched,
+        histogram,
     } = Args::parse();
 
-    let output = AOC[year][day][part].solve(&input.contents()?)?;
+    if let Some(path) = trace {
+        let tracer = Tracer::new(BufWriter::new(File::create(path)?))
+            .with_breakpoints(breakpoints)
+            .with_watched(watched);
+
+        trace::install(if histogram {
+            tracer.with_histogram()
+        } else {
+            tracer
+        });
+    }
+
+    let output = AOC[year][day][part].solve(&input.contents()?);
+    trace::finish()?;
 
-    println!("{}", output);
+    println!("{}", output?);
 
     Ok(())
 }